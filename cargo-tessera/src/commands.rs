@@ -2,13 +2,47 @@ pub mod android;
 pub mod build;
 pub mod dev;
 pub mod new;
+pub mod package;
 pub mod plugin;
 pub mod profiling;
 pub mod web;
 
-use std::{fs, path::PathBuf};
+mod platform_assets;
 
-use anyhow::{Result, anyhow};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, anyhow};
+use serde::Deserialize;
+
+/// App metadata loaded from `tessera-app.toml`.
+#[derive(Debug, Deserialize, Default)]
+pub struct AppToml {
+    /// Reverse-DNS application identifier.
+    pub identifier: Option<String>,
+    /// Human-readable application name.
+    pub name: Option<String>,
+    /// Application version, defaults to the Cargo package version.
+    pub version: Option<String>,
+    /// Icon path relative to the package directory.
+    pub icon: Option<String>,
+    /// Desktop entry categories.
+    #[serde(default)]
+    pub categories: Vec<String>,
+}
+
+/// Load `tessera-app.toml` from a package directory, defaulting when absent
+pub fn load_app_toml(root: &Path) -> Result<AppToml> {
+    let path = root.join("tessera-app.toml");
+    if !path.exists() {
+        return Ok(AppToml::default());
+    }
+    let contents =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    toml::from_str(&contents).with_context(|| format!("Failed to parse {}", path.display()))
+}
 
 /// Find the directory of a package by name in the workspace
 pub fn find_package_dir(package_name: &str) -> Result<PathBuf> {
@@ -58,3 +92,43 @@ pub fn find_package_dir(package_name: &str) -> Result<PathBuf> {
 
     Err(anyhow!("Package '{}' not found in workspace", package_name))
 }
+
+/// Default app identifier derived from the package name
+pub fn default_identifier(package_name: &str) -> String {
+    let sanitized = package_name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    format!("com.example.{sanitized}")
+}
+
+/// Replace characters that are not valid in an app identifier
+pub fn sanitize_identifier(identifier: &str) -> String {
+    identifier
+        .chars()
+        .map(|c| match c {
+            '-' => '_',
+            c if c.is_ascii_alphanumeric() || c == '_' || c == '.' => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+/// Recursively copy a directory
+fn copy_dir_all(source: &Path, target: &Path) -> Result<()> {
+    fs::create_dir_all(target).with_context(|| format!("Failed to create {}", target.display()))?;
+    for entry in
+        fs::read_dir(source).with_context(|| format!("Failed to read {}", source.display()))?
+    {
+        let entry = entry?;
+        let path = entry.path();
+        let dest = target.join(entry.file_name());
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            copy_dir_all(&path, &dest)?;
+        } else {
+            fs::copy(&path, &dest).with_context(|| format!("Failed to copy {}", path.display()))?;
+        }
+    }
+    Ok(())
+}
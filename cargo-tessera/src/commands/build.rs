@@ -41,6 +41,12 @@ pub fn execute(
     output::status("Building", message);
     let asset_backend = resolve_asset_backend(target, asset_backend_override)?;
     output::status("Assets", format!("backend `{}`", asset_backend.as_str()));
+    if asset_backend == AssetBackend::Platform && !target_is_android(target) {
+        output::note(
+            "platform assets are read from `share/<app>` next to the install prefix; \
+             use `cargo tessera package` to stage them",
+        );
+    }
 
     let mut cmd = Command::new("cargo");
     cmd.arg("build");
@@ -98,17 +104,15 @@ fn configure_tessera_ui_features(
 }
 
 fn target_is_android(target: Option<&str>) -> bool {
-    target.map(is_platform_backend_target).unwrap_or(false)
+    target.is_some_and(|target| target.to_ascii_lowercase().contains("android"))
 }
 
 fn resolve_asset_backend(
     target: Option<&str>,
     asset_backend_override: Option<AssetBackend>,
 ) -> Result<AssetBackend> {
-    let supports_platform = target.map(is_platform_backend_target).unwrap_or(false);
-
     if let Some(backend) = asset_backend_override {
-        if backend == AssetBackend::Platform && !supports_platform {
+        if backend == AssetBackend::Platform && !supports_platform_backend(target) {
             bail!(
                 "Requested platform asset backend is not supported for target `{}`",
                 target.unwrap_or("host-default")
@@ -117,13 +121,19 @@ fn resolve_asset_backend(
         return Ok(backend);
     }
 
-    if supports_platform {
+    if target_is_android(target) {
         Ok(AssetBackend::Platform)
     } else {
         Ok(AssetBackend::Embed)
     }
 }
 
-fn is_platform_backend_target(target: &str) -> bool {
-    target.to_ascii_lowercase().contains("android")
+fn supports_platform_backend(target: Option<&str>) -> bool {
+    match target {
+        Some(target) => {
+            let target = target.to_ascii_lowercase();
+            target.contains("android") || target.contains("linux")
+        }
+        None => cfg!(target_os = "linux"),
+    }
 }
@@ -1,36 +1,75 @@
 use std::{
+    fs,
+    io::{BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
     path::{Path, PathBuf},
     process::{Child, Command},
-    sync::mpsc::channel,
+    sync::{Arc, Mutex, mpsc::channel},
+    thread,
     time::{Duration, Instant},
 };
 
-use anyhow::{Result, anyhow};
+use anyhow::{Context, Result, anyhow};
+use cargo_metadata::{MetadataCommand, TargetKind};
 use notify::{Event, EventKind, RecursiveMode, Watcher};
+use serde_json::Value;
 use tessera_build::{AssetBackend, load_tessera_config_from_dir, resolve_assets_dir};
 
 use crate::output;
 
 use super::find_package_dir;
 
-pub fn execute(
-    verbose: bool,
-    package: Option<&str>,
-    release: bool,
-    profiling_output: Option<&Path>,
-    debug_dirty_overlay: bool,
-    asset_backend_override: Option<AssetBackend>,
-) -> Result<()> {
-    output::status("Starting", "dev server (auto rebuild/restart)");
+pub struct DevOptions<'a> {
+    pub verbose: bool,
+    pub package: Option<&'a str>,
+    pub release: bool,
+    pub profiling_output: Option<&'a Path>,
+    pub profiling_stream: Option<&'a str>,
+    pub debug_dirty_overlay: bool,
+    pub asset_backend_override: Option<AssetBackend>,
+    pub hot_reload: bool,
+    pub inspector: bool,
+}
+
+pub fn execute(options: DevOptions<'_>) -> Result<()> {
+    let DevOptions {
+        verbose,
+        package,
+        release,
+        profiling_output,
+        profiling_stream,
+        debug_dirty_overlay,
+        asset_backend_override,
+        hot_reload,
+        inspector,
+    } = options;
+    if hot_reload {
+        output::status("Starting", "dev server (hot reload)");
+    } else {
+        output::status("Starting", "dev server (auto rebuild/restart)");
+    }
     if let Some(pkg) = package {
         output::status("Package", format!("`{}`", pkg));
     }
     if let Some(path) = profiling_output {
         output::status("Profiling", format!("enabled ({})", path.display()));
     }
+    if let Some(addr) = profiling_stream {
+        output::status("Profiling", format!("streaming to {addr}"));
+    }
     if debug_dirty_overlay {
         output::status("DirtyOverlay", "enabled");
     }
+    let inspector_addr = if inspector {
+        let addr = start_inspector_server(verbose)?;
+        output::status(
+            "Inspector",
+            format!("listening on {addr} (press F12 in the app to pick)"),
+        );
+        Some(addr)
+    } else {
+        None
+    };
     output::status("Watching", "for file changes");
 
     let (tx, rx) = channel();
@@ -84,6 +123,12 @@ pub fn execute(
         watcher.watch(&assets_dir, RecursiveMode::Recursive)?;
     }
 
+    let mut hot_reload_session = if hot_reload {
+        Some(HotReloadSession::start(&package_dir, package, release)?)
+    } else {
+        None
+    };
+
     let mut child: Option<Child> = None;
     let mut build_child: Option<Child> = None;
     let mut pending_change = true;
@@ -110,16 +155,25 @@ pub fn execute(
 
         // Kick off a build once the tree is quiet and no build is currently running.
         if pending_change && build_child.is_none() && last_change.elapsed() >= debounce_window {
-            // Kill previous process
-            if let Some(mut c) = child.take() {
+            // A running app picks up library rebuilds in place when hot reload is on.
+            let reload_in_place = hot_reload_session.is_some() && child.is_some();
+            if !reload_in_place && let Some(mut c) = child.take() {
                 let _ = c.kill();
                 let _ = c.wait();
             }
 
-            output::status("Building", "project");
-
             let mut build_cmd = Command::new("cargo");
-            build_cmd.arg("build");
+            if reload_in_place {
+                output::status("Building", "app library");
+                build_cmd
+                    .arg("rustc")
+                    .arg("--lib")
+                    .arg("--crate-type")
+                    .arg("dylib");
+            } else {
+                output::status("Building", "project");
+                build_cmd.arg("build");
+            }
             if release {
                 build_cmd.arg("--release");
             }
@@ -129,8 +183,17 @@ pub fn execute(
             if let Some(pkg) = package {
                 build_cmd.arg("-p").arg(pkg);
             }
-            configure_tessera_ui_features(&mut build_cmd, profiling_output, debug_dirty_overlay);
+            configure_tessera_ui_features(
+                &mut build_cmd,
+                profiling_output,
+                profiling_stream,
+                debug_dirty_overlay,
+                inspector,
+            );
             build_cmd.env("TESSERA_ASSET_BACKEND", asset_backend.as_str());
+            if let Some(session) = hot_reload_session.as_ref() {
+                session.configure(&mut build_cmd);
+            }
 
             match build_cmd.spawn() {
                 Ok(c) => {
@@ -151,6 +214,13 @@ pub fn execute(
                         output::warn("build failed; waiting for changes");
                     } else if pending_change {
                         output::status("Rebuilding", "new changes arrived during build");
+                    } else if let Some(session) = hot_reload_session.as_mut()
+                        && child.is_some()
+                    {
+                        match session.publish() {
+                            Ok(()) => output::status("Reloaded", "app library"),
+                            Err(err) => output::warn(format!("hot reload failed: {err:#}")),
+                        }
                     } else {
                         let mut run_cmd = Command::new("cargo");
                         run_cmd.arg("run");
@@ -163,9 +233,18 @@ pub fn execute(
                         configure_tessera_ui_features(
                             &mut run_cmd,
                             profiling_output,
+                            profiling_stream,
                             debug_dirty_overlay,
+                            inspector,
                         );
+                        if let Some(addr) = inspector_addr {
+                            run_cmd.env("TESSERA_INSPECTOR_ADDR", addr.to_string());
+                        }
                         run_cmd.env("TESSERA_ASSET_BACKEND", asset_backend.as_str());
+                        if let Some(session) = hot_reload_session.as_ref() {
+                            session.configure(&mut run_cmd);
+                            run_cmd.env("TESSERA_HOT_RELOAD_ADDR", session.addr.to_string());
+                        }
 
                         match run_cmd.spawn() {
                             Ok(c) => {
@@ -226,24 +305,273 @@ pub fn execute(
     Ok(())
 }
 
+/// Serves rebuilt app libraries to a running app started with hot reload.
+struct HotReloadSession {
+    addr: SocketAddr,
+    clients: Arc<Mutex<Vec<TcpStream>>>,
+    library_path: PathBuf,
+    reload_dir: PathBuf,
+    generation: u64,
+}
+
+impl HotReloadSession {
+    fn start(package_dir: &Path, package: Option<&str>, release: bool) -> Result<Self> {
+        let metadata = MetadataCommand::new()
+            .manifest_path(package_dir.join("Cargo.toml"))
+            .exec()
+            .context("Failed to load cargo metadata for hot reload")?;
+        let cargo_package = match package {
+            Some(name) => metadata
+                .packages
+                .iter()
+                .find(|pkg| pkg.name.as_str() == name),
+            None => metadata.root_package(),
+        }
+        .ok_or_else(|| anyhow!("Unable to resolve the package to hot reload"))?;
+        if !cargo_package.features.contains_key("hot-reload") {
+            return Err(anyhow!(
+                "Package `{}` has no `hot-reload` feature; add `hot-reload = [\"dep:tessera-dylib\"]` to its features",
+                cargo_package.name
+            ));
+        }
+        let lib_target = cargo_package
+            .targets
+            .iter()
+            .find(|target| {
+                target.kind.iter().any(|kind| {
+                    matches!(
+                        kind,
+                        TargetKind::Lib | TargetKind::RLib | TargetKind::DyLib | TargetKind::CDyLib
+                    )
+                })
+            })
+            .ok_or_else(|| {
+                anyhow!(
+                    "Package `{}` has no library target; move the UI into `src/lib.rs` to use hot reload",
+                    cargo_package.name
+                )
+            })?;
+
+        let profile_dir =
+            metadata
+                .target_directory
+                .as_std_path()
+                .join(if release { "release" } else { "debug" });
+        let library_path = profile_dir.join(format!(
+            "{}{}{}",
+            std::env::consts::DLL_PREFIX,
+            lib_target.name.replace('-', "_"),
+            std::env::consts::DLL_SUFFIX
+        ));
+        let reload_dir = profile_dir.join("hot-reload");
+        fs::create_dir_all(&reload_dir)
+            .with_context(|| format!("Failed to create {}", reload_dir.display()))?;
+
+        let listener =
+            TcpListener::bind(("127.0.0.1", 0)).context("Failed to bind the hot reload socket")?;
+        let addr = listener.local_addr()?;
+        let clients = Arc::new(Mutex::new(Vec::new()));
+        let accepted = Arc::clone(&clients);
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                if let Ok(mut clients) = accepted.lock() {
+                    clients.push(stream);
+                }
+            }
+        });
+        output::status("HotReload", format!("listening on {addr}"));
+
+        Ok(Self {
+            addr,
+            clients,
+            library_path,
+            reload_dir,
+            generation: 0,
+        })
+    }
+
+    /// Applies the flags shared by the app binary and every reloaded library.
+    fn configure(&self, cmd: &mut Command) {
+        let mut rustflags = std::env::var("RUSTFLAGS").unwrap_or_default();
+        if !rustflags.is_empty() {
+            rustflags.push(' ');
+        }
+        rustflags.push_str("-C prefer-dynamic");
+        cmd.env("RUSTFLAGS", rustflags);
+        cmd.arg("--features").arg("hot-reload");
+    }
+
+    /// Copies the freshly built library and asks connected apps to load it.
+    fn publish(&mut self) -> Result<()> {
+        // The dynamic loader caches libraries by path, so each reload needs a
+        // unique file name.
+        self.generation += 1;
+        let file_name = self
+            .library_path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .ok_or_else(|| anyhow!("Invalid library path {}", self.library_path.display()))?;
+        let reload_path = self.reload_dir.join(format!(
+            "{file_name}-{}{}",
+            self.generation,
+            std::env::consts::DLL_SUFFIX
+        ));
+        fs::copy(&self.library_path, &reload_path).with_context(|| {
+            format!(
+                "Failed to copy {} to {}",
+                self.library_path.display(),
+                reload_path.display()
+            )
+        })?;
+
+        let message = format!("reload {}\n", reload_path.display());
+        let mut clients = self
+            .clients
+            .lock()
+            .map_err(|_| anyhow!("hot reload client list is poisoned"))?;
+        clients.retain_mut(|stream| stream.write_all(message.as_bytes()).is_ok());
+        if clients.is_empty() {
+            return Err(anyhow!("no running app is connected"));
+        }
+        Ok(())
+    }
+}
+
 fn configure_tessera_ui_features(
     cmd: &mut Command,
     profiling_output: Option<&Path>,
+    profiling_stream: Option<&str>,
     debug_dirty_overlay: bool,
+    inspector: bool,
 ) {
     let mut features = Vec::new();
-    if profiling_output.is_some() {
+    if profiling_output.is_some() || profiling_stream.is_some() {
         features.push("tessera-ui/profiling");
     }
     if debug_dirty_overlay {
         features.push("tessera-ui/debug-dirty-overlay");
     }
+    if inspector {
+        features.push("tessera-ui/inspector");
+    }
     if !features.is_empty() {
         cmd.arg("--features").arg(features.join(","));
     }
     if let Some(output_path) = profiling_output {
         cmd.env("TESSERA_PROFILING_OUTPUT", output_path);
     }
+    if let Some(addr) = profiling_stream {
+        cmd.env("TESSERA_PROFILING_STREAM", addr);
+    }
+}
+
+/// Accepts inspector connections from running apps and prints their records.
+fn start_inspector_server(verbose: bool) -> Result<SocketAddr> {
+    let listener =
+        TcpListener::bind(("127.0.0.1", 0)).context("Failed to bind the inspector socket")?;
+    let addr = listener.local_addr()?;
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            thread::spawn(move || {
+                for line in BufReader::new(stream).lines() {
+                    let Ok(line) = line else {
+                        break;
+                    };
+                    match serde_json::from_str::<Value>(&line) {
+                        Ok(record) => print_inspector_record(&record, verbose),
+                        Err(err) => output::warn(format!("invalid inspector record: {err}")),
+                    }
+                }
+            });
+        }
+    });
+    Ok(addr)
+}
+
+fn print_inspector_record(record: &Value, verbose: bool) {
+    match record["type"].as_str() {
+        Some("pick") => {
+            let node = &record["node"];
+            if node.is_null() {
+                output::status("Picked", "nothing");
+                return;
+            }
+            let path = record["ancestors"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .chain(node["fn_name"].as_str())
+                .collect::<Vec<_>>()
+                .join(" > ");
+            output::status("Picked", path);
+            output::note(format!(
+                "size {}  at {}  (relative {})",
+                format_pair(&node["size"], "x"),
+                format_pair(&node["abs_pos"], ", "),
+                format_pair(&node["rel_pos"], ", ")
+            ));
+            output::note(format!(
+                "constraint {}",
+                format_constraint(&node["constraint"])
+            ));
+            let modifiers = node["modifiers"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>();
+            if !modifiers.is_empty() {
+                output::note(format!("modifiers {}", modifiers.join(" -> ")));
+            }
+            if !node["semantics"].is_null() {
+                output::note(format!("semantics {}", node["semantics"]));
+            }
+            if let Some(focus) = node["focus"].as_str() {
+                output::note(format!("focus {focus}"));
+            }
+            output::note(format!(
+                "layout_cache_hit {}  props_unchanged {}  clips_children {}  opacity {}",
+                node["layout_cache_hit"],
+                node["props_unchanged"],
+                node["clips_children"],
+                node["opacity"]
+            ));
+        }
+        Some("frame") if verbose => {
+            let node_count = record["nodes"].as_array().map_or(0, Vec::len);
+            output::status(
+                "Frame",
+                format!(
+                    "#{} {} ({node_count} nodes) redraw {} dirty roots {}",
+                    record["frame"],
+                    record["build_mode"].as_str().unwrap_or("-"),
+                    record["redraw_reasons"],
+                    record["dirty_replay_roots"]
+                ),
+            );
+        }
+        _ => {}
+    }
+}
+
+fn format_pair(value: &Value, separator: &str) -> String {
+    match value.as_array().map(Vec::as_slice) {
+        Some([first, second]) => format!("{first}{separator}{second}"),
+        _ => "-".to_string(),
+    }
+}
+
+fn format_constraint(value: &Value) -> String {
+    let axis = |axis: &Value| match axis.as_array().map(Vec::as_slice) {
+        Some([min, max]) if max.is_null() => format!("[{min}, inf]"),
+        Some([min, max]) => format!("[{min}, {max}]"),
+        _ => "-".to_string(),
+    };
+    if value.is_null() {
+        return "-".to_string();
+    }
+    format!("w {} h {}", axis(&value["width"]), axis(&value["height"]))
 }
 
 fn resolve_asset_backend(asset_backend_override: Option<AssetBackend>) -> Result<AssetBackend> {
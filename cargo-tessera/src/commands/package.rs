@@ -0,0 +1,687 @@
+use std::{
+    fs,
+    io::Cursor,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use anyhow::{Context, Result, anyhow, bail};
+use cargo_metadata::{MetadataCommand, Package, TargetKind};
+use clap::ValueEnum;
+use image::{GenericImageView, ImageFormat, imageops::FilterType};
+use serde_json::json;
+use tessera_build::AssetBackend;
+
+use crate::output;
+
+use super::{
+    copy_dir_all, default_identifier, find_package_dir, load_app_toml,
+    platform_assets::{
+        collect_files_recursive, collect_platform_asset_candidates, collect_resolved_packages,
+        collect_used_platform_asset_paths,
+    },
+    sanitize_identifier,
+};
+
+const DEFAULT_CATEGORIES: [&str; 1] = ["Utility"];
+const FLATPAK_RUNTIME: &str = "org.freedesktop.Platform";
+const FLATPAK_RUNTIME_VERSION: &str = "24.08";
+const FLATPAK_SDK: &str = "org.freedesktop.Sdk";
+const HICOLOR_PNG_SIZES: [u32; 10] = [16, 22, 24, 32, 48, 64, 96, 128, 256, 512];
+/// Smallest source size that covers the sizes desktops actually request.
+const RECOMMENDED_ICON_SIZE: u32 = 256;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum LinuxPackageFormat {
+    Appimage,
+    Deb,
+    Flatpak,
+}
+
+impl LinuxPackageFormat {
+    pub const ALL: [Self; 3] = [Self::Appimage, Self::Deb, Self::Flatpak];
+}
+
+pub struct PackageOptions<'a> {
+    pub package: Option<&'a str>,
+    pub target: Option<&'a str>,
+    pub formats: &'a [LinuxPackageFormat],
+    pub asset_backend: Option<AssetBackend>,
+    pub out_dir: Option<&'a Path>,
+}
+
+/// App metadata resolved from `Cargo.toml` and `tessera-app.toml`.
+struct LinuxApp {
+    package_name: String,
+    bin_name: String,
+    display_name: String,
+    identifier: String,
+    version: String,
+    description: Option<String>,
+    maintainer: String,
+    homepage: Option<String>,
+    categories: Vec<String>,
+    icon: Option<AppIcon>,
+}
+
+/// Icon files generated from the `icon` in `tessera-app.toml`, from the
+/// smallest to the largest size.
+struct AppIcon {
+    files: Vec<IconFile>,
+}
+
+struct IconFile {
+    /// Install path relative to the rootfs.
+    install_path: PathBuf,
+    bytes: Vec<u8>,
+}
+
+pub fn execute(options: PackageOptions<'_>) -> Result<()> {
+    let target = options.target;
+    match target {
+        Some(target) if !target.contains("linux") => {
+            bail!("`cargo tessera package` only supports Linux targets, got `{target}`")
+        }
+        None if !cfg!(target_os = "linux") => {
+            bail!("`cargo tessera package` only supports Linux hosts; pass a Linux `--target`")
+        }
+        _ => {}
+    }
+
+    let package_dir = options
+        .package
+        .map(find_package_dir)
+        .transpose()?
+        .unwrap_or_else(|| PathBuf::from("."))
+        .canonicalize()
+        .context("Failed to resolve package directory")?;
+    let metadata = MetadataCommand::new()
+        .manifest_path(package_dir.join("Cargo.toml"))
+        .no_deps()
+        .exec()
+        .context("Failed to run cargo metadata")?;
+    let manifest_path = package_dir.join("Cargo.toml");
+    let package = metadata
+        .packages
+        .iter()
+        .find(|package| {
+            package
+                .manifest_path
+                .clone()
+                .into_std_path_buf()
+                .canonicalize()
+                .is_ok_and(|path| path == manifest_path)
+        })
+        .ok_or_else(|| anyhow!("Failed to resolve the package from cargo metadata"))?;
+    let app = resolve_app(package, &package_dir)?;
+    let asset_backend = options.asset_backend.unwrap_or(AssetBackend::Platform);
+
+    output::status(
+        "Packaging",
+        format!("{} {} ({})", app.display_name, app.version, app.identifier),
+    );
+    output::status("Assets", format!("backend `{}`", asset_backend.as_str()));
+
+    let mut cmd = Command::new("cargo");
+    cmd.arg("build")
+        .arg("--release")
+        .arg("-p")
+        .arg(&app.package_name)
+        .arg("--bin")
+        .arg(&app.bin_name);
+    if let Some(target) = target {
+        cmd.arg("--target").arg(target);
+    }
+    cmd.env("TESSERA_ASSET_BACKEND", asset_backend.as_str());
+    let status = cmd.status().context("Failed to run cargo build")?;
+    if !status.success() {
+        bail!("Build failed");
+    }
+
+    let target_dir = metadata.target_directory.into_std_path_buf();
+    let binary_path = match target {
+        Some(target) => target_dir.join(target).join("release"),
+        None => target_dir.join("release"),
+    }
+    .join(&app.bin_name);
+    let out_dir = options
+        .out_dir
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| target_dir.join("tessera-package").join("linux"));
+    // Only clear directories this command stages, `--out-dir` may hold other
+    // files.
+    for staged in ["rootfs", "deb", "AppDir"] {
+        let path = out_dir.join(staged);
+        if path.exists() {
+            fs::remove_dir_all(&path)
+                .with_context(|| format!("Failed to remove {}", path.display()))?;
+        }
+    }
+
+    let rootfs = out_dir.join("rootfs");
+    stage_rootfs(&app, &binary_path, &rootfs)?;
+    if asset_backend == AssetBackend::Platform {
+        stage_platform_assets(&app, &package_dir, &binary_path, &rootfs)?;
+    }
+    output::status("Staged", rootfs.display().to_string());
+
+    let arch = target
+        .and_then(|target| target.split('-').next())
+        .unwrap_or(std::env::consts::ARCH);
+    let formats = if options.formats.is_empty() {
+        &LinuxPackageFormat::ALL[..]
+    } else {
+        options.formats
+    };
+    for format in formats {
+        match format {
+            LinuxPackageFormat::Deb => build_deb(&app, arch, &rootfs, &out_dir)?,
+            LinuxPackageFormat::Appimage => build_appimage(&app, arch, &rootfs, &out_dir)?,
+            LinuxPackageFormat::Flatpak => write_flatpak_manifest(&app, &out_dir)?,
+        }
+    }
+    Ok(())
+}
+
+fn resolve_app(package: &Package, package_dir: &Path) -> Result<LinuxApp> {
+    let app_toml = load_app_toml(package_dir)?;
+    let package_name = package.name.to_string();
+    let bin_name = package
+        .targets
+        .iter()
+        .filter(|target| target.kind.contains(&TargetKind::Bin))
+        .max_by_key(|target| target.name == package_name)
+        .map(|target| target.name.clone())
+        .ok_or_else(|| anyhow!("Package `{package_name}` has no binary target"))?;
+    let identifier = sanitize_identifier(
+        &app_toml
+            .identifier
+            .unwrap_or_else(|| default_identifier(&package_name)),
+    );
+    let icon = match app_toml.icon {
+        Some(icon) => {
+            let source = package_dir.join(icon);
+            if !source.is_file() {
+                bail!("App icon not found: {}", source.display());
+            }
+            let bytes = fs::read(&source)
+                .with_context(|| format!("Failed to read {}", source.display()))?;
+            let extension = source
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .map(str::to_ascii_lowercase);
+            let files = icon_files(&identifier, extension.as_deref(), &bytes)
+                .with_context(|| format!("Failed to process app icon {}", source.display()))?;
+            Some(AppIcon { files })
+        }
+        None => None,
+    };
+    let categories = if app_toml.categories.is_empty() {
+        DEFAULT_CATEGORIES.iter().map(ToString::to_string).collect()
+    } else {
+        app_toml.categories
+    };
+
+    Ok(LinuxApp {
+        display_name: app_toml.name.unwrap_or_else(|| package_name.clone()),
+        version: app_toml
+            .version
+            .unwrap_or_else(|| package.version.to_string()),
+        description: package.description.clone(),
+        maintainer: package
+            .authors
+            .first()
+            .cloned()
+            .unwrap_or_else(|| format!("{package_name} maintainers")),
+        homepage: package
+            .homepage
+            .clone()
+            .or_else(|| package.repository.clone()),
+        package_name,
+        bin_name,
+        identifier,
+        categories,
+        icon,
+    })
+}
+
+/// Installs the binary, desktop entry and icon under `rootfs/usr`.
+fn stage_rootfs(app: &LinuxApp, binary_path: &Path, rootfs: &Path) -> Result<()> {
+    let bin_dir = rootfs.join("usr/bin");
+    fs::create_dir_all(&bin_dir)
+        .with_context(|| format!("Failed to create {}", bin_dir.display()))?;
+    let installed_binary = bin_dir.join(&app.bin_name);
+    fs::copy(binary_path, &installed_binary)
+        .with_context(|| format!("Failed to copy {}", binary_path.display()))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&installed_binary, fs::Permissions::from_mode(0o755))?;
+    }
+
+    let applications_dir = rootfs.join("usr/share/applications");
+    fs::create_dir_all(&applications_dir)
+        .with_context(|| format!("Failed to create {}", applications_dir.display()))?;
+    fs::write(
+        applications_dir.join(format!("{}.desktop", app.identifier)),
+        desktop_entry(app),
+    )?;
+
+    match app.icon.as_ref() {
+        Some(icon) => {
+            for file in &icon.files {
+                let icon_path = rootfs.join(&file.install_path);
+                if let Some(parent) = icon_path.parent() {
+                    fs::create_dir_all(parent)
+                        .with_context(|| format!("Failed to create {}", parent.display()))?;
+                }
+                fs::write(&icon_path, &file.bytes)
+                    .with_context(|| format!("Failed to write {}", icon_path.display()))?;
+            }
+        }
+        None => output::warn("no `icon` set in tessera-app.toml; packages will use a generic icon"),
+    }
+    Ok(())
+}
+
+fn desktop_entry(app: &LinuxApp) -> String {
+    let mut entry = String::from("[Desktop Entry]\nType=Application\n");
+    entry.push_str(&format!("Name={}\n", app.display_name));
+    if let Some(description) = app.description.as_deref() {
+        entry.push_str(&format!("Comment={}\n", description.replace('\n', " ")));
+    }
+    entry.push_str(&format!("Exec={}\n", app.bin_name));
+    entry.push_str(&format!("Icon={}\n", app.identifier));
+    entry.push_str("Terminal=false\n");
+    entry.push_str(&format!("Categories={};\n", app.categories.join(";")));
+    entry.push_str(&format!("StartupWMClass={}\n", app.bin_name));
+    entry
+}
+
+/// Generates the icon files to install, relative to the rootfs.
+///
+/// SVG icons go into the scalable hicolor directory. Square PNG icons are
+/// resized to every hicolor size up to the source size, and other PNG icons
+/// fall back to `pixmaps`.
+fn icon_files(identifier: &str, extension: Option<&str>, bytes: &[u8]) -> Result<Vec<IconFile>> {
+    match extension {
+        Some("svg") => Ok(vec![IconFile {
+            install_path: PathBuf::from(format!(
+                "usr/share/icons/hicolor/scalable/apps/{identifier}.svg"
+            )),
+            bytes: bytes.to_vec(),
+        }]),
+        Some("png") => {
+            let image = image::load_from_memory_with_format(bytes, ImageFormat::Png)
+                .context("Failed to decode PNG icon")?;
+            let (width, height) = image.dimensions();
+            let sizes = HICOLOR_PNG_SIZES
+                .into_iter()
+                .filter(|size| *size <= width)
+                .collect::<Vec<_>>();
+            if width != height || sizes.is_empty() {
+                output::warn(format!(
+                    "app icon is {width}x{height}; use a square icon of at least {RECOMMENDED_ICON_SIZE} px for the hicolor theme"
+                ));
+                return Ok(vec![IconFile {
+                    install_path: PathBuf::from(format!("usr/share/pixmaps/{identifier}.png")),
+                    bytes: bytes.to_vec(),
+                }]);
+            }
+            if width < RECOMMENDED_ICON_SIZE {
+                output::warn(format!(
+                    "app icon is {width}x{height}; larger hicolor sizes are skipped, use at least {RECOMMENDED_ICON_SIZE} px"
+                ));
+            }
+
+            sizes
+                .into_iter()
+                .map(|size| {
+                    let bytes = if size == width {
+                        bytes.to_vec()
+                    } else {
+                        let mut encoded = Cursor::new(Vec::new());
+                        image
+                            .resize_exact(size, size, FilterType::Lanczos3)
+                            .write_to(&mut encoded, ImageFormat::Png)
+                            .with_context(|| format!("Failed to encode {size}x{size} icon"))?;
+                        encoded.into_inner()
+                    };
+                    Ok(IconFile {
+                        install_path: PathBuf::from(format!(
+                            "usr/share/icons/hicolor/{size}x{size}/apps/{identifier}.png"
+                        )),
+                        bytes,
+                    })
+                })
+                .collect()
+        }
+        _ => bail!("Unsupported app icon format; use a PNG or SVG file"),
+    }
+}
+
+/// Installs platform backend assets used by the binary under
+/// `rootfs/usr/share/<app>/tessera`.
+fn stage_platform_assets(
+    app: &LinuxApp,
+    package_dir: &Path,
+    binary_path: &Path,
+    rootfs: &Path,
+) -> Result<()> {
+    let packages = collect_resolved_packages(package_dir, None)?;
+    let assets_root = rootfs.join("usr/share").join(&app.bin_name).join("tessera");
+    let candidates = collect_platform_asset_candidates(&packages, &assets_root)?;
+    if candidates.is_empty() {
+        return Ok(());
+    }
+    let used = collect_used_platform_asset_paths(&[binary_path.to_path_buf()], &candidates)?;
+
+    let mut copied = 0usize;
+    for candidate in &candidates {
+        if !candidate.always_copy && !used.contains(&candidate.platform_path) {
+            continue;
+        }
+        candidate.install()?;
+        copied += 1;
+    }
+    output::status(
+        "Assets",
+        format!(
+            "installed {} of {} platform assets to /usr/share/{}",
+            copied,
+            candidates.len(),
+            app.bin_name
+        ),
+    );
+    Ok(())
+}
+
+fn build_deb(app: &LinuxApp, arch: &str, rootfs: &Path, out_dir: &Path) -> Result<()> {
+    let deb_arch = debian_arch(arch)?;
+    let deb_package = debian_package_name(&app.package_name);
+    let deb_version = app.version.replace('-', "~");
+    let deb_root = out_dir.join("deb");
+    copy_dir_all(rootfs, &deb_root)?;
+
+    let installed_size_kib = collect_files_recursive(rootfs)?
+        .iter()
+        .map(|path| fs::metadata(path).map(|meta| meta.len()).unwrap_or(0))
+        .sum::<u64>()
+        .div_ceil(1024);
+    let mut control = format!(
+        "Package: {deb_package}\nVersion: {deb_version}\nArchitecture: {deb_arch}\n\
+         Maintainer: {}\nInstalled-Size: {installed_size_kib}\nSection: misc\nPriority: optional\n",
+        app.maintainer
+    );
+    if let Some(homepage) = app.homepage.as_deref() {
+        control.push_str(&format!("Homepage: {homepage}\n"));
+    }
+    let summary = app.description.as_deref().unwrap_or(&app.display_name);
+    control.push_str(&format!("Description: {}\n", summary.replace('\n', " ")));
+    let debian_dir = deb_root.join("DEBIAN");
+    fs::create_dir_all(&debian_dir)
+        .with_context(|| format!("Failed to create {}", debian_dir.display()))?;
+    fs::write(debian_dir.join("control"), control)?;
+
+    let deb_path = out_dir.join(format!("{deb_package}_{deb_version}_{deb_arch}.deb"));
+    if !tool_available("dpkg-deb") {
+        output::warn("`dpkg-deb` not found; skipping .deb creation");
+        output::note(format!(
+            "the package tree is staged at {}",
+            deb_root.display()
+        ));
+        return Ok(());
+    }
+    let status = Command::new("dpkg-deb")
+        .arg("--build")
+        .arg("--root-owner-group")
+        .arg(&deb_root)
+        .arg(&deb_path)
+        .status()
+        .context("Failed to run dpkg-deb")?;
+    if !status.success() {
+        bail!("dpkg-deb failed");
+    }
+    output::status("Deb", deb_path.display().to_string());
+    Ok(())
+}
+
+fn build_appimage(app: &LinuxApp, arch: &str, rootfs: &Path, out_dir: &Path) -> Result<()> {
+    let app_dir = out_dir.join("AppDir");
+    copy_dir_all(rootfs, &app_dir)?;
+
+    // AppImage expects the entry point, desktop entry and icon at the root.
+    fs::copy(
+        rootfs.join(format!("usr/share/applications/{}.desktop", app.identifier)),
+        app_dir.join(format!("{}.desktop", app.identifier)),
+    )?;
+    if let Some(largest) = app.icon.as_ref().and_then(|icon| icon.files.last())
+        && let Some(file_name) = largest.install_path.file_name()
+    {
+        fs::write(app_dir.join(file_name), &largest.bytes)?;
+    }
+    fs::write(
+        app_dir.join("AppRun"),
+        format!(
+            "#!/bin/sh\nHERE=\"$(dirname \"$(readlink -f \"$0\")\")\"\nexec \"$HERE/usr/bin/{}\" \"$@\"\n",
+            app.bin_name
+        ),
+    )?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(app_dir.join("AppRun"), fs::Permissions::from_mode(0o755))?;
+    }
+
+    let appimage_path = out_dir.join(format!(
+        "{}-{}-{arch}.AppImage",
+        app.display_name.replace(' ', "_"),
+        app.version
+    ));
+    if !tool_available("appimagetool") {
+        output::warn("`appimagetool` not found; skipping AppImage creation");
+        output::note(format!("the AppDir is staged at {}", app_dir.display()));
+        return Ok(());
+    }
+    let status = Command::new("appimagetool")
+        .arg(&app_dir)
+        .arg(&appimage_path)
+        .env("ARCH", arch)
+        .status()
+        .context("Failed to run appimagetool")?;
+    if !status.success() {
+        bail!("appimagetool failed");
+    }
+    output::status("AppImage", appimage_path.display().to_string());
+    Ok(())
+}
+
+fn write_flatpak_manifest(app: &LinuxApp, out_dir: &Path) -> Result<()> {
+    // The staged tree is installed as-is, so `usr/` maps to `/app/`.
+    let manifest = json!({
+        "app-id": app.identifier,
+        "runtime": FLATPAK_RUNTIME,
+        "runtime-version": FLATPAK_RUNTIME_VERSION,
+        "sdk": FLATPAK_SDK,
+        "command": app.bin_name,
+        "finish-args": [
+            "--share=ipc",
+            "--socket=wayland",
+            "--socket=fallback-x11",
+            "--device=dri",
+        ],
+        "modules": [
+            {
+                "name": app.bin_name,
+                "buildsystem": "simple",
+                "build-commands": ["cp -r usr/. /app/"],
+                "sources": [
+                    { "type": "dir", "path": "rootfs" }
+                ],
+            }
+        ],
+    });
+    let manifest_path = out_dir.join(format!("{}.json", app.identifier));
+    fs::write(&manifest_path, serde_json::to_string_pretty(&manifest)?)
+        .with_context(|| format!("Failed to write {}", manifest_path.display()))?;
+    output::status("Flatpak", manifest_path.display().to_string());
+    output::note(format!(
+        "build it with `flatpak-builder --user --install build-dir {}`",
+        manifest_path.display()
+    ));
+    Ok(())
+}
+
+fn debian_arch(arch: &str) -> Result<&'static str> {
+    match arch {
+        "x86_64" => Ok("amd64"),
+        "aarch64" => Ok("arm64"),
+        "i686" | "i586" | "x86" => Ok("i386"),
+        "armv7" | "arm" => Ok("armhf"),
+        "riscv64gc" | "riscv64" => Ok("riscv64"),
+        "powerpc64le" => Ok("ppc64el"),
+        other => bail!("No Debian architecture known for `{other}`"),
+    }
+}
+
+fn debian_package_name(package_name: &str) -> String {
+    package_name
+        .to_ascii_lowercase()
+        .chars()
+        .map(|c| match c {
+            c if c.is_ascii_alphanumeric() || c == '+' || c == '.' => c,
+            _ => '-',
+        })
+        .collect()
+}
+
+fn tool_available(tool: &str) -> bool {
+    Command::new(tool)
+        .arg("--version")
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, path::PathBuf};
+
+    use image::{ImageFormat, RgbaImage};
+
+    use super::{
+        AppIcon, HICOLOR_PNG_SIZES, IconFile, LinuxApp, LinuxPackageFormat, debian_arch,
+        debian_package_name, desktop_entry, icon_files,
+    };
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut bytes = Cursor::new(Vec::new());
+        RgbaImage::from_pixel(width, height, image::Rgba([32, 96, 160, 255]))
+            .write_to(&mut bytes, ImageFormat::Png)
+            .expect("encode test icon");
+        bytes.into_inner()
+    }
+
+    fn install_paths(files: &[IconFile]) -> Vec<String> {
+        files
+            .iter()
+            .map(|file| file.install_path.display().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn square_png_generates_every_hicolor_size() {
+        let files = icon_files("com.example.app", Some("png"), &png(512, 512)).unwrap();
+
+        let expected = HICOLOR_PNG_SIZES
+            .map(|size| format!("usr/share/icons/hicolor/{size}x{size}/apps/com.example.app.png"));
+        assert_eq!(install_paths(&files), expected);
+        for (file, size) in files.iter().zip(HICOLOR_PNG_SIZES) {
+            let decoded = image::load_from_memory(&file.bytes).unwrap();
+            assert_eq!((decoded.width(), decoded.height()), (size, size));
+        }
+    }
+
+    #[test]
+    fn small_png_is_not_upscaled() {
+        let files = icon_files("app", Some("png"), &png(64, 64)).unwrap();
+
+        assert_eq!(
+            install_paths(&files),
+            [16, 22, 24, 32, 48, 64]
+                .map(|size| format!("usr/share/icons/hicolor/{size}x{size}/apps/app.png"))
+        );
+    }
+
+    #[test]
+    fn non_square_png_falls_back_to_pixmaps() {
+        let source = png(300, 200);
+        let files = icon_files("app", Some("png"), &source).unwrap();
+
+        assert_eq!(install_paths(&files), ["usr/share/pixmaps/app.png"]);
+        assert_eq!(files[0].bytes, source);
+    }
+
+    #[test]
+    fn svg_is_installed_as_scalable() {
+        let files = icon_files("app", Some("svg"), b"<svg/>").unwrap();
+
+        assert_eq!(
+            install_paths(&files),
+            ["usr/share/icons/hicolor/scalable/apps/app.svg"]
+        );
+    }
+
+    #[test]
+    fn unsupported_or_corrupt_icons_are_rejected() {
+        assert!(icon_files("app", Some("ico"), b"").is_err());
+        assert!(icon_files("app", Some("png"), b"not a png").is_err());
+    }
+
+    #[test]
+    fn default_formats_follow_appimage_deb_flatpak() {
+        assert_eq!(
+            LinuxPackageFormat::ALL,
+            [
+                LinuxPackageFormat::Appimage,
+                LinuxPackageFormat::Deb,
+                LinuxPackageFormat::Flatpak,
+            ]
+        );
+    }
+
+    #[test]
+    fn desktop_entry_uses_identifier_and_categories() {
+        let app = LinuxApp {
+            package_name: "my_app".to_string(),
+            bin_name: "my_app".to_string(),
+            display_name: "My App".to_string(),
+            identifier: "com.example.my_app".to_string(),
+            version: "1.0.0".to_string(),
+            description: Some("A small\napp".to_string()),
+            maintainer: "Jane <jane@example.com>".to_string(),
+            homepage: None,
+            categories: vec!["Utility".to_string(), "Development".to_string()],
+            icon: Some(AppIcon {
+                files: vec![IconFile {
+                    install_path: PathBuf::from("usr/share/pixmaps/com.example.my_app.png"),
+                    bytes: Vec::new(),
+                }],
+            }),
+        };
+
+        assert_eq!(
+            desktop_entry(&app),
+            "[Desktop Entry]\nType=Application\nName=My App\nComment=A small app\n\
+             Exec=my_app\nIcon=com.example.my_app\nTerminal=false\n\
+             Categories=Utility;Development;\nStartupWMClass=my_app\n"
+        );
+    }
+
+    #[test]
+    fn debian_names_are_normalized() {
+        assert_eq!(debian_arch("x86_64").unwrap(), "amd64");
+        assert_eq!(debian_arch("aarch64").unwrap(), "arm64");
+        assert!(debian_arch("wasm32").is_err());
+        assert_eq!(debian_package_name("My_App"), "my-app");
+    }
+}
@@ -0,0 +1,321 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aho_corasick::AhoCorasick;
+use anyhow::{Context, Result, anyhow};
+use cargo_metadata::MetadataCommand;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use tessera_build::{
+    AssetProcessingConfig, ProcessedAsset, asset_namespace, load_tessera_config_from_dir,
+    process_asset, resolve_assets_dir,
+};
+
+use crate::output;
+
+#[derive(Debug)]
+pub struct ResolvedPackage {
+    pub name: String,
+    pub version: String,
+    pub dir: PathBuf,
+    pub is_root: bool,
+}
+
+#[derive(Debug)]
+pub struct PlatformAssetCandidate {
+    pub platform_path: String,
+    pub source_path: PathBuf,
+    pub target_path: PathBuf,
+    pub always_copy: bool,
+    pub processing: Option<AssetProcessingConfig>,
+}
+
+impl PlatformAssetCandidate {
+    /// Writes the asset to `target_path`, applying configured image
+    /// processing.
+    pub fn install(&self) -> Result<()> {
+        if let Some(parent) = self.target_path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        if let Some(processing) = &self.processing {
+            match process_asset(&self.source_path, processing)? {
+                ProcessedAsset::Unchanged => {}
+                ProcessedAsset::Processed(bytes) => {
+                    return fs::write(&self.target_path, bytes).with_context(|| {
+                        format!("Failed to write asset {}", self.target_path.display())
+                    });
+                }
+                ProcessedAsset::Skipped(reason) => {
+                    output::warn(format!(
+                        "skipped processing asset {}: {reason}",
+                        self.source_path.display()
+                    ));
+                }
+            }
+        }
+        fs::copy(&self.source_path, &self.target_path).with_context(|| {
+            format!(
+                "Failed to copy asset {} to {}",
+                self.source_path.display(),
+                self.target_path.display()
+            )
+        })?;
+        Ok(())
+    }
+}
+
+pub fn collect_platform_asset_candidates(
+    packages: &[ResolvedPackage],
+    tessera_assets_root: &Path,
+) -> Result<Vec<PlatformAssetCandidate>> {
+    let mut candidates = Vec::new();
+
+    for package in packages {
+        let Some(config) = load_tessera_config_from_dir(&package.dir)? else {
+            continue;
+        };
+        let Some(assets_dir) = resolve_assets_dir(&package.dir, Some(&config)) else {
+            continue;
+        };
+        if !assets_dir.is_dir() {
+            return Err(anyhow!(
+                "Configured assets directory not found for package `{}`: {}",
+                package.name,
+                assets_dir.display()
+            ));
+        }
+
+        let tree_shaking_exclude_patterns = config
+            .assets
+            .as_ref()
+            .map(|assets| assets.tree_shaking_exclude_patterns())
+            .unwrap_or(&[]);
+        let tree_shaking_exclude_matcher =
+            build_tree_shaking_exclude_matcher(tree_shaking_exclude_patterns, &package.name)?;
+        let namespace = asset_namespace(&package.name, &package.version);
+        let processing = config
+            .assets
+            .as_ref()
+            .and_then(|assets| assets.processing.clone());
+        collect_package_platform_asset_candidates(
+            &assets_dir,
+            &namespace,
+            tessera_assets_root,
+            tree_shaking_exclude_matcher.as_ref(),
+            processing.as_ref(),
+            &mut candidates,
+        )?;
+    }
+
+    candidates.sort_by(|left, right| left.platform_path.cmp(&right.platform_path));
+    Ok(candidates)
+}
+
+fn collect_package_platform_asset_candidates(
+    assets_dir: &Path,
+    namespace: &str,
+    tessera_assets_root: &Path,
+    tree_shaking_exclude_matcher: Option<&GlobSet>,
+    processing: Option<&AssetProcessingConfig>,
+    out: &mut Vec<PlatformAssetCandidate>,
+) -> Result<()> {
+    for source_path in collect_files_recursive(assets_dir)? {
+        let relative = source_path
+            .strip_prefix(assets_dir)
+            .with_context(|| format!("Failed to relativize {}", source_path.display()))?;
+        let relative_path = normalize_relative_path(relative);
+        let platform_path = format!("tessera/{namespace}/{relative_path}");
+        let target_path = tessera_assets_root.join(namespace).join(relative);
+        out.push(PlatformAssetCandidate {
+            platform_path,
+            source_path,
+            target_path,
+            always_copy: tree_shaking_exclude_matcher
+                .map(|matcher| matcher.is_match(&relative_path))
+                .unwrap_or(false),
+            processing: processing.cloned(),
+        });
+    }
+    Ok(())
+}
+
+fn build_tree_shaking_exclude_matcher(
+    patterns: &[String],
+    package_name: &str,
+) -> Result<Option<GlobSet>> {
+    if patterns.is_empty() {
+        return Ok(None);
+    }
+
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let normalized = normalize_tree_shaking_pattern(pattern);
+        if normalized.is_empty() {
+            return Err(anyhow!(
+                "Invalid empty assets.tree_shaking.exclude pattern in package `{}`",
+                package_name
+            ));
+        }
+
+        let glob = Glob::new(&normalized).with_context(|| {
+            format!(
+                "Invalid assets.tree_shaking.exclude pattern `{}` in package `{}`",
+                pattern, package_name
+            )
+        })?;
+        builder.add(glob);
+    }
+
+    let matcher = builder.build().with_context(|| {
+        format!(
+            "Failed to build assets.tree_shaking.exclude matcher for package `{}`",
+            package_name
+        )
+    })?;
+    Ok(Some(matcher))
+}
+
+fn normalize_tree_shaking_pattern(pattern: &str) -> String {
+    let normalized = pattern.trim().replace('\\', "/");
+    if let Some(stripped) = normalized.strip_prefix("./") {
+        stripped.to_string()
+    } else {
+        normalized
+    }
+}
+
+pub fn collect_files_recursive(root: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut stack = vec![root.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        for entry in
+            fs::read_dir(&dir).with_context(|| format!("Failed to read {}", dir.display()))?
+        {
+            let entry = entry?;
+            let path = entry.path();
+            let file_type = entry
+                .file_type()
+                .with_context(|| format!("Failed to inspect {}", path.display()))?;
+            if file_type.is_dir() {
+                stack.push(path);
+            } else if file_type.is_file() {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+pub fn collect_used_platform_asset_paths(
+    binary_paths: &[PathBuf],
+    candidates: &[PlatformAssetCandidate],
+) -> Result<HashSet<String>> {
+    let patterns = candidates
+        .iter()
+        .map(|candidate| candidate.platform_path.as_bytes())
+        .collect::<Vec<_>>();
+    let matcher = AhoCorasick::new(patterns)
+        .context("Failed to build platform asset path matcher for binary scan")?;
+
+    let mut used = HashSet::new();
+    for binary_path in binary_paths {
+        let bytes = fs::read(binary_path)
+            .with_context(|| format!("Failed to read {}", binary_path.display()))?;
+        for matched in matcher.find_iter(&bytes) {
+            if let Some(candidate) = candidates.get(matched.pattern().as_usize()) {
+                used.insert(candidate.platform_path.clone());
+            }
+        }
+    }
+    Ok(used)
+}
+
+pub fn normalize_relative_path(path: &Path) -> String {
+    path.iter()
+        .map(|segment| segment.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+pub fn collect_resolved_packages(
+    root_dir: &Path,
+    package_dir: Option<&PathBuf>,
+) -> Result<Vec<ResolvedPackage>> {
+    let manifest_path = root_dir.join("Cargo.toml");
+    let metadata = MetadataCommand::new()
+        .manifest_path(&manifest_path)
+        .exec()
+        .context("Failed to run cargo metadata")?;
+
+    let root_manifest = package_dir
+        .map(|dir| dir.join("Cargo.toml"))
+        .unwrap_or_else(|| manifest_path.clone())
+        .canonicalize()
+        .with_context(|| "Failed to resolve root Cargo.toml")?;
+
+    let root_pkg = metadata
+        .packages
+        .iter()
+        .find(|pkg| {
+            pkg.manifest_path
+                .clone()
+                .into_std_path_buf()
+                .canonicalize()
+                .map(|path| path == root_manifest)
+                .unwrap_or(false)
+        })
+        .ok_or_else(|| anyhow!("Failed to resolve the root package from cargo metadata"))?;
+
+    let resolve = metadata
+        .resolve
+        .as_ref()
+        .ok_or_else(|| anyhow!("cargo metadata missing dependency graph"))?;
+
+    let mut nodes = HashMap::new();
+    for node in &resolve.nodes {
+        nodes.insert(node.id.clone(), node);
+    }
+
+    let mut visited = HashSet::new();
+    let mut stack = vec![root_pkg.id.clone()];
+    while let Some(id) = stack.pop() {
+        if visited.insert(id.clone())
+            && let Some(node) = nodes.get(&id)
+        {
+            for dep in &node.deps {
+                stack.push(dep.pkg.clone());
+            }
+        }
+    }
+
+    let mut packages = Vec::new();
+    for pkg in metadata
+        .packages
+        .iter()
+        .filter(|pkg| visited.contains(&pkg.id))
+    {
+        let dir = pkg
+            .manifest_path
+            .clone()
+            .into_std_path_buf()
+            .parent()
+            .map(Path::to_path_buf)
+            .ok_or_else(|| anyhow!("Failed to locate package root for {}", pkg.manifest_path))?;
+        packages.push(ResolvedPackage {
+            name: pkg.name.to_string(),
+            version: pkg.version.to_string(),
+            dir,
+            is_root: pkg.id == root_pkg.id,
+        });
+    }
+    packages.sort_by(|left, right| {
+        left.name
+            .cmp(&right.name)
+            .then(left.version.cmp(&right.version))
+    });
+    Ok(packages)
+}
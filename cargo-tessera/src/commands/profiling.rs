@@ -15,6 +15,12 @@ use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::output;
 
+mod chrome_trace;
+mod live;
+
+pub use chrome_trace::export_chrome_trace;
+pub use live::{LiveOptions, live};
+
 const DEFAULT_ANDROID_REMOTE_PATHS: [&str; 2] =
     ["tessera-profiler.jsonl", "files/tessera-profiler.jsonl"];
 
@@ -50,11 +56,13 @@ enum RedrawReason {
     MouseInput,
     MouseWheel,
     TouchInput,
+    TransformGesture,
     ScaleFactorChanged,
     KeyboardInput,
     ModifiersChanged,
     ImeEvent,
     FocusChanged,
+    WindowOccluded,
     RuntimeInvalidation,
     RuntimeFrameAwaiter,
 }
@@ -76,10 +84,10 @@ enum RuntimeEventKind {
 
 #[derive(Deserialize)]
 struct FrameRecord {
-    #[allow(dead_code)]
     frame: u64,
     build_mode: BuildMode,
     redraw_reasons: Vec<RedrawReason>,
+    start_ns: Option<u64>,
     inter_frame_wait_ns: Option<u64>,
     partial_replay_nodes: Option<u64>,
     total_nodes_before_build: Option<u64>,
@@ -118,6 +126,7 @@ impl RatioSummary {
 struct WakeRecord {
     #[allow(dead_code)]
     frame: u64,
+    time_ns: Option<u64>,
     source: WakeSource,
     reasons: Vec<RedrawReason>,
 }
@@ -139,6 +148,7 @@ struct ComponentRecord {
 
 #[derive(Deserialize)]
 struct PhaseDurations {
+    build_start_ns: Option<u64>,
     build_ns: Option<u64>,
     measure_ns: Option<u64>,
     record_ns: Option<u64>,
@@ -365,15 +375,7 @@ pub fn analyze(
         break header;
     };
 
-    if header.format != "tessera-profiler" {
-        bail!("unsupported profiler format `{}`", header.format);
-    }
-    if header.version != 1 {
-        bail!(
-            "unsupported profiler version {}; expected version 1",
-            header.version
-        );
-    }
+    check_header(&header)?;
 
     for (line_idx, line_result) in lines {
         let line = line_result.with_context(|| format!("failed to read line {}", line_idx + 1))?;
@@ -412,6 +414,19 @@ pub fn analyze(
     Ok(())
 }
 
+fn check_header(header: &TraceFileHeader) -> Result<()> {
+    if header.format != "tessera-profiler" {
+        bail!("unsupported profiler format `{}`", header.format);
+    }
+    if header.version != 1 {
+        bail!(
+            "unsupported profiler version {}; expected version 1",
+            header.version
+        );
+    }
+    Ok(())
+}
+
 fn parse_json_line<T>(line: &str) -> Result<T, serde_json::Error>
 where
     T: serde::de::DeserializeOwned,
@@ -1633,6 +1648,14 @@ fn runtime_event_label(kind: RuntimeEventKind) -> &'static str {
     }
 }
 
+fn build_mode_label(mode: &BuildMode) -> &'static str {
+    match mode {
+        BuildMode::RootRecompose => "root_recompose",
+        BuildMode::PartialReplay => "partial_replay",
+        BuildMode::SkipNoInvalidation => "skip",
+    }
+}
+
 fn redraw_reason_label(reason: RedrawReason) -> &'static str {
     match reason {
         RedrawReason::Startup => "startup",
@@ -1642,11 +1665,13 @@ fn redraw_reason_label(reason: RedrawReason) -> &'static str {
         RedrawReason::MouseInput => "mouse_input",
         RedrawReason::MouseWheel => "mouse_wheel",
         RedrawReason::TouchInput => "touch_input",
+        RedrawReason::TransformGesture => "transform_gesture",
         RedrawReason::ScaleFactorChanged => "scale_factor_changed",
         RedrawReason::KeyboardInput => "keyboard_input",
         RedrawReason::ModifiersChanged => "modifiers_changed",
         RedrawReason::ImeEvent => "ime_event",
         RedrawReason::FocusChanged => "focus_changed",
+        RedrawReason::WindowOccluded => "window_occluded",
         RedrawReason::RuntimeInvalidation => "runtime_invalidation",
         RedrawReason::RuntimeFrameAwaiter => "runtime_frame_awaiter",
     }
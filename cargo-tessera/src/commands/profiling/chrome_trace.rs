@@ -0,0 +1,257 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::Path,
+};
+
+use anyhow::{Context, Result, bail};
+use owo_colors::OwoColorize;
+use serde_json::{Value, json};
+
+use crate::output;
+
+use super::{
+    ComponentRecord, FrameRecord, TraceEvent, TraceFileHeader, WakeRecord, build_mode_label,
+    check_header, parse_json_line, redraw_reason_label, wake_source_label,
+};
+
+// Output can be opened in `chrome://tracing`, Perfetto or any other viewer that
+// understands the JSON trace event format.
+const PROCESS_ID: u32 = 1;
+const FRAME_THREAD_ID: u32 = 1;
+const COMPONENT_THREAD_ID: u32 = 2;
+
+pub fn export_chrome_trace(path: &Path, output_path: &Path, skip_invalid: bool) -> Result<()> {
+    output::status("Converting", path.display().to_string());
+    let file = File::open(path)
+        .with_context(|| format!("failed to open profiler file at {}", path.display()))?;
+    let reader = BufReader::new(file);
+
+    let mut events = vec![
+        metadata_event("process_name", 0, "tessera"),
+        metadata_event("thread_name", FRAME_THREAD_ID, "Frames"),
+        metadata_event("thread_name", COMPONENT_THREAD_ID, "Component build"),
+    ];
+    let mut header_checked = false;
+    let mut frames = 0usize;
+    let mut untimed_frames = 0usize;
+
+    for (line_idx, line_result) in reader.lines().enumerate() {
+        let line = line_result.with_context(|| format!("failed to read line {}", line_idx + 1))?;
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        if !header_checked {
+            let header: TraceFileHeader = parse_json_line(trimmed)
+                .with_context(|| format!("invalid profiler header at line {}", line_idx + 1))?;
+            check_header(&header)?;
+            header_checked = true;
+            continue;
+        }
+
+        match parse_json_line::<TraceEvent>(trimmed) {
+            Ok(TraceEvent::Frame(frame)) => {
+                if push_frame_events(&frame, &mut events) {
+                    frames += 1;
+                } else {
+                    untimed_frames += 1;
+                }
+            }
+            Ok(TraceEvent::Wake(wake)) => push_wake_event(&wake, &mut events),
+            Ok(TraceEvent::Runtime(_)) => {}
+            Err(err) => {
+                if !skip_invalid {
+                    bail!("invalid JSON event at line {}: {}", line_idx + 1, err);
+                }
+            }
+        }
+    }
+
+    if !header_checked {
+        bail!("no profiler records found");
+    }
+    if frames == 0 {
+        bail!("no timed frame records found; re-record the profile with a newer tessera-ui");
+    }
+    if untimed_frames > 0 {
+        output::warn(format!(
+            "skipped {untimed_frames} frame(s) without timestamps"
+        ));
+    }
+
+    let trace = json!({
+        "traceEvents": events,
+        "displayTimeUnit": "ms",
+    });
+    let file = File::create(output_path)
+        .with_context(|| format!("failed to create {}", output_path.display()))?;
+    let mut writer = BufWriter::new(file);
+    serde_json::to_writer(&mut writer, &trace)?;
+    writer.flush()?;
+
+    println!(
+        "{} {} ({} frames)",
+        "Wrote Chrome trace:".green(),
+        output_path.display(),
+        frames
+    );
+    Ok(())
+}
+
+fn metadata_event(name: &str, tid: u32, value: &str) -> Value {
+    json!({
+        "name": name,
+        "ph": "M",
+        "pid": PROCESS_ID,
+        "tid": tid,
+        "args": { "name": value },
+    })
+}
+
+fn complete_event(name: &str, category: &str, tid: u32, start_ns: u64, dur_ns: u64) -> Value {
+    json!({
+        "name": name,
+        "cat": category,
+        "ph": "X",
+        "pid": PROCESS_ID,
+        "tid": tid,
+        "ts": ns_to_us(start_ns),
+        "dur": ns_to_us(dur_ns),
+    })
+}
+
+fn ns_to_us(value_ns: u64) -> f64 {
+    value_ns as f64 / 1_000.0
+}
+
+/// Emits the frame span, its phases and the component build spans.
+///
+/// Frame phases run in sequence on the UI thread, so they are laid out one
+/// after another from the frame start. Returns `false` if the frame has no
+/// timestamp.
+fn push_frame_events(frame: &FrameRecord, events: &mut Vec<Value>) -> bool {
+    let Some(start_ns) = frame.start_ns else {
+        return false;
+    };
+
+    let build_ns = frame.build_tree_time_ns.unwrap_or(0);
+    let draw_ns = frame.draw_time_ns.unwrap_or(0);
+    let record_ns = frame.record_time_ns.unwrap_or(0).min(draw_ns);
+    let render_ns = frame.render_time_ns.unwrap_or(0);
+    let total_ns = frame
+        .frame_total_ns
+        .unwrap_or(build_ns + draw_ns + render_ns);
+
+    let mut frame_event = complete_event(
+        &format!("frame {}", frame.frame),
+        "frame",
+        FRAME_THREAD_ID,
+        start_ns,
+        total_ns,
+    );
+    frame_event["args"] = json!({
+        "build_mode": build_mode_label(&frame.build_mode),
+        "redraw_reasons": frame
+            .redraw_reasons
+            .iter()
+            .map(|reason| redraw_reason_label(*reason))
+            .collect::<Vec<_>>(),
+        "partial_replay_nodes": frame.partial_replay_nodes,
+        "total_nodes_before_build": frame.total_nodes_before_build,
+    });
+    events.push(frame_event);
+
+    let mut cursor = start_ns;
+    for (name, dur_ns) in [
+        ("build", build_ns),
+        ("measure", draw_ns - record_ns),
+        ("record", record_ns),
+    ] {
+        if dur_ns > 0 {
+            events.push(complete_event(
+                name,
+                "phase",
+                FRAME_THREAD_ID,
+                cursor,
+                dur_ns,
+            ));
+        }
+        cursor += dur_ns;
+    }
+
+    if render_ns > 0 {
+        events.push(complete_event(
+            "render",
+            "phase",
+            FRAME_THREAD_ID,
+            cursor,
+            render_ns,
+        ));
+        let mut render_cursor = cursor;
+        for (name, dur_ns) in [
+            ("acquire", frame.render_acquire_ns),
+            ("build passes", frame.render_build_passes_ns),
+            ("encode", frame.render_encode_ns),
+            ("queue submit", frame.render_submit_ns),
+            ("present", frame.render_present_ns),
+        ] {
+            let Some(dur_ns) = dur_ns.filter(|dur_ns| *dur_ns > 0) else {
+                continue;
+            };
+            events.push(complete_event(
+                name,
+                "render",
+                FRAME_THREAD_ID,
+                render_cursor,
+                dur_ns,
+            ));
+            render_cursor += dur_ns;
+        }
+    }
+
+    for component in &frame.components {
+        push_component_events(component, events);
+    }
+    true
+}
+
+fn push_component_events(component: &ComponentRecord, events: &mut Vec<Value>) {
+    if let (Some(start_ns), Some(build_ns)) =
+        (component.phases.build_start_ns, component.phases.build_ns)
+    {
+        let name = component.fn_name.as_deref().unwrap_or("<unknown>");
+        let mut event = complete_event(name, "component", COMPONENT_THREAD_ID, start_ns, build_ns);
+        event["args"] = json!({
+            "measure_ns": component.phases.measure_ns,
+            "record_ns": component.phases.record_ns,
+            "input_ns": component.phases.input_ns,
+            "layout_cache_hit": component.layout_cache_hit,
+        });
+        events.push(event);
+    }
+    for child in &component.children {
+        push_component_events(child, events);
+    }
+}
+
+fn push_wake_event(wake: &WakeRecord, events: &mut Vec<Value>) {
+    let Some(time_ns) = wake.time_ns else {
+        return;
+    };
+    let reasons = wake
+        .reasons
+        .iter()
+        .map(|reason| redraw_reason_label(*reason))
+        .collect::<Vec<_>>();
+    events.push(json!({
+        "name": format!("wake ({})", wake_source_label(wake.source)),
+        "cat": "wake",
+        "ph": "i",
+        "s": "t",
+        "pid": PROCESS_ID,
+        "tid": FRAME_THREAD_ID,
+        "ts": ns_to_us(time_ns),
+        "args": { "reasons": reasons },
+    }));
+}
@@ -0,0 +1,425 @@
+use std::{
+    collections::VecDeque,
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Write},
+    net::{TcpListener, TcpStream},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use console::Term;
+use owo_colors::{AnsiColors, OwoColorize};
+
+use crate::output;
+
+use super::{
+    ComponentRecord, FrameRecord, TraceEvent, TraceFileHeader, build_mode_label, check_header,
+    format_ms, parse_json_line, truncate,
+};
+
+/// Minimum interval between two screen refreshes.
+const REFRESH_INTERVAL: Duration = Duration::from_millis(100);
+/// Frame budget used as the minimum scale of the timeline (60 fps).
+const FRAME_BUDGET_NS: u64 = 16_666_667;
+const DEFAULT_TERM_WIDTH: usize = 100;
+const TIMELINE_LABEL_WIDTH: usize = 34;
+const FLAME_MAX_DEPTH: usize = 16;
+
+/// Frame phases shown on the timeline, in execution order.
+const PHASES: [(&str, AnsiColors); 5] = [
+    ("build", AnsiColors::Green),
+    ("measure", AnsiColors::Cyan),
+    ("record", AnsiColors::Blue),
+    ("render", AnsiColors::Magenta),
+    ("submit+present", AnsiColors::Yellow),
+];
+
+const FLAME_COLORS: [AnsiColors; 4] = [
+    AnsiColors::Red,
+    AnsiColors::Yellow,
+    AnsiColors::BrightRed,
+    AnsiColors::BrightYellow,
+];
+
+pub struct LiveOptions<'a> {
+    pub addr: &'a str,
+    pub history: usize,
+    pub record: Option<&'a Path>,
+}
+
+pub fn live(options: LiveOptions<'_>) -> Result<()> {
+    let listener = TcpListener::bind(options.addr)
+        .with_context(|| format!("failed to listen on {}", options.addr))?;
+    let addr = listener.local_addr()?;
+    let mut recorder = options
+        .record
+        .map(|path| {
+            File::create(path)
+                .map(BufWriter::new)
+                .with_context(|| format!("failed to create {}", path.display()))
+        })
+        .transpose()?;
+
+    output::status("Listening", format!("for profiler streams on {addr}"));
+    output::note(format!(
+        "run the app with `cargo tessera dev --profiling-stream {addr}` or build it with the \
+         `tessera-ui/profiling` feature and set TESSERA_PROFILING_STREAM={addr}"
+    ));
+
+    let mut header_recorded = false;
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(err) => {
+                output::warn(format!("failed to accept profiler stream: {err}"));
+                continue;
+            }
+        };
+        let peer = stream
+            .peer_addr()
+            .map(|peer| peer.to_string())
+            .unwrap_or_else(|_| String::from("unknown"));
+        output::status("Connected", format!("profiler stream from {peer}"));
+
+        let mut state = LiveState::new(options.history);
+        if let Err(err) = read_stream(stream, &mut state, &mut recorder, &mut header_recorded) {
+            output::warn(format!("profiler stream error: {err:#}"));
+        }
+        state.draw();
+        output::status(
+            "Disconnected",
+            format!("{} frames received; waiting for the next run", state.frames),
+        );
+    }
+    Ok(())
+}
+
+fn read_stream(
+    stream: TcpStream,
+    state: &mut LiveState,
+    recorder: &mut Option<BufWriter<File>>,
+    header_recorded: &mut bool,
+) -> Result<()> {
+    let mut lines = BufReader::new(stream).lines();
+    let mut last_draw: Option<Instant> = None;
+
+    let header_line = loop {
+        let Some(line) = lines.next() else {
+            return Ok(());
+        };
+        let line = line?;
+        if !line.trim().is_empty() {
+            break line;
+        }
+    };
+    let header: TraceFileHeader =
+        parse_json_line(header_line.trim()).context("invalid profiler header")?;
+    check_header(&header)?;
+    if let Some(recorder) = recorder.as_mut()
+        && !*header_recorded
+    {
+        writeln!(recorder, "{}", header_line.trim())?;
+        *header_recorded = true;
+    }
+
+    for line in lines {
+        let line = line?;
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        if let Some(recorder) = recorder.as_mut() {
+            writeln!(recorder, "{trimmed}")?;
+        }
+        match parse_json_line::<TraceEvent>(trimmed) {
+            Ok(TraceEvent::Frame(frame)) => state.push_frame(*frame),
+            Ok(TraceEvent::Wake(_)) => state.wakes += 1,
+            Ok(TraceEvent::Runtime(_)) => {}
+            Err(err) => output::warn(format!("invalid profiler event: {err}")),
+        }
+
+        if last_draw.is_none_or(|last| last.elapsed() >= REFRESH_INTERVAL) {
+            state.draw();
+            last_draw = Some(Instant::now());
+        }
+    }
+    if let Some(recorder) = recorder.as_mut() {
+        recorder.flush()?;
+    }
+    Ok(())
+}
+
+struct TimelineFrame {
+    frame: u64,
+    build_mode: &'static str,
+    /// Durations in [`PHASES`] order.
+    phases_ns: [u64; 5],
+    total_ns: u64,
+}
+
+impl TimelineFrame {
+    fn from_record(record: &FrameRecord) -> Self {
+        let build_ns = record.build_tree_time_ns.unwrap_or(0);
+        let draw_ns = record.draw_time_ns.unwrap_or(0);
+        let record_ns = record.record_time_ns.unwrap_or(0).min(draw_ns);
+        let render_ns = record.render_time_ns.unwrap_or(0);
+        // Wall time of queue submission and presentation, not GPU execution.
+        let submit_present_ns = (record.render_submit_ns.unwrap_or(0)
+            + record.render_present_ns.unwrap_or(0))
+        .min(render_ns);
+        let phases_ns = [
+            build_ns,
+            draw_ns - record_ns,
+            record_ns,
+            render_ns - submit_present_ns,
+            submit_present_ns,
+        ];
+        Self {
+            frame: record.frame,
+            build_mode: build_mode_label(&record.build_mode),
+            phases_ns,
+            total_ns: record
+                .frame_total_ns
+                .unwrap_or_else(|| phases_ns.iter().sum()),
+        }
+    }
+}
+
+/// One bar of the flame graph.
+struct FlameSpan {
+    name: String,
+    depth: usize,
+    start_col: usize,
+    width: usize,
+    build_ns: u64,
+}
+
+struct LiveState {
+    history: usize,
+    timeline: VecDeque<TimelineFrame>,
+    frames: u64,
+    wakes: u64,
+    first_start_ns: Option<u64>,
+    last_start_ns: Option<u64>,
+    /// Most recent frame that built components, used for the flame graph.
+    flame_frame: Option<(u64, Vec<ComponentRecord>)>,
+}
+
+impl LiveState {
+    fn new(history: usize) -> Self {
+        Self {
+            history: history.max(1),
+            timeline: VecDeque::new(),
+            frames: 0,
+            wakes: 0,
+            first_start_ns: None,
+            last_start_ns: None,
+            flame_frame: None,
+        }
+    }
+
+    fn push_frame(&mut self, record: FrameRecord) {
+        self.frames += 1;
+        if let Some(start_ns) = record.start_ns {
+            self.first_start_ns.get_or_insert(start_ns);
+            self.last_start_ns = Some(start_ns);
+        }
+        self.timeline.push_back(TimelineFrame::from_record(&record));
+        while self.timeline.len() > self.history {
+            self.timeline.pop_front();
+        }
+        let built = record
+            .components
+            .iter()
+            .any(|component| component.phases.build_ns.unwrap_or(0) > 0);
+        if built {
+            self.flame_frame = Some((record.frame, record.components));
+        }
+    }
+
+    fn average_fps(&self) -> Option<f64> {
+        let elapsed_ns = self.last_start_ns? - self.first_start_ns?;
+        (elapsed_ns > 0 && self.frames > 1)
+            .then(|| (self.frames - 1) as f64 / (elapsed_ns as f64 / 1_000_000_000.0))
+    }
+
+    fn draw(&self) {
+        let term = Term::stdout();
+        let width = term
+            .size_checked()
+            .map(|(_, cols)| cols as usize)
+            .unwrap_or(DEFAULT_TERM_WIDTH)
+            .max(TIMELINE_LABEL_WIDTH + 20);
+        let _ = term.clear_screen();
+
+        let fps = self
+            .average_fps()
+            .map(|fps| format!("{fps:.1} fps"))
+            .unwrap_or_else(|| String::from("- fps"));
+        println!(
+            "{}  {} frames  {} wakes  {}",
+            "Tessera live profiler".bold(),
+            self.frames,
+            self.wakes,
+            fps
+        );
+        println!();
+        self.draw_timeline(width);
+        println!();
+        self.draw_flame_graph(width);
+    }
+
+    fn draw_timeline(&self, width: usize) {
+        println!("{}", "Frame timeline".bold());
+        let mut legend = String::new();
+        for (name, color) in PHASES {
+            legend.push_str(&format!("{} {name}  ", "■".color(color)));
+        }
+        println!("{legend}");
+
+        let bar_width = width - TIMELINE_LABEL_WIDTH - 1;
+        let scale_ns = self
+            .timeline
+            .iter()
+            .map(|frame| frame.total_ns)
+            .max()
+            .unwrap_or(0)
+            .max(FRAME_BUDGET_NS);
+        for frame in &self.timeline {
+            let label = format!(
+                "#{:<7} {:<14} {:>8} ms",
+                frame.frame,
+                frame.build_mode,
+                format_ms(frame.total_ns as f64)
+            );
+            println!(
+                "{label:<TIMELINE_LABEL_WIDTH$} {}",
+                render_phase_bar(frame, scale_ns, bar_width)
+            );
+        }
+        let budget_col = (FRAME_BUDGET_NS as f64 / scale_ns as f64 * bar_width as f64) as usize;
+        println!(
+            "{:<TIMELINE_LABEL_WIDTH$} {}{} 16.7 ms",
+            "",
+            " ".repeat(budget_col.min(bar_width).saturating_sub(1)),
+            "^".dimmed()
+        );
+    }
+
+    fn draw_flame_graph(&self, width: usize) {
+        let Some((frame, components)) = &self.flame_frame else {
+            println!("{}", "Component build flame graph".bold());
+            println!("{}", "waiting for a frame that builds components".dimmed());
+            return;
+        };
+        let total_ns: u64 = components
+            .iter()
+            .map(|component| component.phases.build_ns.unwrap_or(0))
+            .sum();
+        println!(
+            "{} (frame #{frame}, {} ms)",
+            "Component build flame graph".bold(),
+            format_ms(total_ns as f64)
+        );
+
+        let mut spans = Vec::new();
+        layout_flame(components, 0, 0, width, &mut spans);
+        let depth = spans.iter().map(|span| span.depth + 1).max().unwrap_or(0);
+        for row in 0..depth {
+            let mut line = String::new();
+            let mut col = 0;
+            for span in spans.iter().filter(|span| span.depth == row) {
+                line.push_str(&" ".repeat(span.start_col.saturating_sub(col)));
+                let label = format!("{} {}", span.name, format_ms(span.build_ns as f64));
+                let text = format!(
+                    "{:<width$}",
+                    truncate(&label, span.width),
+                    width = span.width
+                );
+                let color = FLAME_COLORS[(span.start_col + row) % FLAME_COLORS.len()];
+                line.push_str(&text.black().on_color(color).to_string());
+                col = span.start_col + span.width;
+            }
+            println!("{line}");
+        }
+    }
+}
+
+fn render_phase_bar(frame: &TimelineFrame, scale_ns: u64, width: usize) -> String {
+    let mut bar = String::new();
+    let mut elapsed_ns = 0;
+    let mut col = 0;
+    for ((_, color), phase_ns) in PHASES.iter().zip(frame.phases_ns) {
+        elapsed_ns += phase_ns;
+        let end_col = ((elapsed_ns as f64 / scale_ns as f64) * width as f64).round() as usize;
+        let end_col = end_col.min(width);
+        if end_col > col {
+            bar.push_str(&"█".repeat(end_col - col).color(*color).to_string());
+            col = end_col;
+        }
+    }
+    bar
+}
+
+/// Lays out components as flame graph spans, sized by their build time
+/// relative to the parent span.
+fn layout_flame(
+    components: &[ComponentRecord],
+    depth: usize,
+    start_col: usize,
+    width: usize,
+    spans: &mut Vec<FlameSpan>,
+) {
+    if depth >= FLAME_MAX_DEPTH || width == 0 {
+        return;
+    }
+    let total_ns: u64 = components
+        .iter()
+        .map(|component| component.phases.build_ns.unwrap_or(0))
+        .sum();
+    if total_ns == 0 {
+        return;
+    }
+
+    let mut offset_ns = 0;
+    for component in components {
+        let build_ns = component.phases.build_ns.unwrap_or(0);
+        let span_start = start_col + (offset_ns as f64 / total_ns as f64 * width as f64) as usize;
+        offset_ns += build_ns;
+        let span_end = start_col + (offset_ns as f64 / total_ns as f64 * width as f64) as usize;
+        let span_width = span_end - span_start;
+        if span_width == 0 {
+            continue;
+        }
+        spans.push(FlameSpan {
+            name: component
+                .fn_name
+                .clone()
+                .unwrap_or_else(|| String::from("<unknown>")),
+            depth,
+            start_col: span_start,
+            width: span_width,
+            build_ns,
+        });
+
+        // Children build inside their parent, so they share its span.
+        let children_ns: u64 = component
+            .children
+            .iter()
+            .map(|child| child.phases.build_ns.unwrap_or(0))
+            .sum();
+        let children_width = if children_ns >= build_ns {
+            span_width
+        } else {
+            (children_ns as f64 / build_ns as f64 * span_width as f64) as usize
+        };
+        layout_flame(
+            &component.children,
+            depth + 1,
+            span_start,
+            children_width,
+            spans,
+        );
+    }
+}
@@ -3,14 +3,14 @@ use std::{
     io::{Read, Write},
     net::{TcpListener, TcpStream},
     path::{Path, PathBuf},
-    process::Command,
-    sync::mpsc::channel,
+    process::{Command, Stdio},
+    sync::{Arc, Mutex, mpsc::channel},
     thread,
     time::{Duration, Instant},
 };
 
 use anyhow::{Context, Result, anyhow, bail};
-use cargo_metadata::MetadataCommand;
+use cargo_metadata::{Message, MetadataCommand, diagnostic::DiagnosticLevel};
 use handlebars::Handlebars;
 use notify::{Event, EventKind, RecursiveMode, Watcher};
 use serde_json::json;
@@ -51,6 +51,26 @@ const WASM_TARGET: &str = "wasm32-unknown-unknown";
 const WEB_HOST_DIR: &str = "gen/web";
 const WEB_OUTPUT_DIR: &str = "gen/web/pkg-web";
 const BUILD_DEBOUNCE_WINDOW: Duration = Duration::from_millis(300);
+const LIVE_RELOAD_PATH: &str = "/__tessera/live-reload";
+const LIVE_RELOAD_SCRIPT: &str = r#"<script>
+  (() => {
+    const source = new EventSource("/__tessera/live-reload");
+    let overlay = null;
+    source.addEventListener("reload", () => location.reload());
+    source.addEventListener("build-error", (event) => {
+      if (!overlay) {
+        overlay = document.createElement("pre");
+        overlay.style.cssText =
+          "position:fixed;inset:0;margin:0;padding:24px;overflow:auto;z-index:2147483647;" +
+          "background:rgba(24,0,0,0.92);color:#ffb4ab;font:13px/1.5 ui-monospace,monospace;" +
+          "white-space:pre-wrap;user-select:text;-webkit-user-select:text";
+        document.body.appendChild(overlay);
+      }
+      overlay.textContent = JSON.parse(event.data);
+    });
+  })();
+</script>
+"#;
 
 pub fn init(package: Option<&str>) -> Result<()> {
     let project_dir = resolve_project_dir(package)?;
@@ -112,8 +132,7 @@ pub fn dev(release: bool, package: Option<&str>, port: u16) -> Result<()> {
         format!("web dev server for `{}`", metadata.package_name),
     );
     output::status("Serving", format!("http://127.0.0.1:{port}"));
-    output::status("Watching", "for file changes");
-    output::note("Refresh the browser after rebuilds.");
+    output::status("Watching", "for file changes (live reload)");
 
     build_project(&project_dir, &metadata, release)?;
 
@@ -123,7 +142,8 @@ pub fn dev(release: bool, package: Option<&str>, port: u16) -> Result<()> {
         .set_nonblocking(true)
         .context("Failed to configure non-blocking development server socket")?;
     let web_host_dir = project_dir.join(WEB_HOST_DIR);
-    spawn_static_file_server(listener, web_host_dir.clone());
+    let live_reload = LiveReloadHub::default();
+    spawn_static_file_server(listener, web_host_dir.clone(), live_reload.clone());
     open_browser(format!("http://127.0.0.1:{port}"));
 
     let (tx, rx) = channel();
@@ -187,14 +207,26 @@ pub fn dev(release: bool, package: Option<&str>, port: u16) -> Result<()> {
         if pending_change && last_change.elapsed() >= BUILD_DEBOUNCE_WINDOW {
             output::status("Building", "web app");
             match build_project(&project_dir, &metadata, release) {
-                Ok(()) => output::status(
-                    "Finished",
-                    format!(
-                        "web build in {}",
-                        project_dir.join(WEB_OUTPUT_DIR).display()
-                    ),
-                ),
-                Err(err) => output::error(format!("{err:#}")),
+                Ok(()) => {
+                    output::status(
+                        "Finished",
+                        format!(
+                            "web build in {}",
+                            project_dir.join(WEB_OUTPUT_DIR).display()
+                        ),
+                    );
+                    live_reload.notify_reload();
+                }
+                Err(err) => {
+                    output::error(format!("{err:#}"));
+                    let report = match err.downcast_ref::<WebBuildFailed>() {
+                        Some(failed) if !failed.diagnostics.is_empty() => {
+                            failed.diagnostics.clone()
+                        }
+                        _ => format!("{err:#}"),
+                    };
+                    live_reload.notify_build_error(report);
+                }
             }
             pending_change = false;
         }
@@ -212,12 +244,35 @@ fn build_project(project_dir: &Path, metadata: &ProjectMetadata, release: bool)
     if release {
         cmd.arg("--release");
     }
+    cmd.arg("--message-format=json-diagnostic-rendered-ansi")
+        .stdout(Stdio::piped());
 
-    let status = cmd
-        .status()
+    let mut child = cmd
+        .spawn()
         .context("Failed to run cargo build for web target")?;
+    let mut diagnostics = String::new();
+    if let Some(stdout) = child.stdout.take() {
+        for message in Message::parse_stream(std::io::BufReader::new(stdout)).flatten() {
+            let Message::CompilerMessage(message) = message else {
+                continue;
+            };
+            let Some(rendered) = message.message.rendered.as_deref() else {
+                continue;
+            };
+            output::diagnostic(rendered);
+            if matches!(
+                message.message.level,
+                DiagnosticLevel::Error | DiagnosticLevel::Ice
+            ) {
+                diagnostics.push_str(&strip_ansi_escapes(rendered));
+            }
+        }
+    }
+    let status = child
+        .wait()
+        .context("Failed to wait for cargo build for web target")?;
     if !status.success() {
-        bail!("Web build failed");
+        return Err(WebBuildFailed { diagnostics }.into());
     }
 
     let output_dir = project_dir.join(WEB_OUTPUT_DIR);
@@ -258,6 +313,104 @@ fn build_project(project_dir: &Path, metadata: &ProjectMetadata, release: bool)
     Ok(())
 }
 
+/// Compilation failure of the web target, carrying rendered compiler errors.
+#[derive(Debug)]
+struct WebBuildFailed {
+    diagnostics: String,
+}
+
+impl std::fmt::Display for WebBuildFailed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Web build failed")
+    }
+}
+
+impl std::error::Error for WebBuildFailed {}
+
+fn strip_ansi_escapes(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\u{1b}' {
+            stripped.push(ch);
+            continue;
+        }
+        if chars.next() == Some('[') {
+            for ch in chars.by_ref() {
+                if ch.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        }
+    }
+    stripped
+}
+
+/// Server-sent event channel that tells open pages to reload or show build
+/// errors.
+#[derive(Clone, Default)]
+struct LiveReloadHub {
+    clients: Arc<Mutex<Vec<TcpStream>>>,
+    last_error: Arc<Mutex<Option<String>>>,
+}
+
+impl LiveReloadHub {
+    fn subscribe(&self, mut stream: TcpStream) -> Result<()> {
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nConnection: keep-alive\r\n\r\n"
+        )
+        .context("Failed to write live reload headers")?;
+        let last_error = self
+            .last_error
+            .lock()
+            .map_err(|_| anyhow!("live reload state is poisoned"))?
+            .clone();
+        if let Some(report) = last_error {
+            write_event(&mut stream, "build-error", &report)?;
+        }
+        self.clients
+            .lock()
+            .map_err(|_| anyhow!("live reload clients are poisoned"))?
+            .push(stream);
+        Ok(())
+    }
+
+    fn notify_reload(&self) {
+        if let Ok(mut last_error) = self.last_error.lock() {
+            *last_error = None;
+        }
+        self.broadcast("reload", "");
+    }
+
+    fn notify_build_error(&self, report: String) {
+        self.broadcast("build-error", &report);
+        if let Ok(mut last_error) = self.last_error.lock() {
+            *last_error = Some(report);
+        }
+    }
+
+    fn broadcast(&self, event: &str, data: &str) {
+        if let Ok(mut clients) = self.clients.lock() {
+            clients.retain_mut(|stream| write_event(stream, event, data).is_ok());
+        }
+    }
+}
+
+fn write_event(stream: &mut TcpStream, event: &str, data: &str) -> Result<()> {
+    let data = serde_json::to_string(data)?;
+    write!(stream, "event: {event}\ndata: {data}\n\n").context("Failed to write live reload event")
+}
+
+fn inject_live_reload_script(html: &[u8]) -> Vec<u8> {
+    let html = String::from_utf8_lossy(html);
+    let injected = match html.rfind("</body>") {
+        Some(pos) => format!("{}{LIVE_RELOAD_SCRIPT}{}", &html[..pos], &html[pos..]),
+        None => format!("{html}{LIVE_RELOAD_SCRIPT}"),
+    };
+    injected.into_bytes()
+}
+
 struct ProjectMetadata {
     manifest_path: PathBuf,
     package_name: String,
@@ -551,12 +704,16 @@ fn open_browser(url: String) {
     }
 }
 
-fn spawn_static_file_server(listener: TcpListener, project_dir: PathBuf) {
+fn spawn_static_file_server(
+    listener: TcpListener,
+    project_dir: PathBuf,
+    live_reload: LiveReloadHub,
+) {
     thread::spawn(move || {
         loop {
             match listener.accept() {
                 Ok((stream, _)) => {
-                    if let Err(err) = handle_http_request(stream, &project_dir) {
+                    if let Err(err) = handle_http_request(stream, &project_dir, &live_reload) {
                         output::warn(format!("web server request failed: {err:#}"));
                     }
                 }
@@ -572,7 +729,15 @@ fn spawn_static_file_server(listener: TcpListener, project_dir: PathBuf) {
     });
 }
 
-fn handle_http_request(mut stream: TcpStream, project_dir: &Path) -> Result<()> {
+fn handle_http_request(
+    mut stream: TcpStream,
+    project_dir: &Path,
+    live_reload: &LiveReloadHub,
+) -> Result<()> {
+    // Accepted sockets may inherit the listener's non-blocking mode.
+    stream
+        .set_nonblocking(false)
+        .context("Failed to configure HTTP connection")?;
     let mut buffer = [0_u8; 4096];
     let bytes_read = stream
         .read(&mut buffer)
@@ -599,6 +764,9 @@ fn handle_http_request(mut stream: TcpStream, project_dir: &Path) -> Result<()>
     }
 
     let requested_path = target.split(['?', '#']).next().unwrap_or("/");
+    if method == "GET" && requested_path == LIVE_RELOAD_PATH {
+        return live_reload.subscribe(stream);
+    }
     let Some(file_path) = resolve_served_file(project_dir, requested_path) else {
         return write_response(
             &mut stream,
@@ -608,9 +776,12 @@ fn handle_http_request(mut stream: TcpStream, project_dir: &Path) -> Result<()>
         );
     };
 
-    let body =
+    let mut body =
         fs::read(&file_path).with_context(|| format!("Failed to read {}", file_path.display()))?;
     let content_type = content_type_for_path(&file_path);
+    if content_type.starts_with("text/html") {
+        body = inject_live_reload_script(&body);
+    }
 
     if method == "HEAD" {
         write_headers(&mut stream, "200 OK", content_type, body.len())
@@ -6,6 +6,7 @@ use tessera_build::AssetBackend;
 
 use commands::{
     android::{self, AndroidFormat},
+    package::LinuxPackageFormat,
     plugin, web,
 };
 
@@ -60,12 +61,24 @@ enum TesseraCommands {
         /// (desktop only)
         #[arg(long, value_name = "FILE")]
         profiling_output: Option<PathBuf>,
+        /// Enable profiling and stream records to a `cargo tessera profile`
+        /// viewer at this address (desktop only)
+        #[arg(long, value_name = "ADDR")]
+        profiling_stream: Option<String>,
         /// Overlay dirty replay regions with a translucent debug color
         #[arg(long)]
         debug_dirty_overlay: bool,
         /// Override asset backend
         #[arg(long, value_enum)]
         asset_backend: Option<AssetBackendArg>,
+        /// Reload the app library in place instead of restarting the app
+        /// (desktop only, requires a `hot-reload` feature)
+        #[arg(long)]
+        hot_reload: bool,
+        /// Stream the live component tree to this terminal; press F12 in the
+        /// app to pick nodes under the cursor
+        #[arg(long)]
+        inspector: bool,
     },
     /// Build the project for release (native targets)
     Build {
@@ -89,6 +102,27 @@ enum TesseraCommands {
         #[arg(long, value_enum)]
         asset_backend: Option<AssetBackendArg>,
     },
+    /// Package a Linux desktop app as AppImage, .deb and Flatpak manifest
+    Package {
+        /// Specify package to bundle
+        #[arg(short, long)]
+        package: Option<String>,
+        /// Linux target triple (passed to cargo build)
+        #[arg(short, long)]
+        target: Option<String>,
+        /// Package formats to produce (default: all)
+        #[arg(short, long, value_enum)]
+        format: Vec<LinuxPackageFormat>,
+        /// Override asset backend (default: platform)
+        #[arg(long, value_enum)]
+        asset_backend: Option<AssetBackendArg>,
+        /// Output directory (default: target/tessera-package/linux)
+        #[arg(long, value_name = "DIR")]
+        out_dir: Option<PathBuf>,
+    },
+    /// Show a live frame timeline and component flame graph from a running
+    /// app
+    Profile(LiveProfileArgs),
     /// Profiling utilities
     Profiling {
         #[command(subcommand)]
@@ -144,7 +178,7 @@ enum WebCommands {
         #[arg(long, short)]
         package: Option<String>,
     },
-    /// Rebuild and serve a Tessera app for browser development
+    /// Rebuild, serve, and live-reload a Tessera app for browser development
     Dev {
         /// Build in release mode
         #[arg(long, short)]
@@ -224,6 +258,21 @@ enum ProfilingCommands {
         #[arg(long, value_name = "FILE")]
         csv: Option<PathBuf>,
     },
+    /// Show a live frame timeline and component flame graph from a running
+    /// app (same as `cargo tessera profile`)
+    Live(LiveProfileArgs),
+    /// Convert profiler JSONL output to Chrome trace event format (Perfetto,
+    /// chrome://tracing)
+    ChromeTrace {
+        /// Path to tessera profiler JSONL output file
+        path: PathBuf,
+        /// Output path for the trace JSON
+        #[arg(short, long, value_name = "FILE", default_value = "tessera-trace.json")]
+        output: PathBuf,
+        /// Skip non-frame JSON lines that fail parsing
+        #[arg(long)]
+        skip_invalid: bool,
+    },
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
@@ -241,6 +290,29 @@ impl AssetBackendArg {
     }
 }
 
+#[derive(Args)]
+struct LiveProfileArgs {
+    /// Address to listen on for profiler streams
+    #[arg(long, default_value = "127.0.0.1:7878")]
+    addr: String,
+    /// Number of recent frames shown on the timeline
+    #[arg(long, default_value_t = 20)]
+    history: usize,
+    /// Also save the received records to this JSONL file
+    #[arg(long, value_name = "FILE")]
+    record: Option<PathBuf>,
+}
+
+impl LiveProfileArgs {
+    fn run(&self) -> Result<()> {
+        commands::profiling::live(commands::profiling::LiveOptions {
+            addr: &self.addr,
+            history: self.history,
+            record: self.record.as_deref(),
+        })
+    }
+}
+
 #[derive(Args)]
 struct AndroidBuildArgs {
     /// Build in release mode
@@ -354,17 +426,23 @@ fn run() -> Result<()> {
                 package,
                 release,
                 profiling_output,
+                profiling_stream,
                 debug_dirty_overlay,
                 asset_backend,
+                hot_reload,
+                inspector,
             } => {
-                commands::dev::execute(
+                commands::dev::execute(commands::dev::DevOptions {
                     verbose,
-                    package.as_deref(),
+                    package: package.as_deref(),
                     release,
-                    profiling_output.as_deref(),
+                    profiling_output: profiling_output.as_deref(),
+                    profiling_stream: profiling_stream.as_deref(),
                     debug_dirty_overlay,
-                    asset_backend.map(AssetBackendArg::to_backend),
-                )?;
+                    asset_backend_override: asset_backend.map(AssetBackendArg::to_backend),
+                    hot_reload,
+                    inspector,
+                })?;
             }
             TesseraCommands::Build {
                 release,
@@ -383,6 +461,22 @@ fn run() -> Result<()> {
                     asset_backend.map(AssetBackendArg::to_backend),
                 )?;
             }
+            TesseraCommands::Package {
+                package,
+                target,
+                format,
+                asset_backend,
+                out_dir,
+            } => {
+                commands::package::execute(commands::package::PackageOptions {
+                    package: package.as_deref(),
+                    target: target.as_deref(),
+                    formats: &format,
+                    asset_backend: asset_backend.map(AssetBackendArg::to_backend),
+                    out_dir: out_dir.as_deref(),
+                })?;
+            }
+            TesseraCommands::Profile(args) => args.run()?,
             TesseraCommands::Profiling { command } => match command {
                 ProfilingCommands::Analyze {
                     path,
@@ -434,6 +528,14 @@ fn run() -> Result<()> {
                         },
                     )?;
                 }
+                ProfilingCommands::Live(args) => args.run()?,
+                ProfilingCommands::ChromeTrace {
+                    path,
+                    output,
+                    skip_invalid,
+                } => {
+                    commands::profiling::export_chrome_trace(&path, &output, skip_invalid)?;
+                }
             },
             TesseraCommands::Android { command } => match command {
                 AndroidCommands::Init {
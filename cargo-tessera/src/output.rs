@@ -30,6 +30,10 @@ pub fn step(message: impl AsRef<str>) {
     eprintln!("    {}", message.as_ref());
 }
 
+pub fn diagnostic(rendered: &str) {
+    eprint!("{rendered}");
+}
+
 pub fn format_duration(duration: Duration) -> String {
     HumanDuration(duration).to_string()
 }
@@ -10,6 +10,8 @@ use tessera_ui::{
 use app::app;
 use pages::custom_shader::CustomShaderPackage;
 
+#[cfg(feature = "hot-reload")]
+use tessera_dylib as _;
 #[cfg(target_family = "wasm")]
 use tessera_ui::renderer::WebConfig;
 #[cfg(target_family = "wasm")]
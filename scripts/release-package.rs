@@ -67,6 +67,7 @@ const PUBLISHABLE_PACKAGES: &[&str] = &[
     "tessera-mobile",
     "tessera-platform",
     "tessera-build",
+    "tessera-vector-mesh",
 ];
 
 fn release_package(
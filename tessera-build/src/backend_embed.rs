@@ -6,20 +6,35 @@ pub(super) fn generate_embed_backend_tokens() -> TokenStream {
         #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
         pub struct Asset {
             index: usize,
-            bytes: &'static [u8],
+            qualifiers: &'static [tessera_ui::asset::AssetQualifiers],
+            bytes: &'static [&'static [u8]],
         }
 
         impl Asset {
-            const fn new_embed(index: usize, bytes: &'static [u8]) -> Self {
-                Self { index, bytes }
+            const fn new_embed(
+                index: usize,
+                qualifiers: &'static [tessera_ui::asset::AssetQualifiers],
+                bytes: &'static [&'static [u8]],
+            ) -> Self {
+                Self {
+                    index,
+                    qualifiers,
+                    bytes,
+                }
             }
         }
 
         impl tessera_ui::AssetExt for Asset {
             fn read(self) -> io::Result<Arc<[u8]>> {
-                tessera_ui::asset::read_with_lru_cache::<Asset, _>(self.index as u64, || {
-                    Ok(Arc::<[u8]>::from(self.bytes))
-                })
+                let variant = tessera_ui::AssetExt::variant_index(self);
+                tessera_ui::asset::read_with_lru_cache::<Asset, _>(
+                    (self.index + variant) as u64,
+                    || Ok(Arc::<[u8]>::from(self.bytes[variant])),
+                )
+            }
+
+            fn variant_index(self) -> usize {
+                tessera_ui::asset::select_asset_variant(self.qualifiers)
             }
         }
     }
@@ -2,30 +2,44 @@ use proc_macro2::TokenStream;
 use quote::quote;
 
 use android::generate_android_asset_read_tokens;
+use linux::generate_linux_asset_read_tokens;
 
 mod android;
+mod linux;
 
 pub(super) fn generate_platform_backend_tokens() -> TokenStream {
     let android_tokens = generate_android_asset_read_tokens();
+    let linux_tokens = generate_linux_asset_read_tokens();
 
     quote! {
         #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
         pub struct Asset {
             index: usize,
-            path: &'static str,
+            qualifiers: &'static [tessera_ui::asset::AssetQualifiers],
+            paths: &'static [&'static str],
         }
 
         impl Asset {
-            const fn new_platform(index: usize, path: &'static str) -> Self {
-                Self { index, path }
+            const fn new_platform(
+                index: usize,
+                qualifiers: &'static [tessera_ui::asset::AssetQualifiers],
+                paths: &'static [&'static str],
+            ) -> Self {
+                Self {
+                    index,
+                    qualifiers,
+                    paths,
+                }
             }
         }
 
         #android_tokens
 
-        #[cfg(not(target_os = "android"))]
+        #linux_tokens
+
+        #[cfg(not(any(target_os = "android", target_os = "linux")))]
         compile_error!(
-            "TESSERA_ASSET_BACKEND=platform is currently only implemented for Android. \
+            "TESSERA_ASSET_BACKEND=platform is currently only implemented for Android and Linux. \
              Use TESSERA_ASSET_BACKEND=embed on this target."
         );
 
@@ -34,16 +48,27 @@ pub(super) fn generate_platform_backend_tokens() -> TokenStream {
             __tessera_read_platform_asset_android(path)
         }
 
-        #[cfg(not(target_os = "android"))]
+        #[cfg(target_os = "linux")]
+        fn __tessera_read_platform_asset(path: &str) -> io::Result<Arc<[u8]>> {
+            __tessera_read_platform_asset_linux(path)
+        }
+
+        #[cfg(not(any(target_os = "android", target_os = "linux")))]
         fn __tessera_read_platform_asset(_path: &str) -> io::Result<Arc<[u8]>> {
             panic!("Unsupported target for TESSERA_ASSET_BACKEND=platform")
         }
 
         impl tessera_ui::AssetExt for Asset {
             fn read(self) -> io::Result<Arc<[u8]>> {
-                tessera_ui::asset::read_with_lru_cache::<Asset, _>(self.index as u64, || {
-                    __tessera_read_platform_asset(self.path)
-                })
+                let variant = tessera_ui::AssetExt::variant_index(self);
+                tessera_ui::asset::read_with_lru_cache::<Asset, _>(
+                    (self.index + variant) as u64,
+                    || __tessera_read_platform_asset(self.paths[variant]),
+                )
+            }
+
+            fn variant_index(self) -> usize {
+                tessera_ui::asset::select_asset_variant(self.qualifiers)
             }
         }
     }
@@ -0,0 +1,64 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub(super) fn generate_linux_asset_read_tokens() -> TokenStream {
+    quote! {
+        #[cfg(target_os = "linux")]
+        fn __tessera_linux_asset_roots() -> &'static [std::path::PathBuf] {
+            use std::{env, path::PathBuf, sync::OnceLock};
+
+            static ROOTS: OnceLock<Vec<PathBuf>> = OnceLock::new();
+
+            ROOTS.get_or_init(|| {
+                let mut roots = Vec::new();
+                let Ok(exe) = env::current_exe() else {
+                    return roots;
+                };
+                let Some(app_name) = exe.file_stem().map(|name| name.to_os_string()) else {
+                    return roots;
+                };
+
+                // Install-relative `<prefix>/bin/<app>` -> `<prefix>/share/<app>`,
+                // which also covers AppImage and Flatpak layouts.
+                if let Some(prefix) = exe.parent().and_then(|bin_dir| bin_dir.parent()) {
+                    roots.push(prefix.join("share").join(&app_name));
+                }
+
+                let data_home = env::var_os("XDG_DATA_HOME")
+                    .filter(|value| !value.is_empty())
+                    .map(PathBuf::from)
+                    .or_else(|| {
+                        env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share"))
+                    });
+                let data_dirs = env::var_os("XDG_DATA_DIRS")
+                    .filter(|value| !value.is_empty())
+                    .unwrap_or_else(|| "/usr/local/share:/usr/share".into());
+                for dir in data_home.into_iter().chain(env::split_paths(&data_dirs)) {
+                    if dir.is_absolute() {
+                        let root = dir.join(&app_name);
+                        if !roots.contains(&root) {
+                            roots.push(root);
+                        }
+                    }
+                }
+                roots
+            })
+        }
+
+        #[cfg(target_os = "linux")]
+        fn __tessera_read_platform_asset_linux(path: &str) -> io::Result<Arc<[u8]>> {
+            for root in __tessera_linux_asset_roots() {
+                match std::fs::read(root.join(path)) {
+                    Ok(bytes) => return Ok(Arc::from(bytes)),
+                    Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+                    Err(err) => return Err(err),
+                }
+            }
+
+            Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("Asset not found: {path}"),
+            ))
+        }
+    }
+}
@@ -4,6 +4,32 @@
 //!
 //! Load `tessera-config.toml` and generate compiled asset bindings in build
 //! scripts.
+//!
+//! Assets may declare resolution and theme variants through file name
+//! qualifiers and opt into build-time image processing:
+//!
+//! ```toml
+//! [assets.variants]
+//! resolution = { "@2x" = 2.0, "@3x" = 3.0 }
+//! theme = { "-dark" = "dark" }
+//!
+//! [assets.processing]
+//! max_size = 2048
+//! recompress_png = "webp"
+//! svg = true
+//! ```
+//!
+//! With this config, `logo.png`, `logo@2x.png` and `logo@2x-dark.png` become
+//! variants of one `LOGO_PNG` constant.
+//!
+//! Localized strings live in `<locale>.ftl` files and compile into functions
+//! of a generated `strings` module:
+//!
+//! ```toml
+//! [strings]
+//! dir = "strings"
+//! default_locale = "en"
+//! ```
 
 #![deny(
     missing_docs,
@@ -26,9 +52,14 @@ use serde::Deserialize;
 
 use backend_embed::generate_embed_backend_tokens;
 use backend_platform::generate_platform_backend_tokens;
+use strings::{StringResources, generate_strings_tokens, load_string_resources};
+
+pub use processing::{ProcessedAsset, VECTOR_MESH_MAGIC, process_asset};
 
 mod backend_embed;
 mod backend_platform;
+mod processing;
+mod strings;
 
 /// Environment variable selecting the asset backend (`embed` or `platform`).
 pub const ASSET_BACKEND_ENV: &str = "TESSERA_ASSET_BACKEND";
@@ -43,7 +74,8 @@ pub enum AssetBackend {
     /// Assets are directly embedded within the binary executable.
     Embed,
     /// Assets are loaded using the platform's native asset management system
-    /// (e.g. Android assets).
+    /// (Android assets, or `share/<app>` install and XDG data directories on
+    /// Linux).
     Platform,
 }
 
@@ -91,6 +123,8 @@ pub struct TesseraConfig {
     pub assets: Option<AssetsConfig>,
     /// Plugin wrapper generation settings.
     pub plugin: Option<PluginConfig>,
+    /// Localized string resource settings.
+    pub strings: Option<StringsConfig>,
 }
 
 /// Asset directory and filtering configuration.
@@ -100,6 +134,10 @@ pub struct AssetsConfig {
     pub dir: Option<String>,
     /// Tree-shaking rules for excluding matched assets.
     pub tree_shaking: Option<AssetsTreeShakingConfig>,
+    /// Resolution and theme qualifiers grouping files into variants.
+    pub variants: Option<AssetVariantsConfig>,
+    /// Build-time processing applied to image assets.
+    pub processing: Option<AssetProcessingConfig>,
 }
 
 impl AssetsConfig {
@@ -119,6 +157,55 @@ pub struct AssetsTreeShakingConfig {
     pub exclude: Vec<String>,
 }
 
+/// File name qualifiers that group assets into variants of one constant.
+///
+/// Qualifiers are suffixes of the file stem, so `icon@2x-dark.svg` is the
+/// `@2x`, `-dark` variant of `icon.svg`.
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct AssetVariantsConfig {
+    /// Suffixes mapped to the scale factor they target, e.g. `"@2x" = 2.0`
+    /// or `"-xxhdpi" = 3.0`. Unqualified files target `1.0`.
+    #[serde(default)]
+    pub resolution: BTreeMap<String, f32>,
+    /// Suffixes mapped to the theme they target, e.g. `"-dark" = "dark"`.
+    #[serde(default)]
+    pub theme: BTreeMap<String, String>,
+}
+
+/// Build-time processing applied to image assets.
+///
+/// Processed assets keep their path; decoders detect the content format.
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct AssetProcessingConfig {
+    /// Downscale PNG and JPEG images so neither side exceeds this many pixels.
+    pub max_size: Option<u32>,
+    /// Re-encode PNG images into a format that is cheaper to decode.
+    pub recompress_png: Option<RasterFormat>,
+    /// Pre-tessellate SVG images into vector mesh data.
+    #[serde(default)]
+    pub svg: bool,
+}
+
+/// Raster format used when re-encoding PNG assets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RasterFormat {
+    /// Lossless WebP.
+    Webp,
+    /// Quite OK Image format.
+    Qoi,
+}
+
+/// Localized string resource configuration.
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct StringsConfig {
+    /// Directory of `<locale>.ftl` files relative to the package root.
+    /// Defaults to `strings`.
+    pub dir: Option<String>,
+    /// Locale used when no translation matches. Defaults to `en`.
+    pub default_locale: Option<String>,
+}
+
 /// Plugin-specific configuration options.
 #[derive(Debug, Clone, Deserialize, Default)]
 pub struct PluginConfig {
@@ -140,8 +227,17 @@ struct AssetEntry {
     absolute_path: PathBuf,
     relative_path: String,
     platform_path: String,
+    scale: f32,
+    theme: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct AssetGroup {
+    base_relative_path: String,
     module_segments: Vec<(String, String)>,
     const_name: String,
+    first_index: usize,
+    variants: Vec<AssetEntry>,
 }
 
 #[derive(Debug, Default)]
@@ -153,9 +249,9 @@ struct ModuleNode {
 }
 
 impl ModuleNode {
-    fn insert(&mut self, entry: &AssetEntry, index: usize) -> Result<()> {
+    fn insert(&mut self, group: &AssetGroup, index: usize) -> Result<()> {
         let mut node = self;
-        for (original, module) in &entry.module_segments {
+        for (original, module) in &group.module_segments {
             if let Some(existing) = node.module_origins.get(module) {
                 if existing != original {
                     bail!(
@@ -171,21 +267,21 @@ impl ModuleNode {
             node = node.modules.entry(module.clone()).or_default();
         }
 
-        let original_file = entry.relative_path.clone();
-        if let Some(existing) = node.asset_origins.get(&entry.const_name) {
+        let original_file = group.base_relative_path.clone();
+        if let Some(existing) = node.asset_origins.get(&group.const_name) {
             if existing != &original_file {
                 bail!(
                     "Asset constant collision: `{}` and `{}` both map to `{}`",
                     existing,
                     original_file,
-                    entry.const_name
+                    group.const_name
                 );
             }
         } else {
             node.asset_origins
-                .insert(entry.const_name.clone(), original_file);
+                .insert(group.const_name.clone(), original_file);
         }
-        node.assets.insert(entry.const_name.clone(), index);
+        node.assets.insert(group.const_name.clone(), index);
         Ok(())
     }
 }
@@ -219,9 +315,19 @@ pub fn resolve_assets_dir(manifest_dir: &Path, config: Option<&TesseraConfig>) -
     Some(manifest_dir.join(dir))
 }
 
+/// Resolves the configured string resource directory under `manifest_dir`.
+///
+/// Returns `None` when `config` does not contain a `strings` section.
+pub fn resolve_strings_dir(manifest_dir: &Path, config: Option<&TesseraConfig>) -> Option<PathBuf> {
+    let strings = config.and_then(|cfg| cfg.strings.as_ref())?;
+    let dir = strings.dir.as_deref().unwrap_or("strings");
+    Some(manifest_dir.join(dir))
+}
+
 /// Generates the asset bindings source file during build.
 ///
-/// The generated file is written to `OUT_DIR/tessera_assets.rs`.
+/// The generated file is written to `OUT_DIR/tessera_assets.rs` and also
+/// holds the `strings` module when string resources are configured.
 pub fn generate_assets() -> Result<()> {
     let manifest_dir = PathBuf::from(
         env::var("CARGO_MANIFEST_DIR").context("Missing CARGO_MANIFEST_DIR for build script")?,
@@ -240,7 +346,9 @@ pub fn generate_assets() -> Result<()> {
     let config = load_tessera_config_from_dir(&manifest_dir)?;
     let assets_dir = resolve_assets_dir(&manifest_dir, config.as_ref());
 
-    let mut entries = Vec::new();
+    let assets_config = config.as_ref().and_then(|cfg| cfg.assets.as_ref());
+
+    let mut groups = Vec::new();
     if let Some(dir) = assets_dir.as_ref() {
         println!("cargo:rerun-if-changed={}", dir.display());
         if !dir.is_dir() {
@@ -249,16 +357,58 @@ pub fn generate_assets() -> Result<()> {
                 dir.to_string_lossy()
             );
         }
-        entries = collect_assets(dir, &asset_namespace(&package_name, &package_version))?;
+        let variants = assets_config.and_then(|assets| assets.variants.as_ref());
+        groups = collect_assets(
+            dir,
+            &asset_namespace(&package_name, &package_version),
+            variants,
+        )?;
+    }
+
+    // Platform assets are processed when they are installed.
+    if backend == AssetBackend::Embed
+        && let Some(processing) = assets_config.and_then(|assets| assets.processing.as_ref())
+    {
+        process_embedded_assets(&mut groups, processing, &out_dir.join("tessera_assets"))?;
+    }
+
+    let mut strings = None;
+    if let Some(dir) = resolve_strings_dir(&manifest_dir, config.as_ref()) {
+        println!("cargo:rerun-if-changed={}", dir.display());
+        if !dir.is_dir() {
+            bail!(
+                "Configured strings directory not found: {}",
+                dir.to_string_lossy()
+            );
+        }
+        let default_locale = config
+            .as_ref()
+            .and_then(|cfg| cfg.strings.as_ref())
+            .and_then(|strings| strings.default_locale.as_deref())
+            .unwrap_or("en");
+        strings = Some(load_string_resources(&dir, default_locale)?);
     }
 
-    let generated = generate_asset_file(&entries, backend)?;
+    let generated = generate_asset_file(&groups, backend, strings.as_ref())?;
     fs::write(out_dir.join(GENERATED_ASSET_FILE), generated)
         .with_context(|| "Failed to write generated asset file")?;
     Ok(())
 }
 
-fn collect_assets(assets_dir: &Path, namespace: &str) -> Result<Vec<AssetEntry>> {
+fn collect_assets(
+    assets_dir: &Path,
+    namespace: &str,
+    variants: Option<&AssetVariantsConfig>,
+) -> Result<Vec<AssetGroup>> {
+    if let Some(variants) = variants
+        && let Some((suffix, scale)) = variants
+            .resolution
+            .iter()
+            .find(|(_, scale)| !scale.is_finite() || **scale <= 0.0)
+    {
+        bail!("Invalid scale {scale} for resolution qualifier `{suffix}`");
+    }
+
     let mut file_paths = Vec::new();
     let mut stack = vec![assets_dir.to_path_buf()];
     while let Some(dir) = stack.pop() {
@@ -290,7 +440,7 @@ fn collect_assets(assets_dir: &Path, namespace: &str) -> Result<Vec<AssetEntry>>
         ))
     });
 
-    let mut entries = Vec::with_capacity(file_paths.len());
+    let mut groups = BTreeMap::<String, AssetGroup>::new();
     for absolute_path in file_paths {
         let relative = absolute_path
             .strip_prefix(assets_dir)
@@ -301,40 +451,172 @@ fn collect_assets(assets_dir: &Path, namespace: &str) -> Result<Vec<AssetEntry>>
             .ok_or_else(|| anyhow!("Missing file name for {}", absolute_path.display()))?
             .to_string_lossy()
             .into_owned();
-        let const_name = mangle_file_name(&file_name);
+        let (base_file_name, scale, theme) = split_variant_qualifiers(&file_name, variants);
 
         let mut module_segments = Vec::new();
+        let mut base_relative_path = String::new();
         if let Some(parent) = relative.parent() {
             for segment in parent {
                 let original = segment.to_string_lossy().into_owned();
                 let module = mangle_module_name(&original);
+                base_relative_path.push_str(&original);
+                base_relative_path.push('/');
                 module_segments.push((original, module));
             }
         }
+        base_relative_path.push_str(&base_file_name);
+
+        let group = groups
+            .entry(base_relative_path.clone())
+            .or_insert_with(|| AssetGroup {
+                base_relative_path,
+                module_segments,
+                const_name: mangle_file_name(&base_file_name),
+                first_index: 0,
+                variants: Vec::new(),
+            });
+        if let Some(existing) = group
+            .variants
+            .iter()
+            .find(|variant| variant.scale == scale && variant.theme == theme)
+        {
+            bail!(
+                "Asset variant collision: `{}` and `{}` both target scale {} and theme {:?}",
+                existing.relative_path,
+                relative_path,
+                scale,
+                theme
+            );
+        }
 
         let platform_path = format!("tessera/{namespace}/{relative_path}");
-        entries.push(AssetEntry {
+        group.variants.push(AssetEntry {
             absolute_path,
             relative_path,
             platform_path,
-            module_segments,
-            const_name,
+            scale,
+            theme,
+        });
+    }
+
+    let mut next_index = 0;
+    let mut groups = groups.into_values().collect::<Vec<_>>();
+    for group in &mut groups {
+        group.variants.sort_by(|left, right| {
+            left.theme
+                .cmp(&right.theme)
+                .then(left.scale.total_cmp(&right.scale))
         });
+        group.first_index = next_index;
+        next_index += group.variants.len();
     }
-    Ok(entries)
+    Ok(groups)
 }
 
-fn generate_asset_file(entries: &[AssetEntry], backend: AssetBackend) -> Result<String> {
+/// Splits resolution and theme qualifiers off a file name.
+///
+/// Returns the unqualified file name, the targeted scale and theme.
+fn split_variant_qualifiers(
+    file_name: &str,
+    variants: Option<&AssetVariantsConfig>,
+) -> (String, f32, Option<String>) {
+    let Some(variants) = variants else {
+        return (file_name.to_string(), 1.0, None);
+    };
+    let (mut stem, extension) = match file_name.rfind('.') {
+        Some(dot) if dot > 0 => file_name.split_at(dot),
+        _ => (file_name, ""),
+    };
+
+    let mut scale = None;
+    let mut theme = None;
+    loop {
+        if theme.is_none()
+            && let Some((suffix, name)) = longest_qualifier_suffix(stem, &variants.theme)
+        {
+            stem = &stem[..stem.len() - suffix.len()];
+            theme = Some(name.clone());
+            continue;
+        }
+        if scale.is_none()
+            && let Some((suffix, value)) = longest_qualifier_suffix(stem, &variants.resolution)
+        {
+            stem = &stem[..stem.len() - suffix.len()];
+            scale = Some(*value);
+            continue;
+        }
+        break;
+    }
+    (format!("{stem}{extension}"), scale.unwrap_or(1.0), theme)
+}
+
+fn longest_qualifier_suffix<'a, V>(
+    stem: &str,
+    qualifiers: &'a BTreeMap<String, V>,
+) -> Option<(&'a str, &'a V)> {
+    qualifiers
+        .iter()
+        .filter(|(suffix, _)| {
+            !suffix.is_empty() && stem.len() > suffix.len() && stem.ends_with(suffix.as_str())
+        })
+        .max_by_key(|(suffix, _)| suffix.len())
+        .map(|(suffix, value)| (suffix.as_str(), value))
+}
+
+fn process_embedded_assets(
+    groups: &mut [AssetGroup],
+    processing: &AssetProcessingConfig,
+    processed_dir: &Path,
+) -> Result<()> {
+    for entry in groups
+        .iter_mut()
+        .flat_map(|group| group.variants.iter_mut())
+    {
+        match process_asset(&entry.absolute_path, processing)? {
+            ProcessedAsset::Unchanged => {}
+            ProcessedAsset::Processed(bytes) => {
+                let processed_path = processed_dir.join(&entry.relative_path);
+                if let Some(parent) = processed_path.parent() {
+                    fs::create_dir_all(parent)
+                        .with_context(|| format!("Failed to create {}", parent.display()))?;
+                }
+                fs::write(&processed_path, bytes)
+                    .with_context(|| format!("Failed to write {}", processed_path.display()))?;
+                entry.absolute_path = processed_path;
+            }
+            ProcessedAsset::Skipped(reason) => {
+                println!(
+                    "cargo:warning=Skipped processing asset {}: {reason}",
+                    entry.relative_path
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+fn generate_asset_file(
+    groups: &[AssetGroup],
+    backend: AssetBackend,
+    strings: Option<&StringResources>,
+) -> Result<String> {
     let mut root = ModuleNode::default();
-    for (index, entry) in entries.iter().enumerate() {
-        root.insert(entry, index)?;
+    for (index, group) in groups.iter().enumerate() {
+        root.insert(group, index)?;
     }
+    let strings_tokens = match strings {
+        Some(_) if root.modules.contains_key("strings") => {
+            bail!("Asset directory `strings` collides with the generated `strings` module");
+        }
+        Some(strings) => generate_strings_tokens(strings)?,
+        None => TokenStream::new(),
+    };
 
     let backend_tokens = match backend {
         AssetBackend::Embed => generate_embed_backend_tokens(),
         AssetBackend::Platform => generate_platform_backend_tokens(),
     };
-    let module_body_tokens = generate_module_body_tokens(&root, entries, backend)?;
+    let module_body_tokens = generate_module_body_tokens(&root, groups, backend)?;
 
     let file_tokens = quote! {
         use std::io;
@@ -343,6 +625,8 @@ fn generate_asset_file(entries: &[AssetEntry], backend: AssetBackend) -> Result<
         #backend_tokens
 
         #module_body_tokens
+
+        #strings_tokens
     };
 
     let file = syn::parse2::<syn::File>(file_tokens)
@@ -355,18 +639,17 @@ fn generate_asset_file(entries: &[AssetEntry], backend: AssetBackend) -> Result<
 
 fn generate_module_body_tokens(
     node: &ModuleNode,
-    entries: &[AssetEntry],
+    groups: &[AssetGroup],
     backend: AssetBackend,
 ) -> Result<TokenStream> {
     let mut items = Vec::new();
 
     for (const_name, index) in &node.assets {
         let ident = format_ident!("{const_name}");
-        let index = *index;
-        let entry = entries
-            .get(index)
+        let group = groups
+            .get(*index)
             .ok_or_else(|| anyhow!("Asset index out of range while generating module body"))?;
-        let value_tokens = generate_asset_value_tokens(entry, index, backend);
+        let value_tokens = generate_asset_value_tokens(group, backend);
         items.push(quote! {
             pub const #ident: Asset = #value_tokens;
         });
@@ -374,7 +657,7 @@ fn generate_module_body_tokens(
 
     for (module_name, child) in &node.modules {
         let module_ident = format_ident!("{module_name}");
-        let child_body = generate_module_body_tokens(child, entries, backend)?;
+        let child_body = generate_module_body_tokens(child, groups, backend)?;
         items.push(quote! {
             pub mod #module_ident {
                 use super::Asset;
@@ -388,23 +671,47 @@ fn generate_module_body_tokens(
     })
 }
 
-fn generate_asset_value_tokens(
-    entry: &AssetEntry,
-    index: usize,
-    backend: AssetBackend,
-) -> TokenStream {
+fn generate_asset_value_tokens(group: &AssetGroup, backend: AssetBackend) -> TokenStream {
+    let index = group.first_index;
+    // Assets without variants skip the qualifier table entirely.
+    let qualifiers = if group.variants.len() > 1 {
+        group
+            .variants
+            .iter()
+            .map(|entry| {
+                let scale = entry.scale;
+                let theme = match &entry.theme {
+                    Some(theme) => {
+                        let theme_literal = Literal::string(theme);
+                        quote! { Some(#theme_literal) }
+                    }
+                    None => quote! { None },
+                };
+                quote! { tessera_ui::asset::AssetQualifiers::new(#scale, #theme) }
+            })
+            .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
+
     match backend {
         AssetBackend::Embed => {
-            let path = entry.absolute_path.to_string_lossy().into_owned();
-            let path_literal = Literal::string(&path);
+            let bytes = group.variants.iter().map(|entry| {
+                let path = entry.absolute_path.to_string_lossy().into_owned();
+                let path_literal = Literal::string(&path);
+                quote! { include_bytes!(#path_literal) as &[u8] }
+            });
             quote! {
-                Asset::new_embed(#index, include_bytes!(#path_literal) as &[u8])
+                Asset::new_embed(#index, &[#(#qualifiers),*], &[#(#bytes),*])
             }
         }
         AssetBackend::Platform => {
-            let path_literal = Literal::string(&entry.platform_path);
+            let paths = group
+                .variants
+                .iter()
+                .map(|entry| Literal::string(&entry.platform_path));
             quote! {
-                Asset::new_platform(#index, #path_literal)
+                Asset::new_platform(#index, &[#(#qualifiers),*], &[#(#paths),*])
             }
         }
     }
@@ -538,3 +845,44 @@ fn is_keyword(value: &str) -> bool {
             | "try"
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variants() -> AssetVariantsConfig {
+        AssetVariantsConfig {
+            resolution: BTreeMap::from([("@2x".to_string(), 2.0), ("@3x".to_string(), 3.0)]),
+            theme: BTreeMap::from([("-dark".to_string(), "dark".to_string())]),
+        }
+    }
+
+    #[test]
+    fn splits_resolution_and_theme_qualifiers() {
+        let variants = variants();
+        assert_eq!(
+            split_variant_qualifiers("logo@2x-dark.png", Some(&variants)),
+            ("logo.png".to_string(), 2.0, Some("dark".to_string()))
+        );
+        assert_eq!(
+            split_variant_qualifiers("logo-dark@3x.png", Some(&variants)),
+            ("logo.png".to_string(), 3.0, Some("dark".to_string()))
+        );
+        assert_eq!(
+            split_variant_qualifiers("logo.png", Some(&variants)),
+            ("logo.png".to_string(), 1.0, None)
+        );
+    }
+
+    #[test]
+    fn keeps_names_without_configured_qualifiers() {
+        assert_eq!(
+            split_variant_qualifiers("logo@2x.png", None),
+            ("logo@2x.png".to_string(), 1.0, None)
+        );
+        assert_eq!(
+            split_variant_qualifiers("@2x.png", Some(&variants())),
+            ("@2x.png".to_string(), 1.0, None)
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use std::{fs, io::Cursor, path::Path};
+
+use anyhow::{Context, Result};
+use image::{
+    DynamicImage, GenericImageView, ImageFormat, codecs::jpeg::JpegEncoder, imageops::FilterType,
+};
+
+use tessera_vector_mesh::{encode_vector_mesh, tessellate_svg};
+
+use crate::{AssetProcessingConfig, RasterFormat};
+
+pub use tessera_vector_mesh::VECTOR_MESH_MAGIC;
+
+const JPEG_QUALITY: u8 = 90;
+
+/// Outcome of build-time processing for one asset file.
+#[derive(Debug)]
+pub enum ProcessedAsset {
+    /// The file is used as is.
+    Unchanged,
+    /// The file content is replaced by these bytes.
+    Processed(Vec<u8>),
+    /// Processing is not possible for this file, which is used as is.
+    Skipped(String),
+}
+
+/// Applies the configured processing to the asset file at `path`.
+///
+/// PNG and JPEG images are downscaled to `max_size` and PNG images are
+/// re-encoded into `recompress_png`. SVG images are tessellated into the
+/// vector mesh format starting with [`VECTOR_MESH_MAGIC`]; SVG features the
+/// tessellator does not support yield [`ProcessedAsset::Skipped`].
+pub fn process_asset(path: &Path, config: &AssetProcessingConfig) -> Result<ProcessedAsset> {
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_ascii_lowercase);
+    match extension.as_deref() {
+        Some("svg") if config.svg => {
+            let bytes =
+                fs::read(path).with_context(|| format!("Failed to read {}", path.display()))?;
+            Ok(match tessellate_svg(&bytes, path.parent()) {
+                Ok(mesh) => ProcessedAsset::Processed(encode_vector_mesh(&mesh)),
+                Err(err) => ProcessedAsset::Skipped(err.to_string()),
+            })
+        }
+        Some("png") => process_raster(path, ImageFormat::Png, config),
+        Some("jpg" | "jpeg") => process_raster(path, ImageFormat::Jpeg, config),
+        _ => Ok(ProcessedAsset::Unchanged),
+    }
+}
+
+fn process_raster(
+    path: &Path,
+    format: ImageFormat,
+    config: &AssetProcessingConfig,
+) -> Result<ProcessedAsset> {
+    let target_format = match (format, config.recompress_png) {
+        (ImageFormat::Png, Some(RasterFormat::Webp)) => ImageFormat::WebP,
+        (ImageFormat::Png, Some(RasterFormat::Qoi)) => ImageFormat::Qoi,
+        _ => format,
+    };
+    if config.max_size.is_none() && target_format == format {
+        return Ok(ProcessedAsset::Unchanged);
+    }
+
+    let image =
+        image::open(path).with_context(|| format!("Failed to decode {}", path.display()))?;
+    let (width, height) = image.dimensions();
+    let max_size = config.max_size.unwrap_or(u32::MAX).max(1);
+    let downscale = width > max_size || height > max_size;
+    if !downscale && target_format == format {
+        return Ok(ProcessedAsset::Unchanged);
+    }
+    let image = if downscale {
+        image.resize(max_size, max_size, FilterType::Lanczos3)
+    } else {
+        image
+    };
+
+    let mut bytes = Cursor::new(Vec::new());
+    let encoded = if target_format == ImageFormat::Jpeg {
+        DynamicImage::ImageRgb8(image.to_rgb8())
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut bytes, JPEG_QUALITY))
+    } else {
+        // Lossless WebP and QOI only accept 8-bit channels.
+        DynamicImage::ImageRgba8(image.to_rgba8()).write_to(&mut bytes, target_format)
+    };
+    encoded.with_context(|| format!("Failed to encode {}", path.display()))?;
+    Ok(ProcessedAsset::Processed(bytes.into_inner()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, path::PathBuf};
+
+    use image::RgbaImage;
+
+    use super::*;
+
+    /// Writes a `width` x `height` gradient image to a temporary file.
+    fn source_image(name: &str, width: u32, height: u32, format: ImageFormat) -> PathBuf {
+        let path = env::temp_dir().join(format!("tessera-build-{}-{name}", std::process::id()));
+        let image = RgbaImage::from_fn(width, height, |x, y| {
+            image::Rgba([(x * 4) as u8, (y * 4) as u8, 128, 255])
+        });
+        let image = if format == ImageFormat::Jpeg {
+            DynamicImage::ImageRgb8(DynamicImage::ImageRgba8(image).to_rgb8())
+        } else {
+            DynamicImage::ImageRgba8(image)
+        };
+        image
+            .save_with_format(&path, format)
+            .expect("source image should be written");
+        path
+    }
+
+    fn processed(path: &Path, config: &AssetProcessingConfig) -> (ImageFormat, u32, u32) {
+        let result = process_raster(
+            path,
+            ImageFormat::from_path(path).expect("source format should be known"),
+            config,
+        );
+        let _ = fs::remove_file(path);
+        let ProcessedAsset::Processed(bytes) = result.expect("processing should succeed") else {
+            panic!("expected processed bytes");
+        };
+        let format = image::guess_format(&bytes).expect("output format should be detectable");
+        let (width, height) = image::load_from_memory(&bytes)
+            .expect("output should decode")
+            .dimensions();
+        (format, width, height)
+    }
+
+    #[test]
+    fn downscales_to_max_size_keeping_aspect_and_format() {
+        let config = AssetProcessingConfig {
+            max_size: Some(16),
+            ..Default::default()
+        };
+        let png = source_image("downscale.png", 64, 32, ImageFormat::Png);
+        assert_eq!(processed(&png, &config), (ImageFormat::Png, 16, 8));
+        let jpeg = source_image("downscale.jpg", 20, 40, ImageFormat::Jpeg);
+        assert_eq!(processed(&jpeg, &config), (ImageFormat::Jpeg, 8, 16));
+    }
+
+    #[test]
+    fn recompresses_png_to_webp() {
+        let config = AssetProcessingConfig {
+            recompress_png: Some(RasterFormat::Webp),
+            ..Default::default()
+        };
+        let png = source_image("recompress.png", 12, 6, ImageFormat::Png);
+        assert_eq!(processed(&png, &config), (ImageFormat::WebP, 12, 6));
+    }
+
+    #[test]
+    fn recompresses_and_downscales_png_to_qoi() {
+        let config = AssetProcessingConfig {
+            max_size: Some(16),
+            recompress_png: Some(RasterFormat::Qoi),
+            ..Default::default()
+        };
+        let png = source_image("qoi.png", 32, 64, ImageFormat::Png);
+        assert_eq!(processed(&png, &config), (ImageFormat::Qoi, 8, 16));
+    }
+
+    #[test]
+    fn leaves_images_within_limits_unchanged() {
+        let config = AssetProcessingConfig {
+            max_size: Some(16),
+            recompress_png: Some(RasterFormat::Webp),
+            ..Default::default()
+        };
+        let jpeg = source_image("small.jpg", 16, 8, ImageFormat::Jpeg);
+        let result = process_raster(&jpeg, ImageFormat::Jpeg, &config);
+        let _ = fs::remove_file(&jpeg);
+        assert!(matches!(result, Ok(ProcessedAsset::Unchanged)));
+    }
+}
@@ -0,0 +1,606 @@
+//! Compiled string resources.
+//!
+//! Each `<locale>.ftl` file in the strings directory holds the messages of one
+//! locale, written in a subset of the Fluent syntax:
+//!
+//! ```text
+//! # Comments start with `#`.
+//! greeting = Hello, { $name }!
+//! emails = { $count ->
+//!     [0] No emails
+//!     [one] One email
+//!    *[other] { $count } emails
+//! }
+//! ```
+//!
+//! Messages support variables, string literals and select expressions keyed by
+//! exact values or plural categories. Terms and attributes are rejected.
+//! Every message becomes a function in the generated `strings` module that
+//! resolves the current locale and falls back to the default locale. The
+//! function takes the variables used by any locale in alphabetical order.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::Path,
+};
+
+use anyhow::{Context, Result, anyhow, bail};
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+
+use super::{mangle_module_name, read_dir};
+
+/// String resources of all locales, default locale first.
+#[derive(Debug)]
+pub(crate) struct StringResources {
+    locales: Vec<LocaleStrings>,
+}
+
+#[derive(Debug)]
+struct LocaleStrings {
+    tag: String,
+    messages: BTreeMap<String, Message>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Message {
+    source: String,
+    pattern: Pattern,
+}
+
+type Pattern = Vec<PatternElement>;
+
+#[derive(Debug, Clone, PartialEq)]
+enum PatternElement {
+    Text(String),
+    Variable(String),
+    Select {
+        selector: String,
+        keys: Vec<String>,
+        variants: Vec<Pattern>,
+        default: usize,
+    },
+}
+
+/// Loads every `.ftl` file under `dir`.
+pub(crate) fn load_string_resources(dir: &Path, default_locale: &str) -> Result<StringResources> {
+    let mut locales = Vec::new();
+    for entry in read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|extension| extension != "ftl") {
+            continue;
+        }
+        let tag = path
+            .file_stem()
+            .ok_or_else(|| anyhow!("Missing locale name for {}", path.display()))?
+            .to_string_lossy()
+            .into_owned();
+        let source = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let messages = parse_messages(&source)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        locales.push(LocaleStrings { tag, messages });
+    }
+
+    let default_index = locales
+        .iter()
+        .position(|locale| locale.tag == default_locale)
+        .ok_or_else(|| {
+            anyhow!(
+                "Default locale `{default_locale}` has no {default_locale}.ftl in {}",
+                dir.display()
+            )
+        })?;
+    let default = locales.remove(default_index);
+    locales.sort_by(|left, right| left.tag.cmp(&right.tag));
+    locales.insert(0, default);
+    Ok(StringResources { locales })
+}
+
+/// Generates the `strings` module with one accessor per message.
+pub(crate) fn generate_strings_tokens(resources: &StringResources) -> Result<TokenStream> {
+    let (default, translations) = resources
+        .locales
+        .split_first()
+        .ok_or_else(|| anyhow!("String resources contain no locales"))?;
+    for locale in translations {
+        for id in locale.messages.keys() {
+            if !default.messages.contains_key(id) {
+                println!(
+                    "cargo:warning=Message `{id}` in {}.ftl is missing from {}.ftl and is ignored",
+                    locale.tag, default.tag
+                );
+            }
+        }
+    }
+
+    let tags = resources
+        .locales
+        .iter()
+        .map(|locale| Literal::string(&locale.tag));
+    let mut functions = Vec::new();
+    let mut function_origins = BTreeMap::<String, &str>::new();
+    for (id, message) in &default.messages {
+        let name = mangle_module_name(id);
+        if let Some(existing) = function_origins.insert(name.clone(), id) {
+            bail!("Message name collision: `{existing}` and `{id}` both map to `{name}`");
+        }
+
+        let mut variables = BTreeSet::new();
+        collect_variables(&message.pattern, &mut variables);
+        let mut arms = Vec::new();
+        for (index, locale) in resources.locales.iter().enumerate().skip(1) {
+            if let Some(translation) = locale.messages.get(id) {
+                collect_variables(&translation.pattern, &mut variables);
+                let body = generate_pattern_tokens(&translation.pattern);
+                arms.push(quote! { Some(#index) => { #body } });
+            }
+        }
+
+        let mut parameter_origins = BTreeMap::<String, &str>::new();
+        for variable in &variables {
+            let parameter = mangle_module_name(variable);
+            if let Some(existing) = parameter_origins.insert(parameter.clone(), variable) {
+                bail!(
+                    "Variable name collision in `{id}`: `${existing}` and `${variable}` both map to `{parameter}`"
+                );
+            }
+        }
+        let parameters = variables.iter().map(|variable| {
+            let ident = format_ident!("{}", mangle_module_name(variable));
+            quote! { #ident: impl Into<tessera_ui::locale::MessageArg> }
+        });
+        let conversions = variables.iter().map(|variable| {
+            let ident = format_ident!("{}", mangle_module_name(variable));
+            quote! { let #ident: tessera_ui::locale::MessageArg = #ident.into(); }
+        });
+
+        let doc = if message.source.contains('\n') {
+            format!(" ```text\n {}\n ```", message.source.replace('\n', "\n "))
+        } else {
+            format!(" `{}`", message.source)
+        };
+        let doc = Literal::string(&doc);
+        let ident = format_ident!("{name}");
+        let default_body = generate_pattern_tokens(&message.pattern);
+        functions.push(quote! {
+            #[doc = #doc]
+            pub fn #ident(#(#parameters),*) -> String {
+                #(#conversions)*
+                let __locale = tessera_ui::locale::current_locale();
+                match __locale.resolve(LOCALES) {
+                    #(#arms)*
+                    _ => { #default_body }
+                }
+            }
+        });
+    }
+
+    Ok(quote! {
+        pub mod strings {
+            /// Locales with string resources, default locale first.
+            pub const LOCALES: &[&str] = &[#(#tags),*];
+
+            #(#functions)*
+        }
+    })
+}
+
+fn collect_variables(pattern: &Pattern, variables: &mut BTreeSet<String>) {
+    for element in pattern {
+        match element {
+            PatternElement::Text(_) => {}
+            PatternElement::Variable(name) => {
+                variables.insert(name.clone());
+            }
+            PatternElement::Select {
+                selector, variants, ..
+            } => {
+                variables.insert(selector.clone());
+                for variant in variants {
+                    collect_variables(variant, variables);
+                }
+            }
+        }
+    }
+}
+
+fn generate_pattern_tokens(pattern: &Pattern) -> TokenStream {
+    if let [PatternElement::Text(text)] = pattern.as_slice() {
+        let text = Literal::string(text);
+        return quote! { String::from(#text) };
+    }
+    let pushes = pattern.iter().map(generate_element_tokens);
+    quote! {
+        let mut __out = String::new();
+        #(#pushes)*
+        __out
+    }
+}
+
+fn generate_element_tokens(element: &PatternElement) -> TokenStream {
+    match element {
+        PatternElement::Text(text) => {
+            let text = Literal::string(text);
+            quote! { __out.push_str(#text); }
+        }
+        PatternElement::Variable(name) => {
+            let ident = format_ident!("{}", mangle_module_name(name));
+            quote! { __out.push_str(&#ident.format(&__locale)); }
+        }
+        PatternElement::Select {
+            selector,
+            keys,
+            variants,
+            default,
+        } => {
+            let ident = format_ident!("{}", mangle_module_name(selector));
+            let keys = keys.iter().map(|key| Literal::string(key));
+            let arms = variants.iter().enumerate().map(|(index, variant)| {
+                let pushes = variant.iter().map(generate_element_tokens);
+                if index == *default {
+                    quote! { _ => { #(#pushes)* } }
+                } else {
+                    quote! { #index => { #(#pushes)* } }
+                }
+            });
+            // The default arm must come last.
+            let mut arms = arms.collect::<Vec<_>>();
+            let default_arm = arms.remove(*default);
+            arms.push(default_arm);
+            quote! {
+                match tessera_ui::locale::select_message_variant(
+                    &__locale,
+                    &#ident,
+                    &[#(#keys),*],
+                    #default,
+                ) {
+                    #(#arms)*
+                }
+            }
+        }
+    }
+}
+
+fn parse_messages(source: &str) -> Result<BTreeMap<String, Message>> {
+    let lines = source.lines().collect::<Vec<_>>();
+    let mut messages = BTreeMap::new();
+    let mut line_index = 0;
+    while line_index < lines.len() {
+        let line = lines[line_index];
+        let line_number = line_index + 1;
+        line_index += 1;
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with('-') {
+            bail!("line {line_number}: terms are not supported");
+        }
+        if line.starts_with(char::is_whitespace) {
+            bail!("line {line_number}: expected a message, found indented text");
+        }
+        let (id, value) = line
+            .split_once('=')
+            .ok_or_else(|| anyhow!("line {line_number}: expected `id = value`"))?;
+        let id = id.trim_end();
+        if !is_identifier(id) {
+            bail!("line {line_number}: invalid message id `{id}`");
+        }
+
+        let mut continuation = Vec::new();
+        while let Some(next) = lines.get(line_index) {
+            if next.trim().is_empty()
+                || next.starts_with(char::is_whitespace)
+                || next.starts_with('}')
+            {
+                continuation.push(*next);
+                line_index += 1;
+            } else {
+                break;
+            }
+        }
+        while continuation
+            .last()
+            .is_some_and(|line| line.trim().is_empty())
+        {
+            continuation.pop();
+        }
+        if continuation
+            .iter()
+            .any(|line| line.trim_start().starts_with('.'))
+        {
+            bail!("line {line_number}: attributes are not supported");
+        }
+
+        let indent = continuation
+            .iter()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('}'))
+            .map(|line| leading_whitespace(line))
+            .min()
+            .unwrap_or(0);
+        let mut text = value.trim_start().to_string();
+        for line in continuation {
+            if !text.is_empty() {
+                text.push('\n');
+            }
+            text.push_str(&line[leading_whitespace(line).min(indent)..]);
+        }
+        let text = text.trim_end().to_string();
+        if text.is_empty() {
+            bail!("line {line_number}: message `{id}` has no value");
+        }
+
+        let pattern = PatternParser::new(&text)
+            .parse_pattern(false)
+            .map_err(|err| anyhow!("line {line_number}: {err}"))?;
+        let message = Message {
+            source: text,
+            pattern,
+        };
+        if messages.insert(id.to_string(), message).is_some() {
+            bail!("line {line_number}: duplicate message `{id}`");
+        }
+    }
+    Ok(messages)
+}
+
+fn leading_whitespace(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+fn is_identifier(value: &str) -> bool {
+    let mut chars = value.chars();
+    chars.next().is_some_and(|ch| ch.is_ascii_alphabetic())
+        && chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_')
+}
+
+struct PatternParser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> PatternParser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, position: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let ch = self.peek()?;
+        self.position += ch.len_utf8();
+        Some(ch)
+    }
+
+    fn skip_blank(&mut self, newlines: bool) {
+        while let Some(ch) = self.peek() {
+            if ch == ' ' || ch == '\t' || (newlines && (ch == '\n' || ch == '\r')) {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        match self.bump() {
+            Some(ch) if ch == expected => Ok(()),
+            Some(ch) => bail!("expected `{expected}`, found `{ch}`"),
+            None => bail!("expected `{expected}`, found end of message"),
+        }
+    }
+
+    /// Parses text and placeables up to the end of input, or the end of the
+    /// line for select variants.
+    fn parse_pattern(&mut self, single_line: bool) -> Result<Pattern> {
+        let mut pattern = Vec::new();
+        let mut text = String::new();
+        while let Some(ch) = self.peek() {
+            if single_line && ch == '\n' {
+                break;
+            }
+            if ch == '{' {
+                self.bump();
+                let element = self.parse_placeable()?;
+                match element {
+                    PatternElement::Text(literal) => text.push_str(&literal),
+                    element => {
+                        if !text.is_empty() {
+                            pattern.push(PatternElement::Text(std::mem::take(&mut text)));
+                        }
+                        pattern.push(element);
+                    }
+                }
+                continue;
+            }
+            if ch == '}' {
+                bail!("unbalanced `}}`");
+            }
+            text.push(ch);
+            self.bump();
+        }
+        if single_line {
+            let trimmed = text.trim_end().len();
+            text.truncate(trimmed);
+        }
+        if !text.is_empty() {
+            pattern.push(PatternElement::Text(text));
+        }
+        Ok(pattern)
+    }
+
+    fn parse_placeable(&mut self) -> Result<PatternElement> {
+        self.skip_blank(true);
+        let element = match self.peek() {
+            Some('$') => {
+                self.bump();
+                let name = self.parse_identifier()?;
+                self.skip_blank(true);
+                if self.rest().starts_with("->") {
+                    self.position += 2;
+                    self.parse_select(name)?
+                } else {
+                    PatternElement::Variable(name)
+                }
+            }
+            Some('"') => PatternElement::Text(self.parse_string_literal()?),
+            Some('-') => bail!("terms are not supported"),
+            Some(ch) => bail!("unsupported placeable starting with `{ch}`"),
+            None => bail!("unterminated placeable"),
+        };
+        self.skip_blank(true);
+        self.expect('}')?;
+        Ok(element)
+    }
+
+    fn parse_identifier(&mut self) -> Result<String> {
+        let length = self
+            .rest()
+            .find(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '-' || ch == '_'))
+            .unwrap_or(self.rest().len());
+        let identifier = &self.rest()[..length];
+        if !is_identifier(identifier) {
+            bail!("invalid variable name `${identifier}`");
+        }
+        self.position += length;
+        Ok(identifier.to_string())
+    }
+
+    fn parse_string_literal(&mut self) -> Result<String> {
+        self.expect('"')?;
+        let mut value = String::new();
+        loop {
+            match self.bump() {
+                Some('"') => return Ok(value),
+                Some('\\') => match self.bump() {
+                    Some(ch @ ('"' | '\\')) => value.push(ch),
+                    Some('u') => {
+                        let digits = self.rest().get(..4).unwrap_or_default();
+                        let ch = u32::from_str_radix(digits, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| anyhow!("invalid unicode escape `\\u{digits}`"))?;
+                        self.position += 4;
+                        value.push(ch);
+                    }
+                    _ => bail!("invalid escape sequence in string literal"),
+                },
+                Some('\n') | None => bail!("unterminated string literal"),
+                Some(ch) => value.push(ch),
+            }
+        }
+    }
+
+    fn parse_select(&mut self, selector: String) -> Result<PatternElement> {
+        let mut keys = Vec::new();
+        let mut variants = Vec::new();
+        let mut default = None;
+        loop {
+            self.skip_blank(true);
+            let is_default = match self.peek() {
+                Some('*') => {
+                    self.bump();
+                    true
+                }
+                Some('[') => false,
+                _ => break,
+            };
+            self.expect('[')?;
+            let end = self
+                .rest()
+                .find(']')
+                .ok_or_else(|| anyhow!("unterminated variant key"))?;
+            let key = self.rest()[..end].trim().to_string();
+            if key.is_empty() {
+                bail!("empty variant key");
+            }
+            self.position += end + 1;
+            self.skip_blank(false);
+
+            if is_default {
+                if default.is_some() {
+                    bail!("select on `${selector}` has more than one default variant");
+                }
+                default = Some(keys.len());
+            }
+            keys.push(key);
+            variants.push(self.parse_pattern(true)?);
+        }
+
+        let default =
+            default.ok_or_else(|| anyhow!("select on `${selector}` needs a `*[...]` default"))?;
+        Ok(PatternElement::Select {
+            selector,
+            keys,
+            variants,
+            default,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_variables_and_multiline_values() {
+        let messages = parse_messages(
+            "# comment\ngreeting = Hello, { $user-name }!\nnotice =\n    First line\n      indented { \"{\" }\n",
+        )
+        .expect("messages should parse");
+        assert_eq!(
+            messages["greeting"].pattern,
+            vec![
+                PatternElement::Text("Hello, ".to_string()),
+                PatternElement::Variable("user-name".to_string()),
+                PatternElement::Text("!".to_string()),
+            ]
+        );
+        assert_eq!(
+            messages["notice"].pattern,
+            vec![PatternElement::Text("First line\n  indented {".to_string())]
+        );
+    }
+
+    #[test]
+    fn parses_select_expressions() {
+        let messages = parse_messages(
+            "emails = { $count ->\n    [0] No emails\n    [one] One email\n   *[other] { $count } emails\n}\n",
+        )
+        .expect("messages should parse");
+        let PatternElement::Select {
+            selector,
+            keys,
+            variants,
+            default,
+        } = &messages["emails"].pattern[0]
+        else {
+            panic!("expected a select expression");
+        };
+        assert_eq!(selector, "count");
+        assert_eq!(keys, &["0", "one", "other"]);
+        assert_eq!(*default, 2);
+        assert_eq!(
+            variants[2],
+            vec![
+                PatternElement::Variable("count".to_string()),
+                PatternElement::Text(" emails".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_syntax() {
+        assert!(parse_messages("-brand = Tessera\n").is_err());
+        assert!(parse_messages("title = Title\n    .tooltip = Tip\n").is_err());
+        assert!(parse_messages("count = { $n ->\n    [one] One\n}\n").is_err());
+        assert!(parse_messages("a = One\na = Two\n").is_err());
+    }
+}
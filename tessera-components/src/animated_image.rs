@@ -0,0 +1,679 @@
+//! Animated raster images decoded from GIF, APNG, and animated WebP.
+//!
+//! ## Usage
+//!
+//! Play short looping animations such as stickers, loading art, or previews.
+use std::{
+    io::Cursor,
+    path::Path,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+use image::{
+    AnimationDecoder, Frame, ImageFormat,
+    codecs::{gif::GifDecoder, png::PngDecoder, webp::WebPDecoder},
+};
+use tessera_ui::{
+    AssetExt, ComputedData, FrameNanosControl, LayoutResult, MeasurementError, Modifier, Px, State,
+    layout::{LayoutPolicy, MeasureScope, RenderInput, RenderPolicy, layout},
+    receive_frame_nanos, remember, remember_visibility, tessera,
+};
+
+use crate::{
+    image::{ImageData, ImageLoadError, decode_dynamic_image},
+    pipelines::image::command::ImageCommand,
+};
+
+/// Delays at or below this are replaced by [`DEFAULT_FRAME_DELAY`], matching
+/// how browsers treat zero-delay GIF frames.
+const MIN_FRAME_DELAY: Duration = Duration::from_millis(10);
+const DEFAULT_FRAME_DELAY: Duration = Duration::from_millis(100);
+
+static NEXT_TEXTURE_SLOT: AtomicU64 = AtomicU64::new(1);
+
+/// A single decoded animation frame.
+#[derive(Clone, Debug)]
+pub struct AnimatedImageFrame {
+    /// Fully composited RGBA pixels for this frame.
+    pub data: Arc<ImageData>,
+    /// How long the frame stays on screen.
+    pub delay: Duration,
+}
+
+/// Decoded frames of an animated image.
+///
+/// Every frame has the same size. Still images decode to a single frame.
+#[derive(Clone, Debug)]
+pub struct AnimatedImageData {
+    frames: Arc<[AnimatedImageFrame]>,
+}
+
+impl PartialEq for AnimatedImageData {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.frames, &other.frames)
+    }
+}
+
+impl AnimatedImageData {
+    /// Creates animation data from already-decoded frames.
+    ///
+    /// Returns `None` when `frames` is empty or the frame sizes differ.
+    pub fn new(frames: Vec<AnimatedImageFrame>) -> Option<Self> {
+        let first = frames.first()?;
+        let (width, height) = (first.data.width, first.data.height);
+        if frames
+            .iter()
+            .any(|frame| frame.data.width != width || frame.data.height != height)
+        {
+            return None;
+        }
+        let frames = frames
+            .into_iter()
+            .map(|frame| AnimatedImageFrame {
+                delay: normalize_delay(frame.delay),
+                ..frame
+            })
+            .collect();
+        Some(Self { frames })
+    }
+
+    /// Decodes an encoded image, keeping every animation frame.
+    ///
+    /// GIF, APNG, and animated WebP produce all of their frames; other
+    /// supported formats produce a single still frame.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ImageLoadError> {
+        let frames = match image::guess_format(bytes)? {
+            ImageFormat::Gif => collect_frames(GifDecoder::new(Cursor::new(bytes))?)?,
+            ImageFormat::Png => {
+                let decoder = PngDecoder::new(Cursor::new(bytes))?;
+                if decoder.is_apng()? {
+                    collect_frames(decoder.apng()?)?
+                } else {
+                    still_frame(bytes)?
+                }
+            }
+            ImageFormat::WebP => {
+                let decoder = WebPDecoder::new(Cursor::new(bytes))?;
+                if decoder.has_animation() {
+                    collect_frames(decoder)?
+                } else {
+                    still_frame(bytes)?
+                }
+            }
+            _ => still_frame(bytes)?,
+        };
+        Self::new(frames).ok_or_else(|| {
+            ImageLoadError::Decode(image::ImageError::Decoding(
+                image::error::DecodingError::from_format_hint(
+                    image::error::ImageFormatHint::Unknown,
+                ),
+            ))
+        })
+    }
+
+    /// Reads and decodes an image file, keeping every animation frame.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, ImageLoadError> {
+        let bytes = std::fs::read(path).map_err(image::ImageError::IoError)?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Reads and decodes an image asset, keeping every animation frame.
+    pub fn from_asset<T>(asset: T) -> Result<Self, ImageLoadError>
+    where
+        T: AssetExt,
+    {
+        let bytes = asset
+            .read()
+            .map_err(|source| ImageLoadError::AssetRead { source })?;
+        Self::from_bytes(bytes.as_ref())
+    }
+
+    /// Returns the decoded frames in playback order.
+    pub fn frames(&self) -> &[AnimatedImageFrame] {
+        &self.frames
+    }
+
+    /// Returns the number of frames.
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns whether there is more than one frame to play.
+    pub fn is_animated(&self) -> bool {
+        self.frames.len() > 1
+    }
+
+    /// Returns the length of one pass through every frame.
+    pub fn duration(&self) -> Duration {
+        self.frames.iter().map(|frame| frame.delay).sum()
+    }
+
+    /// Returns the frame width in pixels.
+    pub fn width(&self) -> u32 {
+        self.frames[0].data.width
+    }
+
+    /// Returns the frame height in pixels.
+    pub fn height(&self) -> u32 {
+        self.frames[0].data.height
+    }
+
+    fn frame(&self, index: usize) -> &AnimatedImageFrame {
+        &self.frames[index.min(self.frames.len() - 1)]
+    }
+}
+
+fn collect_frames<'a>(
+    decoder: impl AnimationDecoder<'a>,
+) -> Result<Vec<AnimatedImageFrame>, ImageLoadError> {
+    decoder
+        .into_frames()
+        .map(|frame| Ok(decode_frame(frame?)))
+        .collect()
+}
+
+fn decode_frame(frame: Frame) -> AnimatedImageFrame {
+    let (numer, denom) = frame.delay().numer_denom_ms();
+    let delay_nanos = u64::from(numer) * 1_000_000 / u64::from(denom.max(1));
+    let buffer = frame.into_buffer();
+    AnimatedImageFrame {
+        data: Arc::new(ImageData {
+            width: buffer.width(),
+            height: buffer.height(),
+            data: Arc::new(buffer.into_raw()),
+        }),
+        delay: Duration::from_nanos(delay_nanos),
+    }
+}
+
+fn still_frame(bytes: &[u8]) -> Result<Vec<AnimatedImageFrame>, ImageLoadError> {
+    let decoded = image::load_from_memory(bytes)?;
+    Ok(vec![AnimatedImageFrame {
+        data: Arc::new(decode_dynamic_image(decoded)),
+        delay: DEFAULT_FRAME_DELAY,
+    }])
+}
+
+fn normalize_delay(delay: Duration) -> Duration {
+    if delay <= MIN_FRAME_DELAY {
+        DEFAULT_FRAME_DELAY
+    } else {
+        delay
+    }
+}
+
+/// How many times an animation plays before stopping.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LoopCount {
+    /// Repeat until paused.
+    #[default]
+    Infinite,
+    /// Play the given number of passes, then hold the last frame.
+    ///
+    /// `Finite(0)` plays no passes and holds the first frame.
+    Finite(u32),
+}
+
+/// Playback controller for [`animated_image`].
+///
+/// Tracks the current frame and play state. Share it with other components to
+/// pause, resume, or restart the animation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnimatedImageController {
+    playing: bool,
+    loop_count: LoopCount,
+    completed_loops: u32,
+    frame_index: usize,
+    frame_elapsed: Duration,
+    last_frame_nanos: Option<u64>,
+}
+
+impl Default for AnimatedImageController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnimatedImageController {
+    /// Creates a controller that starts playing from the first frame and
+    /// loops forever.
+    pub fn new() -> Self {
+        Self {
+            playing: true,
+            loop_count: LoopCount::Infinite,
+            completed_loops: 0,
+            frame_index: 0,
+            frame_elapsed: Duration::ZERO,
+            last_frame_nanos: None,
+        }
+    }
+
+    /// Sets how many passes to play before stopping.
+    pub fn with_loop_count(mut self, loop_count: LoopCount) -> Self {
+        self.loop_count = loop_count;
+        self
+    }
+
+    /// Starts or resumes playback.
+    ///
+    /// A finished animation restarts from the first frame.
+    pub fn play(&mut self) {
+        if self.is_finished() {
+            self.restart();
+        }
+        self.playing = true;
+    }
+
+    /// Pauses playback on the current frame.
+    pub fn pause(&mut self) {
+        self.playing = false;
+        self.last_frame_nanos = None;
+    }
+
+    /// Rewinds to the first frame and resets the loop counter.
+    pub fn restart(&mut self) {
+        self.completed_loops = 0;
+        self.frame_index = 0;
+        self.frame_elapsed = Duration::ZERO;
+        self.last_frame_nanos = None;
+    }
+
+    /// Returns whether playback is active.
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Returns whether every requested loop has been played.
+    pub fn is_finished(&self) -> bool {
+        match self.loop_count {
+            LoopCount::Infinite => false,
+            LoopCount::Finite(count) => self.completed_loops >= count,
+        }
+    }
+
+    /// Returns the configured loop count.
+    pub fn loop_count(&self) -> LoopCount {
+        self.loop_count
+    }
+
+    /// Sets how many passes to play before stopping.
+    ///
+    /// Passes that already played count toward the new limit.
+    pub fn set_loop_count(&mut self, loop_count: LoopCount) {
+        self.loop_count = loop_count;
+    }
+
+    /// Returns the index of the frame currently shown.
+    pub fn current_frame(&self) -> usize {
+        self.frame_index
+    }
+
+    /// Shows the frame at `index` of `data` and restarts its delay.
+    ///
+    /// Indices past the last frame show the last frame.
+    pub fn seek_to_frame(&mut self, data: &AnimatedImageData, index: usize) {
+        self.frame_index = index.min(data.frame_count() - 1);
+        self.frame_elapsed = Duration::ZERO;
+    }
+
+    fn needs_frames(&self, data: &AnimatedImageData) -> bool {
+        self.playing && data.is_animated() && !self.is_finished()
+    }
+
+    /// Forgets the previous frame time so hidden time is not played back.
+    fn suspend(&mut self) {
+        self.last_frame_nanos = None;
+    }
+
+    /// Advances playback to `frame_nanos` and returns whether more frames are
+    /// needed.
+    fn tick(&mut self, data: &AnimatedImageData, frame_nanos: u64) -> bool {
+        if !self.needs_frames(data) {
+            self.last_frame_nanos = None;
+            return false;
+        }
+        let Some(last_frame_nanos) = self.last_frame_nanos.replace(frame_nanos) else {
+            return true;
+        };
+        let frame_count = data.frame_count();
+        self.frame_index = self.frame_index.min(frame_count - 1);
+        let mut elapsed =
+            self.frame_elapsed + Duration::from_nanos(frame_nanos.saturating_sub(last_frame_nanos));
+        if self.loop_count == LoopCount::Infinite {
+            // Skip whole passes after long stalls instead of replaying them.
+            let total = data.duration().as_nanos();
+            if elapsed.as_nanos() >= total {
+                elapsed = Duration::from_nanos((elapsed.as_nanos() % total) as u64);
+            }
+        }
+        loop {
+            let delay = data.frame(self.frame_index).delay;
+            if elapsed < delay {
+                break;
+            }
+            elapsed -= delay;
+            if self.frame_index + 1 < frame_count {
+                self.frame_index += 1;
+                continue;
+            }
+            self.completed_loops = self.completed_loops.saturating_add(1);
+            if self.is_finished() {
+                self.playing = false;
+                self.last_frame_nanos = None;
+                elapsed = Duration::ZERO;
+                break;
+            }
+            self.frame_index = 0;
+        }
+        self.frame_elapsed = elapsed;
+        self.playing
+    }
+}
+
+#[derive(Clone)]
+struct AnimatedImageLayout {
+    frame: Arc<ImageData>,
+    texture_slot: u64,
+}
+
+impl PartialEq for AnimatedImageLayout {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.frame, &other.frame) && self.texture_slot == other.texture_slot
+    }
+}
+
+impl LayoutPolicy for AnimatedImageLayout {
+    fn measure(&self, input: &MeasureScope<'_>) -> Result<LayoutResult, MeasurementError> {
+        let width = input
+            .parent_constraint()
+            .width()
+            .clamp(Px(self.frame.width as i32));
+        let height = input
+            .parent_constraint()
+            .height()
+            .clamp(Px(self.frame.height as i32));
+        Ok(LayoutResult::new(ComputedData { width, height }))
+    }
+
+    fn measure_eq(&self, other: &Self) -> bool {
+        self.frame.width == other.frame.width && self.frame.height == other.frame.height
+    }
+}
+
+impl RenderPolicy for AnimatedImageLayout {
+    fn record(&self, input: &mut RenderInput<'_>) {
+        input
+            .metadata_mut()
+            .fragment_mut()
+            .push_draw_command(ImageCommand {
+                data: self.frame.clone(),
+                opacity: 1.0,
+                texture_slot: Some(self.texture_slot),
+            });
+    }
+}
+
+/// # animated_image
+///
+/// Plays a GIF, APNG, or animated WebP image.
+///
+/// ## Usage
+///
+/// Show stickers, animated previews, or decorative loops.
+///
+/// ## Parameters
+///
+/// - `data` — decoded frames to play; nothing is drawn when unset.
+/// - `controller` — optional playback controller for play, pause, and loop
+///   count.
+/// - `modifier` — node-local layout, drawing, and interaction modifiers.
+///
+/// Playback pauses while the image is off screen or the window is minimized
+/// or occluded, and resumes from the same frame when it becomes visible
+/// again. Frames are uploaded into one reused GPU texture.
+///
+/// ## Examples
+///
+/// ```
+/// # use tessera_ui::tessera;
+/// # #[tessera]
+/// # fn component() {
+/// use std::{sync::Arc, time::Duration};
+/// use tessera_components::{
+///     animated_image::{AnimatedImageData, AnimatedImageFrame, animated_image},
+///     image::ImageData,
+/// };
+///
+/// let frame = |value| AnimatedImageFrame {
+///     data: Arc::new(ImageData {
+///         data: Arc::new(vec![value, value, value, 255]),
+///         width: 1,
+///         height: 1,
+///     }),
+///     delay: Duration::from_millis(200),
+/// };
+/// let blink = AnimatedImageData::new(vec![frame(0), frame(255)]).expect("frames match");
+///
+/// animated_image().data(blink);
+/// # }
+/// ```
+#[tessera]
+pub fn animated_image(
+    #[prop(into)] data: Option<AnimatedImageData>,
+    controller: Option<State<AnimatedImageController>>,
+    modifier: Option<Modifier>,
+) {
+    let modifier = modifier.unwrap_or_default();
+    let controller = controller.unwrap_or_else(|| remember(AnimatedImageController::new));
+    let texture_slot = remember(|| NEXT_TEXTURE_SLOT.fetch_add(1, Ordering::Relaxed)).get();
+    let Some(data) = data else {
+        layout().modifier(modifier);
+        return;
+    };
+
+    // The frame callback outlives this build, so it reads the latest data
+    // through state instead of capturing it.
+    let current_data = remember(|| data.clone());
+    if current_data.with(|current| *current != data) {
+        current_data.set(data.clone());
+        controller.with_mut(|current| current.restart());
+    }
+
+    let visible = remember_visibility();
+    if visible.get() && controller.with(|current| current.needs_frames(&data)) {
+        receive_frame_nanos(move |frame_nanos| {
+            if !visible.get() {
+                controller.with_mut(|current| current.suspend());
+                return FrameNanosControl::Stop;
+            }
+            let data = current_data.get();
+            let needs_frames = controller.with_mut(|current| current.tick(&data, frame_nanos));
+            if needs_frames {
+                FrameNanosControl::Continue
+            } else {
+                FrameNanosControl::Stop
+            }
+        });
+    } else if controller.with(|current| current.last_frame_nanos.is_some()) {
+        controller.with_mut(|current| current.suspend());
+    }
+
+    let frame_index = controller.with(|current| current.current_frame());
+    let policy = AnimatedImageLayout {
+        frame: data.frame(frame_index).data.clone(),
+        texture_slot,
+    };
+    layout()
+        .modifier(modifier)
+        .layout_policy(policy.clone())
+        .render_policy(policy);
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{Delay, RgbaImage, codecs::gif::GifEncoder};
+
+    use super::*;
+
+    fn solid_frame(value: u8, delay_ms: u64) -> AnimatedImageFrame {
+        AnimatedImageFrame {
+            data: Arc::new(ImageData {
+                data: Arc::new(vec![value, value, value, 255]),
+                width: 1,
+                height: 1,
+            }),
+            delay: Duration::from_millis(delay_ms),
+        }
+    }
+
+    fn three_frames() -> AnimatedImageData {
+        AnimatedImageData::new(vec![
+            solid_frame(0, 100),
+            solid_frame(1, 100),
+            solid_frame(2, 200),
+        ])
+        .expect("frames share a size")
+    }
+
+    const MS: u64 = 1_000_000;
+
+    #[test]
+    fn decodes_every_gif_frame_with_delays() {
+        let mut bytes = Vec::new();
+        {
+            let mut encoder = GifEncoder::new(&mut bytes);
+            let frames = [[255, 0, 0, 255], [0, 0, 255, 255]].map(|pixel| {
+                Frame::from_parts(
+                    RgbaImage::from_pixel(2, 2, image::Rgba(pixel)),
+                    0,
+                    0,
+                    Delay::from_numer_denom_ms(50, 1),
+                )
+            });
+            encoder.encode_frames(frames).expect("encode gif");
+        }
+
+        let data = AnimatedImageData::from_bytes(&bytes).expect("decode gif");
+        assert_eq!(data.frame_count(), 2);
+        assert_eq!((data.width(), data.height()), (2, 2));
+        let pixel = &data.frames()[1].data.data[..4];
+        assert!(
+            pixel[0] < 32 && pixel[2] > 224,
+            "unexpected pixel {pixel:?}"
+        );
+        assert_eq!(data.duration(), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn zero_delays_use_default_and_mismatched_sizes_are_rejected() {
+        let data = AnimatedImageData::new(vec![solid_frame(0, 0)]).expect("one frame");
+        assert_eq!(data.frames()[0].delay, DEFAULT_FRAME_DELAY);
+
+        let mut wide = solid_frame(0, 100);
+        wide.data = Arc::new(ImageData {
+            data: Arc::new(vec![0; 8]),
+            width: 2,
+            height: 1,
+        });
+        assert!(AnimatedImageData::new(vec![solid_frame(0, 100), wide]).is_none());
+    }
+
+    #[test]
+    fn tick_advances_by_frame_delays_and_loops() {
+        let data = three_frames();
+        let mut controller = AnimatedImageController::new();
+
+        assert!(controller.tick(&data, 0));
+        assert!(controller.tick(&data, 99 * MS));
+        assert_eq!(controller.current_frame(), 0);
+        assert!(controller.tick(&data, 100 * MS));
+        assert_eq!(controller.current_frame(), 1);
+        assert!(controller.tick(&data, 250 * MS));
+        assert_eq!(controller.current_frame(), 2);
+        assert!(controller.tick(&data, 400 * MS));
+        assert_eq!(controller.current_frame(), 0);
+    }
+
+    #[test]
+    fn finite_loops_hold_last_frame() {
+        let data = three_frames();
+        let mut controller = AnimatedImageController::new().with_loop_count(LoopCount::Finite(2));
+
+        assert!(controller.tick(&data, 0));
+        assert!(controller.tick(&data, 400 * MS));
+        assert_eq!(controller.current_frame(), 0);
+        assert!(!controller.tick(&data, 2_000 * MS));
+        assert_eq!(controller.current_frame(), 2);
+        assert!(controller.is_finished());
+        assert!(!controller.is_playing());
+
+        controller.play();
+        assert_eq!(controller.current_frame(), 0);
+        assert!(controller.is_playing());
+    }
+
+    #[test]
+    fn zero_loops_hold_first_frame() {
+        let data = three_frames();
+        let mut controller = AnimatedImageController::new().with_loop_count(LoopCount::Finite(0));
+
+        assert!(controller.is_finished());
+        assert!(!controller.tick(&data, 0));
+        assert!(!controller.tick(&data, 1_000 * MS));
+        assert_eq!(controller.current_frame(), 0);
+
+        controller.play();
+        assert!(!controller.tick(&data, 2_000 * MS));
+        assert_eq!(controller.current_frame(), 0);
+    }
+
+    #[test]
+    fn seek_clamps_to_last_frame_and_restarts_delay() {
+        let data = three_frames();
+        let mut controller = AnimatedImageController::new();
+
+        controller.seek_to_frame(&data, 10);
+        assert_eq!(controller.current_frame(), 2);
+
+        assert!(controller.tick(&data, 0));
+        assert!(controller.tick(&data, 150 * MS));
+        controller.seek_to_frame(&data, 1);
+        assert_eq!(controller.current_frame(), 1);
+        assert!(controller.tick(&data, 249 * MS));
+        assert_eq!(controller.current_frame(), 1);
+        assert!(controller.tick(&data, 250 * MS));
+        assert_eq!(controller.current_frame(), 2);
+    }
+
+    #[test]
+    fn paused_and_suspended_time_is_not_played() {
+        let data = three_frames();
+        let mut controller = AnimatedImageController::new();
+
+        assert!(controller.tick(&data, 0));
+        assert!(controller.tick(&data, 50 * MS));
+        controller.pause();
+        assert!(!controller.tick(&data, 500 * MS));
+        assert_eq!(controller.current_frame(), 0);
+
+        controller.play();
+        assert!(controller.tick(&data, 1_000 * MS));
+        assert!(controller.tick(&data, 1_060 * MS));
+        assert_eq!(controller.current_frame(), 1);
+
+        controller.suspend();
+        assert!(controller.tick(&data, 5_000 * MS));
+        assert_eq!(controller.current_frame(), 1);
+    }
+
+    #[test]
+    fn still_images_do_not_request_frames() {
+        let data = AnimatedImageData::new(vec![solid_frame(0, 100)]).expect("one frame");
+        let mut controller = AnimatedImageController::new();
+        assert!(!controller.tick(&data, 0));
+    }
+}
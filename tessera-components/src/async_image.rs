@@ -0,0 +1,195 @@
+//! Image component that loads its content off the UI thread.
+//!
+//! ## Usage
+//!
+//! Display large photos or remote images without stalling frames.
+use std::sync::Arc;
+
+use tessera_ui::{
+    ComputedData, LayoutResult, MeasurementError, Modifier, PxPosition, State,
+    layout::{LayoutPolicy, MeasureScope, layout},
+    remember, tessera,
+};
+
+use crate::{
+    image::{ImageData, image},
+    image_loader::{ImageLoadState, ImageLoader, ImageSource, ImageTargetSize, remember_image},
+    painter::Painter,
+};
+
+#[derive(Clone)]
+struct AsyncImageLayout {
+    target: State<Option<ImageTargetSize>>,
+}
+
+impl PartialEq for AsyncImageLayout {
+    fn eq(&self, other: &Self) -> bool {
+        self.target == other.target
+    }
+}
+
+impl LayoutPolicy for AsyncImageLayout {
+    fn measure(&self, input: &MeasureScope<'_>) -> Result<LayoutResult, MeasurementError> {
+        let parent = input.parent_constraint();
+        let measured = ImageTargetSize::new(
+            parent
+                .width()
+                .resolve_max()
+                .map_or(0, |width| width.positive()),
+            parent
+                .height()
+                .resolve_max()
+                .map_or(0, |height| height.positive()),
+        );
+        let current = self.target.with(|target| *target);
+        if let Some(next) = next_target(current, measured) {
+            self.target.set(Some(next));
+        }
+
+        let mut result = LayoutResult::default();
+        let mut size = ComputedData::min_from_constraint(parent.as_ref());
+        for child in input.children() {
+            size = child.measure(parent.as_ref())?.size();
+            result.place_child(child, PxPosition::ZERO);
+        }
+        Ok(result.with_size(size))
+    }
+}
+
+/// Returns the decode target to request after measuring `measured`, or `None`
+/// when the current target already covers it.
+///
+/// Targets only grow so shrinking layouts reuse the sharper decoded image.
+fn next_target(
+    current: Option<ImageTargetSize>,
+    measured: ImageTargetSize,
+) -> Option<ImageTargetSize> {
+    let Some(current) = current else {
+        return Some(measured);
+    };
+    if current.covers(measured) {
+        return None;
+    }
+    let grow = |current: u32, measured: u32| {
+        if current == 0 || measured == 0 {
+            0
+        } else {
+            current.max(measured)
+        }
+    };
+    Some(ImageTargetSize::new(
+        grow(current.width, measured.width),
+        grow(current.height, measured.height),
+    ))
+}
+
+fn empty_painter() -> Painter {
+    Painter::Raster(Arc::new(ImageData {
+        data: Arc::new(vec![0, 0, 0, 0]),
+        width: 1,
+        height: 1,
+    }))
+}
+
+/// # async_image
+///
+/// Loads an image on a worker pool and renders it once decoded.
+///
+/// ## Usage
+///
+/// Show photos, thumbnails, or remote images that are too large to decode on
+/// the UI thread.
+///
+/// ## Parameters
+///
+/// - `source` — file path, URI, encoded bytes, or asset to load.
+/// - `placeholder` — painter shown while the image loads.
+/// - `error` — painter shown when loading fails; defaults to `placeholder`.
+/// - `loader` — loader to use instead of [`ImageLoader::global`].
+/// - `modifier` — node-local layout, drawing, and interaction modifiers.
+///
+/// The image is downsampled to the largest size it has been laid out at, so
+/// decoding follows the available space instead of the source resolution.
+/// Loads are shared between components showing the same source and are
+/// cancelled when the component leaves the tree.
+///
+/// ## Examples
+///
+/// ```
+/// # use tessera_ui::tessera;
+/// # #[tessera]
+/// # fn component() {
+/// use std::sync::Arc;
+/// use tessera_components::{async_image::async_image, image::ImageData};
+///
+/// let gray = ImageData {
+///     data: Arc::new(vec![128, 128, 128, 255]),
+///     width: 1,
+///     height: 1,
+/// };
+///
+/// async_image()
+///     .source("photos/mountain.jpg")
+///     .placeholder(gray);
+/// # }
+/// ```
+#[tessera]
+pub fn async_image(
+    #[prop(into)] source: Option<ImageSource>,
+    #[prop(into)] placeholder: Option<Painter>,
+    #[prop(into)] error: Option<Painter>,
+    loader: Option<ImageLoader>,
+    modifier: Option<Modifier>,
+) {
+    let modifier = modifier.unwrap_or_default();
+    let placeholder = placeholder.unwrap_or_else(empty_painter);
+    let target = remember(|| None::<ImageTargetSize>);
+
+    let painter = match (source, target.get()) {
+        (Some(source), Some(target)) => {
+            let loader = loader.unwrap_or_else(ImageLoader::global);
+            let state = remember_image(&loader, source, target);
+            match state.get() {
+                ImageLoadState::Loading => placeholder,
+                ImageLoadState::Ready(data) => Painter::Raster(data),
+                ImageLoadState::Failed(_) => error.unwrap_or(placeholder),
+            }
+        }
+        _ => placeholder,
+    };
+
+    layout()
+        .modifier(modifier)
+        .layout_policy(AsyncImageLayout { target })
+        .child(move || {
+            image().painter(painter.clone());
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_measure_sets_target() {
+        let measured = ImageTargetSize::new(120, 80);
+        assert_eq!(next_target(None, measured), Some(measured));
+    }
+
+    #[test]
+    fn targets_only_grow() {
+        let current = ImageTargetSize::new(120, 80);
+        assert_eq!(
+            next_target(Some(current), ImageTargetSize::new(60, 40)),
+            None
+        );
+        assert_eq!(
+            next_target(Some(current), ImageTargetSize::new(200, 40)),
+            Some(ImageTargetSize::new(200, 80))
+        );
+        assert_eq!(
+            next_target(Some(current), ImageTargetSize::new(60, 0)),
+            Some(ImageTargetSize::new(120, 0))
+        );
+    }
+}
@@ -5,14 +5,17 @@
 //! Used to show contextual information or actions in a modal sheet.
 use std::time::Duration;
 
-use tessera_foundation::gesture::DragRecognizer;
+use tessera_foundation::{
+    gesture::DragRecognizer,
+    overlay::{OverlayPosition, overlay},
+};
 use tessera_ui::{
-    AxisConstraint, Callback, CallbackWith, Color, Constraint, Dp, FocusScopeNode,
-    FocusTraversalPolicy, LayoutResult, MeasurementError, Modifier, Px, PxPosition, RenderSlot,
-    State, current_frame_nanos,
+    AxisConstraint, Callback, CallbackWith, Color, Constraint, Dp, LayoutResult, MeasurementError,
+    Modifier, Px, PxPosition, RenderSlot, State,
+    accesskit::Role,
+    current_frame_nanos,
     layout::{LayoutPolicy, MeasureScope, layout},
-    modifier::FocusModifierExt as _,
-    provide_context, receive_frame_nanos, remember, tessera, use_context, winit,
+    provide_context, receive_frame_nanos, remember, tessera, use_context,
 };
 
 use crate::{
@@ -20,12 +23,13 @@ use crate::{
     animation,
     column::column,
     fluid_glass::{GlassBorder, fluid_glass},
-    modifier::{ModifierExt, with_keyboard_input, with_pointer_input},
+    modifier::{ModifierExt, SemanticsArgs, SemanticsCustomAction, with_pointer_input},
     nested_scroll::{
         NestedScrollConnection, PostScrollInput, PreFlingInput, PreScrollInput, ScrollDelta,
         ScrollVelocity,
     },
     pos_misc::is_position_inside_bounds,
+    res::strings,
     shape_def::{RoundedCorner, Shape},
     spacer::spacer,
     surface::surface,
@@ -273,33 +277,6 @@ fn render_scrim(style: BottomSheetStyle, on_close_request: Callback, progress: f
     }
 }
 
-/// Create the keyboard handler closure used to close the sheet on Escape.
-fn make_keyboard_closure(
-    on_close: Callback,
-) -> Box<dyn Fn(tessera_ui::KeyboardInput<'_>) + Send + Sync> {
-    Box::new(move |mut input: tessera_ui::KeyboardInput<'_>| {
-        let mut handled = false;
-        input.keyboard_events.retain(|event| {
-            if event.state != winit::event::ElementState::Pressed {
-                return true;
-            }
-
-            if let winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::Escape) =
-                event.physical_key
-            {
-                on_close.call();
-                handled = true;
-                return false;
-            }
-
-            true
-        });
-        if handled {
-            input.block_keyboard();
-        }
-    })
-}
-
 /// Handle drag gestures on the bottom sheet.
 fn handle_drag_gestures(
     controller: State<BottomSheetController>,
@@ -349,11 +326,11 @@ fn place_bottom_sheet_if_present(
     progress: f32,
 ) {
     let children = input.children();
-    if children.len() <= 2 {
+    if children.len() <= 1 {
         return;
     }
 
-    let bottom_sheet = children[2];
+    let bottom_sheet = children[1];
 
     let parent_width = input
         .parent_constraint()
@@ -496,31 +473,26 @@ fn bottom_sheet_content_wrapper(
     bottom_sheet_content: Option<RenderSlot>,
     controller: Option<State<BottomSheetController>>,
     on_close: Option<Callback>,
-    just_opened: Option<bool>,
 ) {
     let style = style.unwrap_or_default();
     let on_close = on_close.unwrap_or_default();
-    let just_opened = just_opened.unwrap_or(false);
     let controller = controller.expect("bottom_sheet_content_wrapper requires controller");
     let bottom_sheet_content =
         bottom_sheet_content.expect("bottom_sheet_content_wrapper requires sheet content");
     let parent_nested_scroll = use_context::<NestedScrollConnection>().map(|context| context.get());
     let nested_scroll_connection =
         build_bottom_sheet_nested_scroll_connection(controller, on_close, parent_nested_scroll);
-    let focus_scope = remember(FocusScopeNode::new).get();
-    let modifier = with_keyboard_input(
-        Modifier::new()
-            .focus_scope_with(focus_scope)
-            .focus_traversal_policy(
-                FocusTraversalPolicy::linear()
-                    .wrap(true)
-                    .tab_navigation(true),
-            ),
-        make_keyboard_closure(on_close),
-    );
-    if just_opened {
-        focus_scope.restore_focus();
-    }
+    let semantics = SemanticsArgs {
+        role: Some(Role::Dialog),
+        merge_descendants: true,
+        on_collapse: Some(on_close),
+        custom_actions: vec![SemanticsCustomAction::new(
+            strings::bottom_sheet_dismiss(),
+            on_close,
+        )],
+        ..Default::default()
+    };
+    let modifier = Modifier::new().semantics(semantics);
     layout().modifier(modifier).child(move || {
         let bottom_sheet_content = bottom_sheet_content;
         let nested_scroll_connection = nested_scroll_connection.clone();
@@ -658,12 +630,6 @@ pub fn bottom_sheet_provider(
     // Snapshot state to minimize locking overhead.
     let (is_open, timer_opt, drag_offset) = controller.with(|c| c.snapshot());
     let is_animating = controller.with(|c| c.is_animating());
-    let bottom_sheet_open_state = remember(|| false);
-    let mut just_opened = false;
-    bottom_sheet_open_state.with_mut(|was_open| {
-        just_opened = !*was_open && is_open;
-        *was_open = is_open;
-    });
     if is_animating {
         receive_frame_nanos(move |frame_nanos| {
             let is_animating = controller.with_mut(|controller| {
@@ -684,30 +650,34 @@ pub fn bottom_sheet_provider(
         });
     }
 
-    if !(is_open || is_animating) {
-        return;
-    }
+    main_content.render();
 
     let progress = calc_progress_from_timer(timer_opt);
 
-    layout()
-        .layout_policy(BottomSheetLayout {
-            progress,
-            is_open,
-            drag_offset,
-        })
-        .child(move || {
-            let bottom_sheet_content = bottom_sheet_content;
-            main_content.render();
-
-            render_scrim(style, on_close_request, progress, is_open);
-
-            bottom_sheet_content_wrapper()
-                .style(style)
-                .bottom_sheet_content_shared(bottom_sheet_content)
-                .controller(controller)
-                .on_close_shared(on_close_request)
-                .just_opened(just_opened);
+    // The sheet positions itself, so the layer fills the host and the scrim
+    // handles outside taps.
+    overlay()
+        .is_open(is_open || is_animating)
+        .modal(true)
+        .dismiss_on_outside(false)
+        .on_dismiss_shared(on_close_request)
+        .position(OverlayPosition::Fill)
+        .content(move || {
+            layout()
+                .layout_policy(BottomSheetLayout {
+                    progress,
+                    is_open,
+                    drag_offset,
+                })
+                .child(move || {
+                    render_scrim(style, on_close_request, progress, is_open);
+
+                    bottom_sheet_content_wrapper()
+                        .style(style)
+                        .bottom_sheet_content_shared(bottom_sheet_content)
+                        .controller(controller)
+                        .on_close_shared(on_close_request);
+                });
         });
 }
 
@@ -721,17 +691,9 @@ struct BottomSheetLayout {
 impl LayoutPolicy for BottomSheetLayout {
     fn measure(&self, input: &MeasureScope<'_>) -> Result<LayoutResult, MeasurementError> {
         let mut result = LayoutResult::default();
-        let children = input.children();
-        let child_constraint = input.parent_constraint().without_min();
-        let main_content = children[0];
-        let main_content_size = main_content.measure(&child_constraint)?;
-        result.place_child(main_content, PxPosition::new(Px(0), Px(0)));
-
-        if children.len() > 1 {
-            let scrim = children[1];
-            scrim.measure(&child_constraint)?;
-            result.place_child(scrim, PxPosition::new(Px(0), Px(0)));
-        }
+        let scrim = input.children()[0];
+        let scrim_size = scrim.measure(input.parent_constraint().as_ref())?;
+        result.place_child(scrim, PxPosition::new(Px(0), Px(0)));
 
         place_bottom_sheet_if_present(
             input,
@@ -741,6 +703,6 @@ impl LayoutPolicy for BottomSheetLayout {
             self.progress,
         );
 
-        Ok(result.with_size(main_content_size.size()))
+        Ok(result.with_size(scrim_size.size()))
     }
 }
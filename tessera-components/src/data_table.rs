@@ -0,0 +1,1864 @@
+//! Material data tables for browsing large sets of tabular data.
+//!
+//! ## Usage
+//!
+//! Show records as rows and columns with sorting, selection and keyboard
+//! navigation, even when there are millions of rows.
+use std::{ops::Range, sync::Arc};
+
+use tessera_foundation::gesture::{DragAxis, DragRecognizer, DragSettings, TapRecognizer};
+use tessera_ui::{
+    AccessibilityActionHandler, AccessibilityActionHandlers, AccessibilityNode, AxisConstraint,
+    CallbackWith, ComputedData, Constraint, Dp, FocusProperties, FocusRequester, LayoutResult,
+    MeasurementError, Modifier, Px, PxPosition, RenderSlotWith, SemanticsModifierNode, State,
+    accesskit::{Action, Role},
+    key,
+    layout::{LayoutPolicy, MeasureScope, PlacementScope, layout},
+    modifier::{CursorModifierExt as _, FocusModifierExt as _, ModifierCapabilityExt as _},
+    remember,
+    shortcut::commands,
+    tessera, use_context, winit,
+};
+
+use crate::{
+    alignment::Alignment,
+    boxed::boxed,
+    column::column,
+    divider::horizontal_divider,
+    icon::icon,
+    lazy_list::{ScrollOwner, remember_scroll_proxy, sync_scroll_proxy},
+    lazy_semantics::{LazyCollectionSemantics, LazySemanticsAxis, reveal_scroll_offset},
+    material_icons::filled,
+    modifier::{ModifierExt as _, with_keyboard_input, with_pointer_input},
+    pos_misc::is_position_inside_bounds,
+    res::strings,
+    row::row,
+    scrollable::{ScrollableController, scrollable},
+    selection::{
+        AnchoredSelection, SelectionActions, SelectionGesture, SelectionMode, SelectionOwner,
+        SelectionSet, selection_gesture,
+    },
+    spacer::spacer,
+    surface::{SurfaceStyle, surface},
+    text::text,
+    theme::{MaterialTheme, provide_text_style},
+};
+
+/// Rows assumed visible before the viewport has been measured.
+const DEFAULT_VIEWPORT_ROWS: usize = 10;
+
+/// Defaults for data tables.
+pub struct DataTableDefaults;
+
+impl DataTableDefaults {
+    /// Height of the header row.
+    pub const HEADER_HEIGHT: Dp = Dp(56.0);
+    /// Height of each data row.
+    pub const ROW_HEIGHT: Dp = Dp(52.0);
+    /// Default column width.
+    pub const COLUMN_WIDTH: Dp = Dp(120.0);
+    /// Default minimum width a column can be resized to.
+    pub const MIN_COLUMN_WIDTH: Dp = Dp(48.0);
+    /// Horizontal padding inside header and data cells.
+    pub const CELL_HORIZONTAL_PADDING: Dp = Dp(16.0);
+    /// Width of the drag handle at the trailing edge of resizable headers.
+    pub const RESIZE_HANDLE_WIDTH: Dp = Dp(8.0);
+    /// Rows composed above and below the viewport.
+    pub const OVERSCAN: usize = 2;
+}
+
+/// Direction of a sorted column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SortDirection {
+    /// Smallest values first.
+    Ascending,
+    /// Largest values first.
+    Descending,
+}
+
+/// Column and direction the table is sorted by.
+///
+/// The table only reports the requested order; the caller sorts its data and
+/// maps row indices accordingly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DataTableSort {
+    /// Index of the sorted column.
+    pub column: usize,
+    /// Sort direction.
+    pub direction: SortDirection,
+}
+
+/// How rows of a [`data_table`] can be selected.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DataTableSelectionMode {
+    /// Rows cannot be selected.
+    #[default]
+    None,
+    /// At most one row is selected.
+    Single,
+    /// Any set of rows is selected with modifier clicks, shift-click ranges
+    /// and keyboard extension.
+    Multiple,
+}
+
+impl From<DataTableSelectionMode> for SelectionMode {
+    fn from(mode: DataTableSelectionMode) -> Self {
+        match mode {
+            DataTableSelectionMode::None => Self::None,
+            DataTableSelectionMode::Single => Self::Single,
+            DataTableSelectionMode::Multiple => Self::Multiple,
+        }
+    }
+}
+
+/// Position of a data cell, passed to the cell renderer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DataTableCell {
+    /// Row index, starting at the first data row.
+    pub row: usize,
+    /// Column index in the order the columns were declared.
+    pub column: usize,
+}
+
+/// A column of a [`data_table`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataTableColumn {
+    /// Header text.
+    pub title: String,
+    /// Initial width.
+    pub width: Dp,
+    /// Smallest width the column can be resized to.
+    pub min_width: Dp,
+    /// Whether the column holds numbers, which are aligned to the end.
+    pub numeric: bool,
+    /// Whether clicking the header changes the sort order.
+    pub sortable: bool,
+    /// Whether the header shows a drag handle to change the width.
+    pub resizable: bool,
+    /// Whether the column stays at the start while scrolling horizontally.
+    pub pinned: bool,
+}
+
+impl DataTableColumn {
+    /// Creates a column titled `title` with the default width.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            width: DataTableDefaults::COLUMN_WIDTH,
+            min_width: DataTableDefaults::MIN_COLUMN_WIDTH,
+            numeric: false,
+            sortable: false,
+            resizable: false,
+            pinned: false,
+        }
+    }
+
+    /// Sets the initial width.
+    pub fn width(mut self, width: Dp) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the smallest width the column can be resized to.
+    pub fn min_width(mut self, min_width: Dp) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Marks the column as numeric.
+    pub fn numeric(mut self, numeric: bool) -> Self {
+        self.numeric = numeric;
+        self
+    }
+
+    /// Sets whether the column can be sorted.
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    /// Sets whether the column can be resized.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// Sets whether the column is pinned to the start of the table.
+    pub fn pinned(mut self, pinned: bool) -> Self {
+        self.pinned = pinned;
+        self
+    }
+}
+
+/// Selected rows of a [`data_table`], stored as sorted, disjoint ranges so
+/// selecting a million rows stays cheap.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DataTableSelection {
+    ranges: Vec<Range<usize>>,
+}
+
+impl DataTableSelection {
+    /// Creates an empty selection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether `row` is selected.
+    pub fn contains(&self, row: usize) -> bool {
+        let index = self.ranges.partition_point(|range| range.end <= row);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.start <= row)
+    }
+
+    /// Returns the number of selected rows.
+    pub fn len(&self) -> usize {
+        self.ranges.iter().map(ExactSizeIterator::len).sum()
+    }
+
+    /// Returns whether no row is selected.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns the selected rows as sorted, disjoint ranges.
+    pub fn ranges(&self) -> &[Range<usize>] {
+        &self.ranges
+    }
+
+    /// Iterates over the selected rows in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.ranges.iter().flat_map(Clone::clone)
+    }
+
+    /// Selects `row`.
+    pub fn insert(&mut self, row: usize) {
+        self.insert_range(row..row + 1);
+    }
+
+    /// Selects every row in `rows`.
+    pub fn insert_range(&mut self, rows: Range<usize>) {
+        if rows.is_empty() {
+            return;
+        }
+        // Ranges that overlap or touch `rows` are merged into it.
+        let start = self.ranges.partition_point(|range| range.end < rows.start);
+        let end = self.ranges.partition_point(|range| range.start <= rows.end);
+        let mut merged = rows;
+        if start < end {
+            merged.start = merged.start.min(self.ranges[start].start);
+            merged.end = merged.end.max(self.ranges[end - 1].end);
+        }
+        self.ranges.splice(start..end, [merged]);
+    }
+
+    /// Deselects `row`.
+    pub fn remove(&mut self, row: usize) {
+        let index = self.ranges.partition_point(|range| range.end <= row);
+        let Some(range) = self.ranges.get(index).cloned() else {
+            return;
+        };
+        if range.start > row {
+            return;
+        }
+        let mut parts = Vec::with_capacity(2);
+        if range.start < row {
+            parts.push(range.start..row);
+        }
+        if row + 1 < range.end {
+            parts.push(row + 1..range.end);
+        }
+        self.ranges.splice(index..index + 1, parts);
+    }
+
+    /// Selects `row` when it is not selected, and deselects it otherwise.
+    pub fn toggle(&mut self, row: usize) {
+        if self.contains(row) {
+            self.remove(row);
+        } else {
+            self.insert(row);
+        }
+    }
+
+    /// Deselects every row.
+    pub fn clear(&mut self) {
+        self.ranges.clear();
+    }
+
+    /// Deselects rows at or past `row_count`.
+    fn truncate(&mut self, row_count: usize) {
+        self.ranges.retain(|range| range.start < row_count);
+        if let Some(last) = self.ranges.last_mut() {
+            last.end = last.end.min(row_count);
+        }
+    }
+}
+
+impl SelectionSet for DataTableSelection {
+    type Item = usize;
+
+    fn clear(&mut self) {
+        DataTableSelection::clear(self);
+    }
+
+    fn insert(&mut self, row: usize) {
+        DataTableSelection::insert(self, row);
+    }
+
+    fn toggle(&mut self, row: usize) {
+        DataTableSelection::toggle(self, row);
+    }
+}
+
+/// Selects the rows from `anchor` to `row`, both included.
+fn select_rows(selection: &mut DataTableSelection, anchor: usize, row: usize) {
+    selection.insert_range(anchor.min(row)..anchor.max(row) + 1);
+}
+
+/// Persistent state for [`data_table`].
+///
+/// Holds the scroll position, user-resized column widths, the sort order,
+/// the selection and the focused cell. A table that is removed and shown
+/// again with the same controller, for example from a retained navigation
+/// entry, comes back exactly as the user left it.
+pub struct DataTableController {
+    scroll: ScrollableController,
+    column_widths: Vec<Option<Px>>,
+    sort: Option<DataTableSort>,
+    selection: AnchoredSelection<DataTableSelection>,
+    focused: Option<DataTableCell>,
+    focus: FocusRequester,
+    pending_reveal: Option<DataTableCell>,
+}
+
+impl Default for DataTableController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DataTableController {
+    /// Creates a controller with no sort, selection or focused cell.
+    pub fn new() -> Self {
+        Self {
+            scroll: ScrollableController::new(),
+            column_widths: Vec::new(),
+            sort: None,
+            selection: AnchoredSelection::default(),
+            focused: None,
+            focus: FocusRequester::new(),
+            pending_reveal: None,
+        }
+    }
+
+    /// Returns a reference to the underlying scroll controller.
+    pub fn scroll_controller(&self) -> &ScrollableController {
+        &self.scroll
+    }
+
+    /// Returns a mutable reference to the underlying scroll controller.
+    pub fn scroll_controller_mut(&mut self) -> &mut ScrollableController {
+        &mut self.scroll
+    }
+
+    /// Returns the current sort order.
+    pub fn sort(&self) -> Option<DataTableSort> {
+        self.sort
+    }
+
+    /// Sets the sort order shown in the header.
+    pub fn set_sort(&mut self, sort: Option<DataTableSort>) {
+        self.sort = sort;
+    }
+
+    /// Returns the selected rows.
+    pub fn selection(&self) -> &DataTableSelection {
+        &self.selection.items
+    }
+
+    /// Replaces the selected rows.
+    pub fn set_selection(&mut self, selection: DataTableSelection) {
+        self.selection.items = selection;
+    }
+
+    /// Returns the cell that has keyboard focus.
+    pub fn focused_cell(&self) -> Option<DataTableCell> {
+        self.focused
+    }
+
+    /// Moves keyboard focus to `cell`.
+    pub fn set_focused_cell(&mut self, cell: Option<DataTableCell>) {
+        self.focused = cell;
+    }
+
+    /// Returns the width the user resized column `index` to.
+    pub fn column_width(&self, index: usize) -> Option<Dp> {
+        self.column_widths
+            .get(index)
+            .copied()
+            .flatten()
+            .map(Px::to_dp)
+    }
+
+    /// Overrides the width of column `index`.
+    pub fn set_column_width(&mut self, index: usize, width: Dp) {
+        self.set_column_width_px(index, Px::from(width));
+    }
+
+    /// Restores every column to its declared width.
+    pub fn reset_column_widths(&mut self) {
+        self.column_widths.clear();
+    }
+
+    /// Scrolls the table until `cell` is fully visible.
+    ///
+    /// Any row can be targeted, composed or not: the table scrolls on its
+    /// next build, once it knows its viewport size.
+    pub fn reveal_cell(&mut self, cell: DataTableCell) {
+        self.pending_reveal = Some(cell);
+    }
+
+    /// Requests keyboard focus for the table.
+    pub fn request_focus(&self) {
+        self.focus.request_focus();
+    }
+
+    fn set_column_width_px(&mut self, index: usize, width: Px) {
+        if self.column_widths.len() <= index {
+            self.column_widths.resize(index + 1, None);
+        }
+        self.column_widths[index] = Some(width);
+    }
+
+    /// Drops state that points past the last row or column.
+    fn clamp_to(&mut self, row_count: usize, column_count: usize) {
+        self.selection.items.truncate(row_count);
+        self.selection.retain_anchor(|row| row < row_count);
+        if self
+            .focused
+            .is_some_and(|cell| cell.row >= row_count || cell.column >= column_count)
+        {
+            self.focused = None;
+        }
+    }
+}
+
+impl SelectionOwner for DataTableController {
+    type Selection = DataTableSelection;
+
+    fn anchored_selection(&self) -> &AnchoredSelection<DataTableSelection> {
+        &self.selection
+    }
+
+    fn anchored_selection_mut(&mut self) -> &mut AnchoredSelection<DataTableSelection> {
+        &mut self.selection
+    }
+}
+
+impl ScrollOwner for DataTableController {
+    fn saved_scroll(&self) -> &ScrollableController {
+        &self.scroll
+    }
+
+    fn saved_scroll_mut(&mut self) -> &mut ScrollableController {
+        &mut self.scroll
+    }
+}
+
+/// Returns the sort after clicking the header of `column`: ascending first,
+/// then descending, then unsorted.
+fn next_sort(current: Option<DataTableSort>, column: usize) -> Option<DataTableSort> {
+    let direction = match current {
+        Some(sort) if sort.column == column => match sort.direction {
+            SortDirection::Ascending => Some(SortDirection::Descending),
+            SortDirection::Descending => None,
+        },
+        _ => Some(SortDirection::Ascending),
+    };
+    direction.map(|direction| DataTableSort { column, direction })
+}
+
+/// # data_table
+///
+/// Render a Material data table that only composes the cells inside the
+/// viewport.
+///
+/// ## Usage
+///
+/// Browse large record sets: the header stays on top while scrolling, pinned
+/// columns stay at the start, and rows are virtualized so millions of them
+/// cost no more than a screenful.
+///
+/// Click a sortable header to cycle its sort order, and drag the trailing edge
+/// of a resizable header to change its width. With a selection mode, clicking
+/// a row selects it; in multiple mode ctrl-click toggles rows and shift-click
+/// selects a range. Arrow keys, Home, End and Page Up/Down move the focused
+/// cell, Space selects its row, Shift extends the selection and Enter
+/// activates the cell.
+///
+/// ## Parameters
+///
+/// - `modifier` — optional modifier chain applied to the table viewport.
+/// - `columns` — column definitions.
+/// - `row_count` — number of data rows.
+/// - `cell` — renders the content of one data cell.
+/// - `selection_mode` — how rows can be selected.
+/// - `row_height` — height of each data row.
+/// - `header_height` — height of the header row.
+/// - `overscan` — rows composed above and below the viewport.
+/// - `controller` — optional external controller for scroll, sort, widths,
+///   selection and focus.
+/// - `on_sort_change` — optional callback receiving the requested sort.
+/// - `on_selection_change` — optional callback receiving the new selection.
+/// - `on_cell_activate` — optional callback invoked when Enter is pressed on
+///   the focused cell.
+///
+/// ## Examples
+///
+/// ```
+/// use tessera_components::{
+///     data_table::{DataTableColumn, data_table},
+///     text::text,
+/// };
+/// use tessera_ui::tessera;
+/// # use tessera_components::theme::{MaterialTheme, material_theme};
+///
+/// #[tessera]
+/// fn demo() {
+/// #   material_theme()
+/// #       .theme(|| MaterialTheme::default())
+/// #       .child(|| {
+///     data_table()
+///         .columns(vec![
+///             DataTableColumn::new("Name").pinned(true),
+///             DataTableColumn::new("Size").numeric(true).sortable(true),
+///         ])
+///         .row_count(1_000_000)
+///         .cell(|cell| {
+///             text().content(format!("{}:{}", cell.row, cell.column));
+///         });
+/// #       });
+/// }
+/// ```
+#[tessera]
+pub fn data_table(
+    modifier: Option<Modifier>,
+    columns: Option<Vec<DataTableColumn>>,
+    row_count: Option<usize>,
+    cell: Option<RenderSlotWith<DataTableCell>>,
+    selection_mode: Option<DataTableSelectionMode>,
+    row_height: Option<Dp>,
+    header_height: Option<Dp>,
+    overscan: Option<usize>,
+    controller: Option<State<DataTableController>>,
+    on_sort_change: Option<CallbackWith<Option<DataTableSort>>>,
+    on_selection_change: Option<CallbackWith<DataTableSelection>>,
+    on_cell_activate: Option<CallbackWith<DataTableCell>>,
+) {
+    let args = DataTableViewArgs {
+        columns: columns.unwrap_or_default(),
+        row_count: row_count.unwrap_or(0),
+        cell: cell.unwrap_or_else(|| RenderSlotWith::new(|_| {})),
+        selection_mode: selection_mode.unwrap_or_default(),
+        row_height: Px::from(row_height.unwrap_or(DataTableDefaults::ROW_HEIGHT)).max(Px(1)),
+        header_height: Px::from(header_height.unwrap_or(DataTableDefaults::HEADER_HEIGHT))
+            .max(Px::ZERO),
+        overscan: overscan.unwrap_or(DataTableDefaults::OVERSCAN),
+        on_sort_change: on_sort_change.unwrap_or_else(CallbackWith::default_value),
+        on_selection_change: on_selection_change.unwrap_or_else(CallbackWith::default_value),
+        on_cell_activate: on_cell_activate.unwrap_or_else(CallbackWith::default_value),
+    };
+    let controller = controller.unwrap_or_else(|| remember(DataTableController::new));
+
+    let scroll_controller = remember_scroll_proxy(controller);
+
+    scrollable()
+        .modifier(modifier.unwrap_or_default())
+        .vertical(true)
+        .horizontal(true)
+        .apply_child_offset(false)
+        .controller(scroll_controller)
+        .child(move || {
+            sync_scroll_proxy(controller, scroll_controller);
+            data_table_view()
+                .args(args.clone())
+                .controller(controller)
+                .scroll_controller(scroll_controller);
+        });
+}
+
+#[derive(Clone, PartialEq)]
+struct DataTableViewArgs {
+    columns: Vec<DataTableColumn>,
+    row_count: usize,
+    cell: RenderSlotWith<DataTableCell>,
+    selection_mode: DataTableSelectionMode,
+    row_height: Px,
+    header_height: Px,
+    overscan: usize,
+    on_sort_change: CallbackWith<Option<DataTableSort>>,
+    on_selection_change: CallbackWith<DataTableSelection>,
+    on_cell_activate: CallbackWith<DataTableCell>,
+}
+
+#[tessera]
+fn data_table_view(
+    args: Option<DataTableViewArgs>,
+    controller: Option<State<DataTableController>>,
+    scroll_controller: Option<State<ScrollableController>>,
+) {
+    let args = args.expect("data_table_view requires args");
+    let controller = controller.expect("data_table_view requires controller");
+    let scroll_controller = scroll_controller.expect("data_table_view requires scroll_controller");
+    let column_count = args.columns.len();
+
+    let needs_clamp = controller.with(|c| {
+        c.focused
+            .is_some_and(|cell| cell.row >= args.row_count || cell.column >= column_count)
+            || c.selection.anchor.is_some_and(|row| row >= args.row_count)
+            || c.selection
+                .items
+                .ranges()
+                .last()
+                .is_some_and(|range| range.end > args.row_count)
+    });
+    if needs_clamp {
+        controller.with_mut(|c| c.clamp_to(args.row_count, column_count));
+    }
+
+    let widths = controller.with(|c| {
+        args.columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                let declared = Px::from(column.width);
+                let width = c.column_widths.get(index).copied().flatten();
+                width.unwrap_or(declared).max(Px::from(column.min_width))
+            })
+            .collect::<Vec<_>>()
+    });
+    let metrics = Arc::new(DataTableMetrics::new(
+        &args.columns,
+        &widths,
+        args.header_height,
+        args.row_height,
+        args.row_count,
+    ));
+
+    let content_size = metrics.content_size();
+    let needs_scroll_child_size_update = scroll_controller.with(|c| c.child_size() != content_size);
+    if needs_scroll_child_size_update {
+        scroll_controller.with_mut(|c| c.override_child_size(content_size));
+    }
+
+    let measured_viewport = scroll_controller.with(|s| s.visible_size());
+    let viewport = ComputedData {
+        width: if measured_viewport.width > Px::ZERO {
+            measured_viewport.width
+        } else {
+            content_size.width
+        },
+        height: if measured_viewport.height > Px::ZERO {
+            measured_viewport.height
+        } else {
+            args.header_height + px_mul(args.row_height, DEFAULT_VIEWPORT_ROWS)
+        },
+    };
+    let position = scroll_controller.with(|s| s.child_position());
+    let scroll_offset = PxPosition::new((-position.x).max(Px::ZERO), (-position.y).max(Px::ZERO));
+
+    let scroll = DataTableScroll {
+        controller,
+        scroll_controller,
+        metrics: metrics.clone(),
+        viewport,
+    };
+    if measured_viewport.height > Px::ZERO
+        && controller.with(|c| c.pending_reveal.is_some())
+        && let Some(cell) = controller.with_mut(|c| c.pending_reveal.take())
+    {
+        scroll.reveal(cell);
+    }
+
+    let actions = DataTableActions {
+        controller,
+        scroll: scroll.clone(),
+        selection: SelectionActions {
+            controller,
+            mode: args.selection_mode.into(),
+            on_change: args.on_selection_change,
+        },
+        on_sort_change: args.on_sort_change,
+        on_cell_activate: args.on_cell_activate,
+    };
+
+    let body_viewport = metrics.body_viewport(viewport);
+    let visible_rows = metrics.visible_rows(scroll_offset.y, body_viewport, args.overscan);
+    let visible_columns = metrics.visible_columns(scroll_offset.x, viewport.width);
+    let (focused, sort) = controller.with(|c| (c.focused, c.sort));
+
+    let mut placements = Vec::new();
+    let mut cells = Vec::new();
+    // Later children draw on top: scrolled body cells, then pinned ones, then
+    // the sticky header and its resize handles.
+    let (pinned_columns, scrolled_columns): (Vec<usize>, Vec<usize>) = visible_columns
+        .iter()
+        .partition(|&&position| position < metrics.pinned_count);
+    for columns in [&scrolled_columns, &pinned_columns] {
+        for row in visible_rows.clone() {
+            for &position in columns {
+                let (x, width) = metrics.cell_x(position, scroll_offset.x);
+                let y = args.header_height + metrics.row_top(row) - scroll_offset.y;
+                placements.push((
+                    PxPosition::new(x, y),
+                    ComputedData {
+                        width,
+                        height: args.row_height,
+                    },
+                ));
+                cells.push(TableChild::Cell { row, position });
+            }
+        }
+    }
+    for columns in [&scrolled_columns, &pinned_columns] {
+        for &position in columns {
+            let (x, width) = metrics.cell_x(position, scroll_offset.x);
+            placements.push((
+                PxPosition::new(x, Px::ZERO),
+                ComputedData {
+                    width,
+                    height: args.header_height,
+                },
+            ));
+            cells.push(TableChild::Header { position });
+        }
+    }
+    let handle_width = Px::from(DataTableDefaults::RESIZE_HANDLE_WIDTH);
+    for &position in &visible_columns {
+        if !args.columns[metrics.order[position]].resizable {
+            continue;
+        }
+        let (x, width) = metrics.cell_x(position, scroll_offset.x);
+        placements.push((
+            PxPosition::new(x + width - handle_width / 2, Px::ZERO),
+            ComputedData {
+                width: handle_width,
+                height: args.header_height,
+            },
+        ));
+        cells.push(TableChild::ResizeHandle { position });
+    }
+
+    let size = ComputedData {
+        width: content_size.width.min(viewport.width),
+        height: content_size.height.min(viewport.height),
+    };
+    let (_, max_scroll_y) = metrics.max_scroll(viewport);
+    let scroll_to = scroll.clone();
+    let semantics = LazyCollectionSemantics {
+        role: Role::Grid,
+        axis: LazySemanticsAxis::Vertical,
+        rows: args.row_count + 1,
+        columns: column_count,
+        hierarchical: false,
+        scroll_offset: scroll_offset.y,
+        max_scroll: max_scroll_y,
+        page: body_viewport,
+        scroll_to: Arc::new(move |offset| {
+            let x = scroll_to.offset().x;
+            scroll_to.scroll_to(PxPosition::new(x, offset));
+        }),
+    };
+
+    let tap_recognizer = remember(TapRecognizer::default);
+    let pointer_actions = actions.clone();
+    let keyboard_actions = actions.clone();
+    let focus = controller.with(|c| c.focus);
+    let mut modifier = Modifier::new()
+        .focus_requester(focus)
+        .focusable()
+        .focus_properties(
+            FocusProperties::new()
+                .can_focus(column_count > 0)
+                .can_request_focus(column_count > 0),
+        );
+    if actions.selection.is_multiple() && args.row_count > 0 {
+        let select_all_actions = actions.clone();
+        modifier = modifier.on_command(commands::SELECT_ALL, move || {
+            select_all_actions.select_all();
+        });
+    }
+    let modifier = with_keyboard_input(
+        with_pointer_input(modifier, move |mut input| {
+            let tap = tap_recognizer.with_mut(|recognizer| {
+                recognizer.update(
+                    input.pass,
+                    input.pointer_changes.as_mut_slice(),
+                    input.cursor_position_rel,
+                    input.cursor_position_rel.is_some_and(|position| {
+                        is_position_inside_bounds(input.computed_data, position)
+                    }),
+                )
+            });
+            if !tap.tapped {
+                return;
+            }
+            let Some(position) = input.cursor_position_rel else {
+                return;
+            };
+            let offset = pointer_actions.scroll.offset();
+            let metrics = &pointer_actions.scroll.metrics;
+            if let (Some(row), Some(column)) = (
+                metrics.row_at(position.y, offset.y),
+                metrics.column_at(position.x, offset.x),
+            ) {
+                let cell = DataTableCell {
+                    row,
+                    column: metrics.order[column],
+                };
+                pointer_actions.click_cell(cell, selection_gesture(input.key_modifiers));
+            }
+        }),
+        move |mut input| {
+            let modifiers = input.key_modifiers;
+            input.keyboard_events.retain(|event| {
+                if event.state != winit::event::ElementState::Pressed {
+                    return true;
+                }
+                match data_table_key_command(&event.logical_key, modifiers) {
+                    Some(command) => !keyboard_actions.apply_key(command),
+                    None => true,
+                }
+            });
+        },
+    )
+    .push_semantics(semantics);
+
+    let columns = args.columns;
+    let cell_content = args.cell;
+    let selection_mode = args.selection_mode;
+    layout()
+        .modifier(modifier)
+        .layout_policy(DataTableLayout { placements, size })
+        .child(move || {
+            for child in &cells {
+                match *child {
+                    TableChild::Cell { row, position } => {
+                        let column = metrics.order[position];
+                        let cell = DataTableCell { row, column };
+                        let selected = (selection_mode != DataTableSelectionMode::None)
+                            .then(|| controller.with(|c| c.selection().contains(row)));
+                        let numeric = columns[column].numeric;
+                        let actions = actions.clone();
+                        let content = cell_content.clone();
+                        key((row, column), move || {
+                            data_table_cell()
+                                .cell(cell)
+                                .position(position)
+                                .numeric(numeric)
+                                .selected_state(selected)
+                                .focused(focused == Some(cell))
+                                .content_shared(content)
+                                .actions(actions);
+                        });
+                    }
+                    TableChild::Header { position } => {
+                        let column = metrics.order[position];
+                        let definition = columns[column].clone();
+                        let direction = sort
+                            .filter(|sort| sort.column == column)
+                            .map(|sort| sort.direction);
+                        let actions = actions.clone();
+                        key(("header", column), move || {
+                            data_table_header_cell()
+                                .definition(definition)
+                                .index(column)
+                                .position(position)
+                                .direction(direction)
+                                .actions(actions);
+                        });
+                    }
+                    TableChild::ResizeHandle { position } => {
+                        let column = metrics.order[position];
+                        let min_width = Px::from(columns[column].min_width);
+                        let actions = actions.clone();
+                        key(("resize", column), move || {
+                            data_table_resize_handle()
+                                .index(column)
+                                .min_width(min_width)
+                                .actions(actions);
+                        });
+                    }
+                }
+            }
+        });
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TableChild {
+    Cell { row: usize, position: usize },
+    Header { position: usize },
+    ResizeHandle { position: usize },
+}
+
+#[tessera]
+fn data_table_cell(
+    cell: Option<DataTableCell>,
+    position: Option<usize>,
+    numeric: Option<bool>,
+    selected_state: Option<Option<bool>>,
+    focused: Option<bool>,
+    content: Option<RenderSlotWith<DataTableCell>>,
+    actions: Option<DataTableActions>,
+) {
+    let cell = cell.expect("data_table_cell requires cell");
+    let position = position.unwrap_or_default();
+    let numeric = numeric.unwrap_or(false);
+    let selected = selected_state.flatten();
+    let focused = focused.unwrap_or(false);
+    let content = content.expect("data_table_cell requires content");
+    let actions = actions.expect("data_table_cell requires actions");
+    let theme = use_context::<MaterialTheme>()
+        .expect("MaterialTheme must be provided")
+        .get();
+    let scheme = theme.color_scheme;
+    let text_style = theme.typography.body_medium;
+
+    let fill_color = if selected == Some(true) {
+        scheme.secondary_container
+    } else {
+        scheme.surface
+    };
+    let style = if focused {
+        SurfaceStyle::FilledOutlined {
+            fill_color,
+            border_color: scheme.primary,
+            border_width: Dp(2.0),
+        }
+    } else {
+        SurfaceStyle::Filled { color: fill_color }
+    };
+    let alignment = if numeric {
+        Alignment::CenterEnd
+    } else {
+        Alignment::CenterStart
+    };
+
+    surface()
+        .style(style)
+        .modifier(
+            Modifier::new()
+                .fill_max_size()
+                .push_semantics(DataTableCellSemantics {
+                    cell,
+                    position,
+                    selected,
+                    actions,
+                }),
+        )
+        .child(move || {
+            column()
+                .modifier(Modifier::new().fill_max_size())
+                .children(move || {
+                    boxed()
+                        .alignment(alignment)
+                        .modifier(
+                            Modifier::new()
+                                .weight(1.0)
+                                .fill_max_width()
+                                .padding_symmetric(
+                                    DataTableDefaults::CELL_HORIZONTAL_PADDING,
+                                    Dp(0.0),
+                                ),
+                        )
+                        .children(move || {
+                            provide_text_style(text_style, || content.render(cell));
+                        });
+                    horizontal_divider();
+                });
+        });
+}
+
+#[tessera]
+fn data_table_header_cell(
+    definition: Option<DataTableColumn>,
+    index: Option<usize>,
+    position: Option<usize>,
+    direction: Option<SortDirection>,
+    actions: Option<DataTableActions>,
+) {
+    let definition = definition.expect("data_table_header_cell requires definition");
+    let index = index.unwrap_or_default();
+    let position = position.unwrap_or_default();
+    let actions = actions.expect("data_table_header_cell requires actions");
+    let theme = use_context::<MaterialTheme>()
+        .expect("MaterialTheme must be provided")
+        .get();
+    let scheme = theme.color_scheme;
+    let text_style = theme.typography.title_small;
+
+    let sortable = definition.sortable;
+    let tap_recognizer = remember(TapRecognizer::default);
+    let sort_actions = actions.clone();
+    let modifier = with_pointer_input(Modifier::new().fill_max_size(), move |mut input| {
+        if !sortable {
+            return;
+        }
+        let tap = tap_recognizer.with_mut(|recognizer| {
+            recognizer.update(
+                input.pass,
+                input.pointer_changes.as_mut_slice(),
+                input.cursor_position_rel,
+                input.cursor_position_rel.is_some_and(|position| {
+                    is_position_inside_bounds(input.computed_data, position)
+                }),
+            )
+        });
+        if tap.tapped {
+            sort_actions.sort_by(index);
+        }
+    })
+    .push_semantics(DataTableHeaderSemantics {
+        title: definition.title.clone(),
+        index,
+        position,
+        sortable,
+        direction,
+        actions,
+    });
+
+    let numeric = definition.numeric;
+    let alignment = if numeric {
+        Alignment::CenterEnd
+    } else {
+        Alignment::CenterStart
+    };
+    let title = definition.title;
+    surface()
+        .style(SurfaceStyle::Filled {
+            color: scheme.surface,
+        })
+        .modifier(modifier)
+        .child(move || {
+            let title = title.clone();
+            column()
+                .modifier(Modifier::new().fill_max_size())
+                .children(move || {
+                    let title = title.clone();
+                    boxed()
+                        .alignment(alignment)
+                        .modifier(
+                            Modifier::new()
+                                .weight(1.0)
+                                .fill_max_width()
+                                .padding_symmetric(
+                                    DataTableDefaults::CELL_HORIZONTAL_PADDING,
+                                    Dp(0.0),
+                                ),
+                        )
+                        .children(move || {
+                            let title = title.clone();
+                            row().children(move || {
+                                // Numeric headers lead with the arrow so the
+                                // title stays aligned with the values.
+                                if numeric {
+                                    sort_indicator(direction, scheme.on_surface);
+                                }
+                                text()
+                                    .content(title.clone())
+                                    .style(text_style)
+                                    .color(scheme.on_surface);
+                                if !numeric {
+                                    sort_indicator(direction, scheme.on_surface);
+                                }
+                            });
+                        });
+                    horizontal_divider();
+                });
+        });
+}
+
+fn sort_indicator(direction: Option<SortDirection>, tint: tessera_ui::Color) {
+    let Some(direction) = direction else {
+        return;
+    };
+    let painter = match direction {
+        SortDirection::Ascending => filled::ARROW_UPWARD_SVG,
+        SortDirection::Descending => filled::ARROW_DOWNWARD_SVG,
+    };
+    spacer().modifier(Modifier::new().width(Dp(4.0)));
+    icon().painter(painter).size(Dp(18.0)).tint(tint);
+    spacer().modifier(Modifier::new().width(Dp(4.0)));
+}
+
+#[tessera]
+fn data_table_resize_handle(
+    index: Option<usize>,
+    min_width: Option<Px>,
+    actions: Option<DataTableActions>,
+) {
+    let index = index.unwrap_or_default();
+    let min_width = min_width.unwrap_or(Px::ZERO);
+    let actions = actions.expect("data_table_resize_handle requires actions");
+    let drag_recognizer = remember(|| {
+        DragRecognizer::new(DragSettings {
+            // Keep the header underneath from treating the drag as a tap.
+            consume_when_dragging: true,
+            axis: Some(DragAxis::Horizontal),
+            ..DragSettings::default()
+        })
+    });
+    let modifier = with_pointer_input(
+        Modifier::new()
+            .fill_max_size()
+            .hover_cursor_icon(winit::window::CursorIcon::EwResize),
+        move |mut input| {
+            let within_bounds = input
+                .cursor_position_rel
+                .is_some_and(|position| is_position_inside_bounds(input.computed_data, position));
+            let drag = drag_recognizer.with_mut(|recognizer| {
+                recognizer.update(
+                    input.pass,
+                    input.pointer_changes.as_mut_slice(),
+                    input.cursor_position_rel,
+                    within_bounds,
+                )
+            });
+            if drag.updated && drag.delta_x != Px::ZERO {
+                actions.resize_by(index, drag.delta_x, min_width);
+            }
+        },
+    );
+    layout().modifier(modifier);
+}
+
+/// Sizes and offsets of the table in display order, where pinned columns come
+/// first.
+///
+/// The table virtualizes both axes itself instead of nesting lazy lists: a
+/// lazy list measures one axis with estimated item sizes, while every row
+/// here has the same height and every column a known width, so the visible
+/// cells, the sticky header and the pinned columns follow from arithmetic
+/// without measuring anything.
+#[derive(Clone, Debug, PartialEq)]
+struct DataTableMetrics {
+    /// Column indices in display order.
+    order: Vec<usize>,
+    /// Start offset of each displayed column, followed by the total width.
+    offsets: Vec<Px>,
+    pinned_count: usize,
+    header_height: Px,
+    row_height: Px,
+    row_count: usize,
+}
+
+impl DataTableMetrics {
+    fn new(
+        columns: &[DataTableColumn],
+        widths: &[Px],
+        header_height: Px,
+        row_height: Px,
+        row_count: usize,
+    ) -> Self {
+        let (mut order, unpinned): (Vec<usize>, Vec<usize>) =
+            (0..columns.len()).partition(|&index| columns[index].pinned);
+        let pinned_count = order.len();
+        order.extend(unpinned);
+        let mut offsets = Vec::with_capacity(order.len() + 1);
+        let mut offset = Px::ZERO;
+        offsets.push(offset);
+        for &index in &order {
+            offset = offset.saturating_add(widths[index]);
+            offsets.push(offset);
+        }
+        Self {
+            order,
+            offsets,
+            pinned_count,
+            header_height,
+            row_height,
+            row_count,
+        }
+    }
+
+    fn total_width(&self) -> Px {
+        self.offsets.last().copied().unwrap_or(Px::ZERO)
+    }
+
+    fn pinned_width(&self) -> Px {
+        self.offsets[self.pinned_count]
+    }
+
+    fn content_size(&self) -> ComputedData {
+        ComputedData {
+            width: self.total_width(),
+            height: self
+                .header_height
+                .saturating_add(px_mul(self.row_height, self.row_count)),
+        }
+    }
+
+    fn body_viewport(&self, viewport: ComputedData) -> Px {
+        (viewport.height - self.header_height).max(Px::ZERO)
+    }
+
+    fn max_scroll(&self, viewport: ComputedData) -> (Px, Px) {
+        let content = self.content_size();
+        (
+            (content.width - viewport.width).max(Px::ZERO),
+            (content.height - viewport.height).max(Px::ZERO),
+        )
+    }
+
+    fn row_top(&self, row: usize) -> Px {
+        px_mul(self.row_height, row)
+    }
+
+    fn display_position(&self, column: usize) -> Option<usize> {
+        self.order.iter().position(|&index| index == column)
+    }
+
+    /// Returns the start and width of the column at display `position`.
+    fn column_span(&self, position: usize) -> (Px, Px) {
+        let start = self.offsets[position];
+        (start, self.offsets[position + 1] - start)
+    }
+
+    /// Returns the on-screen start and width of the column at `position`.
+    fn cell_x(&self, position: usize, scroll_x: Px) -> (Px, Px) {
+        let (start, width) = self.column_span(position);
+        if position < self.pinned_count {
+            (start, width)
+        } else {
+            (start - scroll_x, width)
+        }
+    }
+
+    fn visible_rows(&self, scroll_y: Px, body_viewport: Px, overscan: usize) -> Range<usize> {
+        if self.row_count == 0 {
+            return 0..0;
+        }
+        let row_height = i64::from(self.row_height.0.max(1));
+        let start = i64::from(scroll_y.0.max(0)) / row_height;
+        let end = (i64::from(scroll_y.0.max(0)) + i64::from(body_viewport.0.max(0)) + row_height
+            - 1)
+            / row_height;
+        let start = (start as usize)
+            .saturating_sub(overscan)
+            .min(self.row_count);
+        let end = (end as usize)
+            .saturating_add(overscan)
+            .max(start + 1)
+            .min(self.row_count);
+        start..end
+    }
+
+    /// Returns the display positions of the columns inside the viewport.
+    ///
+    /// Pinned columns are always included.
+    fn visible_columns(&self, scroll_x: Px, viewport_width: Px) -> Vec<usize> {
+        let start = scroll_x + self.pinned_width();
+        let end = scroll_x + viewport_width;
+        (0..self.order.len())
+            .filter(|&position| {
+                if position < self.pinned_count {
+                    return true;
+                }
+                let (column_start, width) = self.column_span(position);
+                column_start + width > start && column_start < end
+            })
+            .collect()
+    }
+
+    /// Returns the data row under `y` in viewport coordinates.
+    fn row_at(&self, y: Px, scroll_y: Px) -> Option<usize> {
+        if y < self.header_height {
+            return None;
+        }
+        let content_y = i64::from((y - self.header_height).0) + i64::from(scroll_y.0);
+        let row = (content_y / i64::from(self.row_height.0.max(1))) as usize;
+        (row < self.row_count).then_some(row)
+    }
+
+    /// Returns the display position of the column under `x` in viewport
+    /// coordinates.
+    fn column_at(&self, x: Px, scroll_x: Px) -> Option<usize> {
+        let (positions, content_x) = if x < self.pinned_width() {
+            (0..self.pinned_count, x)
+        } else {
+            (self.pinned_count..self.order.len(), x + scroll_x)
+        };
+        positions.into_iter().find(|&position| {
+            let (start, width) = self.column_span(position);
+            content_x >= start && content_x < start + width
+        })
+    }
+
+    /// Returns the scroll offset that brings `cell` fully into view, or `None`
+    /// when it is already visible.
+    fn reveal_offset(
+        &self,
+        cell: DataTableCell,
+        offset: PxPosition,
+        viewport: ComputedData,
+    ) -> Option<PxPosition> {
+        let (max_x, max_y) = self.max_scroll(viewport);
+        let y = reveal_scroll_offset(
+            self.row_top(cell.row),
+            self.row_height,
+            offset.y,
+            self.body_viewport(viewport),
+            max_y,
+        )
+        .unwrap_or(offset.y);
+        let x = match self.display_position(cell.column) {
+            // Scrolled columns live right of the pinned ones.
+            Some(position) if position >= self.pinned_count => {
+                let (start, width) = self.column_span(position);
+                let pinned = self.pinned_width();
+                reveal_scroll_offset(
+                    start - pinned,
+                    width,
+                    offset.x,
+                    (viewport.width - pinned).max(Px::ZERO),
+                    max_x,
+                )
+                .unwrap_or(offset.x)
+            }
+            _ => offset.x,
+        };
+        let target = PxPosition::new(x, y);
+        (target != offset).then_some(target)
+    }
+
+    /// Returns how many rows fit in the body viewport.
+    fn page_rows(&self, viewport: ComputedData) -> usize {
+        let rows = self.body_viewport(viewport).0 / self.row_height.0.max(1);
+        (rows.max(1)) as usize
+    }
+}
+
+/// Scroll state captured for keyboard and accessibility requests.
+#[derive(Clone, PartialEq)]
+struct DataTableScroll {
+    controller: State<DataTableController>,
+    scroll_controller: State<ScrollableController>,
+    metrics: Arc<DataTableMetrics>,
+    viewport: ComputedData,
+}
+
+impl DataTableScroll {
+    fn offset(&self) -> PxPosition {
+        let position = self.scroll_controller.with(|s| s.child_position());
+        PxPosition::new((-position.x).max(Px::ZERO), (-position.y).max(Px::ZERO))
+    }
+
+    fn scroll_to(&self, offset: PxPosition) {
+        let position = PxPosition::new(-offset.x, -offset.y);
+        self.scroll_controller
+            .with_mut(|c| c.set_scroll_position(position));
+        self.controller
+            .with_mut(|c| c.scroll.set_scroll_position(position));
+    }
+
+    /// Scrolls until `cell` is fully inside the viewport.
+    fn reveal(&self, cell: DataTableCell) {
+        if let Some(target) = self
+            .metrics
+            .reveal_offset(cell, self.offset(), self.viewport)
+        {
+            self.scroll_to(target);
+        }
+    }
+}
+
+/// Table mutations behind clicks, key presses and assistive technology
+/// actions, so every input path updates the controller and fires the
+/// callbacks the same way.
+#[derive(Clone, PartialEq)]
+struct DataTableActions {
+    controller: State<DataTableController>,
+    scroll: DataTableScroll,
+    selection: SelectionActions<DataTableController>,
+    on_sort_change: CallbackWith<Option<DataTableSort>>,
+    on_cell_activate: CallbackWith<DataTableCell>,
+}
+
+impl DataTableActions {
+    fn focus_cell(&self, cell: DataTableCell) {
+        if self.controller.with(|c| c.focused != Some(cell)) {
+            self.controller.with_mut(|c| c.focused = Some(cell));
+        }
+        self.scroll.reveal(cell);
+    }
+
+    fn select_row(&self, row: usize, gesture: SelectionGesture) {
+        self.selection.select(row, gesture, select_rows);
+    }
+
+    fn click_cell(&self, cell: DataTableCell, gesture: SelectionGesture) {
+        self.controller.with(|c| c.request_focus());
+        self.focus_cell(cell);
+        self.select_row(cell.row, gesture);
+    }
+
+    fn sort_by(&self, column: usize) {
+        let sort = next_sort(self.controller.with(|c| c.sort), column);
+        self.controller.with_mut(|c| c.sort = sort);
+        self.on_sort_change.call(sort);
+    }
+
+    fn resize_by(&self, column: usize, delta: Px, min_width: Px) {
+        let Some(position) = self.scroll.metrics.display_position(column) else {
+            return;
+        };
+        let (_, current) = self.scroll.metrics.column_span(position);
+        self.controller.with_mut(|c| {
+            let current = c
+                .column_widths
+                .get(column)
+                .copied()
+                .flatten()
+                .unwrap_or(current);
+            c.set_column_width_px(column, (current + delta).max(min_width));
+        });
+    }
+
+    fn select_all(&self) {
+        let mut selection = DataTableSelection::new();
+        selection.insert_range(0..self.scroll.metrics.row_count);
+        self.selection.select_all(selection);
+    }
+
+    /// Runs a keyboard command and returns whether it was handled.
+    fn apply_key(&self, command: DataTableKeyCommand) -> bool {
+        let metrics = &self.scroll.metrics;
+        let focused = self.controller.with(|c| c.focused);
+        match command {
+            DataTableKeyCommand::Move { movement, extend } => {
+                let page_rows = metrics.page_rows(self.scroll.viewport);
+                let Some(next) = move_cell(
+                    focused,
+                    movement,
+                    metrics.row_count,
+                    &metrics.order,
+                    page_rows,
+                ) else {
+                    return false;
+                };
+                self.focus_cell(next);
+                if extend && self.selection.is_multiple() {
+                    self.select_row(next.row, SelectionGesture::Extend);
+                } else if !extend {
+                    self.selection.set_anchor(next.row);
+                }
+                true
+            }
+            DataTableKeyCommand::Select { extend } => {
+                let Some(cell) = focused else {
+                    return false;
+                };
+                let gesture = if extend {
+                    SelectionGesture::Extend
+                } else {
+                    SelectionGesture::Toggle
+                };
+                self.select_row(cell.row, gesture);
+                self.selection.is_enabled()
+            }
+            DataTableKeyCommand::Activate => {
+                let Some(cell) = focused else {
+                    return false;
+                };
+                self.on_cell_activate.call(cell);
+                true
+            }
+        }
+    }
+}
+
+struct DataTableCellSemantics {
+    cell: DataTableCell,
+    position: usize,
+    selected: Option<bool>,
+    actions: DataTableActions,
+}
+
+impl SemanticsModifierNode for DataTableCellSemantics {
+    fn apply(
+        &self,
+        accessibility: &mut AccessibilityNode,
+        action_handler: &mut Option<AccessibilityActionHandler>,
+    ) {
+        accessibility.role = Some(Role::GridCell);
+        // Row 0 is the header row.
+        accessibility.collection_item_info = Some((self.cell.row + 1, 1, self.position, 1, false));
+        accessibility.selected = self.selected;
+        if !accessibility.actions.contains(&Action::Click) {
+            accessibility.actions.push(Action::Click);
+        }
+        let actions = self.actions.clone();
+        let cell = self.cell;
+        *action_handler = Some(Box::new(move |action| {
+            if action == Action::Click {
+                actions.click_cell(cell, SelectionGesture::Replace);
+            }
+        }));
+    }
+
+    fn apply_action_handlers(&self, handlers: &mut AccessibilityActionHandlers) {
+        let scroll = self.actions.scroll.clone();
+        let cell = self.cell;
+        handlers.on_scroll_into_view(move || scroll.reveal(cell));
+    }
+}
+
+struct DataTableHeaderSemantics {
+    title: String,
+    index: usize,
+    position: usize,
+    sortable: bool,
+    direction: Option<SortDirection>,
+    actions: DataTableActions,
+}
+
+impl SemanticsModifierNode for DataTableHeaderSemantics {
+    fn apply(
+        &self,
+        accessibility: &mut AccessibilityNode,
+        action_handler: &mut Option<AccessibilityActionHandler>,
+    ) {
+        accessibility.role = Some(Role::ColumnHeader);
+        accessibility.label = Some(self.title.clone());
+        accessibility.description = self.direction.map(|direction| match direction {
+            SortDirection::Ascending => strings::data_table_sorted_ascending(),
+            SortDirection::Descending => strings::data_table_sorted_descending(),
+        });
+        accessibility.collection_item_info = Some((0, 1, self.position, 1, true));
+        if !self.sortable {
+            return;
+        }
+        if !accessibility.actions.contains(&Action::Click) {
+            accessibility.actions.push(Action::Click);
+        }
+        let actions = self.actions.clone();
+        let index = self.index;
+        *action_handler = Some(Box::new(move |action| {
+            if action == Action::Click {
+                actions.sort_by(index);
+            }
+        }));
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CellMove {
+    Up,
+    Down,
+    Left,
+    Right,
+    RowStart,
+    RowEnd,
+    First,
+    Last,
+    PageUp,
+    PageDown,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DataTableKeyCommand {
+    Move { movement: CellMove, extend: bool },
+    Select { extend: bool },
+    Activate,
+}
+
+fn data_table_key_command(
+    key: &winit::keyboard::Key,
+    modifiers: winit::keyboard::ModifiersState,
+) -> Option<DataTableKeyCommand> {
+    use winit::keyboard::{Key, NamedKey};
+
+    let shortcut = modifiers.control_key() || modifiers.super_key();
+    let extend = modifiers.shift_key();
+    let movement = match key {
+        Key::Named(NamedKey::ArrowUp) => CellMove::Up,
+        Key::Named(NamedKey::ArrowDown) => CellMove::Down,
+        Key::Named(NamedKey::ArrowLeft) => CellMove::Left,
+        Key::Named(NamedKey::ArrowRight) => CellMove::Right,
+        Key::Named(NamedKey::Home) if shortcut => CellMove::First,
+        Key::Named(NamedKey::Home) => CellMove::RowStart,
+        Key::Named(NamedKey::End) if shortcut => CellMove::Last,
+        Key::Named(NamedKey::End) => CellMove::RowEnd,
+        Key::Named(NamedKey::PageUp) => CellMove::PageUp,
+        Key::Named(NamedKey::PageDown) => CellMove::PageDown,
+        Key::Named(NamedKey::Space) => return Some(DataTableKeyCommand::Select { extend }),
+        Key::Named(NamedKey::Enter) => return Some(DataTableKeyCommand::Activate),
+        _ => return None,
+    };
+    Some(DataTableKeyCommand::Move { movement, extend })
+}
+
+/// Moves the focused cell in display order, starting at the first cell when
+/// nothing is focused.
+fn move_cell(
+    current: Option<DataTableCell>,
+    movement: CellMove,
+    row_count: usize,
+    order: &[usize],
+    page_rows: usize,
+) -> Option<DataTableCell> {
+    if row_count == 0 || order.is_empty() {
+        return None;
+    }
+    let Some(current) = current else {
+        return Some(DataTableCell {
+            row: 0,
+            column: order[0],
+        });
+    };
+    let last_row = row_count - 1;
+    let last_position = order.len() - 1;
+    let row = current.row.min(last_row);
+    let position = order
+        .iter()
+        .position(|&column| column == current.column)
+        .unwrap_or(0);
+    let (row, position) = match movement {
+        CellMove::Up => (row.saturating_sub(1), position),
+        CellMove::Down => ((row + 1).min(last_row), position),
+        CellMove::Left => (row, position.saturating_sub(1)),
+        CellMove::Right => (row, (position + 1).min(last_position)),
+        CellMove::RowStart => (row, 0),
+        CellMove::RowEnd => (row, last_position),
+        CellMove::First => (0, 0),
+        CellMove::Last => (last_row, last_position),
+        CellMove::PageUp => (row.saturating_sub(page_rows), position),
+        CellMove::PageDown => (row.saturating_add(page_rows).min(last_row), position),
+    };
+    Some(DataTableCell {
+        row,
+        column: order[position],
+    })
+}
+
+/// Places each composed child at a precomputed position with an exact size.
+#[derive(Clone, PartialEq)]
+struct DataTableLayout {
+    placements: Vec<(PxPosition, ComputedData)>,
+    size: ComputedData,
+}
+
+impl LayoutPolicy for DataTableLayout {
+    fn measure(&self, input: &MeasureScope<'_>) -> Result<LayoutResult, MeasurementError> {
+        let mut result = LayoutResult::default();
+        let children = input.children();
+        if children.len() != self.placements.len() {
+            return Err(MeasurementError::MeasureFnFailed(
+                "Data table measured child count mismatch".into(),
+            ));
+        }
+        for (child, (position, size)) in children.iter().copied().zip(&self.placements) {
+            child.measure(&Constraint::new(
+                AxisConstraint::exact(size.width),
+                AxisConstraint::exact(size.height),
+            ))?;
+            result.place_child(child, *position);
+        }
+        Ok(result.with_size(self.size))
+    }
+
+    fn measure_eq(&self, other: &Self) -> bool {
+        self.size == other.size
+            && self.placements.len() == other.placements.len()
+            && self
+                .placements
+                .iter()
+                .zip(&other.placements)
+                .all(|(lhs, rhs)| lhs.1 == rhs.1)
+    }
+
+    fn place_children(&self, input: &PlacementScope<'_>) -> Option<Vec<(u64, PxPosition)>> {
+        let mut result = LayoutResult::default();
+        let children = input.children();
+        if children.len() != self.placements.len() {
+            return None;
+        }
+        for (child, (position, _)) in children.iter().copied().zip(&self.placements) {
+            result.place_child(child, *position);
+        }
+        Some(result.into_placements())
+    }
+}
+
+/// Multiplies `px` by `times`, saturating at the `i32` range of [`Px`].
+///
+/// Row offsets use this, so a table is at most `i32::MAX` pixels tall: about
+/// 41 million rows at the default 52 px row height. Rows past that limit
+/// share the last reachable offset and cannot be scrolled to.
+fn px_mul(px: Px, times: usize) -> Px {
+    let value = i64::from(px.0).saturating_mul(times.min(i64::MAX as usize) as i64);
+    Px(value.clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use winit::keyboard::{Key, ModifiersState, NamedKey, SmolStr};
+
+    use super::*;
+
+    fn metrics(pinned: &[bool], widths: &[i32], rows: usize) -> DataTableMetrics {
+        let columns = pinned
+            .iter()
+            .map(|&pinned| DataTableColumn::new("C").pinned(pinned))
+            .collect::<Vec<_>>();
+        let widths = widths.iter().copied().map(Px).collect::<Vec<_>>();
+        DataTableMetrics::new(&columns, &widths, Px(50), Px(10), rows)
+    }
+
+    #[test]
+    fn selection_merges_and_splits_ranges() {
+        let mut selection = DataTableSelection::new();
+        selection.insert_range(2..5);
+        selection.insert(8);
+        selection.insert_range(5..8);
+        assert_eq!(selection.ranges(), &[2..9]);
+        selection.remove(4);
+        assert_eq!(selection.ranges(), &[2..4, 5..9]);
+        assert_eq!(selection.len(), 6);
+        assert!(selection.contains(5));
+        assert!(!selection.contains(4));
+        selection.toggle(4);
+        assert_eq!(selection.ranges(), &[2..9]);
+        selection.truncate(6);
+        assert_eq!(selection.iter().collect::<Vec<_>>(), vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn extending_selects_row_ranges_from_the_anchor() {
+        let multiple = DataTableSelectionMode::Multiple.into();
+        let mut controller = DataTableController::new();
+        let selection = &mut controller.selection;
+        assert!(selection.apply(3, multiple, SelectionGesture::Replace, select_rows));
+        assert!(selection.apply(7, multiple, SelectionGesture::Extend, select_rows));
+        assert_eq!(selection.items.ranges(), &[3..8]);
+        // Extending again starts from the same anchor.
+        assert!(selection.apply(1, multiple, SelectionGesture::Extend, select_rows));
+        assert_eq!(selection.items.ranges(), &[1..4]);
+        assert!(selection.apply(9, multiple, SelectionGesture::Toggle, select_rows));
+        assert_eq!(controller.selection().ranges(), &[1..4, 9..10]);
+
+        controller.clamp_to(3, 1);
+        assert_eq!(controller.selection().ranges(), &[1..3]);
+        assert_eq!(controller.selection.anchor, None);
+    }
+
+    #[test]
+    fn sorting_cycles_through_directions() {
+        let ascending = next_sort(None, 2);
+        assert_eq!(
+            ascending,
+            Some(DataTableSort {
+                column: 2,
+                direction: SortDirection::Ascending
+            })
+        );
+        let descending = next_sort(ascending, 2);
+        assert_eq!(
+            descending.map(|sort| sort.direction),
+            Some(SortDirection::Descending)
+        );
+        assert_eq!(next_sort(descending, 2), None);
+        assert_eq!(next_sort(descending, 0).map(|sort| sort.column), Some(0));
+    }
+
+    #[test]
+    fn pinned_columns_come_first_and_stay_visible() {
+        let metrics = metrics(&[false, true, false, false], &[100, 40, 100, 100], 5);
+        assert_eq!(metrics.order, vec![1, 0, 2, 3]);
+        assert_eq!(metrics.pinned_width(), Px(40));
+        assert_eq!(metrics.total_width(), Px(340));
+        // Scrolled past the first unpinned column.
+        assert_eq!(metrics.visible_columns(Px(110), Px(130)), vec![0, 2]);
+        assert_eq!(metrics.cell_x(0, Px(110)), (Px(0), Px(40)));
+        assert_eq!(metrics.cell_x(2, Px(110)), (Px(30), Px(100)));
+        assert_eq!(metrics.column_at(Px(20), Px(110)), Some(0));
+        assert_eq!(metrics.column_at(Px(60), Px(110)), Some(2));
+    }
+
+    #[test]
+    fn only_rows_near_the_viewport_are_visible() {
+        let metrics = metrics(&[false], &[100], 5_000_000);
+        assert_eq!(metrics.visible_rows(Px(1_000), Px(45), 0), 100..105);
+        assert_eq!(metrics.visible_rows(Px(1_000), Px(45), 2), 98..107);
+        assert_eq!(metrics.visible_rows(Px::ZERO, Px(45), 2), 0..7);
+        assert_eq!(metrics.row_at(Px(40), Px(1_000)), None);
+        assert_eq!(metrics.row_at(Px(75), Px(1_000)), Some(102));
+        assert_eq!(metrics.content_size().height, Px(50_000_050));
+    }
+
+    #[test]
+    fn reveal_scrolls_both_axes_but_not_for_pinned_columns() {
+        let metrics = metrics(&[true, false, false], &[50, 100, 100], 100);
+        let viewport = ComputedData {
+            width: Px(150),
+            height: Px(100),
+        };
+        let cell = DataTableCell { row: 20, column: 2 };
+        assert_eq!(
+            metrics.reveal_offset(cell, PxPosition::ZERO, viewport),
+            Some(PxPosition::new(Px(100), Px(160)))
+        );
+        let pinned = DataTableCell { row: 0, column: 0 };
+        assert_eq!(
+            metrics.reveal_offset(pinned, PxPosition::new(Px(100), Px::ZERO), viewport),
+            None
+        );
+    }
+
+    #[test]
+    fn keys_map_to_cell_movement_and_selection() {
+        let none = ModifiersState::empty();
+        assert_eq!(
+            data_table_key_command(&Key::Named(NamedKey::ArrowDown), ModifiersState::SHIFT),
+            Some(DataTableKeyCommand::Move {
+                movement: CellMove::Down,
+                extend: true
+            })
+        );
+        assert_eq!(
+            data_table_key_command(&Key::Named(NamedKey::Home), ModifiersState::CONTROL),
+            Some(DataTableKeyCommand::Move {
+                movement: CellMove::First,
+                extend: false
+            })
+        );
+        assert_eq!(
+            data_table_key_command(&Key::Named(NamedKey::Space), none),
+            Some(DataTableKeyCommand::Select { extend: false })
+        );
+        // Select all is bound through the shortcut registry instead.
+        assert_eq!(
+            data_table_key_command(&Key::Character(SmolStr::new("a")), ModifiersState::CONTROL),
+            None
+        );
+    }
+
+    #[test]
+    fn cell_movement_follows_display_order_and_clamps() {
+        let order = [1, 0, 2];
+        let start = move_cell(None, CellMove::Down, 10, &order, 4);
+        assert_eq!(start, Some(DataTableCell { row: 0, column: 1 }));
+        let right = move_cell(start, CellMove::Right, 10, &order, 4);
+        assert_eq!(right, Some(DataTableCell { row: 0, column: 0 }));
+        assert_eq!(
+            move_cell(right, CellMove::PageDown, 10, &order, 4),
+            Some(DataTableCell { row: 4, column: 0 })
+        );
+        assert_eq!(
+            move_cell(right, CellMove::Last, 10, &order, 4),
+            Some(DataTableCell { row: 9, column: 2 })
+        );
+        assert_eq!(move_cell(right, CellMove::Up, 10, &order, 4), right);
+        assert_eq!(move_cell(right, CellMove::Up, 0, &order, 4), None);
+    }
+}
@@ -10,8 +10,8 @@ use std::{
 };
 
 use tessera_ui::{
-    AxisConstraint, Callback, Color, Dp, Modifier, RenderSlot, State, provide_context, remember,
-    tessera, use_context,
+    AxisConstraint, Callback, Color, Dp, Modifier, RenderSlot, State, current_locale,
+    provide_context, remember, tessera, use_context,
 };
 
 use crate::{
@@ -19,6 +19,7 @@ use crate::{
     column::column,
     flow_row::flow_row,
     modifier::ModifierExt as _,
+    res::strings,
     row::row,
     shape_def::Shape,
     spacer::spacer,
@@ -58,6 +59,15 @@ pub enum Weekday {
 }
 
 impl Weekday {
+    /// Returns the first day of the week for the current locale.
+    pub fn locale_default() -> Self {
+        if current_locale().week_starts_on_sunday() {
+            Weekday::Sunday
+        } else {
+            Weekday::Monday
+        }
+    }
+
     fn index_from_monday(self) -> i32 {
         match self {
             Weekday::Monday => 0,
@@ -423,7 +433,8 @@ struct DatePickerConfig {
 /// - `year_range` — year range allowed in the internal state.
 /// - `selectable_dates` — selectable-dates policy used by the internal state.
 /// - `display_mode` — initial display mode for the internal state.
-/// - `first_day_of_week` — first day of the week for the calendar grid.
+/// - `first_day_of_week` — first day of the week for the calendar grid;
+///   defaults to [`Weekday::locale_default`].
 /// - `show_weekday_labels` — whether weekday labels are rendered.
 /// - `show_mode_toggle` — whether the display mode toggle is shown.
 /// - `title` — optional override for the title text.
@@ -468,7 +479,7 @@ pub fn date_picker(
 ) {
     let modifier = modifier.unwrap_or_default();
     let display_mode = display_mode.unwrap_or_default();
-    let first_day_of_week = first_day_of_week.unwrap_or_default();
+    let first_day_of_week = first_day_of_week.unwrap_or_else(Weekday::locale_default);
     let show_weekday_labels = show_weekday_labels.unwrap_or(true);
     let show_mode_toggle = show_mode_toggle.unwrap_or(true);
     let year_range = year_range.unwrap_or(DatePickerDefaults::YEAR_RANGE);
@@ -512,7 +523,7 @@ fn date_picker_inner(args: DatePickerConfig) {
     let show_mode_toggle = args.show_mode_toggle;
     let title_text = args
         .title
-        .unwrap_or_else(|| default_title(snapshot.display_mode).to_string());
+        .unwrap_or_else(|| default_title(snapshot.display_mode));
     let headline_text = args
         .headline
         .unwrap_or_else(|| default_headline(snapshot.selected_date));
@@ -606,7 +617,7 @@ fn date_picker_inner(args: DatePickerConfig) {
 /// - `dismiss_button` — optional dismiss button content.
 /// - `picker_modifier` — modifier chain applied to the embedded picker.
 /// - `picker_first_day_of_week` — first day of the week for the embedded
-///   picker.
+///   picker; defaults to [`Weekday::locale_default`].
 /// - `picker_show_weekday_labels` — whether weekday labels are rendered.
 /// - `picker_show_mode_toggle` — whether the display mode toggle is shown.
 /// - `picker_title` — optional picker title override.
@@ -646,7 +657,7 @@ pub fn date_picker_dialog(
 ) {
     let state = state.unwrap_or_else(|| remember(DatePickerState::default));
     let picker_modifier = picker_modifier.unwrap_or_default();
-    let picker_first_day_of_week = picker_first_day_of_week.unwrap_or_default();
+    let picker_first_day_of_week = picker_first_day_of_week.unwrap_or_else(Weekday::locale_default);
     let picker_show_weekday_labels = picker_show_weekday_labels.unwrap_or(true);
     let picker_show_mode_toggle = picker_show_mode_toggle.unwrap_or(true);
     let scheme = use_context::<MaterialTheme>()
@@ -825,7 +836,7 @@ fn weekday_labels_row(first_day_of_week: Weekday) {
                         .content_alignment(Alignment::Center)
                         .child(move || {
                             text()
-                                .content(label)
+                                .content(label.clone())
                                 .size(
                                     use_context::<MaterialTheme>()
                                         .expect("MaterialTheme must be provided")
@@ -970,7 +981,7 @@ fn input_view(snapshot: DatePickerSnapshot, state: State<DatePickerState>) {
                 let decrement_snapshot = snapshot_year.clone();
                 let increment_snapshot = snapshot_year.clone();
                 input_row(
-                    "Year",
+                    strings::date_picker_year(),
                     format!("{}", current_date.year()),
                     Callback::new(move || {
                         adjust_input_date(state, decrement_snapshot.clone(), InputField::Year, -1);
@@ -987,8 +998,8 @@ fn input_view(snapshot: DatePickerSnapshot, state: State<DatePickerState>) {
                 let decrement_snapshot = snapshot_month.clone();
                 let increment_snapshot = snapshot_month.clone();
                 input_row(
-                    "Month",
-                    format_month_name(current_date.month()).to_string(),
+                    strings::date_picker_month(),
+                    format_month_name(current_date.month()),
                     Callback::new(move || {
                         adjust_input_date(state, decrement_snapshot.clone(), InputField::Month, -1);
                     }),
@@ -1004,7 +1015,7 @@ fn input_view(snapshot: DatePickerSnapshot, state: State<DatePickerState>) {
                 let decrement_snapshot = snapshot_day.clone();
                 let increment_snapshot = snapshot_day.clone();
                 input_row(
-                    "Day",
+                    strings::date_picker_day(),
                     format!("{}", current_date.day()),
                     Callback::new(move || {
                         adjust_input_date(state, decrement_snapshot.clone(), InputField::Day, -1);
@@ -1019,9 +1030,9 @@ fn input_view(snapshot: DatePickerSnapshot, state: State<DatePickerState>) {
             };
             {
                 let description = if snapshot_desc.selected_date.is_some() {
-                    "Use the steppers to adjust the selected date."
+                    strings::date_picker_input_hint_selected()
                 } else {
-                    "Use the steppers to pick a date."
+                    strings::date_picker_input_hint_empty()
                 };
                 text()
                     .content(description)
@@ -1038,7 +1049,7 @@ fn input_view(snapshot: DatePickerSnapshot, state: State<DatePickerState>) {
         });
 }
 
-fn input_row(label: &'static str, value: String, on_decrement: Callback, on_increment: Callback) {
+fn input_row(label: String, value: String, on_decrement: Callback, on_increment: Callback) {
     let scheme = use_context::<MaterialTheme>()
         .expect("MaterialTheme must be provided")
         .get()
@@ -1050,7 +1061,7 @@ fn input_row(label: &'static str, value: String, on_decrement: Callback, on_incr
         .children(move || {
             {
                 text()
-                    .content(label)
+                    .content(label.clone())
                     .size(
                         use_context::<MaterialTheme>()
                             .expect("MaterialTheme must be provided")
@@ -1103,8 +1114,8 @@ fn display_mode_toggle(state: State<DatePickerState>) {
         .get()
         .color_scheme;
     let label = state.with(|s| match s.display_mode() {
-        DatePickerDisplayMode::Picker => "Input",
-        DatePickerDisplayMode::Input => "Calendar",
+        DatePickerDisplayMode::Picker => strings::date_picker_switch_to_input(),
+        DatePickerDisplayMode::Input => strings::date_picker_switch_to_calendar(),
     });
     surface()
         .modifier(Modifier::new().padding_all(Dp(4.0)))
@@ -1118,7 +1129,7 @@ fn display_mode_toggle(state: State<DatePickerState>) {
         })
         .child(move || {
             text()
-                .content(label)
+                .content(label.clone())
                 .size(
                     use_context::<MaterialTheme>()
                         .expect("MaterialTheme must be provided")
@@ -1222,64 +1233,42 @@ enum InputField {
     Day,
 }
 
-fn default_title(mode: DatePickerDisplayMode) -> &'static str {
+fn default_title(mode: DatePickerDisplayMode) -> String {
     match mode {
-        DatePickerDisplayMode::Picker => "Select date",
-        DatePickerDisplayMode::Input => "Enter date",
+        DatePickerDisplayMode::Picker => strings::date_picker_title_picker(),
+        DatePickerDisplayMode::Input => strings::date_picker_title_input(),
     }
 }
 
 fn default_headline(selected: Option<CalendarDate>) -> String {
     selected
         .map(format_selected_date)
-        .unwrap_or_else(|| "No date selected".to_string())
+        .unwrap_or_else(strings::date_picker_no_selection)
 }
 
 fn format_selected_date(date: CalendarDate) -> String {
-    format!(
-        "{} {}, {}",
-        format_month_short_name(date.month()),
+    strings::date_picker_selected_date(
         date.day(),
-        date.year()
+        date.month(),
+        format_month_short_name(date.month()),
+        date.year().to_string(),
     )
 }
 
 fn format_month_year(month: YearMonth) -> String {
-    format!("{} {}", format_month_name(month.month()), month.year())
+    strings::date_picker_month_year(
+        month.month(),
+        format_month_name(month.month()),
+        month.year().to_string(),
+    )
 }
 
-fn format_month_name(month: u8) -> &'static str {
-    match month {
-        1 => "January",
-        2 => "February",
-        3 => "March",
-        4 => "April",
-        5 => "May",
-        6 => "June",
-        7 => "July",
-        8 => "August",
-        9 => "September",
-        10 => "October",
-        11 => "November",
-        _ => "December",
-    }
+fn format_month_name(month: u8) -> String {
+    strings::month_name(month)
 }
 
-fn format_month_short_name(month: u8) -> &'static str {
-    match month {
-        1 => "Jan",
-        2 => "Feb",
-        3 => "Mar",
-        4 => "Apr",
-        5 => "May",
-        6 => "Jun",
-        7 => "Jul",
-        8 => "Aug",
-        9 => "Sep",
-        10 => "Oct",
-        11 => "Nov",
-        _ => "Dec",
-    }
+fn format_month_short_name(month: u8) -> String {
+    strings::month_short_name(month)
 }
 
 fn weekday_sequence(first_day_of_week: Weekday) -> [Weekday; DATE_COLUMNS] {
@@ -1291,16 +1280,16 @@ fn weekday_sequence(first_day_of_week: Weekday) -> [Weekday; DATE_COLUMNS] {
     days
 }
 
-fn weekday_short_label(day: Weekday) -> &'static str {
-    match day {
-        Weekday::Monday => "Mon",
-        Weekday::Tuesday => "Tue",
-        Weekday::Wednesday => "Wed",
-        Weekday::Thursday => "Thu",
-        Weekday::Friday => "Fri",
-        Weekday::Saturday => "Sat",
-        Weekday::Sunday => "Sun",
-    }
+fn weekday_short_label(day: Weekday) -> String {
+    strings::weekday_short_name(match day {
+        Weekday::Monday => "mon",
+        Weekday::Tuesday => "tue",
+        Weekday::Wednesday => "wed",
+        Weekday::Thursday => "thu",
+        Weekday::Friday => "fri",
+        Weekday::Saturday => "sat",
+        Weekday::Sunday => "sun",
+    })
 }
 
 fn build_month_grid(month: YearMonth, first_day_of_week: Weekday) -> Vec<Option<CalendarDate>> {
@@ -6,22 +6,23 @@
 //! dialogs block interaction with underlying content while active.
 use std::time::Duration;
 
+use tessera_foundation::{
+    alignment::Alignment,
+    overlay::{OverlayPosition, overlay},
+};
 use tessera_ui::{
-    AxisConstraint, Callback, Color, ComputedData, Dp, FocusScopeNode, FocusTraversalPolicy,
-    LayoutResult, MeasurementError, Modifier, Px, PxPosition, RenderSlot, State,
-    current_frame_nanos,
+    AxisConstraint, Callback, Color, ComputedData, Dp, LayoutResult, MeasurementError, Modifier,
+    Px, PxPosition, RenderSlot, State, current_frame_nanos,
     layout::{LayoutPolicy, MeasureScope, RenderInput, RenderPolicy, layout},
-    modifier::FocusModifierExt as _,
-    provide_context, receive_frame_nanos, remember, tessera, use_context, winit,
+    provide_context, receive_frame_nanos, remember, tessera, use_context,
 };
 
 use crate::{
-    alignment::{Alignment, CrossAxisAlignment, MainAxisAlignment},
+    alignment::{CrossAxisAlignment, MainAxisAlignment},
     animation,
-    boxed::boxed,
     column::column,
     fluid_glass::{GlassBorder, fluid_glass},
-    modifier::{ModifierExt, with_keyboard_input},
+    modifier::ModifierExt,
     row::row,
     shape_def::{RoundedCorner, Shape},
     spacer::spacer,
@@ -152,15 +153,13 @@ impl Default for DialogController {
     }
 }
 
-fn render_scrim(style: DialogStyle, on_close_request: Callback, is_open: bool, progress: f32) {
+fn render_scrim(style: DialogStyle, is_open: bool, progress: f32) {
     match style {
         DialogStyle::Glass => {
             let blur_radius = blur_radius_for(progress, is_open, 5.0);
             fluid_glass()
-                .on_click_shared(on_close_request)
                 .tint_color(Color::TRANSPARENT)
                 .modifier(Modifier::new().fill_max_size())
-                .block_input(true)
                 .blur_radius(Dp(blur_radius as f64))
                 .border(GlassBorder::new(Px(0)))
                 .shape(Shape::RoundedRectangle {
@@ -181,135 +180,82 @@ fn render_scrim(style: DialogStyle, on_close_request: Callback, is_open: bool, p
                 .scrim;
             surface()
                 .style(scrim_color.with_alpha(alpha).into())
-                .on_click_shared(on_close_request)
                 .modifier(Modifier::new().fill_max_size())
-                .block_input(true)
                 .child(|| {});
         }
     }
 }
 
-fn make_keyboard_handler(
-    on_close: Callback,
-) -> Box<dyn for<'a> Fn(tessera_ui::KeyboardInput<'a>) + Send + Sync + 'static> {
-    Box::new(move |mut input| {
-        let mut handled = false;
-        input.keyboard_events.retain(|event| {
-            if event.state != winit::event::ElementState::Pressed {
-                return true;
-            }
-
-            if let winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::Escape) =
-                event.physical_key
-            {
-                on_close.call();
-                handled = true;
-                return false;
-            }
-
-            true
-        });
-        if handled {
-            input.block_keyboard();
-        }
-    })
-}
-
 #[tessera]
 fn dialog_content_wrapper(
     style: Option<DialogStyle>,
     alpha: Option<f32>,
     padding: Option<Dp>,
-    just_opened: Option<bool>,
-    on_close_request: Option<Callback>,
     content: Option<RenderSlot>,
 ) {
     let style = style.unwrap_or_default();
     let alpha = alpha.unwrap_or(1.0);
     let padding = padding.unwrap_or(Dp(24.0));
-    let just_opened = just_opened.unwrap_or(false);
-    let on_close_request = on_close_request.unwrap_or_default();
     let content = content.expect("dialog_content_wrapper requires content");
-    let focus_scope = remember(FocusScopeNode::new).get();
-    let modifier = with_keyboard_input(
-        Modifier::new()
-            .focus_scope_with(focus_scope)
-            .focus_traversal_policy(
-                FocusTraversalPolicy::linear()
-                    .wrap(true)
-                    .tab_navigation(true),
-            ),
-        make_keyboard_handler(on_close_request),
-    );
-    if just_opened {
-        focus_scope.restore_focus();
-    }
     let policy = DialogContentLayout { alpha };
     layout()
-        .modifier(modifier)
         .layout_policy(policy.clone())
         .render_policy(policy)
         .child(move || {
             let content = content;
-            boxed()
-                .modifier(Modifier::new().fill_max_size())
-                .alignment(Alignment::Center)
-                .children(move || {
-                    let content = content;
-                    surface()
-                        .style(Color::TRANSPARENT.into())
-                        .modifier(
-                            Modifier::new()
-                                .constrain(Some(AxisConstraint::NONE), Some(AxisConstraint::NONE))
-                                .padding_all(Dp(24.0)),
-                        )
-                        .child(move || match style {
-                            DialogStyle::Glass => {
+            surface()
+                .style(Color::TRANSPARENT.into())
+                .modifier(
+                    Modifier::new()
+                        .constrain(Some(AxisConstraint::NONE), Some(AxisConstraint::NONE))
+                        .padding_all(Dp(24.0)),
+                )
+                .child(move || match style {
+                    DialogStyle::Glass => {
+                        let content = content;
+                        fluid_glass()
+                            .tint_color(Color::WHITE.with_alpha(alpha / 2.5))
+                            .blur_radius(Dp(5.0 * alpha as f64))
+                            .shape(Shape::RoundedRectangle {
+                                top_left: RoundedCorner::manual(Dp(28.0), 3.0),
+                                top_right: RoundedCorner::manual(Dp(28.0), 3.0),
+                                bottom_right: RoundedCorner::manual(Dp(28.0), 3.0),
+                                bottom_left: RoundedCorner::manual(Dp(28.0), 3.0),
+                            })
+                            .block_input(true)
+                            .padding(padding)
+                            .child(move || {
+                                content.render();
+                            });
+                    }
+                    DialogStyle::Material => {
+                        let content = content;
+                        surface()
+                            .style(
+                                use_context::<MaterialTheme>()
+                                    .expect("MaterialTheme must be provided")
+                                    .get()
+                                    .color_scheme
+                                    .surface_container_high
+                                    .into(),
+                            )
+                            .elevation(Dp(6.0))
+                            .shape(Shape::RoundedRectangle {
+                                top_left: RoundedCorner::manual(Dp(28.0), 3.0),
+                                top_right: RoundedCorner::manual(Dp(28.0), 3.0),
+                                bottom_right: RoundedCorner::manual(Dp(28.0), 3.0),
+                                bottom_left: RoundedCorner::manual(Dp(28.0), 3.0),
+                            })
+                            .block_input(true)
+                            .child(move || {
                                 let content = content;
-                                fluid_glass()
-                                    .tint_color(Color::WHITE.with_alpha(alpha / 2.5))
-                                    .blur_radius(Dp(5.0 * alpha as f64))
-                                    .shape(Shape::RoundedRectangle {
-                                        top_left: RoundedCorner::manual(Dp(28.0), 3.0),
-                                        top_right: RoundedCorner::manual(Dp(28.0), 3.0),
-                                        bottom_right: RoundedCorner::manual(Dp(28.0), 3.0),
-                                        bottom_left: RoundedCorner::manual(Dp(28.0), 3.0),
-                                    })
-                                    .block_input(true)
-                                    .padding(padding)
+                                layout()
+                                    .modifier(Modifier::new().padding_all(padding))
                                     .child(move || {
                                         content.render();
                                     });
-                            }
-                            DialogStyle::Material => {
-                                let content = content;
-                                surface()
-                                    .style(
-                                        use_context::<MaterialTheme>()
-                                            .expect("MaterialTheme must be provided")
-                                            .get()
-                                            .color_scheme
-                                            .surface_container_high
-                                            .into(),
-                                    )
-                                    .elevation(Dp(6.0))
-                                    .shape(Shape::RoundedRectangle {
-                                        top_left: RoundedCorner::manual(Dp(28.0), 3.0),
-                                        top_right: RoundedCorner::manual(Dp(28.0), 3.0),
-                                        bottom_right: RoundedCorner::manual(Dp(28.0), 3.0),
-                                        bottom_left: RoundedCorner::manual(Dp(28.0), 3.0),
-                                    })
-                                    .block_input(true)
-                                    .child(move || {
-                                        let content = content;
-                                        layout()
-                                            .modifier(Modifier::new().padding_all(padding))
-                                            .child(move || {
-                                                content.render();
-                                            });
-                                    });
-                            }
-                        });
+                            });
+                    }
                 });
         });
 }
@@ -349,6 +295,9 @@ impl RenderPolicy for DialogContentLayout {
 ///
 /// Show modal content for alerts, confirmation dialogs, multi-step forms, or
 /// onboarding steps that require blocking user interaction with the main UI.
+/// The dialog opens in the nearest overlay host, so it covers the whole window
+/// even when the provider is nested, and closes on Escape, Back or a tap on
+/// the scrim through `on_close_request`.
 ///
 /// # Parameters
 ///
@@ -441,19 +390,12 @@ fn dialog_provider_render(
     let controller = controller.expect("dialog_provider_render requires controller");
     let main_content = main_content.unwrap_or_else(RenderSlot::empty);
     let dialog_content = dialog_content.unwrap_or_else(RenderSlot::empty);
-    let dialog_open_state = remember(|| false);
 
     // Render the main application content unconditionally.
     main_content.render();
 
-    // If the dialog is open, render the modal overlay.
     // Sample state once to avoid repeated locks and improve readability.
     let (is_open, timer_opt) = controller.with(|c| c.snapshot());
-    let mut just_opened = false;
-    dialog_open_state.with_mut(|was_open| {
-        just_opened = !*was_open && is_open;
-        *was_open = is_open;
-    });
 
     let is_animating = controller.with(|c| c.is_animating());
     if is_animating {
@@ -476,25 +418,27 @@ fn dialog_provider_render(
         });
     }
 
-    if is_open || is_animating {
-        let progress = animation::easing(compute_dialog_progress(timer_opt));
-
-        let content_alpha = if is_open {
-            progress * 1.0 // Transition from 0 to 1 alpha
-        } else {
-            1.0 * (1.0 - progress) // Transition from 1 to 0 alpha
-        };
-
-        render_scrim(style, on_close_request, is_open, progress);
+    let progress = animation::easing(compute_dialog_progress(timer_opt));
+    let content_alpha = if is_open {
+        progress * 1.0 // Transition from 0 to 1 alpha
+    } else {
+        1.0 * (1.0 - progress) // Transition from 1 to 0 alpha
+    };
 
-        dialog_content_wrapper()
-            .style(style)
-            .alpha(content_alpha)
-            .padding(padding)
-            .just_opened(just_opened)
-            .on_close_request_shared(on_close_request)
-            .content_shared(dialog_content);
-    }
+    // The overlay layer handles focus trapping, Escape and outside taps.
+    overlay()
+        .is_open(is_open || is_animating)
+        .modal(true)
+        .on_dismiss_shared(on_close_request)
+        .position(OverlayPosition::Aligned(Alignment::Center))
+        .scrim(move || render_scrim(style, is_open, progress))
+        .content(move || {
+            dialog_content_wrapper()
+                .style(style)
+                .alpha(content_alpha)
+                .padding(padding)
+                .content_shared(dialog_content);
+        });
 }
 
 /// # basic_dialog
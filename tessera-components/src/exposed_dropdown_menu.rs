@@ -0,0 +1,1249 @@
+//! Exposed dropdown menus for choosing values from a list of options.
+//!
+//! ## Usage
+//!
+//! Use in forms to pick one or more options, either from a read-only field or
+//! by typing into the field to filter the list.
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+use tessera_foundation::{
+    gesture::TapRecognizer,
+    overlay::{OverlayPosition, PopupPlacement, overlay},
+};
+use tessera_ui::{
+    AccessibilityActionHandler, AccessibilityNode, AxisConstraint, Callback, CallbackWith, Color,
+    CursorEventContent, Dp, Modifier, PressKeyEventType, Px, PxPosition, PxRect,
+    SemanticsModifierNode, State,
+    accesskit::{Action, Role},
+    current_frame_nanos,
+    layout::layout,
+    modifier::ModifierCapabilityExt as _,
+    remember, remember_bounds, tessera,
+    time::Instant,
+    use_context, winit,
+};
+
+use crate::{
+    alignment::CrossAxisAlignment,
+    checkmark::checkmark,
+    chip::chip,
+    column::column,
+    flow_row::flow_row,
+    icon::icon,
+    lazy_list::{LazyListController, lazy_column},
+    material_icons::filled,
+    menus::{
+        MENU_HORIZONTAL_PADDING, MENU_ITEM_HEIGHT, MENU_LEADING_SIZE, MENU_MAX_HEIGHT,
+        MENU_VERTICAL_GAP, default_menu_color, default_menu_shape,
+    },
+    modifier::{
+        ModifierExt as _, SemanticsArgs, with_keyboard_preview_input, with_pointer_input,
+        with_pointer_preview_input,
+    },
+    pos_misc::is_position_inside_bounds,
+    res::strings,
+    row::row,
+    spacer::spacer,
+    surface::{SurfaceStyle, surface},
+    text::text,
+    text_field::{TextFieldBuilder, TextFieldLineLimit},
+    text_input::TextInputController,
+    theme::{MaterialAlpha, MaterialTheme},
+};
+
+static NEXT_DROPDOWN_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Accessibility key of the option list of the dropdown `id`.
+fn listbox_key(id: u64) -> String {
+    format!("exposed-dropdown-{id}-listbox")
+}
+
+/// Accessibility key of the option at `index` of the dropdown `id`.
+fn option_key(id: u64, index: usize) -> String {
+    format!("exposed-dropdown-{id}-option-{index}")
+}
+
+/// Defaults for exposed dropdown menus.
+pub struct ExposedDropdownMenuDefaults;
+
+impl ExposedDropdownMenuDefaults {
+    /// Default maximum height of the option list before it scrolls.
+    pub const MENU_MAX_HEIGHT: Dp = MENU_MAX_HEIGHT;
+    /// Default spacing between selection chips.
+    pub const CHIP_SPACING: Dp = Dp(8.0);
+    /// Pause after which typed characters start a new type-ahead search.
+    pub const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_millis(1000);
+}
+
+/// Container style of the field of an [`exposed_dropdown_menu`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ExposedDropdownMenuStyle {
+    /// Filled text field container.
+    #[default]
+    Filled,
+    /// Outlined text field container.
+    Outlined,
+}
+
+/// An option offered by an [`exposed_dropdown_menu`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropdownOption {
+    /// Text shown in the menu, in the field and on selection chips.
+    pub label: String,
+    /// Optional secondary text shown below the label in the menu.
+    pub supporting_text: Option<String>,
+    /// Whether the option can be highlighted and selected.
+    pub enabled: bool,
+}
+
+impl DropdownOption {
+    /// Creates an enabled option labelled `label`.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            supporting_text: None,
+            enabled: true,
+        }
+    }
+
+    /// Sets the secondary text shown below the label.
+    pub fn supporting_text(mut self, supporting_text: impl Into<String>) -> Self {
+        self.supporting_text = Some(supporting_text.into());
+        self
+    }
+
+    /// Sets whether the option can be selected.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+impl From<&str> for DropdownOption {
+    fn from(label: &str) -> Self {
+        Self::new(label)
+    }
+}
+
+impl From<String> for DropdownOption {
+    fn from(label: String) -> Self {
+        Self::new(label)
+    }
+}
+
+/// Expanded state, filter query and keyboard highlight of an
+/// [`exposed_dropdown_menu`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExposedDropdownMenuController {
+    expanded: bool,
+    query: Option<String>,
+    highlighted: Option<usize>,
+    type_ahead: String,
+    type_ahead_at: Option<Instant>,
+}
+
+impl ExposedDropdownMenuController {
+    /// Creates a collapsed controller.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opens the option list.
+    pub fn expand(&mut self) {
+        self.expanded = true;
+    }
+
+    /// Closes the option list and discards the filter query.
+    pub fn collapse(&mut self) {
+        self.expanded = false;
+        self.query = None;
+        self.type_ahead.clear();
+        self.type_ahead_at = None;
+    }
+
+    /// Toggles the option list.
+    pub fn toggle(&mut self) {
+        if self.expanded {
+            self.collapse();
+        } else {
+            self.expand();
+        }
+    }
+
+    /// Returns whether the option list is open.
+    pub fn is_expanded(&self) -> bool {
+        self.expanded
+    }
+
+    /// Returns the text typed to filter the options, or `None` when nothing
+    /// was typed since the last selection.
+    pub fn query(&self) -> Option<&str> {
+        self.query.as_deref()
+    }
+
+    /// Filters the options by `query`.
+    pub fn set_query(&mut self, query: impl Into<String>) {
+        self.query = Some(query.into());
+    }
+
+    /// Returns the index of the option highlighted for keyboard selection.
+    pub fn highlighted(&self) -> Option<usize> {
+        self.highlighted
+    }
+
+    /// Highlights the option at `index` for keyboard selection.
+    pub fn set_highlighted(&mut self, index: Option<usize>) {
+        self.highlighted = index;
+    }
+
+    fn is_typing_ahead(&self, now: Instant) -> bool {
+        self.type_ahead_at.is_some_and(|at| {
+            now.saturating_duration_since(at) <= ExposedDropdownMenuDefaults::TYPE_AHEAD_TIMEOUT
+        })
+    }
+
+    /// Appends `text` to the type-ahead buffer, starting over after a pause,
+    /// and returns the buffer.
+    fn push_type_ahead(&mut self, text: &str, now: Instant) -> String {
+        if !self.is_typing_ahead(now) {
+            self.type_ahead.clear();
+        }
+        self.type_ahead.push_str(text);
+        self.type_ahead_at = Some(now);
+        self.type_ahead.clone()
+    }
+}
+
+/// # exposed_dropdown_menu
+///
+/// Render a Material exposed dropdown menu: a text field that opens a list of
+/// options below it.
+///
+/// ## Usage
+///
+/// Pick one value from a read-only field, or type into an `editable` field to
+/// filter the options. With `multiple`, every selected option is shown as a
+/// removable chip below the field.
+///
+/// The field keeps focus while the list is open. Arrow keys move the
+/// highlight, Enter selects it and Escape closes the list; a read-only field
+/// also jumps to options by typing the start of their label.
+///
+/// ## Parameters
+///
+/// - `modifier` — optional modifier chain applied to the dropdown.
+/// - `enabled` — whether the dropdown accepts input.
+/// - `editable` — whether typing into the field filters the options.
+/// - `multiple` — whether several options can be selected.
+/// - `style` — field container style.
+/// - `label` — optional field label.
+/// - `placeholder` — optional placeholder shown when the field is empty.
+/// - `options` — options offered in the list.
+/// - `selected` — indices of the selected options.
+/// - `on_selection_change` — optional callback receiving the new selection.
+/// - `menu_max_height` — maximum height of the option list before it scrolls.
+/// - `controller` — optional external controller for expanded and query state.
+///
+/// ## Examples
+///
+/// ```
+/// # use tessera_ui::tessera;
+/// # #[tessera]
+/// # fn component() {
+/// use tessera_components::exposed_dropdown_menu::{DropdownOption, exposed_dropdown_menu};
+/// use tessera_ui::remember;
+/// # use tessera_components::theme::{MaterialTheme, material_theme};
+///
+/// # material_theme()
+/// #     .theme(|| MaterialTheme::default())
+/// #     .child(|| {
+/// let selected = remember(Vec::<usize>::new);
+/// exposed_dropdown_menu()
+///     .label("Fruit")
+///     .options(vec![
+///         DropdownOption::new("Apple"),
+///         DropdownOption::new("Banana"),
+///     ])
+///     .selected(selected.get())
+///     .on_selection_change(move |next| selected.set(next));
+/// #     });
+/// # }
+/// # component();
+/// ```
+#[tessera]
+pub fn exposed_dropdown_menu(
+    modifier: Option<Modifier>,
+    enabled: Option<bool>,
+    editable: Option<bool>,
+    multiple: Option<bool>,
+    style: Option<ExposedDropdownMenuStyle>,
+    #[prop(into)] label: Option<String>,
+    #[prop(into)] placeholder: Option<String>,
+    options: Option<Vec<DropdownOption>>,
+    selected: Option<Vec<usize>>,
+    on_selection_change: Option<CallbackWith<Vec<usize>>>,
+    menu_max_height: Option<Dp>,
+    controller: Option<State<ExposedDropdownMenuController>>,
+) {
+    let modifier = modifier.unwrap_or_default();
+    let options = options.unwrap_or_default();
+    let args = DropdownArgs {
+        enabled: enabled.unwrap_or(true),
+        editable: editable.unwrap_or(false),
+        multiple: multiple.unwrap_or(false),
+        style: style.unwrap_or_default(),
+        label,
+        placeholder,
+        selected: selected
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|&index| index < options.len())
+            .collect(),
+        options,
+        on_selection_change: on_selection_change.unwrap_or_else(CallbackWith::default_value),
+        menu_max_height: menu_max_height.unwrap_or(ExposedDropdownMenuDefaults::MENU_MAX_HEIGHT),
+    };
+    let controller = controller.unwrap_or_else(|| remember(ExposedDropdownMenuController::new));
+
+    layout().modifier(modifier).child(move || {
+        let args = args.clone();
+        column()
+            .modifier(Modifier::new().fill_max_width())
+            .cross_axis_alignment(CrossAxisAlignment::Start)
+            .children(move || {
+                exposed_dropdown_field()
+                    .args(args.clone())
+                    .controller(controller);
+                if args.multiple && !args.selected.is_empty() {
+                    spacer().modifier(
+                        Modifier::new().height(ExposedDropdownMenuDefaults::CHIP_SPACING),
+                    );
+                    render_selection_chips(&args);
+                }
+            });
+    });
+}
+
+#[derive(Clone, PartialEq)]
+struct DropdownArgs {
+    enabled: bool,
+    editable: bool,
+    multiple: bool,
+    style: ExposedDropdownMenuStyle,
+    label: Option<String>,
+    placeholder: Option<String>,
+    options: Vec<DropdownOption>,
+    selected: Vec<usize>,
+    on_selection_change: CallbackWith<Vec<usize>>,
+    menu_max_height: Dp,
+}
+
+fn render_selection_chips(args: &DropdownArgs) {
+    let options = args.options.clone();
+    let selected = args.selected.clone();
+    let enabled = args.enabled;
+    let on_selection_change = args.on_selection_change;
+    flow_row()
+        .item_spacing(ExposedDropdownMenuDefaults::CHIP_SPACING)
+        .line_spacing(ExposedDropdownMenuDefaults::CHIP_SPACING)
+        .children(move || {
+            for &index in &selected {
+                let label = options[index].label.clone();
+                let remaining = toggle_selection(&selected, index, true);
+                chip()
+                    .input(label.clone())
+                    .trailing_icon(filled::CLOSE_SVG)
+                    .enabled(enabled)
+                    .accessibility_label(strings::exposed_dropdown_menu_remove(label))
+                    .on_click(move || on_selection_change.call(remaining.clone()));
+            }
+        });
+}
+
+/// Opens, closes and updates the dropdown; the field's taps and keys, the
+/// options and the combo box's expand and collapse actions all go through
+/// it so the list and the field stay in step.
+#[derive(Clone)]
+struct DropdownActions {
+    options: Arc<Vec<DropdownOption>>,
+    selected: Arc<Vec<usize>>,
+    multiple: bool,
+    controller: State<ExposedDropdownMenuController>,
+    list_controller: State<LazyListController>,
+    on_selection_change: CallbackWith<Vec<usize>>,
+}
+
+impl DropdownActions {
+    fn visible_options(&self) -> Vec<usize> {
+        self.controller
+            .with(|c| filter_options(&self.options, c.query()))
+    }
+
+    fn expand(&self) {
+        if self.options.is_empty() || self.controller.with(|c| c.is_expanded()) {
+            return;
+        }
+        self.controller.with_mut(|c| c.expand());
+        let visible = self.visible_options();
+        let initial = self
+            .selected
+            .iter()
+            .copied()
+            .find(|index| visible.contains(index) && self.options[*index].enabled);
+        self.highlight(initial, &visible);
+    }
+
+    fn collapse(&self) {
+        if self.controller.with(|c| c.is_expanded()) {
+            self.controller.with_mut(|c| c.collapse());
+        }
+    }
+
+    fn highlight(&self, index: Option<usize>, visible: &[usize]) {
+        if self.controller.with(|c| c.highlighted() != index) {
+            self.controller.with_mut(|c| c.set_highlighted(index));
+        }
+        if let Some(position) = index.and_then(|index| visible.iter().position(|&i| i == index)) {
+            self.list_controller.with_mut(|c| c.reveal_item(position));
+        }
+    }
+
+    fn select(&self, index: usize) {
+        if !self.options.get(index).is_some_and(|option| option.enabled) {
+            return;
+        }
+        self.on_selection_change
+            .call(toggle_selection(&self.selected, index, self.multiple));
+        let multiple = self.multiple;
+        self.controller.with_mut(|c| {
+            c.set_highlighted(Some(index));
+            if multiple {
+                c.query = None;
+            } else {
+                c.collapse();
+            }
+        });
+    }
+
+    fn apply(&self, action: DropdownKeyAction) {
+        match action {
+            DropdownKeyAction::Expand => self.expand(),
+            DropdownKeyAction::Collapse => self.collapse(),
+            DropdownKeyAction::Move(movement) => {
+                let visible = self.visible_options();
+                let current = self.controller.with(|c| c.highlighted());
+                let next = move_highlight(&self.options, &visible, current, movement);
+                self.highlight(next, &visible);
+            }
+            DropdownKeyAction::Select => match self.controller.with(|c| c.highlighted()) {
+                Some(index) => self.select(index),
+                None => self.collapse(),
+            },
+            DropdownKeyAction::TypeAhead(text) => {
+                let prefix = self
+                    .controller
+                    .with_mut(|c| c.push_type_ahead(&text, Instant::now()));
+                self.expand();
+                let visible = self.visible_options();
+                let current = self.controller.with(|c| c.highlighted());
+                if let Some(index) = type_ahead_match(&self.options, &visible, &prefix, current) {
+                    self.highlight(Some(index), &visible);
+                }
+            }
+        }
+    }
+}
+
+#[tessera]
+fn exposed_dropdown_field(
+    args: Option<DropdownArgs>,
+    controller: Option<State<ExposedDropdownMenuController>>,
+) {
+    let args = args.expect("exposed_dropdown_field requires args");
+    let controller = controller.expect("exposed_dropdown_field requires controller");
+    let theme = use_context::<MaterialTheme>()
+        .expect("MaterialTheme must be provided")
+        .get();
+    let font_size = theme.typography.body_large.font_size;
+    let line_height = theme.typography.body_large.line_height;
+    let input_controller = remember(|| TextInputController::new(font_size, line_height));
+    let synced_text = remember(String::new);
+    let list_controller = remember(LazyListController::new);
+    let expanded_on_press = remember(|| None::<bool>);
+    let release_frame = remember(|| None::<u64>);
+    let bounds = remember_bounds();
+    let id = remember(|| NEXT_DROPDOWN_ID.fetch_add(1, Ordering::Relaxed)).get();
+
+    let enabled = args.enabled;
+    let editable = args.editable;
+    let actions = DropdownActions {
+        options: Arc::new(args.options.clone()),
+        selected: Arc::new(args.selected.clone()),
+        multiple: args.multiple,
+        controller,
+        list_controller,
+        on_selection_change: args.on_selection_change,
+    };
+    if !enabled {
+        actions.collapse();
+    }
+
+    let (expanded, query, highlighted) = controller.with(|c| {
+        (
+            c.is_expanded(),
+            c.query().map(str::to_owned),
+            c.highlighted(),
+        )
+    });
+    let list_open = expanded && !args.options.is_empty();
+    let display_text = field_text(
+        &args.options,
+        &args.selected,
+        args.multiple,
+        query.as_deref(),
+    );
+    if synced_text.with(|synced| *synced != display_text) {
+        input_controller.with_mut(|c| c.set_text(&display_text));
+        synced_text.set(display_text.clone());
+    }
+
+    let pointer_actions = actions.clone();
+    let keyboard_actions = actions.clone();
+    let expand_actions = actions.clone();
+    let collapse_actions = actions.clone();
+    let modifier = with_keyboard_preview_input(
+        // The preview pass sees taps before the text input consumes them.
+        with_pointer_preview_input(Modifier::new(), move |input| {
+            if !enabled {
+                return;
+            }
+            let within_bounds = input
+                .cursor_position_rel
+                .is_some_and(|position| is_position_inside_bounds(input.computed_data, position));
+            for change in input.pointer_changes.iter() {
+                if change.is_consumed() {
+                    continue;
+                }
+                match change.content {
+                    CursorEventContent::Pressed(PressKeyEventType::Left) if within_bounds => {
+                        let expanded = controller.with(|c| c.is_expanded());
+                        expanded_on_press.set(Some(expanded));
+                    }
+                    CursorEventContent::Released(PressKeyEventType::Left) => {
+                        let Some(was_expanded) = expanded_on_press.with_mut(Option::take) else {
+                            continue;
+                        };
+                        if !within_bounds {
+                            continue;
+                        }
+                        // Keep the list's light dismiss from undoing this tap.
+                        release_frame.set(Some(current_frame_nanos()));
+                        if was_expanded && !editable {
+                            pointer_actions.collapse();
+                        } else {
+                            pointer_actions.expand();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        })
+        .semantics(SemanticsArgs {
+            role: Some(Role::ComboBox),
+            label: args.label.clone(),
+            value: (!display_text.is_empty()).then(|| display_text.clone()),
+            expanded: Some(expanded),
+            disabled: !enabled,
+            // Screen readers follow the highlight through these links while
+            // focus stays in the field.
+            controls: if list_open {
+                vec![listbox_key(id)]
+            } else {
+                Vec::new()
+            },
+            active_descendant: highlighted
+                .filter(|_| list_open)
+                .map(|index| option_key(id, index)),
+            merge_descendants: true,
+            on_expand: Some(Callback::new(move || expand_actions.expand())),
+            on_collapse: Some(Callback::new(move || collapse_actions.collapse())),
+            ..Default::default()
+        }),
+        move |mut input| {
+            if !enabled
+                || input.key_modifiers.control_key()
+                || input.key_modifiers.alt_key()
+                || input.key_modifiers.super_key()
+            {
+                return;
+            }
+            input.keyboard_events.retain(|event| {
+                if event.state != winit::event::ElementState::Pressed {
+                    return true;
+                }
+                let (expanded, typing) =
+                    controller.with(|c| (c.is_expanded(), c.is_typing_ahead(Instant::now())));
+                if event.logical_key == winit::keyboard::Key::Named(winit::keyboard::NamedKey::Tab)
+                {
+                    // Let focus move on, but do not leave the list behind.
+                    keyboard_actions.collapse();
+                    return true;
+                }
+                match dropdown_key_action(&event.logical_key, expanded, editable, typing) {
+                    Some(action) => {
+                        keyboard_actions.apply(action);
+                        false
+                    }
+                    None => true,
+                }
+            });
+        },
+    );
+
+    let trailing_color = if enabled {
+        theme.color_scheme.on_surface_variant
+    } else {
+        theme
+            .color_scheme
+            .on_surface
+            .with_alpha(MaterialAlpha::DISABLED_CONTENT)
+    };
+    let style = args.style;
+    let field_label = args.label.clone();
+    let placeholder = args.placeholder.clone();
+    let change_actions = actions.clone();
+    layout().modifier(modifier).child(move || {
+        let mut builder = match style {
+            ExposedDropdownMenuStyle::Filled => TextFieldBuilder::filled(),
+            ExposedDropdownMenuStyle::Outlined => TextFieldBuilder::outlined(),
+        }
+        .enabled(enabled)
+        .read_only(!editable)
+        .modifier(Modifier::new().fill_max_width())
+        .line_limit(TextFieldLineLimit::SingleLine)
+        .trailing_icon(move || {
+            let painter = if expanded {
+                filled::ARROW_DROP_UP_SVG
+            } else {
+                filled::ARROW_DROP_DOWN_SVG
+            };
+            icon().painter(painter).size(Dp(24.0)).tint(trailing_color);
+        })
+        .controller(input_controller);
+        if editable {
+            let actions = change_actions.clone();
+            builder = builder.on_change(move |text: String| {
+                synced_text.set(text.clone());
+                controller.with_mut(|c| {
+                    c.set_query(text.clone());
+                    c.expand();
+                });
+                // Autocomplete: offer the first match for Enter.
+                let visible = actions.visible_options();
+                let first = move_highlight(&actions.options, &visible, None, HighlightMove::First);
+                actions.highlight(first, &visible);
+                text
+            });
+        }
+        if let Some(label) = field_label.clone() {
+            builder = builder.label(label);
+        }
+        if let Some(placeholder) = placeholder.clone() {
+            builder.placeholder(placeholder);
+        }
+    });
+
+    let anchor = bounds.get().unwrap_or(PxRect::ZERO);
+    let visible = actions.visible_options();
+    let dismiss_actions = actions.clone();
+    let panel = DropdownPanelArgs {
+        id,
+        options: args.options,
+        visible,
+        selected: args.selected,
+        multiple: args.multiple,
+        label: args.label,
+        width: anchor.width,
+        max_height: args.menu_max_height,
+    };
+    overlay()
+        .is_open(list_open)
+        .on_dismiss(move || {
+            if release_frame.get() != Some(current_frame_nanos()) {
+                dismiss_actions.collapse();
+            }
+        })
+        .position(OverlayPosition::Anchored {
+            anchor,
+            placement: PopupPlacement::BelowStart,
+            offset: PxPosition::new(Px::ZERO, Px::from(MENU_VERTICAL_GAP)),
+        })
+        .content(move || {
+            let select_actions = actions.clone();
+            dropdown_menu_panel()
+                .args(panel.clone())
+                .controller(controller)
+                .list_controller(list_controller)
+                .on_select(move |index| select_actions.select(index));
+        });
+}
+
+#[derive(Clone, PartialEq)]
+struct DropdownPanelArgs {
+    id: u64,
+    options: Vec<DropdownOption>,
+    visible: Vec<usize>,
+    selected: Vec<usize>,
+    multiple: bool,
+    label: Option<String>,
+    width: Px,
+    max_height: Dp,
+}
+
+#[tessera]
+fn dropdown_menu_panel(
+    args: Option<DropdownPanelArgs>,
+    controller: Option<State<ExposedDropdownMenuController>>,
+    list_controller: Option<State<LazyListController>>,
+    on_select: Option<CallbackWith<usize>>,
+) {
+    let args = args.expect("dropdown_menu_panel requires args");
+    let controller = controller.expect("dropdown_menu_panel requires controller");
+    let list_controller = list_controller.expect("dropdown_menu_panel requires list controller");
+    let on_select = on_select.expect("dropdown_menu_panel requires on_select");
+    let scheme = use_context::<MaterialTheme>()
+        .expect("MaterialTheme must be provided")
+        .get()
+        .color_scheme;
+
+    let width = (args.width > Px::ZERO).then(|| AxisConstraint::exact(args.width));
+    let max_height = Px::from(args.max_height);
+    let id = args.id;
+    let panel = surface()
+        .style(SurfaceStyle::Filled {
+            color: default_menu_color(),
+        })
+        .shape(default_menu_shape())
+        .modifier(
+            Modifier::new()
+                .constrain(width, Some(AxisConstraint::new(Px::ZERO, Some(max_height))))
+                .clip_to_bounds()
+                .semantics(SemanticsArgs {
+                    role: Some(Role::ListBox),
+                    label: args.label.clone(),
+                    test_tag: Some(listbox_key(id)),
+                    merge_descendants: true,
+                    ..Default::default()
+                }),
+        )
+        .block_input(true)
+        .elevation(Dp(3.0));
+    panel.child(move || {
+        if args.visible.is_empty() {
+            layout()
+                .modifier(
+                    Modifier::new()
+                        .fill_max_width()
+                        .height(MENU_ITEM_HEIGHT)
+                        .padding_all(MENU_HORIZONTAL_PADDING),
+                )
+                .child(move || {
+                    text()
+                        .content(strings::exposed_dropdown_menu_no_results())
+                        .size(Dp(16.0))
+                        .color(scheme.on_surface_variant);
+                });
+            return;
+        }
+        let options = Arc::new(args.options.clone());
+        let visible = Arc::new(args.visible.clone());
+        let selected = Arc::new(args.selected.clone());
+        let multiple = args.multiple;
+        lazy_column()
+            .modifier(Modifier::new().fill_max_width())
+            .estimated_item_size(MENU_ITEM_HEIGHT)
+            .max_viewport_main(max_height)
+            .controller(list_controller)
+            .items(visible.len(), move |position| {
+                let index = visible[position];
+                let highlighted = controller.with(|c| c.highlighted() == Some(index));
+                dropdown_option_item()
+                    .option(options[index].clone())
+                    .index(index)
+                    .accessibility_key(option_key(id, index))
+                    .highlighted(highlighted)
+                    .selected(selected.contains(&index))
+                    .multiple(multiple)
+                    .controller(controller)
+                    .on_select_shared(on_select);
+            });
+    });
+}
+
+#[tessera]
+fn dropdown_option_item(
+    option: Option<DropdownOption>,
+    index: Option<usize>,
+    #[prop(into)] accessibility_key: Option<String>,
+    highlighted: Option<bool>,
+    selected: Option<bool>,
+    multiple: Option<bool>,
+    controller: Option<State<ExposedDropdownMenuController>>,
+    on_select: Option<CallbackWith<usize>>,
+) {
+    let option = option.expect("dropdown_option_item requires an option");
+    let index = index.unwrap_or_default();
+    let highlighted = highlighted.unwrap_or(false);
+    let selected = selected.unwrap_or(false);
+    let multiple = multiple.unwrap_or(false);
+    let controller = controller.expect("dropdown_option_item requires controller");
+    let on_select = on_select.expect("dropdown_option_item requires on_select");
+    let scheme = use_context::<MaterialTheme>()
+        .expect("MaterialTheme must be provided")
+        .get()
+        .color_scheme;
+    let enabled = option.enabled;
+    let content_color = if enabled {
+        scheme.on_surface
+    } else {
+        scheme
+            .on_surface
+            .with_alpha(MaterialAlpha::DISABLED_CONTENT)
+    };
+    let supporting_color = if enabled {
+        scheme.on_surface_variant
+    } else {
+        content_color
+    };
+    let container_color = if highlighted && enabled {
+        scheme.on_surface.with_alpha(MaterialAlpha::HOVER)
+    } else if selected && !multiple {
+        scheme.secondary_container
+    } else {
+        Color::TRANSPARENT
+    };
+
+    let tap_recognizer = remember(TapRecognizer::default);
+    let modifier = with_pointer_input(Modifier::new(), move |mut input| {
+        if !enabled {
+            return;
+        }
+        let within_bounds = input
+            .cursor_position_rel
+            .is_some_and(|position| is_position_inside_bounds(input.computed_data, position));
+        // Hovering moves the highlight so pointer and keyboard share it.
+        let moved = input
+            .pointer_changes
+            .iter()
+            .any(|change| matches!(change.content, CursorEventContent::Moved(_)));
+        if within_bounds && moved && controller.with(|c| c.highlighted() != Some(index)) {
+            controller.with_mut(|c| c.set_highlighted(Some(index)));
+        }
+        let tap_result = tap_recognizer.with_mut(|recognizer| {
+            recognizer.update(
+                input.pass,
+                input.pointer_changes.as_mut_slice(),
+                input.cursor_position_rel,
+                within_bounds,
+            )
+        });
+        if tap_result.tapped {
+            on_select.call(index);
+        }
+    })
+    .push_semantics(DropdownOptionSemanticsModifierNode {
+        key: accessibility_key,
+        label: option.label.clone(),
+        supporting_text: option.supporting_text.clone(),
+        selected,
+        enabled,
+        on_select: Callback::new(move || on_select.call(index)),
+    });
+
+    let label = option.label;
+    let supporting_text = option.supporting_text;
+    layout().modifier(modifier).child(move || {
+        let label = label.clone();
+        let supporting_text = supporting_text.clone();
+        surface()
+            .style(SurfaceStyle::Filled {
+                color: container_color,
+            })
+            .modifier(Modifier::new().fill_max_width().constrain(
+                None,
+                Some(AxisConstraint::at_least(Px::from(MENU_ITEM_HEIGHT))),
+            ))
+            .child(move || {
+                let label = label.clone();
+                let supporting_text = supporting_text.clone();
+                row()
+                    .modifier(Modifier::new().fill_max_width().constrain(
+                        None,
+                        Some(AxisConstraint::at_least(Px::from(MENU_ITEM_HEIGHT))),
+                    ))
+                    .cross_axis_alignment(CrossAxisAlignment::Center)
+                    .children(move || {
+                        spacer().modifier(Modifier::new().width(MENU_HORIZONTAL_PADDING));
+                        if multiple {
+                            if selected {
+                                checkmark()
+                                    .color(content_color)
+                                    .size(MENU_LEADING_SIZE)
+                                    .padding([2.0, 2.0]);
+                            } else {
+                                spacer().modifier(
+                                    Modifier::new().size(MENU_LEADING_SIZE, MENU_LEADING_SIZE),
+                                );
+                            }
+                            spacer().modifier(Modifier::new().width(MENU_HORIZONTAL_PADDING));
+                        }
+                        let label = label.clone();
+                        let supporting_text = supporting_text.clone();
+                        column()
+                            .cross_axis_alignment(CrossAxisAlignment::Start)
+                            .children(move || {
+                                text()
+                                    .content(label.clone())
+                                    .size(Dp(16.0))
+                                    .color(content_color);
+                                if let Some(supporting_text) = supporting_text.clone() {
+                                    text()
+                                        .content(supporting_text)
+                                        .size(Dp(14.0))
+                                        .color(supporting_color);
+                                }
+                            });
+                        spacer().modifier(Modifier::new().fill_max_width().weight(1.0));
+                        spacer().modifier(Modifier::new().width(MENU_HORIZONTAL_PADDING));
+                    });
+            });
+    });
+}
+
+struct DropdownOptionSemanticsModifierNode {
+    key: Option<String>,
+    label: String,
+    supporting_text: Option<String>,
+    selected: bool,
+    enabled: bool,
+    on_select: Callback,
+}
+
+impl SemanticsModifierNode for DropdownOptionSemanticsModifierNode {
+    fn apply(
+        &self,
+        accessibility: &mut AccessibilityNode,
+        action_handler: &mut Option<AccessibilityActionHandler>,
+    ) {
+        accessibility.role = Some(Role::ListBoxOption);
+        accessibility.key = self.key.clone();
+        accessibility.label = Some(self.label.clone());
+        accessibility.description = self.supporting_text.clone();
+        accessibility.selected = Some(self.selected);
+        accessibility.disabled = !self.enabled;
+        accessibility.actions.clear();
+        if self.enabled {
+            accessibility.actions.push(Action::Click);
+            let on_select = self.on_select;
+            *action_handler = Some(Box::new(move |action| {
+                if action == Action::Click {
+                    on_select.call();
+                }
+            }));
+        } else {
+            *action_handler = None;
+        }
+    }
+}
+
+/// Returns the text shown in the field.
+fn field_text(
+    options: &[DropdownOption],
+    selected: &[usize],
+    multiple: bool,
+    query: Option<&str>,
+) -> String {
+    if let Some(query) = query {
+        return query.to_owned();
+    }
+    if multiple {
+        // Multiple selections are shown as chips instead.
+        return String::new();
+    }
+    selected
+        .first()
+        .and_then(|&index| options.get(index))
+        .map(|option| option.label.clone())
+        .unwrap_or_default()
+}
+
+/// Returns the indices of the options whose label contains `query`, ignoring
+/// case. A missing or blank query matches every option.
+fn filter_options(options: &[DropdownOption], query: Option<&str>) -> Vec<usize> {
+    let query = query
+        .map(str::trim)
+        .filter(|query| !query.is_empty())
+        .map(str::to_lowercase);
+    options
+        .iter()
+        .enumerate()
+        .filter(|(_, option)| {
+            query
+                .as_deref()
+                .is_none_or(|query| option.label.to_lowercase().contains(query))
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Returns the selection after activating option `index`.
+fn toggle_selection(selected: &[usize], index: usize, multiple: bool) -> Vec<usize> {
+    if !multiple {
+        return vec![index];
+    }
+    let mut next = selected.to_vec();
+    if let Some(position) = next.iter().position(|&selected| selected == index) {
+        next.remove(position);
+    } else {
+        next.push(index);
+        next.sort_unstable();
+    }
+    next
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum HighlightMove {
+    Next,
+    Previous,
+    First,
+    Last,
+}
+
+/// Moves the highlight among the enabled `visible` options without wrapping.
+fn move_highlight(
+    options: &[DropdownOption],
+    visible: &[usize],
+    current: Option<usize>,
+    movement: HighlightMove,
+) -> Option<usize> {
+    let enabled = |index: &&usize| options[**index].enabled;
+    let first = || visible.iter().find(enabled).copied();
+    let last = || visible.iter().rev().find(enabled).copied();
+    let position = current.and_then(|current| visible.iter().position(|&index| index == current));
+    match (movement, position) {
+        (HighlightMove::First, _) | (HighlightMove::Next, None) => first(),
+        (HighlightMove::Last, _) | (HighlightMove::Previous, None) => last(),
+        (HighlightMove::Next, Some(position)) => visible[position + 1..]
+            .iter()
+            .find(enabled)
+            .copied()
+            .or(current),
+        (HighlightMove::Previous, Some(position)) => visible[..position]
+            .iter()
+            .rev()
+            .find(enabled)
+            .copied()
+            .or(current),
+    }
+}
+
+/// Finds the next enabled option whose label starts with `prefix`, ignoring
+/// case.
+///
+/// A single character starts after the current option, so pressing the same
+/// key repeatedly cycles through the options sharing that first letter.
+fn type_ahead_match(
+    options: &[DropdownOption],
+    visible: &[usize],
+    prefix: &str,
+    current: Option<usize>,
+) -> Option<usize> {
+    if visible.is_empty() || prefix.is_empty() {
+        return None;
+    }
+    let prefix = prefix.to_lowercase();
+    let position = current.and_then(|current| visible.iter().position(|&index| index == current));
+    let start = match position {
+        Some(position) if prefix.chars().count() == 1 => position + 1,
+        Some(position) => position,
+        None => 0,
+    };
+    (0..visible.len())
+        .map(|step| visible[(start + step) % visible.len()])
+        .find(|&index| {
+            options[index].enabled && options[index].label.to_lowercase().starts_with(&prefix)
+        })
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum DropdownKeyAction {
+    Expand,
+    Collapse,
+    Move(HighlightMove),
+    Select,
+    TypeAhead(String),
+}
+
+fn dropdown_key_action(
+    key: &winit::keyboard::Key,
+    expanded: bool,
+    editable: bool,
+    typing_ahead: bool,
+) -> Option<DropdownKeyAction> {
+    use winit::keyboard::{Key, NamedKey};
+
+    match key {
+        Key::Named(NamedKey::ArrowDown) if expanded => {
+            Some(DropdownKeyAction::Move(HighlightMove::Next))
+        }
+        Key::Named(NamedKey::ArrowUp) if expanded => {
+            Some(DropdownKeyAction::Move(HighlightMove::Previous))
+        }
+        Key::Named(NamedKey::ArrowDown | NamedKey::ArrowUp) => Some(DropdownKeyAction::Expand),
+        // Editable fields keep Home and End for the caret.
+        Key::Named(NamedKey::Home) if expanded && !editable => {
+            Some(DropdownKeyAction::Move(HighlightMove::First))
+        }
+        Key::Named(NamedKey::End) if expanded && !editable => {
+            Some(DropdownKeyAction::Move(HighlightMove::Last))
+        }
+        Key::Named(NamedKey::Enter) if expanded => Some(DropdownKeyAction::Select),
+        Key::Named(NamedKey::Enter) if !editable => Some(DropdownKeyAction::Expand),
+        Key::Named(NamedKey::Escape) if expanded => Some(DropdownKeyAction::Collapse),
+        // A space inside a type-ahead search is part of the label.
+        Key::Named(NamedKey::Space) if !editable && typing_ahead => {
+            Some(DropdownKeyAction::TypeAhead(" ".to_owned()))
+        }
+        Key::Named(NamedKey::Space) if !editable && expanded => Some(DropdownKeyAction::Select),
+        Key::Named(NamedKey::Space) if !editable => Some(DropdownKeyAction::Expand),
+        Key::Character(text) if !editable => Some(DropdownKeyAction::TypeAhead(text.to_string())),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use winit::keyboard::{Key, NamedKey, SmolStr};
+
+    use super::*;
+
+    fn options(labels: &[&str]) -> Vec<DropdownOption> {
+        labels.iter().copied().map(DropdownOption::new).collect()
+    }
+
+    #[test]
+    fn filtering_matches_substrings_ignoring_case() {
+        let options = options(&["Apple", "Banana", "Pineapple", "Cherry"]);
+        assert_eq!(filter_options(&options, Some("APP")), vec![0, 2]);
+        assert_eq!(filter_options(&options, Some("  ")), vec![0, 1, 2, 3]);
+        assert_eq!(filter_options(&options, None), vec![0, 1, 2, 3]);
+        assert!(filter_options(&options, Some("kiwi")).is_empty());
+    }
+
+    #[test]
+    fn highlight_skips_disabled_options_and_stops_at_the_ends() {
+        let mut options = options(&["A", "B", "C", "D"]);
+        options[1].enabled = false;
+        let visible = [0, 1, 2, 3];
+        let step = |current, movement| move_highlight(&options, &visible, current, movement);
+        assert_eq!(step(None, HighlightMove::Next), Some(0));
+        assert_eq!(step(Some(0), HighlightMove::Next), Some(2));
+        assert_eq!(step(Some(3), HighlightMove::Next), Some(3));
+        assert_eq!(step(Some(2), HighlightMove::Previous), Some(0));
+        assert_eq!(step(None, HighlightMove::Previous), Some(3));
+        assert_eq!(step(Some(2), HighlightMove::First), Some(0));
+        // A highlight hidden by the filter restarts from the first match.
+        assert_eq!(
+            move_highlight(&options, &[2, 3], Some(0), HighlightMove::Next),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn type_ahead_cycles_through_options_with_the_same_letter() {
+        let options = options(&["Banana", "Blueberry", "Cherry", "Blackberry"]);
+        let visible = [0, 1, 2, 3];
+        assert_eq!(type_ahead_match(&options, &visible, "b", None), Some(0));
+        assert_eq!(type_ahead_match(&options, &visible, "b", Some(0)), Some(1));
+        assert_eq!(type_ahead_match(&options, &visible, "b", Some(3)), Some(0));
+        assert_eq!(type_ahead_match(&options, &visible, "bl", Some(1)), Some(1));
+        assert_eq!(
+            type_ahead_match(&options, &visible, "BLA", Some(1)),
+            Some(3)
+        );
+        assert_eq!(type_ahead_match(&options, &visible, "z", Some(1)), None);
+    }
+
+    #[test]
+    fn type_ahead_buffer_restarts_after_a_pause() {
+        let mut controller = ExposedDropdownMenuController::new();
+        let start = Instant::now();
+        assert_eq!(controller.push_type_ahead("b", start), "b");
+        assert_eq!(
+            controller.push_type_ahead("l", start + Duration::from_millis(300)),
+            "bl"
+        );
+        let later = start + Duration::from_millis(300) + Duration::from_secs(2);
+        assert!(!controller.is_typing_ahead(later));
+        assert_eq!(controller.push_type_ahead("c", later), "c");
+    }
+
+    #[test]
+    fn selection_replaces_single_and_toggles_multiple() {
+        assert_eq!(toggle_selection(&[2], 0, false), vec![0]);
+        assert_eq!(toggle_selection(&[3], 1, true), vec![1, 3]);
+        assert_eq!(toggle_selection(&[1, 3], 1, true), vec![3]);
+    }
+
+    #[test]
+    fn field_shows_the_query_while_filtering() {
+        let options = options(&["Apple", "Banana"]);
+        assert_eq!(field_text(&options, &[1], false, None), "Banana");
+        assert_eq!(field_text(&options, &[1], false, Some("ap")), "ap");
+        assert_eq!(field_text(&options, &[0, 1], true, None), "");
+    }
+
+    #[test]
+    fn keys_map_to_read_only_and_editable_actions() {
+        let down = Key::Named(NamedKey::ArrowDown);
+        let home = Key::Named(NamedKey::Home);
+        let space = Key::Named(NamedKey::Space);
+        let letter = Key::Character(SmolStr::new("a"));
+        assert_eq!(
+            dropdown_key_action(&down, false, false, false),
+            Some(DropdownKeyAction::Expand)
+        );
+        assert_eq!(
+            dropdown_key_action(&down, true, true, false),
+            Some(DropdownKeyAction::Move(HighlightMove::Next))
+        );
+        assert_eq!(
+            dropdown_key_action(&home, true, false, false),
+            Some(DropdownKeyAction::Move(HighlightMove::First))
+        );
+        assert_eq!(dropdown_key_action(&home, true, true, false), None);
+        assert_eq!(
+            dropdown_key_action(&space, true, false, false),
+            Some(DropdownKeyAction::Select)
+        );
+        assert_eq!(
+            dropdown_key_action(&space, true, false, true),
+            Some(DropdownKeyAction::TypeAhead(" ".to_owned()))
+        );
+        assert_eq!(
+            dropdown_key_action(&letter, false, false, false),
+            Some(DropdownKeyAction::TypeAhead("a".to_owned()))
+        );
+        assert_eq!(dropdown_key_action(&letter, true, true, false), None);
+        assert_eq!(
+            dropdown_key_action(&Key::Named(NamedKey::Escape), false, false, false),
+            None
+        );
+    }
+}
@@ -5,9 +5,9 @@
 //! Use to select a value from a continuous range.
 use tessera_foundation::gesture::{DragRecognizer, TapRecognizer};
 use tessera_ui::{
-    CallbackWith, Color, ComputedData, Constraint, Dp, FocusProperties, FocusRequester,
-    LayoutResult, MeasurementError, Modifier, PointerInput, PointerInputModifierNode, Px,
-    PxPosition, State,
+    AccessibilityValue, Callback, CallbackWith, Color, ComputedData, Constraint, Dp,
+    FocusProperties, FocusRequester, LayoutResult, MeasurementError, Modifier, PointerInput,
+    PointerInputModifierNode, Px, PxPosition, State,
     accesskit::Role,
     layout::{LayoutPolicy, MeasureScope, layout},
     modifier::{CursorModifierExt as _, FocusModifierExt as _, ModifierCapabilityExt as _},
@@ -434,6 +434,22 @@ fn render_glass_slider(args: GlassSliderConfig) {
         .controller
         .expect("render_glass_slider requires controller to be set");
     let mut modifier = args.modifier.clone();
+    let value = args.value;
+    let on_change = args.on_change;
+    let set_value = move |next: f32| {
+        let next = next.clamp(0.0, 1.0);
+        if (next - value).abs() > f32::EPSILON {
+            on_change.call(next);
+        }
+    };
+    let on_increment = Callback::new(move || set_value(value + ACCESSIBILITY_STEP));
+    let on_decrement = Callback::new(move || set_value(value - ACCESSIBILITY_STEP));
+    let on_set_value = CallbackWith::new(move |next: AccessibilityValue| {
+        if let AccessibilityValue::Numeric(next) = next {
+            set_value(next as f32);
+        }
+    });
+    let enabled = !args.disabled;
     let semantics = SemanticsArgs {
         role: Some(Role::Slider),
         label: args.accessibility_label.clone(),
@@ -443,6 +459,9 @@ fn render_glass_slider(args: GlassSliderConfig) {
         focusable: !args.disabled,
         disabled: args.disabled,
         numeric_value_step: Some(ACCESSIBILITY_STEP as f64),
+        on_increment: enabled.then_some(on_increment),
+        on_decrement: enabled.then_some(on_decrement),
+        on_set_value: enabled.then_some(on_set_value),
         ..Default::default()
     };
     modifier = modifier.semantics(semantics);
@@ -172,6 +172,7 @@ impl RenderPolicy for IconLayout {
                 let command = ImageCommand {
                     data: data.clone(),
                     opacity: 1.0,
+                    texture_slot: None,
                 };
                 metadata.fragment_mut().push_draw_command(command);
             }
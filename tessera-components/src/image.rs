@@ -3,6 +3,9 @@
 //! ## Usage
 //!
 //! Use to display images from pre-decoded data or bytes/assets loaded once.
+//! For large photos or remote images, prefer
+//! [`async_image`](crate::async_image::async_image), which decodes off the UI
+//! thread.
 use std::{
     path::{Path, PathBuf},
     sync::Arc,
@@ -56,7 +59,7 @@ fn placeholder_image_data() -> Arc<ImageData> {
     })
 }
 
-fn decode_dynamic_image(decoded: image::DynamicImage) -> ImageData {
+pub(crate) fn decode_dynamic_image(decoded: image::DynamicImage) -> ImageData {
     let (width, height) = decoded.dimensions();
     ImageData {
         data: Arc::new(decoded.to_rgba8().into_raw()),
@@ -221,6 +224,7 @@ impl RenderPolicy for ImageLayout {
                 let image_command = ImageCommand {
                     data: data.clone(),
                     opacity: 1.0,
+                    texture_slot: None,
                 };
                 input
                     .metadata_mut()
@@ -0,0 +1,1228 @@
+//! Asynchronous image loading with memory and disk caches.
+//!
+//! ## Usage
+//!
+//! Fetch and decode large or remote images off the UI thread for
+//! [`async_image`](crate::async_image::async_image).
+//!
+//! An [`ImageLoader`] fetches encoded bytes, decodes them on a small worker
+//! pool and downsamples the result to the requested target size. On the web,
+//! which has no worker threads, the browser's asynchronous image decoder is
+//! used instead. Decoded images are kept in an in-memory LRU cache bounded by
+//! decoded bytes. Bytes fetched for URIs are additionally written to an
+//! optional disk cache.
+//!
+//! Files, in-memory bytes and bundled assets load out of the box. URIs are
+//! loaded by registered [`ImageFetcher`]s; the crate ships no network client,
+//! so register one that wraps the HTTP stack of your application to load
+//! `http://` and `https://` images.
+//!
+//! Concurrent requests for the same source and target size share one load.
+//! Dropping every [`ImageRequest`] for a load cancels it.
+use std::{
+    any::TypeId,
+    collections::{HashMap, hash_map::DefaultHasher},
+    fmt, fs,
+    hash::{Hash, Hasher},
+    io,
+    path::{Path, PathBuf},
+    sync::{
+        Arc, OnceLock,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        mpsc,
+    },
+    time::SystemTime,
+};
+
+use image::{DynamicImage, GenericImageView, imageops::FilterType};
+use lru::LruCache;
+use parking_lot::{Mutex, RwLock};
+use tessera_ui::{AssetExt, State, remember_with_key};
+use thiserror::Error;
+
+use crate::{
+    image::{ImageData, ImageLoadError, decode_dynamic_image},
+    subscription::{Subscriber, Subscription},
+};
+
+const DEFAULT_MEMORY_CACHE_BYTES: usize = 64 * 1024 * 1024;
+const DEFAULT_DISK_CACHE_BYTES: u64 = 256 * 1024 * 1024;
+const MAX_DEFAULT_WORKERS: usize = 4;
+const DISK_CACHE_EXTENSION: &str = "img";
+
+static GLOBAL_LOADER: RwLock<Option<ImageLoader>> = RwLock::new(None);
+static NEXT_DISK_CACHE_TEMP_ID: AtomicU64 = AtomicU64::new(0);
+
+type AssetReader = Arc<dyn Fn() -> io::Result<Arc<[u8]>> + Send + Sync>;
+
+#[derive(Clone)]
+enum ImageSourceRepr {
+    File(PathBuf),
+    Uri(Arc<str>),
+    Bytes(Arc<[u8]>),
+    Asset(AssetReader),
+}
+
+/// Where an asynchronously loaded image comes from.
+///
+/// Strings containing `://` convert into URI sources; other strings and paths
+/// convert into file sources.
+#[derive(Clone)]
+pub struct ImageSource {
+    repr: ImageSourceRepr,
+    /// Cache key, computed on first use for byte sources and shared by
+    /// clones.
+    key: Arc<OnceLock<u64>>,
+}
+
+impl ImageSource {
+    fn with_key(repr: ImageSourceRepr, key: u64) -> Self {
+        Self {
+            repr,
+            key: Arc::new(OnceLock::from(key)),
+        }
+    }
+
+    /// Creates a source for an image file on the local filesystem.
+    pub fn file(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let key = stable_hash(&[b"file:", path.to_string_lossy().as_bytes()]);
+        Self::with_key(ImageSourceRepr::File(path), key)
+    }
+
+    /// Creates a source for a URI loaded by a registered [`ImageFetcher`].
+    ///
+    /// `file://` URIs are read from the local filesystem instead.
+    pub fn uri(uri: impl Into<String>) -> Self {
+        let uri = uri.into();
+        if let Some(path) = uri.strip_prefix("file://") {
+            return Self::file(path);
+        }
+        let key = stable_hash(&[b"uri:", uri.as_bytes()]);
+        Self::with_key(ImageSourceRepr::Uri(uri.into()), key)
+    }
+
+    /// Creates a source from encoded image bytes already in memory.
+    ///
+    /// The cache key hashes the whole buffer the first time it is needed and
+    /// is shared by clones, so keep the source across builds (for example
+    /// with `remember`) instead of recreating it. Use
+    /// [`ImageSource::bytes_with_key`] when the bytes already have an
+    /// identity.
+    pub fn bytes(bytes: impl Into<Arc<[u8]>>) -> Self {
+        Self {
+            repr: ImageSourceRepr::Bytes(bytes.into()),
+            key: Arc::new(OnceLock::new()),
+        }
+    }
+
+    /// Creates a source from encoded image bytes identified by `key`.
+    ///
+    /// The bytes are not hashed; sources with equal keys share cached images,
+    /// so the key must change whenever the bytes do.
+    pub fn bytes_with_key<K>(key: &K, bytes: impl Into<Arc<[u8]>>) -> Self
+    where
+        K: Hash + ?Sized,
+    {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        Self::with_key(ImageSourceRepr::Bytes(bytes.into()), hasher.finish())
+    }
+
+    /// Creates a source from a bundled asset.
+    ///
+    /// The source follows the asset variant resolved at creation time, so
+    /// recreate it during builds to pick up scale factor or theme changes.
+    pub fn asset<T>(asset: T) -> Self
+    where
+        T: AssetExt + Hash + Send + Sync + 'static,
+    {
+        let mut hasher = DefaultHasher::new();
+        TypeId::of::<T>().hash(&mut hasher);
+        asset.hash(&mut hasher);
+        asset.variant_index().hash(&mut hasher);
+        let key = hasher.finish();
+        Self::with_key(ImageSourceRepr::Asset(Arc::new(move || asset.read())), key)
+    }
+
+    /// Returns the key identifying this source in caches.
+    pub fn cache_key(&self) -> u64 {
+        *self.key.get_or_init(|| match &self.repr {
+            ImageSourceRepr::Bytes(bytes) => stable_hash(&[b"bytes:", bytes]),
+            _ => unreachable!("only byte sources compute their key lazily"),
+        })
+    }
+
+    fn kind(&self) -> &'static str {
+        match self.repr {
+            ImageSourceRepr::File(_) => "file",
+            ImageSourceRepr::Uri(_) => "uri",
+            ImageSourceRepr::Bytes(_) => "bytes",
+            ImageSourceRepr::Asset(_) => "asset",
+        }
+    }
+}
+
+impl PartialEq for ImageSource {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind() == other.kind() && self.cache_key() == other.cache_key()
+    }
+}
+
+impl Eq for ImageSource {}
+
+impl fmt::Debug for ImageSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.repr {
+            ImageSourceRepr::File(path) => f.debug_tuple("File").field(path).finish(),
+            ImageSourceRepr::Uri(uri) => f.debug_tuple("Uri").field(uri).finish(),
+            ImageSourceRepr::Bytes(bytes) => {
+                f.debug_struct("Bytes").field("len", &bytes.len()).finish()
+            }
+            ImageSourceRepr::Asset(_) => f
+                .debug_struct("Asset")
+                .field("key", &format_args!("{:016x}", self.cache_key()))
+                .finish(),
+        }
+    }
+}
+
+impl From<&str> for ImageSource {
+    fn from(value: &str) -> Self {
+        if value.contains("://") {
+            Self::uri(value)
+        } else {
+            Self::file(value)
+        }
+    }
+}
+
+impl From<String> for ImageSource {
+    fn from(value: String) -> Self {
+        if value.contains("://") {
+            Self::uri(value)
+        } else {
+            Self::file(value)
+        }
+    }
+}
+
+impl From<PathBuf> for ImageSource {
+    fn from(value: PathBuf) -> Self {
+        Self::file(value)
+    }
+}
+
+impl From<&Path> for ImageSource {
+    fn from(value: &Path) -> Self {
+        Self::file(value)
+    }
+}
+
+impl From<Vec<u8>> for ImageSource {
+    fn from(value: Vec<u8>) -> Self {
+        Self::bytes(value)
+    }
+}
+
+impl From<Arc<[u8]>> for ImageSource {
+    fn from(value: Arc<[u8]>) -> Self {
+        Self::bytes(value)
+    }
+}
+
+/// Loads encoded image bytes for URIs such as `https://` images.
+///
+/// Fetchers run on loader worker threads and may block.
+pub trait ImageFetcher: Send + Sync + 'static {
+    /// Returns whether this fetcher loads `uri`.
+    fn supports(&self, uri: &str) -> bool;
+
+    /// Fetches the encoded image bytes for `uri`.
+    fn fetch(&self, uri: &str) -> io::Result<Vec<u8>>;
+}
+
+/// Errors that can occur while loading an image asynchronously.
+#[derive(Debug, Error)]
+pub enum ImageRequestError {
+    /// No registered fetcher supports the URI.
+    #[error("no image fetcher supports `{uri}`")]
+    UnsupportedUri {
+        /// URI of the failed request.
+        uri: String,
+    },
+    /// A fetcher failed to load the URI.
+    #[error("failed to fetch image `{uri}`: {source}")]
+    Fetch {
+        /// URI of the failed request.
+        uri: String,
+        /// Underlying IO error.
+        #[source]
+        source: io::Error,
+    },
+    /// Reading an image file failed.
+    #[error("failed to read image file `{}`: {source}", path.display())]
+    Read {
+        /// Path of the failed request.
+        path: PathBuf,
+        /// Underlying IO error.
+        #[source]
+        source: io::Error,
+    },
+    /// Reading or decoding the image data failed.
+    #[error(transparent)]
+    Load(#[from] ImageLoadError),
+}
+
+/// Progress of an asynchronous image load.
+#[derive(Clone, Debug)]
+pub enum ImageLoadState {
+    /// The image is still being fetched or decoded.
+    Loading,
+    /// The image finished loading.
+    Ready(Arc<ImageData>),
+    /// The image failed to load.
+    Failed(Arc<ImageRequestError>),
+}
+
+/// Largest decoded size of an image request, in physical pixels.
+///
+/// Images larger than the target are downsampled to fit while keeping their
+/// aspect ratio. A zero dimension leaves that axis unbounded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct ImageTargetSize {
+    /// Maximum decoded width, or `0` for no limit.
+    pub width: u32,
+    /// Maximum decoded height, or `0` for no limit.
+    pub height: u32,
+}
+
+impl ImageTargetSize {
+    /// Target that keeps the original image size.
+    pub const ORIGINAL: Self = Self {
+        width: 0,
+        height: 0,
+    };
+
+    /// Creates a target size.
+    pub const fn new(width: u32, height: u32) -> Self {
+        Self { width, height }
+    }
+
+    /// Returns whether an image decoded for `self` also satisfies `other`.
+    pub fn covers(self, other: Self) -> bool {
+        fn covers_axis(current: u32, other: u32) -> bool {
+            current == 0 || (other != 0 && current >= other)
+        }
+        covers_axis(self.width, other.width) && covers_axis(self.height, other.height)
+    }
+}
+
+/// Configuration for an [`ImageLoader`].
+#[derive(Clone, Debug)]
+pub struct ImageLoaderConfig {
+    /// Number of worker threads that fetch and decode images.
+    pub worker_count: usize,
+    /// Decoded bytes kept in the in-memory LRU cache.
+    pub memory_cache_bytes: usize,
+    /// Directory for bytes fetched from URIs, or `None` to disable the disk
+    /// cache.
+    pub disk_cache_dir: Option<PathBuf>,
+    /// Total size of the disk cache in bytes before the least recently used
+    /// entries are removed.
+    pub disk_cache_bytes: u64,
+}
+
+impl Default for ImageLoaderConfig {
+    fn default() -> Self {
+        Self {
+            worker_count: std::thread::available_parallelism()
+                .map(|count| count.get().min(MAX_DEFAULT_WORKERS))
+                .unwrap_or(1),
+            memory_cache_bytes: DEFAULT_MEMORY_CACHE_BYTES,
+            disk_cache_dir: None,
+            disk_cache_bytes: DEFAULT_DISK_CACHE_BYTES,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct RequestKey {
+    source: u64,
+    target: ImageTargetSize,
+}
+
+impl RequestKey {
+    fn new(source: &ImageSource, target: ImageTargetSize) -> Self {
+        Self {
+            source: source.cache_key(),
+            target,
+        }
+    }
+}
+
+struct MemoryCache {
+    entries: LruCache<RequestKey, Arc<ImageData>>,
+    bytes: usize,
+    capacity: usize,
+}
+
+impl MemoryCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            entries: LruCache::unbounded(),
+            bytes: 0,
+            capacity,
+        }
+    }
+
+    fn get(&mut self, key: &RequestKey) -> Option<Arc<ImageData>> {
+        self.entries.get(key).cloned()
+    }
+
+    fn insert(&mut self, key: RequestKey, data: Arc<ImageData>) {
+        let size = data.data.len();
+        if size > self.capacity {
+            return;
+        }
+        if let Some(previous) = self.entries.put(key, data) {
+            self.bytes -= previous.data.len();
+        }
+        self.bytes += size;
+        while self.bytes > self.capacity {
+            let Some((_, evicted)) = self.entries.pop_lru() else {
+                break;
+            };
+            self.bytes -= evicted.data.len();
+        }
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.bytes = 0;
+    }
+}
+
+type SubscriberCallback = Arc<dyn Fn(ImageLoadState) + Send + Sync>;
+
+fn deliver(subscriber: &Subscriber<SubscriberCallback>, state: ImageLoadState) {
+    subscriber.post(move |callback| callback(state));
+}
+
+struct InFlight {
+    subscribers: Vec<Subscriber<SubscriberCallback>>,
+    cancelled: Arc<AtomicBool>,
+}
+
+struct Job {
+    key: RequestKey,
+    source: ImageSource,
+    cancelled: Arc<AtomicBool>,
+}
+
+struct LoaderInner {
+    config: ImageLoaderConfig,
+    fetchers: RwLock<Vec<Arc<dyn ImageFetcher>>>,
+    memory: Mutex<MemoryCache>,
+    in_flight: Mutex<HashMap<RequestKey, InFlight>>,
+    jobs: Mutex<Option<mpsc::Sender<Job>>>,
+    next_subscriber_id: AtomicU64,
+}
+
+/// Loads, downsamples and caches images off the UI thread.
+///
+/// Cloning a loader shares its caches, fetchers and workers.
+#[derive(Clone)]
+pub struct ImageLoader {
+    inner: Arc<LoaderInner>,
+}
+
+impl ImageLoader {
+    /// Creates a loader with its own caches and worker pool.
+    ///
+    /// Worker threads start with the first asynchronous request.
+    pub fn new(config: ImageLoaderConfig) -> Self {
+        let memory = MemoryCache::new(config.memory_cache_bytes);
+        Self {
+            inner: Arc::new(LoaderInner {
+                config,
+                fetchers: RwLock::new(Vec::new()),
+                memory: Mutex::new(memory),
+                in_flight: Mutex::new(HashMap::new()),
+                jobs: Mutex::new(None),
+                next_subscriber_id: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    /// Returns the process-wide loader used by components by default.
+    pub fn global() -> Self {
+        if let Some(loader) = GLOBAL_LOADER.read().as_ref() {
+            return loader.clone();
+        }
+        GLOBAL_LOADER
+            .write()
+            .get_or_insert_with(|| Self::new(ImageLoaderConfig::default()))
+            .clone()
+    }
+
+    /// Replaces the process-wide loader used by components by default.
+    pub fn set_global(loader: ImageLoader) {
+        *GLOBAL_LOADER.write() = Some(loader);
+    }
+
+    /// Registers a fetcher for URI sources.
+    ///
+    /// Fetchers registered later take precedence for URIs several fetchers
+    /// support.
+    pub fn register_fetcher(&self, fetcher: impl ImageFetcher) {
+        self.inner.fetchers.write().insert(0, Arc::new(fetcher));
+    }
+
+    /// Returns the decoded image from the memory cache, if present.
+    pub fn cached(&self, source: &ImageSource, target: ImageTargetSize) -> Option<Arc<ImageData>> {
+        self.inner
+            .memory
+            .lock()
+            .get(&RequestKey::new(source, target))
+    }
+
+    /// Drops every decoded image from the memory cache.
+    pub fn clear_memory_cache(&self) {
+        self.inner.memory.lock().clear();
+    }
+
+    /// Starts loading `source` on the worker pool.
+    ///
+    /// `on_complete` runs once on the UI thread with the ready or failed
+    /// state, unless the returned request is dropped first. Requests for the
+    /// same source and target share one load.
+    pub fn load<F>(
+        &self,
+        source: ImageSource,
+        target: ImageTargetSize,
+        on_complete: F,
+    ) -> ImageRequest
+    where
+        F: Fn(ImageLoadState) + Send + Sync + 'static,
+    {
+        let key = RequestKey::new(&source, target);
+        let id = self
+            .inner
+            .next_subscriber_id
+            .fetch_add(1, Ordering::Relaxed);
+        let loader = Arc::downgrade(&self.inner);
+        let callback: SubscriberCallback = Arc::new(on_complete);
+        let (subscriber, subscription) = Subscriber::new(id, callback, move |id| {
+            if let Some(loader) = loader.upgrade() {
+                loader.unsubscribe(key, id);
+            }
+        });
+        let request = ImageRequest {
+            _subscription: subscription,
+        };
+
+        if let Some(data) = self.inner.memory.lock().get(&key) {
+            deliver(&subscriber, ImageLoadState::Ready(data));
+            return request;
+        }
+
+        let cancelled = {
+            let mut in_flight = self.inner.in_flight.lock();
+            if let Some(entry) = in_flight.get_mut(&key) {
+                entry.subscribers.push(subscriber);
+                return request;
+            }
+            let cancelled = Arc::new(AtomicBool::new(false));
+            in_flight.insert(
+                key,
+                InFlight {
+                    subscribers: vec![subscriber],
+                    cancelled: cancelled.clone(),
+                },
+            );
+            cancelled
+        };
+        self.inner.schedule(Job {
+            key,
+            source,
+            cancelled,
+        });
+        request
+    }
+
+    /// Loads `source` on the calling thread, using and filling the caches.
+    pub fn load_blocking(
+        &self,
+        source: &ImageSource,
+        target: ImageTargetSize,
+    ) -> Result<Arc<ImageData>, ImageRequestError> {
+        let key = RequestKey::new(source, target);
+        if let Some(data) = self.inner.memory.lock().get(&key) {
+            return Ok(data);
+        }
+        let data = self.inner.load_uncached(source, target)?;
+        self.inner.memory.lock().insert(key, data.clone());
+        Ok(data)
+    }
+
+    fn id(&self) -> usize {
+        Arc::as_ptr(&self.inner) as usize
+    }
+}
+
+impl PartialEq for ImageLoader {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl fmt::Debug for ImageLoader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ImageLoader")
+            .field("config", &self.inner.config)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Handle for a pending asynchronous image load.
+///
+/// Dropping the handle stops its completion callback. The load itself is
+/// cancelled once every handle sharing it has been dropped.
+pub struct ImageRequest {
+    _subscription: Subscription,
+}
+
+impl LoaderInner {
+    fn schedule(self: &Arc<Self>, job: Job) {
+        #[cfg(target_family = "wasm")]
+        wasm_bindgen_futures::spawn_local(self.clone().run_job_in_browser(job));
+        #[cfg(not(target_family = "wasm"))]
+        {
+            if let Err(mpsc::SendError(job)) = self.job_sender().send(job) {
+                self.run_job(job);
+            }
+        }
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn job_sender(self: &Arc<Self>) -> mpsc::Sender<Job> {
+        let mut jobs = self.jobs.lock();
+        if let Some(sender) = jobs.as_ref() {
+            return sender.clone();
+        }
+
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        for index in 0..self.config.worker_count.max(1) {
+            let receiver = receiver.clone();
+            let loader = Arc::downgrade(self);
+            let spawned = std::thread::Builder::new()
+                .name(format!("tessera-image-{index}"))
+                .spawn(move || {
+                    loop {
+                        let job = receiver.lock().recv();
+                        let (Ok(job), Some(loader)) = (job, loader.upgrade()) else {
+                            break;
+                        };
+                        loader.run_job(job);
+                    }
+                });
+            if let Err(err) = spawned {
+                tracing::warn!("Failed to spawn image loader worker: {err}");
+            }
+        }
+        *jobs = Some(sender.clone());
+        sender
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn run_job(&self, job: Job) {
+        if job.cancelled.load(Ordering::Acquire) {
+            return;
+        }
+        let result = self.load_uncached(&job.source, job.key.target);
+        self.complete(job, result);
+    }
+
+    /// Decodes with the browser's asynchronous image decoder so large images
+    /// do not block the UI thread, which is the only thread on the web.
+    #[cfg(target_family = "wasm")]
+    async fn run_job_in_browser(self: Arc<Self>, job: Job) {
+        if job.cancelled.load(Ordering::Acquire) {
+            return;
+        }
+        let target = job.key.target;
+        let result = match self.read_source(&job.source) {
+            Ok(bytes) => match browser_decode(&bytes, target).await {
+                Ok(data) => Ok(Arc::new(data)),
+                Err(err) => {
+                    // Formats the browser cannot decode, such as QOI, use the
+                    // bundled decoders instead.
+                    tracing::debug!("Browser image decoding failed: {err:?}");
+                    decode(&bytes)
+                        .map(|decoded| Arc::new(downsample(decoded, target)))
+                        .map_err(ImageRequestError::from)
+                }
+            },
+            Err(err) => Err(err),
+        };
+        if job.cancelled.load(Ordering::Acquire) {
+            return;
+        }
+        self.complete(job, result);
+    }
+
+    fn complete(&self, job: Job, result: Result<Arc<ImageData>, ImageRequestError>) {
+        let state = match result {
+            Ok(data) => {
+                self.memory.lock().insert(job.key, data.clone());
+                ImageLoadState::Ready(data)
+            }
+            Err(err) => ImageLoadState::Failed(Arc::new(err)),
+        };
+
+        let entry = {
+            let mut in_flight = self.in_flight.lock();
+            // A newer load for the same key may have replaced a cancelled one.
+            let is_current = in_flight
+                .get(&job.key)
+                .is_some_and(|entry| Arc::ptr_eq(&entry.cancelled, &job.cancelled));
+            if !is_current {
+                return;
+            }
+            in_flight.remove(&job.key)
+        };
+        for subscriber in entry.into_iter().flat_map(|entry| entry.subscribers) {
+            deliver(&subscriber, state.clone());
+        }
+    }
+
+    fn unsubscribe(&self, key: RequestKey, id: u64) {
+        let mut in_flight = self.in_flight.lock();
+        let Some(entry) = in_flight.get_mut(&key) else {
+            return;
+        };
+        entry.subscribers.retain(|subscriber| subscriber.id() != id);
+        if entry.subscribers.is_empty() {
+            entry.cancelled.store(true, Ordering::Release);
+            in_flight.remove(&key);
+        }
+    }
+
+    fn load_uncached(
+        &self,
+        source: &ImageSource,
+        target: ImageTargetSize,
+    ) -> Result<Arc<ImageData>, ImageRequestError> {
+        let bytes = self.read_source(source)?;
+        Ok(Arc::new(downsample(decode(&bytes)?, target)))
+    }
+
+    fn read_source(&self, source: &ImageSource) -> Result<Arc<[u8]>, ImageRequestError> {
+        match &source.repr {
+            ImageSourceRepr::File(path) => {
+                fs::read(path)
+                    .map(Arc::from)
+                    .map_err(|source| ImageRequestError::Read {
+                        path: path.clone(),
+                        source,
+                    })
+            }
+            ImageSourceRepr::Uri(uri) => self.fetch_uri(uri, source.cache_key()).map(Arc::from),
+            ImageSourceRepr::Bytes(bytes) => Ok(bytes.clone()),
+            ImageSourceRepr::Asset(read) => {
+                read().map_err(|source| ImageLoadError::AssetRead { source }.into())
+            }
+        }
+    }
+
+    fn fetch_uri(&self, uri: &str, key: u64) -> Result<Vec<u8>, ImageRequestError> {
+        let cache_path = self.disk_cache_path(key);
+        if let Some(path) = cache_path.as_deref()
+            && let Ok(bytes) = fs::read(path)
+        {
+            touch(path);
+            return Ok(bytes);
+        }
+
+        let fetcher = self
+            .fetchers
+            .read()
+            .iter()
+            .find(|fetcher| fetcher.supports(uri))
+            .cloned()
+            .ok_or_else(|| ImageRequestError::UnsupportedUri {
+                uri: uri.to_string(),
+            })?;
+        let bytes = fetcher
+            .fetch(uri)
+            .map_err(|source| ImageRequestError::Fetch {
+                uri: uri.to_string(),
+                source,
+            })?;
+
+        if let Some(path) = cache_path.as_deref() {
+            self.store_disk_cache_entry(path, &bytes);
+        }
+        Ok(bytes)
+    }
+
+    fn disk_cache_path(&self, key: u64) -> Option<PathBuf> {
+        self.config
+            .disk_cache_dir
+            .as_ref()
+            .map(|dir| dir.join(format!("{key:016x}.{DISK_CACHE_EXTENSION}")))
+    }
+
+    fn store_disk_cache_entry(&self, path: &Path, bytes: &[u8]) {
+        let Some(dir) = path.parent() else {
+            return;
+        };
+        // Every write gets its own temp file, so loaders in this or another
+        // process sharing the directory never interleave their bytes.
+        let temp_path = path.with_extension(format!(
+            "{}-{}.tmp",
+            std::process::id(),
+            NEXT_DISK_CACHE_TEMP_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        let written = fs::create_dir_all(dir)
+            .and_then(|()| fs::write(&temp_path, bytes))
+            .and_then(|()| fs::rename(&temp_path, path));
+        if let Err(err) = written {
+            tracing::warn!(
+                "Failed to write image disk cache entry {}: {err}",
+                path.display()
+            );
+            let _ = fs::remove_file(&temp_path);
+            return;
+        }
+        if let Err(err) = trim_disk_cache(dir, self.config.disk_cache_bytes) {
+            tracing::warn!("Failed to trim image disk cache {}: {err}", dir.display());
+        }
+    }
+}
+
+fn decode(bytes: &[u8]) -> Result<DynamicImage, ImageLoadError> {
+    Ok(image::load_from_memory(bytes)?)
+}
+
+fn downsample(decoded: DynamicImage, target: ImageTargetSize) -> ImageData {
+    let (width, height) = decoded.dimensions();
+    let (target_width, target_height) = target_dimensions(width, height, target);
+    if (target_width, target_height) == (width, height) {
+        return decode_dynamic_image(decoded);
+    }
+    decode_dynamic_image(decoded.resize_exact(target_width, target_height, FilterType::Triangle))
+}
+
+/// Returns the size that fits `width` x `height` inside `target` while
+/// keeping the aspect ratio, without upscaling.
+fn target_dimensions(width: u32, height: u32, target: ImageTargetSize) -> (u32, u32) {
+    let max_width = if target.width == 0 {
+        width
+    } else {
+        target.width
+    };
+    let max_height = if target.height == 0 {
+        height
+    } else {
+        target.height
+    };
+    if width <= max_width && height <= max_height {
+        return (width, height);
+    }
+    let scale =
+        (f64::from(max_width) / f64::from(width)).min(f64::from(max_height) / f64::from(height));
+    let fit = |len: u32| ((f64::from(len) * scale).round() as u32).max(1);
+    (fit(width), fit(height))
+}
+
+/// Decodes and downsamples `bytes` with `createImageBitmap`, which runs off
+/// the main thread in browsers.
+#[cfg(target_family = "wasm")]
+async fn browser_decode(
+    bytes: &[u8],
+    target: ImageTargetSize,
+) -> Result<ImageData, wasm_bindgen::JsValue> {
+    use wasm_bindgen::{JsCast, JsValue};
+    use wasm_bindgen_futures::JsFuture;
+
+    let window = web_sys::window().ok_or_else(|| JsValue::from_str("no window"))?;
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes));
+    let blob = web_sys::Blob::new_with_u8_array_sequence(&parts)?;
+    let options = web_sys::ImageBitmapOptions::new();
+    options.set_premultiply_alpha(web_sys::PremultiplyAlpha::None);
+    options.set_color_space_conversion(web_sys::ColorSpaceConversion::None);
+    let bitmap: web_sys::ImageBitmap = JsFuture::from(
+        window.create_image_bitmap_with_blob_and_image_bitmap_options(&blob, &options)?,
+    )
+    .await?
+    .dyn_into()?;
+
+    let (width, height) = target_dimensions(bitmap.width(), bitmap.height(), target);
+    let canvas = web_sys::OffscreenCanvas::new(width, height)?;
+    let context: web_sys::OffscreenCanvasRenderingContext2d = canvas
+        .get_context("2d")?
+        .ok_or_else(|| JsValue::from_str("no 2d context"))?
+        .dyn_into()?;
+    context.draw_image_with_image_bitmap_and_dw_and_dh(
+        &bitmap,
+        0.0,
+        0.0,
+        f64::from(width),
+        f64::from(height),
+    )?;
+    bitmap.close();
+    let pixels = context.get_image_data(0.0, 0.0, f64::from(width), f64::from(height))?;
+    Ok(ImageData {
+        data: Arc::new(pixels.data().0),
+        width,
+        height,
+    })
+}
+
+/// Marks a disk cache entry as recently used.
+fn touch(path: &Path) {
+    let _ = fs::File::options()
+        .write(true)
+        .open(path)
+        .and_then(|file| file.set_modified(SystemTime::now()));
+}
+
+fn trim_disk_cache(dir: &Path, max_bytes: u64) -> io::Result<()> {
+    let mut entries = Vec::new();
+    let mut total = 0u64;
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some(DISK_CACHE_EXTENSION) {
+            continue;
+        }
+        let metadata = entry.metadata()?;
+        total += metadata.len();
+        let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+        entries.push((modified, metadata.len(), path));
+    }
+    if total <= max_bytes {
+        return Ok(());
+    }
+
+    entries.sort_by_key(|(modified, _, _)| *modified);
+    for (_, len, path) in entries {
+        if total <= max_bytes {
+            break;
+        }
+        fs::remove_file(&path)?;
+        total -= len;
+    }
+    Ok(())
+}
+
+/// FNV-1a hash, stable across runs so disk cache entries stay addressable.
+fn stable_hash(parts: &[&[u8]]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    parts
+        .iter()
+        .flat_map(|part| part.iter())
+        .fold(OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+        })
+}
+
+/// Loads an image asynchronously and remembers its state during component
+/// builds.
+///
+/// The load starts on the first build for a source and target size, and is
+/// cancelled when the calling component leaves the tree or requests another
+/// source. Images already in the memory cache are ready immediately.
+pub fn remember_image(
+    loader: &ImageLoader,
+    source: ImageSource,
+    target: ImageTargetSize,
+) -> State<ImageLoadState> {
+    let key = (loader.id(), source.cache_key(), target);
+    let state = remember_with_key(key, || {
+        loader
+            .cached(&source, target)
+            .map_or(ImageLoadState::Loading, ImageLoadState::Ready)
+    });
+    let loader = loader.clone();
+    remember_with_key(key, move || {
+        state
+            .with(|current| matches!(current, ImageLoadState::Loading))
+            .then(|| loader.load(source, target, move |result| state.set(result)))
+    });
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, sync::atomic::AtomicUsize};
+
+    use super::*;
+
+    fn encoded_png(width: u32, height: u32) -> Vec<u8> {
+        let image = DynamicImage::ImageRgba8(image::RgbaImage::new(width, height));
+        let mut bytes = Cursor::new(Vec::new());
+        image
+            .write_to(&mut bytes, image::ImageFormat::Png)
+            .expect("png encoding should succeed");
+        bytes.into_inner()
+    }
+
+    struct CountingFetcher {
+        bytes: Vec<u8>,
+        fetches: Arc<AtomicUsize>,
+    }
+
+    impl ImageFetcher for CountingFetcher {
+        fn supports(&self, uri: &str) -> bool {
+            uri.starts_with("test://")
+        }
+
+        fn fetch(&self, _uri: &str) -> io::Result<Vec<u8>> {
+            self.fetches.fetch_add(1, Ordering::SeqCst);
+            Ok(self.bytes.clone())
+        }
+    }
+
+    fn loader_with_fetcher(config: ImageLoaderConfig) -> (ImageLoader, Arc<AtomicUsize>) {
+        let loader = ImageLoader::new(config);
+        let fetches = Arc::new(AtomicUsize::new(0));
+        loader.register_fetcher(CountingFetcher {
+            bytes: encoded_png(8, 8),
+            fetches: fetches.clone(),
+        });
+        (loader, fetches)
+    }
+
+    #[test]
+    fn string_sources_detect_uris() {
+        assert_eq!(ImageSource::from("https://example.com/a.png").kind(), "uri");
+        assert_eq!(ImageSource::from("photos/a.png").kind(), "file");
+        assert_eq!(
+            ImageSource::from("file:///tmp/a.png"),
+            ImageSource::file("/tmp/a.png")
+        );
+    }
+
+    #[test]
+    fn load_blocking_downsamples_to_target() {
+        let loader = ImageLoader::new(ImageLoaderConfig::default());
+        let source = ImageSource::bytes(encoded_png(64, 32));
+
+        let data = loader
+            .load_blocking(&source, ImageTargetSize::new(16, 16))
+            .expect("image should load");
+        assert_eq!((data.width, data.height), (16, 8));
+
+        let original = loader
+            .load_blocking(&source, ImageTargetSize::ORIGINAL)
+            .expect("image should load");
+        assert_eq!((original.width, original.height), (64, 32));
+    }
+
+    #[test]
+    fn memory_cache_skips_repeated_fetches() {
+        let (loader, fetches) = loader_with_fetcher(ImageLoaderConfig::default());
+        let source = ImageSource::uri("test://memory");
+
+        loader
+            .load_blocking(&source, ImageTargetSize::ORIGINAL)
+            .expect("image should load");
+        loader
+            .load_blocking(&source, ImageTargetSize::ORIGINAL)
+            .expect("image should load");
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+        assert!(loader.cached(&source, ImageTargetSize::ORIGINAL).is_some());
+    }
+
+    #[test]
+    fn disk_cache_is_shared_between_loaders() {
+        let dir =
+            std::env::temp_dir().join(format!("tessera-image-loader-test-{}", std::process::id()));
+        let config = ImageLoaderConfig {
+            disk_cache_dir: Some(dir.clone()),
+            ..Default::default()
+        };
+        let source = ImageSource::uri("test://disk");
+
+        let (first, first_fetches) = loader_with_fetcher(config.clone());
+        first
+            .load_blocking(&source, ImageTargetSize::ORIGINAL)
+            .expect("image should load");
+        let (second, second_fetches) = loader_with_fetcher(config);
+        second
+            .load_blocking(&source, ImageTargetSize::ORIGINAL)
+            .expect("image should load from disk");
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(first_fetches.load(Ordering::SeqCst), 1);
+        assert_eq!(second_fetches.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn unsupported_uri_fails() {
+        let loader = ImageLoader::new(ImageLoaderConfig::default());
+        let result = loader.load_blocking(
+            &ImageSource::uri("ftp://example.com/a.png"),
+            ImageTargetSize::ORIGINAL,
+        );
+        assert!(matches!(
+            result,
+            Err(ImageRequestError::UnsupportedUri { .. })
+        ));
+    }
+
+    #[test]
+    fn memory_cache_evicts_least_recently_used() {
+        let image = |len: usize| {
+            Arc::new(ImageData {
+                data: Arc::new(vec![0; len]),
+                width: 1,
+                height: 1,
+            })
+        };
+        let key = |source: u64| RequestKey {
+            source,
+            target: ImageTargetSize::ORIGINAL,
+        };
+        let mut cache = MemoryCache::new(10);
+        cache.insert(key(1), image(4));
+        cache.insert(key(2), image(4));
+        assert!(cache.get(&key(1)).is_some());
+        cache.insert(key(3), image(4));
+
+        assert!(cache.get(&key(1)).is_some());
+        assert!(cache.get(&key(2)).is_none());
+        assert!(cache.get(&key(3)).is_some());
+        assert_eq!(cache.bytes, 8);
+    }
+
+    #[test]
+    fn requests_share_one_load_and_cancel_when_dropped() {
+        struct BlockingFetcher {
+            release: Mutex<mpsc::Receiver<()>>,
+        }
+
+        impl ImageFetcher for BlockingFetcher {
+            fn supports(&self, _uri: &str) -> bool {
+                true
+            }
+
+            fn fetch(&self, _uri: &str) -> io::Result<Vec<u8>> {
+                let _ = self.release.lock().recv();
+                Ok(encoded_png(1, 1))
+            }
+        }
+
+        let loader = ImageLoader::new(ImageLoaderConfig {
+            worker_count: 1,
+            ..Default::default()
+        });
+        let (release, receiver) = mpsc::channel();
+        loader.register_fetcher(BlockingFetcher {
+            release: Mutex::new(receiver),
+        });
+        let source = ImageSource::uri("test://shared");
+        let key = RequestKey::new(&source, ImageTargetSize::ORIGINAL);
+
+        let first = loader.load(source.clone(), ImageTargetSize::ORIGINAL, |_| {});
+        let second = loader.load(source, ImageTargetSize::ORIGINAL, |_| {});
+        let cancelled = {
+            let in_flight = loader.inner.in_flight.lock();
+            let entry = in_flight.get(&key).expect("load should be in flight");
+            assert_eq!(entry.subscribers.len(), 2);
+            entry.cancelled.clone()
+        };
+
+        drop(first);
+        assert!(!cancelled.load(Ordering::Acquire));
+        drop(second);
+        assert!(cancelled.load(Ordering::Acquire));
+        assert!(loader.inner.in_flight.lock().is_empty());
+        let _ = release.send(());
+    }
+
+    #[test]
+    fn byte_sources_hash_once_and_share_the_key() {
+        let bytes: Arc<[u8]> = encoded_png(4, 4).into();
+        let source = ImageSource::bytes(bytes.clone());
+        let clone = source.clone();
+        assert!(clone.key.get().is_none());
+
+        let key = source.cache_key();
+        assert_eq!(clone.key.get(), Some(&key));
+        assert_eq!(ImageSource::bytes(bytes.clone()), source);
+        assert_ne!(ImageSource::bytes_with_key("photo-1", bytes), source);
+    }
+
+    #[test]
+    fn disk_cache_writes_use_unique_temp_files() {
+        let dir = std::env::temp_dir().join(format!(
+            "tessera-image-loader-temp-test-{}",
+            std::process::id()
+        ));
+        let loader = ImageLoader::new(ImageLoaderConfig {
+            disk_cache_dir: Some(dir.clone()),
+            ..Default::default()
+        });
+        let path = loader
+            .inner
+            .disk_cache_path(7)
+            .expect("disk cache is enabled");
+
+        std::thread::scope(|scope| {
+            for fill in 0..8u8 {
+                let (loader, path) = (&loader, &path);
+                scope.spawn(move || loader.inner.store_disk_cache_entry(path, &[fill; 4096]));
+            }
+        });
+        let bytes = fs::read(&path).expect("cache entry should exist");
+        let leftovers = fs::read_dir(&dir)
+            .expect("cache dir should exist")
+            .filter(|entry| {
+                entry
+                    .as_ref()
+                    .is_ok_and(|entry| entry.path().extension().is_some_and(|ext| ext == "tmp"))
+            })
+            .count();
+        let _ = fs::remove_dir_all(&dir);
+
+        // Whichever write landed last, the entry holds one complete write.
+        assert_eq!(bytes.len(), 4096);
+        assert!(bytes.iter().all(|byte| *byte == bytes[0]));
+        assert_eq!(leftovers, 0);
+    }
+
+    #[test]
+    fn target_dimensions_fit_without_upscaling() {
+        assert_eq!(
+            target_dimensions(64, 32, ImageTargetSize::new(16, 16)),
+            (16, 8)
+        );
+        assert_eq!(
+            target_dimensions(64, 32, ImageTargetSize::new(0, 8)),
+            (16, 8)
+        );
+        assert_eq!(
+            target_dimensions(64, 32, ImageTargetSize::new(128, 128)),
+            (64, 32)
+        );
+        assert_eq!(
+            target_dimensions(1000, 1, ImageTargetSize::new(10, 10)),
+            (10, 1)
+        );
+    }
+
+    #[test]
+    fn target_size_coverage() {
+        let measured = ImageTargetSize::new(100, 50);
+        assert!(ImageTargetSize::ORIGINAL.covers(measured));
+        assert!(ImageTargetSize::new(200, 50).covers(measured));
+        assert!(!ImageTargetSize::new(80, 80).covers(measured));
+        assert!(!measured.covers(ImageTargetSize::new(0, 50)));
+    }
+}
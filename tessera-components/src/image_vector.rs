@@ -11,19 +11,11 @@ use std::{
     sync::Arc,
 };
 
-use lyon_geom::point;
-use lyon_path::Path as LyonPath;
-use lyon_tessellation::{
-    BuffersBuilder, FillOptions, FillRule as LyonFillRule, FillTessellator, FillVertex,
-    LineCap as LyonLineCap, LineJoin as LyonLineJoin, StrokeOptions, StrokeTessellator,
-    StrokeVertex, VertexBuffers,
-};
 use tessera_ui::Color;
-use thiserror::Error;
-use usvg::{
-    BlendMode, FillRule, Group, LineCap as SvgLineCap, LineJoin as SvgLineJoin, Node, Paint,
-    PaintOrder, Path, Stroke, Tree, tiny_skia_path::PathSegment,
+use tessera_vector_mesh::{
+    VECTOR_MESH_MAGIC, VectorMesh, VectorMeshError, decode_vector_mesh, tessellate_svg,
 };
+use thiserror::Error;
 
 use crate::pipelines::image_vector::command::ImageVectorVertex;
 
@@ -66,6 +58,23 @@ pub enum ImageVectorLoadError {
     /// No renderable geometry was produced.
     #[error("SVG produced no renderable paths")]
     EmptyGeometry,
+    /// A pre-tessellated vector mesh is truncated or malformed.
+    #[error("invalid pre-tessellated vector mesh")]
+    InvalidMesh,
+}
+
+impl From<VectorMeshError> for ImageVectorLoadError {
+    fn from(err: VectorMeshError) -> Self {
+        match err {
+            VectorMeshError::Parse(err) => Self::Parse(err),
+            VectorMeshError::InvalidViewport => Self::InvalidViewport,
+            VectorMeshError::UnsupportedFeature(feature) => Self::UnsupportedFeature(feature),
+            VectorMeshError::TransformFailed => Self::TransformFailed,
+            VectorMeshError::Tessellation(err) => Self::Tessellation(err),
+            VectorMeshError::EmptyGeometry => Self::EmptyGeometry,
+            VectorMeshError::InvalidMesh => Self::InvalidMesh,
+        }
+    }
 }
 
 /// Converts a source into shared vector geometry data.
@@ -76,15 +85,35 @@ pub trait TryIntoImageVectorData {
 
 fn load_image_vector_from_bytes(
     bytes: &[u8],
-    resources_dir: Option<PathBuf>,
+    resources_dir: Option<&StdPath>,
 ) -> Result<ImageVectorData, ImageVectorLoadError> {
-    let options = usvg::Options {
-        resources_dir,
-        ..Default::default()
+    // Meshes pre-tessellated by `tessera-build` skip SVG parsing.
+    let mesh = if bytes.starts_with(VECTOR_MESH_MAGIC) {
+        decode_vector_mesh(bytes)?
+    } else {
+        tessellate_svg(bytes, resources_dir)?
     };
-    let tree = Tree::from_data(bytes, &options)?;
-
-    build_vector_data(&tree)
+    Ok(vector_data_from_mesh(mesh))
+}
+
+fn vector_data_from_mesh(mesh: VectorMesh) -> ImageVectorData {
+    let vertices = mesh
+        .vertices
+        .into_iter()
+        .map(|vertex| {
+            let [r, g, b, a] = vertex.color;
+            ImageVectorVertex {
+                position: vertex.position,
+                color: Color::new(r, g, b, a),
+            }
+        })
+        .collect();
+    ImageVectorData::new(
+        mesh.viewport[0],
+        mesh.viewport[1],
+        Arc::new(vertices),
+        Arc::new(mesh.indices),
+    )
 }
 
 fn load_image_vector_from_path(path: &StdPath) -> Result<ImageVectorData, ImageVectorLoadError> {
@@ -93,8 +122,7 @@ fn load_image_vector_from_path(path: &StdPath) -> Result<ImageVectorData, ImageV
         path: path_text,
         source,
     })?;
-    let resources_dir = path.parent().map(|parent| parent.to_path_buf());
-    load_image_vector_from_bytes(&bytes, resources_dir)
+    load_image_vector_from_bytes(&bytes, path.parent())
 }
 
 impl TryIntoImageVectorData for ImageVectorData {
@@ -138,259 +166,3 @@ impl TryIntoImageVectorData for &StdPath {
         Ok(Arc::new(load_image_vector_from_path(self)?))
     }
 }
-
-fn build_vector_data(tree: &Tree) -> Result<ImageVectorData, ImageVectorLoadError> {
-    let size = tree.size();
-    let viewport_width = size.width();
-    let viewport_height = size.height();
-
-    if !viewport_width.is_finite()
-        || !viewport_height.is_finite()
-        || viewport_width <= 0.0
-        || viewport_height <= 0.0
-    {
-        return Err(ImageVectorLoadError::InvalidViewport);
-    }
-
-    let mut collector = VectorGeometryCollector::new(viewport_width, viewport_height);
-    visit_group(tree.root(), 1.0, &mut collector)?;
-
-    collector.finish()
-}
-
-fn visit_group(
-    group: &Group,
-    inherited_opacity: f32,
-    collector: &mut VectorGeometryCollector,
-) -> Result<(), ImageVectorLoadError> {
-    if group.clip_path().is_some() || group.mask().is_some() || !group.filters().is_empty() {
-        return Err(ImageVectorLoadError::UnsupportedFeature(
-            "clip paths, masks, and filters are not supported".to_string(),
-        ));
-    }
-
-    if group.blend_mode() != BlendMode::Normal {
-        return Err(ImageVectorLoadError::UnsupportedFeature(
-            "non-normal blend modes".to_string(),
-        ));
-    }
-
-    let accumulated_opacity = inherited_opacity * group.opacity().get();
-
-    for node in group.children() {
-        match node {
-            Node::Group(child) => visit_group(child, accumulated_opacity, collector)?,
-            Node::Path(path) => collector.process_path(path, accumulated_opacity)?,
-            Node::Image(_) | Node::Text(_) => {
-                return Err(ImageVectorLoadError::UnsupportedFeature(
-                    "non-path nodes in SVG are not supported".to_string(),
-                ));
-            }
-        }
-    }
-
-    Ok(())
-}
-
-struct VectorGeometryCollector {
-    viewport_width: f32,
-    viewport_height: f32,
-    buffers: VertexBuffers<ImageVectorVertex, u32>,
-}
-
-impl VectorGeometryCollector {
-    fn new(viewport_width: f32, viewport_height: f32) -> Self {
-        Self {
-            viewport_width,
-            viewport_height,
-            buffers: VertexBuffers::new(),
-        }
-    }
-
-    fn process_path(
-        &mut self,
-        path: &Path,
-        inherited_opacity: f32,
-    ) -> Result<(), ImageVectorLoadError> {
-        if !path.is_visible() {
-            return Ok(());
-        }
-
-        if path.rendering_mode() != usvg::ShapeRendering::default() {
-            return Err(ImageVectorLoadError::UnsupportedFeature(
-                "shape-rendering modes are not supported".to_string(),
-            ));
-        }
-
-        let lyon_path = convert_to_lyon_path(path)?;
-
-        match path.paint_order() {
-            PaintOrder::FillAndStroke => {
-                if let Some(fill) = path.fill() {
-                    self.tessellate_fill(&lyon_path, fill, inherited_opacity)?;
-                }
-                if let Some(stroke) = path.stroke() {
-                    self.tessellate_stroke(&lyon_path, stroke, inherited_opacity)?;
-                }
-            }
-            PaintOrder::StrokeAndFill => {
-                if let Some(stroke) = path.stroke() {
-                    self.tessellate_stroke(&lyon_path, stroke, inherited_opacity)?;
-                }
-                if let Some(fill) = path.fill() {
-                    self.tessellate_fill(&lyon_path, fill, inherited_opacity)?;
-                }
-            }
-        }
-
-        Ok(())
-    }
-
-    fn tessellate_fill(
-        &mut self,
-        path: &LyonPath,
-        fill: &usvg::Fill,
-        inherited_opacity: f32,
-    ) -> Result<(), ImageVectorLoadError> {
-        let color = color_from_paint(fill.paint(), fill.opacity().get(), inherited_opacity)?;
-        let fill_rule = match fill.rule() {
-            FillRule::EvenOdd => LyonFillRule::EvenOdd,
-            FillRule::NonZero => LyonFillRule::NonZero,
-        };
-
-        let options = FillOptions::default().with_fill_rule(fill_rule);
-        let viewport = [self.viewport_width, self.viewport_height];
-
-        FillTessellator::new().tessellate_path(
-            path,
-            &options,
-            &mut BuffersBuilder::new(&mut self.buffers, |vertex: FillVertex| {
-                ImageVectorVertex::new(vertex.position().to_array(), color, viewport)
-            }),
-        )?;
-
-        Ok(())
-    }
-
-    fn tessellate_stroke(
-        &mut self,
-        path: &LyonPath,
-        stroke: &Stroke,
-        inherited_opacity: f32,
-    ) -> Result<(), ImageVectorLoadError> {
-        if stroke.dasharray().is_some() {
-            return Err(ImageVectorLoadError::UnsupportedFeature(
-                "stroke dash arrays".to_string(),
-            ));
-        }
-
-        let color = color_from_paint(stroke.paint(), stroke.opacity().get(), inherited_opacity)?;
-
-        let mut options = StrokeOptions::default()
-            .with_line_width(stroke.width().get())
-            .with_line_cap(map_line_cap(stroke.linecap()))
-            .with_line_join(map_line_join(stroke.linejoin()));
-
-        options.miter_limit = stroke.miterlimit().get();
-
-        let viewport = [self.viewport_width, self.viewport_height];
-
-        StrokeTessellator::new().tessellate_path(
-            path,
-            &options,
-            &mut BuffersBuilder::new(&mut self.buffers, |vertex: StrokeVertex| {
-                ImageVectorVertex::new(vertex.position().to_array(), color, viewport)
-            }),
-        )?;
-
-        Ok(())
-    }
-
-    fn finish(self) -> Result<ImageVectorData, ImageVectorLoadError> {
-        if self.buffers.vertices.is_empty() || self.buffers.indices.is_empty() {
-            return Err(ImageVectorLoadError::EmptyGeometry);
-        }
-
-        Ok(ImageVectorData::new(
-            self.viewport_width,
-            self.viewport_height,
-            Arc::new(self.buffers.vertices),
-            Arc::new(self.buffers.indices),
-        ))
-    }
-}
-
-fn color_from_paint(
-    paint: &Paint,
-    paint_opacity: f32,
-    inherited_opacity: f32,
-) -> Result<Color, ImageVectorLoadError> {
-    let opacity = (paint_opacity * inherited_opacity).clamp(0.0, 1.0);
-    match paint {
-        Paint::Color(color) => Ok(Color::new(
-            f32::from(color.red) / 255.0,
-            f32::from(color.green) / 255.0,
-            f32::from(color.blue) / 255.0,
-            opacity,
-        )),
-        _ => Err(ImageVectorLoadError::UnsupportedFeature(
-            "only solid color fills and strokes are supported".to_string(),
-        )),
-    }
-}
-
-fn convert_to_lyon_path(path: &Path) -> Result<LyonPath, ImageVectorLoadError> {
-    let transformed = path
-        .data()
-        .clone()
-        .transform(path.abs_transform())
-        .ok_or(ImageVectorLoadError::TransformFailed)?;
-
-    let mut builder = LyonPath::builder().with_svg();
-    for segment in transformed.segments() {
-        match segment {
-            PathSegment::MoveTo(p0) => {
-                builder.move_to(point(p0.x, p0.y));
-            }
-            PathSegment::LineTo(p0) => {
-                builder.line_to(point(p0.x, p0.y));
-            }
-            PathSegment::QuadTo(p0, p1) => {
-                builder.quadratic_bezier_to(point(p0.x, p0.y), point(p1.x, p1.y));
-            }
-            PathSegment::CubicTo(p0, p1, p2) => {
-                builder.cubic_bezier_to(point(p0.x, p0.y), point(p1.x, p1.y), point(p2.x, p2.y));
-            }
-            PathSegment::Close => {
-                builder.close();
-            }
-        }
-    }
-
-    Ok(builder.build())
-}
-
-fn map_line_cap(cap: SvgLineCap) -> LyonLineCap {
-    match cap {
-        SvgLineCap::Butt => LyonLineCap::Butt,
-        SvgLineCap::Round => LyonLineCap::Round,
-        SvgLineCap::Square => LyonLineCap::Square,
-    }
-}
-
-fn map_line_join(join: SvgLineJoin) -> LyonLineJoin {
-    match join {
-        SvgLineJoin::Miter | SvgLineJoin::MiterClip => LyonLineJoin::Miter,
-        SvgLineJoin::Round => LyonLineJoin::Round,
-        SvgLineJoin::Bevel => LyonLineJoin::Bevel,
-    }
-}
-
-impl ImageVectorVertex {
-    fn new(position: [f32; 2], color: Color, viewport: [f32; 2]) -> Self {
-        ImageVectorVertex {
-            position: [position[0] / viewport[0], position[1] / viewport[1]],
-            color,
-        }
-    }
-}
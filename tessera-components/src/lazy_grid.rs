@@ -12,14 +12,19 @@ use std::{
 
 use tessera_ui::{
     AxisConstraint, CallbackWith, Color, ComputedData, Constraint, Dp, FocusDirection,
-    LayoutResult, MeasurementError, Modifier, ParentConstraint, Px, PxPosition, State, key,
+    LayoutResult, MeasurementError, Modifier, ParentConstraint, Px, PxPosition, State,
+    accesskit::Role,
+    key,
     layout::{LayoutChild, LayoutPolicy, MeasureScope, PlacementScope, layout},
-    modifier::FocusModifierExt as _,
+    modifier::{FocusModifierExt as _, ModifierCapabilityExt as _},
     remember, tessera,
 };
 
 use crate::{
     alignment::{CrossAxisAlignment, MainAxisAlignment},
+    lazy_semantics::{
+        LazyCollectionSemantics, LazyItemSemantics, LazySemanticsAxis, reveal_scroll_offset,
+    },
     scrollable::{ScrollBarBehavior, ScrollBarLayout, ScrollableController, scrollable},
 };
 
@@ -95,6 +100,7 @@ impl Default for GridCells {
 /// Persistent state shared by lazy grid components.
 pub struct LazyGridController {
     cache: LazyGridCache,
+    pending_reveal: Option<usize>,
 }
 
 impl Default for LazyGridController {
@@ -108,8 +114,18 @@ impl LazyGridController {
     pub fn new() -> Self {
         Self {
             cache: LazyGridCache::default(),
+            pending_reveal: None,
         }
     }
+
+    /// Scrolls the grid until the line holding the item at `index` is fully
+    /// visible.
+    ///
+    /// Items that are not composed yet can be targeted too; the grid scrolls
+    /// once it is next built and knows its line offsets.
+    pub fn reveal_item(&mut self, index: usize) {
+        self.pending_reveal = Some(index);
+    }
 }
 
 fn hash_key<K>(key: K) -> u64
@@ -999,6 +1015,7 @@ fn lazy_grid_view(
         return;
     }
 
+    let total_lines = controller.with(|c| c.cache.line_count());
     let focus_modifier = lazy_grid_focus_beyond_bounds_modifier(LazyGridFocusArgs {
         axis,
         controller,
@@ -1008,9 +1025,38 @@ fn lazy_grid_view(
         total_main,
         viewport_span,
         visible_line_range: visible_plan.line_range.clone(),
-        total_lines: controller.with(|c| c.cache.line_count()),
+        total_lines,
     });
 
+    let scroll = LazyGridScroll {
+        axis,
+        controller,
+        scroll_controller,
+        estimated_line_main,
+        main_axis_spacing,
+        viewport_span,
+        max_scroll: (total_main - viewport_span).max(Px::ZERO),
+    };
+    // Wait for a measured viewport so the request is not spent on estimates.
+    if axis.visible_span(visible_size) > Px::ZERO
+        && controller.with(|c| c.pending_reveal.is_some())
+        && let Some(index) = controller.with_mut(|c| c.pending_reveal.take())
+    {
+        scroll.reveal(index.min(total_count - 1) / slots_per_line.max(1));
+    }
+    let (rows, columns) = axis.collection_cell(total_lines, slots_per_line);
+    let semantics = LazyCollectionSemantics {
+        role: Role::Grid,
+        axis: axis.semantics_axis(),
+        rows,
+        columns,
+        hierarchical: false,
+        scroll_offset,
+        max_scroll: scroll.max_scroll,
+        page: viewport_span,
+        scroll_to: Arc::new(move |offset| scroll.scroll_to(offset)),
+    };
+
     let viewport_limit = viewport_span + padding_main + padding_main;
     let visible_layout_items = visible_plan
         .items
@@ -1024,7 +1070,7 @@ fn lazy_grid_view(
     let items = visible_plan.items;
     let line_range = visible_plan.line_range.clone();
     layout()
-        .modifier(focus_modifier)
+        .modifier(focus_modifier.push_semantics(semantics))
         .layout_policy(LazyGridLayout {
             axis,
             item_alignment,
@@ -1044,10 +1090,23 @@ fn lazy_grid_view(
         .child(move || {
             for child in &items {
                 let child = child.clone();
-                key(child.key_hash, || {
-                    lazy_grid_item_host()
-                        .builder_handle_shared(child.builder)
-                        .local_index(child.local_index);
+                let item_index = child.line_index * slots_per_line + child.slot_index;
+                let (row, column) = axis.collection_cell(child.line_index, child.slot_index);
+                let item_semantics = LazyItemSemantics {
+                    role: Role::GridCell,
+                    row,
+                    column,
+                    heading: false,
+                    reveal: Arc::new(move || controller.with_mut(|c| c.reveal_item(item_index))),
+                };
+                key(child.key_hash, move || {
+                    layout()
+                        .modifier(Modifier::new().push_semantics(item_semantics))
+                        .child(move || {
+                            lazy_grid_item_host()
+                                .builder_handle_shared(child.builder)
+                                .local_index(child.local_index);
+                        });
                 });
             }
         });
@@ -1223,6 +1282,23 @@ impl LazyGridAxis {
         }
     }
 
+    fn semantics_axis(&self) -> LazySemanticsAxis {
+        match self {
+            Self::Vertical => LazySemanticsAxis::Vertical,
+            Self::Horizontal => LazySemanticsAxis::Horizontal,
+        }
+    }
+
+    /// Maps a `(line, slot)` pair to accessibility `(row, column)` terms.
+    ///
+    /// Also maps line and slot counts to row and column counts.
+    fn collection_cell(&self, line: usize, slot: usize) -> (usize, usize) {
+        match self {
+            Self::Vertical => (line, slot),
+            Self::Horizontal => (slot, line),
+        }
+    }
+
     fn focus_scroll_direction(&self, direction: FocusDirection) -> Option<FocusScrollDirection> {
         match (self, direction) {
             (_, FocusDirection::Next | FocusDirection::Enter) => {
@@ -1543,6 +1619,54 @@ fn lazy_grid_focus_beyond_bounds_modifier(args: LazyGridFocusArgs) -> Modifier {
         true
     }))
 }
+
+/// Scroll state captured for accessibility scroll requests.
+#[derive(Clone, Copy)]
+struct LazyGridScroll {
+    axis: LazyGridAxis,
+    controller: State<LazyGridController>,
+    scroll_controller: State<ScrollableController>,
+    estimated_line_main: Px,
+    main_axis_spacing: Px,
+    viewport_span: Px,
+    max_scroll: Px,
+}
+
+impl LazyGridScroll {
+    fn scroll_to(&self, offset: Px) {
+        let position = self.axis.scroll_position(offset);
+        self.scroll_controller
+            .with_mut(|c| c.set_scroll_position(position));
+    }
+
+    /// Scrolls until the line at `line` is fully inside the viewport.
+    fn reveal(&self, line: usize) {
+        let (line_start, line_main) = self.controller.with(|c| {
+            (
+                c.cache
+                    .offset_for_line(line, self.estimated_line_main, self.main_axis_spacing),
+                c.cache
+                    .measured_line_main
+                    .get(line)
+                    .copied()
+                    .flatten()
+                    .unwrap_or(self.estimated_line_main),
+            )
+        });
+        let scroll_offset = self
+            .axis
+            .scroll_offset(self.scroll_controller.with(|s| s.child_position()));
+        if let Some(target) = reveal_scroll_offset(
+            line_start,
+            line_main,
+            scroll_offset,
+            self.viewport_span,
+            self.max_scroll,
+        ) {
+            self.scroll_to(target);
+        }
+    }
+}
 #[derive(PartialEq, Default)]
 struct LazyGridCache {
     total_items: usize,
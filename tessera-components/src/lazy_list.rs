@@ -12,14 +12,19 @@ use std::{
 
 use tessera_ui::{
     AxisConstraint, CallbackWith, ComputedData, Constraint, Dp, FocusDirection, LayoutResult,
-    MeasurementError, Modifier, ParentConstraint, Px, PxPosition, RenderSlotWith, State, key,
+    MeasurementError, Modifier, ParentConstraint, Px, PxPosition, RenderSlotWith, State,
+    accesskit::Role,
+    key,
     layout::{LayoutPolicy, MeasureScope, PlacementScope, layout},
-    modifier::FocusModifierExt as _,
+    modifier::{FocusModifierExt as _, ModifierCapabilityExt as _},
     remember, tessera,
 };
 
 use crate::{
     alignment::CrossAxisAlignment,
+    lazy_semantics::{
+        LazyCollectionSemantics, LazyItemSemantics, LazySemanticsAxis, reveal_scroll_offset,
+    },
     scrollable::{ScrollableController, scrollable},
 };
 
@@ -53,6 +58,8 @@ const DEFAULT_VIEWPORT_ITEMS: usize = 8;
 pub struct LazyListController {
     scroll: ScrollableController,
     cache: LazyListCache,
+    pending_reveal: Option<usize>,
+    pending_scroll: Px,
 }
 
 impl Default for LazyListController {
@@ -68,6 +75,8 @@ impl LazyListController {
         Self {
             scroll: ScrollableController::new(),
             cache: LazyListCache::default(),
+            pending_reveal: None,
+            pending_scroll: Px::ZERO,
         }
     }
 
@@ -80,6 +89,72 @@ impl LazyListController {
     pub fn scroll_controller_mut(&mut self) -> &mut ScrollableController {
         &mut self.scroll
     }
+
+    /// Scrolls the list until the item at `index` is fully visible.
+    ///
+    /// Items that are not composed yet can be targeted too; the list scrolls
+    /// once it is next built and knows its item offsets.
+    pub fn reveal_item(&mut self, index: usize) {
+        self.pending_reveal = Some(index);
+    }
+
+    /// Scrolls the list by `delta` along its main axis, stopping at either
+    /// end of the content.
+    ///
+    /// Requests made before the list is next built add up.
+    pub fn scroll_by(&mut self, delta: Px) {
+        self.pending_scroll += delta;
+    }
+}
+
+impl ScrollOwner for LazyListController {
+    fn saved_scroll(&self) -> &ScrollableController {
+        &self.scroll
+    }
+
+    fn saved_scroll_mut(&mut self) -> &mut ScrollableController {
+        &mut self.scroll
+    }
+}
+
+/// Controller that keeps the scroll position of a virtualized layout.
+///
+/// The layout scrolls through a proxy [`ScrollableController`] and mirrors
+/// its position into the owner, so the position survives as long as the
+/// owner is retained.
+pub(crate) trait ScrollOwner: Send + Sync + 'static {
+    /// Returns the saved scroll state.
+    fn saved_scroll(&self) -> &ScrollableController;
+
+    /// Returns the saved scroll state for writing.
+    fn saved_scroll_mut(&mut self) -> &mut ScrollableController;
+}
+
+/// Remembers the proxy scroll controller of `owner`, restoring the saved
+/// position on first mount.
+pub(crate) fn remember_scroll_proxy<C: ScrollOwner>(
+    owner: State<C>,
+) -> State<ScrollableController> {
+    let proxy = remember(ScrollableController::default);
+    let saved_position = owner.with(|c| c.saved_scroll().child_position());
+    let should_restore_position = proxy
+        .with(|sc| sc.child_position() == PxPosition::ZERO && saved_position != PxPosition::ZERO);
+    if should_restore_position {
+        proxy.with_mut(|sc| sc.set_scroll_position(saved_position));
+    }
+    proxy
+}
+
+/// Saves the position of `proxy` back into `owner`.
+pub(crate) fn sync_scroll_proxy<C: ScrollOwner>(
+    owner: State<C>,
+    proxy: State<ScrollableController>,
+) {
+    let current_pos = proxy.with(|sc| sc.child_position());
+    let should_sync_position = owner.with(|c| c.saved_scroll().child_position() != current_pos);
+    if should_sync_position {
+        owner.with_mut(|c| c.saved_scroll_mut().set_scroll_position(current_pos));
+    }
 }
 
 fn hash_key<K>(key: K) -> u64
@@ -91,6 +166,27 @@ where
     hasher.finish()
 }
 
+/// Accessibility roles reported by a lazy list and its items.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LazyListRoles {
+    pub(crate) list: Role,
+    /// Role of the node wrapping each item, or `None` when items describe
+    /// themselves.
+    pub(crate) item: Option<Role>,
+    /// Whether items are nested, as in a tree.
+    pub(crate) hierarchical: bool,
+}
+
+impl Default for LazyListRoles {
+    fn default() -> Self {
+        Self {
+            list: Role::List,
+            item: Some(Role::ListItem),
+            hierarchical: false,
+        }
+    }
+}
+
 /// Accumulated lazy-list item declarations carried by the builder.
 #[derive(Clone, Default, PartialEq)]
 pub struct LazyListContent {
@@ -155,6 +251,7 @@ pub fn lazy_column(
     scroll_smoothing: Option<f32>,
     controller: Option<State<LazyListController>>,
     #[prop(skip_setter)] content: Option<LazyListContent>,
+    #[prop(skip_setter)] roles: Option<LazyListRoles>,
 ) {
     let content = content.unwrap_or_default();
     let controller = controller.unwrap_or_else(|| remember(LazyListController::new));
@@ -169,6 +266,7 @@ pub fn lazy_column(
         scroll_smoothing: scroll_smoothing.unwrap_or(0.0),
         controller,
         slots: content.slots,
+        roles: roles.unwrap_or_default(),
     });
 }
 
@@ -184,6 +282,7 @@ struct LazyListSlotsArgs {
     scroll_smoothing: f32,
     controller: State<LazyListController>,
     slots: Vec<LazySlot>,
+    roles: LazyListRoles,
 }
 
 fn lazy_column_slots(args: LazyListSlotsArgs) {
@@ -194,16 +293,7 @@ fn lazy_column_slots(args: LazyListSlotsArgs) {
         .apply_child_offset(false)
         .scroll_smoothing(args.scroll_smoothing);
 
-    // Create a proxy scroll controller that syncs with the LazyListController
-    let scroll_controller = remember(ScrollableController::default);
-
-    // Restore saved position from controller on first mount.
-    let saved_position = args.controller.with(|c| c.scroll.child_position());
-    let should_restore_position = scroll_controller
-        .with(|sc| sc.child_position() == PxPosition::ZERO && saved_position != PxPosition::ZERO);
-    if should_restore_position {
-        scroll_controller.with_mut(|sc| sc.set_scroll_position(saved_position));
-    }
+    let scroll_controller = remember_scroll_proxy(args.controller);
 
     let item_spacing = sanitize_spacing(Px::from(args.item_spacing));
     let estimated_item_main = ensure_positive_px(Px::from(args.estimated_item_size));
@@ -213,15 +303,7 @@ fn lazy_column_slots(args: LazyListSlotsArgs) {
     scrollable_builder
         .controller(scroll_controller)
         .child(move || {
-            // Sync scroll position back to controller
-            let current_pos = scroll_controller.with(|sc| sc.child_position());
-            let should_sync_position = args
-                .controller
-                .with(|c| c.scroll.child_position() != current_pos);
-            if should_sync_position {
-                args.controller
-                    .with_mut(|c| c.scroll.set_scroll_position(current_pos));
-            }
+            sync_scroll_proxy(args.controller, scroll_controller);
             let mut builder = lazy_list_view()
                 .axis(LazyListAxis::Vertical)
                 .cross_axis_alignment(args.cross_axis_alignment)
@@ -231,6 +313,7 @@ fn lazy_column_slots(args: LazyListSlotsArgs) {
                 .padding_main(padding_main)
                 .padding_cross(padding_cross)
                 .slots(args.slots.clone())
+                .roles(args.roles)
                 .controller(args.controller)
                 .scroll_controller(scroll_controller);
             if let Some(max_viewport_main) = args.max_viewport_main {
@@ -307,6 +390,7 @@ pub fn lazy_row(
         scroll_smoothing: scroll_smoothing.unwrap_or(0.0),
         controller,
         slots: content.slots,
+        roles: LazyListRoles::default(),
     });
 }
 
@@ -434,6 +518,14 @@ macro_rules! impl_lazy_list_builder {
 impl_lazy_list_builder!(LazyColumnBuilder);
 impl_lazy_list_builder!(LazyRowBuilder);
 
+impl LazyColumnBuilder {
+    /// Overrides the accessibility roles of the list and its items.
+    pub(crate) fn roles(mut self, roles: LazyListRoles) -> Self {
+        self.props.roles = Some(roles);
+        self
+    }
+}
+
 fn lazy_item_slot<F>(builder: F) -> LazySlot
 where
     F: Fn() + Send + Sync + 'static,
@@ -545,16 +637,7 @@ fn lazy_row_slots(args: LazyListSlotsArgs) {
         .apply_child_offset(false)
         .scroll_smoothing(args.scroll_smoothing);
 
-    // Create a proxy scroll controller that syncs with the LazyListController
-    let scroll_controller = remember(ScrollableController::default);
-
-    // Restore saved position from controller on first mount.
-    let saved_position = args.controller.with(|c| c.scroll.child_position());
-    let should_restore_position = scroll_controller
-        .with(|sc| sc.child_position() == PxPosition::ZERO && saved_position != PxPosition::ZERO);
-    if should_restore_position {
-        scroll_controller.with_mut(|sc| sc.set_scroll_position(saved_position));
-    }
+    let scroll_controller = remember_scroll_proxy(args.controller);
 
     let item_spacing = sanitize_spacing(Px::from(args.item_spacing));
     let estimated_item_main = ensure_positive_px(Px::from(args.estimated_item_size));
@@ -564,15 +647,7 @@ fn lazy_row_slots(args: LazyListSlotsArgs) {
     scrollable_builder
         .controller(scroll_controller)
         .child(move || {
-            // Sync scroll position back to controller
-            let current_pos = scroll_controller.with(|sc| sc.child_position());
-            let should_sync_position = args
-                .controller
-                .with(|c| c.scroll.child_position() != current_pos);
-            if should_sync_position {
-                args.controller
-                    .with_mut(|c| c.scroll.set_scroll_position(current_pos));
-            }
+            sync_scroll_proxy(args.controller, scroll_controller);
             let mut builder = lazy_list_view()
                 .axis(LazyListAxis::Horizontal)
                 .cross_axis_alignment(args.cross_axis_alignment)
@@ -582,6 +657,7 @@ fn lazy_row_slots(args: LazyListSlotsArgs) {
                 .padding_main(padding_main)
                 .padding_cross(padding_cross)
                 .slots(args.slots.clone())
+                .roles(args.roles)
                 .controller(args.controller)
                 .scroll_controller(scroll_controller);
             if let Some(max_viewport_main) = args.max_viewport_main {
@@ -603,6 +679,7 @@ fn lazy_list_view(
     padding_cross: Option<Px>,
     controller: Option<State<LazyListController>>,
     slots: Option<Vec<LazySlot>>,
+    roles: Option<LazyListRoles>,
     scroll_controller: Option<State<ScrollableController>>,
 ) {
     let axis = axis.unwrap_or_default();
@@ -613,6 +690,7 @@ fn lazy_list_view(
     let padding_main = padding_main.unwrap_or(Px::ZERO);
     let padding_cross = padding_cross.unwrap_or(Px::ZERO);
     let slots = slots.unwrap_or_default();
+    let roles = roles.unwrap_or_default();
     let controller = controller.expect("lazy_list_view requires controller");
     let scroll_controller = scroll_controller.expect("lazy_list_view requires scroll_controller");
     let plan = LazySlotPlan::new(slots.clone());
@@ -681,6 +759,41 @@ fn lazy_list_view(
         item_spacing,
     });
 
+    let scroll = LazyListScroll {
+        axis,
+        controller,
+        scroll_controller,
+        estimated_item_main,
+        item_spacing,
+        viewport_span,
+        max_scroll: (total_main - viewport_span).max(Px::ZERO),
+    };
+    // Wait for a measured viewport so the request is not spent on estimates.
+    let viewport_measured =
+        axis.visible_span(scroll_controller.with(|s| s.visible_size())) > Px::ZERO;
+    if viewport_measured && controller.with(|c| c.pending_scroll != Px::ZERO) {
+        let delta = controller.with_mut(|c| std::mem::replace(&mut c.pending_scroll, Px::ZERO));
+        scroll.scroll_to((scroll_offset + delta).clamp(Px::ZERO, scroll.max_scroll));
+    }
+    if viewport_measured
+        && controller.with(|c| c.pending_reveal.is_some())
+        && let Some(index) = controller.with_mut(|c| c.pending_reveal.take())
+    {
+        scroll.reveal(index.min(total_count - 1));
+    }
+    let (rows, columns) = axis.collection_shape(total_count);
+    let semantics = LazyCollectionSemantics {
+        role: roles.list,
+        axis: axis.semantics_axis(),
+        rows,
+        columns,
+        hierarchical: roles.hierarchical,
+        scroll_offset,
+        max_scroll: scroll.max_scroll,
+        page: viewport_span,
+        scroll_to: Arc::new(move |offset| scroll.scroll_to(offset)),
+    };
+
     let viewport_limit = viewport_span + padding_main + padding_main;
     let visible_item_indices = visible_children
         .children
@@ -689,8 +802,9 @@ fn lazy_list_view(
         .collect();
 
     let children = visible_children.children;
+    let sticky_indices = plan.sticky_indices().to_vec();
     layout()
-        .modifier(focus_modifier)
+        .modifier(focus_modifier.push_semantics(semantics))
         .layout_policy(LazyListLayout {
             axis,
             cross_axis_alignment,
@@ -709,8 +823,24 @@ fn lazy_list_view(
         .child(move || {
             for child in &children {
                 let child = child.clone();
-                key(child.key_hash, || {
-                    child.builder.render(child.local_index);
+                let item_index = child.item_index;
+                let (row, column) = axis.collection_cell(item_index);
+                let item_modifier = match roles.item {
+                    Some(role) => Modifier::new().push_semantics(LazyItemSemantics {
+                        role,
+                        row,
+                        column,
+                        heading: sticky_indices.contains(&item_index),
+                        reveal: Arc::new(move || {
+                            controller.with_mut(|c| c.reveal_item(item_index))
+                        }),
+                    }),
+                    None => Modifier::new(),
+                };
+                key(child.key_hash, move || {
+                    layout().modifier(item_modifier).child(move || {
+                        child.builder.render(child.local_index);
+                    });
                 });
             }
         });
@@ -1117,6 +1247,29 @@ impl LazyListAxis {
         }
     }
 
+    fn semantics_axis(&self) -> LazySemanticsAxis {
+        match self {
+            Self::Vertical => LazySemanticsAxis::Vertical,
+            Self::Horizontal => LazySemanticsAxis::Horizontal,
+        }
+    }
+
+    /// Returns the `(rows, columns)` of a collection holding `count` items.
+    fn collection_shape(&self, count: usize) -> (usize, usize) {
+        match self {
+            Self::Vertical => (count, 1),
+            Self::Horizontal => (1, count),
+        }
+    }
+
+    /// Returns the `(row, column)` of item `index` in accessibility terms.
+    fn collection_cell(&self, index: usize) -> (usize, usize) {
+        match self {
+            Self::Vertical => (index, 0),
+            Self::Horizontal => (0, index),
+        }
+    }
+
     fn focus_scroll_direction(&self, direction: FocusDirection) -> Option<FocusScrollDirection> {
         match (self, direction) {
             (_, FocusDirection::Next | FocusDirection::Enter) => {
@@ -1237,15 +1390,73 @@ fn lazy_list_focus_beyond_bounds_modifier(args: LazyListFocusArgs) -> Modifier {
             return false;
         }
 
-        let position = args.axis.scroll_position(desired_scroll);
-        args.scroll_controller
-            .with_mut(|c| c.set_scroll_position(position));
-        args.controller
-            .with_mut(|c| c.scroll.set_scroll_position(position));
+        scroll_lazy_list_to(
+            args.axis,
+            args.controller,
+            args.scroll_controller,
+            desired_scroll,
+        );
         true
     }))
 }
 
+fn scroll_lazy_list_to(
+    axis: LazyListAxis,
+    controller: State<LazyListController>,
+    scroll_controller: State<ScrollableController>,
+    offset: Px,
+) {
+    let position = axis.scroll_position(offset);
+    scroll_controller.with_mut(|c| c.set_scroll_position(position));
+    controller.with_mut(|c| c.scroll.set_scroll_position(position));
+}
+
+/// Scroll state captured for accessibility scroll requests.
+#[derive(Clone, Copy)]
+struct LazyListScroll {
+    axis: LazyListAxis,
+    controller: State<LazyListController>,
+    scroll_controller: State<ScrollableController>,
+    estimated_item_main: Px,
+    item_spacing: Px,
+    viewport_span: Px,
+    max_scroll: Px,
+}
+
+impl LazyListScroll {
+    fn scroll_to(&self, offset: Px) {
+        scroll_lazy_list_to(self.axis, self.controller, self.scroll_controller, offset);
+    }
+
+    /// Scrolls until the item at `index` is fully inside the viewport.
+    fn reveal(&self, index: usize) {
+        let (item_start, item_main) = self.controller.with(|c| {
+            (
+                c.cache
+                    .offset_for(index, self.estimated_item_main, self.item_spacing),
+                c.cache
+                    .measured_main
+                    .get(index)
+                    .copied()
+                    .flatten()
+                    .unwrap_or(self.estimated_item_main),
+            )
+        });
+        let scroll_offset = self
+            .axis
+            .scroll_offset(self.scroll_controller.with(|s| s.child_position()));
+        if let Some(target) = reveal_scroll_offset(
+            item_start,
+            item_main,
+            scroll_offset,
+            self.viewport_span,
+            self.max_scroll,
+        ) {
+            self.scroll_to(target);
+        }
+    }
+}
+
 #[derive(Clone, PartialEq)]
 enum LazySlot {
     Items(LazyItemsSlot),
@@ -0,0 +1,172 @@
+//! Accessibility semantics shared by the virtualized list and grid layouts.
+//!
+//! Lazy layouts only compose the items near the viewport, so the container
+//! reports the size of the whole collection and answers scroll requests on
+//! behalf of the items assistive technology cannot reach yet. Items are keyed
+//! by their stable item key, so their accessibility nodes keep the same id
+//! while the layout recycles them.
+
+use std::sync::Arc;
+
+use tessera_ui::{
+    AccessibilityActionHandler, AccessibilityActionHandlers, AccessibilityNode, Px,
+    SemanticsModifierNode,
+    accesskit::{Action, Role},
+};
+
+/// Moves the lazy layout to a main-axis scroll offset.
+pub(crate) type LazyScrollTo = Arc<dyn Fn(Px) + Send + Sync>;
+
+/// Main scroll axis of a lazy layout.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LazySemanticsAxis {
+    Vertical,
+    Horizontal,
+}
+
+impl LazySemanticsAxis {
+    fn scroll_actions(self) -> (Action, Action) {
+        match self {
+            Self::Vertical => (Action::ScrollUp, Action::ScrollDown),
+            Self::Horizontal => (Action::ScrollLeft, Action::ScrollRight),
+        }
+    }
+}
+
+/// Semantics of the lazy layout container.
+pub(crate) struct LazyCollectionSemantics {
+    pub(crate) role: Role,
+    pub(crate) axis: LazySemanticsAxis,
+    /// Row and column counts of the full collection, composed or not.
+    pub(crate) rows: usize,
+    pub(crate) columns: usize,
+    /// Whether items are nested, as in a tree.
+    pub(crate) hierarchical: bool,
+    pub(crate) scroll_offset: Px,
+    pub(crate) max_scroll: Px,
+    /// Distance moved by one scroll action.
+    pub(crate) page: Px,
+    pub(crate) scroll_to: LazyScrollTo,
+}
+
+impl SemanticsModifierNode for LazyCollectionSemantics {
+    fn apply(
+        &self,
+        accessibility: &mut AccessibilityNode,
+        _action_handler: &mut Option<AccessibilityActionHandler>,
+    ) {
+        accessibility.role = Some(self.role);
+        accessibility.collection_info = Some((self.rows, self.columns, self.hierarchical));
+        let scroll = (
+            self.scroll_offset.to_f32() as f64,
+            0.0,
+            self.max_scroll.to_f32() as f64,
+        );
+        match self.axis {
+            LazySemanticsAxis::Vertical => accessibility.scroll_y = Some(scroll),
+            LazySemanticsAxis::Horizontal => accessibility.scroll_x = Some(scroll),
+        }
+    }
+
+    fn apply_action_handlers(&self, handlers: &mut AccessibilityActionHandlers) {
+        if self.page <= Px::ZERO {
+            return;
+        }
+
+        let (backward, forward) = self.axis.scroll_actions();
+        if self.scroll_offset > Px::ZERO {
+            let scroll_to = self.scroll_to.clone();
+            let target = (self.scroll_offset - self.page).max(Px::ZERO);
+            handlers.on_action(backward, move || scroll_to(target));
+        }
+        if self.scroll_offset < self.max_scroll {
+            let scroll_to = self.scroll_to.clone();
+            let target = (self.scroll_offset + self.page).min(self.max_scroll);
+            handlers.on_action(forward, move || scroll_to(target));
+        }
+    }
+}
+
+/// Semantics of one composed item inside a lazy layout.
+pub(crate) struct LazyItemSemantics {
+    pub(crate) role: Role,
+    pub(crate) row: usize,
+    pub(crate) column: usize,
+    pub(crate) heading: bool,
+    /// Asks the layout controller to reveal the item by index.
+    pub(crate) reveal: Arc<dyn Fn() + Send + Sync>,
+}
+
+impl SemanticsModifierNode for LazyItemSemantics {
+    fn apply(
+        &self,
+        accessibility: &mut AccessibilityNode,
+        _action_handler: &mut Option<AccessibilityActionHandler>,
+    ) {
+        accessibility.role = Some(self.role);
+        accessibility.collection_item_info = Some((self.row, 1, self.column, 1, self.heading));
+    }
+
+    fn apply_action_handlers(&self, handlers: &mut AccessibilityActionHandlers) {
+        let reveal = self.reveal.clone();
+        handlers.on_scroll_into_view(move || reveal());
+    }
+}
+
+/// Returns the scroll offset that brings `item_start..item_start + item_main`
+/// into the viewport, or `None` when it is already fully visible.
+pub(crate) fn reveal_scroll_offset(
+    item_start: Px,
+    item_main: Px,
+    scroll_offset: Px,
+    viewport_span: Px,
+    max_scroll: Px,
+) -> Option<Px> {
+    let item_end = item_start + item_main;
+    let target = if item_start < scroll_offset || item_main > viewport_span {
+        item_start
+    } else if item_end > scroll_offset + viewport_span {
+        item_end - viewport_span
+    } else {
+        return None;
+    };
+    let target = target.max(Px::ZERO).min(max_scroll);
+    (target != scroll_offset).then_some(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reveal_scrolls_the_nearest_edge_into_view() {
+        let max = Px::new(500);
+        let viewport = Px::new(100);
+        assert_eq!(
+            reveal_scroll_offset(Px::new(40), Px::new(20), Px::new(100), viewport, max),
+            Some(Px::new(40))
+        );
+        assert_eq!(
+            reveal_scroll_offset(Px::new(210), Px::new(20), Px::new(100), viewport, max),
+            Some(Px::new(130))
+        );
+        assert_eq!(
+            reveal_scroll_offset(Px::new(150), Px::new(20), Px::new(100), viewport, max),
+            None
+        );
+    }
+
+    #[test]
+    fn reveal_clamps_to_the_scroll_range() {
+        let max = Px::new(500);
+        let viewport = Px::new(100);
+        assert_eq!(
+            reveal_scroll_offset(Px::new(580), Px::new(20), Px::ZERO, viewport, max),
+            Some(Px::new(500))
+        );
+        assert_eq!(
+            reveal_scroll_offset(Px::ZERO, Px::new(300), Px::new(50), viewport, max),
+            Some(Px::ZERO)
+        );
+    }
+}
@@ -12,15 +12,20 @@ use std::{
 
 use tessera_ui::{
     AxisConstraint, CallbackWith, Color, ComputedData, Constraint, Dp, FocusDirection,
-    LayoutResult, MeasurementError, Modifier, ParentConstraint, Px, PxPosition, State, key,
+    LayoutResult, MeasurementError, Modifier, ParentConstraint, Px, PxPosition, State,
+    accesskit::Role,
+    key,
     layout::{LayoutPolicy, MeasureScope, PlacementScope, layout},
-    modifier::FocusModifierExt as _,
+    modifier::{FocusModifierExt as _, ModifierCapabilityExt as _},
     remember, tessera,
 };
 
 use crate::{
     alignment::{CrossAxisAlignment, MainAxisAlignment},
     lazy_grid::GridCells,
+    lazy_semantics::{
+        LazyCollectionSemantics, LazyItemSemantics, LazySemanticsAxis, reveal_scroll_offset,
+    },
     scrollable::{ScrollBarBehavior, ScrollBarLayout, ScrollableController, scrollable},
 };
 
@@ -32,6 +37,7 @@ pub type StaggeredGridCells = GridCells;
 /// Persistent state shared by staggered grid components.
 pub struct LazyStaggeredGridController {
     cache: StaggeredGridCache,
+    pending_reveal: Option<usize>,
 }
 
 impl Default for LazyStaggeredGridController {
@@ -45,8 +51,17 @@ impl LazyStaggeredGridController {
     pub fn new() -> Self {
         Self {
             cache: StaggeredGridCache::default(),
+            pending_reveal: None,
         }
     }
+
+    /// Scrolls the grid until the item at `index` is fully visible.
+    ///
+    /// Items that are not composed yet can be targeted too; their position
+    /// is estimated from the items measured so far.
+    pub fn reveal_item(&mut self, index: usize) {
+        self.pending_reveal = Some(index);
+    }
 }
 
 fn hash_key<K>(key: K) -> u64
@@ -927,6 +942,38 @@ fn lazy_staggered_grid_view(
             lane_count,
         });
 
+    let scroll = LazyStaggeredGridScroll {
+        axis,
+        controller,
+        scroll_controller,
+        estimated_item_main,
+        main_axis_spacing,
+        lane_count,
+        viewport_span,
+        max_scroll: (total_main - viewport_span).max(Px::ZERO),
+    };
+    // Wait for a measured viewport so the request is not spent on estimates.
+    if axis.visible_span(visible_size) > Px::ZERO
+        && controller.with(|c| c.pending_reveal.is_some())
+        && let Some(index) = controller.with_mut(|c| c.pending_reveal.take())
+    {
+        scroll.reveal(index.min(total_count - 1));
+    }
+    // Masonry placement has no stable rows, so items are announced as a list
+    // in index order.
+    let (rows, columns) = axis.collection_cell(total_count, 1);
+    let semantics = LazyCollectionSemantics {
+        role: Role::List,
+        axis: axis.semantics_axis(),
+        rows,
+        columns,
+        hierarchical: false,
+        scroll_offset,
+        max_scroll: scroll.max_scroll,
+        page: viewport_span,
+        scroll_to: Arc::new(move |offset| scroll.scroll_to(offset)),
+    };
+
     let viewport_limit = viewport_span + padding_main + padding_main;
     let visible_layout_items = visible_items
         .iter()
@@ -936,7 +983,7 @@ fn lazy_staggered_grid_view(
         .collect();
 
     layout()
-        .modifier(focus_modifier)
+        .modifier(focus_modifier.push_semantics(semantics))
         .layout_policy(LazyStaggeredGridLayout {
             axis,
             item_alignment,
@@ -956,10 +1003,23 @@ fn lazy_staggered_grid_view(
         .child(move || {
             for child in &visible_items {
                 let child = child.clone();
-                key(child.key_hash, || {
-                    lazy_staggered_grid_item_host()
-                        .builder_handle_shared(child.builder)
-                        .local_index(child.local_index);
+                let item_index = child.item_index;
+                let (row, column) = axis.collection_cell(item_index, 0);
+                let item_semantics = LazyItemSemantics {
+                    role: Role::ListItem,
+                    row,
+                    column,
+                    heading: false,
+                    reveal: Arc::new(move || controller.with_mut(|c| c.reveal_item(item_index))),
+                };
+                key(child.key_hash, move || {
+                    layout()
+                        .modifier(Modifier::new().push_semantics(item_semantics))
+                        .child(move || {
+                            lazy_staggered_grid_item_host()
+                                .builder_handle_shared(child.builder)
+                                .local_index(child.local_index);
+                        });
                 });
             }
         });
@@ -1132,6 +1192,21 @@ impl StaggeredGridAxis {
         }
     }
 
+    fn semantics_axis(&self) -> LazySemanticsAxis {
+        match self {
+            Self::Vertical => LazySemanticsAxis::Vertical,
+            Self::Horizontal => LazySemanticsAxis::Horizontal,
+        }
+    }
+
+    /// Maps a `(main, cross)` position to accessibility `(row, column)` terms.
+    fn collection_cell(&self, main: usize, cross: usize) -> (usize, usize) {
+        match self {
+            Self::Vertical => (main, cross),
+            Self::Horizontal => (cross, main),
+        }
+    }
+
     fn focus_scroll_direction(&self, direction: FocusDirection) -> Option<FocusScrollDirection> {
         match (self, direction) {
             (_, FocusDirection::Next | FocusDirection::Enter) => {
@@ -1480,6 +1555,54 @@ fn lazy_staggered_grid_focus_beyond_bounds_modifier(args: LazyStaggeredGridFocus
     }))
 }
 
+/// Scroll state captured for accessibility scroll requests.
+#[derive(Clone, Copy)]
+struct LazyStaggeredGridScroll {
+    axis: StaggeredGridAxis,
+    controller: State<LazyStaggeredGridController>,
+    scroll_controller: State<ScrollableController>,
+    estimated_item_main: Px,
+    main_axis_spacing: Px,
+    lane_count: usize,
+    viewport_span: Px,
+    max_scroll: Px,
+}
+
+impl LazyStaggeredGridScroll {
+    fn scroll_to(&self, offset: Px) {
+        let position = self.axis.scroll_position(offset);
+        self.scroll_controller
+            .with_mut(|c| c.set_scroll_position(position));
+    }
+
+    /// Scrolls until the item at `index` is fully inside the viewport.
+    fn reveal(&self, index: usize) {
+        let Some((item_start, item_main)) = self.controller.with(|c| {
+            staggered_item_layout_info(
+                &c.cache,
+                index,
+                self.lane_count,
+                self.estimated_item_main,
+                self.main_axis_spacing,
+            )
+        }) else {
+            return;
+        };
+        let scroll_offset = self
+            .axis
+            .scroll_offset(self.scroll_controller.with(|s| s.child_position()));
+        if let Some(target) = reveal_scroll_offset(
+            item_start,
+            item_main,
+            scroll_offset,
+            self.viewport_span,
+            self.max_scroll,
+        ) {
+            self.scroll_to(target);
+        }
+    }
+}
+
 #[derive(PartialEq, Default)]
 struct StaggeredGridCache {
     item_main: Vec<Option<Px>>,
@@ -61,8 +61,10 @@
     rustdoc::invalid_html_tags
 )]
 
+pub mod animated_image;
 mod animation;
 pub mod app_bar;
+pub mod async_image;
 pub mod badge;
 pub mod bottom_sheet;
 pub mod boxed;
@@ -73,9 +75,11 @@ pub mod checkbox;
 mod checkmark;
 pub mod chip;
 pub mod column;
+pub mod data_table;
 pub mod date_picker;
 pub mod dialog;
 pub mod divider;
+pub mod exposed_dropdown_menu;
 pub mod floating_action_button;
 pub mod flow_column;
 pub mod flow_row;
@@ -87,10 +91,12 @@ pub mod glass_switch;
 pub mod icon;
 pub mod icon_button;
 pub mod image;
+pub mod image_loader;
 pub mod image_vector;
 pub mod interaction_state;
 pub mod lazy_grid;
 pub mod lazy_list;
+mod lazy_semantics;
 pub mod lazy_staggered_grid;
 pub mod list_item;
 pub mod material_icons;
@@ -114,6 +120,7 @@ pub mod scaffold;
 pub mod scrollable;
 pub mod search;
 pub mod segmented_buttons;
+mod selection;
 mod selection_highlight_rect;
 pub mod shadow;
 pub mod side_sheet;
@@ -121,6 +128,7 @@ pub mod slider;
 pub mod snackbar;
 pub mod spacer;
 pub mod split_buttons;
+mod subscription;
 pub mod surface;
 pub mod switch;
 pub mod tabs;
@@ -130,6 +138,10 @@ pub mod text_field;
 pub mod text_input;
 pub mod theme;
 pub mod time_picker;
+pub mod tree_view;
+pub mod video_surface;
+pub mod viewport;
+pub mod zoomable;
 
 use tessera_platform::PlatformPackage;
 use tessera_ui::{EntryRegistry, PipelineContext, RenderModule, TesseraPackage};
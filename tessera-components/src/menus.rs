@@ -3,27 +3,29 @@
 //! ## Usage
 //!
 //! Present anchored overflow or context actions as surfaced menus.
-use std::sync::Arc;
-
-use parking_lot::RwLock;
+use tessera_foundation::overlay::{OverlayPosition, PopupPlacement, overlay};
 use tessera_ui::{
-    AxisConstraint, Callback, Color, ComputedData, Dp, FocusRequester, FocusScopeNode,
-    FocusTraversalPolicy, LayoutResult, MeasurementError, Modifier, ParentConstraint, Px,
-    PxPosition, PxSize, RenderSlot, State,
+    AccessibilityActionHandler, AccessibilityActionHandlers, AccessibilityNode, AxisConstraint,
+    Callback, Color, Dp, FocusRequester, FocusScopeNode, FocusTraversalPolicy, Modifier, Px,
+    PxPosition, PxRect, PxSize, RenderSlot, SemanticsModifierNode, State,
     accesskit::Role,
-    layout::{LayoutPolicy, MeasureScope, layout},
-    modifier::FocusModifierExt as _,
-    provide_context, remember, tessera, use_context, winit,
+    layout::layout,
+    modifier::{FocusModifierExt as _, ModifierCapabilityExt as _},
+    provide_context, remember, remember_bounds,
+    shortcut::{self, CommandId},
+    tessera, use_context, winit,
 };
 
+use tessera_platform::menu::{Menu, MenuEntry};
+
 use crate::{
     alignment::CrossAxisAlignment,
     checkmark::checkmark,
     column::column,
+    divider::horizontal_divider,
     icon::icon,
-    modifier::{ModifierExt as _, with_keyboard_input, with_pointer_input},
+    modifier::{ModifierExt as _, with_keyboard_input},
     painter::Painter,
-    pos_misc::is_position_in_rect,
     row::row,
     shape_def::Shape,
     spacer::spacer,
@@ -34,11 +36,11 @@ use crate::{
 
 const MENU_MIN_WIDTH: Dp = Dp(112.0);
 const MENU_MAX_WIDTH: Dp = Dp(280.0);
-const MENU_MAX_HEIGHT: Dp = Dp(320.0);
-const MENU_VERTICAL_GAP: Dp = Dp(4.0);
-const MENU_HORIZONTAL_PADDING: Dp = Dp(16.0);
-const MENU_LEADING_SIZE: Dp = Dp(20.0);
-const MENU_ITEM_HEIGHT: Dp = Dp(48.0);
+pub(crate) const MENU_MAX_HEIGHT: Dp = Dp(320.0);
+pub(crate) const MENU_VERTICAL_GAP: Dp = Dp(4.0);
+pub(crate) const MENU_HORIZONTAL_PADDING: Dp = Dp(16.0);
+pub(crate) const MENU_LEADING_SIZE: Dp = Dp(20.0);
+pub(crate) const MENU_ITEM_HEIGHT: Dp = Dp(48.0);
 const MENU_TRAILING_SPACING: Dp = Dp(16.0);
 
 fn default_menu_width() -> AxisConstraint {
@@ -53,11 +55,11 @@ fn default_max_height() -> Option<Px> {
     Some(Px::from(MENU_MAX_HEIGHT))
 }
 
-fn default_menu_shape() -> Shape {
+pub(crate) fn default_menu_shape() -> Shape {
     Shape::rounded_rectangle(Dp(4.0))
 }
 
-fn default_menu_color() -> Color {
+pub(crate) fn default_menu_color() -> Color {
     use_context::<MaterialTheme>()
         .expect("MaterialTheme must be provided")
         .get()
@@ -72,7 +74,7 @@ fn default_scrim_color() -> Color {
 /// Describes the anchor rectangle used to position a menu.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MenuAnchor {
-    /// Top-left corner of the anchor rectangle, relative to the menu container.
+    /// Top-left corner of the anchor rectangle, relative to the menu provider.
     pub origin: PxPosition,
     /// Size of the anchor rectangle.
     pub size: PxSize,
@@ -213,152 +215,45 @@ impl Default for MenuProviderConfig {
     }
 }
 
-#[derive(Clone, PartialEq, Copy)]
-struct MenuBounds {
-    origin: PxPosition,
-    size: ComputedData,
-}
-
-impl Default for MenuBounds {
-    fn default() -> Self {
-        Self {
-            origin: PxPosition::new(Px::ZERO, Px::ZERO),
-            size: ComputedData::ZERO,
+impl MenuPlacement {
+    fn popup_placement(self) -> PopupPlacement {
+        match self {
+            Self::BelowStart => PopupPlacement::BelowStart,
+            Self::BelowEnd => PopupPlacement::BelowEnd,
+            Self::AboveStart => PopupPlacement::AboveStart,
+            Self::AboveEnd => PopupPlacement::AboveEnd,
+            Self::RightStart => PopupPlacement::RightStart,
+            Self::LeftStart => PopupPlacement::LeftStart,
         }
     }
 }
 
-#[derive(Clone)]
-struct MenuLayout {
-    placement: MenuPlacement,
-    offset: [Dp; 2],
-    anchor: Option<MenuAnchor>,
-    bounds: Arc<RwLock<Option<MenuBounds>>>,
-}
-
-impl PartialEq for MenuLayout {
-    fn eq(&self, other: &Self) -> bool {
-        self.placement == other.placement
-            && self.offset == other.offset
-            && self.anchor == other.anchor
+fn apply_close_action(controller: State<MenuController>, on_dismiss: &Option<Callback>) {
+    if let Some(callback) = on_dismiss {
+        callback.call();
     }
+    controller.with_mut(|c| c.close());
 }
 
-impl LayoutPolicy for MenuLayout {
-    fn measure(&self, input: &MeasureScope<'_>) -> Result<LayoutResult, MeasurementError> {
-        let mut result = LayoutResult::default();
-        let children = input.children();
-        let child_constraint = input.parent_constraint().without_min();
-        let main_content = children
-            .first()
-            .copied()
-            .expect("main content should exist");
-        let main_size = main_content.measure(&child_constraint)?;
-        result.place_child(main_content, PxPosition::new(Px::ZERO, Px::ZERO));
-
-        let background = children
-            .get(1)
-            .copied()
-            .expect("menu background should exist");
-        let menu = children.get(2).copied().expect("menu surface should exist");
-
-        let background_size = background.measure(&child_constraint)?;
-        result.place_child(background, PxPosition::new(Px::ZERO, Px::ZERO));
-
-        let menu_size = menu.measure(&child_constraint)?;
-        let background_size = background_size.size();
-        let menu_size = menu_size.size();
-        let main_size = main_size.size();
-        let available = if background_size.width > Px::ZERO && background_size.height > Px::ZERO {
-            background_size
-        } else {
-            extract_available_size(input.parent_constraint())
-        };
-        let anchor = self.anchor.unwrap_or_else(|| {
-            MenuAnchor::new(
-                PxPosition::ZERO,
-                PxSize::new(main_size.width, main_size.height),
-            )
-        });
-        let menu_position =
-            resolve_menu_position(anchor, self.placement, menu_size, available, self.offset);
-        result.place_child(menu, menu_position);
-
-        *self.bounds.write() = Some(MenuBounds {
-            origin: menu_position,
-            size: menu_size,
-        });
-
-        Ok(result.with_size(main_size))
-    }
+/// Lets assistive technologies dismiss an open menu with `Collapse`.
+struct MenuDismissSemanticsModifierNode {
+    controller: State<MenuController>,
+    on_dismiss: Option<Callback>,
 }
 
-fn resolve_menu_position(
-    anchor: MenuAnchor,
-    placement: MenuPlacement,
-    menu_size: ComputedData,
-    available: ComputedData,
-    offset: [Dp; 2],
-) -> PxPosition {
-    let anchor_end_x = anchor.origin.x + anchor.size.width;
-    let anchor_end_y = anchor.origin.y + anchor.size.height;
-
-    let mut x = match placement {
-        MenuPlacement::BelowStart | MenuPlacement::AboveStart => anchor.origin.x,
-        MenuPlacement::BelowEnd | MenuPlacement::AboveEnd => anchor_end_x - menu_size.width,
-        MenuPlacement::RightStart => anchor_end_x,
-        MenuPlacement::LeftStart => anchor.origin.x - menu_size.width,
-    };
-
-    let mut y = match placement {
-        MenuPlacement::BelowStart | MenuPlacement::BelowEnd => anchor_end_y,
-        MenuPlacement::AboveStart | MenuPlacement::AboveEnd => anchor.origin.y - menu_size.height,
-        MenuPlacement::RightStart | MenuPlacement::LeftStart => anchor.origin.y,
-    };
-
-    x += Px::from(offset[0]);
-    y += Px::from(offset[1]);
-
-    let max_x = available.width - menu_size.width;
-    let max_y = available.height - menu_size.height;
-    if x < Px::ZERO {
-        x = Px::ZERO;
-    }
-    if y < Px::ZERO {
-        y = Px::ZERO;
-    }
-    if max_x > Px::ZERO {
-        x = x.min(max_x);
-    }
-    if max_y > Px::ZERO {
-        y = y.min(max_y);
+impl SemanticsModifierNode for MenuDismissSemanticsModifierNode {
+    fn apply(
+        &self,
+        _accessibility: &mut AccessibilityNode,
+        _action_handler: &mut Option<AccessibilityActionHandler>,
+    ) {
     }
 
-    PxPosition::new(x, y)
-}
-
-fn extract_available_size(constraint: ParentConstraint<'_>) -> ComputedData {
-    let width = constraint.width().resolve_max().unwrap_or(Px::MAX);
-    let height = constraint.height().resolve_max().unwrap_or(Px::MAX);
-
-    ComputedData { width, height }
-}
-
-fn is_click_outside_menu(cursor_position: Option<PxPosition>, bounds: Option<MenuBounds>) -> bool {
-    let Some(bounds) = bounds else {
-        return false;
-    };
-
-    cursor_position
-        .map(|pos| !is_position_in_rect(pos, bounds.origin, bounds.size.width, bounds.size.height))
-        .unwrap_or(false)
-}
-
-fn apply_close_action(controller: State<MenuController>, on_dismiss: &Option<Callback>) {
-    if let Some(callback) = on_dismiss {
-        callback.call();
+    fn apply_action_handlers(&self, handlers: &mut AccessibilityActionHandlers) {
+        let controller = self.controller;
+        let on_dismiss = self.on_dismiss;
+        handlers.on_collapse(move || apply_close_action(controller, &on_dismiss));
     }
-    controller.with_mut(|c| c.close());
 }
 
 /// # menu_provider
@@ -368,7 +263,8 @@ fn apply_close_action(controller: State<MenuController>, on_dismiss: &Option<Cal
 /// ## Usage
 ///
 /// Wrap page content and show contextual or overflow actions aligned to a
-/// trigger element.
+/// trigger element. The menu opens in the nearest overlay host, so it is not
+/// clipped by the provider's parents and flips when there is no room below.
 ///
 /// ## Parameters
 ///
@@ -456,11 +352,12 @@ pub fn menu_provider(
         main_content,
         menu_content,
     };
-    let controller = provider_args
-        .controller
-        .unwrap_or_else(|| remember(MenuController::new));
+    let external_controller = provider_args.controller;
+    let controller = external_controller.unwrap_or_else(|| remember(MenuController::new));
 
-    if controller.with(|c| c.is_open()) != provider_args.is_open {
+    // In controlled mode (external controller provided), do not override
+    // controller state from `is_open`.
+    if external_controller.is_none() && controller.with(|c| c.is_open()) != provider_args.is_open {
         if provider_args.is_open {
             controller.with_mut(|c| c.open());
         } else {
@@ -470,87 +367,49 @@ pub fn menu_provider(
 
     let main_content = provider_args.main_content.unwrap_or_else(RenderSlot::empty);
     let menu_content = provider_args.menu_content.unwrap_or_else(RenderSlot::empty);
-    let menu_open_state = remember(|| false);
+    let bounds = remember_bounds();
+
+    main_content.render();
 
+    // Anchors are relative to the provider, while overlays expect window
+    // coordinates.
     let (is_open, anchor) = controller.with(|c| c.snapshot());
-    let mut just_opened = false;
-    menu_open_state.with_mut(|was_open| {
-        just_opened = !*was_open && is_open;
-        *was_open = is_open;
+    let provider_bounds = bounds.get().unwrap_or(PxRect::ZERO);
+    let anchor = anchor.map_or(provider_bounds, |anchor| {
+        PxRect::new(
+            provider_bounds.x + anchor.origin.x,
+            provider_bounds.y + anchor.origin.y,
+            anchor.size.width,
+            anchor.size.height,
+        )
     });
-    if !is_open {
-        return;
-    }
-
-    // Track menu bounds for outside-click detection.
-    let bounds: Arc<RwLock<Option<MenuBounds>>> = Arc::new(RwLock::new(None));
-
-    // Parent pointer handler: block propagation and close on background click.
-    let bounds = bounds.clone();
     let on_dismiss = provider_args.on_dismiss;
-    let close_on_escape = provider_args.close_on_escape;
-    let close_on_background = provider_args.close_on_background;
-    let mut modifier = with_pointer_input(Modifier::new(), {
-        let bounds = bounds.clone();
-        move |mut input| {
-            let cursor_position = input.cursor_position_rel;
-            let menu_bounds = *bounds.read();
-            let should_close_click = close_on_background
-                && input.has_unconsumed_release()
-                && is_click_outside_menu(cursor_position, menu_bounds);
-
-            // Prevent underlying content from receiving input while menu is open.
-            input.block_all();
-
-            if should_close_click {
-                apply_close_action(controller, &on_dismiss);
-            }
-        }
-    });
-
-    if close_on_escape {
-        let on_dismiss = provider_args.on_dismiss;
-        modifier = with_keyboard_input(modifier, move |mut input| {
-            let should_close_escape = input.keyboard_events.iter().any(|event| {
-                event.state == winit::event::ElementState::Pressed
-                    && matches!(
-                        event.physical_key,
-                        winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::Escape)
-                    )
-            });
-            if should_close_escape {
-                apply_close_action(controller, &on_dismiss);
-                input.block_keyboard();
-            }
-        });
-    }
-
-    // Measurement: place main content, background, and menu based on anchor.
-    layout()
-        .modifier(modifier)
-        .layout_policy(MenuLayout {
-            placement: provider_args.placement,
-            offset: provider_args.offset,
+    let scrim_color = provider_args.scrim_color;
+    overlay()
+        .is_open(is_open)
+        .modal(true)
+        .dismiss_on_back(provider_args.close_on_escape)
+        .dismiss_on_outside(provider_args.close_on_background)
+        .on_dismiss(move || apply_close_action(controller, &on_dismiss))
+        .position(OverlayPosition::Anchored {
             anchor,
-            bounds,
+            placement: provider_args.placement.popup_placement(),
+            offset: PxPosition::new(
+                Px::from(provider_args.offset[0]),
+                Px::from(provider_args.offset[1]),
+            ),
         })
-        .child(move || {
-            let menu_content = menu_content;
-            main_content.render();
-
+        .scrim(move || {
             surface()
-                .style(SurfaceStyle::Filled {
-                    color: provider_args.scrim_color,
-                })
+                .style(SurfaceStyle::Filled { color: scrim_color })
                 .modifier(Modifier::new().fill_max_size())
-                .block_input(true)
                 .child(|| {});
-
+        })
+        .content(move || {
             menu_panel()
                 .provider(provider_args.clone())
                 .controller(controller)
-                .menu_content_shared(menu_content)
-                .just_opened(just_opened);
+                .menu_content_shared(menu_content);
         });
 }
 
@@ -559,10 +418,8 @@ fn menu_panel(
     provider: Option<MenuProviderConfig>,
     controller: Option<State<MenuController>>,
     menu_content: Option<RenderSlot>,
-    just_opened: Option<bool>,
 ) {
     let provider = provider.unwrap_or_default();
-    let just_opened = just_opened.unwrap_or(false);
     let controller = controller.expect("menu_panel requires controller");
     let menu_content = menu_content.expect("menu_panel requires menu content");
     let focus_scope = remember(FocusScopeNode::new).get();
@@ -617,10 +474,6 @@ fn menu_panel(
             }
         },
     );
-    if just_opened {
-        focus_scope.restore_focus();
-    }
-
     layout().modifier(modifier).child(move || {
         let menu_content = menu_content;
         surface()
@@ -636,7 +489,11 @@ fn menu_panel(
                         None,
                         Some(AxisConstraint::new(Px::ZERO, provider.max_height)),
                     )
-                    .clip_to_bounds(),
+                    .clip_to_bounds()
+                    .push_semantics(MenuDismissSemanticsModifierNode {
+                        controller,
+                        on_dismiss,
+                    }),
             )
             .accessibility_role(Role::Menu)
             .block_input(true)
@@ -725,6 +582,8 @@ impl Default for MenuItemConfig {
 /// - `label` — primary text shown for the menu action
 /// - `supporting_text` — optional supporting text below the label
 /// - `trailing_text` — optional trailing text such as a shortcut hint
+/// - `command` — optional command whose bound shortcut is shown when
+///   `trailing_text` is not set
 /// - `leading_icon` — optional icon shown on the leading side
 /// - `trailing_icon` — optional icon shown on the trailing side
 /// - `submenu_content` — optional submenu content opened from this item
@@ -769,6 +628,7 @@ pub fn menu_item(
     #[prop(into)] label: Option<String>,
     #[prop(into)] supporting_text: Option<String>,
     #[prop(into)] trailing_text: Option<String>,
+    #[prop(into)] command: Option<CommandId>,
     #[prop(into)] leading_icon: Option<Painter>,
     #[prop(into)] trailing_icon: Option<Painter>,
     submenu_content: Option<RenderSlot>,
@@ -784,6 +644,7 @@ pub fn menu_item(
 ) {
     let label = label.unwrap_or_default();
     let selected = selected.unwrap_or(false);
+    let trailing_text = trailing_text.or_else(|| command.and_then(shortcut::accelerator_label));
     let scheme = use_context::<MaterialTheme>()
         .expect("MaterialTheme must be provided")
         .get()
@@ -828,6 +689,83 @@ pub fn menu_item(
     menu_item_inner().args(args);
 }
 
+/// # menu_entries
+///
+/// Renders a shared [`Menu`] model as [`menu_item`]s.
+///
+/// ## Usage
+///
+/// Reuse the menu given to the native menu bar or tray for in-app menus, e.g.
+/// when the desktop has no global menu bar.
+///
+/// ## Parameters
+///
+/// - `menu` — menu model whose entries are rendered in order
+///
+/// ## Examples
+/// ```
+/// # use tessera_ui::tessera;
+/// # #[tessera]
+/// # fn component() {
+/// use tessera_components::{
+///     menus::{menu_entries, menu_provider},
+///     text::text,
+/// };
+/// use tessera_platform::menu::{Menu, MenuItem};
+/// # use tessera_components::theme::{MaterialTheme, material_theme};
+///
+/// let menu = Menu::new()
+///     .item(MenuItem::new("Copy").command("edit.copy"))
+///     .separator()
+///     .item(MenuItem::new("Show Hidden").checked(false));
+/// # material_theme()
+/// #     .theme(|| MaterialTheme::default())
+/// #     .child(move || {
+/// # let menu = menu.clone();
+/// menu_provider()
+///     .is_open(true)
+///     .menu_content(move || {
+///         menu_entries().menu(menu.clone());
+///     })
+///     .main_content(|| {
+///         text().content("Main content");
+///     });
+/// # });
+/// # }
+/// # component();
+/// ```
+#[tessera]
+pub fn menu_entries(menu: Option<Menu>) {
+    let Some(menu) = menu else {
+        return;
+    };
+    for entry in menu.entries() {
+        match entry {
+            MenuEntry::Item(item) => {
+                let model = item.clone();
+                let mut builder = menu_item()
+                    .label(item.label())
+                    .selected(item.check_state().unwrap_or(false))
+                    .enabled(item.is_enabled())
+                    .on_click(move || model.activate());
+                if let Some(accelerator) = item.accelerator_label() {
+                    builder = builder.trailing_text(accelerator);
+                }
+                drop(builder);
+            }
+            MenuEntry::Submenu { label, menu } => {
+                let menu = menu.clone();
+                menu_item().label(label.clone()).submenu_content(move || {
+                    menu_entries().menu(menu.clone());
+                });
+            }
+            MenuEntry::Separator => {
+                horizontal_divider();
+            }
+        }
+    }
+}
+
 fn render_leading(args: &MenuItemConfig, enabled: bool) {
     if args.selected {
         checkmark()
@@ -1103,3 +1041,66 @@ fn menu_item_inner(args: Option<MenuItemConfig>) {
         menu_item_surface().item(args);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use tessera_ui::{
+        Modifier,
+        accesskit::Action,
+        testing::{__private, layout_test},
+    };
+
+    use crate::{
+        modifier::{ModifierExt as _, SemanticsArgs},
+        spacer::spacer,
+        theme::{MaterialTheme, material_theme},
+    };
+
+    use super::menu_provider;
+
+    static MENU_DISMISSALS: AtomicUsize = AtomicUsize::new(0);
+
+    fn menu_case() {
+        material_theme().theme(MaterialTheme::default).child(|| {
+            menu_provider()
+                .is_open(true)
+                .modifier(Modifier::new().semantics(SemanticsArgs {
+                    test_tag: Some("menu".to_string()),
+                    ..Default::default()
+                }))
+                .on_dismiss(|| {
+                    MENU_DISMISSALS.fetch_add(1, Ordering::Relaxed);
+                })
+                .main_content(|| {
+                    spacer().modifier(Modifier::new().fill_max_size());
+                })
+                .menu_content(|| {
+                    spacer().modifier(Modifier::new().fill_max_size());
+                });
+        });
+    }
+
+    #[test]
+    fn open_menu_collapses_through_accessibility_action() {
+        MENU_DISMISSALS.store(0, Ordering::Relaxed);
+        let mut session = __private::start_layout_test_session(
+            layout_test(|| {
+                menu_case();
+            })
+            .viewport_px(200, 200),
+        );
+        // The overlay host shows the menu registered during the first frame.
+        let _ = __private::pump_layout_test_session(&mut session, 0);
+        let _ = __private::advance_layout_test_session_by_nanos(&mut session, 16_666_667);
+
+        assert!(__private::perform_layout_test_accessibility_action(
+            &mut session,
+            "menu",
+            Action::Collapse,
+            None,
+        ));
+        assert_eq!(MENU_DISMISSALS.load(Ordering::Relaxed), 1);
+    }
+}
@@ -17,7 +17,8 @@ use tessera_ui::{
 pub use tessera_foundation::modifier::{
     ClickableArgs, DragDelta, DraggableArgs, InteractionState,
     MinimumInteractiveComponentEnforcement, Padding, PointerEventContext, SelectableArgs,
-    SemanticsArgs, ToggleableArgs,
+    SemanticsArgs, SemanticsCustomAction, ToggleableArgs, TransformDelta, TransformVelocity,
+    TransformableArgs,
 };
 
 pub(crate) use tessera_foundation::modifier::{AlignmentParentData, WeightParentData};
@@ -32,7 +33,10 @@ use visual::{AlphaModifierNode, BackgroundModifierNode, BorderModifierNode, Clip
 
 pub use shadow::ShadowArgs;
 
-pub(crate) use interaction::{with_keyboard_input, with_pointer_input};
+pub(crate) use interaction::{
+    with_keyboard_input, with_keyboard_preview_input, with_pointer_input,
+    with_pointer_preview_input,
+};
 
 /// Extensions for composing reusable wrapper behavior around component
 /// subtrees.
@@ -117,6 +121,14 @@ pub trait ModifierExt {
     /// Emits drag deltas with custom drag configuration.
     fn draggable_with(self, args: DraggableArgs) -> Modifier;
 
+    /// Emits pan, zoom, and rotation deltas for multi-pointer transforms.
+    fn transformable<C>(self, on_transform: C) -> Modifier
+    where
+        C: Into<CallbackWith<TransformDelta, ()>>;
+
+    /// Emits transform deltas with custom transform configuration.
+    fn transformable_with(self, args: TransformableArgs) -> Modifier;
+
     /// Prevents cursor events from propagating to components behind this
     /// subtree.
     fn block_touch_propagation(self) -> Modifier;
@@ -286,6 +298,17 @@ impl ModifierExt for Modifier {
         FoundationModifierExt::draggable_with(self, args)
     }
 
+    fn transformable<C>(self, on_transform: C) -> Modifier
+    where
+        C: Into<CallbackWith<TransformDelta, ()>>,
+    {
+        FoundationModifierExt::transformable(self, on_transform)
+    }
+
+    fn transformable_with(self, args: TransformableArgs) -> Modifier {
+        FoundationModifierExt::transformable_with(self, args)
+    }
+
     fn block_touch_propagation(self) -> Modifier {
         apply_block_touch_propagation_modifier(self)
     }
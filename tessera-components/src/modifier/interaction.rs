@@ -62,6 +62,13 @@ where
     base.push_pointer_input(ClosurePointerInputModifierNode { handler })
 }
 
+pub(crate) fn with_pointer_preview_input<F>(base: Modifier, handler: F) -> Modifier
+where
+    F: for<'a> Fn(PointerInput<'a>) + Send + Sync + 'static,
+{
+    base.push_pointer_preview_input(ClosurePointerInputModifierNode { handler })
+}
+
 pub(crate) fn with_keyboard_input<F>(base: Modifier, handler: F) -> Modifier
 where
     F: for<'a> Fn(KeyboardInput<'a>) + Send + Sync + 'static,
@@ -69,6 +76,13 @@ where
     base.push_keyboard_input(ClosureKeyboardInputModifierNode { handler })
 }
 
+pub(crate) fn with_keyboard_preview_input<F>(base: Modifier, handler: F) -> Modifier
+where
+    F: for<'a> Fn(KeyboardInput<'a>) + Send + Sync + 'static,
+{
+    base.push_keyboard_preview_input(ClosureKeyboardInputModifierNode { handler })
+}
+
 fn has_keyboard_activation_event(
     keyboard_events: &[tessera_ui::winit::event::KeyEvent],
     modifiers: tessera_ui::winit::keyboard::ModifiersState,
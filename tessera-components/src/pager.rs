@@ -7,9 +7,13 @@ use tessera_foundation::gesture::{
     DragAxis, DragRecognizer, DragSettings, ScrollRecognizer, ScrollSettings,
 };
 use tessera_ui::{
-    AxisConstraint, CallbackWith, ComputedData, Constraint, Dp, FocusProperties, KeyboardInput,
+    AccessibilityActionHandler, AccessibilityActionHandlers, AccessibilityNode, AxisConstraint,
+    CallbackWith, ComputedData, Constraint, Dp, FocusProperties, KeyboardInput,
     KeyboardInputModifierNode, LayoutResult, MeasurementError, Modifier, PointerInput,
-    PointerInputModifierNode, Px, PxPosition, ScrollDeltaUnit, ScrollEventSource, State, key,
+    PointerInputModifierNode, Px, PxPosition, ScrollDeltaUnit, ScrollEventSource,
+    SemanticsModifierNode, State,
+    accesskit::{Action, Role},
+    key,
     layout::{LayoutPolicy, MeasureScope, PlacementScope, RenderInput, RenderPolicy, layout},
     modifier::{FocusModifierExt as _, ModifierCapabilityExt as _},
     normalize_platform_scroll_delta, receive_frame_nanos, remember, tessera, winit,
@@ -447,6 +451,15 @@ impl PagerAxis {
         }
     }
 
+    /// Returns the accessibility actions that move to the previous and next
+    /// page.
+    fn scroll_actions(self) -> (Action, Action) {
+        match self {
+            Self::Horizontal => (Action::ScrollLeft, Action::ScrollRight),
+            Self::Vertical => (Action::ScrollUp, Action::ScrollDown),
+        }
+    }
+
     fn scroll_delta(self, delta_x: f32, delta_y: f32) -> f32 {
         match self {
             Self::Horizontal => {
@@ -824,6 +837,52 @@ impl PointerInputModifierNode for PagerPointerModifierNode {
     }
 }
 
+struct PagerSemanticsModifierNode {
+    controller: State<PagerController>,
+    axis: PagerAxis,
+    current_page: usize,
+    page_count: usize,
+    user_scroll_enabled: bool,
+}
+
+impl SemanticsModifierNode for PagerSemanticsModifierNode {
+    fn apply(
+        &self,
+        accessibility: &mut AccessibilityNode,
+        _action_handler: &mut Option<AccessibilityActionHandler>,
+    ) {
+        accessibility.role = Some(Role::ScrollView);
+        let pages = (
+            self.current_page as f64,
+            0.0,
+            self.page_count.saturating_sub(1) as f64,
+        );
+        match self.axis {
+            PagerAxis::Horizontal => accessibility.scroll_x = Some(pages),
+            PagerAxis::Vertical => accessibility.scroll_y = Some(pages),
+        }
+    }
+
+    fn apply_action_handlers(&self, handlers: &mut AccessibilityActionHandlers) {
+        if !self.user_scroll_enabled {
+            return;
+        }
+
+        let controller = self.controller;
+        let (previous, next) = self.axis.scroll_actions();
+        if self.current_page > 0 {
+            handlers.on_action(previous, move || {
+                run_pager_keyboard_command(controller, PagerKeyboardCommand::Previous);
+            });
+        }
+        if self.current_page + 1 < self.page_count {
+            handlers.on_action(next, move || {
+                run_pager_keyboard_command(controller, PagerKeyboardCommand::Next);
+            });
+        }
+    }
+}
+
 fn apply_pager_input_modifiers(
     base: Modifier,
     controller: State<PagerController>,
@@ -1083,7 +1142,14 @@ fn pager_render(args: PagerConfig, controller: State<PagerController>, axis: Pag
         args.user_scroll_enabled,
         drag_recognizer,
         scroll_recognizer,
-    );
+    )
+    .push_semantics(PagerSemanticsModifierNode {
+        controller,
+        axis,
+        current_page,
+        page_count: args.page_count,
+        user_scroll_enabled: args.user_scroll_enabled,
+    });
 
     let policy = PagerLayout {
         axis,
@@ -1185,3 +1251,72 @@ fn run_pager_keyboard_command(
         true
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use tessera_ui::{
+        Dp, Modifier,
+        accesskit::Action,
+        testing::{__private, layout_test},
+    };
+
+    use crate::{
+        modifier::{ModifierExt as _, SemanticsArgs},
+        spacer::spacer,
+        theme::{MaterialTheme, material_theme},
+    };
+
+    use super::horizontal_pager;
+
+    fn pager_case() {
+        material_theme().theme(MaterialTheme::default).child(|| {
+            horizontal_pager()
+                .modifier(
+                    Modifier::new()
+                        .size(Dp(100.0), Dp(50.0))
+                        .semantics(SemanticsArgs {
+                            test_tag: Some("pager".to_string()),
+                            ..Default::default()
+                        }),
+                )
+                .page_count(3)
+                .page_content(|_| {
+                    spacer().modifier(Modifier::new().fill_max_size());
+                });
+        });
+    }
+
+    #[test]
+    fn pager_scroll_actions_move_between_pages() {
+        let mut session = __private::start_layout_test_session(
+            layout_test(|| {
+                pager_case();
+            })
+            .viewport_px(100, 50),
+        );
+        let _ = __private::pump_layout_test_session(&mut session, 0);
+
+        assert!(!__private::perform_layout_test_accessibility_action(
+            &mut session,
+            "pager",
+            Action::ScrollLeft,
+            None,
+        ));
+        assert!(__private::perform_layout_test_accessibility_action(
+            &mut session,
+            "pager",
+            Action::ScrollRight,
+            None,
+        ));
+
+        for _ in 0..60 {
+            let _ = __private::advance_layout_test_session_by_nanos(&mut session, 16_666_667);
+        }
+        assert!(__private::perform_layout_test_accessibility_action(
+            &mut session,
+            "pager",
+            Action::ScrollLeft,
+            None,
+        ));
+    }
+}
@@ -105,13 +105,15 @@ where
 /// builds.
 ///
 /// This is the Tessera-aware resource-loading entrypoint for UI code. The
-/// decoded painter state is memoized by asset key, so repeated recomposition
-/// does not re-read or re-decode the same asset at the same callsite.
+/// decoded painter state is memoized by asset key and resolved variant, so
+/// repeated recomposition does not re-read or re-decode the same asset at the
+/// same callsite, while scale factor or theme changes pick up the matching
+/// variant.
 pub fn remember_painter_asset<T>(asset: T) -> State<Painter>
 where
     T: AssetExt + Clone + Hash + Send + Sync + 'static,
 {
-    remember_with_key(asset, move || {
+    remember_with_key((asset, asset.variant_index()), move || {
         try_painter_asset(asset).expect("asset painter should decode successfully")
     })
 }
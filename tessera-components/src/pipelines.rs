@@ -16,6 +16,8 @@ pub(crate) mod shadow;
 pub(crate) mod shape;
 pub(crate) mod simple_rect;
 pub(crate) mod text;
+pub(crate) mod video;
+pub(crate) mod viewport;
 
 mod composite;
 mod compute;
@@ -1,7 +1,12 @@
 use tessera_ui::PipelineContext;
 
-use crate::pipelines::shadow::atlas::ShadowAtlasPipeline;
+use crate::pipelines::{
+    shadow::atlas::ShadowAtlasPipeline, video::pipeline::VideoPipeline,
+    viewport::pipeline::ViewportPipeline,
+};
 
 pub(super) fn register(context: &mut PipelineContext<'_>) {
     context.register_composite_pipeline(ShadowAtlasPipeline::new());
+    context.register_composite_pipeline(VideoPipeline::new());
+    context.register_composite_pipeline(ViewportPipeline::new());
 }
@@ -10,6 +10,8 @@ use crate::pipelines::{
     shape::pipeline::ShapePipeline,
     simple_rect::pipeline::SimpleRectPipeline,
     text::pipeline::GlyphonTextRender,
+    video::pipeline::{VideoBlitPipeline, VideoConvertPipeline},
+    viewport::pipeline::ViewportBlitPipeline,
 };
 
 pub(super) fn register(context: &mut PipelineContext<'_>) {
@@ -22,6 +24,8 @@ pub(super) fn register(context: &mut PipelineContext<'_>) {
     register_fluid_glass(context);
     register_image(context);
     register_image_vector(context);
+    register_video(context);
+    register_viewport(context);
 }
 
 fn register_simple_rect(context: &mut PipelineContext<'_>) {
@@ -128,3 +132,33 @@ fn register_image_vector(context: &mut PipelineContext<'_>) {
     );
     context.register_draw_pipeline(pipeline);
 }
+
+fn register_video(context: &mut PipelineContext<'_>) {
+    let resources = context.resources();
+    let convert = VideoConvertPipeline::new(
+        resources.device,
+        resources.queue,
+        resources.surface_config,
+        resources.pipeline_cache,
+        resources.sample_count,
+    );
+    let blit = VideoBlitPipeline::new(
+        resources.device,
+        resources.surface_config,
+        resources.pipeline_cache,
+        resources.sample_count,
+    );
+    context.register_draw_pipeline(convert);
+    context.register_draw_pipeline(blit);
+}
+
+fn register_viewport(context: &mut PipelineContext<'_>) {
+    let resources = context.resources();
+    let pipeline = ViewportBlitPipeline::new(
+        resources.device,
+        resources.surface_config,
+        resources.pipeline_cache,
+        resources.sample_count,
+    );
+    context.register_draw_pipeline(pipeline);
+}
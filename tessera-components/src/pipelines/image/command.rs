@@ -47,6 +47,12 @@ pub struct ImageCommand {
     pub data: Arc<ImageData>,
     /// Opacity multiplier applied to the sampled image.
     pub opacity: f32,
+    /// Stable texture slot for content whose pixels change between frames.
+    ///
+    /// Commands sharing a slot reuse one GPU texture and upload new pixels
+    /// into it when `data` changes, instead of allocating a texture per
+    /// distinct image. Use `None` for static images.
+    pub texture_slot: Option<u64>,
 }
 
 impl DrawCommand for ImageCommand {
@@ -1,10 +1,10 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
 use encase::{ShaderType, UniformBuffer};
 use glam::Vec4;
 use tessera_ui::{
     PxPosition, PxSize,
-    renderer::drawer::pipeline::{DrawContext, DrawablePipeline},
+    renderer::drawer::pipeline::{DrawContext, DrawablePipeline, FrameContext},
     wgpu,
 };
 
@@ -18,15 +18,28 @@ struct ImageUniforms {
 }
 
 struct ImageResources {
+    texture: wgpu::Texture,
     bind_group: wgpu::BindGroup,
     uniform_buffer: wgpu::Buffer,
 }
 
+/// Texture reused across frames for a [`ImageCommand::texture_slot`].
+struct SlotResources {
+    resources: ImageResources,
+    data: Arc<ImageData>,
+    last_used_frame: u64,
+}
+
+/// Number of frames a texture slot survives without being drawn.
+const SLOT_RETENTION_FRAMES: u64 = 120;
+
 /// Pipeline for rendering images in UI components.
 pub struct ImagePipeline {
     pipeline: wgpu::RenderPipeline,
     bind_group_layout: wgpu::BindGroupLayout,
     resources: HashMap<ImageData, ImageResources>,
+    slots: HashMap<u64, SlotResources>,
+    frame_index: u64,
 }
 
 impl ImagePipeline {
@@ -114,6 +127,8 @@ impl ImagePipeline {
             pipeline,
             bind_group_layout,
             resources: HashMap::new(),
+            slots: HashMap::new(),
+            frame_index: 0,
         }
     }
 
@@ -130,6 +145,57 @@ impl ImagePipeline {
         })
     }
 
+    /// Return the slot texture for `data`, uploading new pixels in place when
+    /// the frame changed but its size did not.
+    fn get_or_update_slot(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+        slot: u64,
+        data: &Arc<ImageData>,
+    ) -> &ImageResources {
+        let frame_index = self.frame_index;
+        let layout = &self.bind_group_layout;
+        let entry = self.slots.entry(slot).or_insert_with(|| SlotResources {
+            resources: Self::create_image_resources(device, queue, config, layout, data),
+            data: data.clone(),
+            last_used_frame: frame_index,
+        });
+        entry.last_used_frame = frame_index;
+        if !Arc::ptr_eq(&entry.data, data) {
+            if entry.data.width == data.width && entry.data.height == data.height {
+                Self::write_texture(queue, &entry.resources.texture, data);
+            } else {
+                entry.resources = Self::create_image_resources(device, queue, config, layout, data);
+            }
+            entry.data = data.clone();
+        }
+        &entry.resources
+    }
+
+    fn write_texture(queue: &wgpu::Queue, texture: &wgpu::Texture, data: &ImageData) {
+        queue.write_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            &data.data,
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(4 * data.width),
+                rows_per_image: Some(data.height),
+            },
+            wgpu::Extent3d {
+                width: data.width,
+                height: data.height,
+                depth_or_array_layers: 1,
+            },
+        );
+    }
+
     /// Compute the ImageUniforms for a given command size and position.
     fn compute_uniforms(
         start_pos: PxPosition,
@@ -187,21 +253,7 @@ impl ImagePipeline {
             view_formats: &[],
         });
 
-        queue.write_texture(
-            wgpu::TexelCopyTextureInfo {
-                texture: &diffuse_texture,
-                mip_level: 0,
-                origin: wgpu::Origin3d::ZERO,
-                aspect: wgpu::TextureAspect::All,
-            },
-            &data.data,
-            wgpu::TexelCopyBufferLayout {
-                offset: 0,
-                bytes_per_row: Some(4 * data.width),
-                rows_per_image: Some(data.height),
-            },
-            texture_size,
-        );
+        Self::write_texture(queue, &diffuse_texture, data);
 
         let diffuse_texture_view =
             diffuse_texture.create_view(&wgpu::TextureViewDescriptor::default());
@@ -242,6 +294,7 @@ impl ImagePipeline {
         });
 
         ImageResources {
+            texture: diffuse_texture,
             bind_group: diffuse_bind_group,
             uniform_buffer,
         }
@@ -249,17 +302,37 @@ impl ImagePipeline {
 }
 
 impl DrawablePipeline<ImageCommand> for ImagePipeline {
+    fn begin_frame(&mut self, _context: &FrameContext<'_>) {
+        self.frame_index = self.frame_index.wrapping_add(1);
+    }
+
+    fn end_frame(&mut self, _context: &FrameContext<'_>) {
+        let frame_index = self.frame_index;
+        self.slots.retain(|_, slot| {
+            frame_index.wrapping_sub(slot.last_used_frame) <= SLOT_RETENTION_FRAMES
+        });
+    }
+
     fn draw(&mut self, context: &mut DrawContext<ImageCommand>) {
         context.render_pass.set_pipeline(&self.pipeline);
 
         for (command, size, start_pos) in context.commands.iter() {
             // Use the extracted helper to obtain or create GPU resources.
-            let resources = self.get_or_create_resources(
-                context.device,
-                context.queue,
-                context.config,
-                &command.data,
-            );
+            let resources = match command.texture_slot {
+                Some(slot) => self.get_or_update_slot(
+                    context.device,
+                    context.queue,
+                    context.config,
+                    slot,
+                    &command.data,
+                ),
+                None => self.get_or_create_resources(
+                    context.device,
+                    context.queue,
+                    context.config,
+                    &command.data,
+                ),
+            };
 
             // Use the extracted uniforms computation helper (dereference borrowed tuple
             // elements).
@@ -1,4 +1,5 @@
 //! Text rendering pipeline.
 
+pub(crate) mod accessibility;
 pub mod command;
 pub mod pipeline;
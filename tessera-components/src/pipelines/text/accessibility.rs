@@ -0,0 +1,244 @@
+//! Accessible text runs derived from shaped glyphon buffers.
+
+use tessera_ui::{AccessibilityText, AccessibilityTextRun, Px, PxRect};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Horizontal extent of one shaped glyph cluster within a line.
+#[derive(Debug, Clone, Copy)]
+struct GlyphExtent {
+    /// Byte range of the cluster within the line text.
+    start: usize,
+    end: usize,
+    x: f32,
+    width: f32,
+}
+
+/// Builds one accessible run per visual line of `buffer`.
+///
+/// Byte offsets count line endings, so they match the text the buffer was
+/// built from.
+pub(crate) fn buffer_accessibility_text(buffer: &glyphon::Buffer) -> AccessibilityText {
+    let mut line_starts = Vec::with_capacity(buffer.lines.len());
+    let mut offset = 0;
+    for line in &buffer.lines {
+        line_starts.push(offset);
+        offset += line.text().len() + line.ending().as_str().len();
+    }
+
+    let runs = buffer
+        .layout_runs()
+        .map(|run| {
+            let glyphs: Vec<GlyphExtent> = run
+                .glyphs
+                .iter()
+                .map(|glyph| GlyphExtent {
+                    start: glyph.start,
+                    end: glyph.end,
+                    x: glyph.x,
+                    width: glyph.w,
+                })
+                .collect();
+            let bounds = PxRect::new(
+                Px::ZERO,
+                Px::from_f32(run.line_top),
+                Px::from_f32(run.line_w.ceil()),
+                Px::from_f32(run.line_height.ceil()),
+            );
+            let line_start = line_starts.get(run.line_i).copied().unwrap_or(offset);
+            layout_run(run.text, line_start, &glyphs, bounds)
+        })
+        .collect();
+    AccessibilityText::new(runs)
+}
+
+/// Builds the run for the part of `line` covered by `glyphs`.
+fn layout_run(
+    line: &str,
+    line_start: usize,
+    glyphs: &[GlyphExtent],
+    bounds: PxRect,
+) -> AccessibilityTextRun {
+    let start = glyphs.iter().map(|glyph| glyph.start).min().unwrap_or(0);
+    let end = glyphs.iter().map(|glyph| glyph.end).max().unwrap_or(start);
+    let text = line.get(start..end).unwrap_or_default();
+
+    let graphemes: Vec<(usize, &str)> = text
+        .grapheme_indices(true)
+        .map(|(offset, grapheme)| (start + offset, grapheme))
+        .collect();
+
+    // Ligatures spread their advance evenly over the characters they cover;
+    // clusters with several glyphs widen the character they belong to.
+    // Glyphs come in visual order, so walk them by byte offset with a cursor
+    // that only moves forward over the graphemes.
+    let mut order: Vec<&GlyphExtent> = glyphs.iter().collect();
+    order.sort_by_key(|glyph| glyph.start);
+    let mut extents: Vec<Option<(f32, f32)>> = vec![None; graphemes.len()];
+    let mut cursor = 0;
+    for glyph in order {
+        while cursor < graphemes.len() && graphemes[cursor].0 < glyph.start {
+            cursor += 1;
+        }
+        let covered_end = cursor
+            + graphemes[cursor..]
+                .iter()
+                .take_while(|(offset, _)| *offset < glyph.end)
+                .count();
+        let covered = if covered_end > cursor {
+            cursor..covered_end
+        } else if cursor > 0 {
+            // The glyph starts inside a grapheme that an earlier one opened.
+            cursor - 1..cursor
+        } else {
+            continue;
+        };
+        let share = glyph.width / covered.len() as f32;
+        for (slot, index) in covered.enumerate() {
+            let left = glyph.x + share * slot as f32;
+            let right = left + share;
+            extents[index] = Some(match extents[index] {
+                Some((l, r)) => (l.min(left), r.max(right)),
+                None => (left, right),
+            });
+        }
+    }
+
+    // Character lengths are single bytes, so graphemes longer than that are
+    // reported as several characters sharing the grapheme's extent.
+    let mut characters = Vec::with_capacity(graphemes.len());
+    let mut character_lengths = Vec::with_capacity(graphemes.len());
+    let mut character_positions = Vec::with_capacity(graphemes.len());
+    let mut character_widths = Vec::with_capacity(graphemes.len());
+    let mut edge = 0.0_f32;
+    for ((_, grapheme), extent) in graphemes.iter().zip(extents) {
+        let (left, right) = extent.unwrap_or((edge, edge));
+        let pieces = split_character(grapheme);
+        let share = (right - left) / pieces.len() as f32;
+        for (slot, piece) in pieces.into_iter().enumerate() {
+            characters.push(piece);
+            character_lengths.push(piece.len() as u8);
+            character_positions.push(left + share * slot as f32);
+            character_widths.push(share);
+        }
+        edge = right;
+    }
+
+    AccessibilityTextRun {
+        text: text.to_string(),
+        byte_start: line_start + start,
+        bounds,
+        character_lengths,
+        character_positions,
+        character_widths,
+        word_lengths: word_lengths(characters.into_iter()),
+    }
+}
+
+/// Splits `grapheme` on char boundaries into pieces of at most `u8::MAX`
+/// bytes.
+fn split_character(grapheme: &str) -> Vec<&str> {
+    let mut pieces = Vec::with_capacity(1);
+    let mut rest = grapheme;
+    while rest.len() > u8::MAX as usize {
+        let mut split = u8::MAX as usize;
+        while !rest.is_char_boundary(split) {
+            split -= 1;
+        }
+        let (piece, tail) = rest.split_at(split);
+        pieces.push(piece);
+        rest = tail;
+    }
+    pieces.push(rest);
+    pieces
+}
+
+/// Counts characters per word; each word keeps its trailing whitespace.
+fn word_lengths<'a>(graphemes: impl Iterator<Item = &'a str>) -> Vec<u8> {
+    let mut lengths = Vec::new();
+    let mut previous_is_space = true;
+    for grapheme in graphemes {
+        let is_space = grapheme.chars().all(char::is_whitespace);
+        let starts_word = previous_is_space && !is_space;
+        match lengths.last_mut() {
+            Some(length) if !starts_word && *length < u8::MAX => *length += 1,
+            _ => lengths.push(1),
+        }
+        previous_is_space = is_space;
+    }
+    lengths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glyph(start: usize, end: usize, x: f32, width: f32) -> GlyphExtent {
+        GlyphExtent {
+            start,
+            end,
+            x,
+            width,
+        }
+    }
+
+    #[test]
+    fn ligatures_split_their_advance() {
+        let glyphs = [glyph(0, 2, 0.0, 20.0), glyph(2, 3, 20.0, 8.0)];
+        let run = layout_run("fix", 5, &glyphs, PxRect::ZERO);
+        assert_eq!(run.text, "fix");
+        assert_eq!(run.byte_start, 5);
+        assert_eq!(run.character_lengths, vec![1, 1, 1]);
+        assert_eq!(run.character_positions, vec![0.0, 10.0, 20.0]);
+        assert_eq!(run.character_widths, vec![10.0, 10.0, 8.0]);
+    }
+
+    #[test]
+    fn wrapped_runs_cover_their_glyphs() {
+        let glyphs = [glyph(6, 7, 0.0, 5.0), glyph(7, 9, 5.0, 5.0)];
+        let run = layout_run("hello wé", 0, &glyphs, PxRect::ZERO);
+        assert_eq!(run.text, "wé");
+        assert_eq!(run.byte_start, 6);
+        assert_eq!(run.character_lengths, vec![1, 2]);
+        assert_eq!(run.character_offset(2), 9);
+    }
+
+    #[test]
+    fn right_to_left_glyphs_map_to_their_graphemes() {
+        let glyphs = [
+            glyph(2, 3, 0.0, 4.0),
+            glyph(1, 2, 4.0, 6.0),
+            glyph(0, 1, 10.0, 8.0),
+        ];
+        let run = layout_run("abc", 0, &glyphs, PxRect::ZERO);
+        assert_eq!(run.character_positions, vec![10.0, 4.0, 0.0]);
+        assert_eq!(run.character_widths, vec![8.0, 6.0, 4.0]);
+    }
+
+    #[test]
+    fn long_graphemes_split_into_byte_sized_characters() {
+        let line = format!("e{}x", "\u{301}".repeat(200));
+        let cluster = line.len() - 1;
+        let glyphs = [
+            glyph(0, cluster, 0.0, 12.0),
+            glyph(cluster, line.len(), 12.0, 6.0),
+        ];
+        let run = layout_run(&line, 0, &glyphs, PxRect::ZERO);
+        let lengths: Vec<usize> = run
+            .character_lengths
+            .iter()
+            .map(|&len| len as usize)
+            .collect();
+        assert_eq!(lengths, vec![255, cluster - 255, 1]);
+        assert_eq!(lengths.iter().sum::<usize>(), line.len());
+        assert_eq!(run.character_positions, vec![0.0, 6.0, 12.0]);
+        assert_eq!(run.character_widths, vec![6.0, 6.0, 6.0]);
+        assert_eq!(run.word_lengths, vec![3]);
+    }
+
+    #[test]
+    fn words_keep_trailing_whitespace() {
+        let lengths = word_lengths(["a", "b", " ", " ", "c", " ", "d"].into_iter());
+        assert_eq!(lengths, vec![4, 2, 1]);
+        assert_eq!(word_lengths([" ", "a"].into_iter()), vec![1, 1]);
+    }
+}
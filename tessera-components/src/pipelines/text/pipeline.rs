@@ -19,12 +19,15 @@ use std::{num::NonZero, sync::OnceLock};
 use glyphon::fontdb;
 use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 use tessera_ui::{
-    Color, PxPosition,
+    AccessibilityText, Color, PxPosition,
     renderer::drawer::pipeline::{DrawContext, DrawablePipeline},
     wgpu,
 };
 
-use super::command::{TextCommand, TextConstraint};
+use super::{
+    accessibility::buffer_accessibility_text,
+    command::{TextCommand, TextConstraint},
+};
 
 #[cfg(target_family = "wasm")]
 use crate::res;
@@ -524,6 +527,11 @@ impl TextData {
         }
     }
 
+    /// Returns the laid-out text as accessible runs, one per visual line.
+    pub fn accessibility_text(&self) -> AccessibilityText {
+        buffer_accessibility_text(&self.text_buffer)
+    }
+
     /// Get the glyphon text area from the text data
     fn text_area(&'_ self, start_pos: PxPosition) -> glyphon::TextArea<'_> {
         let bounds = glyphon::TextBounds {
@@ -0,0 +1,4 @@
+//! Video pipeline for frames produced outside the render graph.
+
+pub mod command;
+pub mod pipeline;
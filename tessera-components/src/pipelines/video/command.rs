@@ -0,0 +1,518 @@
+use std::{sync::Arc, time::Duration};
+
+use tessera_ui::{CompositeCommand, DrawCommand, PxSize, wgpu};
+use thiserror::Error;
+
+/// Errors returned when a video frame description is inconsistent.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum VideoFrameError {
+    /// The frame has a zero width or height.
+    #[error("video frame size {width}x{height} is empty")]
+    EmptyFrame {
+        /// Frame width in pixels.
+        width: u32,
+        /// Frame height in pixels.
+        height: u32,
+    },
+    /// A plane stride is shorter than one row of pixels.
+    #[error("{plane} plane stride {stride} is shorter than a {row_bytes}-byte row")]
+    StrideTooSmall {
+        /// Plane name.
+        plane: &'static str,
+        /// Provided stride in bytes.
+        stride: u32,
+        /// Bytes needed for one row.
+        row_bytes: u32,
+    },
+    /// A plane buffer is shorter than its rows require.
+    #[error("{plane} plane holds {actual} bytes but {expected} are required")]
+    PlaneTooSmall {
+        /// Plane name.
+        plane: &'static str,
+        /// Required length in bytes.
+        expected: usize,
+        /// Provided length in bytes.
+        actual: usize,
+    },
+    /// A GPU texture cannot be sampled by the video pipeline.
+    #[error("texture format {0:?} or usage cannot be sampled as a video frame")]
+    UnsupportedTexture(wgpu::TextureFormat),
+}
+
+/// One plane of CPU pixel data.
+#[derive(Debug, Clone)]
+pub struct VideoPlane {
+    /// Plane bytes, `stride` bytes per row.
+    pub data: Arc<[u8]>,
+    /// Distance between the starts of consecutive rows in bytes.
+    pub stride: u32,
+}
+
+impl VideoPlane {
+    /// Creates a plane from bytes and a row stride.
+    pub fn new(data: impl Into<Arc<[u8]>>, stride: u32) -> Self {
+        Self {
+            data: data.into(),
+            stride,
+        }
+    }
+
+    fn validate(
+        &self,
+        plane: &'static str,
+        row_bytes: u32,
+        rows: u32,
+    ) -> Result<(), VideoFrameError> {
+        if self.stride < row_bytes {
+            return Err(VideoFrameError::StrideTooSmall {
+                plane,
+                stride: self.stride,
+                row_bytes,
+            });
+        }
+        let expected = self.stride as usize * (rows as usize - 1) + row_bytes as usize;
+        if self.data.len() < expected {
+            return Err(VideoFrameError::PlaneTooSmall {
+                plane,
+                expected,
+                actual: self.data.len(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// YUV to RGB conversion matrix family.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum YuvColorSpace {
+    /// ITU-R BT.601, used by SD video and most webcams.
+    Bt601,
+    /// ITU-R BT.709, used by HD video.
+    #[default]
+    Bt709,
+}
+
+/// Value range of YUV samples.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum YuvRange {
+    /// Studio range: luma in 16..=235, chroma in 16..=240.
+    #[default]
+    Limited,
+    /// Full 0..=255 range, used by JPEG and many camera APIs.
+    Full,
+}
+
+/// Pixel storage of a [`VideoFrame`].
+#[derive(Debug, Clone)]
+pub enum VideoPixels {
+    /// Packed 8-bit RGBA.
+    Rgba(VideoPlane),
+    /// Packed 8-bit BGRA.
+    Bgra(VideoPlane),
+    /// Planar YUV 4:2:0 with separate U and V planes.
+    I420 {
+        /// Full resolution luma plane.
+        y: VideoPlane,
+        /// Half resolution U plane.
+        u: VideoPlane,
+        /// Half resolution V plane.
+        v: VideoPlane,
+    },
+    /// YUV 4:2:0 with interleaved UV samples.
+    Nv12 {
+        /// Full resolution luma plane.
+        y: VideoPlane,
+        /// Half resolution interleaved UV plane.
+        uv: VideoPlane,
+    },
+    /// A GPU texture created on the renderer device, sampled without copies.
+    Texture(wgpu::Texture),
+}
+
+/// A video frame ready for display.
+///
+/// CPU frames are uploaded into textures that are reused while the frame
+/// size and layout stay the same. Texture frames are sampled directly.
+#[derive(Debug, Clone)]
+pub struct VideoFrame {
+    pixels: VideoPixels,
+    width: u32,
+    height: u32,
+    color_space: YuvColorSpace,
+    range: YuvRange,
+    timestamp: Option<Duration>,
+}
+
+impl VideoFrame {
+    /// Creates a frame from packed RGBA pixels.
+    pub fn rgba(width: u32, height: u32, plane: VideoPlane) -> Result<Self, VideoFrameError> {
+        Self::packed(width, height, VideoPixels::Rgba(plane))
+    }
+
+    /// Creates a frame from packed BGRA pixels.
+    pub fn bgra(width: u32, height: u32, plane: VideoPlane) -> Result<Self, VideoFrameError> {
+        Self::packed(width, height, VideoPixels::Bgra(plane))
+    }
+
+    /// Creates a frame from I420 planes.
+    pub fn i420(
+        width: u32,
+        height: u32,
+        y: VideoPlane,
+        u: VideoPlane,
+        v: VideoPlane,
+    ) -> Result<Self, VideoFrameError> {
+        ensure_not_empty(width, height)?;
+        let (chroma_width, chroma_height) = chroma_size(width, height);
+        y.validate("y", width, height)?;
+        u.validate("u", chroma_width, chroma_height)?;
+        v.validate("v", chroma_width, chroma_height)?;
+        Ok(Self::with_pixels(
+            width,
+            height,
+            VideoPixels::I420 { y, u, v },
+        ))
+    }
+
+    /// Creates a frame from NV12 planes.
+    pub fn nv12(
+        width: u32,
+        height: u32,
+        y: VideoPlane,
+        uv: VideoPlane,
+    ) -> Result<Self, VideoFrameError> {
+        ensure_not_empty(width, height)?;
+        let (chroma_width, chroma_height) = chroma_size(width, height);
+        y.validate("y", width, height)?;
+        uv.validate("uv", chroma_width * 2, chroma_height)?;
+        Ok(Self::with_pixels(
+            width,
+            height,
+            VideoPixels::Nv12 { y, uv },
+        ))
+    }
+
+    /// Wraps a GPU texture without copying it.
+    ///
+    /// The texture must be created on the renderer device, for example one
+    /// captured in
+    /// [`RenderModule::register_pipelines`](tessera_ui::RenderModule),
+    /// use an 8-bit RGBA or BGRA format, and allow `TEXTURE_BINDING`.
+    pub fn texture(texture: wgpu::Texture) -> Result<Self, VideoFrameError> {
+        let format = texture.format();
+        let supported = matches!(
+            format,
+            wgpu::TextureFormat::Rgba8Unorm
+                | wgpu::TextureFormat::Rgba8UnormSrgb
+                | wgpu::TextureFormat::Bgra8Unorm
+                | wgpu::TextureFormat::Bgra8UnormSrgb
+        ) && texture
+            .usage()
+            .contains(wgpu::TextureUsages::TEXTURE_BINDING);
+        if !supported {
+            return Err(VideoFrameError::UnsupportedTexture(format));
+        }
+        let (width, height) = (texture.width(), texture.height());
+        ensure_not_empty(width, height)?;
+        Ok(Self::with_pixels(
+            width,
+            height,
+            VideoPixels::Texture(texture),
+        ))
+    }
+
+    /// Sets the YUV matrix and range used to convert YUV frames.
+    pub fn with_color(mut self, color_space: YuvColorSpace, range: YuvRange) -> Self {
+        self.color_space = color_space;
+        self.range = range;
+        self
+    }
+
+    /// Sets the presentation timestamp of the frame.
+    ///
+    /// Timestamps are relative to an arbitrary stream origin; only the
+    /// differences between frames matter.
+    pub fn with_timestamp(mut self, timestamp: Duration) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Returns the pixel storage.
+    pub fn pixels(&self) -> &VideoPixels {
+        &self.pixels
+    }
+
+    /// Returns the frame width in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the frame height in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the YUV matrix family.
+    pub fn color_space(&self) -> YuvColorSpace {
+        self.color_space
+    }
+
+    /// Returns the YUV value range.
+    pub fn range(&self) -> YuvRange {
+        self.range
+    }
+
+    /// Returns the presentation timestamp, if any.
+    pub fn timestamp(&self) -> Option<Duration> {
+        self.timestamp
+    }
+
+    fn packed(width: u32, height: u32, pixels: VideoPixels) -> Result<Self, VideoFrameError> {
+        ensure_not_empty(width, height)?;
+        let (VideoPixels::Rgba(plane) | VideoPixels::Bgra(plane)) = &pixels else {
+            unreachable!("packed frames are RGBA or BGRA");
+        };
+        plane.validate("packed", width * 4, height)?;
+        Ok(Self::with_pixels(width, height, pixels))
+    }
+
+    fn with_pixels(width: u32, height: u32, pixels: VideoPixels) -> Self {
+        Self {
+            pixels,
+            width,
+            height,
+            color_space: YuvColorSpace::default(),
+            range: YuvRange::default(),
+            timestamp: None,
+        }
+    }
+}
+
+fn ensure_not_empty(width: u32, height: u32) -> Result<(), VideoFrameError> {
+    if width == 0 || height == 0 {
+        return Err(VideoFrameError::EmptyFrame { width, height });
+    }
+    Ok(())
+}
+
+pub(crate) fn chroma_size(width: u32, height: u32) -> (u32, u32) {
+    (width.div_ceil(2), height.div_ceil(2))
+}
+
+/// How a video frame is scaled into its layout bounds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum VideoContentScale {
+    /// Scale uniformly to fit inside the bounds, letterboxing the rest.
+    #[default]
+    Fit,
+    /// Scale uniformly to cover the bounds, cropping the overflow.
+    Fill,
+    /// Stretch to the bounds, ignoring the aspect ratio.
+    Stretch,
+}
+
+/// Destination rect and source UV rect for a scaled frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ContentPlacement {
+    /// `[x, y, width, height]` in pixels relative to the node origin.
+    pub rect: [f32; 4],
+    /// `[u, v, width, height]` in normalized texture coordinates.
+    pub uv: [f32; 4],
+}
+
+/// Computes where a `frame_width` x `frame_height` frame is drawn inside
+/// `bounds` for the given scale mode.
+pub(crate) fn place_content(
+    scale: VideoContentScale,
+    frame_width: u32,
+    frame_height: u32,
+    bounds: PxSize,
+) -> ContentPlacement {
+    let bounds_width = bounds.width.to_f32();
+    let bounds_height = bounds.height.to_f32();
+    let full = ContentPlacement {
+        rect: [0.0, 0.0, bounds_width, bounds_height],
+        uv: [0.0, 0.0, 1.0, 1.0],
+    };
+    if frame_width == 0 || frame_height == 0 || bounds_width <= 0.0 || bounds_height <= 0.0 {
+        return full;
+    }
+    let width_ratio = bounds_width / frame_width as f32;
+    let height_ratio = bounds_height / frame_height as f32;
+    match scale {
+        VideoContentScale::Stretch => full,
+        VideoContentScale::Fit => {
+            let ratio = width_ratio.min(height_ratio);
+            let width = frame_width as f32 * ratio;
+            let height = frame_height as f32 * ratio;
+            ContentPlacement {
+                rect: [
+                    (bounds_width - width) / 2.0,
+                    (bounds_height - height) / 2.0,
+                    width,
+                    height,
+                ],
+                uv: full.uv,
+            }
+        }
+        VideoContentScale::Fill => {
+            let ratio = width_ratio.max(height_ratio);
+            let visible_u = bounds_width / (frame_width as f32 * ratio);
+            let visible_v = bounds_height / (frame_height as f32 * ratio);
+            ContentPlacement {
+                rect: full.rect,
+                uv: [
+                    (1.0 - visible_u) / 2.0,
+                    (1.0 - visible_v) / 2.0,
+                    visible_u,
+                    visible_v,
+                ],
+            }
+        }
+    }
+}
+
+/// Returns the YUV to RGB conversion as three rows of `[y, u, v, offset]`.
+///
+/// Multiplying a row with raw normalized `[y, u, v, 1]` samples gives the
+/// matching gamma-encoded RGB channel.
+pub(crate) fn yuv_to_rgb_rows(color_space: YuvColorSpace, range: YuvRange) -> [[f32; 4]; 3] {
+    let (kr, kb) = match color_space {
+        YuvColorSpace::Bt601 => (0.299_f32, 0.114_f32),
+        YuvColorSpace::Bt709 => (0.2126_f32, 0.0722_f32),
+    };
+    let kg = 1.0 - kr - kb;
+    let (y_scale, y_offset, c_scale) = match range {
+        YuvRange::Limited => (255.0 / 219.0, 16.0 / 255.0, 255.0 / 224.0),
+        YuvRange::Full => (1.0, 0.0, 1.0),
+    };
+    let c_offset = 128.0 / 255.0;
+
+    let r_v = 2.0 * (1.0 - kr) * c_scale;
+    let b_u = 2.0 * (1.0 - kb) * c_scale;
+    let g_u = -2.0 * kb * (1.0 - kb) / kg * c_scale;
+    let g_v = -2.0 * kr * (1.0 - kr) / kg * c_scale;
+    let y_bias = -y_offset * y_scale;
+
+    [
+        [y_scale, 0.0, r_v, y_bias - r_v * c_offset],
+        [y_scale, g_u, g_v, y_bias - (g_u + g_v) * c_offset],
+        [y_scale, b_u, 0.0, y_bias - b_u * c_offset],
+    ]
+}
+
+/// Composite command that shows the current frame of a video surface.
+#[derive(Debug, Clone)]
+pub struct VideoCommand {
+    /// Frame to display.
+    pub frame: Arc<VideoFrame>,
+    /// Source the frame came from; surfaces showing one source share its
+    /// converted texture.
+    pub source_id: u64,
+    /// How the frame is scaled into the node bounds.
+    pub content_scale: VideoContentScale,
+}
+
+impl CompositeCommand for VideoCommand {}
+
+/// Draw command that converts a frame into its external texture.
+#[derive(Debug, Clone)]
+pub struct VideoConvertCommand {
+    /// Frame to convert.
+    pub frame: Arc<VideoFrame>,
+    /// Registry id of the texture written by the conversion.
+    pub target_id: u32,
+}
+
+impl DrawCommand for VideoConvertCommand {
+    fn apply_opacity(&mut self, _opacity: f32) {}
+}
+
+/// Draw command that samples a converted frame into the scene.
+#[derive(Debug, Clone)]
+pub struct VideoBlitCommand {
+    pub(crate) placement: ContentPlacement,
+    /// Opacity multiplier applied to the frame.
+    pub opacity: f32,
+}
+
+impl DrawCommand for VideoBlitCommand {
+    fn apply_opacity(&mut self, opacity: f32) {
+        self.opacity = (self.opacity * opacity).clamp(0.0, 1.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tessera_ui::Px;
+
+    use super::*;
+
+    fn convert(rows: [[f32; 4]; 3], yuv: [u8; 3]) -> [f32; 3] {
+        let sample = yuv.map(|value| value as f32 / 255.0);
+        rows.map(|row| row[0] * sample[0] + row[1] * sample[1] + row[2] * sample[2] + row[3])
+    }
+
+    fn assert_close(actual: [f32; 3], expected: [f32; 3]) {
+        for (actual, expected) in actual.into_iter().zip(expected) {
+            assert!((actual - expected).abs() < 0.01, "{actual} != {expected}");
+        }
+    }
+
+    #[test]
+    fn limited_range_maps_reference_black_and_white() {
+        for color_space in [YuvColorSpace::Bt601, YuvColorSpace::Bt709] {
+            let rows = yuv_to_rgb_rows(color_space, YuvRange::Limited);
+            assert_close(convert(rows, [16, 128, 128]), [0.0, 0.0, 0.0]);
+            assert_close(convert(rows, [235, 128, 128]), [1.0, 1.0, 1.0]);
+        }
+    }
+
+    #[test]
+    fn full_range_bt601_maps_pure_red() {
+        let rows = yuv_to_rgb_rows(YuvColorSpace::Bt601, YuvRange::Full);
+        assert_close(convert(rows, [76, 85, 255]), [1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn frame_constructors_validate_planes() {
+        let plane = |len: usize, stride| VideoPlane::new(vec![0; len], stride);
+        assert!(VideoFrame::rgba(2, 2, plane(16, 8)).is_ok());
+        assert_eq!(
+            VideoFrame::rgba(2, 2, plane(12, 8)).unwrap_err(),
+            VideoFrameError::PlaneTooSmall {
+                plane: "packed",
+                expected: 16,
+                actual: 12,
+            }
+        );
+        assert!(matches!(
+            VideoFrame::bgra(2, 2, plane(16, 4)),
+            Err(VideoFrameError::StrideTooSmall { .. })
+        ));
+        assert!(VideoFrame::i420(3, 3, plane(9, 3), plane(4, 2), plane(4, 2)).is_ok());
+        assert!(VideoFrame::nv12(3, 3, plane(9, 3), plane(8, 4)).is_ok());
+        assert!(VideoFrame::nv12(3, 3, plane(9, 3), plane(7, 4)).is_err());
+        assert!(matches!(
+            VideoFrame::nv12(0, 3, plane(9, 3), plane(8, 4)),
+            Err(VideoFrameError::EmptyFrame { .. })
+        ));
+    }
+
+    #[test]
+    fn content_scale_places_frame() {
+        let bounds = PxSize::new(Px::new(200), Px::new(100));
+
+        let fit = place_content(VideoContentScale::Fit, 100, 100, bounds);
+        assert_eq!(fit.rect, [50.0, 0.0, 100.0, 100.0]);
+        assert_eq!(fit.uv, [0.0, 0.0, 1.0, 1.0]);
+
+        let fill = place_content(VideoContentScale::Fill, 100, 100, bounds);
+        assert_eq!(fill.rect, [0.0, 0.0, 200.0, 100.0]);
+        assert_eq!(fill.uv, [0.0, 0.25, 1.0, 0.5]);
+
+        let stretch = place_content(VideoContentScale::Stretch, 100, 100, bounds);
+        assert_eq!(stretch.rect, [0.0, 0.0, 200.0, 100.0]);
+        assert_eq!(stretch.uv, [0.0, 0.0, 1.0, 1.0]);
+    }
+}
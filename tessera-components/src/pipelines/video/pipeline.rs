@@ -0,0 +1,758 @@
+use std::{any::TypeId, collections::HashMap, sync::Arc};
+
+use encase::{ShaderType, UniformBuffer};
+use glam::Vec4;
+use smallvec::SmallVec;
+use tessera_ui::{
+    Command, CompositeBatchItem, CompositeContext, CompositeOutput, CompositePipeline, Px,
+    PxPosition, PxSize, RenderGraphOp, RenderResourceId, RenderTextureDesc,
+    composite::CompositeReplacement,
+    renderer::{
+        ExternalTextureHandle,
+        drawer::pipeline::{DrawContext, DrawablePipeline, FrameContext},
+    },
+    wgpu::{self, util::DeviceExt},
+};
+
+use super::command::{
+    VideoBlitCommand, VideoCommand, VideoConvertCommand, VideoFrame, VideoPixels, VideoPlane,
+    chroma_size, place_content, yuv_to_rgb_rows,
+};
+
+/// Number of frames a source keeps its textures without being drawn.
+const TARGET_RETENTION_FRAMES: u64 = 120;
+
+const MODE_PACKED: u32 = 0;
+const MODE_I420: u32 = 1;
+const MODE_NV12: u32 = 2;
+const MODE_CONVERTED: u32 = 3;
+
+/// Clip-space rect covering the whole render target.
+const FULL_TARGET: [f32; 4] = [0.0, 0.0, 1.0, 1.0];
+
+#[derive(ShaderType)]
+struct VideoUniforms {
+    rect: Vec4,
+    uv_rect: Vec4,
+    yuv_r: Vec4,
+    yuv_g: Vec4,
+    yuv_b: Vec4,
+    mode: u32,
+    linearize: u32,
+    opacity: f32,
+}
+
+/// Converted texture retained for one video source.
+struct VideoTarget {
+    handle: ExternalTextureHandle,
+    desc: RenderTextureDesc,
+    sample_count: u32,
+    converted: Option<Arc<VideoFrame>>,
+    last_used_frame: u64,
+}
+
+/// Composite pipeline that keeps the current frame of every source in an
+/// external texture.
+///
+/// A frame is converted into its source's texture once, when it replaces
+/// the previous one; surfaces then sample that texture with their own
+/// scaling and opacity.
+pub struct VideoPipeline {
+    targets: HashMap<u64, VideoTarget>,
+}
+
+impl VideoPipeline {
+    /// Creates a video pipeline without targets.
+    pub fn new() -> Self {
+        Self {
+            targets: HashMap::new(),
+        }
+    }
+}
+
+impl CompositePipeline<VideoCommand> for VideoPipeline {
+    fn compile(
+        &mut self,
+        context: &CompositeContext<'_>,
+        items: &[CompositeBatchItem<'_, VideoCommand>],
+    ) -> CompositeOutput {
+        let device = context.resources.device;
+        let registry = &context.external_textures;
+        let sample_count = context.sample_count;
+        let frame_index = context.frame_index;
+        let mut output = CompositeOutput::empty();
+
+        for item in items {
+            if item.size.width.0 <= 0 || item.size.height.0 <= 0 {
+                continue;
+            }
+            let command = item.command;
+            let frame = &command.frame;
+            let desc = RenderTextureDesc {
+                size: PxSize::new(Px(frame.width() as i32), Px(frame.height() as i32)),
+                format: context.surface_format,
+            };
+            let target = self
+                .targets
+                .entry(command.source_id)
+                .or_insert_with(|| VideoTarget {
+                    handle: registry.allocate(device, desc.clone(), sample_count),
+                    desc: desc.clone(),
+                    sample_count,
+                    converted: None,
+                    last_used_frame: frame_index,
+                });
+            if target.desc != desc || target.sample_count != sample_count {
+                target
+                    .handle
+                    .ensure(registry, device, desc.clone(), sample_count);
+                target.desc = desc.clone();
+                target.sample_count = sample_count;
+                target.converted = None;
+            }
+            target.last_used_frame = frame_index;
+
+            let stale = target
+                .converted
+                .as_ref()
+                .is_none_or(|converted| !Arc::ptr_eq(converted, frame));
+            if stale {
+                target.converted = Some(frame.clone());
+                let write = output.add_external_texture(target.handle.desc(true));
+                output.prelude_ops.push(RenderGraphOp {
+                    command: Command::Draw(Box::new(VideoConvertCommand {
+                        frame: frame.clone(),
+                        target_id: target.handle.id(),
+                    })),
+                    type_id: TypeId::of::<VideoConvertCommand>(),
+                    read: None,
+                    write: Some(write),
+                    deps: SmallVec::new(),
+                    size: desc.size,
+                    position: PxPosition::ZERO,
+                    opacity: 1.0,
+                    sequence_index: 0,
+                });
+            }
+
+            let read = output.add_external_texture(target.handle.desc(false));
+            let blit = VideoBlitCommand {
+                placement: place_content(
+                    command.content_scale,
+                    frame.width(),
+                    frame.height(),
+                    item.size,
+                ),
+                opacity: item.opacity,
+            };
+            output.replacements.push(CompositeReplacement {
+                target_op: item.op_index,
+                ops: vec![RenderGraphOp {
+                    command: Command::Draw(Box::new(blit)),
+                    type_id: TypeId::of::<VideoBlitCommand>(),
+                    read: Some(read),
+                    write: Some(RenderResourceId::SceneColor),
+                    deps: SmallVec::new(),
+                    size: item.size,
+                    position: item.position,
+                    opacity: item.opacity,
+                    sequence_index: 0,
+                }],
+            });
+        }
+
+        self.targets.retain(|_, target| {
+            frame_index
+                <= target
+                    .last_used_frame
+                    .saturating_add(TARGET_RETENTION_FRAMES)
+        });
+
+        output
+    }
+}
+
+/// Render pipeline, layout and sampler shared by conversion and blits.
+struct VideoShader {
+    pipeline: wgpu::RenderPipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+}
+
+impl VideoShader {
+    fn new(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        pipeline_cache: Option<&wgpu::PipelineCache>,
+        sample_count: u32,
+        blend: Option<wgpu::BlendState>,
+    ) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Video Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("video.wgsl").into()),
+        });
+
+        let plane_entry = |binding| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Texture {
+                multisampled: false,
+                view_dimension: wgpu::TextureViewDimension::D2,
+                sample_type: wgpu::TextureSampleType::Float { filterable: true },
+            },
+            count: None,
+        };
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            entries: &[
+                plane_entry(0),
+                plane_entry(1),
+                plane_entry(2),
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+            label: Some("video_bind_group_layout"),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Video Pipeline Layout"),
+            bind_group_layouts: &[Some(&bind_group_layout)],
+            immediate_size: 0,
+        });
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Video Render Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: config.format,
+                    blend,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState {
+                count: sample_count,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            multiview_mask: None,
+            cache: pipeline_cache,
+        });
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::MipmapFilterMode::Nearest,
+            ..Default::default()
+        });
+
+        Self {
+            pipeline,
+            bind_group_layout,
+            sampler,
+        }
+    }
+
+    fn bind_group(
+        &self,
+        device: &wgpu::Device,
+        planes: [&wgpu::TextureView; 3],
+        uniform_buffer: &wgpu::Buffer,
+    ) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            layout: &self.bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(planes[0]),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(planes[1]),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::TextureView(planes[2]),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: wgpu::BindingResource::Sampler(&self.sampler),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: uniform_buffer.as_entire_binding(),
+                },
+            ],
+            label: Some("video_bind_group"),
+        })
+    }
+}
+
+fn uniform_bytes(uniforms: &VideoUniforms) -> Vec<u8> {
+    let mut buffer = UniformBuffer::new(Vec::new());
+    buffer
+        .write(uniforms)
+        .expect("video uniform serialization failed");
+    buffer.into_inner()
+}
+
+/// Texture set matching the shape of an uploaded CPU frame.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PlaneLayout {
+    Rgba,
+    Bgra,
+    I420,
+    Nv12,
+}
+
+enum FrameTextures {
+    /// Textures owned by the pipeline and refreshed with `write_texture`.
+    Uploaded {
+        layout: PlaneLayout,
+        width: u32,
+        height: u32,
+        planes: Vec<wgpu::Texture>,
+    },
+    /// A producer texture bound directly.
+    Producer(wgpu::Texture),
+}
+
+struct ConvertResources {
+    textures: FrameTextures,
+    bind_group: wgpu::BindGroup,
+    uniform_buffer: wgpu::Buffer,
+    last_used_frame: u64,
+}
+
+/// Pipeline that converts video frames into their external textures.
+///
+/// CPU planes are uploaded into textures kept per target and reused while
+/// the frame shape is unchanged; producer textures are sampled directly.
+pub struct VideoConvertPipeline {
+    shader: VideoShader,
+    placeholder_view: wgpu::TextureView,
+    srgb_target: bool,
+    targets: HashMap<u32, ConvertResources>,
+    frame_index: u64,
+}
+
+impl VideoConvertPipeline {
+    /// Create a new VideoConvertPipeline.
+    pub fn new(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+        pipeline_cache: Option<&wgpu::PipelineCache>,
+        sample_count: u32,
+    ) -> Self {
+        // Conversion replaces the target contents, premultiplying alpha.
+        let shader = VideoShader::new(device, config, pipeline_cache, sample_count, None);
+
+        // Bound to plane slots a frame layout does not use.
+        let placeholder = Self::create_plane_texture(device, wgpu::TextureFormat::R8Unorm, 1, 1);
+        queue.write_texture(
+            placeholder.as_image_copy(),
+            &[0],
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(1),
+                rows_per_image: Some(1),
+            },
+            placeholder.size(),
+        );
+
+        Self {
+            shader,
+            placeholder_view: placeholder.create_view(&wgpu::TextureViewDescriptor::default()),
+            srgb_target: config.format.is_srgb(),
+            targets: HashMap::new(),
+            frame_index: 0,
+        }
+    }
+
+    fn create_plane_texture(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        width: u32,
+        height: u32,
+    ) -> wgpu::Texture {
+        device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("video_plane_texture"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        })
+    }
+
+    fn plane_layout(frame: &VideoFrame) -> Option<PlaneLayout> {
+        match frame.pixels() {
+            VideoPixels::Rgba(_) => Some(PlaneLayout::Rgba),
+            VideoPixels::Bgra(_) => Some(PlaneLayout::Bgra),
+            VideoPixels::I420 { .. } => Some(PlaneLayout::I420),
+            VideoPixels::Nv12 { .. } => Some(PlaneLayout::Nv12),
+            VideoPixels::Texture(_) => None,
+        }
+    }
+
+    fn create_planes(
+        device: &wgpu::Device,
+        layout: PlaneLayout,
+        width: u32,
+        height: u32,
+    ) -> Vec<wgpu::Texture> {
+        let (chroma_width, chroma_height) = chroma_size(width, height);
+        let plane =
+            |format, width, height| Self::create_plane_texture(device, format, width, height);
+        match layout {
+            PlaneLayout::Rgba => vec![plane(wgpu::TextureFormat::Rgba8Unorm, width, height)],
+            PlaneLayout::Bgra => vec![plane(wgpu::TextureFormat::Bgra8Unorm, width, height)],
+            PlaneLayout::I420 => vec![
+                plane(wgpu::TextureFormat::R8Unorm, width, height),
+                plane(wgpu::TextureFormat::R8Unorm, chroma_width, chroma_height),
+                plane(wgpu::TextureFormat::R8Unorm, chroma_width, chroma_height),
+            ],
+            PlaneLayout::Nv12 => vec![
+                plane(wgpu::TextureFormat::R8Unorm, width, height),
+                plane(wgpu::TextureFormat::Rg8Unorm, chroma_width, chroma_height),
+            ],
+        }
+    }
+
+    fn upload_plane(queue: &wgpu::Queue, texture: &wgpu::Texture, plane: &VideoPlane) {
+        queue.write_texture(
+            texture.as_image_copy(),
+            &plane.data,
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(plane.stride),
+                rows_per_image: Some(texture.height()),
+            },
+            texture.size(),
+        );
+    }
+
+    fn upload_frame(queue: &wgpu::Queue, planes: &[wgpu::Texture], frame: &VideoFrame) {
+        match frame.pixels() {
+            VideoPixels::Rgba(plane) | VideoPixels::Bgra(plane) => {
+                Self::upload_plane(queue, &planes[0], plane);
+            }
+            VideoPixels::I420 { y, u, v } => {
+                Self::upload_plane(queue, &planes[0], y);
+                Self::upload_plane(queue, &planes[1], u);
+                Self::upload_plane(queue, &planes[2], v);
+            }
+            VideoPixels::Nv12 { y, uv } => {
+                Self::upload_plane(queue, &planes[0], y);
+                Self::upload_plane(queue, &planes[1], uv);
+            }
+            VideoPixels::Texture(_) => {}
+        }
+    }
+
+    fn create_bind_group(
+        &self,
+        device: &wgpu::Device,
+        textures: &FrameTextures,
+        uniform_buffer: &wgpu::Buffer,
+    ) -> wgpu::BindGroup {
+        let views: Vec<wgpu::TextureView> = match textures {
+            FrameTextures::Uploaded { planes, .. } => planes
+                .iter()
+                .map(|plane| plane.create_view(&wgpu::TextureViewDescriptor::default()))
+                .collect(),
+            FrameTextures::Producer(texture) => {
+                vec![texture.create_view(&wgpu::TextureViewDescriptor::default())]
+            }
+        };
+        let view = |index: usize| views.get(index).unwrap_or(&self.placeholder_view);
+        self.shader
+            .bind_group(device, [view(0), view(1), view(2)], uniform_buffer)
+    }
+
+    /// Returns whether `textures` can display `frame` without reallocating.
+    fn textures_match(textures: &FrameTextures, frame: &VideoFrame) -> bool {
+        match (textures, frame.pixels()) {
+            (FrameTextures::Producer(current), VideoPixels::Texture(next)) => current == next,
+            (
+                FrameTextures::Uploaded {
+                    layout,
+                    width,
+                    height,
+                    ..
+                },
+                _,
+            ) => {
+                Self::plane_layout(frame) == Some(*layout)
+                    && *width == frame.width()
+                    && *height == frame.height()
+            }
+            _ => false,
+        }
+    }
+
+    fn create_textures(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        frame: &VideoFrame,
+    ) -> FrameTextures {
+        match (frame.pixels(), Self::plane_layout(frame)) {
+            (VideoPixels::Texture(texture), _) => FrameTextures::Producer(texture.clone()),
+            (_, Some(layout)) => {
+                let planes = Self::create_planes(device, layout, frame.width(), frame.height());
+                Self::upload_frame(queue, &planes, frame);
+                FrameTextures::Uploaded {
+                    layout,
+                    width: frame.width(),
+                    height: frame.height(),
+                    planes,
+                }
+            }
+            (_, None) => unreachable!("CPU frames always have a plane layout"),
+        }
+    }
+
+    /// Return the resources converting into `target_id`, uploading the
+    /// pixels of `frame` into the existing textures when the frame shape is
+    /// unchanged.
+    fn prepare_target(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        target_id: u32,
+        frame: &VideoFrame,
+    ) -> &ConvertResources {
+        let frame_index = self.frame_index;
+        if let Some(resources) = self.targets.get_mut(&target_id)
+            && Self::textures_match(&resources.textures, frame)
+        {
+            if let FrameTextures::Uploaded { planes, .. } = &resources.textures {
+                Self::upload_frame(queue, planes, frame);
+            }
+            resources.last_used_frame = frame_index;
+            return &self.targets[&target_id];
+        }
+
+        let textures = Self::create_textures(device, queue, frame);
+        let uniform_buffer = match self.targets.remove(&target_id) {
+            Some(previous) => previous.uniform_buffer,
+            None => device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Video Uniform Buffer"),
+                size: VideoUniforms::min_size().get(),
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }),
+        };
+        let bind_group = self.create_bind_group(device, &textures, &uniform_buffer);
+        self.targets.entry(target_id).or_insert(ConvertResources {
+            textures,
+            bind_group,
+            uniform_buffer,
+            last_used_frame: frame_index,
+        })
+    }
+
+    fn convert_uniforms(&self, frame: &VideoFrame) -> VideoUniforms {
+        let (mode, linearize) = match frame.pixels() {
+            VideoPixels::Rgba(_) | VideoPixels::Bgra(_) => (MODE_PACKED, self.srgb_target),
+            VideoPixels::I420 { .. } => (MODE_I420, self.srgb_target),
+            VideoPixels::Nv12 { .. } => (MODE_NV12, self.srgb_target),
+            VideoPixels::Texture(texture) => {
+                (MODE_PACKED, self.srgb_target && !texture.format().is_srgb())
+            }
+        };
+        let [yuv_r, yuv_g, yuv_b] = yuv_to_rgb_rows(frame.color_space(), frame.range());
+
+        VideoUniforms {
+            rect: FULL_TARGET.into(),
+            uv_rect: FULL_TARGET.into(),
+            yuv_r: yuv_r.into(),
+            yuv_g: yuv_g.into(),
+            yuv_b: yuv_b.into(),
+            mode,
+            linearize: u32::from(linearize),
+            opacity: 1.0,
+        }
+    }
+}
+
+impl DrawablePipeline<VideoConvertCommand> for VideoConvertPipeline {
+    fn begin_frame(&mut self, _context: &FrameContext<'_>) {
+        self.frame_index = self.frame_index.wrapping_add(1);
+    }
+
+    fn end_frame(&mut self, _context: &FrameContext<'_>) {
+        let frame_index = self.frame_index;
+        self.targets.retain(|_, target| {
+            frame_index.wrapping_sub(target.last_used_frame) <= TARGET_RETENTION_FRAMES
+        });
+    }
+
+    fn draw(&mut self, context: &mut DrawContext<VideoConvertCommand>) {
+        context.render_pass.set_pipeline(&self.shader.pipeline);
+
+        for (command, _size, _start_pos) in context.commands.iter() {
+            let uniforms = self.convert_uniforms(&command.frame);
+            let resources = self.prepare_target(
+                context.device,
+                context.queue,
+                command.target_id,
+                &command.frame,
+            );
+            context
+                .queue
+                .write_buffer(&resources.uniform_buffer, 0, &uniform_bytes(&uniforms));
+
+            context
+                .render_pass
+                .set_bind_group(0, &resources.bind_group, &[]);
+            context.render_pass.draw(0..6, 0..1);
+        }
+    }
+}
+
+/// Pipeline that draws converted video frames into the scene.
+pub struct VideoBlitPipeline {
+    shader: VideoShader,
+}
+
+impl VideoBlitPipeline {
+    /// Creates the video blit pipeline.
+    pub fn new(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        pipeline_cache: Option<&wgpu::PipelineCache>,
+        sample_count: u32,
+    ) -> Self {
+        let blend = Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING);
+        Self {
+            shader: VideoShader::new(device, config, pipeline_cache, sample_count, blend),
+        }
+    }
+
+    fn blit_uniforms(
+        command: &VideoBlitCommand,
+        start_pos: PxPosition,
+        target_size: PxSize,
+    ) -> VideoUniforms {
+        let [x, y, width, height] = command.placement.rect;
+        let target_width = target_size.width.to_f32();
+        let target_height = target_size.height.to_f32();
+        let left = start_pos.x.to_f32() + x;
+        let top = start_pos.y.to_f32() + y;
+        let rect = [
+            (left / target_width) * 2.0 - 1.0 + width / target_width,
+            (top / target_height) * -2.0 + 1.0 - height / target_height,
+            width / target_width,
+            height / target_height,
+        ];
+
+        VideoUniforms {
+            rect: rect.into(),
+            uv_rect: command.placement.uv.into(),
+            yuv_r: Vec4::ZERO,
+            yuv_g: Vec4::ZERO,
+            yuv_b: Vec4::ZERO,
+            mode: MODE_CONVERTED,
+            linearize: 0,
+            opacity: command.opacity,
+        }
+    }
+}
+
+impl DrawablePipeline<VideoBlitCommand> for VideoBlitPipeline {
+    fn draw(&mut self, context: &mut DrawContext<VideoBlitCommand>) {
+        context.render_pass.set_pipeline(&self.shader.pipeline);
+
+        for (command, _size, start_pos) in context.commands.iter() {
+            if command.opacity <= 0.0 {
+                continue;
+            }
+            let uniforms = Self::blit_uniforms(command, *start_pos, context.target_size);
+            let uniform_buffer =
+                context
+                    .device
+                    .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                        label: Some("video_blit_uniform_buffer"),
+                        contents: &uniform_bytes(&uniforms),
+                        usage: wgpu::BufferUsages::UNIFORM,
+                    });
+            // The converted frame is the op's read resource; the unused
+            // plane slots reuse the same view.
+            let frame_view = context.scene_texture_view;
+            let bind_group = self.shader.bind_group(
+                context.device,
+                [frame_view, frame_view, frame_view],
+                &uniform_buffer,
+            );
+
+            context.render_pass.set_bind_group(0, &bind_group, &[]);
+            context.render_pass.draw(0..6, 0..1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipelines::video::command::VideoContentScale;
+
+    fn blit(placement_bounds: PxSize, opacity: f32) -> VideoBlitCommand {
+        VideoBlitCommand {
+            placement: place_content(VideoContentScale::Fit, 4, 2, placement_bounds),
+            opacity,
+        }
+    }
+
+    #[test]
+    fn blit_covers_target_for_matching_aspect() {
+        let size = PxSize::new(Px(200), Px(100));
+        let uniforms = VideoBlitPipeline::blit_uniforms(&blit(size, 1.0), PxPosition::ZERO, size);
+        assert_eq!(uniforms.rect, Vec4::new(0.0, 0.0, 1.0, 1.0));
+        assert_eq!(uniforms.uv_rect, Vec4::new(0.0, 0.0, 1.0, 1.0));
+        assert_eq!(uniforms.mode, MODE_CONVERTED);
+    }
+
+    #[test]
+    fn blit_letterboxes_fit_frames() {
+        let target = PxSize::new(Px(200), Px(200));
+        let uniforms =
+            VideoBlitPipeline::blit_uniforms(&blit(target, 0.5), PxPosition::ZERO, target);
+        assert_eq!(uniforms.rect, Vec4::new(0.0, 0.0, 1.0, 0.5));
+        assert_eq!(uniforms.opacity, 0.5);
+    }
+}
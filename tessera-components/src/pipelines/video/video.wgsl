@@ -0,0 +1,91 @@
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) tex_coords: vec2<f32>,
+};
+
+struct Uniforms {
+    rect: vec4<f32>,
+    uv_rect: vec4<f32>,
+    // Rows of the YUV to RGB matrix, offset stored in `w`.
+    yuv_r: vec4<f32>,
+    yuv_g: vec4<f32>,
+    yuv_b: vec4<f32>,
+    // 0: packed RGB, 1: I420, 2: NV12, 3: premultiplied converted frame.
+    mode: u32,
+    // Convert gamma-encoded samples to linear for sRGB targets.
+    linearize: u32,
+    opacity: f32,
+};
+@group(0) @binding(4)
+var<uniform> uniforms: Uniforms;
+
+@vertex
+fn vs_main(@builtin(vertex_index) in_vertex_index: u32) -> VertexOutput {
+    let vertices = array<vec2<f32>, 6>(
+        vec2<f32>(-1.0, -1.0),
+        vec2<f32>(1.0, -1.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(-1.0, -1.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(-1.0, 1.0),
+    );
+
+    let tex_coords = array<vec2<f32>, 6>(
+        vec2<f32>(0.0, 1.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(1.0, 0.0),
+        vec2<f32>(0.0, 1.0),
+        vec2<f32>(1.0, 0.0),
+        vec2<f32>(0.0, 0.0),
+    );
+
+    var out: VertexOutput;
+    let pos = vertices[in_vertex_index] * uniforms.rect.zw + uniforms.rect.xy;
+    out.clip_position = vec4<f32>(pos, 0.0, 1.0);
+    out.tex_coords = uniforms.uv_rect.xy + tex_coords[in_vertex_index] * uniforms.uv_rect.zw;
+    return out;
+}
+
+@group(0) @binding(0)
+var t_plane0: texture_2d<f32>;
+@group(0) @binding(1)
+var t_plane1: texture_2d<f32>;
+@group(0) @binding(2)
+var t_plane2: texture_2d<f32>;
+@group(0) @binding(3)
+var s_video: sampler;
+
+fn srgb_to_linear(color: vec3<f32>) -> vec3<f32> {
+    let low = color / 12.92;
+    let high = pow((color + 0.055) / 1.055, vec3<f32>(2.4));
+    return select(high, low, color <= vec3<f32>(0.04045));
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let plane0 = textureSample(t_plane0, s_video, in.tex_coords);
+    let plane1 = textureSample(t_plane1, s_video, in.tex_coords);
+    let plane2 = textureSample(t_plane2, s_video, in.tex_coords);
+    if uniforms.mode == 3u {
+        return plane0 * uniforms.opacity;
+    }
+
+    var rgb = plane0.rgb;
+    var alpha = plane0.a;
+    if uniforms.mode != 0u {
+        var yuv = vec4<f32>(plane0.r, plane1.r, plane2.r, 1.0);
+        if uniforms.mode == 2u {
+            yuv = vec4<f32>(plane0.r, plane1.r, plane1.g, 1.0);
+        }
+        rgb = clamp(
+            vec3<f32>(dot(uniforms.yuv_r, yuv), dot(uniforms.yuv_g, yuv), dot(uniforms.yuv_b, yuv)),
+            vec3<f32>(0.0),
+            vec3<f32>(1.0),
+        );
+        alpha = 1.0;
+    }
+    if uniforms.linearize == 1u {
+        rgb = srgb_to_linear(rgb);
+    }
+    return vec4<f32>(rgb * alpha, alpha) * uniforms.opacity;
+}
@@ -0,0 +1,4 @@
+//! Viewport pipelines for passes recorded by the application.
+
+pub mod command;
+pub mod pipeline;
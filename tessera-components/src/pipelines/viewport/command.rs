@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use tessera_ui::{CompositeCommand, DrawCommand, PxSize, wgpu};
+
+/// Callback that records an application's passes into a viewport target.
+pub(crate) type ViewportRenderFn = Arc<dyn Fn(&mut ViewportRenderContext<'_>) + Send + Sync>;
+
+/// GPU state handed to a viewport render callback.
+///
+/// `encoder` is the frame's own encoder; passes recorded into it run before
+/// the frame's passes, which composite the viewport. Both targets keep their
+/// contents between renders, so callbacks clear them with their first pass. The
+/// color target is composited with premultiplied alpha blending.
+pub struct ViewportRenderContext<'a> {
+    /// Device that owns the targets.
+    pub device: &'a wgpu::Device,
+    /// Queue the frame is submitted on, for buffer and texture uploads.
+    pub queue: &'a wgpu::Queue,
+    /// Encoder for the callback's passes.
+    pub encoder: &'a mut wgpu::CommandEncoder,
+    /// Single-sampled color target sized to the viewport.
+    pub color_view: &'a wgpu::TextureView,
+    /// Format of `color_view`, matching the window surface.
+    pub color_format: wgpu::TextureFormat,
+    /// Depth target with the same size as `color_view`.
+    pub depth_view: &'a wgpu::TextureView,
+    /// Format of `depth_view`.
+    pub depth_format: wgpu::TextureFormat,
+    /// Target size in physical pixels.
+    pub size: PxSize,
+}
+
+/// Composite command that renders a viewport and draws it into the scene.
+#[derive(Clone)]
+pub struct ViewportCommand {
+    pub(crate) render: ViewportRenderFn,
+    pub(crate) target_slot: u64,
+    pub(crate) version: u64,
+}
+
+impl CompositeCommand for ViewportCommand {}
+
+/// Draw command that samples a rendered viewport target.
+#[derive(Clone)]
+pub struct ViewportBlitCommand {
+    pub(crate) view: wgpu::TextureView,
+    pub(crate) opacity: f32,
+}
+
+impl DrawCommand for ViewportBlitCommand {
+    fn apply_opacity(&mut self, opacity: f32) {
+        self.opacity = (self.opacity * opacity).clamp(0.0, 1.0);
+    }
+}
@@ -0,0 +1,384 @@
+use std::{any::TypeId, collections::HashMap, sync::Arc};
+
+use encase::{ShaderType, UniformBuffer};
+use glam::Vec4;
+use parking_lot::Mutex;
+use smallvec::SmallVec;
+use tessera_ui::{
+    Command, CompositeBatchItem, CompositeContext, CompositeOutput, CompositePipeline, DrawCommand,
+    PxPosition, PxSize, RenderGraphOp, RenderResourceId,
+    composite::CompositeReplacement,
+    renderer::drawer::pipeline::{DrawContext, DrawablePipeline},
+    wgpu::{self, util::DeviceExt},
+};
+
+use super::command::{ViewportBlitCommand, ViewportCommand, ViewportRenderContext};
+
+/// Depth format of viewport targets.
+pub(crate) const VIEWPORT_DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
+
+/// Targets of viewports that stop recording are released after this many
+/// frames.
+const TARGET_RETENTION_FRAMES: u64 = 120;
+
+struct ViewportTarget {
+    size: PxSize,
+    format: wgpu::TextureFormat,
+    color_view: wgpu::TextureView,
+    depth_view: wgpu::TextureView,
+    /// Set once the frame's encoder actually records the callback, so a
+    /// dropped frame renders the version again.
+    rendered_version: Arc<Mutex<Option<u64>>>,
+    last_used_frame: u64,
+}
+
+impl ViewportTarget {
+    fn new(device: &wgpu::Device, size: PxSize, format: wgpu::TextureFormat) -> Self {
+        let extent = wgpu::Extent3d {
+            width: size.width.positive().max(1),
+            height: size.height.positive().max(1),
+            depth_or_array_layers: 1,
+        };
+        let color = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Viewport Color Target"),
+            size: extent,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::TEXTURE_BINDING
+                | wgpu::TextureUsages::COPY_SRC
+                | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        let depth = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Viewport Depth Target"),
+            size: extent,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: VIEWPORT_DEPTH_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        Self {
+            size,
+            format,
+            color_view: color.create_view(&wgpu::TextureViewDescriptor::default()),
+            depth_view: depth.create_view(&wgpu::TextureViewDescriptor::default()),
+            rendered_version: Arc::new(Mutex::new(None)),
+            last_used_frame: 0,
+        }
+    }
+}
+
+/// Composite pipeline that runs viewport callbacks into retained targets.
+///
+/// A callback only runs when its viewport asked for a new version or its
+/// target was resized; otherwise the previous contents are composited
+/// again.
+pub struct ViewportPipeline {
+    targets: HashMap<u64, ViewportTarget>,
+}
+
+impl ViewportPipeline {
+    /// Creates a viewport pipeline without targets.
+    pub fn new() -> Self {
+        Self {
+            targets: HashMap::new(),
+        }
+    }
+}
+
+impl CompositePipeline<ViewportCommand> for ViewportPipeline {
+    fn compile(
+        &mut self,
+        context: &CompositeContext<'_>,
+        items: &[CompositeBatchItem<'_, ViewportCommand>],
+    ) -> CompositeOutput {
+        let device = context.resources.device;
+        let format = context.surface_format;
+        let frame_index = context.frame_index;
+        let mut output = CompositeOutput::empty();
+
+        for item in items {
+            if item.size.width.0 <= 0 || item.size.height.0 <= 0 {
+                continue;
+            }
+            let command = item.command;
+            let target = self
+                .targets
+                .entry(command.target_slot)
+                .or_insert_with(|| ViewportTarget::new(device, item.size, format));
+            if target.size != item.size || target.format != format {
+                *target = ViewportTarget::new(device, item.size, format);
+            }
+            target.last_used_frame = frame_index;
+
+            if *target.rendered_version.lock() != Some(command.version) {
+                let render = command.render.clone();
+                let version = command.version;
+                let rendered_version = target.rendered_version.clone();
+                let color_view = target.color_view.clone();
+                let color_format = target.format;
+                let depth_view = target.depth_view.clone();
+                let size = target.size;
+                output.encoder_work.push(Box::new(move |frame| {
+                    render(&mut ViewportRenderContext {
+                        device: frame.device,
+                        queue: frame.queue,
+                        encoder: frame.encoder,
+                        color_view: &color_view,
+                        color_format,
+                        depth_view: &depth_view,
+                        depth_format: VIEWPORT_DEPTH_FORMAT,
+                        size,
+                    });
+                    *rendered_version.lock() = Some(version);
+                }));
+            }
+
+            let mut blit = ViewportBlitCommand {
+                view: target.color_view.clone(),
+                opacity: 1.0,
+            };
+            blit.apply_opacity(item.opacity);
+            output.replacements.push(CompositeReplacement {
+                target_op: item.op_index,
+                ops: vec![RenderGraphOp {
+                    command: Command::Draw(Box::new(blit)),
+                    type_id: TypeId::of::<ViewportBlitCommand>(),
+                    read: None,
+                    write: Some(RenderResourceId::SceneColor),
+                    deps: SmallVec::new(),
+                    size: item.size,
+                    position: item.position,
+                    opacity: item.opacity,
+                    sequence_index: 0,
+                }],
+            });
+        }
+
+        self.targets.retain(|_, target| {
+            frame_index
+                <= target
+                    .last_used_frame
+                    .saturating_add(TARGET_RETENTION_FRAMES)
+        });
+
+        output
+    }
+}
+
+#[derive(ShaderType)]
+struct BlitUniforms {
+    rect: Vec4,
+    opacity: f32,
+}
+
+/// Pipeline that draws rendered viewport targets into the scene.
+pub struct ViewportBlitPipeline {
+    pipeline: wgpu::RenderPipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+}
+
+impl ViewportBlitPipeline {
+    /// Creates the viewport blit pipeline.
+    pub fn new(
+        gpu: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        pipeline_cache: Option<&wgpu::PipelineCache>,
+        sample_count: u32,
+    ) -> Self {
+        let shader = gpu.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Viewport Blit Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("viewport.wgsl").into()),
+        });
+
+        // Targets match the viewport size in pixels, so nearest sampling is
+        // an exact copy.
+        let sampler = gpu.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Viewport Blit Sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            mipmap_filter: wgpu::MipmapFilterMode::Nearest,
+            ..Default::default()
+        });
+
+        let bind_group_layout = gpu.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        multisampled: false,
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+            label: Some("viewport_blit_bind_group_layout"),
+        });
+
+        let pipeline_layout = gpu.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Viewport Blit Pipeline Layout"),
+            bind_group_layouts: &[Some(&bind_group_layout)],
+            immediate_size: 0,
+        });
+
+        let pipeline = gpu.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Viewport Blit Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: config.format,
+                    blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState {
+                count: sample_count,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            multiview_mask: None,
+            cache: pipeline_cache,
+        });
+
+        Self {
+            pipeline,
+            bind_group_layout,
+            sampler,
+        }
+    }
+
+    fn compute_uniforms(
+        start_pos: PxPosition,
+        size: PxSize,
+        target_size: PxSize,
+        opacity: f32,
+    ) -> BlitUniforms {
+        let rect = [
+            (start_pos.x.0 as f32 / target_size.width.to_f32()) * 2.0 - 1.0
+                + (size.width.0 as f32 / target_size.width.to_f32()),
+            (start_pos.y.0 as f32 / target_size.height.to_f32()) * -2.0 + 1.0
+                - (size.height.0 as f32 / target_size.height.to_f32()),
+            size.width.0 as f32 / target_size.width.to_f32(),
+            size.height.0 as f32 / target_size.height.to_f32(),
+        ]
+        .into();
+
+        BlitUniforms { rect, opacity }
+    }
+}
+
+impl DrawablePipeline<ViewportBlitCommand> for ViewportBlitPipeline {
+    fn draw(&mut self, context: &mut DrawContext<ViewportBlitCommand>) {
+        if context.commands.is_empty() {
+            return;
+        }
+
+        context.render_pass.set_pipeline(&self.pipeline);
+
+        for (command, size, start_pos) in context.commands.iter() {
+            if command.opacity <= 0.0 {
+                continue;
+            }
+            let uniforms =
+                Self::compute_uniforms(*start_pos, *size, context.target_size, command.opacity);
+            let mut buffer = UniformBuffer::new(Vec::new());
+            buffer
+                .write(&uniforms)
+                .expect("viewport blit uniform serialization failed");
+
+            let uniform_buffer =
+                context
+                    .device
+                    .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                        label: Some("viewport_blit_uniform_buffer"),
+                        contents: &buffer.into_inner(),
+                        usage: wgpu::BufferUsages::UNIFORM,
+                    });
+
+            let bind_group = context
+                .device
+                .create_bind_group(&wgpu::BindGroupDescriptor {
+                    layout: &self.bind_group_layout,
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: wgpu::BindingResource::TextureView(&command.view),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: wgpu::BindingResource::Sampler(&self.sampler),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 2,
+                            resource: uniform_buffer.as_entire_binding(),
+                        },
+                    ],
+                    label: Some("viewport_blit_bind_group"),
+                });
+
+            context.render_pass.set_bind_group(0, &bind_group, &[]);
+            context.render_pass.draw(0..6, 0..1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tessera_ui::Px;
+
+    use super::*;
+
+    #[test]
+    fn blit_rect_covers_target_in_clip_space() {
+        let size = PxSize::new(Px(200), Px(100));
+        let uniforms = ViewportBlitPipeline::compute_uniforms(PxPosition::ZERO, size, size, 1.0);
+        assert_eq!(uniforms.rect, Vec4::new(0.0, 0.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn blit_rect_maps_top_left_quadrant() {
+        let target = PxSize::new(Px(200), Px(100));
+        let size = PxSize::new(Px(100), Px(50));
+        let uniforms = ViewportBlitPipeline::compute_uniforms(PxPosition::ZERO, size, target, 0.5);
+        assert_eq!(uniforms.rect, Vec4::new(-0.5, 0.5, 0.5, 0.5));
+        assert_eq!(uniforms.opacity, 0.5);
+    }
+}
@@ -7,12 +7,13 @@ use tessera_ui::{
     ComputedData, Constraint, Dp, LayoutPolicy, LayoutResult, MeasurementError, Modifier, Px,
     PxPosition, RenderSlot,
     layout::{MeasureScope, layout},
-    tessera,
+    provide_context, tessera,
 };
 
 use crate::{
     alignment::Alignment,
     modifier::{ModifierExt as _, Padding},
+    snackbar::SnackbarHostAlignment,
 };
 
 fn center_axis(container: Px, child: Px) -> Px {
@@ -249,7 +250,8 @@ impl LayoutPolicy for ScaffoldLayout {
 /// - `floating_action_button_offset` — additional x/y offset applied to the
 ///   floating action button, defaulting to `0dp`.
 /// - `snackbar_host` — optional snackbar host slot.
-/// - `snackbar_alignment` — optional snackbar host alignment.
+/// - `snackbar_alignment` — optional snackbar alignment, inherited by a
+///   [`snackbar_host`](crate::snackbar::snackbar_host) that sets none.
 /// - `snackbar_offset` — additional x/y offset applied to the snackbar host,
 ///   defaulting to `0dp`.
 ///
@@ -325,7 +327,10 @@ pub fn scaffold(
                 top_bar.render();
             }
             if let Some(snackbar_host) = snackbar_host {
-                snackbar_host.render();
+                provide_context(
+                    move || SnackbarHostAlignment(snackbar_alignment),
+                    || snackbar_host.render(),
+                );
             }
             if let Some(floating_action_button) = floating_action_button {
                 floating_action_button.render();
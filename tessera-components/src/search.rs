@@ -15,6 +15,7 @@ use crate::{
     divider::horizontal_divider,
     modifier::{ModifierExt as _, with_keyboard_input, with_pointer_input},
     pos_misc::is_position_inside_bounds,
+    res::strings,
     shape_def::Shape,
     spacer::spacer,
     surface::surface,
@@ -210,7 +211,8 @@ enum SearchBarLayoutKind {
 /// - `enabled` - whether the input is enabled.
 /// - `read_only` - whether the input is read-only.
 /// - `is_active` - whether the search bar is currently expanded.
-/// - `placeholder` - optional placeholder text.
+/// - `placeholder` - optional placeholder text; defaults to a localized
+///   "Search".
 /// - `leading_icon` - optional leading icon slot.
 /// - `trailing_icon` - optional trailing icon slot.
 /// - `on_query_change` - optional callback for query changes.
@@ -720,9 +722,10 @@ fn build_search_field(args: SearchFieldArgs) {
             next
         })
         .controller(args.input_controller);
-    if let Some(placeholder) = args.placeholder {
-        builder = builder.placeholder(placeholder);
-    }
+    builder = builder.placeholder(
+        args.placeholder
+            .unwrap_or_else(strings::search_bar_placeholder),
+    );
     if let Some(leading_icon) = args.leading_icon {
         builder = builder.leading_icon(move || {
             leading_icon.render();
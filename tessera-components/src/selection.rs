@@ -0,0 +1,271 @@
+//! Item selection shared by the data table and the tree view.
+//!
+//! Both collections select items with the same gestures: a plain click
+//! replaces the selection, a ctrl or cmd click toggles one item, and shift
+//! extends a range from the anchor, the item the last non-extending gesture
+//! landed on. They only differ in how items are stored and in what a range
+//! covers, which [`SelectionSet`] and the `select_span` argument of
+//! [`SelectionActions::select`] supply.
+
+use std::{collections::HashSet, hash::Hash};
+
+use tessera_ui::{CallbackWith, State, winit};
+
+/// How a click or key press changes the selection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum SelectionGesture {
+    /// Select only the item.
+    Replace,
+    /// Add or remove the item.
+    Toggle,
+    /// Select the items between the anchor and the item.
+    Extend,
+}
+
+/// Returns the gesture of a click made with `modifiers` held.
+pub(crate) fn selection_gesture(modifiers: winit::keyboard::ModifiersState) -> SelectionGesture {
+    if modifiers.shift_key() {
+        SelectionGesture::Extend
+    } else if modifiers.control_key() || modifiers.super_key() {
+        SelectionGesture::Toggle
+    } else {
+        SelectionGesture::Replace
+    }
+}
+
+/// How many items a collection lets the user select.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum SelectionMode {
+    None,
+    Single,
+    Multiple,
+}
+
+/// Storage for the selected items of a collection.
+pub(crate) trait SelectionSet: Clone + PartialEq + Send + Sync + 'static {
+    /// Identifies one item.
+    type Item: Copy + PartialEq + Send + Sync + 'static;
+
+    fn clear(&mut self);
+
+    fn insert(&mut self, item: Self::Item);
+
+    fn toggle(&mut self, item: Self::Item);
+}
+
+impl<T> SelectionSet for HashSet<T>
+where
+    T: Copy + Eq + Hash + Send + Sync + 'static,
+{
+    type Item = T;
+
+    fn clear(&mut self) {
+        HashSet::clear(self);
+    }
+
+    fn insert(&mut self, item: T) {
+        HashSet::insert(self, item);
+    }
+
+    fn toggle(&mut self, item: T) {
+        if !self.remove(&item) {
+            HashSet::insert(self, item);
+        }
+    }
+}
+
+/// Selected items and the anchor that range gestures extend from.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct AnchoredSelection<S: SelectionSet> {
+    pub(crate) items: S,
+    pub(crate) anchor: Option<S::Item>,
+}
+
+impl<S: SelectionSet> AnchoredSelection<S> {
+    /// Applies `gesture` on `item` and returns whether the selected items
+    /// changed.
+    ///
+    /// `select_span` adds the items from the anchor to `item` to an empty
+    /// selection.
+    pub(crate) fn apply(
+        &mut self,
+        item: S::Item,
+        mode: SelectionMode,
+        gesture: SelectionGesture,
+        select_span: impl FnOnce(&mut S, S::Item, S::Item),
+    ) -> bool {
+        let previous = self.items.clone();
+        match (mode, gesture) {
+            (SelectionMode::None, _) => return false,
+            (SelectionMode::Multiple, SelectionGesture::Extend) => {
+                // Extending keeps the anchor, so the range can be reshaped.
+                let anchor = *self.anchor.get_or_insert(item);
+                self.items.clear();
+                select_span(&mut self.items, anchor, item);
+                return self.items != previous;
+            }
+            (SelectionMode::Multiple, SelectionGesture::Toggle) => self.items.toggle(item),
+            (SelectionMode::Single, _) | (SelectionMode::Multiple, SelectionGesture::Replace) => {
+                self.items.clear();
+                self.items.insert(item);
+            }
+        }
+        self.anchor = Some(item);
+        self.items != previous
+    }
+
+    /// Drops the anchor when `keep` rejects it.
+    pub(crate) fn retain_anchor(&mut self, keep: impl FnOnce(S::Item) -> bool) {
+        if self.anchor.is_some_and(|anchor| !keep(anchor)) {
+            self.anchor = None;
+        }
+    }
+}
+
+/// Controller that owns an [`AnchoredSelection`].
+pub(crate) trait SelectionOwner: Send + Sync + 'static {
+    type Selection: SelectionSet;
+
+    fn anchored_selection(&self) -> &AnchoredSelection<Self::Selection>;
+
+    fn anchored_selection_mut(&mut self) -> &mut AnchoredSelection<Self::Selection>;
+}
+
+/// Changes the selection of a controller and reports every change.
+///
+/// Clicks, key presses and assistive technology actions all select through
+/// it, so the change callback fires once per actual change whichever way
+/// the user selected.
+pub(crate) struct SelectionActions<C: SelectionOwner> {
+    pub(crate) controller: State<C>,
+    pub(crate) mode: SelectionMode,
+    pub(crate) on_change: CallbackWith<C::Selection>,
+}
+
+impl<C: SelectionOwner> Copy for SelectionActions<C> {}
+
+impl<C: SelectionOwner> Clone for SelectionActions<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: SelectionOwner> PartialEq for SelectionActions<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.controller == other.controller
+            && self.mode == other.mode
+            && self.on_change == other.on_change
+    }
+}
+
+impl<C: SelectionOwner> SelectionActions<C> {
+    /// Returns whether items can be selected at all.
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.mode != SelectionMode::None
+    }
+
+    /// Returns whether more than one item can be selected.
+    pub(crate) fn is_multiple(&self) -> bool {
+        self.mode == SelectionMode::Multiple
+    }
+
+    /// Applies `gesture` on `item`; see [`AnchoredSelection::apply`].
+    pub(crate) fn select(
+        &self,
+        item: <C::Selection as SelectionSet>::Item,
+        gesture: SelectionGesture,
+        select_span: impl FnOnce(
+            &mut C::Selection,
+            <C::Selection as SelectionSet>::Item,
+            <C::Selection as SelectionSet>::Item,
+        ),
+    ) {
+        let mode = self.mode;
+        let changed = self.controller.with_mut(|c| {
+            c.anchored_selection_mut()
+                .apply(item, mode, gesture, select_span)
+        });
+        if changed {
+            self.notify();
+        }
+    }
+
+    /// Selects exactly `items`, as Select All does in multiple mode.
+    pub(crate) fn select_all(&self, items: C::Selection) {
+        if !self.is_multiple() {
+            return;
+        }
+        if self
+            .controller
+            .with(|c| c.anchored_selection().items != items)
+        {
+            self.controller
+                .with_mut(|c| c.anchored_selection_mut().items = items);
+            self.notify();
+        }
+    }
+
+    /// Moves the anchor without changing the selection, as plain keyboard
+    /// navigation does.
+    pub(crate) fn set_anchor(&self, item: <C::Selection as SelectionSet>::Item) {
+        self.controller
+            .with_mut(|c| c.anchored_selection_mut().anchor = Some(item));
+    }
+
+    fn notify(&self) {
+        let items = self
+            .controller
+            .with(|c| c.anchored_selection().items.clone());
+        self.on_change.call(items);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(items: &mut HashSet<u32>, anchor: u32, item: u32) {
+        items.extend(anchor.min(item)..=anchor.max(item));
+    }
+
+    #[test]
+    fn gestures_follow_the_mode_and_keep_the_anchor_while_extending() {
+        let multiple = SelectionMode::Multiple;
+        let mut selection = AnchoredSelection::<HashSet<u32>>::default();
+        assert!(selection.apply(3, multiple, SelectionGesture::Replace, span));
+        assert!(selection.apply(6, multiple, SelectionGesture::Extend, span));
+        assert_eq!(selection.items, HashSet::from([3, 4, 5, 6]));
+        assert!(selection.apply(1, multiple, SelectionGesture::Extend, span));
+        assert_eq!(selection.items, HashSet::from([1, 2, 3]));
+        assert!(selection.apply(2, multiple, SelectionGesture::Toggle, span));
+        assert_eq!(selection.items, HashSet::from([1, 3]));
+        assert_eq!(selection.anchor, Some(2));
+
+        let single = SelectionMode::Single;
+        let mut selection = AnchoredSelection::<HashSet<u32>>::default();
+        selection.apply(3, single, SelectionGesture::Replace, span);
+        selection.apply(5, single, SelectionGesture::Extend, span);
+        assert_eq!(selection.items, HashSet::from([5]));
+        assert!(!selection.apply(7, SelectionMode::None, SelectionGesture::Toggle, span));
+        selection.retain_anchor(|anchor| anchor < 5);
+        assert_eq!(selection.anchor, None);
+    }
+
+    #[test]
+    fn modifiers_pick_the_gesture() {
+        use winit::keyboard::ModifiersState;
+
+        assert_eq!(
+            selection_gesture(ModifiersState::empty()),
+            SelectionGesture::Replace
+        );
+        assert_eq!(
+            selection_gesture(ModifiersState::CONTROL),
+            SelectionGesture::Toggle
+        );
+        assert_eq!(
+            selection_gesture(ModifiersState::SHIFT | ModifiersState::CONTROL),
+            SelectionGesture::Extend
+        );
+    }
+}
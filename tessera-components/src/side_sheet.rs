@@ -6,6 +6,7 @@
 //! screen.
 use std::time::Duration;
 
+use tessera_foundation::overlay::{OverlayPosition, overlay};
 use tessera_ui::{
     Callback, CallbackWith, Color, Constraint, Dp, FocusScopeNode, FocusTraversalPolicy,
     LayoutResult, MeasurementError, Modifier, Px, PxPosition, RenderSlot, State,
@@ -384,17 +385,15 @@ fn build_side_sheet_nested_scroll_connection(
 fn place_side_sheet_if_present(
     input: &MeasureScope<'_>,
     result: &mut LayoutResult,
+    sheet_index: usize,
     is_open: bool,
     progress: f32,
     position: SideSheetPosition,
     drag_offset: f32,
 ) {
-    let children = input.children();
-    if children.len() <= 2 {
+    let Some(side_sheet) = input.children().get(sheet_index).copied() else {
         return;
-    }
-
-    let side_sheet = children[2];
+    };
     let parent_width = input
         .parent_constraint()
         .width()
@@ -642,29 +641,57 @@ fn side_sheet_provider_render(
 
     let progress = calc_progress_from_timer(timer_opt);
 
-    layout()
-        .layout_policy(SideSheetLayout {
-            progress,
-            is_open,
-            position,
-            drag_offset,
-        })
-        .child(move || {
-            let side_sheet_content = side_sheet_content;
-            main_content.render();
-
-            if show_side_sheet {
-                render_scrim(sheet_type, on_close_request, progress, is_open);
-
-                side_sheet_content_wrapper()
-                    .sheet_type(sheet_type)
-                    .position(position)
-                    .controller(controller)
-                    .on_close_request_shared(on_close_request)
-                    .just_opened(just_opened)
-                    .content_shared(side_sheet_content);
-            }
-        });
+    let mut sheet_layout = SideSheetLayout {
+        progress,
+        is_open,
+        position,
+        drag_offset,
+        with_main_content: true,
+    };
+
+    if sheet_type == SideSheetType::Modal {
+        main_content.render();
+
+        // Modal sheets render in the overlay layer so they cover the whole
+        // window; the scrim handles outside taps.
+        sheet_layout.with_main_content = false;
+        overlay()
+            .is_open(show_side_sheet)
+            .modal(true)
+            .dismiss_on_outside(false)
+            .on_dismiss_shared(on_close_request)
+            .position(OverlayPosition::Fill)
+            .content(move || {
+                layout().layout_policy(sheet_layout.clone()).child(move || {
+                    render_scrim(sheet_type, on_close_request, progress, is_open);
+
+                    side_sheet_content_wrapper()
+                        .sheet_type(sheet_type)
+                        .position(position)
+                        .controller(controller)
+                        .on_close_request_shared(on_close_request)
+                        .content_shared(side_sheet_content);
+                });
+            });
+        return;
+    }
+
+    layout().layout_policy(sheet_layout).child(move || {
+        let side_sheet_content = side_sheet_content;
+        main_content.render();
+
+        if show_side_sheet {
+            render_scrim(sheet_type, on_close_request, progress, is_open);
+
+            side_sheet_content_wrapper()
+                .sheet_type(sheet_type)
+                .position(position)
+                .controller(controller)
+                .on_close_request_shared(on_close_request)
+                .just_opened(just_opened)
+                .content_shared(side_sheet_content);
+        }
+    });
 }
 
 #[tessera]
@@ -694,17 +721,23 @@ fn side_sheet_content_wrapper(
     let on_close_request_for_keyboard = on_close_request;
 
     let focus_scope = remember(FocusScopeNode::new).get();
-    let modifier = with_keyboard_input(
+    // Modal sheets get focus trapping and Escape handling from their overlay
+    // layer.
+    let modifier = if is_modal {
         Modifier::new()
-            .focus_scope_with(focus_scope)
-            .focus_traversal_policy(
-                FocusTraversalPolicy::linear()
-                    .wrap(true)
-                    .tab_navigation(true),
-            ),
-        make_keyboard_closure(on_close_request_for_keyboard),
-    );
-    if just_opened {
+    } else {
+        with_keyboard_input(
+            Modifier::new()
+                .focus_scope_with(focus_scope)
+                .focus_traversal_policy(
+                    FocusTraversalPolicy::linear()
+                        .wrap(true)
+                        .tab_navigation(true),
+                ),
+            make_keyboard_closure(on_close_request_for_keyboard),
+        )
+    };
+    if just_opened && !is_modal {
         focus_scope.restore_focus();
     }
     layout().modifier(modifier).child(move || {
@@ -776,6 +809,8 @@ struct SideSheetLayout {
     is_open: bool,
     position: SideSheetPosition,
     drag_offset: f32,
+    /// Whether the first child is the main content rather than the scrim.
+    with_main_content: bool,
 }
 
 impl LayoutPolicy for SideSheetLayout {
@@ -783,12 +818,13 @@ impl LayoutPolicy for SideSheetLayout {
         let mut result = LayoutResult::default();
         let children = input.children();
         let child_constraint = input.parent_constraint().without_min();
-        let main_content = children[0];
-        let main_content_size = main_content.measure(&child_constraint)?;
-        result.place_child(main_content, PxPosition::new(Px(0), Px(0)));
+        let first_child = children[0];
+        let first_child_size = first_child.measure(&child_constraint)?;
+        result.place_child(first_child, PxPosition::new(Px(0), Px(0)));
 
-        if children.len() > 1 {
-            let scrim = children[1];
+        let scrim_index = usize::from(self.with_main_content);
+        if self.with_main_content && children.len() > scrim_index {
+            let scrim = children[scrim_index];
             scrim.measure(&child_constraint)?;
             result.place_child(scrim, PxPosition::new(Px(0), Px(0)));
         }
@@ -796,12 +832,13 @@ impl LayoutPolicy for SideSheetLayout {
         place_side_sheet_if_present(
             input,
             &mut result,
+            scrim_index + 1,
             self.is_open,
             self.progress,
             self.position,
             self.drag_offset,
         );
 
-        Ok(result.with_size(main_content_size.size()))
+        Ok(result.with_size(first_child_size.size()))
     }
 }
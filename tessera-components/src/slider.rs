@@ -5,10 +5,11 @@
 //! Use to allow users to select a value from a continuous range.
 use tessera_foundation::gesture::{DragRecognizer, TapRecognizer};
 use tessera_ui::{
-    AccessibilityActionHandler, AccessibilityNode, AxisConstraint, CallbackWith, Color,
-    ComputedData, Constraint, Dp, FocusProperties, FocusRequester, LayoutResult, MeasurementError,
-    Modifier, PointerInput, PointerInputModifierNode, Px, PxPosition, SemanticsModifierNode, State,
-    accesskit::{Action, Role},
+    AccessibilityActionHandler, AccessibilityActionHandlers, AccessibilityNode, AccessibilityValue,
+    AxisConstraint, CallbackWith, Color, ComputedData, Constraint, Dp, FocusProperties,
+    FocusRequester, LayoutResult, MeasurementError, Modifier, PointerInput,
+    PointerInputModifierNode, Px, PxPosition, SemanticsModifierNode, State,
+    accesskit::Role,
     layout::{LayoutPolicy, MeasureScope, layout},
     modifier::{CursorModifierExt as _, FocusModifierExt as _, ModifierCapabilityExt as _},
     remember, tessera, use_context,
@@ -22,8 +23,9 @@ use crate::{
 };
 
 use interaction::{
-    RangeSliderHandleWidths, apply_range_slider_semantics, apply_slider_semantics,
-    handle_range_slider_state, handle_slider_state, snap_fraction,
+    RangeSliderHandleWidths, accessibility_step, apply_range_slider_semantics,
+    apply_slider_action_handlers, apply_slider_semantics, handle_range_slider_state,
+    handle_slider_state, snap_fraction,
 };
 use layout::{
     CenteredSliderLayout, RangeSliderLayout, SliderLayout, fallback_component_width,
@@ -79,7 +81,6 @@ struct RangeSliderThumbProps {
 
 fn apply_range_thumb_semantics(
     accessibility: &mut AccessibilityNode,
-    action_handler: &mut Option<AccessibilityActionHandler>,
     args: &RangeThumbAccessibility,
 ) {
     accessibility.role = Some(Role::Slider);
@@ -97,34 +98,30 @@ fn apply_range_thumb_semantics(
     accessibility.focusable = !args.disabled;
     accessibility.disabled = args.disabled;
     accessibility.actions.clear();
+}
 
+fn apply_range_thumb_action_handlers(
+    handlers: &mut AccessibilityActionHandlers,
+    args: &RangeThumbAccessibility,
+) {
     if args.disabled {
-        *action_handler = None;
         return;
     }
 
-    accessibility.actions.push(Action::Increment);
-    accessibility.actions.push(Action::Decrement);
-
-    let delta = if args.steps == 0 {
-        ACCESSIBILITY_STEP
-    } else {
-        1.0 / (args.steps as f32 + 1.0)
-    };
+    let delta = accessibility_step(args.steps);
     let value = args.value;
     let min = args.min;
     let max = args.max;
     let steps = args.steps;
     let on_change = args.on_change;
-    *action_handler = Some(Box::new(move |action| {
-        let next = match action {
-            Action::Increment => value + delta,
-            Action::Decrement => value - delta,
-            _ => return,
-        };
-        let next = snap_fraction(next, steps).clamp(min, max);
-        on_change.call(next);
-    }));
+    let set_value = move |next: f32| on_change.call(snap_fraction(next, steps).clamp(min, max));
+    handlers.on_increment(move || set_value(value + delta));
+    handlers.on_decrement(move || set_value(value - delta));
+    handlers.on_set_value(move |next| {
+        if let AccessibilityValue::Numeric(next) = next {
+            set_value(next as f32);
+        }
+    });
 }
 
 #[tessera]
@@ -184,9 +181,13 @@ impl SemanticsModifierNode for RangeSliderThumbSemanticsModifierNode {
     fn apply(
         &self,
         accessibility: &mut AccessibilityNode,
-        action_handler: &mut Option<AccessibilityActionHandler>,
+        _action_handler: &mut Option<AccessibilityActionHandler>,
     ) {
-        apply_range_thumb_semantics(accessibility, action_handler, &self.accessibility);
+        apply_range_thumb_semantics(accessibility, &self.accessibility);
+    }
+
+    fn apply_action_handlers(&self, handlers: &mut AccessibilityActionHandlers) {
+        apply_range_thumb_action_handlers(handlers, &self.accessibility);
     }
 }
 
@@ -238,11 +239,14 @@ impl SemanticsModifierNode for SliderSemanticsModifierNode {
     fn apply(
         &self,
         accessibility: &mut AccessibilityNode,
-        action_handler: &mut Option<AccessibilityActionHandler>,
+        _action_handler: &mut Option<AccessibilityActionHandler>,
     ) {
-        apply_slider_semantics(
-            accessibility,
-            action_handler,
+        apply_slider_semantics(accessibility, &self.args, self.clamped_value);
+    }
+
+    fn apply_action_handlers(&self, handlers: &mut AccessibilityActionHandlers) {
+        apply_slider_action_handlers(
+            handlers,
             &self.args,
             self.clamped_value,
             &self.args.on_change,
@@ -1921,3 +1925,75 @@ fn render_range_slider(args: RangeSliderConfig) {
                 .accessibility(end_thumb_args.accessibility);
         });
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use tessera_ui::{
+        Dp, Modifier,
+        accesskit::{Action, ActionData},
+        testing::{__private, layout_test},
+    };
+
+    use crate::{
+        modifier::{ModifierExt as _, SemanticsArgs},
+        theme::{MaterialTheme, material_theme},
+    };
+
+    use super::slider;
+
+    static SLIDER_VALUE: AtomicU32 = AtomicU32::new(0);
+
+    fn slider_value() -> f32 {
+        f32::from_bits(SLIDER_VALUE.load(Ordering::Relaxed))
+    }
+
+    fn slider_case() {
+        material_theme().theme(MaterialTheme::default).child(|| {
+            slider()
+                .modifier(Modifier::new().width(Dp(200.0)).semantics(SemanticsArgs {
+                    test_tag: Some("slider".to_string()),
+                    ..Default::default()
+                }))
+                .value(0.5)
+                .steps(3)
+                .on_change(|value: f32| SLIDER_VALUE.store(value.to_bits(), Ordering::Relaxed));
+        });
+    }
+
+    #[test]
+    fn slider_handles_increment_and_set_value_actions() {
+        SLIDER_VALUE.store(0.5_f32.to_bits(), Ordering::Relaxed);
+        let mut session = __private::start_layout_test_session(
+            layout_test(|| {
+                slider_case();
+            })
+            .viewport_px(200, 100),
+        );
+        let _ = __private::pump_layout_test_session(&mut session, 0);
+
+        assert!(__private::perform_layout_test_accessibility_action(
+            &mut session,
+            "slider",
+            Action::Increment,
+            None,
+        ));
+        assert_eq!(slider_value(), 0.75);
+
+        assert!(__private::perform_layout_test_accessibility_action(
+            &mut session,
+            "slider",
+            Action::SetValue,
+            Some(ActionData::NumericValue(0.3)),
+        ));
+        assert_eq!(slider_value(), 0.25);
+
+        assert!(!__private::perform_layout_test_accessibility_action(
+            &mut session,
+            "slider",
+            Action::SetValue,
+            None,
+        ));
+    }
+}
@@ -1,8 +1,7 @@
 use tessera_foundation::gesture::{DragRecognizer, TapRecognizer};
 use tessera_ui::{
-    AccessibilityActionHandler, AccessibilityNode, CallbackWith, ComputedData, FocusRequester,
-    PointerInput, Px, PxPosition, State,
-    accesskit::{Action, Role},
+    AccessibilityActionHandlers, AccessibilityNode, AccessibilityValue, CallbackWith, ComputedData,
+    FocusRequester, PointerInput, Px, PxPosition, State, accesskit::Role,
 };
 
 use super::{ACCESSIBILITY_STEP, SliderArgs, SliderController, SliderLayout};
@@ -208,10 +207,8 @@ fn notify_on_change(new_value: Option<f32>, args: &SliderArgs) {
 
 pub(super) fn apply_slider_semantics(
     accessibility: &mut AccessibilityNode,
-    action_handler: &mut Option<AccessibilityActionHandler>,
     args: &SliderArgs,
     current_value: f32,
-    on_change: &CallbackWith<f32>,
 ) {
     accessibility.role = Some(Role::Slider);
     accessibility.label = args.accessibility_label.clone();
@@ -222,34 +219,43 @@ pub(super) fn apply_slider_semantics(
     accessibility.focusable = !args.disabled;
     accessibility.disabled = args.disabled;
     accessibility.actions.clear();
+}
 
+pub(super) fn apply_slider_action_handlers(
+    handlers: &mut AccessibilityActionHandlers,
+    args: &SliderArgs,
+    current_value: f32,
+    on_change: &CallbackWith<f32>,
+) {
     if args.disabled {
-        *action_handler = None;
         return;
     }
 
     let on_change = *on_change;
     let steps = args.steps;
-    *action_handler = Some(Box::new(move |action| {
-        let delta = if steps == 0 {
-            ACCESSIBILITY_STEP
-        } else {
-            1.0 / (steps as f32 + 1.0)
-        };
-        let new_value = match action {
-            Action::Increment => Some(snap_fraction(current_value + delta, steps)),
-            Action::Decrement => Some(snap_fraction(current_value - delta, steps)),
-            _ => None,
-        };
-
-        if let Some(new_value) = new_value
-            && (new_value - current_value).abs() > f32::EPSILON
-        {
+    let delta = accessibility_step(steps);
+    let set_value = move |new_value: f32| {
+        let new_value = snap_fraction(new_value, steps);
+        if (new_value - current_value).abs() > f32::EPSILON {
             on_change.call(new_value);
         }
-    }));
-    accessibility.actions.push(Action::Increment);
-    accessibility.actions.push(Action::Decrement);
+    };
+    handlers.on_increment(move || set_value(current_value + delta));
+    handlers.on_decrement(move || set_value(current_value - delta));
+    handlers.on_set_value(move |value| {
+        if let AccessibilityValue::Numeric(value) = value {
+            set_value(value as f32);
+        }
+    });
+}
+
+/// Fraction moved by one accessibility increment.
+pub(super) fn accessibility_step(steps: usize) -> f32 {
+    if steps == 0 {
+        ACCESSIBILITY_STEP
+    } else {
+        1.0 / (steps as f32 + 1.0)
+    }
 }
 
 /// Controller for the `range_slider` component.
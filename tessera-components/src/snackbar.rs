@@ -6,9 +6,13 @@
 
 use std::{collections::VecDeque, time::Duration};
 
+use tessera_foundation::{
+    alignment::Alignment as OverlayAlignment,
+    overlay::{OverlayPosition, overlay},
+};
 use tessera_ui::{
     Callback, CallbackWith, Color, Dp, Modifier, State, current_frame_nanos, layout::layout,
-    receive_frame_nanos, remember, tessera, use_context,
+    receive_frame_nanos, remember, remember_bounds, tessera, use_context,
 };
 
 use crate::{
@@ -19,6 +23,7 @@ use crate::{
     icon_button::{IconButtonVariant, icon_button},
     material_icons::filled,
     modifier::{ModifierExt as _, Padding},
+    res::strings,
     row::row,
     shape_def::Shape,
     spacer::spacer,
@@ -347,6 +352,11 @@ impl Default for SnackbarHostState {
     }
 }
 
+/// Alignment [`snackbar_host`] falls back to, provided by
+/// [`scaffold`](crate::scaffold::scaffold) from its `snackbar_alignment`.
+#[derive(Clone, Copy)]
+pub(crate) struct SnackbarHostAlignment(pub(crate) Alignment);
+
 /// Default values used by snackbars.
 pub struct SnackbarDefaults;
 
@@ -367,6 +377,8 @@ impl SnackbarDefaults {
     pub const ACTION_SPACING: Dp = Dp(8.0);
     /// Vertical spacing between text and actions when stacked.
     pub const ACTION_VERTICAL_SPACING: Dp = Dp(2.0);
+    /// Overlay stacking order of snackbars, above menus, sheets and dialogs.
+    pub const Z_INDEX: i32 = 100;
 
     /// Default snackbar shape.
     pub fn shape() -> crate::shape_def::Shape {
@@ -586,10 +598,16 @@ pub fn snackbar(
 /// Use with [`scaffold`](crate::scaffold::scaffold) to show transient messages
 /// above app content.
 ///
+/// The host fills the space it is given and shows the current snackbar in a
+/// non-modal overlay layer aligned inside that area, so snackbars stack with
+/// menus, sheets and dialogs instead of being covered by them.
+///
 /// ## Parameters
 ///
-/// - `modifier` — modifier chain applied to the host container.
+/// - `modifier` — modifier chain applied to the host area.
 /// - `state` — state that provides snackbar queue data.
+/// - `alignment` — optional alignment of the snackbar inside the host area;
+///   defaults to the scaffold's `snackbar_alignment`, or bottom center.
 /// - `snackbar` — optional custom snackbar slot for rendering.
 ///
 /// ## Examples
@@ -619,9 +637,13 @@ pub fn snackbar(
 pub fn snackbar_host(
     modifier: Option<Modifier>,
     state: Option<State<SnackbarHostState>>,
+    alignment: Option<Alignment>,
     snackbar: Option<CallbackWith<SnackbarData>>,
 ) {
     let modifier = modifier.unwrap_or_default();
+    let alignment = alignment
+        .or_else(|| use_context::<SnackbarHostAlignment>().map(|context| context.get().0))
+        .unwrap_or(Alignment::BottomCenter);
     let state = state.unwrap_or_else(|| remember(SnackbarHostState::default));
     let snackbar_slot = snackbar;
     let frame_nanos = current_frame_nanos();
@@ -648,23 +670,50 @@ pub fn snackbar_host(
             }
         });
     }
-    let Some(record) = record else {
-        return;
-    };
-    let data = SnackbarData::new(record, state);
 
-    layout().modifier(modifier).child(move || {
-        let data = data.clone();
-        if let Some(snackbar_slot) = snackbar_slot {
-            snackbar_slot.call(data.clone());
-        } else {
-            layout()
-                .modifier(Modifier::new().padding(SnackbarDefaults::HOST_PADDING))
-                .child(move || {
-                    snackbar_from_data(data.clone());
-                });
-        }
-    });
+    let bounds = remember_bounds();
+    layout().modifier(modifier.fill_max_size());
+
+    let is_open = record.is_some();
+    let data = record.map(|record| SnackbarData::new(record, state));
+    let area = bounds.get().unwrap_or_default();
+    overlay()
+        .is_open(is_open)
+        .dismiss_on_back(false)
+        .dismiss_on_outside(false)
+        .z_index(SnackbarDefaults::Z_INDEX)
+        .position(OverlayPosition::Within {
+            bounds: area,
+            alignment: overlay_alignment(alignment),
+        })
+        .content(move || {
+            let Some(data) = data.clone() else {
+                return;
+            };
+            if let Some(snackbar_slot) = snackbar_slot {
+                snackbar_slot.call(data);
+            } else {
+                layout()
+                    .modifier(Modifier::new().padding(SnackbarDefaults::HOST_PADDING))
+                    .child(move || {
+                        snackbar_from_data(data.clone());
+                    });
+            }
+        });
+}
+
+fn overlay_alignment(alignment: Alignment) -> OverlayAlignment {
+    match alignment {
+        Alignment::TopStart => OverlayAlignment::TopStart,
+        Alignment::TopCenter => OverlayAlignment::TopCenter,
+        Alignment::TopEnd => OverlayAlignment::TopEnd,
+        Alignment::CenterStart => OverlayAlignment::CenterStart,
+        Alignment::Center => OverlayAlignment::Center,
+        Alignment::CenterEnd => OverlayAlignment::CenterEnd,
+        Alignment::BottomStart => OverlayAlignment::BottomStart,
+        Alignment::BottomCenter => OverlayAlignment::BottomCenter,
+        Alignment::BottomEnd => OverlayAlignment::BottomEnd,
+    }
 }
 
 #[derive(Clone)]
@@ -762,6 +811,7 @@ fn render_dismiss_button(dismiss_color: Color, on_dismiss: Option<Callback>) {
         .icon(filled::CLOSE_SVG)
         .variant(IconButtonVariant::Standard)
         .content_color(dismiss_color)
+        .accessibility_label(strings::snackbar_dismiss())
         .on_click_shared(on_dismiss.unwrap_or_default());
 }
 
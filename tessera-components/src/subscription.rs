@@ -0,0 +1,171 @@
+//! Listeners that producers on other threads reach on the UI thread.
+//!
+//! Image loads, video sources and viewport scenes hand their results to
+//! components from worker or producer threads. A [`Subscriber`] is the
+//! producer's entry for one listener and a [`Subscription`] is the
+//! component's handle to it; dropping the handle also stops deliveries that
+//! were posted but have not run yet.
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, AtomicU64, Ordering},
+};
+
+use parking_lot::Mutex;
+use tessera_ui::{State, post_to_ui_thread, remember_with_key};
+
+static NEXT_NOTIFIER_ID: AtomicU64 = AtomicU64::new(1);
+
+type Unsubscribe = Box<dyn FnOnce(u64) + Send + Sync>;
+
+/// Producer-side entry for one listener.
+pub(crate) struct Subscriber<T> {
+    id: u64,
+    active: Arc<AtomicBool>,
+    listener: T,
+}
+
+impl<T> Subscriber<T> {
+    /// Creates the entry for `listener` and the subscription that keeps it
+    /// active. `unsubscribe` receives `id` once the subscription is dropped.
+    pub(crate) fn new(
+        id: u64,
+        listener: T,
+        unsubscribe: impl FnOnce(u64) + Send + Sync + 'static,
+    ) -> (Self, Subscription) {
+        let active = Arc::new(AtomicBool::new(true));
+        let subscription = Subscription {
+            id,
+            active: active.clone(),
+            unsubscribe: Some(Box::new(unsubscribe)),
+        };
+        (
+            Self {
+                id,
+                active,
+                listener,
+            },
+            subscription,
+        )
+    }
+
+    /// Returns the id passed to [`Subscriber::new`].
+    pub(crate) fn id(&self) -> u64 {
+        self.id
+    }
+}
+
+impl<T: Clone + Send + 'static> Subscriber<T> {
+    /// Runs `deliver` with the listener on the UI thread, unless the
+    /// subscription is dropped before it gets there.
+    pub(crate) fn post(&self, deliver: impl FnOnce(T) + Send + 'static) {
+        let active = self.active.clone();
+        let listener = self.listener.clone();
+        post_to_ui_thread(move || {
+            if active.load(Ordering::Acquire) {
+                deliver(listener);
+            }
+        });
+    }
+}
+
+/// Component-side handle that keeps a [`Subscriber`] registered.
+pub(crate) struct Subscription {
+    id: u64,
+    active: Arc<AtomicBool>,
+    unsubscribe: Option<Unsubscribe>,
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        self.active.store(false, Ordering::Release);
+        if let Some(unsubscribe) = self.unsubscribe.take() {
+            unsubscribe(self.id);
+        }
+    }
+}
+
+/// Rebuilds observing components when a producer signals a change.
+///
+/// Signals raised before the UI thread handled the previous one are
+/// coalesced into a single rebuild.
+pub(crate) struct Notifier {
+    id: u64,
+    subscribers: Mutex<Vec<Subscriber<State<u64>>>>,
+    pending: AtomicBool,
+    next_subscriber_id: AtomicU64,
+}
+
+impl Notifier {
+    pub(crate) fn new() -> Arc<Self> {
+        Arc::new(Self {
+            id: NEXT_NOTIFIER_ID.fetch_add(1, Ordering::Relaxed),
+            subscribers: Mutex::new(Vec::new()),
+            pending: AtomicBool::new(false),
+            next_subscriber_id: AtomicU64::new(1),
+        })
+    }
+
+    /// Schedules a rebuild of every observing component. Can be called from
+    /// any thread.
+    pub(crate) fn notify(self: &Arc<Self>) {
+        if self.pending.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        let notifier = self.clone();
+        post_to_ui_thread(move || {
+            notifier.pending.store(false, Ordering::Release);
+            for subscriber in notifier.subscribers.lock().iter() {
+                if subscriber.active.load(Ordering::Acquire) {
+                    subscriber
+                        .listener
+                        .with_mut(|counter| *counter = counter.wrapping_add(1));
+                }
+            }
+        });
+    }
+
+    fn subscribe(self: &Arc<Self>, counter: State<u64>) -> Subscription {
+        let id = self.next_subscriber_id.fetch_add(1, Ordering::Relaxed);
+        let notifier = Arc::downgrade(self);
+        let (subscriber, subscription) = Subscriber::new(id, counter, move |id| {
+            if let Some(notifier) = notifier.upgrade() {
+                notifier
+                    .subscribers
+                    .lock()
+                    .retain(|subscriber| subscriber.id != id);
+            }
+        });
+        self.subscribers.lock().push(subscriber);
+        subscription
+    }
+}
+
+/// Rebuilds the calling component whenever `notifier` fires while it stays
+/// in the tree.
+///
+/// Returns the counter the notifier bumps; bumping it directly schedules a
+/// rebuild the same way.
+pub(crate) fn observe(notifier: &Arc<Notifier>) -> State<u64> {
+    let counter = remember_with_key(notifier.id, || 0_u64);
+    let subscriber = notifier.clone();
+    remember_with_key(notifier.id, move || subscriber.subscribe(counter));
+    counter.get();
+    counter
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dropping_subscription_runs_unsubscribe_once() {
+        let removed = Arc::new(Mutex::new(Vec::new()));
+        let sink = removed.clone();
+        let (subscriber, subscription) = Subscriber::new(7, (), move |id| sink.lock().push(id));
+        assert!(subscriber.active.load(Ordering::Acquire));
+        drop(subscription);
+        assert!(!subscriber.active.load(Ordering::Acquire));
+        assert_eq!(*removed.lock(), vec![7]);
+    }
+}
@@ -168,13 +168,12 @@ impl RenderPolicy for TextLayout {
             [computed.width.raw() as u32, computed.height.raw() as u32],
         );
 
+        let mut metadata = input.metadata_mut();
+        metadata.set_accessibility_text(text_data.accessibility_text());
         let drawable = TextCommand {
             data: text_data,
             offset: PxPosition::ZERO,
         };
-        input
-            .metadata_mut()
-            .fragment_mut()
-            .push_draw_command(drawable);
+        metadata.fragment_mut().push_draw_command(drawable);
     }
 }
@@ -20,6 +20,7 @@
 //! component tree.
 
 mod cursor;
+mod input_transformation;
 
 use std::ops::Range;
 
@@ -29,8 +30,8 @@ use glyphon::{
 };
 use tessera_platform::clipboard;
 use tessera_ui::{
-    CallbackWith, Color, ComputedData, Dp, FocusRequester, LayoutResult, MeasurementError, Px,
-    PxPosition, State, current_frame_nanos,
+    AccessibilityText, AccessibilityTextSelection, CallbackWith, Color, ComputedData, Dp,
+    FocusRequester, LayoutResult, MeasurementError, Px, PxPosition, State, current_frame_nanos,
     layout::{LayoutPolicy, MeasureScope, RenderInput, RenderPolicy, layout},
     receive_frame_nanos, tessera,
     time::Instant,
@@ -47,6 +48,10 @@ use crate::{
     text_edit_core::cursor::CURSOR_WIDRH,
 };
 
+pub use input_transformation::{
+    InputMask, InputTransformation, InputTransformations, TextChange, TextEditKind,
+};
+
 /// Display-only text transform output with offset mapping between raw and
 /// transformed content.
 #[derive(Clone, PartialEq)]
@@ -123,7 +128,6 @@ impl TransformedText {
         self.raw_to_transformed[index]
     }
 
-    #[allow(dead_code)]
     fn map_to_raw(&self, transformed_offset: usize) -> usize {
         let index = boundary_index_for_offset(&self.transformed_boundaries, transformed_offset);
         self.transformed_to_raw[index]
@@ -454,7 +458,13 @@ struct PreviewEditResult {
 
 struct PreviewReplaceResult {
     text: String,
-    replaced_range: Range<usize>,
+}
+
+struct TransformedEdit {
+    text: String,
+    selection: TextSelection,
+    changed_range: Range<usize>,
+    rejected: bool,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -477,6 +487,7 @@ impl TextLayoutCacheKey {
 struct TextEditState {
     editor: glyphon::Editor<'static>,
     display_transform: Option<DisplayTransform>,
+    input_transformation: Option<InputTransformation>,
     text_color: Color,
     cursor_color: Color,
     single_line: bool,
@@ -538,6 +549,18 @@ impl TextEditState {
         should_update
     }
 
+    fn input_transformation(&self) -> Option<InputTransformation> {
+        self.input_transformation
+    }
+
+    fn set_input_transformation(&mut self, transformation: Option<InputTransformation>) -> bool {
+        if self.input_transformation == transformation {
+            return false;
+        }
+        self.input_transformation = transformation;
+        true
+    }
+
     fn single_line(&self) -> bool {
         self.single_line
     }
@@ -618,10 +641,7 @@ impl TextEditState {
         let end = range.end.min(current_text.len()).max(start);
         let mut text = current_text;
         text.replace_range(start..end, replacement);
-        PreviewReplaceResult {
-            text,
-            replaced_range: start..(start + replacement.len()),
-        }
+        PreviewReplaceResult { text }
     }
 
     fn apply_action(&mut self, action: glyphon::Action) {
@@ -1876,6 +1896,45 @@ impl TextEditorController {
         self.layout_state.text_data()
     }
 
+    /// Returns the displayed text runs with the selection in display offsets.
+    pub(crate) fn current_accessibility_text(&self) -> Option<AccessibilityText> {
+        let text_data = self.current_text_data()?;
+        let selection = self.selection();
+        let display = |offset| {
+            self.layout_state
+                .transformed_text()
+                .map_or(offset, |transformed_text| {
+                    transformed_text.map_from_raw(offset)
+                })
+        };
+        Some(
+            text_data
+                .accessibility_text()
+                .with_selection(AccessibilityTextSelection {
+                    anchor: display(selection.start),
+                    focus: display(selection.end),
+                }),
+        )
+    }
+
+    /// Moves the cursor and selection to offsets in the displayed text.
+    pub(crate) fn set_display_selection(&mut self, selection: AccessibilityTextSelection) {
+        let text_len = self.text().len();
+        let raw = |offset: usize| {
+            self.layout_state
+                .transformed_text()
+                .map_or(offset, |transformed_text| {
+                    transformed_text.map_to_raw(offset)
+                })
+                .min(text_len)
+        };
+        let selection = TextSelection {
+            start: raw(selection.anchor),
+            end: raw(selection.focus),
+        };
+        self.apply_cursor_and_selection_offsets(selection.end, Some(selection));
+    }
+
     fn cached_layout(&self, constraint: &TextConstraint) -> Option<CachedLayout> {
         self.layout_state.cached_geometry_for_constraint(constraint)
     }
@@ -1940,6 +1999,7 @@ impl TextEditorController {
             edit_state: TextEditState {
                 editor,
                 display_transform: None,
+                input_transformation: None,
                 text_color,
                 cursor_color,
                 single_line: false,
@@ -2100,6 +2160,17 @@ impl TextEditorController {
         }
     }
 
+    /// Sets the edit-aware transformation applied to user edits before they
+    /// are committed.
+    pub fn set_input_transformation(&mut self, transformation: Option<InputTransformation>) {
+        self.edit_state.set_input_transformation(transformation);
+    }
+
+    /// Returns the current input transformation, if any.
+    pub fn input_transformation(&self) -> Option<InputTransformation> {
+        self.edit_state.input_transformation()
+    }
+
     pub(crate) fn single_line(&self) -> bool {
         self.edit_state.single_line()
     }
@@ -2672,6 +2743,81 @@ impl TextEditorController {
         self.invalidate_layout_geometry();
     }
 
+    // Runs the input transformation over a proposed edit. Without a
+    // transformation, or when the edit leaves the text untouched, the proposal
+    // is returned as-is.
+    fn transform_edit(
+        &self,
+        edit_kind: Option<TextEditKind>,
+        replaced_range: Option<Range<usize>>,
+        text: String,
+        selection: TextSelection,
+    ) -> TransformedEdit {
+        let mut original_text = self.text();
+        let changed_range = replaced_range.clone().map_or(0..text.len(), |range| {
+            let retained_len = original_text.len() - range.len();
+            range.start..range.start + text.len().saturating_sub(retained_len)
+        });
+        let (Some(kind), Some(transformation)) = (edit_kind, self.input_transformation()) else {
+            return TransformedEdit {
+                text,
+                selection,
+                changed_range,
+                rejected: false,
+            };
+        };
+        if original_text == text {
+            return TransformedEdit {
+                text,
+                selection,
+                changed_range,
+                rejected: false,
+            };
+        }
+        let mut original_selection = self.selection();
+        let mut replaced_range = replaced_range;
+        // Composing text is provisional, so a committed IME edit is presented
+        // as an insertion into the text without the preedit.
+        if kind == TextEditKind::ImeCommit
+            && let Some(composition) = self.composition()
+        {
+            let start = composition.range.start.min(original_text.len());
+            let end = composition.range.end.min(original_text.len()).max(start);
+            original_text.replace_range(start..end, "");
+            original_selection = TextSelection::collapsed(start);
+            replaced_range = Some(start..start);
+        }
+
+        let change = match replaced_range {
+            Some(range) => TextChange::new(
+                kind,
+                original_text,
+                original_selection,
+                range,
+                text,
+                selection,
+            ),
+            None => TextChange::from_diff(kind, original_text, original_selection, text, selection),
+        };
+        let change = transformation.call(change);
+        if change.is_rejected() {
+            let start = change.replaced_range().start;
+            let (text, selection) = change.into_original();
+            return TransformedEdit {
+                text,
+                selection,
+                changed_range: start..start,
+                rejected: true,
+            };
+        }
+        TransformedEdit {
+            selection: change.proposed_selection(),
+            changed_range: change.changed_range(),
+            text: change.into_text(),
+            rejected: false,
+        }
+    }
+
     pub(crate) fn apply_action_with_pipeline(
         &mut self,
         action: glyphon::Action,
@@ -2679,8 +2825,19 @@ impl TextEditorController {
         input_transform: Option<CallbackWith<String, String>>,
     ) {
         let preview = self.edit_state.preview_action_result(action);
-        let raw_content_after_action = preview.text;
-        let selection_after_action = preview.selection;
+        let edit = self.transform_edit(
+            Some(edit_kind_for_action(&action)),
+            None,
+            preview.text.clone(),
+            preview.selection.clone(),
+        );
+        if edit.rejected {
+            return;
+        }
+        let raw_content_after_action = edit.text;
+        let selection_after_action = edit.selection;
+        let action_matches_edit =
+            raw_content_after_action == preview.text && selection_after_action == preview.selection;
         let transformed_content = if let Some(transform) = input_transform.as_ref() {
             transform.call(raw_content_after_action.clone())
         } else {
@@ -2691,7 +2848,8 @@ impl TextEditorController {
             &transformed_content,
             selection_after_action.clone(),
         );
-        let raw_action_matches_final = transformed_content == raw_content_after_action;
+        let raw_action_matches_final =
+            action_matches_edit && transformed_content == raw_content_after_action;
 
         let new_content = on_change.call(transformed_content.clone());
         let (final_content, final_selection) = if new_content != transformed_content {
@@ -2719,12 +2877,24 @@ impl TextEditorController {
         range: Range<usize>,
         replacement: &str,
         selection: TextSelection,
+        edit_kind: Option<TextEditKind>,
         on_change: CallbackWith<String, String>,
         input_transform: Option<CallbackWith<String, String>>,
     ) -> ImeEditResult {
+        let text_len = self.text().len();
+        let start = range.start.min(text_len);
+        let replaced_range = start..range.end.min(text_len).max(start);
         let preview = self.edit_state.preview_replace_result(range, replacement);
-        let raw_content_after_replace = preview.text;
-        let raw_replaced_range = preview.replaced_range;
+        let edit = self.transform_edit(edit_kind, Some(replaced_range), preview.text, selection);
+        if edit.rejected && edit.text == self.text() {
+            return ImeEditResult {
+                selection: self.selection(),
+                replaced_range: edit.changed_range,
+            };
+        }
+        let raw_content_after_replace = edit.text;
+        let raw_replaced_range = edit.changed_range;
+        let selection = edit.selection;
 
         let transformed_content = if let Some(transform) = input_transform.as_ref() {
             transform.call(raw_content_after_replace.clone())
@@ -2768,6 +2938,7 @@ impl TextEditorController {
     pub(crate) fn replace_selected_text_with_pipeline(
         &mut self,
         replacement: &str,
+        edit_kind: TextEditKind,
         on_change: CallbackWith<String, String>,
         input_transform: Option<CallbackWith<String, String>>,
     ) -> TextSelection {
@@ -2776,6 +2947,7 @@ impl TextEditorController {
             selection.clone(),
             replacement,
             TextSelection::collapsed(selection.start + replacement.len()),
+            Some(edit_kind),
             on_change,
             input_transform,
         )
@@ -2799,7 +2971,7 @@ impl TextEditorController {
         if !self.copy_selection_to_clipboard() {
             return false;
         }
-        self.replace_selected_text_with_pipeline("", on_change, input_transform);
+        self.replace_selected_text_with_pipeline("", TextEditKind::Cut, on_change, input_transform);
         true
     }
 
@@ -2811,7 +2983,12 @@ impl TextEditorController {
         let Some(text) = clipboard::get_text() else {
             return false;
         };
-        self.replace_selected_text_with_pipeline(&text, on_change, input_transform);
+        self.replace_selected_text_with_pipeline(
+            &text,
+            TextEditKind::Paste,
+            on_change,
+            input_transform,
+        );
         true
     }
 
@@ -2828,6 +3005,7 @@ impl TextEditorController {
             range.clone(),
             "",
             TextSelection::collapsed(range.start),
+            Some(TextEditKind::Delete),
             on_change,
             input_transform,
         );
@@ -3110,6 +3288,13 @@ fn apply_horizontal_scroll_offset(rects: &mut [RectDef], scroll_horizontal: Px)
     }
 }
 
+fn edit_kind_for_action(action: &glyphon::Action) -> TextEditKind {
+    match action {
+        glyphon::Action::Backspace | glyphon::Action::Delete => TextEditKind::Delete,
+        _ => TextEditKind::Insert,
+    }
+}
+
 fn editor_content(editor: &glyphon::Editor) -> String {
     editor.with_buffer(|buffer| {
         buffer
@@ -3378,6 +3563,9 @@ impl RenderPolicy for TextEditLayout {
     fn record(&self, input: &mut RenderInput<'_>) {
         let mut metadata = input.metadata_mut();
         metadata.set_clips_children(true);
+        if let Some(text) = self.controller.with(|c| c.current_accessibility_text()) {
+            metadata.set_accessibility_text(text);
+        }
         if let Some(text_data) = self.controller.with(|c| c.current_text_data()) {
             let drawable = TextCommand {
                 data: text_data,
@@ -3474,10 +3662,11 @@ mod tests {
         TransformedText, active_ime_rect, build_display_buffer, build_display_editor,
         compute_transformed_composition_rects, text_offset_to_cursor_in_buffer, write_font_system,
     };
+    use super::{InputMask, InputTransformation, InputTransformations, TextChange, TextEditKind};
     use crate::pipelines::text::command::TextConstraint;
     use glyphon::{Action as GlyphonAction, Edit as _, cosmic_text::Motion};
     use tessera_ui::winit::keyboard::NamedKey;
-    use tessera_ui::{ComputedData, Dp, Px};
+    use tessera_ui::{CallbackWith, ComputedData, Dp, Px, tessera};
 
     fn controller_with_text(text: &str) -> TextEditorController {
         let mut controller = TextEditorController::new(Dp(14.0), None);
@@ -4839,4 +5028,173 @@ mod tests {
             })
         );
     }
+
+    thread_local! {
+        static PIPELINE_CASE: std::cell::RefCell<Option<PipelineCase>> =
+            const { std::cell::RefCell::new(None) };
+        static PIPELINE_OUTCOME: std::cell::RefCell<Option<(String, TextSelection)>> =
+            const { std::cell::RefCell::new(None) };
+    }
+
+    #[derive(Clone)]
+    struct PipelineCase {
+        text: &'static str,
+        selection: TextSelection,
+        transformation: fn() -> InputTransformation,
+        edit: fn(&mut TextEditorController),
+    }
+
+    // Input transformations are callback handles, so the edit has to run
+    // inside a component build.
+    #[tessera]
+    fn input_transformation_pipeline_case() {
+        let Some(case) = PIPELINE_CASE.with(|slot| slot.borrow().clone()) else {
+            return;
+        };
+        let mut controller = controller_with_text(case.text);
+        controller.set_text_and_selection(case.text, case.selection);
+        controller.set_input_transformation(Some((case.transformation)()));
+        (case.edit)(&mut controller);
+        PIPELINE_OUTCOME.with(|slot| {
+            *slot.borrow_mut() = Some((controller.text(), controller.selection()));
+        });
+    }
+
+    fn run_pipeline(case: PipelineCase) -> (String, TextSelection) {
+        PIPELINE_CASE.with(|slot| *slot.borrow_mut() = Some(case));
+        tessera_ui::testing::layout_test(|| {
+            input_transformation_pipeline_case();
+        })
+        .run();
+        PIPELINE_CASE.with(|slot| slot.borrow_mut().take());
+        PIPELINE_OUTCOME
+            .with(|slot| slot.borrow_mut().take())
+            .expect("pipeline case should run during build")
+    }
+
+    fn type_char(controller: &mut TextEditorController, c: char) {
+        controller.apply_action_with_pipeline(
+            GlyphonAction::Insert(c),
+            CallbackWith::identity(),
+            None,
+        );
+    }
+
+    fn paste(controller: &mut TextEditorController, text: &str) {
+        controller.replace_selected_text_with_pipeline(
+            text,
+            TextEditKind::Paste,
+            CallbackWith::identity(),
+            None,
+        );
+    }
+
+    #[test]
+    fn pipeline_rejects_typed_characters_filtered_out_by_transformation() {
+        let outcome = run_pipeline(PipelineCase {
+            text: "12",
+            selection: TextSelection::collapsed(2),
+            transformation: InputTransformations::digits_only,
+            edit: |controller| type_char(controller, 'a'),
+        });
+        assert_eq!(outcome, ("12".to_string(), TextSelection::collapsed(2)));
+
+        let outcome = run_pipeline(PipelineCase {
+            text: "12",
+            selection: TextSelection::collapsed(1),
+            transformation: InputTransformations::digits_only,
+            edit: |controller| type_char(controller, '7'),
+        });
+        assert_eq!(outcome, ("172".to_string(), TextSelection::collapsed(2)));
+    }
+
+    #[test]
+    fn pipeline_filters_pasted_text_and_places_cursor_after_kept_characters() {
+        let outcome = run_pipeline(PipelineCase {
+            text: "1289",
+            selection: TextSelection::collapsed(2),
+            transformation: InputTransformations::digits_only,
+            edit: |controller| paste(controller, "3a4b"),
+        });
+        assert_eq!(outcome, ("123489".to_string(), TextSelection::collapsed(4)));
+    }
+
+    #[test]
+    fn pipeline_truncates_paste_to_max_length_and_replaces_selection() {
+        let outcome = run_pipeline(PipelineCase {
+            text: "12xx",
+            selection: TextSelection { start: 2, end: 4 },
+            transformation: || InputTransformations::max_length(5),
+            edit: |controller| paste(controller, "3456"),
+        });
+        assert_eq!(outcome, ("12345".to_string(), TextSelection::collapsed(5)));
+    }
+
+    #[test]
+    fn pipeline_applies_chained_transformations_in_order() {
+        let outcome = run_pipeline(PipelineCase {
+            text: "ab",
+            selection: TextSelection::collapsed(2),
+            transformation: || {
+                InputTransformations::chain(
+                    InputTransformations::uppercase(),
+                    InputTransformations::max_length(3),
+                )
+            },
+            edit: |controller| paste(controller, "cd"),
+        });
+        assert_eq!(outcome, ("abC".to_string(), TextSelection::collapsed(3)));
+    }
+
+    #[test]
+    fn pipeline_rejects_input_beyond_mask_slots() {
+        let outcome = run_pipeline(PipelineCase {
+            text: "12311999",
+            selection: TextSelection::collapsed(8),
+            transformation: || InputMask::date().input_transformation(),
+            edit: |controller| type_char(controller, '9'),
+        });
+        assert_eq!(
+            outcome,
+            ("12311999".to_string(), TextSelection::collapsed(8))
+        );
+
+        let outcome = run_pipeline(PipelineCase {
+            text: "1231199",
+            selection: TextSelection::collapsed(7),
+            transformation: || InputMask::date().input_transformation(),
+            edit: |controller| type_char(controller, '9'),
+        });
+        assert_eq!(
+            outcome,
+            ("12311999".to_string(), TextSelection::collapsed(8))
+        );
+    }
+
+    #[test]
+    fn pipeline_reports_edit_kind_and_keeps_text_when_deletion_is_rejected() {
+        let outcome = run_pipeline(PipelineCase {
+            text: "locked",
+            selection: TextSelection::collapsed(6),
+            transformation: || {
+                CallbackWith::new(|mut change: TextChange| {
+                    if change.kind() == TextEditKind::Delete {
+                        change.reject();
+                    }
+                    change
+                })
+            },
+            edit: |controller| {
+                controller.replace_text_range_with_pipeline(
+                    5..6,
+                    "",
+                    TextSelection::collapsed(5),
+                    Some(TextEditKind::Delete),
+                    CallbackWith::identity(),
+                    None,
+                );
+            },
+        });
+        assert_eq!(outcome, ("locked".to_string(), TextSelection::collapsed(6)));
+    }
 }
@@ -0,0 +1,517 @@
+//! Edit-aware input transformations for text editing components.
+//!
+//! ## Usage
+//!
+//! Filter, reshape, or reject user edits before they reach the text buffer.
+
+use std::ops::Range;
+
+use tessera_ui::CallbackWith;
+
+use super::{DisplayTransform, TextSelection, TransformedText};
+
+/// Describes which user interaction produced a [`TextChange`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextEditKind {
+    /// Characters typed from the keyboard, including Enter.
+    Insert,
+    /// Characters removed with Backspace, Delete, or word deletion.
+    Delete,
+    /// Text inserted from the clipboard.
+    Paste,
+    /// Selected text removed by a cut operation.
+    Cut,
+    /// Text committed by an input method.
+    ImeCommit,
+}
+
+/// A proposed edit to the text buffer, passed to an [`InputTransformation`].
+///
+/// All offsets are UTF-8 byte offsets. The proposed text starts out as the
+/// result of applying the user's edit to [`TextChange::original_text`], and the
+/// transformation may modify it further or reject it entirely. Selection and
+/// the changed range are remapped automatically when the proposed text is
+/// edited through [`TextChange::replace`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextChange {
+    kind: TextEditKind,
+    original_text: String,
+    original_selection: TextSelection,
+    replaced_range: Range<usize>,
+    text: String,
+    selection: TextSelection,
+    changed_range: Range<usize>,
+    rejected: bool,
+}
+
+impl TextChange {
+    pub(crate) fn new(
+        kind: TextEditKind,
+        original_text: String,
+        original_selection: TextSelection,
+        replaced_range: Range<usize>,
+        text: String,
+        selection: TextSelection,
+    ) -> Self {
+        let retained_len = original_text.len() - replaced_range.len();
+        let inserted_len = text.len().saturating_sub(retained_len);
+        let changed_range = replaced_range.start..replaced_range.start + inserted_len;
+        Self {
+            kind,
+            original_text,
+            original_selection,
+            replaced_range,
+            text,
+            selection,
+            changed_range,
+            rejected: false,
+        }
+    }
+
+    pub(crate) fn from_diff(
+        kind: TextEditKind,
+        original_text: String,
+        original_selection: TextSelection,
+        text: String,
+        selection: TextSelection,
+    ) -> Self {
+        let (replaced_range, changed_range) = diff_ranges(&original_text, &text);
+        Self {
+            kind,
+            original_text,
+            original_selection,
+            replaced_range,
+            text,
+            selection,
+            changed_range,
+            rejected: false,
+        }
+    }
+
+    /// Returns the interaction that produced this change.
+    pub fn kind(&self) -> TextEditKind {
+        self.kind
+    }
+
+    /// Returns the text content before the edit.
+    pub fn original_text(&self) -> &str {
+        &self.original_text
+    }
+
+    /// Returns the ordered selection range before the edit.
+    pub fn original_selection(&self) -> Range<usize> {
+        self.original_selection.ordered_range()
+    }
+
+    /// Returns the range of [`TextChange::original_text`] replaced by the
+    /// edit.
+    pub fn replaced_range(&self) -> Range<usize> {
+        self.replaced_range.clone()
+    }
+
+    /// Returns the proposed text content.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the range of the proposed text that differs from the original.
+    ///
+    /// The range is empty for pure deletions.
+    pub fn changed_range(&self) -> Range<usize> {
+        self.changed_range.clone()
+    }
+
+    /// Returns the text inserted by the edit, as currently proposed.
+    pub fn changed_text(&self) -> &str {
+        &self.text[self.changed_range.clone()]
+    }
+
+    /// Returns the ordered selection range in the proposed text.
+    pub fn selection(&self) -> Range<usize> {
+        self.selection.ordered_range()
+    }
+
+    /// Returns the cursor offset in the proposed text.
+    pub fn cursor(&self) -> usize {
+        self.selection.end
+    }
+
+    /// Returns the number of characters in the proposed text.
+    pub fn char_count(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    /// Replaces `range` of the proposed text with `replacement`.
+    ///
+    /// Selection offsets after the range shift with it and offsets inside the
+    /// range move to the end of the replacement.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds or does not lie on character
+    /// boundaries.
+    pub fn replace(&mut self, range: Range<usize>, replacement: &str) {
+        self.text.replace_range(range.clone(), replacement);
+        let new_len = replacement.len();
+        self.selection = TextSelection {
+            start: map_offset_through_replace(self.selection.start, &range, new_len),
+            end: map_offset_through_replace(self.selection.end, &range, new_len),
+        };
+        self.changed_range = if range.end < self.changed_range.start
+            || range.start > self.changed_range.end
+        {
+            map_offset_through_replace(self.changed_range.start, &range, new_len)
+                ..map_offset_through_replace(self.changed_range.end, &range, new_len)
+        } else {
+            self.changed_range.start.min(range.start)
+                ..map_offset_through_replace(self.changed_range.end.max(range.end), &range, new_len)
+        };
+    }
+
+    /// Inserts `text` at `offset` in the proposed text.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is out of bounds or not on a character boundary.
+    pub fn insert(&mut self, offset: usize, text: &str) {
+        self.replace(offset..offset, text);
+    }
+
+    /// Deletes `range` from the proposed text.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds or does not lie on character
+    /// boundaries.
+    pub fn delete(&mut self, range: Range<usize>) {
+        self.replace(range, "");
+    }
+
+    /// Keeps only the characters of the changed range that match `predicate`.
+    ///
+    /// Text outside the changed range is left untouched, so existing content
+    /// that predates the transformation is never rewritten.
+    pub fn retain_changed(&mut self, mut predicate: impl FnMut(char) -> bool) {
+        let filtered: String = self
+            .changed_text()
+            .chars()
+            .filter(|c| predicate(*c))
+            .collect();
+        if filtered.len() != self.changed_range.len() {
+            self.replace(self.changed_range(), &filtered);
+        }
+    }
+
+    /// Sets the selection in the proposed text.
+    ///
+    /// Offsets are clamped to the proposed text length.
+    pub fn set_selection(&mut self, anchor: usize, cursor: usize) {
+        self.selection = TextSelection {
+            start: anchor.min(self.text.len()),
+            end: cursor.min(self.text.len()),
+        };
+    }
+
+    /// Collapses the selection to `offset` in the proposed text.
+    pub fn place_cursor(&mut self, offset: usize) {
+        self.set_selection(offset, offset);
+    }
+
+    /// Rejects the edit, leaving the text and selection unchanged.
+    pub fn reject(&mut self) {
+        self.rejected = true;
+    }
+
+    /// Returns whether the edit has been rejected.
+    pub fn is_rejected(&self) -> bool {
+        self.rejected
+    }
+
+    pub(crate) fn proposed_selection(&self) -> TextSelection {
+        self.selection.clone()
+    }
+
+    pub(crate) fn into_text(self) -> String {
+        self.text
+    }
+
+    pub(crate) fn into_original(self) -> (String, TextSelection) {
+        (self.original_text, self.original_selection)
+    }
+}
+
+/// Edit-aware transformation applied to every user edit before it is committed
+/// to the text buffer.
+///
+/// The callback receives the proposed [`TextChange`] and returns it after
+/// accepting, modifying, or rejecting it. Edits applied programmatically
+/// through `set_text` bypass the transformation.
+pub type InputTransformation = CallbackWith<TextChange, TextChange>;
+
+/// Built-in [`InputTransformation`]s.
+///
+/// Each constructor creates a callback handle and must be called during a
+/// component build.
+pub struct InputTransformations;
+
+impl InputTransformations {
+    /// Accepts only ASCII digits from inserted text.
+    pub fn digits_only() -> InputTransformation {
+        CallbackWith::new(|mut change: TextChange| {
+            change.retain_changed(|c| c.is_ascii_digit());
+            change
+        })
+    }
+
+    /// Limits the text to at most `max_chars` characters.
+    ///
+    /// Inserted text is truncated to fit; an edit that cannot fit at all is
+    /// rejected.
+    pub fn max_length(max_chars: usize) -> InputTransformation {
+        CallbackWith::new(move |mut change: TextChange| {
+            apply_max_length(&mut change, max_chars);
+            change
+        })
+    }
+
+    /// Converts inserted text to uppercase.
+    pub fn uppercase() -> InputTransformation {
+        CallbackWith::new(|mut change: TextChange| {
+            let changed = change.changed_text();
+            let upper = changed.to_uppercase();
+            if upper != changed {
+                change.replace(change.changed_range(), &upper);
+            }
+            change
+        })
+    }
+
+    /// Applies `first` and then `second`, stopping early if `first` rejects the
+    /// edit.
+    pub fn chain(first: InputTransformation, second: InputTransformation) -> InputTransformation {
+        CallbackWith::new(move |change: TextChange| {
+            let change = first.call(change);
+            if change.is_rejected() {
+                change
+            } else {
+                second.call(change)
+            }
+        })
+    }
+}
+
+fn apply_max_length(change: &mut TextChange, max_chars: usize) {
+    let excess = change.char_count().saturating_sub(max_chars);
+    if excess == 0 {
+        return;
+    }
+    let changed_chars = change.changed_text().chars().count();
+    if changed_chars <= excess {
+        change.reject();
+        return;
+    }
+    let keep = changed_chars - excess;
+    let range = change.changed_range();
+    let cut = change
+        .changed_text()
+        .char_indices()
+        .nth(keep)
+        .map_or(range.end, |(index, _)| range.start + index);
+    change.delete(cut..range.end);
+}
+
+/// Fixed-pattern input mask pairing an [`InputTransformation`] with a
+/// [`DisplayTransform`].
+///
+/// The raw text only stores the characters typed into slots; the display
+/// transform inserts the literal characters of the pattern around them, so
+/// cursor movement and selection skip over separators.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputMask {
+    pattern: String,
+    slot: char,
+}
+
+impl InputMask {
+    /// Default slot character accepted by [`InputMask::new`] patterns.
+    pub const DIGIT_SLOT: char = '#';
+
+    /// Creates a digit mask where each `#` in `pattern` accepts one digit and
+    /// every other character is displayed literally.
+    pub fn new(pattern: impl Into<String>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            slot: Self::DIGIT_SLOT,
+        }
+    }
+
+    /// North American phone number mask, `(###) ###-####`.
+    pub fn phone() -> Self {
+        Self::new("(###) ###-####")
+    }
+
+    /// Credit card number mask, `#### #### #### ####`.
+    pub fn credit_card() -> Self {
+        Self::new("#### #### #### ####")
+    }
+
+    /// Numeric date mask, `##/##/####`.
+    pub fn date() -> Self {
+        Self::new("##/##/####")
+    }
+
+    /// Returns the number of slots in the pattern.
+    pub fn slot_count(&self) -> usize {
+        self.pattern.chars().filter(|c| *c == self.slot).count()
+    }
+
+    /// Formats raw slot characters with the pattern's literals.
+    ///
+    /// Literals are only emitted before a filled slot, so partially typed
+    /// values do not end in dangling separators.
+    pub fn format(&self, raw: &str) -> String {
+        let mut raw_chars = raw.chars().peekable();
+        let mut formatted = String::with_capacity(self.pattern.len());
+        let mut pending_literals = String::new();
+        for pattern_char in self.pattern.chars() {
+            if raw_chars.peek().is_none() {
+                break;
+            }
+            if pattern_char == self.slot {
+                formatted.push_str(&pending_literals);
+                pending_literals.clear();
+                if let Some(raw_char) = raw_chars.next() {
+                    formatted.push(raw_char);
+                }
+            } else {
+                pending_literals.push(pattern_char);
+            }
+        }
+        formatted.extend(raw_chars);
+        formatted
+    }
+
+    /// Creates the input transformation accepting digits up to the slot count.
+    ///
+    /// Must be called during a component build.
+    pub fn input_transformation(&self) -> InputTransformation {
+        let max_chars = self.slot_count();
+        CallbackWith::new(move |mut change: TextChange| {
+            change.retain_changed(|c| c.is_ascii_digit());
+            apply_max_length(&mut change, max_chars);
+            change
+        })
+    }
+
+    /// Creates the display transform rendering the raw digits with the
+    /// pattern's literals.
+    ///
+    /// Must be called during a component build.
+    pub fn display_transform(&self) -> DisplayTransform {
+        let mask = self.clone();
+        CallbackWith::new(move |raw: String| {
+            let formatted = mask.format(&raw);
+            TransformedText::from_strings(&raw, formatted)
+        })
+    }
+}
+
+fn map_offset_through_replace(offset: usize, range: &Range<usize>, new_len: usize) -> usize {
+    if offset <= range.start {
+        offset
+    } else if offset >= range.end {
+        offset - range.len() + new_len
+    } else {
+        range.start + new_len
+    }
+}
+
+fn diff_ranges(before: &str, after: &str) -> (Range<usize>, Range<usize>) {
+    let prefix = before
+        .char_indices()
+        .zip(after.chars())
+        .find(|((_, lhs), rhs)| lhs != rhs)
+        .map_or(before.len().min(after.len()), |((index, _), _)| index);
+    let max_suffix = before.len().min(after.len()) - prefix;
+    let suffix = before[prefix..]
+        .chars()
+        .rev()
+        .zip(after[prefix..].chars().rev())
+        .take_while(|(lhs, rhs)| lhs == rhs)
+        .map(|(lhs, _)| lhs.len_utf8())
+        .scan(0usize, |total, len| {
+            *total += len;
+            (*total <= max_suffix).then_some(*total)
+        })
+        .last()
+        .unwrap_or(0);
+    (prefix..before.len() - suffix, prefix..after.len() - suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InputMask, TextChange, TextEditKind, apply_max_length};
+    use crate::text_edit_core::TextSelection;
+
+    fn insertion(original: &str, offset: usize, inserted: &str) -> TextChange {
+        let mut text = original.to_string();
+        text.insert_str(offset, inserted);
+        TextChange::new(
+            TextEditKind::Paste,
+            original.to_string(),
+            TextSelection::collapsed(offset),
+            offset..offset,
+            text,
+            TextSelection::collapsed(offset + inserted.len()),
+        )
+    }
+
+    #[test]
+    fn retain_changed_filters_only_inserted_text_and_maps_cursor() {
+        let mut change = insertion("a1", 1, "2b3");
+        change.retain_changed(|c| c.is_ascii_digit());
+        assert_eq!(change.text(), "a231");
+        assert_eq!(change.changed_range(), 1..3);
+        assert_eq!(change.cursor(), 3);
+    }
+
+    #[test]
+    fn max_length_truncates_inserted_text_to_fit() {
+        let mut change = insertion("abc", 3, "defg");
+        apply_max_length(&mut change, 5);
+        assert!(!change.is_rejected());
+        assert_eq!(change.text(), "abcde");
+        assert_eq!(change.cursor(), 5);
+    }
+
+    #[test]
+    fn max_length_rejects_edit_that_cannot_fit() {
+        let mut change = insertion("abcde", 2, "x");
+        apply_max_length(&mut change, 5);
+        assert!(change.is_rejected());
+    }
+
+    #[test]
+    fn from_diff_detects_replaced_and_changed_ranges() {
+        let change = TextChange::from_diff(
+            TextEditKind::Delete,
+            "hello".to_string(),
+            TextSelection::collapsed(3),
+            "helo".to_string(),
+            TextSelection::collapsed(2),
+        );
+        assert_eq!(change.replaced_range(), 3..4);
+        assert!(change.changed_range().is_empty());
+    }
+
+    #[test]
+    fn input_mask_formats_without_trailing_literals() {
+        let mask = InputMask::phone();
+        assert_eq!(mask.slot_count(), 10);
+        assert_eq!(mask.format(""), "");
+        assert_eq!(mask.format("555"), "(555");
+        assert_eq!(mask.format("5551"), "(555) 1");
+        assert_eq!(mask.format("5551234567"), "(555) 123-4567");
+        assert_eq!(InputMask::date().format("0102"), "01/02");
+    }
+}
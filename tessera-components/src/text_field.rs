@@ -26,8 +26,8 @@ use crate::{
     text::text,
     text_edit_core::DisplayTransform,
     text_input::{
-        DisplayTransformText, TextInputController, TextInputProps, create_surface_args,
-        text_input_core,
+        DisplayTransformText, InputMask, InputTransformation, TextInputController, TextInputProps,
+        create_surface_args, text_input_core,
     },
     theme::{ContentColor, MaterialColorScheme, MaterialTheme, TextSelectionColors, TextStyle},
 };
@@ -199,6 +199,8 @@ struct TextFieldProps {
     pub context_menu: TextFieldContextMenu,
     /// Optional transform applied to text changes before on_change.
     pub input_transform: Option<CallbackWith<String, String>>,
+    /// Optional edit-aware transformation applied to each user edit.
+    pub input_transformation: Option<InputTransformation>,
     /// Optional obfuscation character for secure fields.
     pub obfuscation_char: Option<char>,
     /// Optional transform applied only for display.
@@ -268,6 +270,14 @@ impl TextFieldBuilder {
             .line_limit(TextFieldLineLimit::SingleLine)
             .context_menu(TextFieldContextMenu::secure_default())
     }
+
+    /// Applies an input mask, installing both its input transformation and
+    /// its matching display transform.
+    pub fn input_mask(mut self, mask: InputMask) -> Self {
+        self.props.input_transformation = Some(mask.input_transformation());
+        self.props.display_transform = Some(mask.display_transform());
+        self
+    }
 }
 
 impl Default for TextFieldProps {
@@ -312,6 +322,7 @@ impl Default for TextFieldProps {
             line_limit: TextFieldLineLimit::SingleLine,
             context_menu: TextFieldContextMenu::default(),
             input_transform: None,
+            input_transformation: None,
             obfuscation_char: None,
             display_transform: None,
             controller: None,
@@ -405,6 +416,7 @@ fn build_editor_args(
         line_height: args.line_height,
         single_line: matches!(args.line_limit, TextFieldLineLimit::SingleLine),
         input_transform,
+        input_transformation: args.input_transformation,
         display_transform,
         controller: None,
     }
@@ -1040,6 +1052,9 @@ fn configure_text_field_menu(
 /// - `line_limit` — text line limit policy.
 /// - `context_menu` — context menu policy.
 /// - `input_transform` — optional input transform callback.
+/// - `input_transformation` — optional edit-aware input transformation; see
+///   [`InputTransformations`](crate::text_input::InputTransformations) and
+///   [`TextFieldBuilder::input_mask`].
 /// - `obfuscation_char` — optional obfuscation character.
 /// - `display_transform` — optional display transform.
 /// - `controller` — optional external text input controller.
@@ -1098,6 +1113,7 @@ pub fn text_field(
     line_limit: Option<TextFieldLineLimit>,
     context_menu: Option<TextFieldContextMenu>,
     input_transform: Option<CallbackWith<String, String>>,
+    input_transformation: Option<InputTransformation>,
     obfuscation_char: Option<char>,
     #[prop(skip_setter)] display_transform: Option<DisplayTransform>,
     controller: Option<State<TextInputController>>,
@@ -1196,6 +1212,7 @@ pub fn text_field(
         line_limit,
         context_menu,
         input_transform,
+        input_transformation,
         obfuscation_char,
         display_transform,
         controller,
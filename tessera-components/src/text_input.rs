@@ -6,13 +6,19 @@
 use glyphon::Action as GlyphonAction;
 use tessera_foundation::gesture::{ScrollRecognizer, ScrollResult, TapRecognizer};
 use tessera_ui::{
-    AccessibilityActionHandler, AccessibilityNode, Callback, CallbackWith, Color, ComputedData, Dp,
-    ImeInput, ImeInputModifierNode, ImeRequest, KeyboardInput, KeyboardInputModifierNode, Modifier,
+    AccessibilityActionHandler, AccessibilityNode, AccessibilityTextAction,
+    AccessibilityTextActionHandler, Callback, CallbackWith, Color, ComputedData, Dp, ImeInput,
+    ImeInputModifierNode, ImeRequest, KeyboardInput, KeyboardInputModifierNode, Modifier,
     PointerInput, PointerInputModifierNode, Px, PxPosition, PxSize, SemanticsModifierNode, State,
     accesskit::{Action, Role},
     layout::layout,
-    modifier::{CursorModifierExt as _, FocusModifierExt as _, ModifierCapabilityExt as _},
-    remember, tessera, use_context, winit,
+    modifier::{
+        CommandModifierExt as _, CursorModifierExt as _, FocusModifierExt as _,
+        ModifierCapabilityExt as _,
+    },
+    remember,
+    shortcut::commands,
+    tessera, use_context, winit,
 };
 
 use crate::{
@@ -32,7 +38,8 @@ use glyphon::Edit;
 /// State structure for the text input, managing text content, cursor,
 /// selection, and editing logic.
 pub use crate::text_edit_core::{
-    DisplayTransform, TextEditorController as TextInputController,
+    DisplayTransform, InputMask, InputTransformation, InputTransformations, TextChange,
+    TextEditKind, TextEditorController as TextInputController,
     TransformedText as DisplayTransformText,
 };
 
@@ -97,7 +104,11 @@ fn apply_text_input_input_modifiers(
     scroll_recognizer: State<ScrollRecognizer>,
 ) -> Modifier {
     let modifier = if args.enabled {
-        base.hover_cursor_icon(winit::window::CursorIcon::Text)
+        apply_text_input_command_handlers(
+            base.hover_cursor_icon(winit::window::CursorIcon::Text),
+            &args,
+            controller,
+        )
     } else {
         base
     };
@@ -119,6 +130,36 @@ fn apply_text_input_input_modifiers(
         .push_ime_input(TextInputImeModifierNode { args, controller })
 }
 
+/// Handles the edit commands so rebound shortcuts and menu items reach the
+/// focused editor.
+fn apply_text_input_command_handlers(
+    modifier: Modifier,
+    args: &TextInputProps,
+    controller: State<TextInputController>,
+) -> Modifier {
+    let modifier = modifier
+        .on_command(commands::COPY, move || {
+            controller.with(|s| s.copy_selection_to_clipboard());
+        })
+        .on_command(commands::SELECT_ALL, move || {
+            controller.with_mut(|s| s.select_all());
+        });
+    if args.read_only {
+        return modifier;
+    }
+
+    let on_change = args.on_change;
+    let input_transform = args.input_transform;
+    modifier
+        .on_command(commands::CUT, move || {
+            controller.with_mut(|s| s.cut_selection_with_pipeline(on_change, input_transform));
+        })
+        .on_command(commands::PASTE, move || {
+            controller
+                .with_mut(|s| s.paste_from_clipboard_with_pipeline(on_change, input_transform));
+        })
+}
+
 #[derive(Clone, PartialEq)]
 pub(crate) struct TextInputProps {
     /// Whether the editor is enabled for user input.
@@ -178,6 +219,8 @@ pub(crate) struct TextInputProps {
     pub single_line: bool,
     /// Optional transform applied to text changes before on_change.
     pub input_transform: Option<CallbackWith<String, String>>,
+    /// Optional edit-aware transformation applied to each user edit.
+    pub input_transformation: Option<InputTransformation>,
     /// Optional transform applied only for display.
     pub display_transform: Option<DisplayTransform>,
     /// Optional external controller for text, cursor, and selection state.
@@ -224,6 +267,7 @@ impl Default for TextInputProps {
             line_height: None,
             single_line: false,
             input_transform: None,
+            input_transformation: None,
             display_transform: None,
             controller: None,
         }
@@ -311,6 +355,8 @@ impl TextInputBuilder {
 /// - `single_line` — whether the editor behaves as a single-line field.
 /// - `input_transform` — optional transform applied to text changes before
 ///   `on_change`.
+/// - `input_transformation` — optional edit-aware transformation that can
+///   accept, modify, or reject each user edit.
 /// - `display_transform` — optional display-only transform.
 /// - `controller` — optional external controller for text, cursor, and
 ///   selection state.
@@ -359,6 +405,7 @@ pub fn text_input(
     line_height: Option<Dp>,
     single_line: Option<bool>,
     input_transform: Option<CallbackWith<String, String>>,
+    input_transformation: Option<InputTransformation>,
     display_transform: Option<DisplayTransform>,
     controller: Option<State<TextInputController>>,
 ) {
@@ -431,6 +478,7 @@ pub fn text_input(
         line_height,
         single_line,
         input_transform,
+        input_transformation,
         display_transform,
         controller,
     };
@@ -558,6 +606,12 @@ fn sync_text_input_controller(controller: &State<TextInputController>, args: &Te
     if needs_display_transform_update {
         controller.with_mut(|c| c.set_display_transform(display_transform));
     }
+    let input_transformation = args.input_transformation;
+    let needs_input_transformation_update =
+        controller.with(|c| c.input_transformation() != input_transformation);
+    if needs_input_transformation_update {
+        controller.with_mut(|c| c.set_input_transformation(input_transformation));
+    }
     let needs_single_line_update = controller.with(|c| c.single_line() != args.single_line);
     if needs_single_line_update {
         controller.with_mut(|c| c.set_single_line(args.single_line));
@@ -838,7 +892,6 @@ fn handle_text_input_keyboard(
     }
 
     let is_ctrl = input.key_modifiers.control_key() || input.key_modifiers.super_key();
-    let is_shift = input.key_modifiers.shift_key();
     let mut all_actions = Vec::new();
     let mut should_block_keyboard = false;
     for key_event in input.keyboard_events.iter().cloned() {
@@ -857,34 +910,6 @@ fn handle_text_input_keyboard(
             continue;
         }
 
-        if let Some(behavior) =
-            clipboard_shortcut_for_key(is_ctrl, is_shift, key_event.state, &key_event.logical_key)
-        {
-            should_block_keyboard = true;
-            match behavior {
-                ClipboardShortcutBehavior::Copy => {
-                    controller.with(|s| {
-                        s.copy_selection_to_clipboard();
-                    });
-                }
-                ClipboardShortcutBehavior::Cut => {
-                    if !args.read_only {
-                        controller.with_mut(|s| {
-                            s.cut_selection_with_pipeline(*on_change, *input_transform);
-                        });
-                    }
-                }
-                ClipboardShortcutBehavior::Paste => {
-                    if !args.read_only {
-                        controller.with_mut(|s| {
-                            s.paste_from_clipboard_with_pipeline(*on_change, *input_transform);
-                        });
-                    }
-                }
-            }
-            continue;
-        }
-
         if let Some(motion) =
             deletion_motion_for_key(is_ctrl, key_event.state, &key_event.logical_key)
         {
@@ -898,46 +923,6 @@ fn handle_text_input_keyboard(
         }
 
         should_block_keyboard = true;
-        let shortcut = if let winit::keyboard::Key::Character(s) = &key_event.logical_key {
-            if is_ctrl && key_event.state == winit::event::ElementState::Pressed {
-                Some(s.to_lowercase())
-            } else {
-                None
-            }
-        } else {
-            None
-        };
-
-        match shortcut.as_deref() {
-            Some("a") => {
-                controller.with_mut(|s| s.select_all());
-                continue;
-            }
-            Some("c") => {
-                controller.with(|s| {
-                    s.copy_selection_to_clipboard();
-                });
-                continue;
-            }
-            Some("x") => {
-                if !args.read_only {
-                    controller.with_mut(|s| {
-                        s.cut_selection_with_pipeline(*on_change, *input_transform);
-                    });
-                }
-                continue;
-            }
-            Some("v") => {
-                if !args.read_only {
-                    controller.with_mut(|s| {
-                        s.paste_from_clipboard_with_pipeline(*on_change, *input_transform);
-                    });
-                }
-                continue;
-            }
-            _ => {}
-        }
-
         controller.with_mut(|s| {
             if let Some(actions) = s.map_key_event_to_action(key_event, input.key_modifiers) {
                 all_actions.extend(actions);
@@ -993,11 +978,18 @@ fn handle_text_input_ime(
                 }
             }
             PlannedImeEvent::Edit(plan) => {
+                // Preedit text is provisional and bypasses the input
+                // transformation until it is committed.
+                let edit_kind = plan
+                    .composition_range
+                    .is_none()
+                    .then_some(TextEditKind::ImeCommit);
                 let result = replace_text_range_with_selection(
                     controller,
                     plan.replacement_range.clone(),
                     &plan.replacement_text,
                     plan.selection.clone(),
+                    edit_kind,
                     *on_change,
                     *input_transform,
                 );
@@ -1062,13 +1054,6 @@ enum SingleLineKeyBehavior {
     Propagate,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum ClipboardShortcutBehavior {
-    Copy,
-    Cut,
-    Paste,
-}
-
 fn deletion_motion_for_key(
     is_ctrl: bool,
     key_state: winit::event::ElementState,
@@ -1089,30 +1074,6 @@ fn deletion_motion_for_key(
     }
 }
 
-fn clipboard_shortcut_for_key(
-    is_ctrl: bool,
-    is_shift: bool,
-    key_state: winit::event::ElementState,
-    logical_key: &winit::keyboard::Key,
-) -> Option<ClipboardShortcutBehavior> {
-    if key_state != winit::event::ElementState::Pressed {
-        return None;
-    }
-
-    match logical_key {
-        winit::keyboard::Key::Named(winit::keyboard::NamedKey::Insert) if is_ctrl => {
-            Some(ClipboardShortcutBehavior::Copy)
-        }
-        winit::keyboard::Key::Named(winit::keyboard::NamedKey::Insert) if is_shift => {
-            Some(ClipboardShortcutBehavior::Paste)
-        }
-        winit::keyboard::Key::Named(winit::keyboard::NamedKey::Delete) if is_shift => {
-            Some(ClipboardShortcutBehavior::Cut)
-        }
-        _ => None,
-    }
-}
-
 fn single_line_key_behavior(
     single_line: bool,
     key_state: winit::event::ElementState,
@@ -1147,6 +1108,7 @@ fn replace_text_range_with_selection(
     range: std::ops::Range<usize>,
     replacement: &str,
     selection: TextSelection,
+    edit_kind: Option<TextEditKind>,
     on_change: CallbackWith<String, String>,
     input_transform: Option<CallbackWith<String, String>>,
 ) -> ImeEditResult {
@@ -1155,6 +1117,7 @@ fn replace_text_range_with_selection(
             range,
             replacement,
             selection,
+            edit_kind,
             on_change,
             input_transform,
         )
@@ -1174,6 +1137,10 @@ impl SemanticsModifierNode for TextInputSemanticsModifierNode {
     ) {
         apply_text_input_semantics(accessibility, action_handler, &self.args, &self.controller);
     }
+
+    fn apply_text_actions(&self, handler: &mut Option<AccessibilityTextActionHandler>) {
+        *handler = text_input_text_action_handler(&self.args, self.controller);
+    }
 }
 
 #[cfg(test)]
@@ -1482,6 +1449,35 @@ fn apply_text_input_semantics(
     }
 }
 
+fn text_input_text_action_handler(
+    args: &TextInputProps,
+    state: State<TextInputController>,
+) -> Option<AccessibilityTextActionHandler> {
+    if !args.enabled {
+        return None;
+    }
+    let read_only = args.read_only;
+    let on_change = args.on_change;
+    let input_transform = args.input_transform;
+    Some(Box::new(move |action| match action {
+        AccessibilityTextAction::SetSelection(selection) => {
+            state.with_mut(|c| c.set_display_selection(selection));
+        }
+        AccessibilityTextAction::ReplaceSelectedText(text) => {
+            if !read_only {
+                state.with_mut(|c| {
+                    c.replace_selected_text_with_pipeline(
+                        &text,
+                        TextEditKind::Insert,
+                        on_change,
+                        input_transform,
+                    );
+                });
+            }
+        }
+    }))
+}
+
 fn text_input_accessibility_role(single_line: bool) -> Role {
     if single_line {
         Role::TextInput
@@ -1503,7 +1499,10 @@ mod tests {
     use std::time::Duration;
 
     use glyphon::{Action as GlyphonAction, Edit as _};
-    use tessera_ui::{ComputedData, Px, PxPosition, PxSize, accesskit::Role, time::Instant, winit};
+    use tessera_ui::{
+        ComputedData, Px, PxPosition, PxSize, accesskit::Role, shortcut::commands, time::Instant,
+        winit,
+    };
 
     use crate::text_edit_core::{
         ClickType, ImeComposition, PlannedImeEdit, PlannedImeEvent, RectDef, TextEditorController,
@@ -1511,11 +1510,10 @@ mod tests {
     };
 
     use super::{
-        ClipboardShortcutBehavior, DragSelectionPointerPosition, SingleLineKeyBehavior,
-        build_ime_request, clipboard_shortcut_for_key, deletion_motion_for_key,
-        drag_selection_pointer_position_with_scroll, editor_selection, rebase_offset, rebase_range,
-        rebase_selection, should_expose_submit_accessibility_action, single_line_key_behavior,
-        text_content_origin_from_values, text_input_accessibility_role,
+        DragSelectionPointerPosition, SingleLineKeyBehavior, build_ime_request,
+        deletion_motion_for_key, drag_selection_pointer_position_with_scroll, editor_selection,
+        rebase_offset, rebase_range, rebase_selection, should_expose_submit_accessibility_action,
+        single_line_key_behavior, text_content_origin_from_values, text_input_accessibility_role,
         text_viewport_size_from_origin,
     };
 
@@ -1539,7 +1537,6 @@ mod tests {
     #[derive(Clone, Copy, Default)]
     struct SimulatedKeyboardPipeline {
         is_ctrl: bool,
-        is_shift: bool,
         read_only: bool,
         input_transform: Option<fn(String) -> String>,
         on_change: Option<fn(String) -> String>,
@@ -1769,6 +1766,48 @@ mod tests {
         controller.deletion_range_for_motion(motion)
     }
 
+    /// Mirrors the handlers installed by `apply_text_input_command_handlers`.
+    fn apply_text_input_command(
+        state: &mut SimulatedTextInputSession,
+        command: &str,
+        pipeline: SimulatedKeyboardPipeline,
+    ) {
+        match command {
+            commands::SELECT_ALL => {
+                state.selection = TextSelection {
+                    start: 0,
+                    end: state.text.len(),
+                };
+            }
+            commands::COPY => {
+                if let Some(text) = selected_text_in_text_input_session(state) {
+                    state.clipboard = text;
+                }
+            }
+            commands::CUT if !pipeline.read_only => {
+                if let Some(text) = selected_text_in_text_input_session(state) {
+                    state.clipboard = text;
+                    replace_selected_text_in_text_input_session(
+                        state,
+                        "",
+                        pipeline.input_transform,
+                        pipeline.on_change,
+                    );
+                }
+            }
+            commands::PASTE if !pipeline.read_only => {
+                let text = state.clipboard.clone();
+                replace_selected_text_in_text_input_session(
+                    state,
+                    &text,
+                    pipeline.input_transform,
+                    pipeline.on_change,
+                );
+            }
+            _ => {}
+        }
+    }
+
     fn apply_text_input_keyboard_event(
         state: &mut SimulatedTextInputSession,
         key_state: winit::event::ElementState,
@@ -1786,43 +1825,6 @@ mod tests {
             return;
         }
 
-        if let Some(behavior) =
-            clipboard_shortcut_for_key(pipeline.is_ctrl, pipeline.is_shift, key_state, &logical_key)
-        {
-            match behavior {
-                ClipboardShortcutBehavior::Copy => {
-                    if let Some(text) = selected_text_in_text_input_session(state) {
-                        state.clipboard = text;
-                    }
-                }
-                ClipboardShortcutBehavior::Cut => {
-                    if !pipeline.read_only
-                        && let Some(text) = selected_text_in_text_input_session(state)
-                    {
-                        state.clipboard = text;
-                        replace_selected_text_in_text_input_session(
-                            state,
-                            "",
-                            pipeline.input_transform,
-                            pipeline.on_change,
-                        );
-                    }
-                }
-                ClipboardShortcutBehavior::Paste => {
-                    if !pipeline.read_only {
-                        let text = state.clipboard.clone();
-                        replace_selected_text_in_text_input_session(
-                            state,
-                            &text,
-                            pipeline.input_transform,
-                            pipeline.on_change,
-                        );
-                    }
-                }
-            }
-            return;
-        }
-
         if let Some(motion) = deletion_motion_for_key(pipeline.is_ctrl, key_state, &logical_key) {
             if !pipeline.read_only
                 && let Some(range) = deletion_range_in_text_input_session(state, motion)
@@ -1838,59 +1840,6 @@ mod tests {
             return;
         }
 
-        let shortcut = if let winit::keyboard::Key::Character(text) = &logical_key {
-            if pipeline.is_ctrl && key_state == winit::event::ElementState::Pressed {
-                Some(text.to_lowercase())
-            } else {
-                None
-            }
-        } else {
-            None
-        };
-
-        match shortcut.as_deref() {
-            Some("a") => {
-                state.selection = TextSelection {
-                    start: 0,
-                    end: state.text.len(),
-                };
-                return;
-            }
-            Some("c") => {
-                if let Some(text) = selected_text_in_text_input_session(state) {
-                    state.clipboard = text;
-                }
-                return;
-            }
-            Some("x") => {
-                if !pipeline.read_only
-                    && let Some(text) = selected_text_in_text_input_session(state)
-                {
-                    state.clipboard = text;
-                    replace_selected_text_in_text_input_session(
-                        state,
-                        "",
-                        pipeline.input_transform,
-                        pipeline.on_change,
-                    );
-                }
-                return;
-            }
-            Some("v") => {
-                if !pipeline.read_only {
-                    let text = state.clipboard.clone();
-                    replace_selected_text_in_text_input_session(
-                        state,
-                        &text,
-                        pipeline.input_transform,
-                        pipeline.on_change,
-                    );
-                }
-                return;
-            }
-            _ => {}
-        }
-
         if key_state != winit::event::ElementState::Pressed {
             return;
         }
@@ -2531,7 +2480,7 @@ mod tests {
     }
 
     #[test]
-    fn text_input_keyboard_sequence_shift_delete_cuts_selection_and_ctrl_v_pastes_it_back() {
+    fn text_input_cut_command_cuts_selection_and_paste_command_pastes_it_back() {
         let mut state = SimulatedTextInputSession {
             text: "hello world".to_string(),
             selection: TextSelection { start: 6, end: 11 },
@@ -2541,28 +2490,12 @@ mod tests {
             clipboard: String::new(),
         };
 
-        apply_text_input_keyboard_event(
-            &mut state,
-            winit::event::ElementState::Pressed,
-            winit::keyboard::Key::Named(winit::keyboard::NamedKey::Delete),
-            SimulatedKeyboardPipeline {
-                is_shift: true,
-                ..Default::default()
-            },
-        );
+        apply_text_input_command(&mut state, commands::CUT, Default::default());
         assert_eq!(state.text, "hello ");
         assert_eq!(state.selection, TextSelection::collapsed(6));
         assert_eq!(state.clipboard, "world");
 
-        apply_text_input_keyboard_event(
-            &mut state,
-            winit::event::ElementState::Pressed,
-            winit::keyboard::Key::Character("v".into()),
-            SimulatedKeyboardPipeline {
-                is_ctrl: true,
-                ..Default::default()
-            },
-        );
+        apply_text_input_command(&mut state, commands::PASTE, Default::default());
         assert_eq!(state.text, "hello world");
         assert_eq!(state.selection, TextSelection::collapsed(11));
         assert_eq!(state.clipboard, "world");
@@ -2614,7 +2547,7 @@ mod tests {
     }
 
     #[test]
-    fn text_input_keyboard_sequence_ctrl_a_then_ctrl_c_copies_full_selection() {
+    fn text_input_select_all_then_copy_commands_copy_full_selection() {
         let mut state = SimulatedTextInputSession {
             text: "hello world".to_string(),
             selection: TextSelection::collapsed(5),
@@ -2624,24 +2557,8 @@ mod tests {
             clipboard: String::new(),
         };
 
-        apply_text_input_keyboard_event(
-            &mut state,
-            winit::event::ElementState::Pressed,
-            winit::keyboard::Key::Character("a".into()),
-            SimulatedKeyboardPipeline {
-                is_ctrl: true,
-                ..Default::default()
-            },
-        );
-        apply_text_input_keyboard_event(
-            &mut state,
-            winit::event::ElementState::Pressed,
-            winit::keyboard::Key::Character("c".into()),
-            SimulatedKeyboardPipeline {
-                is_ctrl: true,
-                ..Default::default()
-            },
-        );
+        apply_text_input_command(&mut state, commands::SELECT_ALL, Default::default());
+        apply_text_input_command(&mut state, commands::COPY, Default::default());
         assert_eq!(
             state.selection,
             TextSelection {
@@ -2653,7 +2570,31 @@ mod tests {
     }
 
     #[test]
-    fn text_input_keyboard_sequence_read_only_paste_does_not_mutate_text() {
+    fn text_input_read_only_paste_command_does_not_mutate_text() {
+        let mut state = SimulatedTextInputSession {
+            text: "hello".to_string(),
+            selection: TextSelection::collapsed(5),
+            composition: None,
+            single_line: false,
+            submit_count: 0,
+            clipboard: " world".to_string(),
+        };
+
+        apply_text_input_command(
+            &mut state,
+            commands::PASTE,
+            SimulatedKeyboardPipeline {
+                read_only: true,
+                ..Default::default()
+            },
+        );
+        assert_eq!(state.text, "hello");
+        assert_eq!(state.selection, TextSelection::collapsed(5));
+        assert_eq!(state.clipboard, " world");
+    }
+
+    #[test]
+    fn text_input_keyboard_sequence_unbound_ctrl_character_is_not_inserted() {
         let mut state = SimulatedTextInputSession {
             text: "hello".to_string(),
             selection: TextSelection::collapsed(5),
@@ -2663,18 +2604,18 @@ mod tests {
             clipboard: " world".to_string(),
         };
 
+        // This pipeline skips shortcut dispatch, so Ctrl+V arrives as a plain key,
+        // as it would after the user unbinds `edit.paste`.
         apply_text_input_keyboard_event(
             &mut state,
             winit::event::ElementState::Pressed,
             winit::keyboard::Key::Character("v".into()),
             SimulatedKeyboardPipeline {
                 is_ctrl: true,
-                read_only: true,
                 ..Default::default()
             },
         );
         assert_eq!(state.text, "hello");
-        assert_eq!(state.selection, TextSelection::collapsed(5));
         assert_eq!(state.clipboard, " world");
     }
 
@@ -2958,55 +2899,14 @@ mod tests {
     }
 
     #[test]
-    fn ctrl_insert_uses_copy_clipboard_shortcut_behavior() {
-        assert_eq!(
-            clipboard_shortcut_for_key(
-                true,
-                false,
-                winit::event::ElementState::Pressed,
-                &winit::keyboard::Key::Named(winit::keyboard::NamedKey::Insert),
-            ),
-            Some(ClipboardShortcutBehavior::Copy)
-        );
-    }
-
-    #[test]
-    fn shift_insert_uses_paste_clipboard_shortcut_behavior() {
-        assert_eq!(
-            clipboard_shortcut_for_key(
-                false,
-                true,
-                winit::event::ElementState::Pressed,
-                &winit::keyboard::Key::Named(winit::keyboard::NamedKey::Insert),
-            ),
-            Some(ClipboardShortcutBehavior::Paste)
-        );
-    }
-
-    #[test]
-    fn shift_delete_uses_cut_clipboard_shortcut_behavior() {
-        assert_eq!(
-            clipboard_shortcut_for_key(
-                false,
-                true,
-                winit::event::ElementState::Pressed,
-                &winit::keyboard::Key::Named(winit::keyboard::NamedKey::Delete),
-            ),
-            Some(ClipboardShortcutBehavior::Cut)
-        );
-    }
-
-    #[test]
-    fn released_insert_does_not_trigger_clipboard_shortcut_behavior() {
-        assert_eq!(
-            clipboard_shortcut_for_key(
-                true,
-                false,
-                winit::event::ElementState::Released,
-                &winit::keyboard::Key::Named(winit::keyboard::NamedKey::Insert),
-            ),
-            None
-        );
+    fn legacy_clipboard_keys_are_bound_to_edit_commands() {
+        let bound = |command: &str, sequence: &str| {
+            tessera_ui::shortcut::command_bindings(command)
+                .contains(&sequence.parse().expect("valid sequence"))
+        };
+        assert!(bound(commands::COPY, "Ctrl+Insert"));
+        assert!(bound(commands::PASTE, "Shift+Insert"));
+        assert!(bound(commands::CUT, "Shift+Delete"));
     }
 
     #[test]
@@ -8,7 +8,10 @@ use material_color_utilities::{
     dynamiccolor::{DynamicSchemeBuilder, MaterialDynamicColors, SpecVersion, Variant},
     hct::Hct,
 };
-use tessera_ui::{CallbackWith, Color, Dp, RenderSlot, provide_context, tessera};
+use tessera_foundation::overlay::{has_overlay_host, overlay_host};
+use tessera_ui::{
+    AssetTheme, CallbackWith, Color, Dp, RenderSlot, provide_context, tessera, use_context,
+};
 
 use crate::shape_def::Shape;
 
@@ -265,6 +268,9 @@ impl MaterialTheme {
 /// ## Usage
 ///
 /// Wrap your app (or a subtree) to configure defaults for Material components.
+/// The outermost theme also hosts the overlay layer used by dialogs, menus
+/// and sheets. Dark color schemes also provide a `dark` [`AssetTheme`] so
+/// generated assets resolve their dark variants.
 ///
 /// ## Parameters
 ///
@@ -302,11 +308,38 @@ pub fn material_theme(theme: Option<CallbackWith<(), MaterialTheme>>, child: Opt
     provide_context(
         move || theme.call(()),
         move || {
-            child.render();
+            let is_dark = use_context::<MaterialTheme>()
+                .expect("MaterialTheme must be provided")
+                .with(|theme| theme.color_scheme.is_dark);
+            let asset_theme = asset_theme_for(is_dark);
+            provide_context(
+                || asset_theme.clone(),
+                move || {
+                    let context = use_context::<AssetTheme>().expect("AssetTheme must be provided");
+                    if context.with(|current| *current != asset_theme) {
+                        context.set(asset_theme);
+                    }
+                    if has_overlay_host() {
+                        child.render();
+                    } else {
+                        overlay_host().content_shared(child);
+                    }
+                },
+            );
         },
     );
 }
 
+/// Returns the asset theme that selects `dark` asset variants for dark color
+/// schemes.
+fn asset_theme_for(is_dark: bool) -> AssetTheme {
+    if is_dark {
+        AssetTheme::new("dark")
+    } else {
+        AssetTheme::default()
+    }
+}
+
 /// A Material Design color scheme, which can be light or dark,
 /// produced from a seed color.
 #[derive(Clone, PartialEq, Debug)]
@@ -14,6 +14,7 @@ use crate::{
     alignment::{Alignment, CrossAxisAlignment, MainAxisAlignment},
     column::column,
     modifier::ModifierExt as _,
+    res::strings,
     row::row,
     shape_def::Shape,
     spacer::spacer,
@@ -310,7 +311,7 @@ fn time_picker_inner(args: TimePickerConfig) {
                     let hour_display = hour_display.clone();
                     {
                         time_stepper_column(
-                            "Hour",
+                            strings::time_picker_hour(),
                             hour_display.clone(),
                             show_labels,
                             Callback::new(move || {
@@ -341,7 +342,7 @@ fn time_picker_inner(args: TimePickerConfig) {
                     {
                         let minute_display = minute_display.clone();
                         time_stepper_column(
-                            "Minute",
+                            strings::time_picker_minute(),
                             minute_display,
                             show_labels,
                             Callback::new(move || {
@@ -457,7 +458,8 @@ pub fn time_picker_dialog(
                     .cross_axis_alignment(CrossAxisAlignment::Center)
                     .children(move || {
                         {
-                            let title_text = title.as_deref().unwrap_or("Select time");
+                            let title_text =
+                                title.clone().unwrap_or_else(strings::time_picker_title);
                             text()
                                 .content(title_text)
                                 .size(
@@ -532,7 +534,7 @@ pub fn time_picker_dialog(
 }
 
 fn time_stepper_column(
-    label: &'static str,
+    label: String,
     value: String,
     show_label: bool,
     on_increment: Callback,
@@ -569,7 +571,7 @@ fn time_stepper_column(
                 };
                 {
                     text()
-                        .content(label)
+                        .content(label.clone())
                         .style(TextStyle {
                             font_size: typography.label_small.font_size,
                             line_height: typography.label_small.line_height,
@@ -645,23 +647,18 @@ fn period_toggle(is_pm: bool, state: State<TimePickerState>) {
         .cross_axis_alignment(CrossAxisAlignment::Center)
         .children(move || {
             {
-                period_button("AM", !is_pm, DayPeriod::Am, state);
+                period_button(strings::time_picker_am(), !is_pm, DayPeriod::Am, state);
             };
             {
                 spacer().modifier(Modifier::new().width(Dp(8.0)));
             };
             {
-                period_button("PM", is_pm, DayPeriod::Pm, state);
+                period_button(strings::time_picker_pm(), is_pm, DayPeriod::Pm, state);
             };
         });
 }
 
-fn period_button(
-    label: &'static str,
-    selected: bool,
-    period: DayPeriod,
-    state: State<TimePickerState>,
-) {
+fn period_button(label: String, selected: bool, period: DayPeriod, state: State<TimePickerState>) {
     let scheme = use_context::<MaterialTheme>()
         .expect("MaterialTheme must be provided")
         .get()
@@ -694,7 +691,7 @@ fn period_button(
         })
         .child(move || {
             text()
-                .content(label)
+                .content(label.clone())
                 .size(
                     use_context::<MaterialTheme>()
                         .expect("MaterialTheme must be provided")
@@ -713,8 +710,8 @@ fn time_display_mode_toggle(state: State<TimePickerState>) {
         .get()
         .color_scheme;
     let label = state.with(|s| match s.display_mode() {
-        TimePickerDisplayMode::Picker => "Input",
-        TimePickerDisplayMode::Input => "Picker",
+        TimePickerDisplayMode::Picker => strings::time_picker_switch_to_input(),
+        TimePickerDisplayMode::Input => strings::time_picker_switch_to_picker(),
     });
     surface()
         .modifier(Modifier::new().padding_all(Dp(4.0)))
@@ -728,7 +725,7 @@ fn time_display_mode_toggle(state: State<TimePickerState>) {
         })
         .child(move || {
             text()
-                .content(label)
+                .content(label.clone())
                 .size(
                     use_context::<MaterialTheme>()
                         .expect("MaterialTheme must be provided")
@@ -0,0 +1,1830 @@
+//! Material tree views for browsing hierarchical data.
+//!
+//! ## Usage
+//!
+//! Show file hierarchies and document outlines whose nodes expand to reveal
+//! their children.
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
+
+use tessera_foundation::gesture::{DragRecognizer, DragSettings, TapRecognizer, TapSettings};
+use tessera_ui::{
+    AccessibilityActionHandler, AccessibilityActionHandlers, AccessibilityNode, CallbackWith, Dp,
+    FocusProperties, FocusRequester, FrameNanosControl, Modifier, Px, RenderSlotWith,
+    SemanticsModifierNode, State,
+    accesskit::{Action, Role},
+    current_frame_nanos, key,
+    modifier::{FocusModifierExt as _, ModifierCapabilityExt as _},
+    receive_frame_nanos, remember,
+    shortcut::commands,
+    tessera, use_context, winit,
+};
+
+use crate::{
+    alignment::{Alignment, CrossAxisAlignment},
+    animation::easing,
+    boxed::boxed,
+    column::column,
+    divider::horizontal_divider,
+    icon::icon,
+    lazy_list::{LazyListController, LazyListRoles, lazy_column},
+    material_icons::filled,
+    modifier::{ModifierExt as _, with_keyboard_input, with_pointer_input},
+    pos_misc::is_position_inside_bounds,
+    res::strings,
+    row::row,
+    selection::{
+        AnchoredSelection, SelectionActions, SelectionGesture, SelectionMode, SelectionOwner,
+        selection_gesture,
+    },
+    spacer::spacer,
+    surface::{SurfaceStyle, surface},
+    text::text,
+    theme::{MaterialTheme, provide_text_style},
+};
+
+/// Duration of the expand and collapse animation.
+const EXPAND_ANIMATION_DURATION: Duration = Duration::from_millis(200);
+/// Band along the top and bottom of the viewport where a dragged node
+/// scrolls the tree.
+const AUTO_SCROLL_EDGE: Dp = Dp(48.0);
+/// Auto-scroll speed per second with the pointer at or past the edge; it
+/// ramps up from zero across the band.
+const AUTO_SCROLL_MAX_SPEED: Dp = Dp(960.0);
+
+/// Defaults for tree views.
+pub struct TreeViewDefaults;
+
+impl TreeViewDefaults {
+    /// Height of each row.
+    pub const ROW_HEIGHT: Dp = Dp(40.0);
+    /// Horizontal offset added for each nesting level.
+    pub const INDENT: Dp = Dp(24.0);
+    /// Horizontal padding inside each row.
+    pub const HORIZONTAL_PADDING: Dp = Dp(12.0);
+    /// Size of the area that toggles expansion.
+    pub const EXPANDER_SIZE: Dp = Dp(24.0);
+    /// Size of the expand and collapse icon.
+    pub const EXPAND_ICON_SIZE: Dp = Dp(20.0);
+    /// Rows composed above and below the viewport.
+    pub const OVERSCAN: usize = 4;
+}
+
+/// Stable identifier of a tree node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TreeNodeId(pub u64);
+
+impl From<u64> for TreeNodeId {
+    fn from(id: u64) -> Self {
+        Self(id)
+    }
+}
+
+/// Children of a [`TreeNode`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum TreeChildren {
+    /// The node is a leaf.
+    #[default]
+    None,
+    /// The children are known up front.
+    Loaded(Vec<TreeNode>),
+    /// The children are loaded on first expansion and supplied through
+    /// [`TreeViewController::set_children`].
+    Lazy,
+}
+
+/// A node of a [`tree_view`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreeNode {
+    /// Identifier, unique within the tree.
+    pub id: TreeNodeId,
+    /// Text shown for the node and reported to assistive technology.
+    pub label: String,
+    /// Child nodes.
+    pub children: TreeChildren,
+}
+
+impl TreeNode {
+    /// Creates a leaf node.
+    pub fn new(id: impl Into<TreeNodeId>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            children: TreeChildren::None,
+        }
+    }
+
+    /// Sets the children of the node.
+    pub fn children(mut self, children: Vec<TreeNode>) -> Self {
+        self.children = TreeChildren::Loaded(children);
+        self
+    }
+
+    /// Marks the node as having children that are loaded on demand.
+    pub fn lazy_children(mut self) -> Self {
+        self.children = TreeChildren::Lazy;
+        self
+    }
+
+    /// Returns whether the node can be expanded.
+    pub fn has_children(&self) -> bool {
+        match &self.children {
+            TreeChildren::None => false,
+            TreeChildren::Loaded(children) => !children.is_empty(),
+            TreeChildren::Lazy => true,
+        }
+    }
+}
+
+/// How nodes of a [`tree_view`] can be selected.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TreeViewSelectionMode {
+    /// Nodes cannot be selected.
+    #[default]
+    None,
+    /// At most one node is selected.
+    Single,
+    /// Any set of nodes is selected with modifier clicks, shift-click ranges
+    /// and keyboard extension.
+    Multiple,
+}
+
+impl From<TreeViewSelectionMode> for SelectionMode {
+    fn from(mode: TreeViewSelectionMode) -> Self {
+        match mode {
+            TreeViewSelectionMode::None => Self::None,
+            TreeViewSelectionMode::Single => Self::Single,
+            TreeViewSelectionMode::Multiple => Self::Multiple,
+        }
+    }
+}
+
+/// A request to move a node under a new parent, produced by dragging.
+///
+/// The tree only reports the move; the caller updates its data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TreeMove {
+    /// The dragged node.
+    pub node: TreeNodeId,
+    /// The new parent, or `None` for the root level.
+    pub parent: Option<TreeNodeId>,
+    /// Position among the new parent's children once the node has been
+    /// removed from its old place.
+    pub index: usize,
+}
+
+/// A visible node, passed to the item renderer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreeViewItem {
+    /// Identifier of the node.
+    pub id: TreeNodeId,
+    /// Label of the node.
+    pub label: String,
+    /// Nesting level, starting at 0 for root nodes.
+    pub depth: usize,
+    /// Whether the node can be expanded.
+    pub has_children: bool,
+    /// Whether the node is expanded.
+    pub expanded: bool,
+    /// Whether the node is selected.
+    pub selected: bool,
+}
+
+/// Where a dragged node lands relative to the row under the pointer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DropPosition {
+    Before,
+    Inside,
+    After,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct TreeDrag {
+    node: TreeNodeId,
+    /// Pointer y and viewport height, both in viewport coordinates.
+    pointer: Option<(Px, Px)>,
+    target: Option<(TreeNodeId, DropPosition)>,
+    /// Frame of the previous auto-scroll step.
+    last_frame_nanos: Option<u64>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct ExpandTransition {
+    expanding: bool,
+    start_frame_nanos: u64,
+}
+
+/// Persistent state for [`tree_view`].
+///
+/// Holds expansion, lazily loaded children, the selection and the focused
+/// node. Children supplied through [`TreeViewController::set_children`] live
+/// here rather than in the caller's nodes, so keep the controller alive for
+/// as long as they should stay loaded.
+pub struct TreeViewController {
+    expanded: HashSet<TreeNodeId>,
+    loaded: HashMap<TreeNodeId, Vec<TreeNode>>,
+    requested: HashSet<TreeNodeId>,
+    selection: AnchoredSelection<HashSet<TreeNodeId>>,
+    focused: Option<TreeNodeId>,
+    focus: FocusRequester,
+    pending_reveal: Option<TreeNodeId>,
+    transitions: HashMap<TreeNodeId, ExpandTransition>,
+    frame_nanos: u64,
+    drag: Option<TreeDrag>,
+}
+
+impl Default for TreeViewController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TreeViewController {
+    /// Creates a controller with every node collapsed and nothing selected.
+    pub fn new() -> Self {
+        Self {
+            expanded: HashSet::new(),
+            loaded: HashMap::new(),
+            requested: HashSet::new(),
+            selection: AnchoredSelection::default(),
+            focused: None,
+            focus: FocusRequester::new(),
+            pending_reveal: None,
+            transitions: HashMap::new(),
+            frame_nanos: 0,
+            drag: None,
+        }
+    }
+
+    /// Returns whether the node is expanded.
+    pub fn is_expanded(&self, id: TreeNodeId) -> bool {
+        self.expanded.contains(&id)
+    }
+
+    /// Expands or collapses the node with an animation.
+    pub fn set_expanded(&mut self, id: TreeNodeId, expanded: bool) {
+        self.set_expanded_at(id, expanded, current_frame_nanos());
+    }
+
+    /// Supplies the children of a node created with
+    /// [`TreeNode::lazy_children`].
+    pub fn set_children(&mut self, id: TreeNodeId, children: Vec<TreeNode>) {
+        self.loaded.insert(id, children);
+        self.requested.insert(id);
+    }
+
+    /// Drops the loaded children of the node so they are requested again the
+    /// next time it is shown expanded.
+    pub fn invalidate_children(&mut self, id: TreeNodeId) {
+        self.loaded.remove(&id);
+        self.requested.remove(&id);
+    }
+
+    /// Returns whether children of the node were requested but not supplied
+    /// yet.
+    pub fn is_loading(&self, id: TreeNodeId) -> bool {
+        self.requested.contains(&id) && !self.loaded.contains_key(&id)
+    }
+
+    /// Returns the selected nodes.
+    pub fn selection(&self) -> &HashSet<TreeNodeId> {
+        &self.selection.items
+    }
+
+    /// Returns whether the node is selected.
+    pub fn is_selected(&self, id: TreeNodeId) -> bool {
+        self.selection.items.contains(&id)
+    }
+
+    /// Replaces the selected nodes.
+    pub fn set_selection(&mut self, selection: impl IntoIterator<Item = TreeNodeId>) {
+        self.selection.items = selection.into_iter().collect();
+    }
+
+    /// Returns the node that has keyboard focus.
+    pub fn focused_node(&self) -> Option<TreeNodeId> {
+        self.focused
+    }
+
+    /// Moves keyboard focus to the node.
+    pub fn set_focused_node(&mut self, id: Option<TreeNodeId>) {
+        self.focused = id;
+    }
+
+    /// Scrolls the tree until the node is visible, if its ancestors are
+    /// expanded.
+    ///
+    /// A node hidden under a collapsed ancestor is not expanded to reach it;
+    /// expand the ancestors first.
+    pub fn reveal_node(&mut self, id: TreeNodeId) {
+        self.pending_reveal = Some(id);
+    }
+
+    /// Requests keyboard focus for the tree.
+    pub fn request_focus(&self) {
+        self.focus.request_focus();
+    }
+
+    fn set_expanded_at(&mut self, id: TreeNodeId, expanded: bool, frame_nanos: u64) {
+        if self.is_expanded(id) == expanded {
+            return;
+        }
+        if expanded {
+            self.expanded.insert(id);
+        } else {
+            self.expanded.remove(&id);
+        }
+        // Reversing a running animation continues from the current height.
+        let duration = animation_nanos();
+        let elapsed = self.transitions.get(&id).map_or(0, |transition| {
+            duration
+                - frame_nanos
+                    .saturating_sub(transition.start_frame_nanos)
+                    .min(duration)
+        });
+        self.transitions.insert(
+            id,
+            ExpandTransition {
+                expanding: expanded,
+                start_frame_nanos: frame_nanos.saturating_sub(elapsed),
+            },
+        );
+        self.frame_nanos = frame_nanos;
+    }
+
+    fn is_animating(&self) -> bool {
+        !self.transitions.is_empty()
+    }
+
+    /// Advances running animations and returns whether any is still running.
+    fn advance(&mut self, frame_nanos: u64) -> bool {
+        self.frame_nanos = frame_nanos;
+        let duration = animation_nanos();
+        self.transitions.retain(|_, transition| {
+            frame_nanos.saturating_sub(transition.start_frame_nanos) < duration
+        });
+        self.is_animating()
+    }
+
+    /// Returns the height factor of the node's children, from 0 when hidden
+    /// to 1 when fully shown.
+    fn children_visibility(&self, id: TreeNodeId) -> f32 {
+        let Some(transition) = self.transitions.get(&id) else {
+            return 1.0;
+        };
+        let duration = animation_nanos();
+        let fraction = if duration == 0 {
+            1.0
+        } else {
+            let elapsed = self
+                .frame_nanos
+                .saturating_sub(transition.start_frame_nanos);
+            (elapsed as f32 / duration as f32).min(1.0)
+        };
+        if transition.expanding {
+            easing(fraction)
+        } else {
+            1.0 - easing(fraction)
+        }
+    }
+
+    /// Returns how far the drag should scroll the tree since the previous
+    /// frame, or `None` once the pointer is outside both edge bands.
+    fn auto_scroll_step(&mut self, frame_nanos: u64, edge: Px, max_speed: Px) -> Option<Px> {
+        let drag = self.drag.as_mut()?;
+        let speed = drag.pointer.map_or(0.0, |(y, height)| {
+            auto_scroll_speed(y, height, edge, max_speed)
+        });
+        if speed == 0.0 {
+            drag.last_frame_nanos = None;
+            return None;
+        }
+        let last_frame_nanos = drag
+            .last_frame_nanos
+            .replace(frame_nanos)
+            .unwrap_or(frame_nanos);
+        let seconds = frame_nanos.saturating_sub(last_frame_nanos) as f32 / 1e9;
+        Some(Px((speed * seconds).round() as i32))
+    }
+}
+
+impl SelectionOwner for TreeViewController {
+    type Selection = HashSet<TreeNodeId>;
+
+    fn anchored_selection(&self) -> &AnchoredSelection<HashSet<TreeNodeId>> {
+        &self.selection
+    }
+
+    fn anchored_selection_mut(&mut self) -> &mut AnchoredSelection<HashSet<TreeNodeId>> {
+        &mut self.selection
+    }
+}
+
+fn animation_nanos() -> u64 {
+    EXPAND_ANIMATION_DURATION
+        .as_nanos()
+        .min(u128::from(u64::MAX)) as u64
+}
+
+/// Returns the signed auto-scroll speed in pixels per second for a pointer at
+/// `y` in a viewport `height` tall; negative speeds scroll up.
+fn auto_scroll_speed(y: Px, height: Px, edge: Px, max_speed: Px) -> f32 {
+    let edge = edge.min(height / 2);
+    if edge <= Px::ZERO {
+        return 0.0;
+    }
+    let depth = if y < edge {
+        y - edge
+    } else if y > height - edge {
+        y - (height - edge)
+    } else {
+        return 0.0;
+    };
+    (depth.to_f32() / edge.to_f32()).clamp(-1.0, 1.0) * max_speed.to_f32()
+}
+
+/// Height of a row whose ancestors are partly expanded.
+fn scaled_height(row_height: Dp, visibility: f32) -> Dp {
+    Dp(row_height.0 * f64::from(visibility))
+}
+
+/// # tree_view
+///
+/// Render a Material tree that only composes the rows inside the viewport.
+///
+/// ## Usage
+///
+/// Browse file hierarchies and outlines. Visible nodes are flattened into a
+/// lazy column, so large and deeply nested trees stay cheap, and children
+/// marked with [`TreeNode::lazy_children`] are requested only when their
+/// parent is first expanded.
+///
+/// Clicking the chevron expands or collapses a node with an animation. With a
+/// selection mode, clicking a row selects it; in multiple mode ctrl-click
+/// toggles nodes and shift-click selects the visible range. Up and Down move
+/// the focused node, Right expands it or moves to its first child, Left
+/// collapses it or moves to its parent, Space selects it and Enter activates
+/// it. When `on_move` is set, rows can be dragged onto, above or below other
+/// rows to reparent them; holding a dragged row near the top or bottom edge
+/// scrolls the tree.
+///
+/// ## Parameters
+///
+/// - `modifier` — optional modifier chain applied to the tree viewport.
+/// - `roots` — top-level nodes.
+/// - `item` — optional renderer for row content; defaults to the node label.
+/// - `selection_mode` — how nodes can be selected.
+/// - `row_height` — height of each row.
+/// - `indent` — horizontal offset added for each nesting level.
+/// - `overscan` — rows composed above and below the viewport.
+/// - `controller` — optional external controller for expansion, loaded
+///   children, selection and focus.
+/// - `on_load_children` — optional callback invoked once when a node with lazy
+///   children is first shown expanded; answer it with
+///   [`TreeViewController::set_children`].
+/// - `on_selection_change` — optional callback receiving the new selection.
+/// - `on_activate` — optional callback invoked when Enter is pressed on the
+///   focused node.
+/// - `on_move` — optional callback receiving drag-to-reparent requests;
+///   dragging is disabled without it.
+///
+/// ## Examples
+///
+/// ```
+/// use tessera_components::tree_view::{TreeNode, tree_view};
+/// use tessera_ui::tessera;
+/// # use tessera_components::theme::{MaterialTheme, material_theme};
+///
+/// #[tessera]
+/// fn demo() {
+/// #   material_theme()
+/// #       .theme(|| MaterialTheme::default())
+/// #       .child(|| {
+///     tree_view().roots(vec![
+///         TreeNode::new(1, "src").children(vec![
+///             TreeNode::new(2, "lib.rs"),
+///             TreeNode::new(3, "main.rs"),
+///         ]),
+///         TreeNode::new(4, "Cargo.toml"),
+///     ]);
+/// #       });
+/// }
+/// ```
+#[tessera]
+pub fn tree_view(
+    modifier: Option<Modifier>,
+    roots: Option<Vec<TreeNode>>,
+    item: Option<RenderSlotWith<TreeViewItem>>,
+    selection_mode: Option<TreeViewSelectionMode>,
+    row_height: Option<Dp>,
+    indent: Option<Dp>,
+    overscan: Option<usize>,
+    controller: Option<State<TreeViewController>>,
+    on_load_children: Option<CallbackWith<TreeNodeId>>,
+    on_selection_change: Option<CallbackWith<HashSet<TreeNodeId>>>,
+    on_activate: Option<CallbackWith<TreeNodeId>>,
+    on_move: Option<CallbackWith<TreeMove>>,
+) {
+    let roots = roots.unwrap_or_default();
+    let row_height = row_height.unwrap_or(TreeViewDefaults::ROW_HEIGHT);
+    let indent = indent.unwrap_or(TreeViewDefaults::INDENT);
+    let controller = controller.unwrap_or_else(|| remember(TreeViewController::new));
+    let list_controller = remember(LazyListController::new);
+
+    if controller.with(|c| c.is_animating()) {
+        receive_frame_nanos(move |frame_nanos| {
+            let is_animating = controller.with_mut(|c| c.advance(frame_nanos));
+            if is_animating {
+                FrameNanosControl::Continue
+            } else {
+                FrameNanosControl::Stop
+            }
+        });
+    }
+
+    let rows = Arc::new(controller.with(|c| TreeRows::new(&roots, c)));
+
+    // The dragged node can vanish mid-drag, under a collapsing ancestor or
+    // from new roots; there is nothing left to drop then.
+    if let Some(drag) = controller.with(|c| c.drag)
+        && !rows.is_draggable(drag.node)
+    {
+        controller.with_mut(|c| c.drag = None);
+    }
+
+    // Request children of lazy nodes the first time they are shown expanded.
+    let unrequested = controller.with(|c| {
+        rows.rows
+            .iter()
+            .filter(|row| row.placeholder && !row.leaving && !c.requested.contains(&row.id))
+            .map(|row| row.id)
+            .collect::<Vec<_>>()
+    });
+    if !unrequested.is_empty() {
+        controller.with_mut(|c| c.requested.extend(unrequested.iter().copied()));
+        if let Some(on_load_children) = &on_load_children {
+            for id in unrequested {
+                on_load_children.call(id);
+            }
+        }
+    }
+
+    if controller.with(|c| c.pending_reveal.is_some())
+        && let Some(id) = controller.with_mut(|c| c.pending_reveal.take())
+        && let Some(&index) = rows.index.get(&id)
+    {
+        list_controller.with_mut(|c| c.reveal_item(index));
+    }
+
+    let actions = TreeViewActions {
+        controller,
+        list_controller,
+        rows: rows.clone(),
+        row_height,
+        selection: SelectionActions {
+            controller,
+            mode: selection_mode.unwrap_or_default().into(),
+            on_change: on_selection_change.unwrap_or_else(CallbackWith::default_value),
+        },
+        drag_enabled: on_move.is_some(),
+        on_activate: on_activate.unwrap_or_else(CallbackWith::default_value),
+        on_move: on_move.unwrap_or_else(CallbackWith::default_value),
+    };
+
+    if controller.with(|c| c.drag.is_some_and(|drag| drag.pointer.is_some())) {
+        // Rows move under a still pointer while the tree scrolls.
+        actions.update_drop_target();
+        let edge = Px::from(AUTO_SCROLL_EDGE);
+        let max_speed = Px::from(AUTO_SCROLL_MAX_SPEED);
+        let at_edge = controller.with(|c| {
+            c.drag
+                .and_then(|drag| drag.pointer)
+                .is_some_and(|(y, height)| auto_scroll_speed(y, height, edge, max_speed) != 0.0)
+        });
+        if at_edge {
+            key("drag-auto-scroll", || {
+                receive_frame_nanos(move |frame_nanos| {
+                    match controller.with_mut(|c| c.auto_scroll_step(frame_nanos, edge, max_speed))
+                    {
+                        Some(delta) => {
+                            list_controller.with_mut(|c| c.scroll_by(delta));
+                            FrameNanosControl::Continue
+                        }
+                        None => FrameNanosControl::Stop,
+                    }
+                });
+            });
+        }
+    }
+
+    let focus = controller.with(|c| c.focus);
+    let can_focus = rows.first().is_some();
+    let mut modifier = modifier
+        .unwrap_or_default()
+        .focus_requester(focus)
+        .focusable()
+        .focus_properties(
+            FocusProperties::new()
+                .can_focus(can_focus)
+                .can_request_focus(can_focus),
+        );
+    if actions.selection.is_multiple() && can_focus {
+        let select_all_actions = actions.clone();
+        modifier = modifier.on_command(commands::SELECT_ALL, move || {
+            select_all_actions.select_all();
+        });
+    }
+    // Dragging is handled by the list rather than the rows, so it keeps
+    // going while the dragged row scrolls out of view and is dropped.
+    let drag_recognizer = remember(|| {
+        DragRecognizer::new(DragSettings {
+            // Keep the list from scrolling under the pointer.
+            consume_when_dragging: true,
+            ..DragSettings::default()
+        })
+    });
+    if actions.drag_enabled {
+        let drag_actions = actions.clone();
+        modifier = with_pointer_input(modifier, move |mut input| {
+            let within_bounds = input
+                .cursor_position_rel
+                .is_some_and(|position| is_position_inside_bounds(input.computed_data, position));
+            let drag = drag_recognizer.with_mut(|recognizer| {
+                recognizer.update(
+                    input.pass,
+                    input.pointer_changes.as_mut_slice(),
+                    input.cursor_position_rel,
+                    within_bounds,
+                )
+            });
+            if let Some(cursor) = input.cursor_position_rel {
+                if drag.started {
+                    drag_actions.drag_start(cursor.y - drag.delta_y);
+                }
+                if drag.started || drag.updated {
+                    drag_actions.drag_over(cursor.y, input.computed_data.height);
+                }
+            }
+            if drag.ended {
+                drag_actions.drag_end();
+            }
+        });
+    }
+    let keyboard_actions = actions.clone();
+    let modifier = with_keyboard_input(modifier, move |mut input| {
+        let modifiers = input.key_modifiers;
+        input.keyboard_events.retain(|event| {
+            if event.state != winit::event::ElementState::Pressed {
+                return true;
+            }
+            match tree_key_command(&event.logical_key, modifiers) {
+                Some(command) => !keyboard_actions.apply_key(command),
+                None => true,
+            }
+        });
+    });
+
+    let key_rows = rows.clone();
+    let item_rows = rows.clone();
+    lazy_column()
+        .modifier(modifier)
+        .controller(list_controller)
+        .overscan(overscan.unwrap_or(TreeViewDefaults::OVERSCAN))
+        .estimated_item_size(row_height)
+        .roles(LazyListRoles {
+            list: Role::Tree,
+            item: None,
+            hierarchical: true,
+        })
+        .items_with_key(
+            rows.rows.len(),
+            move |index| {
+                let row = &key_rows.rows[index];
+                (row.id, row.placeholder)
+            },
+            move |index| {
+                let mut builder = tree_view_row()
+                    .entry(item_rows.rows[index].clone())
+                    .position(index)
+                    .row_height(row_height)
+                    .indent(indent)
+                    .actions(actions.clone());
+                if let Some(item) = item {
+                    builder = builder.content_shared(item);
+                }
+                drop(builder);
+            },
+        );
+}
+
+#[tessera]
+fn tree_view_row(
+    entry: Option<TreeRow>,
+    position: Option<usize>,
+    row_height: Option<Dp>,
+    indent: Option<Dp>,
+    content: Option<RenderSlotWith<TreeViewItem>>,
+    actions: Option<TreeViewActions>,
+) {
+    let entry = entry.expect("tree_view_row requires entry");
+    let position = position.unwrap_or_default();
+    let row_height = row_height.unwrap_or(TreeViewDefaults::ROW_HEIGHT);
+    let indent = indent.unwrap_or(TreeViewDefaults::INDENT);
+    let actions = actions.expect("tree_view_row requires actions");
+    let theme = use_context::<MaterialTheme>()
+        .expect("MaterialTheme must be provided")
+        .get();
+    let scheme = theme.color_scheme;
+    let leading = Dp(indent.0 * entry.depth as f64);
+    let base = Modifier::new()
+        .fill_max_width()
+        .height(scaled_height(row_height, entry.visibility))
+        .clip_to_bounds();
+
+    if entry.placeholder {
+        let text_style = theme.typography.body_medium;
+        row()
+            .modifier(
+                base.alpha(entry.visibility)
+                    .padding_symmetric(TreeViewDefaults::HORIZONTAL_PADDING, Dp(0.0)),
+            )
+            .cross_axis_alignment(CrossAxisAlignment::Center)
+            .children(move || {
+                spacer().modifier(
+                    Modifier::new().width(Dp(leading.0 + TreeViewDefaults::EXPANDER_SIZE.0 + 8.0)),
+                );
+                text()
+                    .content(strings::tree_view_loading())
+                    .style(text_style)
+                    .color(scheme.on_surface_variant);
+            });
+        return;
+    }
+
+    let id = entry.id;
+    let selectable = actions.selection.is_enabled();
+    let (selected, focused, drag) = actions
+        .controller
+        .with(|c| (c.is_selected(id), c.focused == Some(id), c.drag));
+    let selected = selected && selectable;
+    let dragging = drag.is_some_and(|drag| drag.node == id);
+    let drop_indicator = drag
+        .and_then(|drag| drag.target)
+        .filter(|(target, _)| *target == id)
+        .map(|(_, position)| position);
+
+    let tap_recognizer = remember(TapRecognizer::default);
+    let pointer_actions = actions.clone();
+    let interactive = !entry.leaving;
+    let modifier = with_pointer_input(
+        base.alpha(entry.visibility * if dragging { 0.5 } else { 1.0 }),
+        move |mut input| {
+            if !interactive {
+                return;
+            }
+            let within_bounds = input
+                .cursor_position_rel
+                .is_some_and(|position| is_position_inside_bounds(input.computed_data, position));
+            let tap = tap_recognizer.with_mut(|recognizer| {
+                recognizer.update(
+                    input.pass,
+                    input.pointer_changes.as_mut_slice(),
+                    input.cursor_position_rel,
+                    within_bounds,
+                )
+            });
+            if tap.tapped {
+                pointer_actions.click(id, selection_gesture(input.key_modifiers));
+            }
+        },
+    )
+    .push_semantics(TreeItemSemantics {
+        id,
+        label: entry.label.clone(),
+        position,
+        depth: entry.depth,
+        has_children: entry.has_children,
+        expanded: entry.expanded,
+        selected: selectable.then_some(selected),
+        actions: actions.clone(),
+    });
+
+    let fill_color = if selected {
+        scheme.secondary_container
+    } else {
+        scheme.surface
+    };
+    let style = if focused || drop_indicator == Some(DropPosition::Inside) {
+        SurfaceStyle::FilledOutlined {
+            fill_color,
+            border_color: scheme.primary,
+            border_width: Dp(2.0),
+        }
+    } else {
+        SurfaceStyle::Filled { color: fill_color }
+    };
+    let item = TreeViewItem {
+        id,
+        label: entry.label.clone(),
+        depth: entry.depth,
+        has_children: entry.has_children,
+        expanded: entry.expanded,
+        selected,
+    };
+    let text_style = theme.typography.body_large;
+    let indicator_color = scheme.primary;
+    surface().style(style).modifier(modifier).child(move || {
+        let item = item.clone();
+        let actions = actions.clone();
+        column()
+            .modifier(Modifier::new().fill_max_size())
+            .children(move || {
+                if drop_indicator == Some(DropPosition::Before) {
+                    horizontal_divider()
+                        .thickness(Dp(2.0))
+                        .color(indicator_color);
+                }
+                let item = item.clone();
+                let actions = actions.clone();
+                row()
+                    .modifier(
+                        Modifier::new()
+                            .weight(1.0)
+                            .fill_max_width()
+                            .padding_symmetric(TreeViewDefaults::HORIZONTAL_PADDING, Dp(0.0)),
+                    )
+                    .cross_axis_alignment(CrossAxisAlignment::Center)
+                    .children(move || {
+                        spacer().modifier(Modifier::new().width(leading));
+                        if item.has_children {
+                            tree_view_expander()
+                                .id(item.id)
+                                .expanded(item.expanded)
+                                .actions(actions.clone());
+                        } else {
+                            spacer()
+                                .modifier(Modifier::new().width(TreeViewDefaults::EXPANDER_SIZE));
+                        }
+                        spacer().modifier(Modifier::new().width(Dp(8.0)));
+                        let item = item.clone();
+                        boxed()
+                            .alignment(Alignment::CenterStart)
+                            .modifier(Modifier::new().weight(1.0))
+                            .children(move || {
+                                let item = item.clone();
+                                provide_text_style(text_style, || match content {
+                                    Some(content) => content.render(item),
+                                    None => {
+                                        text().content(item.label);
+                                    }
+                                });
+                            });
+                    });
+                if drop_indicator == Some(DropPosition::After) {
+                    horizontal_divider()
+                        .thickness(Dp(2.0))
+                        .color(indicator_color);
+                }
+            });
+    });
+}
+
+#[tessera]
+fn tree_view_expander(
+    id: Option<TreeNodeId>,
+    expanded: Option<bool>,
+    actions: Option<TreeViewActions>,
+) {
+    let id = id.expect("tree_view_expander requires id");
+    let expanded = expanded.unwrap_or(false);
+    let actions = actions.expect("tree_view_expander requires actions");
+    let tint = use_context::<MaterialTheme>()
+        .expect("MaterialTheme must be provided")
+        .get()
+        .color_scheme
+        .on_surface_variant;
+    // Consume the click so the row underneath does not select the node.
+    let tap_recognizer = remember(|| {
+        TapRecognizer::new(TapSettings {
+            consume_on_press: true,
+            consume_on_release: true,
+            consume_on_tap: true,
+            ..Default::default()
+        })
+    });
+    let modifier = with_pointer_input(
+        Modifier::new().size(
+            TreeViewDefaults::EXPANDER_SIZE,
+            TreeViewDefaults::EXPANDER_SIZE,
+        ),
+        move |mut input| {
+            let within_bounds = input
+                .cursor_position_rel
+                .is_some_and(|position| is_position_inside_bounds(input.computed_data, position));
+            let tap = tap_recognizer.with_mut(|recognizer| {
+                recognizer.update(
+                    input.pass,
+                    input.pointer_changes.as_mut_slice(),
+                    input.cursor_position_rel,
+                    within_bounds,
+                )
+            });
+            if tap.tapped {
+                actions.set_expanded(id, !expanded);
+            }
+        },
+    );
+    let painter = if expanded {
+        filled::EXPAND_MORE_SVG
+    } else {
+        filled::CHEVRON_RIGHT_SVG
+    };
+    boxed()
+        .alignment(Alignment::Center)
+        .modifier(modifier)
+        .children(move || {
+            icon()
+                .painter(painter)
+                .size(TreeViewDefaults::EXPAND_ICON_SIZE)
+                .tint(tint);
+        });
+}
+
+/// A row of the flattened tree.
+#[derive(Clone, Debug, PartialEq)]
+struct TreeRow {
+    id: TreeNodeId,
+    label: String,
+    depth: usize,
+    parent: Option<TreeNodeId>,
+    /// Index among the parent's children.
+    index: usize,
+    has_children: bool,
+    expanded: bool,
+    /// Stands in for the children of `id` while they load.
+    placeholder: bool,
+    /// Height factor while an ancestor expands or collapses.
+    visibility: f32,
+    /// Whether an ancestor is collapsing, so the row is about to disappear.
+    leaving: bool,
+}
+
+/// Visible nodes of a tree in display order.
+#[derive(Debug, Default, PartialEq)]
+struct TreeRows {
+    rows: Vec<TreeRow>,
+    /// Row index of each node; placeholders are not indexed.
+    index: HashMap<TreeNodeId, usize>,
+    /// Whether some rows are shorter than the row height while they expand
+    /// or collapse.
+    animating: bool,
+}
+
+impl TreeRows {
+    fn new(roots: &[TreeNode], controller: &TreeViewController) -> Self {
+        let mut rows = Self {
+            animating: controller.is_animating(),
+            ..Self::default()
+        };
+        rows.push_nodes(roots, None, 0, 1.0, false, controller);
+        rows
+    }
+
+    fn push_nodes(
+        &mut self,
+        nodes: &[TreeNode],
+        parent: Option<TreeNodeId>,
+        depth: usize,
+        visibility: f32,
+        leaving: bool,
+        controller: &TreeViewController,
+    ) {
+        for (index, node) in nodes.iter().enumerate() {
+            let expanded = controller.is_expanded(node.id);
+            let has_children = node.has_children();
+            self.index.insert(node.id, self.rows.len());
+            self.rows.push(TreeRow {
+                id: node.id,
+                label: node.label.clone(),
+                depth,
+                parent,
+                index,
+                has_children,
+                expanded,
+                placeholder: false,
+                visibility,
+                leaving,
+            });
+            // Collapsing nodes keep their children until the animation ends.
+            let shown = expanded || controller.transitions.contains_key(&node.id);
+            if !has_children || !shown {
+                continue;
+            }
+            let child_visibility = visibility * controller.children_visibility(node.id);
+            let child_leaving = leaving || !expanded;
+            let children = match &node.children {
+                TreeChildren::None => continue,
+                TreeChildren::Loaded(children) => children,
+                TreeChildren::Lazy => match controller.loaded.get(&node.id) {
+                    Some(children) => children,
+                    None => {
+                        self.rows.push(TreeRow {
+                            id: node.id,
+                            label: String::new(),
+                            depth: depth + 1,
+                            parent: Some(node.id),
+                            index: 0,
+                            has_children: false,
+                            expanded: false,
+                            placeholder: true,
+                            visibility: child_visibility,
+                            leaving: child_leaving,
+                        });
+                        continue;
+                    }
+                },
+            };
+            self.push_nodes(
+                children,
+                Some(node.id),
+                depth + 1,
+                child_visibility,
+                child_leaving,
+                controller,
+            );
+        }
+    }
+
+    fn get(&self, id: TreeNodeId) -> Option<&TreeRow> {
+        self.index.get(&id).map(|&index| &self.rows[index])
+    }
+
+    /// Returns the rows that keyboard navigation can land on.
+    fn navigable(&self) -> impl DoubleEndedIterator<Item = &TreeRow> {
+        self.rows
+            .iter()
+            .filter(|row| !row.placeholder && !row.leaving)
+    }
+
+    fn navigable_ids(&self) -> Vec<TreeNodeId> {
+        self.navigable().map(|row| row.id).collect()
+    }
+
+    /// Adds the navigable nodes from `anchor` to `id` to `selection`, or only
+    /// `id` when the anchor is hidden.
+    fn select_span(&self, selection: &mut HashSet<TreeNodeId>, anchor: TreeNodeId, id: TreeNodeId) {
+        let order = self.navigable_ids();
+        let anchor_index = order.iter().position(|&node| node == anchor);
+        let index = order.iter().position(|&node| node == id);
+        match (anchor_index, index) {
+            (Some(anchor_index), Some(index)) => {
+                let range = anchor_index.min(index)..=anchor_index.max(index);
+                selection.extend(order[range].iter().copied());
+            }
+            _ => {
+                selection.insert(id);
+            }
+        }
+    }
+
+    fn first(&self) -> Option<TreeNodeId> {
+        self.navigable().next().map(|row| row.id)
+    }
+
+    fn last(&self) -> Option<TreeNodeId> {
+        self.navigable().next_back().map(|row| row.id)
+    }
+
+    fn next(&self, id: TreeNodeId) -> Option<TreeNodeId> {
+        let index = *self.index.get(&id)?;
+        self.rows[index + 1..]
+            .iter()
+            .find(|row| !row.placeholder && !row.leaving)
+            .map(|row| row.id)
+    }
+
+    fn previous(&self, id: TreeNodeId) -> Option<TreeNodeId> {
+        let index = *self.index.get(&id)?;
+        self.rows[..index]
+            .iter()
+            .rev()
+            .find(|row| !row.placeholder && !row.leaving)
+            .map(|row| row.id)
+    }
+
+    fn first_child(&self, id: TreeNodeId) -> Option<TreeNodeId> {
+        let index = *self.index.get(&id)?;
+        self.rows
+            .get(index + 1)
+            .filter(|row| row.parent == Some(id) && !row.placeholder && !row.leaving)
+            .map(|row| row.id)
+    }
+
+    fn is_ancestor(&self, ancestor: TreeNodeId, id: TreeNodeId) -> bool {
+        let mut current = self.get(id).and_then(|row| row.parent);
+        while let Some(parent) = current {
+            if parent == ancestor {
+                return true;
+            }
+            current = self.get(parent).and_then(|row| row.parent);
+        }
+        false
+    }
+
+    /// Returns whether the node is shown and can be picked up.
+    fn is_draggable(&self, id: TreeNodeId) -> bool {
+        self.get(id).is_some_and(|row| !row.leaving)
+    }
+
+    /// Returns the index of the row at `y` from the top of the content and
+    /// the offset of `y` within that row.
+    fn row_at(&self, y: Px, row_height: Dp) -> Option<(usize, Px)> {
+        if y < Px::ZERO {
+            return None;
+        }
+        let full_height = Px::from(row_height);
+        if !self.animating && full_height > Px::ZERO {
+            let index = (y / full_height).0 as usize;
+            if index >= self.rows.len() {
+                return None;
+            }
+            return Some((index, y - full_height * index as i32));
+        }
+        let mut top = Px::ZERO;
+        for (index, row) in self.rows.iter().enumerate() {
+            let height = Px::from(scaled_height(row_height, row.visibility));
+            if y < top + height {
+                return Some((index, y - top));
+            }
+            top += height;
+        }
+        None
+    }
+
+    /// Finds the drop target at `y` from the top of the content.
+    fn drop_target(&self, y: Px, row_height: Dp) -> Option<(TreeNodeId, DropPosition)> {
+        let (index, offset) = self.row_at(y, row_height)?;
+        let row = &self.rows[index];
+        if row.placeholder || row.leaving {
+            return None;
+        }
+        let height = Px::from(scaled_height(row_height, row.visibility));
+        Some((row.id, drop_position(offset, height, row.has_children)))
+    }
+
+    /// Returns the move produced by dropping `node` on `target`, or `None`
+    /// when the drop is invalid or changes nothing.
+    fn drop_move(
+        &self,
+        node: TreeNodeId,
+        target: TreeNodeId,
+        position: DropPosition,
+    ) -> Option<TreeMove> {
+        let dragged = self.get(node)?;
+        let target_row = self.get(target)?;
+        if node == target || self.is_ancestor(node, target) {
+            return None;
+        }
+        let (parent, index) = match position {
+            DropPosition::Inside => (Some(target), 0),
+            DropPosition::Before => (target_row.parent, target_row.index),
+            // Below an expanded node is above its first child.
+            DropPosition::After if target_row.expanded && target_row.has_children => {
+                (Some(target), 0)
+            }
+            DropPosition::After => (target_row.parent, target_row.index + 1),
+        };
+        let index = if parent == dragged.parent && dragged.index < index {
+            index - 1
+        } else {
+            index
+        };
+        if parent == dragged.parent && index == dragged.index {
+            return None;
+        }
+        Some(TreeMove {
+            node,
+            parent,
+            index,
+        })
+    }
+}
+
+/// Splits a row into drop zones: the middle half drops inside nodes that
+/// have children, the rest drops before or after the row.
+fn drop_position(y: Px, height: Px, accepts_children: bool) -> DropPosition {
+    if accepts_children {
+        let quarter = height / 4;
+        if y < quarter {
+            DropPosition::Before
+        } else if y >= height - quarter {
+            DropPosition::After
+        } else {
+            DropPosition::Inside
+        }
+    } else if y < height / 2 {
+        DropPosition::Before
+    } else {
+        DropPosition::After
+    }
+}
+
+/// Tree operations bound to one build's visible rows.
+///
+/// Rows, the expander, key handling and the tree item semantics each hold a
+/// copy, so a node expands, selects or moves the same way whether it was
+/// clicked, dragged, reached with the arrow keys or driven by a screen
+/// reader.
+#[derive(Clone, PartialEq)]
+struct TreeViewActions {
+    controller: State<TreeViewController>,
+    list_controller: State<LazyListController>,
+    rows: Arc<TreeRows>,
+    row_height: Dp,
+    selection: SelectionActions<TreeViewController>,
+    drag_enabled: bool,
+    on_activate: CallbackWith<TreeNodeId>,
+    on_move: CallbackWith<TreeMove>,
+}
+
+impl TreeViewActions {
+    fn focus(&self, id: TreeNodeId) {
+        self.controller.with_mut(|c| {
+            c.focused = Some(id);
+            c.pending_reveal = Some(id);
+        });
+    }
+
+    fn select(&self, id: TreeNodeId, gesture: SelectionGesture) {
+        self.selection.select(id, gesture, |selection, anchor, id| {
+            self.rows.select_span(selection, anchor, id)
+        });
+    }
+
+    fn select_all(&self) {
+        let selection = self.rows.navigable_ids().into_iter().collect();
+        self.selection.select_all(selection);
+    }
+
+    fn click(&self, id: TreeNodeId, gesture: SelectionGesture) {
+        self.controller.with(|c| c.request_focus());
+        self.focus(id);
+        self.select(id, gesture);
+    }
+
+    fn set_expanded(&self, id: TreeNodeId, expanded: bool) {
+        let Some(row) = self.rows.get(id) else {
+            return;
+        };
+        if !row.has_children || row.expanded == expanded {
+            return;
+        }
+        let hides_focus = !expanded
+            && self
+                .controller
+                .with(|c| c.focused)
+                .is_some_and(|focused| self.rows.is_ancestor(id, focused));
+        self.controller.with_mut(|c| {
+            c.set_expanded(id, expanded);
+            // Focus must not stay on a hidden descendant.
+            if hides_focus {
+                c.focused = Some(id);
+            }
+        });
+    }
+
+    /// Returns how far the list is scrolled from the top of the content.
+    fn scroll_offset(&self) -> Px {
+        self.list_controller
+            .with(|c| -c.scroll_controller().child_position().y)
+            .max(Px::ZERO)
+    }
+
+    /// Picks up the node pressed at `y` in viewport coordinates.
+    fn drag_start(&self, y: Px) {
+        let Some((index, _)) = self.rows.row_at(y + self.scroll_offset(), self.row_height) else {
+            return;
+        };
+        let row = &self.rows.rows[index];
+        if row.placeholder || row.leaving {
+            return;
+        }
+        let node = row.id;
+        self.controller.with_mut(|c| {
+            c.drag = Some(TreeDrag {
+                node,
+                pointer: None,
+                target: None,
+                last_frame_nanos: None,
+            })
+        });
+    }
+
+    /// Moves the dragged node to `y` in a viewport `viewport_height` tall.
+    fn drag_over(&self, y: Px, viewport_height: Px) {
+        if self.controller.with(|c| c.drag.is_none()) {
+            return;
+        }
+        self.controller.with_mut(|c| {
+            if let Some(drag) = &mut c.drag {
+                drag.pointer = Some((y, viewport_height));
+            }
+        });
+        self.update_drop_target();
+    }
+
+    /// Points the drag at the row now under the pointer.
+    fn update_drop_target(&self) {
+        let Some(drag) = self.controller.with(|c| c.drag) else {
+            return;
+        };
+        let Some((y, _)) = drag.pointer else {
+            return;
+        };
+        let target = self
+            .rows
+            .drop_target(y + self.scroll_offset(), self.row_height)
+            .filter(|&(target, position)| {
+                self.rows.drop_move(drag.node, target, position).is_some()
+            });
+        if drag.target != target {
+            self.controller.with_mut(|c| {
+                if let Some(drag) = &mut c.drag {
+                    drag.target = target;
+                }
+            });
+        }
+    }
+
+    fn drag_end(&self) {
+        let Some(drag) = self.controller.with_mut(|c| c.drag.take()) else {
+            return;
+        };
+        if let Some((target, position)) = drag.target
+            && let Some(tree_move) = self.rows.drop_move(drag.node, target, position)
+        {
+            self.on_move.call(tree_move);
+        }
+    }
+
+    /// Runs a keyboard command and returns whether it was handled.
+    fn apply_key(&self, command: TreeKeyCommand) -> bool {
+        let focused = self
+            .controller
+            .with(|c| c.focused)
+            .filter(|&id| self.rows.get(id).is_some_and(|row| !row.leaving));
+        let Some(focused) = focused else {
+            // Any navigation key starts at the first node.
+            return match command {
+                TreeKeyCommand::Move { .. } => match self.rows.first() {
+                    Some(first) => {
+                        self.focus(first);
+                        true
+                    }
+                    None => false,
+                },
+                _ => false,
+            };
+        };
+        match command {
+            TreeKeyCommand::Move { movement, extend } => {
+                let next = match movement {
+                    TreeMovement::Up => self.rows.previous(focused),
+                    TreeMovement::Down => self.rows.next(focused),
+                    TreeMovement::First => self.rows.first(),
+                    TreeMovement::Last => self.rows.last(),
+                };
+                let Some(next) = next else {
+                    return false;
+                };
+                self.focus(next);
+                if extend && self.selection.is_multiple() {
+                    self.select(next, SelectionGesture::Extend);
+                } else if !extend {
+                    self.selection.set_anchor(next);
+                }
+                true
+            }
+            TreeKeyCommand::Expand => {
+                let Some(row) = self.rows.get(focused) else {
+                    return false;
+                };
+                if !row.has_children {
+                    return false;
+                }
+                if !row.expanded {
+                    self.set_expanded(focused, true);
+                } else if let Some(child) = self.rows.first_child(focused) {
+                    self.focus(child);
+                }
+                true
+            }
+            TreeKeyCommand::Collapse => {
+                let Some(row) = self.rows.get(focused) else {
+                    return false;
+                };
+                if row.has_children && row.expanded {
+                    self.set_expanded(focused, false);
+                    true
+                } else if let Some(parent) = row.parent {
+                    self.focus(parent);
+                    true
+                } else {
+                    false
+                }
+            }
+            TreeKeyCommand::Select { extend } => {
+                let gesture = if extend {
+                    SelectionGesture::Extend
+                } else {
+                    SelectionGesture::Toggle
+                };
+                self.select(focused, gesture);
+                self.selection.is_enabled()
+            }
+            TreeKeyCommand::Activate => {
+                self.on_activate.call(focused);
+                true
+            }
+        }
+    }
+}
+
+struct TreeItemSemantics {
+    id: TreeNodeId,
+    label: String,
+    position: usize,
+    depth: usize,
+    has_children: bool,
+    expanded: bool,
+    selected: Option<bool>,
+    actions: TreeViewActions,
+}
+
+impl SemanticsModifierNode for TreeItemSemantics {
+    fn apply(
+        &self,
+        accessibility: &mut AccessibilityNode,
+        action_handler: &mut Option<AccessibilityActionHandler>,
+    ) {
+        accessibility.role = Some(Role::TreeItem);
+        accessibility.label = Some(self.label.clone());
+        accessibility.collection_item_info = Some((self.position, 1, 0, 1, false));
+        accessibility.collection_item_level = Some(self.depth + 1);
+        accessibility.expanded = self.has_children.then_some(self.expanded);
+        accessibility.selected = self.selected;
+        if !accessibility.actions.contains(&Action::Click) {
+            accessibility.actions.push(Action::Click);
+        }
+        let actions = self.actions.clone();
+        let id = self.id;
+        *action_handler = Some(Box::new(move |action| {
+            if action == Action::Click {
+                actions.click(id, SelectionGesture::Replace);
+            }
+        }));
+    }
+
+    fn apply_action_handlers(&self, handlers: &mut AccessibilityActionHandlers) {
+        let id = self.id;
+        if self.has_children {
+            let actions = self.actions.clone();
+            if self.expanded {
+                handlers.on_collapse(move || actions.set_expanded(id, false));
+            } else {
+                handlers.on_expand(move || actions.set_expanded(id, true));
+            }
+        }
+        let controller = self.actions.controller;
+        handlers.on_scroll_into_view(move || controller.with_mut(|c| c.reveal_node(id)));
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TreeMovement {
+    Up,
+    Down,
+    First,
+    Last,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TreeKeyCommand {
+    Move {
+        movement: TreeMovement,
+        extend: bool,
+    },
+    Expand,
+    Collapse,
+    Select {
+        extend: bool,
+    },
+    Activate,
+}
+
+fn tree_key_command(
+    key: &winit::keyboard::Key,
+    modifiers: winit::keyboard::ModifiersState,
+) -> Option<TreeKeyCommand> {
+    use winit::keyboard::{Key, NamedKey};
+
+    let extend = modifiers.shift_key();
+    let movement = match key {
+        Key::Named(NamedKey::ArrowUp) => TreeMovement::Up,
+        Key::Named(NamedKey::ArrowDown) => TreeMovement::Down,
+        Key::Named(NamedKey::Home) => TreeMovement::First,
+        Key::Named(NamedKey::End) => TreeMovement::Last,
+        Key::Named(NamedKey::ArrowRight) => return Some(TreeKeyCommand::Expand),
+        Key::Named(NamedKey::ArrowLeft) => return Some(TreeKeyCommand::Collapse),
+        Key::Named(NamedKey::Space) => return Some(TreeKeyCommand::Select { extend }),
+        Key::Named(NamedKey::Enter) => return Some(TreeKeyCommand::Activate),
+        _ => return None,
+    };
+    Some(TreeKeyCommand::Move { movement, extend })
+}
+
+#[cfg(test)]
+mod tests {
+    use winit::keyboard::{Key, ModifiersState, NamedKey, SmolStr};
+
+    use super::*;
+
+    fn id(value: u64) -> TreeNodeId {
+        TreeNodeId(value)
+    }
+
+    /// 1
+    /// ├─ 2
+    /// │  └─ 3
+    /// └─ 4
+    /// 5 (lazy)
+    fn sample() -> Vec<TreeNode> {
+        vec![
+            TreeNode::new(1, "a").children(vec![
+                TreeNode::new(2, "b").children(vec![TreeNode::new(3, "c")]),
+                TreeNode::new(4, "d"),
+            ]),
+            TreeNode::new(5, "e").lazy_children(),
+        ]
+    }
+
+    fn settled(expanded: &[u64]) -> TreeViewController {
+        let mut controller = TreeViewController::new();
+        for &node in expanded {
+            controller.set_expanded_at(id(node), true, 0);
+        }
+        controller.advance(animation_nanos());
+        controller
+    }
+
+    fn ids(rows: &TreeRows) -> Vec<(u64, bool)> {
+        rows.rows
+            .iter()
+            .map(|row| (row.id.0, row.placeholder))
+            .collect()
+    }
+
+    #[test]
+    fn only_expanded_nodes_show_their_children() {
+        let rows = TreeRows::new(&sample(), &settled(&[]));
+        assert_eq!(ids(&rows), vec![(1, false), (5, false)]);
+
+        let rows = TreeRows::new(&sample(), &settled(&[1, 2]));
+        assert_eq!(
+            ids(&rows),
+            vec![(1, false), (2, false), (3, false), (4, false), (5, false)]
+        );
+        let leaf = rows.get(id(3)).unwrap();
+        assert_eq!((leaf.depth, leaf.parent, leaf.index), (2, Some(id(2)), 0));
+        assert_eq!(rows.get(id(4)).unwrap().index, 1);
+    }
+
+    #[test]
+    fn lazy_children_show_a_placeholder_until_loaded() {
+        let mut controller = settled(&[5]);
+        let rows = TreeRows::new(&sample(), &controller);
+        assert_eq!(ids(&rows), vec![(1, false), (5, false), (5, true)]);
+
+        controller.set_children(id(5), vec![TreeNode::new(6, "f")]);
+        assert!(!controller.is_loading(id(5)));
+        let rows = TreeRows::new(&sample(), &controller);
+        assert_eq!(ids(&rows), vec![(1, false), (5, false), (6, false)]);
+        controller.invalidate_children(id(5));
+        assert_eq!(
+            ids(&TreeRows::new(&sample(), &controller)),
+            vec![(1, false), (5, false), (5, true)]
+        );
+    }
+
+    #[test]
+    fn collapsing_keeps_children_until_the_animation_ends() {
+        let mut controller = settled(&[1]);
+        let start = animation_nanos();
+        controller.set_expanded_at(id(1), false, start);
+        let rows = TreeRows::new(&sample(), &controller);
+        assert_eq!(rows.rows.len(), 4);
+        assert!(rows.get(id(2)).unwrap().leaving);
+        assert_eq!(rows.get(id(2)).unwrap().visibility, 1.0);
+        assert_eq!(rows.navigable_ids(), vec![id(1), id(5)]);
+
+        assert!(controller.advance(start + animation_nanos() / 2));
+        let visibility = TreeRows::new(&sample(), &controller)
+            .get(id(2))
+            .unwrap()
+            .visibility;
+        assert!(visibility > 0.0 && visibility < 1.0);
+
+        assert!(!controller.advance(start + animation_nanos()));
+        assert_eq!(TreeRows::new(&sample(), &controller).rows.len(), 2);
+    }
+
+    #[test]
+    fn reversing_an_animation_continues_from_the_current_height() {
+        let start = animation_nanos();
+        let now = start + animation_nanos() / 4;
+        let mut controller = TreeViewController::new();
+        controller.set_expanded_at(id(1), true, start);
+        controller.advance(now);
+        let before = controller.children_visibility(id(1));
+        controller.set_expanded_at(id(1), false, now);
+        let after = controller.children_visibility(id(1));
+        assert!((before - after).abs() < 1e-4, "{before} != {after}");
+    }
+
+    #[test]
+    fn navigation_skips_placeholders_and_follows_the_hierarchy() {
+        let rows = TreeRows::new(&sample(), &settled(&[1, 5]));
+        assert_eq!(rows.first(), Some(id(1)));
+        assert_eq!(rows.next(id(4)), Some(id(5)));
+        assert_eq!(rows.next(id(5)), None);
+        assert_eq!(rows.last(), Some(id(5)));
+        assert_eq!(rows.previous(id(5)), Some(id(4)));
+        assert_eq!(rows.first_child(id(1)), Some(id(2)));
+        assert_eq!(rows.first_child(id(2)), None);
+        assert!(rows.is_ancestor(id(1), id(4)));
+        assert!(!rows.is_ancestor(id(4), id(1)));
+    }
+
+    #[test]
+    fn range_selection_follows_visible_order() {
+        let rows = TreeRows::new(&sample(), &settled(&[1, 2]));
+        let span = |selection: &mut HashSet<TreeNodeId>, anchor, node| {
+            rows.select_span(selection, anchor, node)
+        };
+        let multiple = TreeViewSelectionMode::Multiple.into();
+        let mut controller = TreeViewController::new();
+        let selection = &mut controller.selection;
+        assert!(selection.apply(id(2), multiple, SelectionGesture::Replace, span));
+        assert!(selection.apply(id(4), multiple, SelectionGesture::Extend, span));
+        assert_eq!(
+            controller.selection(),
+            &HashSet::from([id(2), id(3), id(4)])
+        );
+
+        // A hidden anchor only selects the node itself.
+        let selection = &mut controller.selection;
+        selection.anchor = Some(id(6));
+        assert!(selection.apply(id(3), multiple, SelectionGesture::Extend, span));
+        assert_eq!(controller.selection(), &HashSet::from([id(3)]));
+    }
+
+    #[test]
+    fn drop_targets_are_found_from_the_content_offset() {
+        let rows = TreeRows::new(&sample(), &settled(&[1, 2]));
+        let height = Dp(10.0);
+        let row = Px::from(height);
+        assert_eq!(rows.row_at(row + row / 2, height), Some((1, row / 2)));
+        assert_eq!(
+            rows.drop_target(row + row / 2, height),
+            Some((id(2), DropPosition::Inside))
+        );
+        assert_eq!(
+            rows.drop_target(row * 4 + Px(1), height),
+            Some((id(5), DropPosition::Before))
+        );
+        assert_eq!(rows.drop_target(-Px(1), height), None);
+        assert_eq!(rows.drop_target(row * 5, height), None);
+        assert_eq!(drop_position(Px(9), Px(10), false), DropPosition::After);
+
+        // Collapsing rows are shorter and cannot be dropped on.
+        let mut controller = settled(&[1]);
+        let start = animation_nanos();
+        controller.set_expanded_at(id(1), false, start);
+        controller.advance(start + animation_nanos() / 2);
+        let rows = TreeRows::new(&sample(), &controller);
+        let leaving = Px::from(scaled_height(height, rows.get(id(2)).unwrap().visibility));
+        assert_eq!(rows.row_at(row + leaving * 2, height), Some((3, Px::ZERO)));
+        assert_eq!(rows.drop_target(row + Px(1), height), None);
+        assert_eq!(
+            rows.drop_target(row + leaving * 2, height),
+            Some((id(5), DropPosition::Before))
+        );
+        assert!(!rows.is_draggable(id(2)));
+        assert!(rows.is_draggable(id(5)));
+    }
+
+    #[test]
+    fn dragging_near_an_edge_scrolls_faster_towards_it() {
+        let edge = Px(40);
+        let speed = Px(1000);
+        let height = Px(400);
+        assert_eq!(auto_scroll_speed(Px(200), height, edge, speed), 0.0);
+        assert_eq!(auto_scroll_speed(Px(20), height, edge, speed), -500.0);
+        assert_eq!(auto_scroll_speed(Px(380), height, edge, speed), 500.0);
+        assert_eq!(auto_scroll_speed(Px(-30), height, edge, speed), -1000.0);
+
+        let mut controller = TreeViewController::new();
+        assert_eq!(controller.auto_scroll_step(0, edge, speed), None);
+        controller.drag = Some(TreeDrag {
+            node: id(1),
+            pointer: Some((Px(420), height)),
+            target: None,
+            last_frame_nanos: None,
+        });
+        assert_eq!(controller.auto_scroll_step(0, edge, speed), Some(Px(0)));
+        assert_eq!(
+            controller.auto_scroll_step(100_000_000, edge, speed),
+            Some(Px(100))
+        );
+        controller.drag.as_mut().unwrap().pointer = Some((Px(200), height));
+        assert_eq!(controller.auto_scroll_step(200_000_000, edge, speed), None);
+        assert_eq!(controller.drag.unwrap().last_frame_nanos, None);
+    }
+
+    #[test]
+    fn drops_produce_moves_relative_to_the_new_parent() {
+        let rows = TreeRows::new(&sample(), &settled(&[1, 2]));
+        // Into a node.
+        assert_eq!(
+            rows.drop_move(id(4), id(2), DropPosition::Inside),
+            Some(TreeMove {
+                node: id(4),
+                parent: Some(id(2)),
+                index: 0
+            })
+        );
+        // Below the last root.
+        assert_eq!(
+            rows.drop_move(id(3), id(5), DropPosition::After),
+            Some(TreeMove {
+                node: id(3),
+                parent: None,
+                index: 2
+            })
+        );
+        // Moving down among siblings accounts for the removed node.
+        assert_eq!(
+            rows.drop_move(id(1), id(5), DropPosition::After),
+            Some(TreeMove {
+                node: id(1),
+                parent: None,
+                index: 1
+            })
+        );
+        // Below an expanded node is its first child.
+        assert_eq!(
+            rows.drop_move(id(4), id(1), DropPosition::After),
+            Some(TreeMove {
+                node: id(4),
+                parent: Some(id(1)),
+                index: 0
+            })
+        );
+        // No-ops and drops into the node's own subtree are rejected.
+        assert_eq!(rows.drop_move(id(2), id(4), DropPosition::Before), None);
+        assert_eq!(rows.drop_move(id(1), id(3), DropPosition::Inside), None);
+        assert_eq!(rows.drop_move(id(2), id(2), DropPosition::Inside), None);
+    }
+
+    #[test]
+    fn keys_map_to_tree_commands() {
+        let none = ModifiersState::empty();
+        assert_eq!(
+            tree_key_command(&Key::Named(NamedKey::ArrowRight), none),
+            Some(TreeKeyCommand::Expand)
+        );
+        assert_eq!(
+            tree_key_command(&Key::Named(NamedKey::ArrowLeft), none),
+            Some(TreeKeyCommand::Collapse)
+        );
+        assert_eq!(
+            tree_key_command(&Key::Named(NamedKey::ArrowDown), ModifiersState::SHIFT),
+            Some(TreeKeyCommand::Move {
+                movement: TreeMovement::Down,
+                extend: true
+            })
+        );
+        // Select all is bound through the shortcut registry instead.
+        assert_eq!(
+            tree_key_command(&Key::Character(SmolStr::new("a")), ModifiersState::CONTROL),
+            None
+        );
+        assert_eq!(
+            tree_key_command(&Key::Character(SmolStr::new("a")), none),
+            None
+        );
+    }
+}
@@ -0,0 +1,376 @@
+//! Video and camera frames fed from outside the render graph.
+//!
+//! ## Usage
+//!
+//! Show camera previews, software-decoded video, or textures rendered by
+//! another engine.
+use std::{
+    collections::VecDeque,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use parking_lot::Mutex;
+use tessera_ui::{
+    ComputedData, FrameNanosControl, LayoutResult, MeasurementError, Modifier, Px,
+    current_frame_time,
+    layout::{LayoutPolicy, MeasureScope, RenderInput, RenderPolicy, layout},
+    receive_frame_nanos, tessera,
+};
+
+pub use crate::pipelines::video::command::{
+    VideoContentScale, VideoFrame, VideoFrameError, VideoPixels, VideoPlane, YuvColorSpace,
+    YuvRange,
+};
+use crate::{
+    pipelines::video::command::VideoCommand,
+    subscription::{Notifier, observe},
+};
+
+/// Frames waiting for their presentation time beyond this are dropped,
+/// oldest first.
+const MAX_QUEUED_FRAMES: usize = 8;
+
+/// Timed frames further ahead of the playback clock than this restart it
+/// instead of stalling the surface.
+const MAX_CLOCK_LEAD: Duration = Duration::from_secs(1);
+
+static NEXT_SOURCE_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Playback clock mapping frame timestamps to wall-clock instants.
+#[derive(Clone, Copy)]
+struct PlaybackClock {
+    timestamp: Duration,
+    instant: Instant,
+}
+
+impl PlaybackClock {
+    fn due_at(&self, timestamp: Duration) -> Instant {
+        self.instant + timestamp.saturating_sub(self.timestamp)
+    }
+}
+
+#[derive(Default)]
+struct Playback {
+    queue: VecDeque<Arc<VideoFrame>>,
+    current: Option<Arc<VideoFrame>>,
+    clock: Option<PlaybackClock>,
+}
+
+impl Playback {
+    fn push(&mut self, frame: Arc<VideoFrame>) {
+        if self.queue.len() == MAX_QUEUED_FRAMES {
+            self.queue.pop_front();
+        }
+        self.queue.push_back(frame);
+    }
+
+    /// Advances to the newest frame due at `now`.
+    fn advance(&mut self, now: Instant) {
+        while let Some(next) = self.queue.front() {
+            if let Some(timestamp) = next.timestamp() {
+                let rewound = self
+                    .current
+                    .as_ref()
+                    .and_then(|current| current.timestamp())
+                    .is_some_and(|current| timestamp < current);
+                let clock = match self.clock {
+                    Some(clock) if !rewound && clock.due_at(timestamp) <= now + MAX_CLOCK_LEAD => {
+                        clock
+                    }
+                    _ => *self.clock.insert(PlaybackClock {
+                        timestamp,
+                        instant: now,
+                    }),
+                };
+                if clock.due_at(timestamp) > now {
+                    break;
+                }
+            }
+            self.current = self.queue.pop_front();
+        }
+    }
+}
+
+struct SourceInner {
+    id: u64,
+    playback: Mutex<Playback>,
+    notifier: Arc<Notifier>,
+}
+
+/// Producer handle that feeds frames into [`video_surface`].
+///
+/// Clones share the same stream, so one clone can live on a decoder or
+/// camera thread while another is passed to the component. Frames without a
+/// timestamp are shown as soon as possible. Timestamped frames are paced
+/// against a playback clock that starts at the first timestamp and restarts
+/// when timestamps go backwards, so a seek or loop needs no extra call.
+#[derive(Clone)]
+pub struct VideoSource {
+    inner: Arc<SourceInner>,
+}
+
+impl PartialEq for VideoSource {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Default for VideoSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VideoSource {
+    /// Creates an empty source.
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(SourceInner {
+                id: NEXT_SOURCE_ID.fetch_add(1, Ordering::Relaxed),
+                playback: Mutex::new(Playback::default()),
+                notifier: Notifier::new(),
+            }),
+        }
+    }
+
+    /// Queues `frame` for display. Can be called from any thread.
+    ///
+    /// At most eight frames wait for their timestamps; older ones are
+    /// dropped when producers run ahead.
+    pub fn push_frame(&self, frame: VideoFrame) {
+        self.inner.playback.lock().push(Arc::new(frame));
+        self.inner.notifier.notify();
+    }
+
+    /// Drops queued frames and blanks every surface showing this source.
+    pub fn clear(&self) {
+        *self.inner.playback.lock() = Playback::default();
+        self.inner.notifier.notify();
+    }
+
+    /// Returns the frame to show at `now` and whether frames are still
+    /// waiting for their presentation time.
+    fn frame_at(&self, now: Instant) -> (Option<Arc<VideoFrame>>, bool) {
+        let mut playback = self.inner.playback.lock();
+        playback.advance(now);
+        (playback.current.clone(), !playback.queue.is_empty())
+    }
+}
+
+#[derive(Clone)]
+struct VideoSurfaceLayout {
+    frame: Option<Arc<VideoFrame>>,
+    source_id: u64,
+    content_scale: VideoContentScale,
+}
+
+impl PartialEq for VideoSurfaceLayout {
+    fn eq(&self, other: &Self) -> bool {
+        let same_frame = match (&self.frame, &other.frame) {
+            (Some(current), Some(other)) => Arc::ptr_eq(current, other),
+            (None, None) => true,
+            _ => false,
+        };
+        same_frame && self.source_id == other.source_id && self.content_scale == other.content_scale
+    }
+}
+
+impl VideoSurfaceLayout {
+    fn frame_size(&self) -> (u32, u32) {
+        self.frame
+            .as_ref()
+            .map_or((0, 0), |frame| (frame.width(), frame.height()))
+    }
+}
+
+impl LayoutPolicy for VideoSurfaceLayout {
+    fn measure(&self, input: &MeasureScope<'_>) -> Result<LayoutResult, MeasurementError> {
+        let (width, height) = self.frame_size();
+        let width = input.parent_constraint().width().clamp(Px(width as i32));
+        let height = input.parent_constraint().height().clamp(Px(height as i32));
+        Ok(LayoutResult::new(ComputedData { width, height }))
+    }
+
+    fn measure_eq(&self, other: &Self) -> bool {
+        self.frame_size() == other.frame_size()
+    }
+}
+
+impl RenderPolicy for VideoSurfaceLayout {
+    fn record(&self, input: &mut RenderInput<'_>) {
+        let Some(frame) = self.frame.clone() else {
+            return;
+        };
+        input
+            .metadata_mut()
+            .fragment_mut()
+            .push_composite_command(VideoCommand {
+                frame,
+                source_id: self.source_id,
+                content_scale: self.content_scale,
+            });
+    }
+}
+
+/// # video_surface
+///
+/// Displays frames pushed into a [`VideoSource`] from any thread.
+///
+/// ## Usage
+///
+/// Embed camera previews, software video decoders, or textures produced by
+/// another renderer.
+///
+/// ## Parameters
+///
+/// - `source` — producer handle whose frames are shown; nothing is drawn when
+///   unset or before the first frame arrives.
+/// - `content_scale` — how frames are scaled into the node bounds; defaults to
+///   [`VideoContentScale::Fit`].
+/// - `modifier` — node-local layout, drawing, and interaction modifiers.
+///
+/// Without a size modifier the surface measures to the frame size. Each new
+/// frame is converted on the GPU, YUV planes included, into a texture kept
+/// per source, so surfaces showing the same source share one conversion and
+/// [`VideoFrame::texture`] frames never round-trip through the CPU.
+///
+/// ## Examples
+///
+/// ```
+/// # use tessera_ui::tessera;
+/// # #[tessera]
+/// # fn component() {
+/// use tessera_components::video_surface::{
+///     VideoContentScale, VideoFrame, VideoPlane, VideoSource, video_surface,
+/// };
+///
+/// let source = VideoSource::new();
+/// let producer = source.clone();
+/// std::thread::spawn(move || {
+///     let pixels = VideoPlane::new(vec![255; 4 * 4 * 4], 4 * 4);
+///     let frame = VideoFrame::rgba(4, 4, pixels).expect("plane fits the frame");
+///     producer.push_frame(frame);
+/// });
+///
+/// video_surface()
+///     .source(source)
+///     .content_scale(VideoContentScale::Fill);
+/// # }
+/// ```
+#[tessera]
+pub fn video_surface(
+    #[prop(into)] source: Option<VideoSource>,
+    content_scale: Option<VideoContentScale>,
+    modifier: Option<Modifier>,
+) {
+    let modifier = modifier.unwrap_or_default();
+    let Some(source) = source else {
+        layout().modifier(modifier);
+        return;
+    };
+
+    let pushed = observe(&source.inner.notifier);
+
+    let now = current_frame_time().unwrap_or_else(Instant::now);
+    let (frame, has_queued) = source.frame_at(now);
+    if has_queued {
+        // Rebuild each frame until queued frames reach their timestamps.
+        receive_frame_nanos(move |_| {
+            pushed.with_mut(|pushed| *pushed = pushed.wrapping_add(1));
+            FrameNanosControl::Stop
+        });
+    }
+
+    let policy = VideoSurfaceLayout {
+        frame,
+        source_id: source.inner.id,
+        content_scale: content_scale.unwrap_or_default(),
+    };
+    layout()
+        .modifier(modifier)
+        .layout_policy(policy.clone())
+        .render_policy(policy);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(value: u8, timestamp_ms: Option<u64>) -> VideoFrame {
+        let frame = VideoFrame::rgba(1, 1, VideoPlane::new(vec![value; 4], 4))
+            .expect("plane fits the frame");
+        match timestamp_ms {
+            Some(ms) => frame.with_timestamp(Duration::from_millis(ms)),
+            None => frame,
+        }
+    }
+
+    fn shown(source: &VideoSource, now: Instant) -> Option<u8> {
+        let (frame, _) = source.frame_at(now);
+        frame.map(|frame| match frame.pixels() {
+            VideoPixels::Rgba(plane) => plane.data[0],
+            _ => unreachable!(),
+        })
+    }
+
+    #[test]
+    fn untimed_frames_show_latest() {
+        let source = VideoSource::new();
+        let now = Instant::now();
+        assert_eq!(shown(&source, now), None);
+        source.push_frame(frame(1, None));
+        source.push_frame(frame(2, None));
+        assert_eq!(shown(&source, now), Some(2));
+        assert!(!source.frame_at(now).1);
+    }
+
+    #[test]
+    fn timed_frames_follow_playback_clock() {
+        let source = VideoSource::new();
+        let start = Instant::now();
+        source.push_frame(frame(1, Some(1_000)));
+        source.push_frame(frame(2, Some(1_040)));
+        source.push_frame(frame(3, Some(1_080)));
+
+        assert_eq!(shown(&source, start), Some(1));
+        assert!(source.frame_at(start).1);
+        assert_eq!(shown(&source, start + Duration::from_millis(50)), Some(2));
+        assert_eq!(shown(&source, start + Duration::from_millis(90)), Some(3));
+        assert!(!source.frame_at(start + Duration::from_millis(90)).1);
+    }
+
+    #[test]
+    fn rewound_timestamps_restart_clock() {
+        let source = VideoSource::new();
+        let start = Instant::now();
+        source.push_frame(frame(1, Some(5_000)));
+        assert_eq!(shown(&source, start), Some(1));
+
+        let later = start + Duration::from_millis(10);
+        source.push_frame(frame(2, Some(0)));
+        assert_eq!(shown(&source, later), Some(2));
+    }
+
+    #[test]
+    fn queue_drops_oldest_frames() {
+        let source = VideoSource::new();
+        let start = Instant::now();
+        for index in 0..MAX_QUEUED_FRAMES as u64 + 2 {
+            source.push_frame(frame(index as u8, Some(index * 10)));
+        }
+        // The first two frames were dropped, so the clock starts at frame 2.
+        assert_eq!(shown(&source, start), Some(2));
+    }
+
+    #[test]
+    fn clear_blanks_source() {
+        let source = VideoSource::new();
+        source.push_frame(frame(1, None));
+        source.clear();
+        assert_eq!(shown(&source, Instant::now()), None);
+    }
+}
@@ -0,0 +1,243 @@
+//! Offscreen viewports for passes recorded by the application.
+//!
+//! ## Usage
+//!
+//! Embed 3D scenes, plots, or custom `wgpu` passes inside the UI.
+use std::sync::{
+    Arc,
+    atomic::{AtomicU64, Ordering},
+};
+
+use tessera_ui::{
+    ComputedData, FrameNanosControl, LayoutResult, MeasurementError, Modifier, Px,
+    layout::{LayoutPolicy, MeasureScope, RenderInput, RenderPolicy, layout},
+    receive_frame_nanos, remember, remember_visibility, tessera,
+};
+
+pub use crate::pipelines::viewport::command::ViewportRenderContext;
+use crate::{
+    pipelines::viewport::command::{ViewportCommand, ViewportRenderFn},
+    subscription::{Notifier, observe},
+};
+
+static NEXT_TARGET_SLOT: AtomicU64 = AtomicU64::new(1);
+
+struct SceneInner {
+    render: ViewportRenderFn,
+    version: AtomicU64,
+    notifier: Arc<Notifier>,
+}
+
+/// Render callback shown by [`viewport`].
+///
+/// The callback records its passes into the [`ViewportRenderContext`] it
+/// receives. It runs on the render thread when a viewport first appears,
+/// when its size changes, and after [`ViewportScene::invalidate`]; in between,
+/// the last rendered image is composited again. Clones share the same
+/// callback and invalidation.
+#[derive(Clone)]
+pub struct ViewportScene {
+    inner: Arc<SceneInner>,
+}
+
+impl PartialEq for ViewportScene {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl ViewportScene {
+    /// Creates a scene rendered by `render`.
+    pub fn new<F>(render: F) -> Self
+    where
+        F: Fn(&mut ViewportRenderContext<'_>) + Send + Sync + 'static,
+    {
+        Self {
+            inner: Arc::new(SceneInner {
+                render: Arc::new(render),
+                version: AtomicU64::new(0),
+                notifier: Notifier::new(),
+            }),
+        }
+    }
+
+    /// Re-runs the callback for every viewport showing this scene on the
+    /// next frame. Can be called from any thread.
+    pub fn invalidate(&self) {
+        self.inner.version.fetch_add(1, Ordering::AcqRel);
+        self.inner.notifier.notify();
+    }
+
+    fn version(&self) -> u64 {
+        self.inner.version.load(Ordering::Acquire)
+    }
+}
+
+#[derive(Clone)]
+struct ViewportLayout {
+    scene: ViewportScene,
+    target_slot: u64,
+    version: u64,
+}
+
+impl PartialEq for ViewportLayout {
+    fn eq(&self, other: &Self) -> bool {
+        self.scene == other.scene
+            && self.target_slot == other.target_slot
+            && self.version == other.version
+    }
+}
+
+impl LayoutPolicy for ViewportLayout {
+    fn measure(&self, input: &MeasureScope<'_>) -> Result<LayoutResult, MeasurementError> {
+        let width = input.parent_constraint().width().clamp(Px::ZERO);
+        let height = input.parent_constraint().height().clamp(Px::ZERO);
+        Ok(LayoutResult::new(ComputedData { width, height }))
+    }
+
+    fn measure_eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl RenderPolicy for ViewportLayout {
+    fn record(&self, input: &mut RenderInput<'_>) {
+        input
+            .metadata_mut()
+            .fragment_mut()
+            .push_composite_command(ViewportCommand {
+                render: self.scene.inner.render.clone(),
+                target_slot: self.target_slot,
+                version: self.version,
+            });
+    }
+}
+
+/// # viewport
+///
+/// Reserves a rectangle whose pixels are rendered by application passes.
+///
+/// ## Usage
+///
+/// Show 3D scenes, plots, or other custom `wgpu` rendering inside the UI.
+///
+/// ## Parameters
+///
+/// - `scene` — callback that records into the viewport targets; nothing is
+///   drawn when unset.
+/// - `continuous` — re-runs the callback every frame while the viewport is
+///   visible, for animated content; defaults to `false`.
+/// - `modifier` — node-local layout, drawing, and interaction modifiers.
+///
+/// The viewport has no intrinsic size, so give it one with a size modifier.
+/// The callback gets the `wgpu` device and queue, a command encoder, and a
+/// color and depth target sized to the viewport in physical pixels. The
+/// result is composited into the scene with the node's clip and opacity.
+///
+/// ## Examples
+///
+/// ```
+/// # use tessera_ui::tessera;
+/// # #[tessera]
+/// # fn component() {
+/// use tessera_components::{
+///     modifier::ModifierExt as _,
+///     viewport::{ViewportScene, viewport},
+/// };
+/// use tessera_ui::{Dp, Modifier, wgpu};
+///
+/// let scene = ViewportScene::new(|context| {
+///     let _pass = context
+///         .encoder
+///         .begin_render_pass(&wgpu::RenderPassDescriptor {
+///             label: Some("scene"),
+///             color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+///                 view: context.color_view,
+///                 depth_slice: None,
+///                 resolve_target: None,
+///                 ops: wgpu::Operations {
+///                     load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+///                     store: wgpu::StoreOp::Store,
+///                 },
+///             })],
+///             depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+///                 view: context.depth_view,
+///                 depth_ops: Some(wgpu::Operations {
+///                     load: wgpu::LoadOp::Clear(1.0),
+///                     store: wgpu::StoreOp::Discard,
+///                 }),
+///                 stencil_ops: None,
+///             }),
+///             ..Default::default()
+///         });
+/// });
+///
+/// viewport()
+///     .scene(scene)
+///     .modifier(Modifier::new().size(Dp(320.0), Dp(240.0)));
+/// # }
+/// ```
+#[tessera]
+pub fn viewport(
+    #[prop(into)] scene: Option<ViewportScene>,
+    continuous: Option<bool>,
+    modifier: Option<Modifier>,
+) {
+    let modifier = modifier.unwrap_or_default();
+    let target_slot = remember(|| NEXT_TARGET_SLOT.fetch_add(1, Ordering::Relaxed)).get();
+    let Some(scene) = scene else {
+        layout().modifier(modifier);
+        return;
+    };
+
+    observe(&scene.inner.notifier);
+
+    let frames = remember(|| 0_u64);
+    let visible = remember_visibility();
+    if continuous.unwrap_or(false) && visible.get() {
+        receive_frame_nanos(move |_| {
+            frames.with_mut(|frames| *frames = frames.wrapping_add(1));
+            FrameNanosControl::Stop
+        });
+    }
+
+    // Both counters only grow, so their sum changes whenever either does.
+    let version = scene.version().wrapping_add(frames.get());
+    let policy = ViewportLayout {
+        scene,
+        target_slot,
+        version,
+    };
+    layout()
+        .modifier(modifier)
+        .layout_policy(policy.clone())
+        .render_policy(policy);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalidate_advances_scene_version() {
+        let scene = ViewportScene::new(|_| {});
+        let before = scene.version();
+        scene.clone().invalidate();
+        assert_eq!(scene.version(), before + 1);
+    }
+
+    #[test]
+    fn layouts_differ_after_invalidation() {
+        let scene = ViewportScene::new(|_| {});
+        let layout = |scene: &ViewportScene| ViewportLayout {
+            scene: scene.clone(),
+            target_slot: 1,
+            version: scene.version(),
+        };
+        let before = layout(&scene);
+        assert!(before == layout(&scene));
+        scene.invalidate();
+        assert!(before != layout(&scene));
+        assert!(before != layout(&ViewportScene::new(|_| {})));
+    }
+}
@@ -0,0 +1,694 @@
+//! Zoomable container for pinch, pan, and double-tap zoom.
+//!
+//! ## Usage
+//!
+//! Show photos, maps, or documents that users zoom and pan with touch or a
+//! trackpad.
+use std::time::Duration;
+
+use tessera_foundation::{
+    gesture::{TapRecognizer, TapSettings},
+    modifier::{TransformDelta, TransformVelocity, TransformableArgs},
+};
+use tessera_ui::{
+    AxisConstraint, Callback, CallbackWith, ComputedData, Constraint, LayoutResult,
+    MeasurementError, Modifier, PointerEventPass, PointerInput, PointerInputModifierNode, Px,
+    PxPosition, RenderSlot, State,
+    layout::{LayoutPolicy, MeasureScope, PlacementScope, RenderInput, RenderPolicy, layout},
+    modifier::ModifierCapabilityExt as _,
+    receive_frame_nanos, remember, tessera,
+    time::Instant,
+};
+
+use crate::{modifier::ModifierExt as _, pos_misc::is_position_inside_bounds};
+
+const DEFAULT_MIN_SCALE: f32 = 1.0;
+const DEFAULT_MAX_SCALE: f32 = 5.0;
+const DEFAULT_DOUBLE_TAP_SCALE: f32 = 2.5;
+const DOUBLE_TAP_TIMEOUT: Duration = Duration::from_millis(300);
+const DOUBLE_TAP_SLOP_PX: f32 = 40.0;
+const ZOOM_ANIMATION_NANOS: u64 = 250_000_000;
+const FLING_DECAY_RATE: f32 = 5.0;
+const MIN_FLING_VELOCITY: f32 = 10.0;
+const MAX_FLING_VELOCITY: f32 = 6000.0;
+const SCALE_EPSILON: f32 = 0.001;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ZoomAnimation {
+    from_scale: f32,
+    from_offset: [f32; 2],
+    to_scale: f32,
+    to_offset: [f32; 2],
+    start_nanos: Option<u64>,
+}
+
+/// Controller for [`zoomable`] containers.
+///
+/// Holds the current zoom scale, content offset and rotation. Share it with
+/// other components to read or reset the zoom state.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ZoomableController {
+    scale: f32,
+    offset: [f32; 2],
+    rotation: f32,
+    viewport: [f32; 2],
+    min_scale: f32,
+    max_scale: f32,
+    velocity: [f32; 2],
+    animation: Option<ZoomAnimation>,
+    last_frame_nanos: Option<u64>,
+    last_tap: Option<(Instant, PxPosition)>,
+}
+
+impl ZoomableController {
+    /// Creates a controller at the unzoomed scale.
+    pub fn new() -> Self {
+        Self {
+            scale: DEFAULT_MIN_SCALE,
+            offset: [0.0, 0.0],
+            rotation: 0.0,
+            viewport: [0.0, 0.0],
+            min_scale: DEFAULT_MIN_SCALE,
+            max_scale: DEFAULT_MAX_SCALE,
+            velocity: [0.0, 0.0],
+            animation: None,
+            last_frame_nanos: None,
+            last_tap: None,
+        }
+    }
+
+    /// Returns the current zoom scale.
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Returns the content offset relative to the container origin.
+    pub fn offset(&self) -> PxPosition {
+        PxPosition::from_f32_arr2(self.offset)
+    }
+
+    /// Returns the accumulated rotation in radians, clockwise positive.
+    ///
+    /// Stays `0.0` unless the container enables rotation.
+    pub fn rotation(&self) -> f32 {
+        self.rotation
+    }
+
+    /// Returns whether the content is zoomed past the minimum scale.
+    pub fn is_zoomed(&self) -> bool {
+        self.scale > self.min_scale + SCALE_EPSILON
+    }
+
+    /// Resets the zoom scale, offset and rotation immediately.
+    pub fn reset(&mut self) {
+        self.stop_motion();
+        self.scale = self.min_scale;
+        self.rotation = 0.0;
+        self.offset = self.clamped_offset(self.offset, self.scale);
+    }
+
+    /// Animates to `scale`, keeping the content under `focus` in place.
+    ///
+    /// `focus` is relative to the container origin.
+    pub fn animate_zoom_to(&mut self, scale: f32, focus: PxPosition) {
+        let to_scale = scale.clamp(self.min_scale, self.max_scale);
+        let to_offset = self.clamped_offset(
+            zoom_offset_around(self.offset, self.scale, to_scale, focus.to_f32_arr2()),
+            to_scale,
+        );
+        self.velocity = [0.0, 0.0];
+        self.animation = Some(ZoomAnimation {
+            from_scale: self.scale,
+            from_offset: self.offset,
+            to_scale,
+            to_offset,
+            start_nanos: None,
+        });
+    }
+
+    fn set_scale_range(&mut self, min_scale: f32, max_scale: f32) {
+        let min_scale = min_scale.max(SCALE_EPSILON);
+        self.min_scale = min_scale;
+        self.max_scale = max_scale.max(min_scale);
+        self.scale = self.scale.clamp(self.min_scale, self.max_scale);
+        self.offset = self.clamped_offset(self.offset, self.scale);
+    }
+
+    fn set_viewport(&mut self, viewport: [f32; 2]) {
+        self.viewport = viewport;
+        self.offset = self.clamped_offset(self.offset, self.scale);
+    }
+
+    fn apply_transform(&mut self, delta: TransformDelta, rotation_enabled: bool) {
+        self.stop_motion();
+        if rotation_enabled {
+            self.rotation = wrap_rotation(self.rotation + delta.rotation);
+        }
+        let next_scale = (self.scale * delta.zoom).clamp(self.min_scale, self.max_scale);
+        let mut offset = zoom_offset_around(
+            self.offset,
+            self.scale,
+            next_scale,
+            delta.centroid.to_f32_arr2(),
+        );
+        offset[0] += delta.pan_x;
+        offset[1] += delta.pan_y;
+        self.scale = next_scale;
+        self.offset = self.clamped_offset(offset, next_scale);
+    }
+
+    fn start_fling(&mut self, velocity: TransformVelocity) {
+        let velocity = [
+            velocity.x.clamp(-MAX_FLING_VELOCITY, MAX_FLING_VELOCITY),
+            velocity.y.clamp(-MAX_FLING_VELOCITY, MAX_FLING_VELOCITY),
+        ];
+        if velocity[0].hypot(velocity[1]) < MIN_FLING_VELOCITY {
+            return;
+        }
+        self.velocity = velocity;
+        self.last_frame_nanos = None;
+    }
+
+    fn handle_tap(
+        &mut self,
+        timestamp: Instant,
+        position: PxPosition,
+        double_tap_scale: f32,
+    ) -> bool {
+        let is_double_tap = self.last_tap.is_some_and(|(last_time, last_position)| {
+            timestamp.duration_since(last_time) <= DOUBLE_TAP_TIMEOUT
+                && last_position.distance_to(position) <= DOUBLE_TAP_SLOP_PX
+        });
+        if !is_double_tap {
+            self.last_tap = Some((timestamp, position));
+            return false;
+        }
+
+        self.last_tap = None;
+        let target = if self.is_zoomed() {
+            self.min_scale
+        } else {
+            double_tap_scale
+        };
+        self.animate_zoom_to(target, position);
+        true
+    }
+
+    fn has_pending_animation_frame(&self) -> bool {
+        self.animation.is_some() || self.velocity != [0.0, 0.0]
+    }
+
+    fn tick(&mut self, frame_nanos: u64) {
+        let dt = self
+            .last_frame_nanos
+            .map(|last| frame_nanos.saturating_sub(last) as f32 / 1_000_000_000.0)
+            .unwrap_or(0.0);
+        self.last_frame_nanos = Some(frame_nanos);
+
+        if let Some(mut animation) = self.animation {
+            let start = *animation.start_nanos.get_or_insert(frame_nanos);
+            let progress = (frame_nanos.saturating_sub(start) as f32 / ZOOM_ANIMATION_NANOS as f32)
+                .clamp(0.0, 1.0);
+            let eased = 1.0 - (1.0 - progress).powi(3);
+            self.scale = lerp(animation.from_scale, animation.to_scale, eased);
+            self.offset = [
+                lerp(animation.from_offset[0], animation.to_offset[0], eased),
+                lerp(animation.from_offset[1], animation.to_offset[1], eased),
+            ];
+            self.animation = (progress < 1.0).then_some(animation);
+            return;
+        }
+
+        if self.velocity == [0.0, 0.0] || dt <= 0.0 {
+            return;
+        }
+        let target = [
+            self.offset[0] + self.velocity[0] * dt,
+            self.offset[1] + self.velocity[1] * dt,
+        ];
+        let clamped = self.clamped_offset(target, self.scale);
+        for ((velocity, clamped), target) in self.velocity.iter_mut().zip(clamped).zip(target) {
+            // Hitting an edge ends the fling along that axis.
+            if (clamped - target).abs() > f32::EPSILON {
+                *velocity = 0.0;
+            }
+        }
+        self.offset = clamped;
+
+        let decay = (-FLING_DECAY_RATE * dt).exp();
+        self.velocity = [self.velocity[0] * decay, self.velocity[1] * decay];
+        if self.velocity[0].hypot(self.velocity[1]) < MIN_FLING_VELOCITY {
+            self.velocity = [0.0, 0.0];
+        }
+    }
+
+    fn stop_motion(&mut self) {
+        self.animation = None;
+        self.velocity = [0.0, 0.0];
+        self.last_frame_nanos = None;
+    }
+
+    fn clamped_offset(&self, offset: [f32; 2], scale: f32) -> [f32; 2] {
+        [
+            clamp_axis_offset(offset[0], self.viewport[0], scale),
+            clamp_axis_offset(offset[1], self.viewport[1], scale),
+        ]
+    }
+}
+
+impl Default for ZoomableController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn wrap_rotation(rotation: f32) -> f32 {
+    use std::f32::consts::{PI, TAU};
+
+    (rotation + PI).rem_euclid(TAU) - PI
+}
+
+fn lerp(from: f32, to: f32, fraction: f32) -> f32 {
+    from + (to - from) * fraction
+}
+
+fn zoom_offset_around(offset: [f32; 2], scale: f32, next_scale: f32, focus: [f32; 2]) -> [f32; 2] {
+    let ratio = next_scale / scale.max(SCALE_EPSILON);
+    [
+        focus[0] - (focus[0] - offset[0]) * ratio,
+        focus[1] - (focus[1] - offset[1]) * ratio,
+    ]
+}
+
+/// Keeps scaled content covering the viewport, or centered when it is smaller.
+fn clamp_axis_offset(offset: f32, viewport: f32, scale: f32) -> f32 {
+    let content = viewport * scale;
+    if content <= viewport {
+        (viewport - content) / 2.0
+    } else {
+        offset.clamp(viewport - content, 0.0)
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct ZoomableLayout {
+    scale: f32,
+    offset: [f32; 2],
+}
+
+impl ZoomableLayout {
+    /// Clamps the controller offset against the measured viewport.
+    ///
+    /// The controller only learns the viewport from pointer input, so a
+    /// resize is reflected here before the controller catches up.
+    fn placement_offset(&self, size: ComputedData) -> PxPosition {
+        PxPosition::from_f32_arr2([
+            clamp_axis_offset(self.offset[0], size.width.to_f32(), self.scale),
+            clamp_axis_offset(self.offset[1], size.height.to_f32(), self.scale),
+        ])
+    }
+}
+
+impl LayoutPolicy for ZoomableLayout {
+    fn measure(&self, input: &MeasureScope<'_>) -> Result<LayoutResult, MeasurementError> {
+        let mut result = LayoutResult::default();
+        let parent = input.parent_constraint();
+        let size = ComputedData {
+            width: resolve_viewport_axis(parent.width()),
+            height: resolve_viewport_axis(parent.height()),
+        };
+
+        let content_constraint = Constraint::exact(
+            Px::saturating_from_f32(size.width.to_f32() * self.scale),
+            Px::saturating_from_f32(size.height.to_f32() * self.scale),
+        );
+        let offset = self.placement_offset(size);
+        for child in input.children() {
+            child.measure(&content_constraint)?;
+            result.place_child(child, offset);
+        }
+
+        Ok(result.with_size(size))
+    }
+
+    fn measure_eq(&self, other: &Self) -> bool {
+        self.scale == other.scale
+    }
+
+    fn placement_eq(&self, other: &Self) -> bool {
+        self.scale == other.scale && self.offset == other.offset
+    }
+
+    fn place_children(&self, input: &PlacementScope<'_>) -> Option<Vec<(u64, PxPosition)>> {
+        let mut result = LayoutResult::default();
+        let offset = self.placement_offset(input.size());
+        for child in input.children() {
+            result.place_child(child, offset);
+        }
+        Some(result.into_placements())
+    }
+}
+
+impl RenderPolicy for ZoomableLayout {
+    fn record(&self, input: &mut RenderInput<'_>) {
+        input.metadata_mut().set_clips_children(true);
+    }
+}
+
+fn resolve_viewport_axis(constraint: AxisConstraint) -> Px {
+    constraint
+        .resolve_max()
+        .unwrap_or_else(|| constraint.resolve_min())
+}
+
+/// Keeps the controller viewport in sync with the measured container size
+/// before the transform and double-tap handlers run.
+struct ZoomableViewportModifierNode {
+    controller: State<ZoomableController>,
+}
+
+impl PointerInputModifierNode for ZoomableViewportModifierNode {
+    fn on_pointer_input(&self, input: PointerInput<'_>) {
+        if input.pass != PointerEventPass::Initial || input.pointer_changes.is_empty() {
+            return;
+        }
+        let viewport = [
+            input.computed_data.width.to_f32(),
+            input.computed_data.height.to_f32(),
+        ];
+        if self
+            .controller
+            .with(|controller| controller.viewport != viewport)
+        {
+            self.controller
+                .with_mut(|controller| controller.set_viewport(viewport));
+        }
+    }
+}
+
+struct ZoomableDoubleTapModifierNode {
+    controller: State<ZoomableController>,
+    tap_recognizer: State<TapRecognizer>,
+    double_tap_scale: f32,
+}
+
+impl PointerInputModifierNode for ZoomableDoubleTapModifierNode {
+    fn on_pointer_input(&self, input: PointerInput<'_>) {
+        let within_bounds = input
+            .cursor_position_rel
+            .is_some_and(|position| is_position_inside_bounds(input.computed_data, position));
+        let tap_result = self.tap_recognizer.with_mut(|recognizer| {
+            recognizer.update(
+                input.pass,
+                input.pointer_changes,
+                input.cursor_position_rel,
+                within_bounds,
+            )
+        });
+        if !tap_result.tapped {
+            return;
+        }
+        let (Some(timestamp), Some(position)) =
+            (tap_result.release_timestamp, input.cursor_position_rel)
+        else {
+            return;
+        };
+        self.controller.with_mut(|controller| {
+            controller.handle_tap(timestamp, position, self.double_tap_scale);
+        });
+    }
+}
+
+/// # zoomable
+///
+/// Hosts content that zooms and pans with pinch, trackpad, and double-tap
+/// gestures.
+///
+/// ## Usage
+///
+/// Build image viewers, maps, or document previews that need pinch-to-zoom.
+///
+/// ## Parameters
+///
+/// - `modifier` — modifier chain applied to the zoomable container.
+/// - `min_scale` — smallest zoom scale; defaults to `1.0`.
+/// - `max_scale` — largest zoom scale; defaults to `5.0`.
+/// - `double_tap_scale` — scale applied by a double tap when unzoomed; defaults
+///   to `2.5`.
+/// - `rotation_enabled` — whether two-finger and trackpad rotation update
+///   [`ZoomableController::rotation`]; defaults to `false`.
+/// - `enabled` — whether zoom and pan gestures are handled.
+/// - `controller` — optional external controller for reading or resetting the
+///   zoom state.
+/// - `child` — content rendered at the zoomed size.
+///
+/// The container fills the available space. Single-pointer panning only
+/// starts once the content is zoomed, so an unzoomed container leaves drags
+/// to enclosing pagers and scrollables.
+///
+/// Layout stays axis-aligned, so rotation is not applied to the child. Read
+/// [`ZoomableController::rotation`] in the child and draw rotated content,
+/// such as an icon with a rotation, to follow the gesture.
+///
+/// ## Examples
+///
+/// ```
+/// use tessera_components::text::text;
+/// use tessera_components::zoomable::{ZoomableController, zoomable};
+/// use tessera_ui::{remember, tessera};
+/// # use tessera_components::theme::{MaterialTheme, material_theme};
+///
+/// #[tessera]
+/// fn demo() {
+///     let controller = remember(ZoomableController::new);
+///     assert!(!controller.with(|c| c.is_zoomed()));
+///
+///     material_theme()
+///         .theme(|| MaterialTheme::default())
+///         .child(move || {
+///             zoomable().controller(controller).max_scale(8.0).child(|| {
+///                 text().content("Zoom me");
+///             });
+///         });
+/// }
+///
+/// demo();
+/// ```
+#[tessera]
+pub fn zoomable(
+    modifier: Option<Modifier>,
+    min_scale: Option<f32>,
+    max_scale: Option<f32>,
+    double_tap_scale: Option<f32>,
+    rotation_enabled: Option<bool>,
+    enabled: Option<bool>,
+    controller: Option<State<ZoomableController>>,
+    child: Option<RenderSlot>,
+) {
+    let min_scale = min_scale.unwrap_or(DEFAULT_MIN_SCALE);
+    let max_scale = max_scale.unwrap_or(DEFAULT_MAX_SCALE);
+    let double_tap_scale = double_tap_scale.unwrap_or(DEFAULT_DOUBLE_TAP_SCALE);
+    let rotation_enabled = rotation_enabled.unwrap_or(false);
+    let enabled = enabled.unwrap_or(true);
+    let controller = controller.unwrap_or_else(|| remember(ZoomableController::new));
+
+    let should_set_range = controller.with(|current| {
+        let mut next = current.clone();
+        next.set_scale_range(min_scale, max_scale);
+        next != *current
+    });
+    if should_set_range {
+        controller.with_mut(|current| current.set_scale_range(min_scale, max_scale));
+    }
+
+    if controller.with(|current| current.has_pending_animation_frame()) {
+        receive_frame_nanos(move |frame_nanos| {
+            let has_pending_animation_frame = controller.with_mut(|current| {
+                current.tick(frame_nanos);
+                current.has_pending_animation_frame()
+            });
+            if has_pending_animation_frame {
+                tessera_ui::FrameNanosControl::Continue
+            } else {
+                tessera_ui::FrameNanosControl::Stop
+            }
+        });
+    }
+
+    let (scale, offset, is_zoomed) =
+        controller.with(|current| (current.scale(), current.offset(), current.is_zoomed()));
+    let mut modifier = modifier.unwrap_or_default().clip_to_bounds();
+    if enabled {
+        let tap_recognizer = remember(|| TapRecognizer::new(TapSettings::default()));
+        modifier = modifier
+            .push_pointer_preview_input(ZoomableViewportModifierNode { controller })
+            .transformable_with(TransformableArgs {
+                on_transform: CallbackWith::new(move |delta| {
+                    controller.with_mut(|current| current.apply_transform(delta, rotation_enabled));
+                }),
+                single_pointer_pan: is_zoomed,
+                on_transform_started: Some(Callback::new(move || {
+                    controller.with_mut(|current| current.stop_motion());
+                })),
+                on_transform_stopped: Some(CallbackWith::new(move |velocity| {
+                    controller.with_mut(|current| current.start_fling(velocity));
+                })),
+                ..Default::default()
+            })
+            .push_pointer_input(ZoomableDoubleTapModifierNode {
+                controller,
+                tap_recognizer,
+                double_tap_scale,
+            });
+    }
+
+    let policy = ZoomableLayout {
+        scale,
+        offset: offset.to_f32_arr2(),
+    };
+    layout()
+        .modifier(modifier)
+        .layout_policy(policy.clone())
+        .render_policy(policy)
+        .child(move || {
+            if let Some(child) = child {
+                child.render();
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn controller_with_viewport(width: f32, height: f32) -> ZoomableController {
+        let mut controller = ZoomableController::new();
+        controller.set_viewport([width, height]);
+        controller
+    }
+
+    #[test]
+    fn clamp_axis_offset_keeps_content_covering_viewport() {
+        assert_eq!(clamp_axis_offset(10.0, 100.0, 2.0), 0.0);
+        assert_eq!(clamp_axis_offset(-150.0, 100.0, 2.0), -100.0);
+        assert_eq!(clamp_axis_offset(-40.0, 100.0, 2.0), -40.0);
+    }
+
+    #[test]
+    fn clamp_axis_offset_centers_smaller_content() {
+        assert_eq!(clamp_axis_offset(-30.0, 100.0, 0.5), 25.0);
+    }
+
+    #[test]
+    fn zoom_keeps_focus_point_fixed() {
+        let mut controller = controller_with_viewport(200.0, 100.0);
+        controller.apply_transform(
+            TransformDelta {
+                zoom: 2.0,
+                centroid: PxPosition::new(Px(100), Px(50)),
+                ..Default::default()
+            },
+            false,
+        );
+        assert_eq!(controller.scale(), 2.0);
+        assert_eq!(controller.offset, [-100.0, -50.0]);
+    }
+
+    #[test]
+    fn zoom_and_pan_are_clamped() {
+        let mut controller = controller_with_viewport(100.0, 100.0);
+        controller.apply_transform(
+            TransformDelta {
+                zoom: 20.0,
+                ..Default::default()
+            },
+            false,
+        );
+        assert_eq!(controller.scale(), DEFAULT_MAX_SCALE);
+
+        controller.apply_transform(
+            TransformDelta {
+                pan_x: 1000.0,
+                pan_y: -1000.0,
+                ..Default::default()
+            },
+            false,
+        );
+        assert_eq!(controller.offset, [0.0, -400.0]);
+    }
+
+    #[test]
+    fn rotation_accumulates_only_when_enabled() {
+        let mut controller = controller_with_viewport(100.0, 100.0);
+        let quarter_turn = TransformDelta {
+            rotation: std::f32::consts::FRAC_PI_2,
+            ..Default::default()
+        };
+        controller.apply_transform(quarter_turn, false);
+        assert_eq!(controller.rotation(), 0.0);
+
+        controller.apply_transform(quarter_turn, true);
+        controller.apply_transform(quarter_turn, true);
+        controller.apply_transform(quarter_turn, true);
+        assert!((controller.rotation() + std::f32::consts::FRAC_PI_2).abs() < 1e-5);
+
+        controller.reset();
+        assert_eq!(controller.rotation(), 0.0);
+    }
+
+    #[test]
+    fn layout_clamps_offset_to_the_measured_viewport() {
+        let layout = ZoomableLayout {
+            scale: 2.0,
+            offset: [-150.0, 40.0],
+        };
+        let size = ComputedData {
+            width: Px(100),
+            height: Px(100),
+        };
+        assert_eq!(
+            layout.placement_offset(size),
+            PxPosition::new(Px(-100), Px(0))
+        );
+    }
+
+    #[test]
+    fn fling_decays_and_stops_at_bounds() {
+        let mut controller = controller_with_viewport(100.0, 100.0);
+        controller.apply_transform(
+            TransformDelta {
+                zoom: 2.0,
+                centroid: PxPosition::new(Px(50), Px(50)),
+                ..Default::default()
+            },
+            false,
+        );
+        controller.start_fling(TransformVelocity { x: -5000.0, y: 0.0 });
+        controller.tick(0);
+        controller.tick(100_000_000);
+        assert_eq!(controller.offset[0], -100.0);
+        assert_eq!(controller.velocity[0], 0.0);
+        assert!(!controller.has_pending_animation_frame());
+    }
+
+    #[test]
+    fn double_tap_toggles_zoom() {
+        let mut controller = controller_with_viewport(100.0, 100.0);
+        let start = Instant::now();
+        let position = PxPosition::new(Px(50), Px(50));
+        assert!(!controller.handle_tap(start, position, 2.5));
+        assert!(controller.handle_tap(start + Duration::from_millis(120), position, 2.5));
+
+        controller.tick(0);
+        controller.tick(ZOOM_ANIMATION_NANOS);
+        assert_eq!(controller.scale(), 2.5);
+        assert!(controller.is_zoomed());
+
+        let later = start + Duration::from_secs(2);
+        assert!(!controller.handle_tap(later, position, 2.5));
+        assert!(controller.handle_tap(later + Duration::from_millis(120), position, 2.5));
+        controller.tick(ZOOM_ANIMATION_NANOS * 2);
+        controller.tick(ZOOM_ANIMATION_NANOS * 3);
+        assert!(!controller.is_zoomed());
+    }
+}
@@ -0,0 +1,91 @@
+# Built-in component labels for tessera-components.
+
+## Date picker
+
+date-picker-title-picker = Select date
+date-picker-title-input = Enter date
+date-picker-no-selection = No date selected
+date-picker-year = Year
+date-picker-month = Month
+date-picker-day = Day
+date-picker-input-hint-selected = Use the steppers to adjust the selected date.
+date-picker-input-hint-empty = Use the steppers to pick a date.
+date-picker-switch-to-input = Input
+date-picker-switch-to-calendar = Calendar
+# `$year` is passed as text so it is not digit-grouped.
+date-picker-selected-date = { $month-short-name } { $day }, { $year }
+date-picker-month-year = { $month-name } { $year }
+
+month-name = { $month ->
+    [1] January
+    [2] February
+    [3] March
+    [4] April
+    [5] May
+    [6] June
+    [7] July
+    [8] August
+    [9] September
+    [10] October
+    [11] November
+   *[12] December
+}
+month-short-name = { $month ->
+    [1] Jan
+    [2] Feb
+    [3] Mar
+    [4] Apr
+    [5] May
+    [6] Jun
+    [7] Jul
+    [8] Aug
+    [9] Sep
+    [10] Oct
+    [11] Nov
+   *[12] Dec
+}
+weekday-short-name = { $weekday ->
+    [mon] Mon
+    [tue] Tue
+    [wed] Wed
+    [thu] Thu
+    [fri] Fri
+    [sat] Sat
+   *[sun] Sun
+}
+
+## Time picker
+
+time-picker-title = Select time
+time-picker-hour = Hour
+time-picker-minute = Minute
+time-picker-am = AM
+time-picker-pm = PM
+time-picker-switch-to-input = Input
+time-picker-switch-to-picker = Picker
+
+## Exposed dropdown menu
+
+exposed-dropdown-menu-no-results = No results
+exposed-dropdown-menu-remove = Remove { $label }
+
+## Bottom sheet
+
+bottom-sheet-dismiss = Dismiss
+
+## Search bar
+
+search-bar-placeholder = Search
+
+## Snackbar
+
+snackbar-dismiss = Dismiss
+
+## Data table
+
+data-table-sorted-ascending = Sorted ascending
+data-table-sorted-descending = Sorted descending
+
+## Tree view
+
+tree-view-loading = Loading…
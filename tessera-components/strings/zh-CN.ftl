@@ -0,0 +1,64 @@
+# tessera-components 内置组件文本。
+
+## Date picker
+
+date-picker-title-picker = 选择日期
+date-picker-title-input = 输入日期
+date-picker-no-selection = 未选择日期
+date-picker-year = 年
+date-picker-month = 月
+date-picker-day = 日
+date-picker-input-hint-selected = 使用步进按钮调整所选日期。
+date-picker-input-hint-empty = 使用步进按钮选择日期。
+date-picker-switch-to-input = 输入
+date-picker-switch-to-calendar = 日历
+date-picker-selected-date = { $year }年{ $month }月{ $day }日
+date-picker-month-year = { $year }年{ $month }月
+
+month-name = { $month }月
+month-short-name = { $month }月
+weekday-short-name = { $weekday ->
+    [mon] 一
+    [tue] 二
+    [wed] 三
+    [thu] 四
+    [fri] 五
+    [sat] 六
+   *[sun] 日
+}
+
+## Time picker
+
+time-picker-title = 选择时间
+time-picker-hour = 时
+time-picker-minute = 分
+time-picker-am = 上午
+time-picker-pm = 下午
+time-picker-switch-to-input = 输入
+time-picker-switch-to-picker = 表盘
+
+## Exposed dropdown menu
+
+exposed-dropdown-menu-no-results = 无结果
+exposed-dropdown-menu-remove = 移除{ $label }
+
+## Bottom sheet
+
+bottom-sheet-dismiss = 关闭
+
+## Search bar
+
+search-bar-placeholder = 搜索
+
+## Snackbar
+
+snackbar-dismiss = 关闭
+
+## Data table
+
+data-table-sorted-ascending = 升序排列
+data-table-sorted-descending = 降序排列
+
+## Tree view
+
+tree-view-loading = 加载中…
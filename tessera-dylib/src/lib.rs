@@ -0,0 +1,28 @@
+//! Dynamically linked tessera-ui runtime for hot reload.
+//!
+//! ## Usage
+//!
+//! Add this crate as a dependency of the app behind a `hot-reload` feature.
+//! `cargo tessera dev --hot-reload` enables the feature so the host binary and
+//! every reloaded app library share one copy of the tessera-ui runtime, which
+//! keeps memoized state alive across reloads.
+//!
+//! ```toml
+//! [features]
+//! hot-reload = ["dep:tessera-dylib"]
+//!
+//! [dependencies]
+//! tessera-dylib = { version = "0.1", optional = true }
+//! ```
+//!
+//! Then reference it from the app library so it is linked:
+//!
+//! ```rust,ignore
+//! #[cfg(feature = "hot-reload")]
+//! use tessera_dylib as _;
+//! ```
+//!
+//! Do not enable it for release builds.
+#![allow(unused_imports)]
+
+use tessera_ui;
@@ -1,4 +1,5 @@
-//! Pointer gesture recognizers for tap, drag, long-press, and scroll.
+//! Pointer gesture recognizers for tap, drag, long-press, scroll, and
+//! multi-pointer transform.
 //!
 //! ## Usage
 //!
@@ -9,11 +10,13 @@ use std::time::Duration;
 
 use tessera_ui::{
     CursorEventContent, GestureState, PointerChange, PointerEventPass, PointerId,
-    PressKeyEventType, Px, PxPosition, ScrollDeltaUnit, ScrollEventContent, ScrollEventSource,
-    time::Instant,
+    PressKeyEventType, Px, PxPosition, PxRect, PxSize, ScrollDeltaUnit, ScrollEventContent,
+    ScrollEventSource, time::Instant,
 };
 
 const DEFAULT_SLOP_PX: f32 = 8.0;
+const TRANSFORM_VELOCITY_WINDOW: Duration = Duration::from_millis(100);
+const TRANSFORM_VELOCITY_IDLE_CUTOFF: Duration = Duration::from_millis(65);
 
 /// Configuration for tap gesture recognition.
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -634,3 +637,676 @@ impl Default for ScrollRecognizer {
         Self::new(ScrollSettings::default())
     }
 }
+
+/// Configuration for multi-pointer transform recognition.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TransformSettings {
+    /// Minimum pan distance, span change, or rotation arc before the
+    /// transform starts.
+    pub slop_px: f32,
+    /// Whether a single pointer pans the content.
+    ///
+    /// When disabled, at least two pointers are required to start a transform.
+    pub single_pointer_pan: bool,
+    /// Whether pointer changes should be consumed after the transform starts.
+    pub consume_when_transforming: bool,
+}
+
+impl Default for TransformSettings {
+    fn default() -> Self {
+        Self {
+            slop_px: DEFAULT_SLOP_PX,
+            single_pointer_pan: true,
+            consume_when_transforming: true,
+        }
+    }
+}
+
+/// Per-update transform recognition output.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TransformResult {
+    /// Whether the transform started this update.
+    pub started: bool,
+    /// Whether a transform delta was produced this update.
+    pub updated: bool,
+    /// Whether the transform ended this update.
+    pub ended: bool,
+    /// Horizontal pan delta in pixels.
+    pub pan_x: f32,
+    /// Vertical pan delta in pixels.
+    pub pan_y: f32,
+    /// Multiplicative zoom delta, where `1.0` means no change.
+    pub zoom: f32,
+    /// Rotation delta in radians, clockwise positive.
+    pub rotation: f32,
+    /// Centroid of the active pointers relative to the component.
+    pub centroid: Option<PxPosition>,
+    /// Number of pointers participating in the transform.
+    pub pointer_count: usize,
+    /// Horizontal pan velocity in pixels per second when the transform ended.
+    pub velocity_x: f32,
+    /// Vertical pan velocity in pixels per second when the transform ended.
+    pub velocity_y: f32,
+}
+
+impl Default for TransformResult {
+    fn default() -> Self {
+        Self {
+            started: false,
+            updated: false,
+            ended: false,
+            pan_x: 0.0,
+            pan_y: 0.0,
+            zoom: 1.0,
+            rotation: 0.0,
+            centroid: None,
+            pointer_count: 0,
+            velocity_x: 0.0,
+            velocity_y: 0.0,
+        }
+    }
+}
+
+impl TransformResult {
+    fn accumulate(&mut self, delta: TransformDeltaParts) {
+        self.pan_x += delta.pan[0];
+        self.pan_y += delta.pan[1];
+        self.zoom *= delta.zoom;
+        self.rotation += delta.rotation;
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct TransformDeltaParts {
+    pan: [f32; 2],
+    zoom: f32,
+    rotation: f32,
+}
+
+impl TransformDeltaParts {
+    const IDENTITY: Self = Self {
+        pan: [0.0, 0.0],
+        zoom: 1.0,
+        rotation: 0.0,
+    };
+
+    fn then(self, next: Self) -> Self {
+        Self {
+            pan: [self.pan[0] + next.pan[0], self.pan[1] + next.pan[1]],
+            zoom: self.zoom * next.zoom,
+            rotation: self.rotation + next.rotation,
+        }
+    }
+
+    fn exceeds_slop(&self, span: f32, slop_px: f32) -> bool {
+        let pan = self.pan[0].hypot(self.pan[1]);
+        let span_change = (span - span / self.zoom.max(f32::EPSILON)).abs();
+        let arc = span * self.rotation.abs();
+        pan > slop_px || span_change > slop_px || arc > slop_px
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct PointerGeometry {
+    centroid: [f32; 2],
+    span: f32,
+    angle: Option<f32>,
+}
+
+impl PointerGeometry {
+    fn of(pointers: &[(PointerId, [f32; 2])]) -> Option<Self> {
+        if pointers.is_empty() {
+            return None;
+        }
+        let count = pointers.len() as f32;
+        let (sum_x, sum_y) = pointers
+            .iter()
+            .fold((0.0, 0.0), |(x, y), (_, p)| (x + p[0], y + p[1]));
+        let centroid = [sum_x / count, sum_y / count];
+        let span = pointers
+            .iter()
+            .map(|(_, p)| (p[0] - centroid[0]).hypot(p[1] - centroid[1]))
+            .sum::<f32>()
+            / count;
+        let angle = match pointers {
+            [(_, first), (_, second), ..] => {
+                Some((second[1] - first[1]).atan2(second[0] - first[0]))
+            }
+            _ => None,
+        };
+        Some(Self {
+            centroid,
+            span,
+            angle,
+        })
+    }
+
+    fn delta_from(&self, before: &Self, allow_pan: bool) -> TransformDeltaParts {
+        let pan = if allow_pan {
+            [
+                self.centroid[0] - before.centroid[0],
+                self.centroid[1] - before.centroid[1],
+            ]
+        } else {
+            [0.0, 0.0]
+        };
+        let zoom = if before.span > f32::EPSILON && self.span > f32::EPSILON {
+            self.span / before.span
+        } else {
+            1.0
+        };
+        let rotation = match (before.angle, self.angle) {
+            (Some(before), Some(after)) => wrap_angle(after - before),
+            _ => 0.0,
+        };
+        TransformDeltaParts {
+            pan,
+            zoom,
+            rotation,
+        }
+    }
+}
+
+fn wrap_angle(angle: f32) -> f32 {
+    use std::f32::consts::{PI, TAU};
+
+    let wrapped = (angle + PI).rem_euclid(TAU) - PI;
+    if wrapped <= -PI {
+        wrapped + TAU
+    } else {
+        wrapped
+    }
+}
+
+/// Stateful multi-pointer transform recognizer.
+///
+/// Tracks every pointer pressed inside the component and reports pan, zoom,
+/// and rotation deltas around the pointer centroid. Trackpad pinch and
+/// rotation events are reported as zoom and rotation deltas directly.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransformRecognizer {
+    settings: TransformSettings,
+    pointers: Vec<(PointerId, [f32; 2])>,
+    pending: TransformDeltaParts,
+    transforming: bool,
+    pan_samples: Vec<(Instant, [f32; 2])>,
+}
+
+impl TransformRecognizer {
+    /// Creates a transform recognizer with custom settings.
+    pub fn new(settings: TransformSettings) -> Self {
+        Self {
+            settings,
+            pointers: Vec::new(),
+            pending: TransformDeltaParts::IDENTITY,
+            transforming: false,
+            pan_samples: Vec::new(),
+        }
+    }
+
+    /// Updates the recognizer with the current pointer pass and events.
+    ///
+    /// `origin` is the component position in window coordinates and `size`
+    /// its measured size; pointer positions are reported relative to
+    /// `origin`.
+    pub fn update(
+        &mut self,
+        pass: PointerEventPass,
+        pointer_changes: &mut [PointerChange],
+        origin: PxPosition,
+        size: PxSize,
+    ) -> TransformResult {
+        if pass != PointerEventPass::Main {
+            return TransformResult::default();
+        }
+
+        let bounds = PxRect::from_position_size(origin, size);
+        let mut result = TransformResult::default();
+        for change in pointer_changes.iter_mut() {
+            if change.is_consumed() {
+                continue;
+            }
+            match change.content {
+                CursorEventContent::Pressed(PressKeyEventType::Left) => {
+                    let Some(position) = change.position else {
+                        continue;
+                    };
+                    if !bounds.contains(position) || self.pointer_index(change.pointer_id).is_some()
+                    {
+                        continue;
+                    }
+                    self.pointers
+                        .push((change.pointer_id, local_point(position, origin)));
+                    self.pan_samples.clear();
+                }
+                CursorEventContent::Moved(position) => {
+                    let Some(index) = self.pointer_index(change.pointer_id) else {
+                        continue;
+                    };
+                    let Some(before) = PointerGeometry::of(&self.pointers) else {
+                        continue;
+                    };
+                    self.pointers[index].1 = local_point(position, origin);
+                    let Some(after) = PointerGeometry::of(&self.pointers) else {
+                        continue;
+                    };
+                    let allow_pan = self.settings.single_pointer_pan || self.pointers.len() > 1;
+                    let delta = after.delta_from(&before, allow_pan);
+                    self.record_pan_sample(change.timestamp, delta.pan);
+
+                    if !self.transforming {
+                        self.pending = self.pending.then(delta);
+                        if allow_pan && self.pending.exceeds_slop(after.span, self.settings.slop_px)
+                        {
+                            self.transforming = true;
+                            result.started = true;
+                            result.accumulate(self.pending);
+                            result.updated = true;
+                            self.pending = TransformDeltaParts::IDENTITY;
+                        }
+                    } else {
+                        result.accumulate(delta);
+                        result.updated = true;
+                    }
+
+                    if self.transforming && self.settings.consume_when_transforming {
+                        change.consume();
+                    }
+                }
+                CursorEventContent::Scroll(ScrollEventContent {
+                    source: ScrollEventSource::Touch,
+                    ..
+                }) => {
+                    if self.transforming
+                        && self.settings.consume_when_transforming
+                        && self.pointer_index(change.pointer_id).is_some()
+                    {
+                        change.consume();
+                    }
+                }
+                CursorEventContent::Released(PressKeyEventType::Left) => {
+                    let Some(index) = self.pointer_index(change.pointer_id) else {
+                        continue;
+                    };
+                    self.pointers.remove(index);
+                    if self.transforming && self.settings.consume_when_transforming {
+                        change.consume();
+                    }
+                    if self.pointers.is_empty() {
+                        if self.transforming {
+                            let [velocity_x, velocity_y] = self.resolve_velocity(change.timestamp);
+                            result.ended = true;
+                            result.velocity_x = velocity_x;
+                            result.velocity_y = velocity_y;
+                        }
+                        self.reset();
+                    } else {
+                        // Re-anchor velocity tracking so the centroid jump caused
+                        // by lifting a finger does not read as a fling.
+                        self.pan_samples.clear();
+                    }
+                }
+                CursorEventContent::Transform(ref transform) => {
+                    let Some(position) = change.position else {
+                        continue;
+                    };
+                    if !bounds.contains(position) {
+                        continue;
+                    }
+                    result.accumulate(TransformDeltaParts {
+                        pan: [0.0, 0.0],
+                        zoom: transform.zoom,
+                        rotation: transform.rotation,
+                    });
+                    result.updated = true;
+                    result.centroid = Some(position - origin);
+                    if self.settings.consume_when_transforming {
+                        change.consume();
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        result.pointer_count = self.pointers.len();
+        if result.updated
+            && result.centroid.is_none()
+            && let Some(geometry) = PointerGeometry::of(&self.pointers)
+        {
+            result.centroid = Some(PxPosition::from_f32_arr2(geometry.centroid));
+        }
+        result
+    }
+
+    /// Replaces the recognizer settings while preserving active gesture state.
+    pub fn set_settings(&mut self, settings: TransformSettings) {
+        self.settings = settings;
+    }
+
+    /// Returns whether a transform is currently active.
+    pub fn is_transforming(&self) -> bool {
+        self.transforming
+    }
+
+    /// Returns the number of pointers currently tracked.
+    pub fn pointer_count(&self) -> usize {
+        self.pointers.len()
+    }
+
+    fn pointer_index(&self, pointer_id: PointerId) -> Option<usize> {
+        self.pointers.iter().position(|(id, _)| *id == pointer_id)
+    }
+
+    fn record_pan_sample(&mut self, timestamp: Instant, pan: [f32; 2]) {
+        self.pan_samples.push((timestamp, pan));
+        self.pan_samples.retain(|(sample_time, _)| {
+            timestamp.duration_since(*sample_time) <= TRANSFORM_VELOCITY_WINDOW
+        });
+    }
+
+    fn resolve_velocity(&self, release_time: Instant) -> [f32; 2] {
+        let Some((last_time, _)) = self.pan_samples.last() else {
+            return [0.0, 0.0];
+        };
+        if release_time.duration_since(*last_time) > TRANSFORM_VELOCITY_IDLE_CUTOFF {
+            return [0.0, 0.0];
+        }
+        let Some((first_time, _)) = self.pan_samples.first() else {
+            return [0.0, 0.0];
+        };
+        // The first sample only anchors the window; its delta happened before it.
+        let elapsed = last_time.duration_since(*first_time).as_secs_f32();
+        if elapsed <= f32::EPSILON {
+            return [0.0, 0.0];
+        }
+        let (dx, dy) = self.pan_samples[1..]
+            .iter()
+            .fold((0.0, 0.0), |(x, y), (_, pan)| (x + pan[0], y + pan[1]));
+        [dx / elapsed, dy / elapsed]
+    }
+
+    fn reset(&mut self) {
+        self.pointers.clear();
+        self.pending = TransformDeltaParts::IDENTITY;
+        self.transforming = false;
+        self.pan_samples.clear();
+    }
+}
+
+impl Default for TransformRecognizer {
+    fn default() -> Self {
+        Self::new(TransformSettings::default())
+    }
+}
+
+fn local_point(position: PxPosition, origin: PxPosition) -> [f32; 2] {
+    (position - origin).to_f32_arr2()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use tessera_ui::MOUSE_POINTER_ID;
+
+    use super::*;
+
+    const SIZE: PxSize = PxSize {
+        width: Px(200),
+        height: Px(200),
+    };
+
+    struct Touches {
+        start: Instant,
+        recognizer: TransformRecognizer,
+    }
+
+    impl Touches {
+        fn new(settings: TransformSettings) -> Self {
+            Self {
+                start: Instant::now(),
+                recognizer: TransformRecognizer::new(settings),
+            }
+        }
+
+        fn change(
+            &self,
+            pointer_id: PointerId,
+            content: CursorEventContent,
+            position: Option<PxPosition>,
+            millis: u64,
+        ) -> PointerChange {
+            let mut change = PointerChange::new(pointer_id, content);
+            change.position = position;
+            change.timestamp = self.start + Duration::from_millis(millis);
+            change
+        }
+
+        fn send(&mut self, mut change: PointerChange) -> (TransformResult, bool) {
+            let result = self.recognizer.update(
+                PointerEventPass::Main,
+                std::slice::from_mut(&mut change),
+                PxPosition::ZERO,
+                SIZE,
+            );
+            (result, change.is_consumed())
+        }
+
+        fn press(&mut self, pointer_id: PointerId, x: i32, y: i32, millis: u64) -> TransformResult {
+            let change = self.change(
+                pointer_id,
+                CursorEventContent::Pressed(PressKeyEventType::Left),
+                Some(point(x, y)),
+                millis,
+            );
+            self.send(change).0
+        }
+
+        fn move_to(
+            &mut self,
+            pointer_id: PointerId,
+            x: i32,
+            y: i32,
+            millis: u64,
+        ) -> (TransformResult, bool) {
+            let change = self.change(
+                pointer_id,
+                CursorEventContent::Moved(point(x, y)),
+                Some(point(x, y)),
+                millis,
+            );
+            self.send(change)
+        }
+
+        fn release(&mut self, pointer_id: PointerId, millis: u64) -> TransformResult {
+            let change = self.change(
+                pointer_id,
+                CursorEventContent::Released(PressKeyEventType::Left),
+                None,
+                millis,
+            );
+            self.send(change).0
+        }
+    }
+
+    fn point(x: i32, y: i32) -> PxPosition {
+        PxPosition::new(Px(x), Px(y))
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-3,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn single_pointer_pan_waits_for_slop() {
+        let mut touches = Touches::new(TransformSettings::default());
+        touches.press(1, 50, 50, 0);
+
+        let (result, consumed) = touches.move_to(1, 55, 50, 10);
+        assert!(!result.started && !result.updated && !consumed);
+
+        // The pan held back below the slop is reported once the slop is
+        // exceeded.
+        let (result, consumed) = touches.move_to(1, 60, 50, 20);
+        assert!(result.started && result.updated && consumed);
+        assert_close(result.pan_x, 10.0);
+
+        let (result, _) = touches.move_to(1, 63, 50, 30);
+        assert!(!result.started && result.updated);
+        assert_close(result.pan_x, 3.0);
+    }
+
+    #[test]
+    fn single_pointer_pan_can_be_disabled() {
+        let mut touches = Touches::new(TransformSettings {
+            single_pointer_pan: false,
+            ..Default::default()
+        });
+        touches.press(1, 50, 50, 0);
+
+        let (result, consumed) = touches.move_to(1, 150, 50, 10);
+        assert!(!result.started && !consumed);
+        assert!(!touches.recognizer.is_transforming());
+    }
+
+    #[test]
+    fn pinch_reports_zoom_and_centroid() {
+        let mut touches = Touches::new(TransformSettings::default());
+        touches.press(1, 50, 100, 0);
+        touches.press(2, 150, 100, 0);
+
+        let (result, _) = touches.move_to(2, 250, 100, 10);
+        assert!(result.started);
+        assert_eq!(result.pointer_count, 2);
+        assert_close(result.zoom, 2.0);
+        assert_close(result.pan_x, 50.0);
+        assert_eq!(result.centroid, Some(point(150, 100)));
+    }
+
+    #[test]
+    fn rotation_is_clockwise_positive() {
+        let mut touches = Touches::new(TransformSettings::default());
+        touches.press(1, 100, 100, 0);
+        touches.press(2, 150, 100, 0);
+
+        let (result, _) = touches.move_to(2, 100, 150, 10);
+        assert!(result.started);
+        assert_close(result.rotation, FRAC_PI_2);
+        assert_close(result.zoom, 1.0);
+    }
+
+    #[test]
+    fn release_reports_fling_velocity() {
+        let mut touches = Touches::new(TransformSettings::default());
+        touches.press(1, 10, 100, 0);
+        for step in 1..=4 {
+            touches.move_to(1, 10 + step as i32 * 10, 100, step * 10);
+        }
+
+        let result = touches.release(1, 45);
+        assert!(result.ended);
+        assert_close(result.velocity_x, 1000.0);
+        assert_close(result.velocity_y, 0.0);
+        assert!(!touches.recognizer.is_transforming());
+    }
+
+    #[test]
+    fn release_after_a_pause_does_not_fling() {
+        let mut touches = Touches::new(TransformSettings::default());
+        touches.press(1, 10, 100, 0);
+        for step in 1..=4 {
+            touches.move_to(1, 10 + step as i32 * 10, 100, step * 10);
+        }
+
+        let result = touches.release(1, 140);
+        assert!(result.ended);
+        assert_eq!((result.velocity_x, result.velocity_y), (0.0, 0.0));
+    }
+
+    #[test]
+    fn lifting_a_pointer_re_anchors_the_gesture() {
+        let mut touches = Touches::new(TransformSettings::default());
+        touches.press(1, 50, 100, 0);
+        touches.press(2, 150, 100, 0);
+        let (result, _) = touches.move_to(2, 250, 100, 10);
+        assert!(result.started);
+
+        let result = touches.release(2, 20);
+        assert!(!result.ended);
+        assert_eq!(result.pointer_count, 1);
+        assert!(touches.recognizer.is_transforming());
+
+        // The centroid jumps from 150 to 50 when the second pointer lifts;
+        // only the remaining pointer's own motion is reported.
+        let (result, _) = touches.move_to(1, 54, 100, 30);
+        assert_close(result.pan_x, 4.0);
+        assert_close(result.zoom, 1.0);
+
+        // Samples from before the lift are dropped, so the fast pinch does
+        // not turn into a fling.
+        let result = touches.release(1, 35);
+        assert!(result.ended);
+        assert_eq!((result.velocity_x, result.velocity_y), (0.0, 0.0));
+    }
+
+    #[test]
+    fn trackpad_transform_is_reported_directly() {
+        let mut touches = Touches::new(TransformSettings::default());
+        let origin = point(10, 10);
+
+        let mut pinch = touches.change(
+            MOUSE_POINTER_ID,
+            CursorEventContent::from_pinch_gesture(0.5),
+            Some(point(30, 40)),
+            0,
+        );
+        let result = touches.recognizer.update(
+            PointerEventPass::Main,
+            std::slice::from_mut(&mut pinch),
+            origin,
+            SIZE,
+        );
+        assert!(result.updated && pinch.is_consumed());
+        assert_close(result.zoom, 1.5);
+        assert_eq!(result.centroid, Some(point(20, 30)));
+        assert_eq!(result.pointer_count, 0);
+
+        let (result, consumed) = touches.send(touches.change(
+            MOUSE_POINTER_ID,
+            CursorEventContent::from_rotation_gesture(90.0),
+            Some(point(30, 40)),
+            10,
+        ));
+        assert!(result.updated && consumed);
+        assert_close(result.rotation, -FRAC_PI_2);
+
+        let (result, consumed) = touches.send(touches.change(
+            MOUSE_POINTER_ID,
+            CursorEventContent::from_pinch_gesture(0.5),
+            Some(point(500, 500)),
+            20,
+        ));
+        assert!(!result.updated && !consumed);
+    }
+
+    #[test]
+    fn only_the_main_pass_is_handled() {
+        let mut touches = Touches::new(TransformSettings::default());
+        let mut press = touches.change(
+            1,
+            CursorEventContent::Pressed(PressKeyEventType::Left),
+            Some(point(50, 50)),
+            0,
+        );
+        touches.recognizer.update(
+            PointerEventPass::Initial,
+            std::slice::from_mut(&mut press),
+            PxPosition::ZERO,
+            SIZE,
+        );
+        assert_eq!(touches.recognizer.pointer_count(), 0);
+    }
+}
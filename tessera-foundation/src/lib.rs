@@ -3,7 +3,8 @@
 //! ## Usage
 //!
 //! Use foundational types such as alignment and shape definitions when building
-//! reusable layout and visual APIs.
+//! reusable layout and visual APIs, and overlay layers when content has to
+//! draw above the rest of the UI.
 #![deny(
     missing_docs,
     clippy::unwrap_used,
@@ -15,4 +16,5 @@
 pub mod alignment;
 pub mod gesture;
 pub mod modifier;
+pub mod overlay;
 pub mod shape_def;
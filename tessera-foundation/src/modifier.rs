@@ -21,12 +21,12 @@ use layout::{
 
 pub use interaction::{
     ClickableArgs, DragDelta, DraggableArgs, InteractionState, PointerEventContext, SelectableArgs,
-    ToggleableArgs,
+    ToggleableArgs, TransformDelta, TransformVelocity, TransformableArgs,
 };
 pub use layout::{
     AlignmentParentData, MinimumInteractiveComponentEnforcement, Padding, WeightParentData,
 };
-pub use semantics::SemanticsArgs;
+pub use semantics::{SemanticsArgs, SemanticsCustomAction};
 
 /// Shared modifier extensions that are not tied to a specific design system.
 pub trait ModifierExt {
@@ -89,6 +89,14 @@ pub trait ModifierExt {
     /// Emits drag deltas with custom drag configuration.
     fn draggable_with(self, args: DraggableArgs) -> Modifier;
 
+    /// Emits pan, zoom, and rotation deltas for multi-pointer transforms.
+    fn transformable<C>(self, on_transform: C) -> Modifier
+    where
+        C: Into<CallbackWith<TransformDelta, ()>>;
+
+    /// Emits transform deltas with custom transform configuration.
+    fn transformable_with(self, args: TransformableArgs) -> Modifier;
+
     /// Attaches accessibility semantics metadata to this subtree.
     fn semantics(self, args: SemanticsArgs) -> Modifier;
 
@@ -229,6 +237,20 @@ impl ModifierExt for Modifier {
         interaction::apply_draggable_modifier(self, args)
     }
 
+    fn transformable<C>(self, on_transform: C) -> Modifier
+    where
+        C: Into<CallbackWith<TransformDelta, ()>>,
+    {
+        self.transformable_with(TransformableArgs {
+            on_transform: on_transform.into(),
+            ..Default::default()
+        })
+    }
+
+    fn transformable_with(self, args: TransformableArgs) -> Modifier {
+        interaction::apply_transformable_modifier(self, args)
+    }
+
     fn semantics(self, args: SemanticsArgs) -> Modifier {
         semantics::apply_semantics_modifier(self, args)
     }
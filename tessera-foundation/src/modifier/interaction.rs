@@ -2,8 +2,8 @@
 //!
 //! ## Usage
 //!
-//! Configure clickable, toggleable, selectable, and draggable modifier
-//! behavior.
+//! Configure clickable, toggleable, selectable, draggable, and transformable
+//! modifier behavior.
 
 use tessera_ui::{
     Callback, CallbackWith, FocusProperties, FocusRequester, Modifier, PointerInput,
@@ -11,7 +11,9 @@ use tessera_ui::{
     modifier::ModifierCapabilityExt as _, remember,
 };
 
-use crate::gesture::{DragAxis, DragRecognizer, DragSettings};
+use crate::gesture::{
+    DragAxis, DragRecognizer, DragSettings, TransformRecognizer, TransformSettings,
+};
 
 /// Context for pointer press/release callbacks.
 #[derive(Clone, PartialEq, Copy, Debug)]
@@ -24,6 +26,7 @@ pub struct PointerEventContext {
 
 type PressCallback = CallbackWith<PointerEventContext, ()>;
 type DragCallback = CallbackWith<DragDelta, ()>;
+type TransformCallback = CallbackWith<TransformDelta, ()>;
 
 /// Arguments for the `clickable` modifier.
 #[derive(Clone)]
@@ -199,6 +202,77 @@ impl Default for DraggableArgs {
     }
 }
 
+/// Transform delta produced by the `transformable` modifier.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TransformDelta {
+    /// Horizontal pan delta in pixels.
+    pub pan_x: f32,
+    /// Vertical pan delta in pixels.
+    pub pan_y: f32,
+    /// Multiplicative zoom delta, where `1.0` means no change.
+    pub zoom: f32,
+    /// Rotation delta in radians, clockwise positive.
+    pub rotation: f32,
+    /// Gesture centroid relative to the element.
+    pub centroid: PxPosition,
+}
+
+impl Default for TransformDelta {
+    fn default() -> Self {
+        Self {
+            pan_x: 0.0,
+            pan_y: 0.0,
+            zoom: 1.0,
+            rotation: 0.0,
+            centroid: PxPosition::ZERO,
+        }
+    }
+}
+
+/// Pan velocity reported when a `transformable` gesture ends.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct TransformVelocity {
+    /// Horizontal velocity in pixels per second.
+    pub x: f32,
+    /// Vertical velocity in pixels per second.
+    pub y: f32,
+}
+
+/// Arguments for the `transformable` modifier.
+#[derive(Clone)]
+pub struct TransformableArgs {
+    /// Callback invoked with each pan, zoom, and rotation delta.
+    pub on_transform: TransformCallback,
+    /// Whether transforming is enabled.
+    pub enabled: bool,
+    /// Whether a single pointer pans the content.
+    pub single_pointer_pan: bool,
+    /// Minimum pan, span change, or rotation arc before the transform starts.
+    pub slop_px: f32,
+    /// Whether pointer changes should be consumed after the transform starts.
+    pub consume_when_transforming: bool,
+    /// Optional callback invoked when a pointer transform starts.
+    pub on_transform_started: Option<Callback>,
+    /// Optional callback invoked with the release velocity when a pointer
+    /// transform stops.
+    pub on_transform_stopped: Option<CallbackWith<TransformVelocity, ()>>,
+}
+
+impl Default for TransformableArgs {
+    fn default() -> Self {
+        let settings = TransformSettings::default();
+        Self {
+            on_transform: CallbackWith::default_value(),
+            enabled: true,
+            single_pointer_pan: settings.single_pointer_pan,
+            slop_px: settings.slop_px,
+            consume_when_transforming: settings.consume_when_transforming,
+            on_transform_started: None,
+            on_transform_stopped: None,
+        }
+    }
+}
+
 /// Tracks basic interaction flags and derives state-layer alpha.
 #[derive(Clone, PartialEq, Copy, Debug, Default)]
 pub struct InteractionState {
@@ -331,6 +405,50 @@ impl PointerInputModifierNode for DraggablePointerModifierNode {
     }
 }
 
+struct TransformablePointerModifierNode {
+    transform_recognizer: State<TransformRecognizer>,
+    on_transform: TransformCallback,
+    on_transform_started: Option<Callback>,
+    on_transform_stopped: Option<CallbackWith<TransformVelocity, ()>>,
+}
+
+impl PointerInputModifierNode for TransformablePointerModifierNode {
+    fn on_pointer_input(&self, input: PointerInput<'_>) {
+        let size = PxSize::new(input.computed_data.width, input.computed_data.height);
+        let result = self.transform_recognizer.with_mut(|recognizer| {
+            recognizer.update(input.pass, input.pointer_changes, input.abs_position, size)
+        });
+
+        if result.started
+            && let Some(on_transform_started) = self.on_transform_started
+        {
+            on_transform_started.call();
+        }
+
+        if result.updated {
+            self.on_transform.call(TransformDelta {
+                pan_x: result.pan_x,
+                pan_y: result.pan_y,
+                zoom: result.zoom,
+                rotation: result.rotation,
+                centroid: result.centroid.unwrap_or(PxPosition::new(
+                    input.computed_data.width / 2,
+                    input.computed_data.height / 2,
+                )),
+            });
+        }
+
+        if result.ended
+            && let Some(on_transform_stopped) = self.on_transform_stopped
+        {
+            on_transform_stopped.call(TransformVelocity {
+                x: result.velocity_x,
+                y: result.velocity_y,
+            });
+        }
+    }
+}
+
 fn cursor_within_bounds(position: Option<PxPosition>, size: PxSize) -> bool {
     let Some(position) = position else {
         return false;
@@ -385,3 +503,34 @@ pub(crate) fn apply_draggable_modifier(base: Modifier, args: DraggableArgs) -> M
         interaction_state,
     })
 }
+
+pub(crate) fn apply_transformable_modifier(base: Modifier, args: TransformableArgs) -> Modifier {
+    let TransformableArgs {
+        on_transform,
+        enabled,
+        single_pointer_pan,
+        slop_px,
+        consume_when_transforming,
+        on_transform_started,
+        on_transform_stopped,
+    } = args;
+
+    if !enabled {
+        return base;
+    }
+
+    let settings = TransformSettings {
+        slop_px,
+        single_pointer_pan,
+        consume_when_transforming,
+    };
+    let transform_recognizer = remember(move || TransformRecognizer::new(settings));
+    transform_recognizer.with_mut(|recognizer| recognizer.set_settings(settings));
+
+    base.push_pointer_input(TransformablePointerModifierNode {
+        transform_recognizer,
+        on_transform,
+        on_transform_started,
+        on_transform_stopped,
+    })
+}
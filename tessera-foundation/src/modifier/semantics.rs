@@ -5,7 +5,8 @@
 //! Attach accessibility roles, labels, and testing tags to component subtrees.
 
 use tessera_ui::{
-    AccessibilityActionHandler, AccessibilityNode, SemanticsModifierNode,
+    AccessibilityActionHandler, AccessibilityActionHandlers, AccessibilityNode, AccessibilityValue,
+    Callback, CallbackWith, SemanticsModifierNode,
     accesskit::{Action, Live, Role, Toggled},
     modifier::ModifierCapabilityExt as _,
 };
@@ -33,6 +34,10 @@ pub struct SemanticsArgs {
     pub focused: bool,
     /// Optional toggled state.
     pub toggled: Option<Toggled>,
+    /// Optional expanded state for collapsible controls.
+    pub expanded: Option<bool>,
+    /// Optional selected state for options and other selectable items.
+    pub selected: Option<bool>,
     /// Whether the node is disabled.
     pub disabled: bool,
     /// Whether the node is hidden from accessibility.
@@ -41,6 +46,11 @@ pub struct SemanticsArgs {
     pub actions: Vec<Action>,
     /// Optional testing tag (mapped to the accessibility key).
     pub test_tag: Option<String>,
+    /// Test tags of the nodes this node controls.
+    pub controls: Vec<String>,
+    /// Optional test tag of the node that is active while this node keeps
+    /// focus.
+    pub active_descendant: Option<String>,
     /// Optional padding applied to semantic bounds.
     pub bounds_padding: Option<Padding>,
     /// Whether to merge child semantics into this node.
@@ -68,6 +78,41 @@ pub struct SemanticsArgs {
     /// Optional collection item info (row_index, row_span, col_index, col_span,
     /// heading).
     pub collection_item_info: Option<(usize, usize, usize, usize, bool)>,
+    /// Optional 1-based depth of the item in a hierarchical collection.
+    pub collection_item_level: Option<usize>,
+    /// Optional handler for the `Increment` action.
+    pub on_increment: Option<Callback>,
+    /// Optional handler for the `Decrement` action.
+    pub on_decrement: Option<Callback>,
+    /// Optional handler for the `Expand` action.
+    pub on_expand: Option<Callback>,
+    /// Optional handler for the `Collapse` action.
+    pub on_collapse: Option<Callback>,
+    /// Optional handler for the `ScrollIntoView` action.
+    pub on_scroll_into_view: Option<Callback>,
+    /// Optional handler for the `SetValue` action.
+    pub on_set_value: Option<CallbackWith<AccessibilityValue>>,
+    /// Named actions offered in addition to the standard ones.
+    pub custom_actions: Vec<SemanticsCustomAction>,
+}
+
+/// Named accessibility action such as "Archive" or "Mark as read".
+#[derive(Clone)]
+pub struct SemanticsCustomAction {
+    /// Label announced by assistive technologies.
+    pub label: String,
+    /// Called when the action is performed.
+    pub on_action: Callback,
+}
+
+impl SemanticsCustomAction {
+    /// Creates a custom action with the given label and handler.
+    pub fn new(label: impl Into<String>, on_action: impl Into<Callback>) -> Self {
+        Self {
+            label: label.into(),
+            on_action: on_action.into(),
+        }
+    }
 }
 
 impl SemanticsArgs {
@@ -100,10 +145,14 @@ impl SemanticsModifierNode for SemanticsModifierNodeImpl {
             focusable,
             focused,
             toggled,
+            expanded,
+            selected,
             disabled,
             hidden,
             actions,
             test_tag,
+            controls,
+            active_descendant,
             bounds_padding,
             merge_descendants,
             state_description,
@@ -117,6 +166,14 @@ impl SemanticsModifierNode for SemanticsModifierNodeImpl {
             numeric_value_jump,
             collection_info,
             collection_item_info,
+            collection_item_level,
+            on_increment: _,
+            on_decrement: _,
+            on_expand: _,
+            on_collapse: _,
+            on_scroll_into_view: _,
+            on_set_value: _,
+            custom_actions: _,
         } = &self.args;
 
         accessibility.role = *role;
@@ -129,10 +186,14 @@ impl SemanticsModifierNode for SemanticsModifierNodeImpl {
         accessibility.focusable = *focusable;
         accessibility.focused = *focused;
         accessibility.toggled = *toggled;
+        accessibility.expanded = *expanded;
+        accessibility.selected = *selected;
         accessibility.disabled = *disabled;
         accessibility.hidden = *hidden;
         accessibility.actions = actions.clone();
         accessibility.key = test_tag.clone();
+        accessibility.controls = controls.clone();
+        accessibility.active_descendant = active_descendant.clone();
         accessibility.merge_descendants = *merge_descendants;
         accessibility.state_description = state_description.clone();
         accessibility.role_description = role_description.clone();
@@ -145,6 +206,7 @@ impl SemanticsModifierNode for SemanticsModifierNodeImpl {
         accessibility.numeric_value_jump = *numeric_value_jump;
         accessibility.collection_info = *collection_info;
         accessibility.collection_item_info = *collection_item_info;
+        accessibility.collection_item_level = *collection_item_level;
         accessibility.bounds_padding =
             bounds_padding.map(|padding| tessera_ui::accessibility::AccessibilityPadding {
                 left: padding.left.into(),
@@ -153,6 +215,32 @@ impl SemanticsModifierNode for SemanticsModifierNodeImpl {
                 bottom: padding.bottom.into(),
             });
     }
+
+    fn apply_action_handlers(&self, handlers: &mut AccessibilityActionHandlers) {
+        let args = &self.args;
+        if let Some(on_increment) = args.on_increment {
+            handlers.on_increment(move || on_increment.call());
+        }
+        if let Some(on_decrement) = args.on_decrement {
+            handlers.on_decrement(move || on_decrement.call());
+        }
+        if let Some(on_expand) = args.on_expand {
+            handlers.on_expand(move || on_expand.call());
+        }
+        if let Some(on_collapse) = args.on_collapse {
+            handlers.on_collapse(move || on_collapse.call());
+        }
+        if let Some(on_scroll_into_view) = args.on_scroll_into_view {
+            handlers.on_scroll_into_view(move || on_scroll_into_view.call());
+        }
+        if let Some(on_set_value) = args.on_set_value {
+            handlers.on_set_value(move |value| on_set_value.call(value));
+        }
+        for action in &args.custom_actions {
+            let on_action = action.on_action;
+            handlers.add_custom_action(action.label.clone(), move || on_action.call());
+        }
+    }
 }
 
 pub(crate) fn apply_semantics_modifier(
@@ -0,0 +1,1052 @@
+//! Overlay layers for popups, menus, dialogs and sheets.
+//!
+//! ## Usage
+//!
+//! Show content above the rest of the UI, either anchored to a component or
+//! placed in the window, with shared stacking, focus handling and dismissal.
+//!
+//! [`overlay_host`] renders every open [`overlay`] after its own content, so
+//! overlays draw above the whole subtree no matter where they are declared.
+//! Layers stack by z-index and then by the order they were opened. Overlay
+//! content is rendered by the host but sees the contexts provided around the
+//! [`overlay`] call, as if it were rendered in place.
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicU64, Ordering},
+};
+
+use parking_lot::RwLock;
+use tessera_ui::{
+    AxisConstraint, Callback, ComputedData, Constraint, ContextSnapshot, FocusManager,
+    FocusScopeNode, FocusTraversalPolicy, KeyboardInput, KeyboardInputModifierNode, LayoutResult,
+    MeasurementError, Modifier, PointerInput, PointerInputModifierNode, Px, PxPosition, PxRect,
+    PxSize, RenderSlot, SavedFocus, State, key,
+    layout::{LayoutPolicy, MeasureScope, layout},
+    modifier::{FocusModifierExt as _, ModifierCapabilityExt as _},
+    post_to_ui_thread, provide_context, remember, remember_bounds, tessera, use_context,
+    winit::{
+        event::ElementState,
+        keyboard::{Key, NamedKey},
+    },
+};
+
+use crate::alignment::Alignment;
+
+/// Side of the anchor a popup opens on and the edge it aligns to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PopupPlacement {
+    /// Align to the anchor's start edge and expand downward.
+    #[default]
+    BelowStart,
+    /// Align to the anchor's end edge and expand downward.
+    BelowEnd,
+    /// Align to the anchor's start edge and expand upward.
+    AboveStart,
+    /// Align to the anchor's end edge and expand upward.
+    AboveEnd,
+    /// Align to the anchor's top edge and expand rightward.
+    RightStart,
+    /// Align to the anchor's top edge and expand leftward.
+    LeftStart,
+}
+
+impl PopupPlacement {
+    /// Returns the placement on the opposite side of the anchor.
+    pub fn flipped(self) -> Self {
+        match self {
+            Self::BelowStart => Self::AboveStart,
+            Self::BelowEnd => Self::AboveEnd,
+            Self::AboveStart => Self::BelowStart,
+            Self::AboveEnd => Self::BelowEnd,
+            Self::RightStart => Self::LeftStart,
+            Self::LeftStart => Self::RightStart,
+        }
+    }
+
+    fn is_vertical(self) -> bool {
+        !matches!(self, Self::RightStart | Self::LeftStart)
+    }
+}
+
+/// Computes where to place a popup of `popup` size next to `anchor`.
+///
+/// The popup opens on the side given by `placement` and flips to the opposite
+/// side when it does not fit but the opposite side has more room. It is then
+/// shifted to stay inside `bounds`. `offset` is added after placement; its
+/// main-axis component is mirrored when the popup flips so a gap stays a gap.
+///
+/// # Examples
+///
+/// ```
+/// use tessera_foundation::overlay::{PopupPlacement, resolve_popup_position};
+/// use tessera_ui::{Px, PxPosition, PxRect, PxSize};
+///
+/// let anchor = PxRect::new(Px(10), Px(180), Px(20), Px(10));
+/// let position = resolve_popup_position(
+///     anchor,
+///     PopupPlacement::BelowStart,
+///     PxPosition::new(Px(0), Px(4)),
+///     PxSize::new(Px(50), Px(30)),
+///     PxSize::new(Px(200), Px(200)),
+/// );
+/// // There is no room below, so the popup opens above the anchor.
+/// assert_eq!(position, PxPosition::new(Px(10), Px(146)));
+/// ```
+pub fn resolve_popup_position(
+    anchor: PxRect,
+    placement: PopupPlacement,
+    offset: PxPosition,
+    popup: PxSize,
+    bounds: PxSize,
+) -> PxPosition {
+    let room = |placement: PopupPlacement| match placement {
+        PopupPlacement::BelowStart | PopupPlacement::BelowEnd => {
+            bounds.height - (anchor.y + anchor.height)
+        }
+        PopupPlacement::AboveStart | PopupPlacement::AboveEnd => anchor.y,
+        PopupPlacement::RightStart => bounds.width - (anchor.x + anchor.width),
+        PopupPlacement::LeftStart => anchor.x,
+    };
+    let needed = if placement.is_vertical() {
+        popup.height + offset.y.max(-offset.y)
+    } else {
+        popup.width + offset.x.max(-offset.x)
+    };
+
+    let flipped = placement.flipped();
+    let (placement, offset) = if room(placement) >= needed || room(placement) >= room(flipped) {
+        (placement, offset)
+    } else if placement.is_vertical() {
+        (flipped, PxPosition::new(offset.x, -offset.y))
+    } else {
+        (flipped, PxPosition::new(-offset.x, offset.y))
+    };
+
+    let anchor_end_x = anchor.x + anchor.width;
+    let anchor_end_y = anchor.y + anchor.height;
+    let x = match placement {
+        PopupPlacement::BelowStart | PopupPlacement::AboveStart => anchor.x,
+        PopupPlacement::BelowEnd | PopupPlacement::AboveEnd => anchor_end_x - popup.width,
+        PopupPlacement::RightStart => anchor_end_x,
+        PopupPlacement::LeftStart => anchor.x - popup.width,
+    };
+    let y = match placement {
+        PopupPlacement::BelowStart | PopupPlacement::BelowEnd => anchor_end_y,
+        PopupPlacement::AboveStart | PopupPlacement::AboveEnd => anchor.y - popup.height,
+        PopupPlacement::RightStart | PopupPlacement::LeftStart => anchor.y,
+    };
+
+    PxPosition::new(
+        clamp_to_bounds(x + offset.x, popup.width, bounds.width),
+        clamp_to_bounds(y + offset.y, popup.height, bounds.height),
+    )
+}
+
+fn clamp_to_bounds(position: Px, size: Px, available: Px) -> Px {
+    let max = (available - size).max(Px::ZERO);
+    position.max(Px::ZERO).min(max)
+}
+
+fn aligned_position(alignment: Alignment, popup: PxSize, bounds: PxSize) -> PxPosition {
+    let free_width = (bounds.width - popup.width).max(Px::ZERO);
+    let free_height = (bounds.height - popup.height).max(Px::ZERO);
+    let x = match alignment {
+        Alignment::TopStart | Alignment::CenterStart | Alignment::BottomStart => Px::ZERO,
+        Alignment::TopCenter | Alignment::Center | Alignment::BottomCenter => free_width / 2,
+        Alignment::TopEnd | Alignment::CenterEnd | Alignment::BottomEnd => free_width,
+    };
+    let y = match alignment {
+        Alignment::TopStart | Alignment::TopCenter | Alignment::TopEnd => Px::ZERO,
+        Alignment::CenterStart | Alignment::Center | Alignment::CenterEnd => free_height / 2,
+        Alignment::BottomStart | Alignment::BottomCenter | Alignment::BottomEnd => free_height,
+    };
+    PxPosition::new(x, y)
+}
+
+/// Where an [`overlay`] places its content inside the host.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OverlayPosition {
+    /// Gives the content the whole host and lets it position itself.
+    Fill,
+    /// Aligns the content inside the host.
+    Aligned(Alignment),
+    /// Aligns the content inside a rectangle of the host, such as the area a
+    /// component reserves for transient messages.
+    Within {
+        /// Rectangle in window coordinates, usually from
+        /// [`remember_bounds`].
+        bounds: PxRect,
+        /// Alignment of the content inside `bounds`.
+        alignment: Alignment,
+    },
+    /// Places the content next to an anchor rectangle.
+    Anchored {
+        /// Anchor rectangle in window coordinates, usually from
+        /// [`remember_bounds`].
+        anchor: PxRect,
+        /// Side of the anchor to open on.
+        placement: PopupPlacement,
+        /// Extra offset applied after placement.
+        offset: PxPosition,
+    },
+}
+
+impl OverlayPosition {
+    fn relative_to(self, origin: PxPosition) -> Self {
+        match self {
+            Self::Anchored {
+                anchor,
+                placement,
+                offset,
+            } => Self::Anchored {
+                anchor: PxRect::new(
+                    anchor.x - origin.x,
+                    anchor.y - origin.y,
+                    anchor.width,
+                    anchor.height,
+                ),
+                placement,
+                offset,
+            },
+            Self::Within { bounds, alignment } => Self::Within {
+                bounds: PxRect::new(
+                    bounds.x - origin.x,
+                    bounds.y - origin.y,
+                    bounds.width,
+                    bounds.height,
+                ),
+                alignment,
+            },
+            position => position,
+        }
+    }
+}
+
+impl Default for OverlayPosition {
+    fn default() -> Self {
+        Self::Aligned(Alignment::Center)
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct OverlayLayer {
+    z_index: i32,
+    modal: bool,
+    dismiss_on_back: bool,
+    dismiss_on_outside: bool,
+    on_dismiss: Callback,
+    position: OverlayPosition,
+    focus_scope: FocusScopeNode,
+    contexts: ContextSnapshot,
+    scrim: RenderSlot,
+    content: RenderSlot,
+}
+
+#[derive(Clone, PartialEq)]
+struct OverlayEntry {
+    id: u64,
+    sequence: u64,
+    layer: OverlayLayer,
+}
+
+/// Open layers in paint order, bottom first.
+#[derive(Default)]
+struct OverlayStack {
+    entries: Vec<OverlayEntry>,
+    next_sequence: u64,
+}
+
+impl OverlayStack {
+    /// Adds or updates the layer of overlay `id`, returning whether the stack
+    /// changed.
+    fn upsert(&mut self, id: u64, layer: OverlayLayer) -> bool {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.id == id) {
+            if entry.layer == layer {
+                return false;
+            }
+            entry.layer = layer;
+        } else {
+            let sequence = self.next_sequence;
+            self.next_sequence += 1;
+            self.entries.push(OverlayEntry {
+                id,
+                sequence,
+                layer,
+            });
+        }
+        self.entries
+            .sort_by_key(|entry| (entry.layer.z_index, entry.sequence));
+        true
+    }
+
+    fn remove(&mut self, id: u64) -> bool {
+        let len = self.entries.len();
+        self.entries.retain(|entry| entry.id != id);
+        self.entries.len() != len
+    }
+
+    /// Returns the dismiss callback of the layer that handles back, if any.
+    ///
+    /// A modal layer that ignores back also shields the layers below it.
+    fn back_target(&self) -> Option<Callback> {
+        for entry in self.entries.iter().rev() {
+            if entry.layer.dismiss_on_back {
+                return Some(entry.layer.on_dismiss);
+            }
+            if entry.layer.modal {
+                return None;
+            }
+        }
+        None
+    }
+}
+
+#[derive(Clone)]
+struct OverlayHost {
+    stack: Arc<RwLock<OverlayStack>>,
+    revision: State<u64>,
+}
+
+impl OverlayHost {
+    fn update(&self, f: impl FnOnce(&mut OverlayStack) -> bool) {
+        let changed = f(&mut self.stack.write());
+        if changed && self.revision.is_alive() {
+            self.revision.with_mut(|revision| *revision += 1);
+        }
+    }
+}
+
+/// Returns whether an [`overlay_host`] is available to the current component.
+pub fn has_overlay_host() -> bool {
+    use_context::<OverlayHost>().is_some()
+}
+
+fn next_overlay_id() -> u64 {
+    static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Keeps an [`overlay`]'s layer in the host stack in sync with its props.
+struct OverlayRegistration {
+    id: u64,
+    host: OverlayHost,
+    is_open: bool,
+    saved_focus: Option<SavedFocus>,
+}
+
+impl OverlayRegistration {
+    fn sync(&mut self, is_open: bool, layer: OverlayLayer) {
+        if is_open {
+            if !self.is_open {
+                self.saved_focus = FocusManager::current().save_focus();
+            }
+            self.host.update(|stack| stack.upsert(self.id, layer));
+            self.is_open = true;
+        } else if self.is_open {
+            let had_focus = layer.focus_scope.has_focus();
+            self.host.update(|stack| stack.remove(self.id));
+            if let Some(saved_focus) = self.saved_focus.take()
+                && had_focus
+            {
+                saved_focus.restore();
+            }
+            self.is_open = false;
+        }
+    }
+}
+
+impl Drop for OverlayRegistration {
+    fn drop(&mut self) {
+        if !self.is_open {
+            return;
+        }
+        // The slot table is borrowed while remembered values drop, so the
+        // host is updated on the next frame instead.
+        let id = self.id;
+        let host = self.host.clone();
+        let saved_focus = self.saved_focus.take();
+        post_to_ui_thread(move || {
+            host.update(|stack| stack.remove(id));
+            if let Some(saved_focus) = saved_focus {
+                saved_focus.restore();
+            }
+        });
+    }
+}
+
+struct OverlayHostKeyboardModifierNode {
+    stack: Arc<RwLock<OverlayStack>>,
+}
+
+impl KeyboardInputModifierNode for OverlayHostKeyboardModifierNode {
+    fn on_keyboard_input(&self, mut input: KeyboardInput<'_>) {
+        let back_pressed = input.keyboard_events.iter().any(|event| {
+            event.state == ElementState::Pressed
+                && matches!(
+                    event.logical_key,
+                    Key::Named(NamedKey::Escape | NamedKey::BrowserBack | NamedKey::GoBack)
+                )
+        });
+        if !back_pressed {
+            return;
+        }
+        let Some(on_dismiss) = self.stack.read().back_target() else {
+            return;
+        };
+        on_dismiss.call();
+        input.block_keyboard();
+    }
+}
+
+/// Places the host content and gives every layer the host's full size.
+#[derive(Clone, PartialEq)]
+struct OverlayHostLayout;
+
+impl LayoutPolicy for OverlayHostLayout {
+    fn measure(&self, input: &MeasureScope<'_>) -> Result<LayoutResult, MeasurementError> {
+        let children = input.children();
+        let mut result = LayoutResult::default();
+        let Some((content, layers)) = children.split_first() else {
+            return Ok(result.with_size(ComputedData::min_from_constraint(
+                input.parent_constraint().as_ref(),
+            )));
+        };
+        let content_size = content.measure(input.parent_constraint().as_ref())?;
+        result.place_child(*content, PxPosition::ZERO);
+
+        let size = ComputedData {
+            width: input
+                .parent_constraint()
+                .width()
+                .resolve_max()
+                .unwrap_or(content_size.width),
+            height: input
+                .parent_constraint()
+                .height()
+                .resolve_max()
+                .unwrap_or(content_size.height),
+        };
+        let layer_constraint = Constraint::exact(size.width, size.height);
+        for layer in layers {
+            layer.measure(&layer_constraint)?;
+            result.place_child(*layer, PxPosition::ZERO);
+        }
+        Ok(result.with_size(size))
+    }
+}
+
+/// Places a layer's scrim over the whole layer and its popup per
+/// [`OverlayPosition`].
+#[derive(Clone)]
+struct OverlayLayerLayout {
+    position: OverlayPosition,
+    popup_bounds: Arc<RwLock<Option<PxRect>>>,
+}
+
+impl PartialEq for OverlayLayerLayout {
+    fn eq(&self, other: &Self) -> bool {
+        self.position == other.position && Arc::ptr_eq(&self.popup_bounds, &other.popup_bounds)
+    }
+}
+
+impl LayoutPolicy for OverlayLayerLayout {
+    fn measure(&self, input: &MeasureScope<'_>) -> Result<LayoutResult, MeasurementError> {
+        let children = input.children();
+        let mut result = LayoutResult::default();
+        let size = PxSize::new(
+            input
+                .parent_constraint()
+                .width()
+                .resolve_max()
+                .unwrap_or(Px::ZERO),
+            input
+                .parent_constraint()
+                .height()
+                .resolve_max()
+                .unwrap_or(Px::ZERO),
+        );
+        let (Some(scrim), Some(popup)) = (children.first(), children.get(1)) else {
+            return Ok(result.with_size(ComputedData {
+                width: size.width,
+                height: size.height,
+            }));
+        };
+
+        let fill = Constraint::exact(size.width, size.height);
+        scrim.measure(&fill)?;
+        result.place_child(*scrim, PxPosition::ZERO);
+
+        let loose = Constraint::new(
+            AxisConstraint::at_most(size.width),
+            AxisConstraint::at_most(size.height),
+        );
+        let (popup_size, popup_position) = match self.position {
+            OverlayPosition::Fill => {
+                popup.measure(&fill)?;
+                (size, PxPosition::ZERO)
+            }
+            OverlayPosition::Aligned(alignment) => {
+                let measured = popup.measure(&loose)?;
+                let popup_size = PxSize::new(measured.width, measured.height);
+                (popup_size, aligned_position(alignment, popup_size, size))
+            }
+            OverlayPosition::Within { bounds, alignment } => {
+                let area = Constraint::new(
+                    AxisConstraint::at_most(bounds.width.min(size.width)),
+                    AxisConstraint::at_most(bounds.height.min(size.height)),
+                );
+                let measured = popup.measure(&area)?;
+                let popup_size = PxSize::new(measured.width, measured.height);
+                let aligned = aligned_position(
+                    alignment,
+                    popup_size,
+                    PxSize::new(bounds.width, bounds.height),
+                );
+                (
+                    popup_size,
+                    PxPosition::new(
+                        clamp_to_bounds(bounds.x + aligned.x, popup_size.width, size.width),
+                        clamp_to_bounds(bounds.y + aligned.y, popup_size.height, size.height),
+                    ),
+                )
+            }
+            OverlayPosition::Anchored {
+                anchor,
+                placement,
+                offset,
+            } => {
+                let measured = popup.measure(&loose)?;
+                let popup_size = PxSize::new(measured.width, measured.height);
+                (
+                    popup_size,
+                    resolve_popup_position(anchor, placement, offset, popup_size, size),
+                )
+            }
+        };
+        result.place_child(*popup, popup_position);
+        *self.popup_bounds.write() = Some(PxRect::from_position_size(popup_position, popup_size));
+
+        Ok(result.with_size(ComputedData {
+            width: size.width,
+            height: size.height,
+        }))
+    }
+}
+
+/// Dismisses on releases outside the popup and keeps modal layers from
+/// leaking pointer input to content below.
+///
+/// Pointer input reaches a layer's children before the layer itself, so a
+/// release that is still unconsumed here missed everything the popup handles.
+struct OverlayLayerPointerModifierNode {
+    modal: bool,
+    dismiss_on_outside: bool,
+    on_dismiss: Callback,
+    popup_bounds: Arc<RwLock<Option<PxRect>>>,
+}
+
+impl PointerInputModifierNode for OverlayLayerPointerModifierNode {
+    fn on_pointer_input(&self, mut input: PointerInput<'_>) {
+        if self.dismiss_on_outside && input.has_unconsumed_release() {
+            let popup_bounds = *self.popup_bounds.read();
+            let outside = input.cursor_position_rel.is_some_and(|position| {
+                popup_bounds.is_some_and(|bounds| !bounds.contains(position))
+            });
+            if outside {
+                self.on_dismiss.call();
+            }
+        }
+        if self.modal {
+            input.block_all();
+        }
+    }
+}
+
+#[tessera]
+fn overlay_layer(layer: Option<OverlayLayer>) {
+    let layer = layer.expect("overlay_layer requires a layer");
+    let popup_bounds = remember(|| Arc::new(RwLock::new(None::<PxRect>))).get();
+    let opened = remember(|| false);
+    if layer.modal && !opened.with_mut(|opened| std::mem::replace(opened, true)) {
+        layer.focus_scope.restore_focus();
+    }
+
+    let mut modifier = Modifier::new().focus_scope_with(layer.focus_scope);
+    if layer.modal {
+        modifier = modifier.focus_traversal_policy(
+            FocusTraversalPolicy::linear()
+                .wrap(true)
+                .tab_navigation(true),
+        );
+    }
+    let modifier = modifier.push_pointer_input(OverlayLayerPointerModifierNode {
+        modal: layer.modal,
+        dismiss_on_outside: layer.dismiss_on_outside,
+        on_dismiss: layer.on_dismiss,
+        popup_bounds: popup_bounds.clone(),
+    });
+
+    let scrim = layer.scrim;
+    let content = layer.content;
+    let contexts = layer.contexts;
+    layout()
+        .layout_policy(OverlayLayerLayout {
+            position: layer.position,
+            popup_bounds,
+        })
+        .modifier(modifier)
+        .child(move || {
+            contexts.provide(|| {
+                layout().child(move || scrim.render());
+                layout().child(move || content.render());
+            });
+        });
+}
+
+/// # overlay_host
+///
+/// Hosts the overlay layers opened anywhere inside `content`.
+///
+/// ## Usage
+///
+/// Wrap the application root once so popups, menus, dialogs and sheets share
+/// one stack above the rest of the UI. Design-system themes usually do this
+/// for you.
+///
+/// ## Parameters
+///
+/// - `content` — optional content the overlays are drawn above.
+///
+/// ## Examples
+///
+/// ```
+/// # use tessera_ui::tessera;
+/// # #[tessera]
+/// # fn component() {
+/// use tessera_foundation::overlay::overlay_host;
+///
+/// overlay_host().content(|| { /* app content */ });
+/// # }
+/// # component();
+/// ```
+#[tessera]
+pub fn overlay_host(content: Option<RenderSlot>) {
+    let content = content.unwrap_or_else(RenderSlot::empty);
+    let stack = remember(|| Arc::new(RwLock::new(OverlayStack::default()))).get();
+    let revision = remember(|| 0_u64);
+    let bounds = remember_bounds();
+
+    // Reading the revision rebuilds the host whenever a layer changes.
+    let _ = revision.get();
+    let origin = bounds.get().map_or(PxPosition::ZERO, |bounds| {
+        PxPosition::new(bounds.x, bounds.y)
+    });
+    let entries = stack.read().entries.clone();
+    let modifier = Modifier::new().push_keyboard_input(OverlayHostKeyboardModifierNode {
+        stack: stack.clone(),
+    });
+    let host = OverlayHost { stack, revision };
+
+    provide_context(
+        move || host,
+        move || {
+            layout()
+                .layout_policy(OverlayHostLayout)
+                .modifier(modifier)
+                .child(move || {
+                    content.render();
+                    for entry in entries.iter() {
+                        let mut layer = entry.layer.clone();
+                        layer.position = layer.position.relative_to(origin);
+                        key(entry.id, move || {
+                            overlay_layer().layer(layer);
+                        });
+                    }
+                });
+        },
+    );
+}
+
+/// # overlay
+///
+/// Shows content in a layer above the rest of the UI.
+///
+/// ## Usage
+///
+/// Build popups, menus, dialogs and sheets that need to escape their parent's
+/// bounds, stack with other overlays and close on Escape, Back or a tap
+/// outside.
+///
+/// The content is rendered by the nearest [`overlay_host`]; without one it is
+/// rendered in place. Opening a modal overlay moves focus into it and traps
+/// Tab navigation there; closing it returns focus to whatever had it before.
+///
+/// ## Parameters
+///
+/// - `is_open` — whether the overlay is shown.
+/// - `modal` — whether the layer blocks input to content below and traps focus.
+/// - `dismiss_on_back` — whether Escape or Back calls `on_dismiss`.
+/// - `dismiss_on_outside` — whether a tap outside the content calls
+///   `on_dismiss`.
+/// - `on_dismiss` — optional callback invoked when the overlay asks to close.
+/// - `z_index` — stacking order; layers with equal z-index stack in opening
+///   order.
+/// - `position` — where the content is placed inside the host.
+/// - `scrim` — optional content drawn across the whole layer behind the
+///   content.
+/// - `content` — optional overlay content.
+///
+/// ## Examples
+///
+/// ```
+/// # use tessera_ui::tessera;
+/// # #[tessera]
+/// # fn component() {
+/// use tessera_foundation::overlay::{OverlayPosition, PopupPlacement, overlay};
+/// use tessera_ui::{Px, PxPosition, PxRect};
+///
+/// overlay()
+///     .is_open(true)
+///     .position(OverlayPosition::Anchored {
+///         anchor: PxRect::new(Px(16), Px(16), Px(48), Px(48)),
+///         placement: PopupPlacement::BelowStart,
+///         offset: PxPosition::ZERO,
+///     })
+///     .on_dismiss(|| {})
+///     .content(|| { /* popup content */ });
+/// # }
+/// # component();
+/// ```
+#[tessera]
+pub fn overlay(
+    is_open: Option<bool>,
+    modal: Option<bool>,
+    dismiss_on_back: Option<bool>,
+    dismiss_on_outside: Option<bool>,
+    on_dismiss: Option<Callback>,
+    z_index: Option<i32>,
+    position: Option<OverlayPosition>,
+    scrim: Option<RenderSlot>,
+    content: Option<RenderSlot>,
+) {
+    let is_open = is_open.unwrap_or(false);
+    let focus_scope = remember(FocusScopeNode::new).get();
+    let layer = OverlayLayer {
+        z_index: z_index.unwrap_or(0),
+        modal: modal.unwrap_or(false),
+        dismiss_on_back: dismiss_on_back.unwrap_or(true),
+        dismiss_on_outside: dismiss_on_outside.unwrap_or(true),
+        on_dismiss: on_dismiss.unwrap_or_default(),
+        position: position.unwrap_or_default(),
+        focus_scope,
+        contexts: ContextSnapshot::capture(),
+        scrim: scrim.unwrap_or_else(RenderSlot::empty),
+        content: content.unwrap_or_else(RenderSlot::empty),
+    };
+
+    let Some(host) = use_context::<OverlayHost>().map(|host| host.get()) else {
+        let bounds = remember_bounds();
+        if is_open {
+            let origin = bounds.get().map_or(PxPosition::ZERO, |bounds| {
+                PxPosition::new(bounds.x, bounds.y)
+            });
+            let mut layer = layer;
+            layer.position = layer.position.relative_to(origin);
+            overlay_layer().layer(layer);
+        }
+        return;
+    };
+
+    let registration = remember(|| OverlayRegistration {
+        id: next_overlay_id(),
+        host,
+        is_open: false,
+        saved_focus: None,
+    });
+    registration.with_mut(|registration| registration.sync(is_open, layer));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use tessera_ui::{
+        FocusRequester,
+        testing::{
+            __private::{
+                LayoutTestSession, advance_layout_test_session_by_nanos, pump_layout_test_session,
+                start_layout_test_session, tap_layout_test_session,
+            },
+            layout_test,
+        },
+    };
+
+    use super::*;
+
+    const FRAME_NANOS: u64 = 16_666_667;
+
+    thread_local! {
+        static DISMISSALS: Cell<usize> = const { Cell::new(0) };
+        static BACKGROUND_RELEASES: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[derive(Clone, PartialEq)]
+    struct FixedSize {
+        width: i32,
+        height: i32,
+    }
+
+    impl LayoutPolicy for FixedSize {
+        fn measure(&self, _input: &MeasureScope<'_>) -> Result<LayoutResult, MeasurementError> {
+            Ok(LayoutResult::default().with_size(ComputedData {
+                width: Px(self.width),
+                height: Px(self.height),
+            }))
+        }
+    }
+
+    /// Calls `on_release` for releases nothing above it consumed.
+    struct ReleaseProbe {
+        on_release: Callback,
+    }
+
+    impl PointerInputModifierNode for ReleaseProbe {
+        fn on_pointer_input(&self, mut input: PointerInput<'_>) {
+            if input.has_unconsumed_release() {
+                self.on_release.call();
+                input.block_all();
+            }
+        }
+    }
+
+    fn fixed_box(modifier: Modifier) {
+        layout()
+            .layout_policy(FixedSize {
+                width: 100,
+                height: 100,
+            })
+            .modifier(modifier);
+    }
+
+    /// A 100x100 background box at the origin that opens a 100x100 popup
+    /// centered in the window when tapped.
+    #[tessera]
+    fn overlay_input_case(modal: Option<bool>, anchor: Option<FocusRequester>) {
+        let modal = modal.unwrap_or(false);
+        let anchor = anchor.unwrap_or_default();
+        let open = remember(|| false);
+        overlay_host().content(move || {
+            fixed_box(
+                Modifier::new()
+                    .focus_requester(anchor)
+                    .focusable()
+                    .push_pointer_input(ReleaseProbe {
+                        on_release: Callback::new(move || {
+                            BACKGROUND_RELEASES.with(|count| count.set(count.get() + 1));
+                            open.set(true);
+                        }),
+                    }),
+            );
+            overlay()
+                .is_open(open.get())
+                .modal(modal)
+                .position(OverlayPosition::Aligned(Alignment::Center))
+                .on_dismiss(move || {
+                    DISMISSALS.with(|count| count.set(count.get() + 1));
+                    open.set(false);
+                })
+                .content(|| fixed_box(Modifier::new().focusable()));
+        });
+    }
+
+    fn start_input_case(modal: bool, anchor: FocusRequester) -> LayoutTestSession<impl Fn()> {
+        DISMISSALS.with(|count| count.set(0));
+        BACKGROUND_RELEASES.with(|count| count.set(0));
+        let mut session = start_layout_test_session(
+            layout_test(move || {
+                overlay_input_case().modal(modal).anchor(anchor);
+            })
+            .viewport_px(400, 300),
+        );
+        let _ = pump_layout_test_session(&mut session, 0);
+        session
+    }
+
+    /// Pumps enough frames for input callbacks, the overlay registration and
+    /// the host rebuild to take effect.
+    fn settle(session: &mut LayoutTestSession<impl Fn()>) {
+        for _ in 0..3 {
+            let _ = advance_layout_test_session_by_nanos(session, FRAME_NANOS);
+        }
+    }
+
+    fn tap(session: &mut LayoutTestSession<impl Fn()>, x: i32, y: i32) {
+        tap_layout_test_session(session, PxPosition::new(Px(x), Px(y)));
+        settle(session);
+    }
+
+    fn dismissals() -> usize {
+        DISMISSALS.with(Cell::get)
+    }
+
+    fn background_releases() -> usize {
+        BACKGROUND_RELEASES.with(Cell::get)
+    }
+
+    fn rect(x: i32, y: i32, width: i32, height: i32) -> PxRect {
+        PxRect::new(Px(x), Px(y), Px(width), Px(height))
+    }
+
+    fn resolve(anchor: PxRect, placement: PopupPlacement, offset: (i32, i32)) -> PxPosition {
+        resolve_popup_position(
+            anchor,
+            placement,
+            PxPosition::new(Px(offset.0), Px(offset.1)),
+            PxSize::new(Px(50), Px(30)),
+            PxSize::new(Px(200), Px(200)),
+        )
+    }
+
+    fn layer(z_index: i32, modal: bool, dismiss_on_back: bool) -> OverlayLayer {
+        OverlayLayer {
+            z_index,
+            modal,
+            dismiss_on_back,
+            dismiss_on_outside: true,
+            on_dismiss: Callback::noop(),
+            position: OverlayPosition::default(),
+            focus_scope: FocusScopeNode::new(),
+            contexts: ContextSnapshot::default(),
+            scrim: RenderSlot::empty(),
+            content: RenderSlot::empty(),
+        }
+    }
+
+    #[test]
+    fn popups_open_on_the_requested_side_when_they_fit() {
+        let anchor = rect(10, 10, 20, 10);
+        assert_eq!(
+            resolve(anchor, PopupPlacement::BelowStart, (0, 4)),
+            PxPosition::new(Px(10), Px(24))
+        );
+        assert_eq!(
+            resolve(rect(100, 100, 20, 10), PopupPlacement::AboveEnd, (0, 0)),
+            PxPosition::new(Px(70), Px(70))
+        );
+    }
+
+    #[test]
+    fn popups_flip_to_the_side_with_more_room() {
+        assert_eq!(
+            resolve(rect(10, 180, 20, 10), PopupPlacement::BelowStart, (0, 4)),
+            PxPosition::new(Px(10), Px(146))
+        );
+        assert_eq!(
+            resolve(rect(180, 20, 10, 10), PopupPlacement::RightStart, (0, 0)),
+            PxPosition::new(Px(130), Px(20))
+        );
+        // Neither side fits, so the roomier original side wins.
+        assert_eq!(
+            resolve_popup_position(
+                rect(0, 120, 20, 10),
+                PopupPlacement::AboveStart,
+                PxPosition::ZERO,
+                PxSize::new(Px(50), Px(150)),
+                PxSize::new(Px(200), Px(200)),
+            ),
+            PxPosition::new(Px(0), Px(0))
+        );
+    }
+
+    #[test]
+    fn popups_shift_to_stay_inside_bounds() {
+        assert_eq!(
+            resolve(rect(190, 10, 10, 10), PopupPlacement::BelowStart, (0, 0)),
+            PxPosition::new(Px(150), Px(20))
+        );
+        assert_eq!(
+            resolve(rect(5, 10, 10, 10), PopupPlacement::BelowEnd, (0, 0)),
+            PxPosition::new(Px(0), Px(20))
+        );
+    }
+
+    #[test]
+    fn layers_stack_by_z_index_then_opening_order() {
+        let mut stack = OverlayStack::default();
+        assert!(stack.upsert(1, layer(0, false, true)));
+        assert!(stack.upsert(2, layer(1, false, true)));
+        assert!(stack.upsert(3, layer(0, false, true)));
+        let order: Vec<u64> = stack.entries.iter().map(|entry| entry.id).collect();
+        assert_eq!(order, [1, 3, 2]);
+
+        let unchanged = stack.entries[0].layer.clone();
+        assert!(!stack.upsert(1, unchanged));
+        assert!(stack.remove(2));
+        assert!(!stack.remove(2));
+    }
+
+    #[test]
+    fn back_dismisses_the_topmost_layer_unless_a_modal_layer_ignores_it() {
+        let mut stack = OverlayStack::default();
+        assert!(stack.back_target().is_none());
+        stack.upsert(1, layer(0, false, true));
+        stack.upsert(2, layer(0, false, false));
+        assert!(stack.back_target().is_some());
+        stack.upsert(3, layer(0, true, false));
+        assert!(stack.back_target().is_none());
+    }
+
+    #[test]
+    fn taps_outside_the_popup_dismiss_it_and_taps_inside_do_not() {
+        let mut session = start_input_case(false, FocusRequester::new());
+        tap(&mut session, 50, 50);
+        assert_eq!(background_releases(), 1);
+
+        // The popup spans (150, 100) to (250, 200).
+        tap(&mut session, 200, 150);
+        assert_eq!(dismissals(), 0);
+
+        tap(&mut session, 350, 250);
+        assert_eq!(dismissals(), 1);
+
+        // The closed layer no longer intercepts taps.
+        tap(&mut session, 350, 250);
+        assert_eq!(dismissals(), 1);
+    }
+
+    #[test]
+    fn non_modal_layers_let_taps_reach_content_below() {
+        let mut session = start_input_case(false, FocusRequester::new());
+        tap(&mut session, 50, 50);
+        tap(&mut session, 50, 50);
+        assert_eq!(background_releases(), 2);
+    }
+
+    #[test]
+    fn modal_layers_block_taps_to_content_below() {
+        let mut session = start_input_case(true, FocusRequester::new());
+        tap(&mut session, 50, 50);
+        assert_eq!(background_releases(), 1);
+
+        tap(&mut session, 50, 50);
+        assert_eq!(background_releases(), 1);
+        assert_eq!(dismissals(), 1);
+    }
+
+    #[test]
+    fn closing_a_modal_layer_restores_focus_to_the_opener() {
+        let anchor = FocusRequester::new();
+        let mut session = start_input_case(true, anchor);
+        anchor.request_focus();
+        settle(&mut session);
+        assert!(anchor.has_focus());
+
+        tap(&mut session, 50, 50);
+        assert!(!anchor.has_focus());
+
+        tap(&mut session, 350, 250);
+        assert_eq!(dismissals(), 1);
+        assert!(anchor.has_focus());
+    }
+}
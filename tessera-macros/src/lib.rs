@@ -1521,27 +1521,37 @@ pub fn tessera(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// Annotate a public zero-argument function that returns
 /// `tessera_ui::EntryPoint`.
+///
+/// When the annotated crate is built with its `hot-reload` feature on a
+/// desktop target, the entry is also exported as an unmangled symbol so
+/// `cargo tessera dev --hot-reload` can load rebuilt libraries into a running
+/// app.
 #[proc_macro_attribute]
 pub fn entry(attr: TokenStream, item: TokenStream) -> TokenStream {
     let crate_path: syn::Path = match parse_crate_path(attr) {
         Ok(path) => path,
         Err(err) => return err.to_compile_error().into(),
     };
-    let mut input_fn = parse_macro_input!(item as ItemFn);
+    let input_fn = parse_macro_input!(item as ItemFn);
+
+    match entry_tokens(&crate_path, input_fn) {
+        Ok(expanded) => expanded.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
 
+fn entry_tokens(crate_path: &Path, mut input_fn: ItemFn) -> syn::Result<proc_macro2::TokenStream> {
     if !input_fn.sig.inputs.is_empty() {
-        return syn::Error::new_spanned(
+        return Err(syn::Error::new_spanned(
             &input_fn.sig.inputs,
             "entry functions must not accept arguments",
-        )
-        .to_compile_error()
-        .into();
+        ));
     }
 
     input_fn.attrs.retain(|attr| !attr.path().is_ident("entry"));
     let fn_name = &input_fn.sig.ident;
 
-    let expanded = quote! {
+    Ok(quote! {
         #input_fn
 
         #[cfg(target_os = "android")]
@@ -1551,9 +1561,23 @@ pub fn entry(attr: TokenStream, item: TokenStream) -> TokenStream {
                 eprintln!("App failed to run: {err}");
             }
         }
-    };
 
-    expanded.into()
+        // The `hot-reload` feature belongs to the app crate, which may not
+        // declare it.
+        #[allow(unexpected_cfgs)]
+        const _: () = {
+            #[cfg(all(
+                feature = "hot-reload",
+                not(target_os = "android"),
+                not(target_family = "wasm")
+            ))]
+            #[doc(hidden)]
+            #[unsafe(no_mangle)]
+            pub fn __tessera_hot_reload_entry() -> #crate_path::EntryPoint {
+                #fn_name()
+            }
+        };
+    })
 }
 
 /// Transforms a function into a *shard component* that can be navigated to via
@@ -1835,3 +1859,77 @@ pub fn shard(attr: TokenStream, input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens as _;
+    use syn::{Item, parse_quote};
+
+    use super::entry_tokens;
+
+    fn hot_reload_cfg(file: &syn::File) -> Option<String> {
+        file.items.iter().find_map(|item| {
+            let Item::Const(item) = item else {
+                return None;
+            };
+            let syn::Expr::Block(block) = item.expr.as_ref() else {
+                return None;
+            };
+            block.block.stmts.iter().find_map(|stmt| {
+                let syn::Stmt::Item(Item::Fn(function)) = stmt else {
+                    return None;
+                };
+                (function.sig.ident == "__tessera_hot_reload_entry").then(|| {
+                    function
+                        .attrs
+                        .iter()
+                        .filter(|attr| attr.path().is_ident("cfg"))
+                        .map(|attr| attr.to_token_stream().to_string())
+                        .collect::<String>()
+                })
+            })
+        })
+    }
+
+    #[test]
+    fn entry_exports_hot_reload_symbol_only_with_hot_reload_feature() {
+        let expanded = entry_tokens(
+            &parse_quote!(::tessera_ui),
+            parse_quote! {
+                pub fn run() -> ::tessera_ui::EntryPoint {
+                    todo!()
+                }
+            },
+        )
+        .expect("entry should expand");
+        let file: syn::File = syn::parse2(expanded).expect("expansion should parse");
+
+        let cfg = hot_reload_cfg(&file).expect("hot reload entry should be emitted");
+        assert!(cfg.contains("feature = \"hot-reload\""), "{cfg}");
+        assert!(cfg.contains("not (target_family = \"wasm\")"), "{cfg}");
+        assert!(
+            !file.items.iter().any(|item| matches!(
+                item,
+                Item::Fn(function) if function.sig.ident == "__tessera_hot_reload_entry"
+            )),
+            "the symbol must only be exported from the gated block"
+        );
+    }
+
+    #[test]
+    fn entry_rejects_arguments() {
+        let error = entry_tokens(
+            &parse_quote!(::tessera_ui),
+            parse_quote! {
+                pub fn run(value: u32) -> ::tessera_ui::EntryPoint {
+                    todo!()
+                }
+            },
+        )
+        .expect_err("arguments should be rejected");
+        assert_eq!(
+            error.to_string(),
+            "entry functions must not accept arguments"
+        );
+    }
+}
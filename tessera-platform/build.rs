@@ -0,0 +1,3 @@
+fn main() {
+    tessera_build::generate_assets().expect("Failed to generate tessera assets");
+}
@@ -0,0 +1,758 @@
+//! Linux menu bar, tray icon and notifications over D-Bus.
+//!
+//! Menus are exported with the `com.canonical.dbusmenu` protocol. The menu
+//! bar is handed to the `com.canonical.AppMenu.Registrar` service for an X11
+//! window, the tray is a `StatusNotifierItem` registered with the
+//! `StatusNotifierWatcher`, and notifications go to
+//! `org.freedesktop.Notifications`.
+
+use std::{collections::HashMap, sync::Arc};
+
+use parking_lot::RwLock;
+use serde::Serialize;
+use tessera_ui::{
+    post_to_ui_thread,
+    shortcut::KeySequence,
+    winit::{
+        keyboard::ModifiersState,
+        raw_window_handle::{HasWindowHandle, RawWindowHandle},
+        window::Window,
+    },
+};
+use tracing::warn;
+use zbus::{
+    blocking::Connection,
+    interface, proxy,
+    zvariant::{ObjectPath, OwnedObjectPath, OwnedValue, Type, Value},
+};
+
+use crate::{
+    menu::{Menu, MenuEntry, MenuItem},
+    native_menu::{Notification, TrayIcon, TrayImage},
+};
+
+const DBUS_MENU_INTERFACE: &str = "com.canonical.dbusmenu";
+const STATUS_NOTIFIER_ITEM_INTERFACE: &str = "org.kde.StatusNotifierItem";
+const MENU_BAR_PATH: &str = "/MenuBar";
+const TRAY_PATH: &str = "/StatusNotifierItem";
+const TRAY_MENU_PATH: &str = "/StatusNotifierItem/Menu";
+
+/// Returns the X11 id of `window`; other window systems have no global menu
+/// bar registrar.
+pub(crate) fn x11_window_id(window: &Window) -> Option<u32> {
+    match window.window_handle().ok()?.as_raw() {
+        RawWindowHandle::Xlib(handle) => u32::try_from(handle.window).ok(),
+        RawWindowHandle::Xcb(handle) => Some(handle.window.get()),
+        _ => None,
+    }
+}
+
+/// Session bus connection exporting the app's menus and tray icon.
+pub(crate) struct DbusShell {
+    connection: Connection,
+    menu_bar: Option<ExportedMenuBar>,
+    tray: Option<ExportedTray>,
+}
+
+struct ExportedMenuBar {
+    window_id: u32,
+    menu: Menu,
+    tree: Arc<RwLock<MenuTree>>,
+    registered: bool,
+}
+
+struct ExportedTray {
+    service_name: String,
+    tray: Arc<RwLock<TrayIcon>>,
+    menu: Arc<RwLock<MenuTree>>,
+}
+
+impl DbusShell {
+    /// Connects to the session bus named by `DBUS_SESSION_BUS_ADDRESS`.
+    pub(crate) fn session() -> zbus::Result<Self> {
+        Ok(Self {
+            connection: Connection::session()?,
+            menu_bar: None,
+            tray: None,
+        })
+    }
+
+    /// Returns whether a global menu bar accepted the exported menu.
+    pub(crate) fn menu_bar_registered(&self) -> bool {
+        self.menu_bar
+            .as_ref()
+            .is_some_and(|menu_bar| menu_bar.registered)
+    }
+
+    /// Exports `menu` as the menu bar of the given X11 window, or removes the
+    /// exported menu bar.
+    pub(crate) fn set_menu_bar(&mut self, menu_bar: Option<(&Menu, u32)>) -> zbus::Result<()> {
+        if let (Some((menu, window_id)), Some(exported)) = (menu_bar, self.menu_bar.as_mut())
+            && exported.window_id == window_id
+        {
+            if exported.menu != *menu {
+                exported.menu = menu.clone();
+                let tree = exported.tree.clone();
+                self.update_menu(MENU_BAR_PATH, &tree, menu)?;
+            }
+            return Ok(());
+        }
+
+        if let Some(exported) = self.menu_bar.take() {
+            self.unregister_menu_bar(&exported);
+            self.connection
+                .object_server()
+                .remove::<DbusMenu, _>(MENU_BAR_PATH)?;
+        }
+        let Some((menu, window_id)) = menu_bar else {
+            return Ok(());
+        };
+
+        let tree = Arc::new(RwLock::new(MenuTree::new(menu, 1)));
+        self.connection
+            .object_server()
+            .at(MENU_BAR_PATH, DbusMenu { tree: tree.clone() })?;
+        let registered = AppMenuRegistrarProxyBlocking::new(&self.connection)
+            .and_then(|registrar| {
+                registrar.register_window(
+                    window_id,
+                    &ObjectPath::from_static_str_unchecked(MENU_BAR_PATH),
+                )
+            })
+            .is_ok();
+        self.menu_bar = Some(ExportedMenuBar {
+            window_id,
+            menu: menu.clone(),
+            tree,
+            registered,
+        });
+        Ok(())
+    }
+
+    /// Exports `tray` as a `StatusNotifierItem`, or removes the exported one.
+    pub(crate) fn set_tray(&mut self, tray: Option<&TrayIcon>) -> zbus::Result<()> {
+        let Some(tray) = tray else {
+            if let Some(exported) = self.tray.take() {
+                let server = self.connection.object_server();
+                server.remove::<StatusNotifierItem, _>(TRAY_PATH)?;
+                server.remove::<DbusMenu, _>(TRAY_MENU_PATH)?;
+                self.connection
+                    .release_name(exported.service_name.as_str())?;
+            }
+            return Ok(());
+        };
+
+        if let Some(exported) = &self.tray {
+            *exported.tray.write() = tray.clone();
+            let menu = exported.menu.clone();
+            self.update_menu(TRAY_MENU_PATH, &menu, &tray.menu)?;
+            for signal in ["NewTitle", "NewIcon", "NewToolTip"] {
+                self.connection.emit_signal(
+                    None::<&str>,
+                    TRAY_PATH,
+                    STATUS_NOTIFIER_ITEM_INTERFACE,
+                    signal,
+                    &(),
+                )?;
+            }
+            return Ok(());
+        }
+
+        let menu = Arc::new(RwLock::new(MenuTree::new(&tray.menu, 1)));
+        let state = Arc::new(RwLock::new(tray.clone()));
+        let server = self.connection.object_server();
+        server.at(TRAY_MENU_PATH, DbusMenu { tree: menu.clone() })?;
+        server.at(
+            TRAY_PATH,
+            StatusNotifierItem {
+                tray: state.clone(),
+            },
+        )?;
+        let service_name = format!("org.kde.StatusNotifierItem-{}-1", std::process::id());
+        self.connection.request_name(service_name.as_str())?;
+        if let Err(err) = StatusNotifierWatcherProxyBlocking::new(&self.connection)
+            .and_then(|watcher| watcher.register_status_notifier_item(&service_name))
+        {
+            warn!("No StatusNotifierItem host is available: {err}");
+        }
+        self.tray = Some(ExportedTray {
+            service_name,
+            tray: state,
+            menu,
+        });
+        Ok(())
+    }
+
+    /// Shows `notification`, returning its server id.
+    pub(crate) fn notify(&self, notification: &Notification) -> zbus::Result<u32> {
+        let app_name = self
+            .tray
+            .as_ref()
+            .map(|exported| exported.tray.read().title.clone())
+            .unwrap_or_default();
+        let expire_timeout = notification.timeout.map_or(-1, |timeout| {
+            i32::try_from(timeout.as_millis()).unwrap_or(i32::MAX)
+        });
+        NotificationsProxyBlocking::new(&self.connection)?.notify(
+            &app_name,
+            0,
+            notification.icon_name.as_deref().unwrap_or_default(),
+            &notification.summary,
+            &notification.body,
+            &[],
+            HashMap::new(),
+            expire_timeout,
+        )
+    }
+
+    fn update_menu(&self, path: &str, tree: &RwLock<MenuTree>, menu: &Menu) -> zbus::Result<()> {
+        let revision = {
+            let mut tree = tree.write();
+            let revision = tree.revision.wrapping_add(1);
+            *tree = MenuTree::new(menu, revision);
+            revision
+        };
+        self.connection.emit_signal(
+            None::<&str>,
+            path,
+            DBUS_MENU_INTERFACE,
+            "LayoutUpdated",
+            &(revision, 0_i32),
+        )
+    }
+
+    fn unregister_menu_bar(&self, exported: &ExportedMenuBar) {
+        if !exported.registered {
+            return;
+        }
+        let _ = AppMenuRegistrarProxyBlocking::new(&self.connection)
+            .and_then(|registrar| registrar.unregister_window(exported.window_id));
+    }
+}
+
+impl Drop for DbusShell {
+    fn drop(&mut self) {
+        if let Some(exported) = self.menu_bar.take() {
+            self.unregister_menu_bar(&exported);
+        }
+    }
+}
+
+/// A menu flattened into the numbered items of the dbusmenu protocol.
+///
+/// Id 0 is the root; entries are numbered depth first.
+struct MenuTree {
+    revision: u32,
+    nodes: Vec<MenuNode>,
+}
+
+struct MenuNode {
+    entry: NodeEntry,
+    children: Vec<i32>,
+}
+
+enum NodeEntry {
+    Root,
+    Item(MenuItem),
+    Submenu(String),
+    Separator,
+}
+
+/// One `(ia{sv}av)` layout item; children are variants holding layout items.
+#[derive(Serialize, Type, Value, OwnedValue)]
+struct LayoutNode {
+    id: i32,
+    properties: HashMap<String, OwnedValue>,
+    children: Vec<OwnedValue>,
+}
+
+impl MenuTree {
+    fn new(menu: &Menu, revision: u32) -> Self {
+        let mut tree = Self {
+            revision,
+            nodes: vec![MenuNode {
+                entry: NodeEntry::Root,
+                children: Vec::new(),
+            }],
+        };
+        tree.nodes[0].children = tree.push_entries(menu);
+        tree
+    }
+
+    fn push_entries(&mut self, menu: &Menu) -> Vec<i32> {
+        menu.entries()
+            .iter()
+            .map(|entry| {
+                let id = self.nodes.len();
+                let (entry, submenu) = match entry {
+                    MenuEntry::Item(item) => (NodeEntry::Item(item.clone()), None),
+                    MenuEntry::Submenu { label, menu } => {
+                        (NodeEntry::Submenu(label.clone()), Some(menu))
+                    }
+                    MenuEntry::Separator => (NodeEntry::Separator, None),
+                };
+                self.nodes.push(MenuNode {
+                    entry,
+                    children: Vec::new(),
+                });
+                if let Some(menu) = submenu {
+                    self.nodes[id].children = self.push_entries(menu);
+                }
+                id as i32
+            })
+            .collect()
+    }
+
+    fn node(&self, id: i32) -> Option<&MenuNode> {
+        usize::try_from(id)
+            .ok()
+            .and_then(|index| self.nodes.get(index))
+    }
+
+    fn item(&self, id: i32) -> Option<MenuItem> {
+        match &self.node(id)?.entry {
+            NodeEntry::Item(item) => Some(item.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the requested properties of item `id`; no names means all.
+    fn properties(&self, id: i32, names: &[String]) -> Option<HashMap<String, OwnedValue>> {
+        let node = self.node(id)?;
+        let mut properties = HashMap::new();
+        let mut insert = |name: &str, value: Value<'_>| {
+            if (names.is_empty() || names.iter().any(|wanted| wanted == name))
+                && let Ok(value) = OwnedValue::try_from(value)
+            {
+                properties.insert(name.to_string(), value);
+            }
+        };
+        match &node.entry {
+            NodeEntry::Root => insert("children-display", Value::from("submenu")),
+            NodeEntry::Submenu(label) => {
+                insert("label", Value::from(escape_mnemonics(label)));
+                insert("children-display", Value::from("submenu"));
+            }
+            NodeEntry::Separator => insert("type", Value::from("separator")),
+            NodeEntry::Item(item) => {
+                insert("label", Value::from(escape_mnemonics(item.label())));
+                insert("enabled", Value::from(item.is_enabled()));
+                if let Some(checked) = item.check_state() {
+                    insert("toggle-type", Value::from("checkmark"));
+                    insert("toggle-state", Value::from(i32::from(checked)));
+                }
+                if let Some(accelerator) = item.resolved_accelerator() {
+                    insert("shortcut", Value::from(shortcut_property(&accelerator)));
+                }
+            }
+        }
+        Some(properties)
+    }
+
+    /// Lays out item `id` with `depth` levels of children; -1 is unlimited.
+    fn layout(&self, id: i32, depth: i32, names: &[String]) -> Option<LayoutNode> {
+        let node = self.node(id)?;
+        let children = if depth == 0 {
+            Vec::new()
+        } else {
+            node.children
+                .iter()
+                .filter_map(|child| self.layout(*child, depth - 1, names))
+                .filter_map(|child| OwnedValue::try_from(child).ok())
+                .collect()
+        };
+        Some(LayoutNode {
+            id,
+            properties: self.properties(id, names)?,
+            children,
+        })
+    }
+}
+
+/// dbusmenu labels mark mnemonics with `_`; literal underscores are doubled.
+fn escape_mnemonics(label: &str) -> String {
+    label.replace('_', "__")
+}
+
+/// Converts a shortcut to the dbusmenu `shortcut` property, one list of
+/// modifier and key names per chord.
+fn shortcut_property(sequence: &KeySequence) -> Vec<Vec<String>> {
+    sequence
+        .chords()
+        .iter()
+        .map(|chord| {
+            let modifiers = chord.modifiers();
+            let mut keys: Vec<String> = [
+                (ModifiersState::CONTROL, "Control"),
+                (ModifiersState::ALT, "Alt"),
+                (ModifiersState::SHIFT, "Shift"),
+                (ModifiersState::SUPER, "Super"),
+            ]
+            .into_iter()
+            .filter(|(modifier, _)| modifiers.contains(*modifier))
+            .map(|(_, name)| name.to_string())
+            .collect();
+            keys.push(chord.key().name());
+            keys
+        })
+        .collect()
+}
+
+/// Converts an RGBA image to the ARGB32 pixmaps of the StatusNotifierItem
+/// protocol.
+fn argb_pixmaps(icon: Option<&TrayImage>) -> Vec<(i32, i32, Vec<u8>)> {
+    icon.map(|icon| {
+        let argb = icon
+            .rgba
+            .chunks_exact(4)
+            .flat_map(|pixel| [pixel[3], pixel[0], pixel[1], pixel[2]])
+            .collect();
+        vec![(icon.width as i32, icon.height as i32, argb)]
+    })
+    .unwrap_or_default()
+}
+
+fn unknown_item(id: i32) -> zbus::fdo::Error {
+    zbus::fdo::Error::InvalidArgs(format!("unknown menu item {id}"))
+}
+
+struct DbusMenu {
+    tree: Arc<RwLock<MenuTree>>,
+}
+
+#[interface(name = "com.canonical.dbusmenu")]
+impl DbusMenu {
+    fn get_layout(
+        &self,
+        parent_id: i32,
+        recursion_depth: i32,
+        property_names: Vec<String>,
+    ) -> zbus::fdo::Result<(u32, LayoutNode)> {
+        let tree = self.tree.read();
+        let layout = tree
+            .layout(parent_id, recursion_depth, &property_names)
+            .ok_or_else(|| unknown_item(parent_id))?;
+        Ok((tree.revision, layout))
+    }
+
+    fn get_group_properties(
+        &self,
+        ids: Vec<i32>,
+        property_names: Vec<String>,
+    ) -> Vec<(i32, HashMap<String, OwnedValue>)> {
+        let tree = self.tree.read();
+        ids.into_iter()
+            .filter_map(|id| Some((id, tree.properties(id, &property_names)?)))
+            .collect()
+    }
+
+    fn get_property(&self, id: i32, name: String) -> zbus::fdo::Result<OwnedValue> {
+        self.tree
+            .read()
+            .properties(id, std::slice::from_ref(&name))
+            .and_then(|mut properties| properties.remove(&name))
+            .ok_or_else(|| unknown_item(id))
+    }
+
+    fn event(&self, id: i32, event_id: String, _data: OwnedValue, _timestamp: u32) {
+        if event_id != "clicked" {
+            return;
+        }
+        let item = self.tree.read().item(id);
+        if let Some(item) = item {
+            item.activate();
+        }
+    }
+
+    fn event_group(&self, events: Vec<(i32, String, OwnedValue, u32)>) -> Vec<i32> {
+        let mut id_errors = Vec::new();
+        for (id, event_id, data, timestamp) in events {
+            if self.tree.read().node(id).is_none() {
+                id_errors.push(id);
+                continue;
+            }
+            self.event(id, event_id, data, timestamp);
+        }
+        id_errors
+    }
+
+    fn about_to_show(&self, _id: i32) -> bool {
+        false
+    }
+
+    fn about_to_show_group(&self, _ids: Vec<i32>) -> (Vec<i32>, Vec<i32>) {
+        (Vec::new(), Vec::new())
+    }
+
+    #[zbus(property)]
+    fn version(&self) -> u32 {
+        3
+    }
+
+    #[zbus(property)]
+    fn text_direction(&self) -> String {
+        "ltr".to_string()
+    }
+
+    #[zbus(property)]
+    fn status(&self) -> String {
+        "normal".to_string()
+    }
+
+    #[zbus(property)]
+    fn icon_theme_path(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+struct StatusNotifierItem {
+    tray: Arc<RwLock<TrayIcon>>,
+}
+
+#[interface(name = "org.kde.StatusNotifierItem")]
+impl StatusNotifierItem {
+    fn activate(&self, _x: i32, _y: i32) {
+        if let Some(action) = self.tray.read().on_activate.clone() {
+            post_to_ui_thread(move || action());
+        }
+    }
+
+    fn secondary_activate(&self, _x: i32, _y: i32) {}
+
+    fn context_menu(&self, _x: i32, _y: i32) {}
+
+    fn scroll(&self, _delta: i32, _orientation: String) {}
+
+    #[zbus(property)]
+    fn category(&self) -> String {
+        "ApplicationStatus".to_string()
+    }
+
+    #[zbus(property)]
+    fn id(&self) -> String {
+        self.tray.read().id.clone()
+    }
+
+    #[zbus(property)]
+    fn title(&self) -> String {
+        self.tray.read().title.clone()
+    }
+
+    #[zbus(property)]
+    fn status(&self) -> String {
+        "Active".to_string()
+    }
+
+    #[zbus(property)]
+    fn window_id(&self) -> i32 {
+        0
+    }
+
+    #[zbus(property)]
+    fn icon_name(&self) -> String {
+        self.tray.read().icon_name.clone().unwrap_or_default()
+    }
+
+    #[zbus(property)]
+    fn icon_pixmap(&self) -> Vec<(i32, i32, Vec<u8>)> {
+        argb_pixmaps(self.tray.read().icon.as_ref())
+    }
+
+    #[zbus(property)]
+    fn overlay_icon_name(&self) -> String {
+        String::new()
+    }
+
+    #[zbus(property)]
+    fn attention_icon_name(&self) -> String {
+        String::new()
+    }
+
+    #[zbus(property)]
+    fn tool_tip(&self) -> (String, Vec<(i32, i32, Vec<u8>)>, String, String) {
+        let tray = self.tray.read();
+        (
+            tray.icon_name.clone().unwrap_or_default(),
+            argb_pixmaps(tray.icon.as_ref()),
+            tray.title.clone(),
+            tray.tooltip.clone().unwrap_or_default(),
+        )
+    }
+
+    #[zbus(property)]
+    fn item_is_menu(&self) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn menu(&self) -> OwnedObjectPath {
+        ObjectPath::from_static_str_unchecked(TRAY_MENU_PATH).into()
+    }
+}
+
+#[proxy(
+    interface = "org.kde.StatusNotifierWatcher",
+    default_service = "org.kde.StatusNotifierWatcher",
+    default_path = "/StatusNotifierWatcher"
+)]
+trait StatusNotifierWatcher {
+    fn register_status_notifier_item(&self, service: &str) -> zbus::Result<()>;
+}
+
+#[proxy(
+    interface = "com.canonical.AppMenu.Registrar",
+    default_service = "com.canonical.AppMenu.Registrar",
+    default_path = "/com/canonical/AppMenu/Registrar"
+)]
+trait AppMenuRegistrar {
+    fn register_window(
+        &self,
+        window_id: u32,
+        menu_object_path: &ObjectPath<'_>,
+    ) -> zbus::Result<()>;
+
+    fn unregister_window(&self, window_id: u32) -> zbus::Result<()>;
+}
+
+#[proxy(
+    interface = "org.freedesktop.Notifications",
+    default_service = "org.freedesktop.Notifications",
+    default_path = "/org/freedesktop/Notifications"
+)]
+trait Notifications {
+    #[allow(clippy::too_many_arguments)]
+    fn notify(
+        &self,
+        app_name: &str,
+        replaces_id: u32,
+        app_icon: &str,
+        summary: &str,
+        body: &str,
+        actions: &[&str],
+        hints: HashMap<&str, &Value<'_>>,
+        expire_timeout: i32,
+    ) -> zbus::Result<u32>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file_menu() -> Menu {
+        Menu::new().submenu(
+            "_File",
+            Menu::new()
+                .item(
+                    MenuItem::new("Open").accelerator(
+                        "Ctrl+Shift+O"
+                            .parse::<KeySequence>()
+                            .expect("valid shortcut"),
+                    ),
+                )
+                .separator()
+                .item(MenuItem::new("Autosave").checked(true).enabled(false)),
+        )
+    }
+
+    fn property<T>(properties: &HashMap<String, OwnedValue>, name: &str) -> T
+    where
+        T: TryFrom<OwnedValue>,
+        T::Error: std::fmt::Debug,
+    {
+        let value = properties.get(name).expect("property is set");
+        T::try_from(value.try_clone().expect("no file descriptors")).expect("value type")
+    }
+
+    #[test]
+    fn menus_flatten_depth_first() {
+        let tree = MenuTree::new(&file_menu(), 7);
+        assert_eq!(tree.revision, 7);
+        assert_eq!(tree.nodes[0].children, vec![1]);
+        assert_eq!(tree.nodes[1].children, vec![2, 3, 4]);
+
+        let submenu = tree.properties(1, &[]).expect("submenu exists");
+        assert_eq!(property::<String>(&submenu, "label"), "__File");
+        assert_eq!(property::<String>(&submenu, "children-display"), "submenu");
+        let separator = tree.properties(3, &[]).expect("separator exists");
+        assert_eq!(property::<String>(&separator, "type"), "separator");
+        assert!(tree.properties(5, &[]).is_none());
+        assert!(tree.item(2).is_some() && tree.item(3).is_none());
+    }
+
+    #[test]
+    fn item_properties_follow_requested_names() {
+        let tree = MenuTree::new(&file_menu(), 1);
+        let toggle = tree
+            .properties(4, &["toggle-state".to_string()])
+            .expect("item exists");
+        assert_eq!(toggle.len(), 1);
+        assert_eq!(property::<i32>(&toggle, "toggle-state"), 1);
+
+        let shortcut = "Ctrl+K Shift+F5"
+            .parse::<KeySequence>()
+            .expect("valid shortcut");
+        assert_eq!(
+            shortcut_property(&shortcut),
+            vec![
+                vec!["Control".to_string(), "K".to_string()],
+                vec!["Shift".to_string(), "F5".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn pixmaps_are_argb() {
+        let icon = TrayImage::from_rgba(1, 1, vec![1, 2, 3, 4]).expect("one pixel");
+        assert_eq!(argb_pixmaps(Some(&icon)), vec![(1, 1, vec![4, 1, 2, 3])]);
+        assert!(TrayImage::from_rgba(2, 1, vec![0; 4]).is_none());
+    }
+
+    #[proxy(interface = "com.canonical.dbusmenu")]
+    trait MenuClient {
+        #[allow(clippy::type_complexity)]
+        fn get_layout(
+            &self,
+            parent_id: i32,
+            recursion_depth: i32,
+            property_names: &[&str],
+        ) -> zbus::Result<(u32, (i32, HashMap<String, OwnedValue>, Vec<OwnedValue>))>;
+    }
+
+    /// Exports a tray on the session bus and reads its menu back from a second
+    /// connection. Run it with
+    /// `dbus-run-session -- cargo test -p tessera-platform --features
+    /// native-shell -- --ignored`.
+    #[test]
+    #[ignore = "requires a D-Bus session bus"]
+    fn tray_menu_is_served_on_the_session_bus() {
+        let mut shell = DbusShell::session().expect("session bus");
+        let tray = TrayIcon::new("tessera-test").menu(file_menu());
+        shell.set_tray(Some(&tray)).expect("tray exported");
+
+        let client = Connection::session().expect("session bus");
+        let service = shell
+            .connection
+            .unique_name()
+            .expect("unique name")
+            .to_string();
+        let menu = MenuClientProxyBlocking::builder(&client)
+            .destination(service)
+            .expect("valid destination")
+            .path(TRAY_MENU_PATH)
+            .expect("valid path")
+            .build()
+            .expect("menu proxy");
+
+        let (revision, (id, _, children)) = menu.get_layout(0, -1, &[]).expect("layout");
+        assert_eq!((revision, id, children.len()), (1, 0, 1));
+
+        shell
+            .set_tray(Some(&tray.clone().menu(Menu::new())))
+            .expect("tray updated");
+        let (revision, (_, _, children)) = menu.get_layout(0, -1, &[]).expect("layout");
+        assert_eq!((revision, children.len()), (2, 0));
+
+        shell.set_tray(None).expect("tray removed");
+        assert!(menu.get_layout(0, -1, &[]).is_err());
+    }
+}
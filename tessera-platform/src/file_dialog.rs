@@ -0,0 +1,636 @@
+//! Native file open, save and folder dialogs.
+//!
+//! ## Usage
+//!
+//! Let users pick documents to open, a location to save to, or a folder,
+//! without blocking the UI thread.
+//!
+//! Dialogs run in the background and deliver their [`FileDialogResponse`] to
+//! a [`State`] on the UI thread. With the `native-shell` feature,
+//! `FileDialogPlugin` installs the platform backend: the XDG Desktop Portal
+//! on Linux and `<input type=file>` on the web. Tests install a
+//! [`FakeFileDialogBackend`] to script responses headlessly.
+//!
+//! ```
+//! use tessera_platform::file_dialog::{FileDialog, FileDialogResponse, FileFilter};
+//! use tessera_ui::{remember, tessera};
+//!
+//! #[tessera]
+//! fn open_button() {
+//!     let picked = remember(|| None::<FileDialogResponse>);
+//!     let open = move || {
+//!         FileDialog::new()
+//!             .title("Open Image")
+//!             .filter(FileFilter::new("Images").extension("png").extension("jpg"))
+//!             .multiple(true)
+//!             .open_file(picked);
+//!     };
+//! }
+//! ```
+
+use std::{
+    collections::VecDeque,
+    fmt,
+    path::{Path, PathBuf},
+    sync::{Arc, OnceLock},
+};
+
+use parking_lot::{Mutex, RwLock};
+#[cfg(feature = "native-shell")]
+use tessera_ui::{Plugin, PluginContext, PluginResult};
+use tessera_ui::{State, post_to_ui_thread};
+
+/// Plugin that provides the platform file dialog backend.
+///
+/// Requires the `native-shell` feature. Without it, dialogs only reach a
+/// backend installed with [`set_file_dialog_backend`].
+#[cfg(feature = "native-shell")]
+#[derive(Clone, Debug)]
+pub struct FileDialogPlugin;
+
+#[cfg(feature = "native-shell")]
+impl FileDialogPlugin {
+    /// Creates a file dialog plugin.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[cfg(feature = "native-shell")]
+impl Default for FileDialogPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "native-shell")]
+impl Plugin for FileDialogPlugin {
+    fn on_resumed(&mut self, context: &PluginContext) -> PluginResult {
+        let mut state = file_dialog_state().write();
+        #[cfg(all(feature = "native-shell", target_os = "linux"))]
+        {
+            state.parent_window = crate::dbus_menu::x11_window_id(context.window())
+                .map(|window_id| format!("x11:{window_id:x}"));
+        }
+        #[cfg(not(all(feature = "native-shell", target_os = "linux")))]
+        {
+            let _ = context;
+        }
+        if state.backend.is_none() {
+            state.backend = platform_backend();
+        }
+        Ok(())
+    }
+
+    fn on_shutdown(&mut self, _context: &PluginContext) -> PluginResult {
+        #[cfg(all(feature = "native-shell", target_os = "linux"))]
+        {
+            file_dialog_state().write().parent_window = None;
+        }
+        Ok(())
+    }
+}
+
+/// What a file dialog asks the user for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileDialogKind {
+    /// One or more existing files.
+    OpenFile,
+    /// A path to save to.
+    SaveFile,
+    /// An existing folder.
+    PickFolder,
+}
+
+/// A named group of file types offered by a dialog.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileFilter {
+    /// Name shown for the filter, e.g. `Images`.
+    pub name: String,
+    /// Glob patterns such as `*.png`.
+    pub patterns: Vec<String>,
+    /// MIME types such as `image/png`.
+    pub mime_types: Vec<String>,
+}
+
+impl FileFilter {
+    /// Creates an empty filter named `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            patterns: Vec::new(),
+            mime_types: Vec::new(),
+        }
+    }
+
+    /// Adds a glob pattern such as `*.tar.gz`.
+    pub fn pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.patterns.push(pattern.into());
+        self
+    }
+
+    /// Adds files ending in `.{extension}`.
+    pub fn extension(self, extension: &str) -> Self {
+        let extension = extension.trim_start_matches('.');
+        self.pattern(format!("*.{extension}"))
+    }
+
+    /// Adds a MIME type such as `image/*`.
+    pub fn mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_types.push(mime_type.into());
+        self
+    }
+}
+
+/// A dialog request as seen by a [`FileDialogBackend`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileDialogRequest {
+    /// What the dialog asks for.
+    pub kind: FileDialogKind,
+    /// Dialog title; backends pick a default when `None`.
+    pub title: Option<String>,
+    /// Label of the confirm button.
+    pub accept_label: Option<String>,
+    /// Filters offered to the user; the first one is selected.
+    pub filters: Vec<FileFilter>,
+    /// Whether several files may be opened at once.
+    pub multiple: bool,
+    /// Folder to start in, or file to preselect when saving.
+    pub default_path: Option<PathBuf>,
+}
+
+/// Builder for a file dialog.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FileDialog {
+    title: Option<String>,
+    accept_label: Option<String>,
+    filters: Vec<FileFilter>,
+    multiple: bool,
+    default_path: Option<PathBuf>,
+}
+
+impl FileDialog {
+    /// Creates a dialog with platform defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the dialog title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the label of the confirm button.
+    pub fn accept_label(mut self, label: impl Into<String>) -> Self {
+        self.accept_label = Some(label.into());
+        self
+    }
+
+    /// Adds a file type filter; the first filter is selected initially.
+    pub fn filter(mut self, filter: FileFilter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    /// Allows opening several files at once.
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    /// Starts in a folder, or preselects a file name when saving.
+    pub fn default_path(mut self, path: impl AsRef<Path>) -> Self {
+        self.default_path = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Asks for files to open and stores the response in `result`.
+    pub fn open_file(self, result: State<Option<FileDialogResponse>>) {
+        self.show(FileDialogKind::OpenFile, deliver_to(result));
+    }
+
+    /// Asks for a path to save to and stores the response in `result`.
+    pub fn save_file(self, result: State<Option<FileDialogResponse>>) {
+        self.show(FileDialogKind::SaveFile, deliver_to(result));
+    }
+
+    /// Asks for a folder and stores the response in `result`.
+    pub fn pick_folder(self, result: State<Option<FileDialogResponse>>) {
+        self.show(FileDialogKind::PickFolder, deliver_to(result));
+    }
+
+    /// Shows the dialog and passes the response to `on_response`.
+    ///
+    /// `on_response` may run on any thread, once.
+    pub fn show<F>(self, kind: FileDialogKind, on_response: F)
+    where
+        F: FnOnce(FileDialogResponse) + Send + 'static,
+    {
+        let request = FileDialogRequest {
+            kind,
+            title: self.title,
+            accept_label: self.accept_label,
+            filters: self.filters,
+            multiple: self.multiple && kind == FileDialogKind::OpenFile,
+            default_path: self.default_path,
+        };
+        let responder = FileDialogResponder::new(on_response);
+        let backend = file_dialog_state().read().backend.clone();
+        match backend {
+            Some(backend) => backend.show(request, responder),
+            None => responder.respond(FileDialogResponse::Failed(
+                "no file dialog backend is available on this platform".to_string(),
+            )),
+        }
+    }
+}
+
+/// Outcome of a file dialog.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FileDialogResponse {
+    /// The user confirmed; holds one entry unless several files were opened.
+    Selected(Vec<PickedFile>),
+    /// The user dismissed the dialog.
+    Cancelled,
+    /// The dialog could not be shown.
+    Failed(String),
+}
+
+impl FileDialogResponse {
+    /// Returns the picked files, or nothing when the dialog was not confirmed.
+    pub fn files(&self) -> &[PickedFile] {
+        match self {
+            Self::Selected(files) => files,
+            Self::Cancelled | Self::Failed(_) => &[],
+        }
+    }
+}
+
+/// A file or folder picked in a dialog.
+///
+/// Native dialogs return paths. The web has no file system paths, so its
+/// files carry their contents instead.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PickedFile {
+    name: String,
+    path: Option<PathBuf>,
+    contents: Option<Arc<[u8]>>,
+}
+
+impl PickedFile {
+    /// Creates an entry for a file system path.
+    pub fn from_path(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Self {
+            name,
+            path: Some(path),
+            contents: None,
+        }
+    }
+
+    /// Creates an entry for a file that only exists in memory.
+    pub fn from_contents(name: impl Into<String>, contents: impl Into<Arc<[u8]>>) -> Self {
+        Self {
+            name: name.into(),
+            path: None,
+            contents: Some(contents.into()),
+        }
+    }
+
+    /// Returns the file name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the file system path, when there is one.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Returns the contents read by the dialog, when the platform has no
+    /// paths.
+    pub fn contents(&self) -> Option<&[u8]> {
+        self.contents.as_deref()
+    }
+}
+
+impl fmt::Debug for PickedFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PickedFile")
+            .field("name", &self.name)
+            .field("path", &self.path)
+            .field(
+                "contents_len",
+                &self.contents.as_ref().map(|bytes| bytes.len()),
+            )
+            .finish()
+    }
+}
+
+/// Shows file dialogs for a platform.
+pub trait FileDialogBackend: Send + Sync {
+    /// Shows the dialog described by `request` without blocking, then
+    /// answers through `responder`.
+    fn show(&self, request: FileDialogRequest, responder: FileDialogResponder);
+}
+
+/// Delivers the response of one dialog.
+///
+/// Dropping it without responding reports [`FileDialogResponse::Cancelled`].
+pub struct FileDialogResponder {
+    on_response: Option<Box<dyn FnOnce(FileDialogResponse) + Send>>,
+}
+
+impl FileDialogResponder {
+    fn new<F>(on_response: F) -> Self
+    where
+        F: FnOnce(FileDialogResponse) + Send + 'static,
+    {
+        Self {
+            on_response: Some(Box::new(on_response)),
+        }
+    }
+
+    /// Answers the dialog request.
+    pub fn respond(mut self, response: FileDialogResponse) {
+        if let Some(on_response) = self.on_response.take() {
+            on_response(response);
+        }
+    }
+}
+
+impl Drop for FileDialogResponder {
+    fn drop(&mut self) {
+        if let Some(on_response) = self.on_response.take() {
+            on_response(FileDialogResponse::Cancelled);
+        }
+    }
+}
+
+/// Backend that answers dialogs with scripted responses, for headless tests.
+///
+/// Dialogs without a scripted response are cancelled.
+#[derive(Clone, Default)]
+pub struct FakeFileDialogBackend {
+    inner: Arc<Mutex<FakeFileDialogState>>,
+}
+
+#[derive(Default)]
+struct FakeFileDialogState {
+    responses: VecDeque<FileDialogResponse>,
+    requests: Vec<FileDialogRequest>,
+}
+
+impl FakeFileDialogBackend {
+    /// Creates a backend with no scripted responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues the response for the next dialog.
+    pub fn push_response(&self, response: FileDialogResponse) {
+        self.inner.lock().responses.push_back(response);
+    }
+
+    /// Queues a confirmed dialog that picked `paths`.
+    pub fn push_paths<I, P>(&self, paths: I)
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PathBuf>,
+    {
+        let files = paths.into_iter().map(PickedFile::from_path).collect();
+        self.push_response(FileDialogResponse::Selected(files));
+    }
+
+    /// Returns the requests shown so far, oldest first.
+    pub fn requests(&self) -> Vec<FileDialogRequest> {
+        self.inner.lock().requests.clone()
+    }
+}
+
+impl FileDialogBackend for FakeFileDialogBackend {
+    fn show(&self, request: FileDialogRequest, responder: FileDialogResponder) {
+        let response = {
+            let mut inner = self.inner.lock();
+            inner.requests.push(request);
+            inner.responses.pop_front()
+        };
+        responder.respond(response.unwrap_or(FileDialogResponse::Cancelled));
+    }
+}
+
+#[derive(Default)]
+struct FileDialogState {
+    backend: Option<Arc<dyn FileDialogBackend>>,
+    #[cfg(all(feature = "native-shell", target_os = "linux"))]
+    parent_window: Option<String>,
+}
+
+fn file_dialog_state() -> &'static Arc<RwLock<FileDialogState>> {
+    static STATE: OnceLock<Arc<RwLock<FileDialogState>>> = OnceLock::new();
+    STATE.get_or_init(|| Arc::new(RwLock::new(FileDialogState::default())))
+}
+
+/// Replaces the backend used by every dialog, e.g. with a
+/// [`FakeFileDialogBackend`] in tests.
+pub fn set_file_dialog_backend(backend: impl FileDialogBackend + 'static) {
+    file_dialog_state().write().backend = Some(Arc::new(backend));
+}
+
+/// Returns the platform handle of the window dialogs are attached to, in XDG
+/// Desktop Portal form, e.g. `x11:4a00007`.
+#[cfg(all(feature = "native-shell", target_os = "linux"))]
+pub(crate) fn parent_window() -> Option<String> {
+    file_dialog_state().read().parent_window.clone()
+}
+
+fn deliver_to(
+    result: State<Option<FileDialogResponse>>,
+) -> impl FnOnce(FileDialogResponse) + Send + 'static {
+    move |response| post_to_ui_thread(move || result.set(Some(response)))
+}
+
+#[cfg(all(feature = "native-shell", target_os = "linux"))]
+fn platform_backend() -> Option<Arc<dyn FileDialogBackend>> {
+    Some(Arc::new(crate::xdg_portal::PortalFileDialogBackend))
+}
+
+#[cfg(all(feature = "native-shell", target_family = "wasm"))]
+fn platform_backend() -> Option<Arc<dyn FileDialogBackend>> {
+    Some(Arc::new(web::WebFileDialogBackend))
+}
+
+#[cfg(all(
+    feature = "native-shell",
+    not(any(target_os = "linux", target_family = "wasm"))
+))]
+fn platform_backend() -> Option<Arc<dyn FileDialogBackend>> {
+    None
+}
+
+#[cfg(all(feature = "native-shell", target_family = "wasm"))]
+mod web {
+    use std::{cell::RefCell, rc::Rc};
+
+    use wasm_bindgen::{JsCast, closure::Closure};
+    use wasm_bindgen_futures::JsFuture;
+    use web_sys::{FileList, HtmlInputElement};
+
+    use super::{
+        FileDialogBackend, FileDialogKind, FileDialogRequest, FileDialogResponder,
+        FileDialogResponse, FileFilter, PickedFile,
+    };
+
+    /// Shows dialogs with a detached `<input type=file>`.
+    pub(super) struct WebFileDialogBackend;
+
+    impl FileDialogBackend for WebFileDialogBackend {
+        fn show(&self, request: FileDialogRequest, responder: FileDialogResponder) {
+            if request.kind == FileDialogKind::SaveFile {
+                responder.respond(FileDialogResponse::Failed(
+                    "saving through a file dialog is not supported on the web".to_string(),
+                ));
+                return;
+            }
+            let Some(input) = web_sys::window()
+                .and_then(|window| window.document())
+                .and_then(|document| document.create_element("input").ok())
+                .and_then(|element| element.dyn_into::<HtmlInputElement>().ok())
+            else {
+                responder.respond(FileDialogResponse::Failed(
+                    "no document to show a file input in".to_string(),
+                ));
+                return;
+            };
+
+            input.set_type("file");
+            input.set_multiple(request.multiple);
+            input.set_accept(&accept_attribute(&request.filters));
+            if request.kind == FileDialogKind::PickFolder {
+                let _ = input.set_attribute("webkitdirectory", "");
+            }
+
+            // Only one of `change` and `cancel` fires.
+            let responder = Rc::new(RefCell::new(Some(responder)));
+            let change_responder = responder.clone();
+            let change_input = input.clone();
+            let on_change = Closure::once_into_js(move || {
+                let Some(responder) = change_responder.borrow_mut().take() else {
+                    return;
+                };
+                let files = change_input.files();
+                wasm_bindgen_futures::spawn_local(async move {
+                    responder.respond(read_files(files).await);
+                });
+            });
+            let on_cancel = Closure::once_into_js(move || {
+                if let Some(responder) = responder.borrow_mut().take() {
+                    responder.respond(FileDialogResponse::Cancelled);
+                }
+            });
+            input.set_onchange(Some(on_change.unchecked_ref()));
+            input.set_oncancel(Some(on_cancel.unchecked_ref()));
+            input.click();
+        }
+    }
+
+    async fn read_files(files: Option<FileList>) -> FileDialogResponse {
+        let Some(files) = files else {
+            return FileDialogResponse::Cancelled;
+        };
+        let mut picked = Vec::new();
+        for index in 0..files.length() {
+            let Some(file) = files.get(index) else {
+                continue;
+            };
+            match JsFuture::from(file.array_buffer()).await {
+                Ok(buffer) => {
+                    let contents = js_sys::Uint8Array::new(&buffer).to_vec();
+                    picked.push(PickedFile::from_contents(file.name(), contents));
+                }
+                Err(_) => {
+                    return FileDialogResponse::Failed(format!("failed to read {}", file.name()));
+                }
+            }
+        }
+        FileDialogResponse::Selected(picked)
+    }
+
+    /// Joins filters into an `accept` attribute, e.g. `.png,image/jpeg`.
+    fn accept_attribute(filters: &[FileFilter]) -> String {
+        filters
+            .iter()
+            .flat_map(|filter| {
+                let extensions = filter
+                    .patterns
+                    .iter()
+                    .filter_map(|pattern| pattern.strip_prefix('*'))
+                    .map(str::to_string);
+                extensions.chain(filter.mime_types.iter().cloned())
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use super::*;
+
+    #[test]
+    fn fake_backend_scripts_responses_and_records_requests() {
+        let backend = FakeFileDialogBackend::new();
+        backend.push_paths(["/tmp/a.txt", "/tmp/b.txt"]);
+        set_file_dialog_backend(backend.clone());
+
+        let (sender, receiver) = mpsc::channel();
+        let dialog = FileDialog::new()
+            .title("Open")
+            .filter(FileFilter::new("Text").extension(".txt"))
+            .multiple(true)
+            .default_path("/tmp");
+        let on_response = {
+            let sender = sender.clone();
+            move |response| sender.send(response).expect("receiver alive")
+        };
+        dialog.clone().show(FileDialogKind::OpenFile, on_response);
+        let response = receiver.recv().expect("response delivered");
+        let names: Vec<&str> = response.files().iter().map(PickedFile::name).collect();
+        assert_eq!(names, ["a.txt", "b.txt"]);
+        assert_eq!(response.files()[0].path(), Some(Path::new("/tmp/a.txt")));
+
+        dialog.show(FileDialogKind::SaveFile, move |response| {
+            sender.send(response).expect("receiver alive")
+        });
+        assert_eq!(
+            receiver.recv().expect("response delivered"),
+            FileDialogResponse::Cancelled
+        );
+
+        let requests = backend.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].filters[0].patterns, ["*.txt"]);
+        assert!(requests[0].multiple);
+        // Only opening files can select several.
+        assert!(!requests[1].multiple);
+    }
+
+    #[test]
+    fn dropped_responders_cancel() {
+        let (sender, receiver) = mpsc::channel();
+        drop(FileDialogResponder::new(move |response| {
+            sender.send(response).expect("receiver alive")
+        }));
+        assert_eq!(
+            receiver.recv().expect("response delivered"),
+            FileDialogResponse::Cancelled
+        );
+    }
+}
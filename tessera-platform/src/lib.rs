@@ -2,7 +2,13 @@
 //!
 //! ## Usage
 //!
-//! Register platform plugins like clipboard and window access at app startup.
+//! Register platform plugins like clipboard, window, native menu and file
+//! dialog access at app startup.
+//!
+//! ## Features
+//!
+//! - `native-shell`: native menu bar, tray icon, notifications and file
+//!   dialogs. Off by default because it brings a D-Bus client on Linux.
 #![deny(
     missing_docs,
     clippy::unwrap_used,
@@ -12,11 +18,24 @@
 )]
 
 pub mod clipboard;
+#[cfg(all(feature = "native-shell", target_os = "linux"))]
+mod dbus_menu;
+pub mod file_dialog;
+pub mod menu;
+#[cfg(feature = "native-shell")]
+pub mod native_menu;
+pub mod res;
 pub mod window;
+#[cfg(all(feature = "native-shell", target_os = "linux"))]
+mod xdg_portal;
 
 use tessera_ui::{EntryRegistry, TesseraPackage};
 
 pub use clipboard::{Clipboard, ClipboardPlugin};
+#[cfg(feature = "native-shell")]
+pub use file_dialog::FileDialogPlugin;
+#[cfg(feature = "native-shell")]
+pub use native_menu::NativeMenuPlugin;
 pub use window::WindowPlugin;
 
 /// Package that registers platform plugins.
@@ -34,5 +53,10 @@ impl TesseraPackage for PlatformPackage {
     fn register(self, registry: &mut EntryRegistry) {
         registry.register_plugin(ClipboardPlugin::new());
         registry.register_plugin(WindowPlugin::new());
+        #[cfg(feature = "native-shell")]
+        {
+            registry.register_plugin(NativeMenuPlugin::new());
+            registry.register_plugin(FileDialogPlugin::new());
+        }
     }
 }
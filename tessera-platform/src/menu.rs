@@ -0,0 +1,254 @@
+//! Declarative menu model shared by native and in-app menus.
+//!
+//! ## Usage
+//!
+//! Describe application, tray and context menus once and show them natively
+//! or with the `menus` components.
+//!
+//! ```
+//! use tessera_platform::menu::{Menu, MenuItem};
+//!
+//! let file = Menu::new()
+//!     .item(MenuItem::new("Save").command("file.save"))
+//!     .separator()
+//!     .item(MenuItem::new("Word Wrap").checked(true));
+//! let menu_bar = Menu::new().submenu("File", file);
+//! assert_eq!(menu_bar.entries().len(), 1);
+//! ```
+
+use std::sync::Arc;
+
+use tessera_ui::{
+    post_to_ui_thread,
+    shortcut::{self, CommandId, KeySequence},
+};
+
+/// Action run when a menu item is activated.
+pub type MenuAction = Arc<dyn Fn() + Send + Sync>;
+
+/// An ordered list of menu entries.
+#[derive(Clone, Default, PartialEq)]
+pub struct Menu {
+    entries: Vec<MenuEntry>,
+}
+
+impl Menu {
+    /// Creates an empty menu.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an item.
+    pub fn item(mut self, item: MenuItem) -> Self {
+        self.entries.push(MenuEntry::Item(item));
+        self
+    }
+
+    /// Appends a nested menu opened from an entry labelled `label`.
+    pub fn submenu(mut self, label: impl Into<String>, menu: Menu) -> Self {
+        self.entries.push(MenuEntry::Submenu {
+            label: label.into(),
+            menu,
+        });
+        self
+    }
+
+    /// Appends a separator.
+    pub fn separator(mut self) -> Self {
+        self.entries.push(MenuEntry::Separator);
+        self
+    }
+
+    /// Returns the entries in display order.
+    pub fn entries(&self) -> &[MenuEntry] {
+        &self.entries
+    }
+
+    /// Returns whether the menu has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// One entry of a [`Menu`].
+#[derive(Clone, PartialEq)]
+pub enum MenuEntry {
+    /// An activatable item.
+    Item(MenuItem),
+    /// A nested menu.
+    Submenu {
+        /// Label of the entry that opens the nested menu.
+        label: String,
+        /// Entries of the nested menu.
+        menu: Menu,
+    },
+    /// A separator line.
+    Separator,
+}
+
+/// An activatable menu item.
+///
+/// An item runs its command, its action, or both when activated. Its
+/// accelerator defaults to the first shortcut bound to its command.
+#[derive(Clone)]
+pub struct MenuItem {
+    label: String,
+    command: Option<CommandId>,
+    accelerator: Option<KeySequence>,
+    enabled: bool,
+    checked: Option<bool>,
+    on_activate: Option<MenuAction>,
+}
+
+impl MenuItem {
+    /// Creates an enabled item labelled `label`.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            command: None,
+            accelerator: None,
+            enabled: true,
+            checked: None,
+            on_activate: None,
+        }
+    }
+
+    /// Runs `command` when the item is activated.
+    pub fn command(mut self, command: impl Into<CommandId>) -> Self {
+        self.command = Some(command.into());
+        self
+    }
+
+    /// Shows `accelerator` instead of the command's bound shortcut.
+    pub fn accelerator(mut self, accelerator: impl Into<KeySequence>) -> Self {
+        self.accelerator = Some(accelerator.into());
+        self
+    }
+
+    /// Sets whether the item can be activated.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Makes the item checkable and sets its check state.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    /// Runs `action` on the UI thread when the item is activated.
+    pub fn on_activate<F>(mut self, action: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.on_activate = Some(Arc::new(action));
+        self
+    }
+
+    /// Returns the label.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the command run on activation.
+    pub fn command_id(&self) -> Option<&CommandId> {
+        self.command.as_ref()
+    }
+
+    /// Returns whether the item can be activated.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the check state, or `None` when the item is not checkable.
+    pub fn check_state(&self) -> Option<bool> {
+        self.checked
+    }
+
+    /// Returns the shortcut shown next to the item.
+    pub fn resolved_accelerator(&self) -> Option<KeySequence> {
+        self.accelerator.clone().or_else(|| {
+            let command = self.command.clone()?;
+            shortcut::command_bindings(command).into_iter().next()
+        })
+    }
+
+    /// Returns the label of [`Self::resolved_accelerator`] for the current
+    /// platform.
+    pub fn accelerator_label(&self) -> Option<String> {
+        self.resolved_accelerator().as_ref().map(KeySequence::label)
+    }
+
+    /// Activates the item from any thread.
+    ///
+    /// Disabled items ignore activation.
+    pub fn activate(&self) {
+        if !self.enabled {
+            return;
+        }
+        if let Some(action) = self.on_activate.clone() {
+            post_to_ui_thread(move || action());
+        }
+        if let Some(command) = self.command.clone() {
+            shortcut::execute_command(command);
+        }
+    }
+}
+
+impl PartialEq for MenuItem {
+    fn eq(&self, other: &Self) -> bool {
+        let same_action = match (&self.on_activate, &other.on_activate) {
+            (Some(action), Some(other)) => Arc::ptr_eq(action, other),
+            (None, None) => true,
+            _ => false,
+        };
+        same_action
+            && self.label == other.label
+            && self.command == other.command
+            && self.accelerator == other.accelerator
+            && self.enabled == other.enabled
+            && self.checked == other.checked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explicit_accelerator_overrides_command_binding() {
+        shortcut::bind_command(
+            "menu.test.open",
+            "Ctrl+O".parse::<KeySequence>().expect("valid shortcut"),
+        );
+        let item = MenuItem::new("Open").command("menu.test.open");
+        assert_eq!(
+            item.accelerator_label().as_deref(),
+            Some(label("⌃O", "Ctrl+O"))
+        );
+
+        let item = item.accelerator("F3".parse::<KeySequence>().expect("valid shortcut"));
+        assert_eq!(item.accelerator_label().as_deref(), Some("F3"));
+        assert_eq!(MenuItem::new("About").accelerator_label(), None);
+    }
+
+    #[test]
+    fn items_compare_actions_by_identity() {
+        let action: MenuAction = Arc::new(|| {});
+        let mut item = MenuItem::new("Quit");
+        item.on_activate = Some(action.clone());
+        let mut same = MenuItem::new("Quit");
+        same.on_activate = Some(action);
+        assert!(item == same);
+        assert!(item != MenuItem::new("Quit").on_activate(|| {}));
+    }
+
+    fn label(apple: &'static str, other: &'static str) -> &'static str {
+        if cfg!(any(target_os = "macos", target_os = "ios")) {
+            apple
+        } else {
+            other
+        }
+    }
+}
@@ -0,0 +1,479 @@
+//! Native application menu bar, system tray and notifications.
+//!
+//! ## Usage
+//!
+//! Show the app's [`Menu`] in the desktop's global menu bar and keep a tray
+//! icon with a context menu.
+//!
+//! State set here is kept while the platform is unavailable and applied once
+//! [`NativeMenuPlugin`] connects. On Linux the tray is a
+//! `StatusNotifierItem`, menus use the `com.canonical.dbusmenu` protocol and
+//! the menu bar is registered with the `com.canonical.AppMenu.Registrar`
+//! service, which only knows X11 windows. Apps should render an in-window
+//! menu bar when [`menu_bar_is_native`] returns `false`.
+
+#[cfg(target_os = "linux")]
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::{
+    sync::{Arc, OnceLock, mpsc},
+    time::Duration,
+};
+
+use parking_lot::RwLock;
+use tessera_ui::{Plugin, PluginContext, PluginResult};
+
+#[cfg(target_os = "linux")]
+use tracing::warn;
+
+use crate::menu::{Menu, MenuAction};
+
+/// Plugin that shows the native menu bar and tray icon.
+#[derive(Clone, Debug)]
+pub struct NativeMenuPlugin;
+
+impl NativeMenuPlugin {
+    /// Creates a native menu plugin.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for NativeMenuPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for NativeMenuPlugin {
+    fn on_resumed(&mut self, context: &PluginContext) -> PluginResult {
+        #[cfg(target_os = "linux")]
+        {
+            let window_id = crate::dbus_menu::x11_window_id(context.window());
+            if let Some(shell) = shell_thread() {
+                native_menu_state().write().connect(shell, window_id);
+            }
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            let _ = context;
+        }
+        Ok(())
+    }
+
+    fn on_shutdown(&mut self, _context: &PluginContext) -> PluginResult {
+        native_menu_state().write().disconnect();
+        Ok(())
+    }
+}
+
+/// A tray icon with a context menu.
+#[derive(Clone)]
+pub struct TrayIcon {
+    pub(crate) id: String,
+    pub(crate) title: String,
+    pub(crate) tooltip: Option<String>,
+    pub(crate) icon_name: Option<String>,
+    pub(crate) icon: Option<TrayImage>,
+    pub(crate) menu: Menu,
+    pub(crate) on_activate: Option<MenuAction>,
+}
+
+impl TrayIcon {
+    /// Creates a tray icon identified by `id`, usually the app name.
+    pub fn new(id: impl Into<String>) -> Self {
+        let id = id.into();
+        Self {
+            title: id.clone(),
+            id,
+            tooltip: None,
+            icon_name: None,
+            icon: None,
+            menu: Menu::new(),
+            on_activate: None,
+        }
+    }
+
+    /// Sets the title announced for the icon.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Sets the tooltip text.
+    pub fn tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Uses an icon from the desktop icon theme.
+    pub fn icon_name(mut self, icon_name: impl Into<String>) -> Self {
+        self.icon_name = Some(icon_name.into());
+        self
+    }
+
+    /// Uses an image, shown when no themed icon is set or found.
+    pub fn icon(mut self, icon: TrayImage) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Sets the context menu.
+    pub fn menu(mut self, menu: Menu) -> Self {
+        self.menu = menu;
+        self
+    }
+
+    /// Runs `action` on the UI thread when the icon itself is activated.
+    pub fn on_activate<F>(mut self, action: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.on_activate = Some(Arc::new(action));
+        self
+    }
+}
+
+/// RGBA8 image shown as a tray icon.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TrayImage {
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    pub(crate) rgba: Arc<[u8]>,
+}
+
+impl TrayImage {
+    /// Creates an image from tightly packed RGBA8 pixels.
+    ///
+    /// Returns `None` when `rgba` does not hold `width * height` pixels.
+    pub fn from_rgba(width: u32, height: u32, rgba: impl Into<Arc<[u8]>>) -> Option<Self> {
+        let rgba = rgba.into();
+        let expected = (width as usize)
+            .checked_mul(height as usize)?
+            .checked_mul(4)?;
+        (rgba.len() == expected).then_some(Self {
+            width,
+            height,
+            rgba,
+        })
+    }
+}
+
+/// A desktop notification.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Notification {
+    pub(crate) summary: String,
+    pub(crate) body: String,
+    pub(crate) icon_name: Option<String>,
+    pub(crate) timeout: Option<Duration>,
+}
+
+impl Notification {
+    /// Creates a notification with a one-line summary.
+    pub fn new(summary: impl Into<String>) -> Self {
+        Self {
+            summary: summary.into(),
+            body: String::new(),
+            icon_name: None,
+            timeout: None,
+        }
+    }
+
+    /// Sets the body text.
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Uses an icon from the desktop icon theme.
+    pub fn icon_name(mut self, icon_name: impl Into<String>) -> Self {
+        self.icon_name = Some(icon_name.into());
+        self
+    }
+
+    /// Closes the notification after `timeout` instead of the desktop
+    /// default.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+/// Work for the thread that owns the D-Bus connection, so that blocking bus
+/// calls never run on the UI thread.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+enum ShellTask {
+    Connect,
+    Disconnect,
+    MenuBar(Option<(Menu, u32)>),
+    Tray(Option<TrayIcon>),
+    Notify(Notification),
+}
+
+// Other platforms only keep the state until they get a backend.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+#[derive(Default)]
+struct NativeMenuState {
+    menu_bar: Option<Menu>,
+    tray: Option<TrayIcon>,
+    window_id: Option<u32>,
+    /// Queue of the shell thread while the plugin is connected.
+    shell: Option<mpsc::Sender<ShellTask>>,
+}
+
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+impl NativeMenuState {
+    fn connect(&mut self, shell: mpsc::Sender<ShellTask>, window_id: Option<u32>) {
+        self.shell = Some(shell);
+        self.window_id = window_id;
+        self.send(ShellTask::Connect);
+        self.sync_menu_bar();
+        self.sync_tray();
+    }
+
+    fn disconnect(&mut self) {
+        self.send(ShellTask::Disconnect);
+        self.shell = None;
+    }
+
+    fn sync_menu_bar(&mut self) {
+        let menu_bar = self.menu_bar.clone().zip(self.window_id);
+        self.send(ShellTask::MenuBar(menu_bar));
+    }
+
+    fn sync_tray(&mut self) {
+        self.send(ShellTask::Tray(self.tray.clone()));
+    }
+
+    fn send(&mut self, task: ShellTask) {
+        if let Some(shell) = &self.shell
+            && shell.send(task).is_err()
+        {
+            self.shell = None;
+        }
+    }
+}
+
+fn native_menu_state() -> &'static Arc<RwLock<NativeMenuState>> {
+    static STATE: OnceLock<Arc<RwLock<NativeMenuState>>> = OnceLock::new();
+    STATE.get_or_init(|| Arc::new(RwLock::new(NativeMenuState::default())))
+}
+
+/// Whether the desktop accepted the exported menu bar, as last seen by the
+/// shell thread.
+#[cfg(target_os = "linux")]
+static MENU_BAR_REGISTERED: AtomicBool = AtomicBool::new(false);
+
+/// Returns the queue of the shell thread, starting it on first use.
+#[cfg(target_os = "linux")]
+fn shell_thread() -> Option<mpsc::Sender<ShellTask>> {
+    static THREAD: OnceLock<Option<mpsc::Sender<ShellTask>>> = OnceLock::new();
+    THREAD
+        .get_or_init(|| {
+            let (sender, tasks) = mpsc::channel();
+            let spawned = std::thread::Builder::new()
+                .name("tessera-native-menu".to_string())
+                .spawn(move || run_shell(tasks));
+            match spawned {
+                Ok(_) => Some(sender),
+                Err(err) => {
+                    warn!("Failed to start the native menu thread: {err}");
+                    None
+                }
+            }
+        })
+        .clone()
+}
+
+#[cfg(target_os = "linux")]
+fn run_shell(tasks: mpsc::Receiver<ShellTask>) {
+    use crate::dbus_menu::DbusShell;
+
+    let mut shell: Option<DbusShell> = None;
+    for task in tasks {
+        match task {
+            ShellTask::Connect => {
+                if shell.is_none() {
+                    match DbusShell::session() {
+                        Ok(connected) => shell = Some(connected),
+                        Err(err) => warn!("Failed to connect to the D-Bus session bus: {err}"),
+                    }
+                }
+            }
+            ShellTask::Disconnect => shell = None,
+            ShellTask::MenuBar(menu_bar) => {
+                let menu_bar = menu_bar
+                    .as_ref()
+                    .map(|(menu, window_id)| (menu, *window_id));
+                if let Some(shell) = shell.as_mut()
+                    && let Err(err) = shell.set_menu_bar(menu_bar)
+                {
+                    warn!("Failed to export the native menu bar: {err}");
+                }
+            }
+            ShellTask::Tray(tray) => {
+                if let Some(shell) = shell.as_mut()
+                    && let Err(err) = shell.set_tray(tray.as_ref())
+                {
+                    warn!("Failed to export the tray icon: {err}");
+                }
+            }
+            ShellTask::Notify(notification) => {
+                if let Some(shell) = shell.as_ref()
+                    && let Err(err) = shell.notify(&notification)
+                {
+                    warn!("Failed to show notification: {err}");
+                }
+            }
+        }
+        let registered = shell.as_ref().is_some_and(DbusShell::menu_bar_registered);
+        MENU_BAR_REGISTERED.store(registered, Ordering::Release);
+    }
+}
+
+/// Shows `menu` as the application menu bar; each top-level submenu becomes
+/// one menu bar entry.
+pub fn set_menu_bar(menu: Menu) {
+    let mut state = native_menu_state().write();
+    state.menu_bar = Some(menu);
+    state.sync_menu_bar();
+}
+
+/// Removes the application menu bar.
+pub fn clear_menu_bar() {
+    let mut state = native_menu_state().write();
+    state.menu_bar = None;
+    state.sync_menu_bar();
+}
+
+/// Returns whether the desktop currently shows the menu bar set with
+/// [`set_menu_bar`].
+pub fn menu_bar_is_native() -> bool {
+    #[cfg(target_os = "linux")]
+    {
+        MENU_BAR_REGISTERED.load(Ordering::Acquire)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        false
+    }
+}
+
+/// Shows `tray`, replacing the current tray icon.
+pub fn set_tray_icon(tray: TrayIcon) {
+    let mut state = native_menu_state().write();
+    state.tray = Some(tray);
+    state.sync_tray();
+}
+
+/// Removes the tray icon.
+pub fn remove_tray_icon() {
+    let mut state = native_menu_state().write();
+    state.tray = None;
+    state.sync_tray();
+}
+
+/// Shows a desktop notification when notifications are available.
+pub fn show_notification(notification: Notification) {
+    native_menu_state()
+        .write()
+        .send(ShellTask::Notify(notification));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::menu::MenuItem;
+
+    fn file_menu() -> Menu {
+        Menu::new().submenu("File", Menu::new().item(MenuItem::new("Open")))
+    }
+
+    fn drain(tasks: &mpsc::Receiver<ShellTask>) -> Vec<ShellTask> {
+        tasks.try_iter().collect()
+    }
+
+    #[test]
+    fn state_set_before_connecting_is_applied_on_connect() {
+        let mut state = NativeMenuState {
+            menu_bar: Some(file_menu()),
+            tray: Some(TrayIcon::new("app").tooltip("Running")),
+            ..Default::default()
+        };
+        state.sync_menu_bar();
+        state.send(ShellTask::Notify(Notification::new("dropped")));
+
+        let (sender, tasks) = mpsc::channel();
+        state.connect(sender, Some(42));
+        let sent = drain(&tasks);
+        assert_eq!(sent.len(), 3);
+        assert!(matches!(sent[0], ShellTask::Connect));
+        assert!(matches!(
+            &sent[1],
+            ShellTask::MenuBar(Some((menu, 42))) if *menu == file_menu()
+        ));
+        assert!(matches!(
+            &sent[2],
+            ShellTask::Tray(Some(tray)) if tray.tooltip.as_deref() == Some("Running")
+        ));
+    }
+
+    #[test]
+    fn menu_bar_needs_a_window() {
+        let (sender, tasks) = mpsc::channel();
+        let mut state = NativeMenuState::default();
+        state.connect(sender, None);
+        drain(&tasks);
+
+        state.menu_bar = Some(file_menu());
+        state.sync_menu_bar();
+        assert!(matches!(drain(&tasks)[..], [ShellTask::MenuBar(None)]));
+    }
+
+    #[test]
+    fn disconnecting_stops_queueing() {
+        let (sender, tasks) = mpsc::channel();
+        let mut state = NativeMenuState::default();
+        state.connect(sender, Some(1));
+        drain(&tasks);
+
+        state.disconnect();
+        state.tray = Some(TrayIcon::new("app"));
+        state.sync_tray();
+        state.send(ShellTask::Notify(Notification::new("later")));
+        assert!(matches!(drain(&tasks)[..], [ShellTask::Disconnect]));
+        assert!(state.tray.is_some());
+    }
+
+    #[test]
+    fn a_stopped_shell_thread_disconnects() {
+        let (sender, tasks) = mpsc::channel();
+        let mut state = NativeMenuState::default();
+        state.connect(sender, Some(1));
+        drop(tasks);
+
+        state.sync_tray();
+        assert!(state.shell.is_none());
+    }
+
+    #[test]
+    fn builders_fill_tray_and_notification() {
+        let tray = TrayIcon::new("app").title("App").icon_name("app-icon");
+        assert_eq!((tray.id.as_str(), tray.title.as_str()), ("app", "App"));
+        assert_eq!(tray.icon_name.as_deref(), Some("app-icon"));
+        assert_eq!(TrayIcon::new("app").title, "app");
+
+        let notification = Notification::new("Saved")
+            .body("All changes are on disk")
+            .timeout(Duration::from_secs(3));
+        assert_eq!(notification.summary, "Saved");
+        assert_eq!(notification.body, "All changes are on disk");
+        assert_eq!(notification.timeout, Some(Duration::from_secs(3)));
+    }
+
+    #[test]
+    fn tray_images_need_one_rgba_pixel_per_texel() {
+        assert!(TrayImage::from_rgba(2, 2, vec![0; 16]).is_some());
+        assert!(TrayImage::from_rgba(2, 2, vec![0; 12]).is_none());
+        assert!(TrayImage::from_rgba(u32::MAX, u32::MAX, vec![0; 4]).is_none());
+    }
+}
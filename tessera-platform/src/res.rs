@@ -0,0 +1,13 @@
+//! Generated string bindings for tessera-platform.
+//!
+//! ## Usage
+//!
+//! Call the functions of the generated `strings` module to get labels in the
+//! current locale.
+// The crate ships strings only, so the asset half of the file is unused.
+#[allow(missing_docs, dead_code, unused_imports, clippy::all)]
+mod generated {
+    include!(concat!(env!("OUT_DIR"), "/tessera_assets.rs"));
+}
+
+pub use generated::*;
@@ -0,0 +1,271 @@
+//! File dialogs through the XDG Desktop Portal.
+//!
+//! The portal's `FileChooser` answers on a request object whose path is
+//! derived from our bus name and a token, so the response subscription is set
+//! up before the call to avoid missing a fast reply.
+
+use std::{
+    collections::HashMap,
+    os::unix::ffi::OsStrExt,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use tracing::warn;
+use zbus::{
+    blocking::Connection,
+    proxy,
+    zvariant::{OwnedObjectPath, OwnedValue, Value},
+};
+
+use crate::{
+    file_dialog::{
+        FileDialogBackend, FileDialogKind, FileDialogRequest, FileDialogResponder,
+        FileDialogResponse, FileFilter, PickedFile, parent_window,
+    },
+    res::strings,
+};
+
+/// Shows dialogs with `org.freedesktop.portal.FileChooser` on a worker
+/// thread.
+pub(crate) struct PortalFileDialogBackend;
+
+impl FileDialogBackend for PortalFileDialogBackend {
+    fn show(&self, request: FileDialogRequest, responder: FileDialogResponder) {
+        let parent_window = parent_window().unwrap_or_default();
+        let spawned = std::thread::Builder::new()
+            .name("tessera-file-dialog".to_string())
+            .spawn(move || {
+                let response = run_file_chooser(&request, &parent_window)
+                    .unwrap_or_else(|err| FileDialogResponse::Failed(err.to_string()));
+                responder.respond(response);
+            });
+        // A failed spawn drops the responder, which cancels the dialog.
+        if let Err(err) = spawned {
+            warn!("Failed to start the file dialog thread: {err}");
+        }
+    }
+}
+
+fn run_file_chooser(
+    request: &FileDialogRequest,
+    parent_window: &str,
+) -> zbus::Result<FileDialogResponse> {
+    static NEXT_TOKEN: AtomicU64 = AtomicU64::new(0);
+
+    let connection = Connection::session()?;
+    let token = format!("tessera{}", NEXT_TOKEN.fetch_add(1, Ordering::Relaxed));
+    let sender = connection
+        .unique_name()
+        .ok_or_else(|| zbus::Error::Failure("connection has no unique name".to_string()))?
+        .as_str()
+        .trim_start_matches(':')
+        .replace('.', "_");
+    let handle = format!("/org/freedesktop/portal/desktop/request/{sender}/{token}");
+    let mut responses = RequestProxyBlocking::builder(&connection)
+        .path(handle)?
+        .build()?
+        .receive_response()?;
+
+    let chooser = FileChooserProxyBlocking::new(&connection)?;
+    let title = request
+        .title
+        .clone()
+        .unwrap_or_else(|| default_title(request.kind));
+    let options = chooser_options(request, &token);
+    match request.kind {
+        FileDialogKind::SaveFile => chooser.save_file(parent_window, &title, options)?,
+        FileDialogKind::OpenFile | FileDialogKind::PickFolder => {
+            chooser.open_file(parent_window, &title, options)?
+        }
+    };
+
+    let Some(response) = responses.next() else {
+        return Ok(FileDialogResponse::Cancelled);
+    };
+    let args = response.args()?;
+    Ok(match *args.response() {
+        0 => {
+            let uris = args
+                .results()
+                .get("uris")
+                .and_then(|uris| uris.try_clone().ok())
+                .and_then(|uris| Vec::<String>::try_from(uris).ok())
+                .unwrap_or_default();
+            FileDialogResponse::Selected(
+                uris.iter()
+                    .filter_map(|uri| file_uri_to_path(uri))
+                    .map(PickedFile::from_path)
+                    .collect(),
+            )
+        }
+        1 => FileDialogResponse::Cancelled,
+        _ => FileDialogResponse::Failed("the file dialog closed unexpectedly".to_string()),
+    })
+}
+
+fn default_title(kind: FileDialogKind) -> String {
+    match kind {
+        FileDialogKind::OpenFile => strings::file_dialog_open_file(),
+        FileDialogKind::SaveFile => strings::file_dialog_save_file(),
+        FileDialogKind::PickFolder => strings::file_dialog_pick_folder(),
+    }
+}
+
+fn chooser_options(
+    request: &FileDialogRequest,
+    token: &str,
+) -> HashMap<&'static str, Value<'static>> {
+    let mut options = HashMap::new();
+    options.insert("handle_token", Value::from(token.to_string()));
+    if let Some(label) = &request.accept_label {
+        options.insert("accept_label", Value::from(label.clone()));
+    }
+    if request.kind != FileDialogKind::SaveFile {
+        options.insert("multiple", Value::from(request.multiple));
+        options.insert(
+            "directory",
+            Value::from(request.kind == FileDialogKind::PickFolder),
+        );
+    }
+    if !request.filters.is_empty() {
+        let filters: Vec<_> = request.filters.iter().map(portal_filter).collect();
+        options.insert("current_filter", Value::from(filters[0].clone()));
+        options.insert("filters", Value::from(filters));
+    }
+    if let Some(path) = &request.default_path {
+        let (folder, file) = if path.is_dir() {
+            (Some(path.as_path()), None)
+        } else {
+            (path.parent(), path.file_name())
+        };
+        if let Some(folder) = folder.filter(|folder| !folder.as_os_str().is_empty()) {
+            options.insert("current_folder", Value::from(path_bytes(folder)));
+        }
+        if request.kind == FileDialogKind::SaveFile
+            && let Some(file) = file
+        {
+            options.insert(
+                "current_name",
+                Value::from(file.to_string_lossy().into_owned()),
+            );
+        }
+    }
+    options
+}
+
+/// Converts a filter to the portal's `(sa(us))` form; kind 0 is a glob and 1
+/// a MIME type.
+fn portal_filter(filter: &FileFilter) -> (String, Vec<(u32, String)>) {
+    let globs = filter.patterns.iter().map(|pattern| (0, pattern.clone()));
+    let mime_types = filter.mime_types.iter().map(|mime| (1, mime.clone()));
+    (filter.name.clone(), globs.chain(mime_types).collect())
+}
+
+/// Returns the NUL-terminated bytes the portal expects for paths.
+fn path_bytes(path: &Path) -> Vec<u8> {
+    let mut bytes = path.as_os_str().as_bytes().to_vec();
+    bytes.push(0);
+    bytes
+}
+
+/// Decodes a `file://` URI; other schemes have no local path.
+fn file_uri_to_path(uri: &str) -> Option<PathBuf> {
+    let path = uri.strip_prefix("file://")?;
+    // Skip an authority such as `localhost`.
+    let path = &path[path.find('/')?..];
+    let mut bytes = Vec::with_capacity(path.len());
+    let mut rest = path.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%'
+            && let Some(hex) = tail.get(..2)
+            && let Ok(hex) = std::str::from_utf8(hex)
+            && let Ok(decoded) = u8::from_str_radix(hex, 16)
+        {
+            bytes.push(decoded);
+            rest = &tail[2..];
+            continue;
+        }
+        bytes.push(byte);
+        rest = tail;
+    }
+    Some(PathBuf::from(std::ffi::OsStr::from_bytes(&bytes)))
+}
+
+#[proxy(
+    interface = "org.freedesktop.portal.FileChooser",
+    default_service = "org.freedesktop.portal.Desktop",
+    default_path = "/org/freedesktop/portal/desktop"
+)]
+trait FileChooser {
+    fn open_file(
+        &self,
+        parent_window: &str,
+        title: &str,
+        options: HashMap<&str, Value<'_>>,
+    ) -> zbus::Result<OwnedObjectPath>;
+
+    fn save_file(
+        &self,
+        parent_window: &str,
+        title: &str,
+        options: HashMap<&str, Value<'_>>,
+    ) -> zbus::Result<OwnedObjectPath>;
+}
+
+#[proxy(
+    interface = "org.freedesktop.portal.Request",
+    default_service = "org.freedesktop.portal.Desktop"
+)]
+trait Request {
+    #[zbus(signal)]
+    fn response(&self, response: u32, results: HashMap<String, OwnedValue>) -> zbus::Result<()>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_uris_decode_to_paths() {
+        assert_eq!(
+            file_uri_to_path("file:///home/me/My%20Notes/%C3%A9t%C3%A9.txt"),
+            Some(PathBuf::from("/home/me/My Notes/été.txt"))
+        );
+        assert_eq!(
+            file_uri_to_path("file://localhost/tmp/a%2"),
+            Some(PathBuf::from("/tmp/a%2"))
+        );
+        assert_eq!(file_uri_to_path("https://example.com/a.txt"), None);
+    }
+
+    #[test]
+    fn save_options_split_the_default_path() {
+        let request = FileDialogRequest {
+            kind: FileDialogKind::SaveFile,
+            title: None,
+            accept_label: None,
+            filters: vec![
+                FileFilter::new("Text")
+                    .extension("txt")
+                    .mime_type("text/plain"),
+            ],
+            multiple: false,
+            default_path: Some(PathBuf::from("/nonexistent-dir/notes.txt")),
+        };
+        let options = chooser_options(&request, "tessera0");
+        assert!(!options.contains_key("multiple"));
+        assert_eq!(options.get("current_name"), Some(&Value::from("notes.txt")));
+        assert_eq!(
+            options.get("current_folder"),
+            Some(&Value::from(b"/nonexistent-dir\0".to_vec()))
+        );
+        assert_eq!(
+            portal_filter(&request.filters[0]),
+            (
+                "Text".to_string(),
+                vec![(0, "*.txt".to_string()), (1, "text/plain".to_string())]
+            )
+        );
+    }
+}
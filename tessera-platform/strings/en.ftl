@@ -0,0 +1,7 @@
+# Built-in labels for tessera-platform.
+
+## File dialog
+
+file-dialog-open-file = Open File
+file-dialog-save-file = Save File
+file-dialog-pick-folder = Select Folder
@@ -0,0 +1,7 @@
+# tessera-platform 内置文本。
+
+## File dialog
+
+file-dialog-open-file = 打开文件
+file-dialog-save-file = 保存文件
+file-dialog-pick-folder = 选择文件夹
@@ -13,9 +13,11 @@
 
 mod tree_builder;
 
-use accesskit::{Action, NodeId as AccessKitNodeId, Role, Toggled};
+use std::hash::{Hash, Hasher};
 
-use crate::Px;
+use accesskit::{Action, ActionData, CustomAction, NodeId as AccessKitNodeId, Role, Toggled};
+
+use crate::{Px, PxRect};
 
 pub(crate) use tree_builder::{build_tree_update, dispatch_action};
 
@@ -64,8 +66,29 @@ impl AccessibilityId {
         let index: usize = node_id.into();
         Self(index as u64)
     }
+
+    /// Returns the ID of the `index`-th text run owned by this node.
+    ///
+    /// Text run IDs set the high bit, so they never collide with component
+    /// node IDs.
+    pub(crate) fn text_run(self, index: usize) -> Self {
+        Self(TEXT_RUN_FLAG | (self.0 << TEXT_RUN_INDEX_BITS) | index as u64)
+    }
+
+    /// Splits a text run ID into its owner ID and run index.
+    pub(crate) fn text_run_owner(self) -> Option<(Self, usize)> {
+        if self.0 & TEXT_RUN_FLAG == 0 {
+            return None;
+        }
+        let id = self.0 & !TEXT_RUN_FLAG;
+        let index = id & ((1 << TEXT_RUN_INDEX_BITS) - 1);
+        Some((Self(id >> TEXT_RUN_INDEX_BITS), index as usize))
+    }
 }
 
+const TEXT_RUN_FLAG: u64 = 1 << 63;
+const TEXT_RUN_INDEX_BITS: u32 = 20;
+
 /// Padding applied to semantic bounds without affecting layout.
 #[derive(Debug, Clone, Copy)]
 pub struct AccessibilityPadding {
@@ -91,6 +114,139 @@ impl AccessibilityPadding {
     }
 }
 
+/// One visual line of laid-out text exposed as an AccessKit text run.
+///
+/// Characters are user-perceived characters (grapheme clusters), so the
+/// per-character vectors all have the same length.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibilityTextRun {
+    /// Text of the run.
+    pub text: String,
+    /// Byte offset of the run within the node's text.
+    pub byte_start: usize,
+    /// Run bounds relative to the node origin.
+    pub bounds: PxRect,
+    /// UTF-8 length of each character in bytes.
+    pub character_lengths: Vec<u8>,
+    /// Start of each character relative to the left edge of `bounds`.
+    pub character_positions: Vec<f32>,
+    /// Advance width of each character.
+    pub character_widths: Vec<f32>,
+    /// Number of characters in each word, including trailing whitespace.
+    pub word_lengths: Vec<u8>,
+}
+
+impl AccessibilityTextRun {
+    /// Returns the byte offset of the `character_index`-th character within
+    /// the node's text.
+    pub fn character_offset(&self, character_index: usize) -> usize {
+        self.byte_start
+            + self
+                .character_lengths
+                .iter()
+                .take(character_index)
+                .map(|&length| length as usize)
+                .sum::<usize>()
+    }
+
+    /// Returns the index of the character starting at or containing
+    /// `offset`, clamped to the run.
+    pub fn character_index(&self, offset: usize) -> usize {
+        let mut position = self.byte_start;
+        for (index, &length) in self.character_lengths.iter().enumerate() {
+            if offset < position + length as usize {
+                return index;
+            }
+            position += length as usize;
+        }
+        self.character_lengths.len()
+    }
+
+    fn byte_end(&self) -> usize {
+        self.byte_start + self.text.len()
+    }
+}
+
+/// Text selection as byte offsets into a node's text.
+///
+/// A collapsed selection marks the caret position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccessibilityTextSelection {
+    /// Offset where the selection started.
+    pub anchor: usize,
+    /// Offset where the selection ends and the caret sits.
+    pub focus: usize,
+}
+
+impl AccessibilityTextSelection {
+    /// Creates a collapsed selection at `offset`.
+    pub fn caret(offset: usize) -> Self {
+        Self {
+            anchor: offset,
+            focus: offset,
+        }
+    }
+}
+
+/// Laid-out text of a node, recorded after layout.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AccessibilityText {
+    /// Visible runs in reading order.
+    pub runs: Vec<AccessibilityTextRun>,
+    /// Current selection, when the text is selectable.
+    pub selection: Option<AccessibilityTextSelection>,
+}
+
+impl AccessibilityText {
+    /// Creates text from laid-out runs without a selection.
+    pub fn new(runs: Vec<AccessibilityTextRun>) -> Self {
+        Self {
+            runs,
+            selection: None,
+        }
+    }
+
+    /// Sets the current selection.
+    pub fn with_selection(mut self, selection: AccessibilityTextSelection) -> Self {
+        self.selection = Some(selection);
+        self
+    }
+
+    /// Returns the run index and character index for a byte offset.
+    ///
+    /// Offsets outside the visible runs clamp to the nearest run edge.
+    pub(crate) fn position(&self, offset: usize) -> Option<(usize, usize)> {
+        let last = self.runs.len().checked_sub(1)?;
+        for (index, run) in self.runs.iter().enumerate() {
+            if offset < run.byte_start {
+                return Some((index, 0));
+            }
+            if offset < run.byte_end() {
+                return Some((index, run.character_index(offset)));
+            }
+            // A caret at the end of a line stays on it unless the next run
+            // continues from the same offset.
+            let continues = self
+                .runs
+                .get(index + 1)
+                .is_some_and(|next| next.byte_start == offset);
+            if offset == run.byte_end() && !continues {
+                return Some((index, run.character_lengths.len()));
+            }
+        }
+        Some((last, self.runs[last].character_lengths.len()))
+    }
+}
+
+/// Text selection or editing request from an assistive technology.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AccessibilityTextAction {
+    /// Moves the selection or caret.
+    SetSelection(AccessibilityTextSelection),
+    /// Replaces the selected text, or inserts at the caret.
+    ReplaceSelectedText(String),
+}
+
 /// Semantic information for an accessibility node.
 ///
 /// This structure contains all the metadata that assistive technologies need
@@ -117,6 +273,10 @@ pub struct AccessibilityNode {
     pub focused: bool,
     /// Toggled/checked state (for checkboxes, switches, radio buttons)
     pub toggled: Option<Toggled>,
+    /// Expanded state (for combo boxes, tree items, disclosure buttons)
+    pub expanded: Option<bool>,
+    /// Selected state (for list box options, tabs, grid cells)
+    pub selected: Option<bool>,
     /// Whether this node is disabled
     pub disabled: bool,
     /// Whether this node is hidden from accessibility
@@ -125,6 +285,12 @@ pub struct AccessibilityNode {
     pub actions: Vec<Action>,
     /// Custom accessibility key provided by the component
     pub key: Option<String>,
+    /// Keys of the nodes this node controls, such as the list box a combo
+    /// box opens
+    pub controls: Vec<String>,
+    /// Key of the descendant or controlled node that is active while this
+    /// node keeps focus, such as the highlighted option of a combo box
+    pub active_descendant: Option<String>,
     /// Whether to merge child semantics into this node. When false, child
     /// semantics are ignored, similar to Compose's `clearAndSetSemantics`.
     pub merge_descendants: bool,
@@ -155,6 +321,8 @@ pub struct AccessibilityNode {
     /// Optional collection item info: row_index, row_span, col_index, col_span,
     /// heading.
     pub collection_item_info: Option<(usize, usize, usize, usize, bool)>,
+    /// Optional 1-based depth of an item in a hierarchical collection.
+    pub collection_item_level: Option<usize>,
     /// Optional editable text flag.
     pub is_editable_text: bool,
 }
@@ -220,6 +388,18 @@ impl AccessibilityNode {
         self
     }
 
+    /// Sets the expanded state of this node.
+    pub fn with_expanded(mut self, expanded: bool) -> Self {
+        self.expanded = Some(expanded);
+        self
+    }
+
+    /// Sets the selected state of this node.
+    pub fn with_selected(mut self, selected: bool) -> Self {
+        self.selected = Some(selected);
+        self
+    }
+
     /// Marks this node as disabled.
     pub fn disabled(mut self) -> Self {
         self.disabled = true;
@@ -249,6 +429,18 @@ impl AccessibilityNode {
         self.key = Some(key.into());
         self
     }
+
+    /// Adds the key of a node this node controls.
+    pub fn with_controls(mut self, key: impl Into<String>) -> Self {
+        self.controls.push(key.into());
+        self
+    }
+
+    /// Sets the key of the active descendant.
+    pub fn with_active_descendant(mut self, key: impl Into<String>) -> Self {
+        self.active_descendant = Some(key.into());
+        self
+    }
 }
 
 impl Default for AccessibilityNode {
@@ -264,10 +456,14 @@ impl Default for AccessibilityNode {
             focusable: false,
             focused: false,
             toggled: None,
+            expanded: None,
+            selected: None,
             disabled: false,
             hidden: false,
             actions: Vec::new(),
             key: None,
+            controls: Vec::new(),
+            active_descendant: None,
             merge_descendants: true,
             bounds_padding: None,
             state_description: None,
@@ -281,6 +477,7 @@ impl Default for AccessibilityNode {
             numeric_value_jump: None,
             collection_info: None,
             collection_item_info: None,
+            collection_item_level: None,
             is_editable_text: false,
         }
     }
@@ -291,3 +488,281 @@ impl Default for AccessibilityNode {
 /// When an assistive technology requests an action (like clicking a button),
 /// this handler is invoked.
 pub type AccessibilityActionHandler = Box<dyn Fn(Action) + Send + Sync>;
+
+/// Handler for text selection and editing actions.
+///
+/// Offsets refer to the text recorded with
+/// [`RenderMetadataMut::set_accessibility_text`](crate::layout::RenderMetadataMut::set_accessibility_text).
+pub type AccessibilityTextActionHandler = Box<dyn Fn(AccessibilityTextAction) + Send + Sync>;
+
+/// Value requested by a `SetValue` action.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AccessibilityValue {
+    /// Text value, for example from a combo box or spin button.
+    Text(String),
+    /// Numeric value, for example from a slider.
+    Numeric(f64),
+}
+
+type ActionFn = Box<dyn Fn() + Send + Sync>;
+
+/// Typed handlers for standard and custom accessibility actions.
+///
+/// Each registered handler also advertises its action on the node, so
+/// components do not need to list these actions in
+/// [`AccessibilityNode::actions`].
+#[derive(Default)]
+pub struct AccessibilityActionHandlers {
+    standard: Vec<(Action, ActionFn)>,
+    set_value: Option<Box<dyn Fn(AccessibilityValue) + Send + Sync>>,
+    custom: Vec<(i32, String, ActionFn)>,
+}
+
+impl AccessibilityActionHandlers {
+    /// Handles `action` with `handler`, replacing any earlier handler for it.
+    ///
+    /// Intended for actions without data such as `Increment`, `Decrement`,
+    /// `Expand`, `Collapse`, `ScrollIntoView`, and the scroll directions.
+    pub fn on_action<F>(&mut self, action: Action, handler: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.standard.retain(|(existing, _)| *existing != action);
+        self.standard.push((action, Box::new(handler)));
+    }
+
+    /// Handles `Increment`.
+    pub fn on_increment<F>(&mut self, handler: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.on_action(Action::Increment, handler);
+    }
+
+    /// Handles `Decrement`.
+    pub fn on_decrement<F>(&mut self, handler: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.on_action(Action::Decrement, handler);
+    }
+
+    /// Handles `Expand`.
+    pub fn on_expand<F>(&mut self, handler: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.on_action(Action::Expand, handler);
+    }
+
+    /// Handles `Collapse`.
+    pub fn on_collapse<F>(&mut self, handler: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.on_action(Action::Collapse, handler);
+    }
+
+    /// Handles `ScrollIntoView`.
+    pub fn on_scroll_into_view<F>(&mut self, handler: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.on_action(Action::ScrollIntoView, handler);
+    }
+
+    /// Handles `SetValue` with the requested value.
+    pub fn on_set_value<F>(&mut self, handler: F)
+    where
+        F: Fn(AccessibilityValue) + Send + Sync + 'static,
+    {
+        self.set_value = Some(Box::new(handler));
+    }
+
+    /// Adds a named action such as "Archive" or "Mark as read".
+    ///
+    /// The action id is derived from `label`, so it does not change when
+    /// modifiers are combined. Adding the same label again replaces its
+    /// handler. Custom actions are listed in the order they are first added.
+    pub fn add_custom_action<F>(&mut self, label: impl Into<String>, handler: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        let label = label.into();
+        let handler: ActionFn = Box::new(handler);
+        if let Some(entry) = self
+            .custom
+            .iter_mut()
+            .find(|(_, existing, _)| *existing == label)
+        {
+            entry.2 = handler;
+            return;
+        }
+
+        let mut id = custom_action_id(&label);
+        while self.custom.iter().any(|(existing, _, _)| *existing == id) {
+            id = id.wrapping_add(1) & i32::MAX;
+        }
+        self.custom.push((id, label, handler));
+    }
+
+    /// Returns `true` when no handler is registered.
+    pub fn is_empty(&self) -> bool {
+        self.standard.is_empty() && self.set_value.is_none() && self.custom.is_empty()
+    }
+
+    /// Returns the actions advertised by the registered handlers.
+    pub(crate) fn actions(&self) -> impl Iterator<Item = Action> + '_ {
+        self.standard
+            .iter()
+            .map(|(action, _)| *action)
+            .chain(self.set_value.as_ref().map(|_| Action::SetValue))
+            .chain((!self.custom.is_empty()).then_some(Action::CustomAction))
+    }
+
+    /// Returns the AccessKit descriptions of the custom actions.
+    pub(crate) fn custom_actions(&self) -> Vec<CustomAction> {
+        self.custom
+            .iter()
+            .map(|(id, label, _)| CustomAction {
+                id: *id,
+                description: label.as_str().into(),
+            })
+            .collect()
+    }
+
+    /// Runs the handler for `action`, returning `false` when none applies.
+    pub(crate) fn dispatch(&self, action: Action, data: Option<&ActionData>) -> bool {
+        match (action, data) {
+            (Action::CustomAction, Some(ActionData::CustomAction(id))) => {
+                let Some((_, _, handler)) =
+                    self.custom.iter().find(|(existing, _, _)| existing == id)
+                else {
+                    return false;
+                };
+                handler();
+                true
+            }
+            (Action::SetValue, Some(data)) => {
+                let (Some(handler), Some(value)) = (&self.set_value, set_value(data)) else {
+                    return false;
+                };
+                handler(value);
+                true
+            }
+            _ => {
+                let Some((_, handler)) = self
+                    .standard
+                    .iter()
+                    .find(|(existing, _)| *existing == action)
+                else {
+                    return false;
+                };
+                handler();
+                true
+            }
+        }
+    }
+}
+
+/// Hashes `label` into a non-negative custom action id.
+fn custom_action_id(label: &str) -> i32 {
+    let mut hasher = rustc_hash::FxHasher::default();
+    label.hash(&mut hasher);
+    (hasher.finish() & i32::MAX as u64) as i32
+}
+
+fn set_value(data: &ActionData) -> Option<AccessibilityValue> {
+    match data {
+        ActionData::Value(value) => Some(AccessibilityValue::Text(value.to_string())),
+        ActionData::NumericValue(value) => Some(AccessibilityValue::Numeric(*value)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use super::*;
+
+    #[test]
+    fn handlers_dispatch_by_action_and_data() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut handlers = AccessibilityActionHandlers::default();
+        let increments = calls.clone();
+        handlers.on_increment(move || {
+            increments.fetch_add(1, Ordering::Relaxed);
+        });
+        let archived = calls.clone();
+        handlers.add_custom_action("Archive", move || {
+            archived.fetch_add(10, Ordering::Relaxed);
+        });
+        handlers.on_set_value(|value| assert_eq!(value, AccessibilityValue::Numeric(0.5)));
+
+        let archive = handlers.custom_actions()[0].id;
+
+        assert!(handlers.dispatch(Action::Increment, None));
+        assert!(handlers.dispatch(
+            Action::CustomAction,
+            Some(&ActionData::CustomAction(archive))
+        ));
+        assert!(!handlers.dispatch(
+            Action::CustomAction,
+            Some(&ActionData::CustomAction(archive.wrapping_add(1)))
+        ));
+        assert!(handlers.dispatch(Action::SetValue, Some(&ActionData::NumericValue(0.5))));
+        assert!(!handlers.dispatch(Action::SetValue, None));
+        assert!(!handlers.dispatch(Action::Decrement, None));
+        assert_eq!(calls.load(Ordering::Relaxed), 11);
+    }
+
+    #[test]
+    fn handlers_advertise_their_actions() {
+        let mut handlers = AccessibilityActionHandlers::default();
+        assert!(handlers.is_empty());
+        handlers.on_expand(|| {});
+        handlers.on_expand(|| {});
+        handlers.add_custom_action("Mark as read", || {});
+        let actions: Vec<_> = handlers.actions().collect();
+        assert_eq!(actions, vec![Action::Expand, Action::CustomAction]);
+        let custom = handlers.custom_actions();
+        assert_eq!(custom.len(), 1);
+        assert_eq!(&*custom[0].description, "Mark as read");
+    }
+
+    #[test]
+    fn custom_action_ids_do_not_depend_on_order() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut archive_first = AccessibilityActionHandlers::default();
+        archive_first.add_custom_action("Archive", || {});
+        archive_first.add_custom_action("Delete", || {});
+        let mut delete_first = AccessibilityActionHandlers::default();
+        delete_first.add_custom_action("Delete", || {});
+        let replaced = calls.clone();
+        delete_first.add_custom_action("Archive", || {});
+        delete_first.add_custom_action("Archive", move || {
+            replaced.fetch_add(1, Ordering::Relaxed);
+        });
+
+        let id = |handlers: &AccessibilityActionHandlers, label: &str| {
+            handlers
+                .custom_actions()
+                .into_iter()
+                .find(|action| &*action.description == label)
+                .map(|action| action.id)
+                .expect("custom action should be advertised")
+        };
+        assert_eq!(delete_first.custom_actions().len(), 2);
+        assert_eq!(id(&archive_first, "Archive"), id(&delete_first, "Archive"));
+        assert_eq!(id(&archive_first, "Delete"), id(&delete_first, "Delete"));
+        assert_ne!(id(&archive_first, "Archive"), id(&archive_first, "Delete"));
+
+        let archive = ActionData::CustomAction(id(&delete_first, "Archive"));
+        assert!(delete_first.dispatch(Action::CustomAction, Some(&archive)));
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
+}
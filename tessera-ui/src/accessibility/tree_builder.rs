@@ -3,11 +3,17 @@
 //! This module contains the logic to build AccessKit TreeUpdates from Tessera's
 //! component tree.
 
-use accesskit::{Node, NodeId as AccessKitNodeId, Rect, Tree, TreeId, TreeUpdate};
+use accesskit::{
+    Node, NodeId as AccessKitNodeId, Rect, TextPosition, TextSelection, Tree, TreeId, TreeUpdate,
+};
 use indextree::NodeId as ComponentNodeId;
+use rustc_hash::FxHashMap as HashMap;
 
 use crate::{
-    accessibility::AccessibilityId,
+    accessibility::{
+        AccessibilityId, AccessibilityText, AccessibilityTextAction, AccessibilityTextRun,
+        AccessibilityTextSelection,
+    },
     component_tree::{ComponentNodeMetaDatas, ComponentNodeTree, ComputedData},
     focus::{FocusOwner, bind_focus_owner},
     px::PxPosition,
@@ -40,6 +46,7 @@ pub fn build_tree_update(
 ) -> Option<TreeUpdate> {
     let mut nodes = Vec::new();
     let mut focus = None;
+    let mut relations = NodeRelations::default();
 
     // Convert root node ID
     let root_accesskit_id = AccessibilityId::from_component_node_id(root_node_id);
@@ -51,6 +58,7 @@ pub fn build_tree_update(
         root_node_id,
         &mut nodes,
         &mut focus,
+        &mut relations,
         true,
         root_label,
     );
@@ -60,6 +68,8 @@ pub fn build_tree_update(
         return None;
     }
 
+    relations.resolve(&mut nodes);
+
     // Create the tree structure
     let tree_struct = Tree::new(root_accesskit_id.to_accesskit_id());
 
@@ -75,12 +85,14 @@ pub fn build_tree_update(
 ///
 /// Returns `true` if this subtree produced at least one accessibility node
 /// (real or synthesized), `false` otherwise.
+#[allow(clippy::too_many_arguments)]
 fn traverse_and_collect(
     tree: &ComponentNodeTree,
     metadatas: &ComponentNodeMetaDatas,
     node_id: ComponentNodeId,
     nodes: &mut Vec<(AccessKitNodeId, Node)>,
     focus: &mut Option<AccessKitNodeId>,
+    relations: &mut NodeRelations,
     is_root: bool,
     root_label: Option<&str>,
 ) -> bool {
@@ -106,8 +118,9 @@ fn traverse_and_collect(
     if merge_descendants {
         for child_id in node_id.children(tree) {
             // Recursively process child
-            let child_has_accessibility =
-                traverse_and_collect(tree, metadatas, child_id, nodes, focus, false, root_label);
+            let child_has_accessibility = traverse_and_collect(
+                tree, metadatas, child_id, nodes, focus, relations, false, root_label,
+            );
 
             has_accessible_descendants |= child_has_accessibility;
 
@@ -211,6 +224,10 @@ fn traverse_and_collect(
             }
         }
 
+        if let Some(level) = accessibility_node.collection_item_level {
+            node.set_level(level);
+        }
+
         if accessibility_node.is_editable_text {
             node.set_live(accesskit::Live::Polite);
         }
@@ -230,6 +247,14 @@ fn traverse_and_collect(
             node.set_toggled(toggled);
         }
 
+        // Set expanded and selected states
+        if let Some(expanded) = accessibility_node.expanded {
+            node.set_expanded(expanded);
+        }
+        if let Some(selected) = accessibility_node.selected {
+            node.set_selected(selected);
+        }
+
         // Set disabled
         if accessibility_node.disabled {
             node.set_disabled();
@@ -245,6 +270,35 @@ fn traverse_and_collect(
             node.add_action(action);
         }
 
+        let handlers = &metadata.accessibility_action_handlers;
+        for action in handlers.actions() {
+            node.add_action(action);
+        }
+        let custom_actions = handlers.custom_actions();
+        if !custom_actions.is_empty() {
+            node.set_custom_actions(custom_actions);
+        }
+
+        if let Some((text, origin)) = node_text(tree, metadatas, node_id) {
+            for (index, run) in text.runs.iter().enumerate() {
+                let run_id = accesskit_id.text_run(index).to_accesskit_id();
+                accessible_children.push(run_id);
+                nodes.push((run_id, text_run_node(run, origin)));
+            }
+            if let Some(selection) = text.selection
+                && let Some(selection) = text_selection(accesskit_id, text, selection)
+            {
+                node.set_text_selection(selection);
+            }
+        }
+
+        if metadata.accessibility_text_action_handler.is_some() {
+            node.add_action(accesskit::Action::SetTextSelection);
+            if accessibility_node.is_editable_text {
+                node.add_action(accesskit::Action::ReplaceSelectedText);
+            }
+        }
+
         // Set children if any
         if !accessible_children.is_empty() {
             node.set_children(accessible_children);
@@ -259,6 +313,22 @@ fn traverse_and_collect(
             node.set_bounds(bounds);
         }
 
+        // Relations name other nodes by key and are resolved once every
+        // node is collected
+        if let Some(key) = accessibility_node.key {
+            relations
+                .ids_by_key
+                .insert(key, accesskit_id.to_accesskit_id());
+        }
+        if !accessibility_node.controls.is_empty() || accessibility_node.active_descendant.is_some()
+        {
+            relations.pending.push(PendingRelation {
+                index: nodes.len(),
+                controls: accessibility_node.controls,
+                active_descendant: accessibility_node.active_descendant,
+            });
+        }
+
         // Add to collection
         nodes.push((accesskit_id.to_accesskit_id(), node));
 
@@ -290,6 +360,123 @@ fn traverse_and_collect(
     }
 }
 
+/// Keyed nodes and the relations that refer to them.
+#[derive(Default)]
+struct NodeRelations {
+    ids_by_key: HashMap<String, AccessKitNodeId>,
+    pending: Vec<PendingRelation>,
+}
+
+/// Relations of the node at `index` in the collected nodes.
+struct PendingRelation {
+    index: usize,
+    controls: Vec<String>,
+    active_descendant: Option<String>,
+}
+
+impl NodeRelations {
+    /// Sets the relations on their nodes, skipping keys that name no node in
+    /// this tree, such as items of a list that are not composed.
+    fn resolve(self, nodes: &mut [(AccessKitNodeId, Node)]) {
+        for relation in self.pending {
+            let Some((_, node)) = nodes.get_mut(relation.index) else {
+                continue;
+            };
+            let controls: Vec<_> = relation
+                .controls
+                .iter()
+                .filter_map(|key| self.ids_by_key.get(key).copied())
+                .collect();
+            if !controls.is_empty() {
+                node.set_controls(controls);
+            }
+            if let Some(id) = relation
+                .active_descendant
+                .as_ref()
+                .and_then(|key| self.ids_by_key.get(key))
+            {
+                node.set_active_descendant(*id);
+            }
+        }
+    }
+}
+
+/// Finds the text exposed by a semantic node.
+///
+/// Text recorded on the node itself wins; otherwise the first text recorded
+/// by a descendant without its own semantics is used, since components often
+/// attach semantics to a wrapper around the node that lays out the text.
+fn node_text<'a>(
+    tree: &ComponentNodeTree,
+    metadatas: &'a ComponentNodeMetaDatas,
+    node_id: ComponentNodeId,
+) -> Option<(&'a AccessibilityText, Option<PxPosition>)> {
+    let metadata = metadatas.get(&node_id)?;
+    if let Some(text) = &metadata.accessibility_text {
+        return Some((text, metadata.abs_position));
+    }
+    node_id.children(tree).find_map(|child_id| {
+        let child = metadatas.get(&child_id)?;
+        if child.accessibility.is_some() {
+            return None;
+        }
+        node_text(tree, metadatas, child_id)
+    })
+}
+
+fn text_run_node(run: &AccessibilityTextRun, origin: Option<PxPosition>) -> Node {
+    let mut node = Node::new(accesskit::Role::TextRun);
+    node.set_value(run.text.clone());
+    node.set_character_lengths(run.character_lengths.clone());
+    node.set_character_positions(run.character_positions.clone());
+    node.set_character_widths(run.character_widths.clone());
+    node.set_word_lengths(run.word_lengths.clone());
+    if let Some(origin) = origin {
+        let x0 = (origin.x + run.bounds.x).0 as f64;
+        let y0 = (origin.y + run.bounds.y).0 as f64;
+        node.set_bounds(Rect {
+            x0,
+            y0,
+            x1: x0 + run.bounds.width.0 as f64,
+            y1: y0 + run.bounds.height.0 as f64,
+        });
+    }
+    node
+}
+
+fn text_selection(
+    owner: AccessibilityId,
+    text: &AccessibilityText,
+    selection: AccessibilityTextSelection,
+) -> Option<TextSelection> {
+    let position = |offset| {
+        let (run, character_index) = text.position(offset)?;
+        Some(TextPosition {
+            node: owner.text_run(run).to_accesskit_id(),
+            character_index,
+        })
+    };
+    Some(TextSelection {
+        anchor: position(selection.anchor)?,
+        focus: position(selection.focus)?,
+    })
+}
+
+/// Converts an AccessKit text position back to a byte offset.
+fn text_offset(
+    owner: AccessibilityId,
+    text: &AccessibilityText,
+    position: TextPosition,
+) -> Option<usize> {
+    let (run_owner, run) = AccessibilityId::from_accesskit_id(position.node).text_run_owner()?;
+    if run_owner != owner {
+        return None;
+    }
+    let run = text.runs.get(run)?;
+    let character_index = position.character_index.min(run.character_lengths.len());
+    Some(run.character_offset(character_index))
+}
+
 fn rect_from_geometry(
     abs_position: Option<PxPosition>,
     computed_data: Option<ComputedData>,
@@ -326,7 +513,7 @@ fn rect_from_geometry_with_padding(
 /// This function:
 /// 1. Converts the AccessKit NodeId back to a component NodeId
 /// 2. Looks up the component's metadata
-/// 3. Calls the component's accessibility_action_handler if present
+/// 3. Calls the matching text, typed, or generic handler if present
 ///
 /// # Arguments
 ///
@@ -348,13 +535,41 @@ pub fn dispatch_action(
         return false;
     }
 
-    let accessibility_id = AccessibilityId::from_accesskit_id(action_request.target_node);
+    let mut accessibility_id = AccessibilityId::from_accesskit_id(action_request.target_node);
+    // Actions on text runs go to the node that owns them.
+    if let Some((owner, _)) = accessibility_id.text_run_owner() {
+        accessibility_id = owner;
+    }
 
     // Convert to component NodeId using get_node_id_at
     // The AccessibilityId stores the 1-based index from indextree
     let index = std::num::NonZero::new(accessibility_id.0 as usize);
     let component_node_id = index.and_then(|idx| tree.get_node_id_at(idx));
 
+    if let Some(node_id) = component_node_id
+        && let Some(action) =
+            text_action(tree, metadatas, node_id, accessibility_id, &action_request)
+        && let Some(handler) = metadatas
+            .get(&node_id)
+            .and_then(|metadata| metadata.accessibility_text_action_handler.as_ref())
+    {
+        let _focus_owner_guard = bind_focus_owner(focus_owner);
+        handler(action);
+        return true;
+    }
+
+    if let Some(node_id) = component_node_id
+        && let Some(metadata) = metadatas.get(&node_id)
+    {
+        let _focus_owner_guard = bind_focus_owner(focus_owner);
+        if metadata
+            .accessibility_action_handlers
+            .dispatch(action_request.action, action_request.data.as_ref())
+        {
+            return true;
+        }
+    }
+
     // Look up the component's metadata and call handler
     if let Some(node_id) = component_node_id
         && let Some(metadata) = metadatas.get(&node_id)
@@ -368,3 +583,119 @@ pub fn dispatch_action(
 
     false
 }
+
+/// Decodes a text selection or editing request for a node.
+fn text_action(
+    tree: &ComponentNodeTree,
+    metadatas: &ComponentNodeMetaDatas,
+    node_id: ComponentNodeId,
+    owner: AccessibilityId,
+    action_request: &accesskit::ActionRequest,
+) -> Option<AccessibilityTextAction> {
+    match (action_request.action, action_request.data.as_ref()?) {
+        (
+            accesskit::Action::SetTextSelection,
+            accesskit::ActionData::SetTextSelection(selection),
+        ) => {
+            let (text, _) = node_text(tree, metadatas, node_id)?;
+            Some(AccessibilityTextAction::SetSelection(
+                AccessibilityTextSelection {
+                    anchor: text_offset(owner, text, selection.anchor)?,
+                    focus: text_offset(owner, text, selection.focus)?,
+                },
+            ))
+        }
+        (accesskit::Action::ReplaceSelectedText, accesskit::ActionData::Value(value)) => Some(
+            AccessibilityTextAction::ReplaceSelectedText(value.to_string()),
+        ),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PxRect;
+
+    fn run(text: &str, byte_start: usize) -> AccessibilityTextRun {
+        let count = text.chars().count();
+        AccessibilityTextRun {
+            text: text.to_string(),
+            byte_start,
+            bounds: PxRect::ZERO,
+            character_lengths: text.chars().map(|c| c.len_utf8() as u8).collect(),
+            character_positions: (0..count).map(|i| i as f32 * 10.0).collect(),
+            character_widths: vec![10.0; count],
+            word_lengths: vec![count as u8],
+        }
+    }
+
+    #[test]
+    fn text_run_ids_round_trip() {
+        let owner = AccessibilityId(42);
+        let id = owner.text_run(3);
+        assert_eq!(id.text_run_owner(), Some((owner, 3)));
+        assert_eq!(owner.text_run_owner(), None);
+    }
+
+    #[test]
+    fn selection_round_trips_through_text_positions() {
+        let owner = AccessibilityId(7);
+        let text = AccessibilityText::new(vec![run("héllo ", 0), run("world", 7)]);
+        let selection = AccessibilityTextSelection {
+            anchor: 3,
+            focus: 9,
+        };
+        let converted = text_selection(owner, &text, selection).unwrap();
+        assert_eq!(converted.anchor.node, owner.text_run(0).to_accesskit_id());
+        assert_eq!(converted.anchor.character_index, 2);
+        assert_eq!(converted.focus.node, owner.text_run(1).to_accesskit_id());
+        assert_eq!(converted.focus.character_index, 2);
+        assert_eq!(text_offset(owner, &text, converted.anchor), Some(3));
+        assert_eq!(text_offset(owner, &text, converted.focus), Some(9));
+        assert_eq!(
+            text_offset(AccessibilityId(8), &text, converted.focus),
+            None
+        );
+    }
+
+    #[test]
+    fn relations_resolve_keys_and_skip_unknown_ones() {
+        let combo_box = AccessKitNodeId(1);
+        let list_box = AccessKitNodeId(2);
+        let option = AccessKitNodeId(3);
+        let mut nodes = vec![
+            (combo_box, Node::new(accesskit::Role::ComboBox)),
+            (list_box, Node::new(accesskit::Role::ListBox)),
+            (option, Node::new(accesskit::Role::ListBoxOption)),
+        ];
+        let mut relations = NodeRelations::default();
+        relations.ids_by_key.insert("list".to_string(), list_box);
+        relations.ids_by_key.insert("option-4".to_string(), option);
+        relations.pending.push(PendingRelation {
+            index: 0,
+            controls: vec!["list".to_string(), "missing".to_string()],
+            active_descendant: Some("option-4".to_string()),
+        });
+        relations.pending.push(PendingRelation {
+            index: 1,
+            controls: Vec::new(),
+            active_descendant: Some("option-9".to_string()),
+        });
+
+        relations.resolve(&mut nodes);
+        assert_eq!(nodes[0].1.controls(), [list_box]);
+        assert_eq!(nodes[0].1.active_descendant(), Some(option));
+        assert_eq!(nodes[1].1.active_descendant(), None);
+    }
+
+    #[test]
+    fn positions_clamp_to_run_edges() {
+        let text = AccessibilityText::new(vec![run("ab", 0), run("cd", 2)]);
+        // A wrapped line continues at the same offset, so the caret moves on.
+        assert_eq!(text.position(2), Some((1, 0)));
+        assert_eq!(text.position(4), Some((1, 2)));
+        assert_eq!(text.position(100), Some((1, 2)));
+        assert_eq!(AccessibilityText::default().position(0), None);
+    }
+}
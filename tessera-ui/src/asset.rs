@@ -3,14 +3,30 @@
 //! ## Usage
 //!
 //! Use generated asset constants and call [`AssetExt::read`] to load raw bytes.
+//!
+//! Assets declared with resolution or theme qualifiers in `tessera-config.toml`
+//! resolve to the variant that best matches the current window scale factor
+//! and the [`current_asset_theme`].
+//!
+//! Provide an [`AssetTheme`] context to pick theme variants for a subtree.
+//! Components that resolve asset variants during build subscribe to it, so
+//! calling [`Context::set`](crate::Context::set) with another theme rebuilds
+//! exactly those components.
 
 use std::{
     any::TypeId,
     collections::HashMap,
+    hash::{Hash, Hasher},
     io,
     sync::{Arc, OnceLock, RwLock, RwLockWriteGuard},
 };
 
+use crate::{
+    context::use_context,
+    dp::SCALE_FACTOR,
+    runtime::{RuntimePhase, current_phase},
+};
+
 type AssetCacheKey = (TypeId, u64);
 type AssetCacheMap = HashMap<AssetCacheKey, AssetCacheEntry>;
 
@@ -18,6 +34,8 @@ const CACHE_MAX_ENTRIES: usize = 4096;
 const CACHE_MAX_BYTES: usize = 64 * 1024 * 1024;
 
 static ASSET_BYTES_CACHE: OnceLock<RwLock<AssetLruCache>> = OnceLock::new();
+static DEFAULT_ASSET_THEME: parking_lot::RwLock<AssetTheme> =
+    parking_lot::RwLock::new(AssetTheme { name: None });
 
 #[derive(Clone)]
 struct AssetCacheEntry {
@@ -88,6 +106,134 @@ impl AssetLruCache {
 pub trait AssetExt: Copy {
     /// Read raw bytes for this asset.
     fn read(self) -> io::Result<Arc<[u8]>>;
+
+    /// Returns the index of the variant [`AssetExt::read`] currently resolves
+    /// to.
+    ///
+    /// Assets without qualified variants always return `0`. Use this as part
+    /// of memoization keys so decoded content follows scale factor and theme
+    /// changes.
+    fn variant_index(self) -> usize {
+        0
+    }
+}
+
+/// Qualifiers of one asset variant, generated from `tessera-config.toml`.
+#[derive(Clone, Copy, Debug)]
+pub struct AssetQualifiers {
+    /// Scale factor the variant targets, `1.0` for unqualified files.
+    pub scale: f32,
+    /// Theme the variant targets, `None` for unqualified files.
+    pub theme: Option<&'static str>,
+}
+
+impl AssetQualifiers {
+    /// Creates qualifiers for a variant.
+    pub const fn new(scale: f32, theme: Option<&'static str>) -> Self {
+        Self { scale, theme }
+    }
+}
+
+impl PartialEq for AssetQualifiers {
+    fn eq(&self, other: &Self) -> bool {
+        self.scale.to_bits() == other.scale.to_bits() && self.theme == other.theme
+    }
+}
+
+impl Eq for AssetQualifiers {}
+
+impl Hash for AssetQualifiers {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u32(self.scale.to_bits());
+        self.theme.hash(state);
+    }
+}
+
+/// The theme used to resolve theme-qualified asset variants.
+///
+/// Theme names match the values declared under `[assets.variants.theme]`,
+/// for example `"dark"`. The default theme prefers unqualified variants.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AssetTheme {
+    name: Option<Arc<str>>,
+}
+
+impl AssetTheme {
+    /// Creates a theme that prefers variants qualified with `name`.
+    pub fn new(name: impl AsRef<str>) -> Self {
+        Self {
+            name: Some(name.as_ref().into()),
+        }
+    }
+
+    /// Returns the theme name, or `None` for the default theme.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+}
+
+/// Returns the theme used to resolve theme-qualified asset variants.
+///
+/// During component builds this reads the nearest [`AssetTheme`] context and
+/// subscribes the component to it. Elsewhere, or when no context is provided,
+/// the [`default_asset_theme`] is used.
+pub fn current_asset_theme() -> AssetTheme {
+    if current_phase() == Some(RuntimePhase::Build)
+        && let Some(theme) = use_context::<AssetTheme>()
+    {
+        return theme.get();
+    }
+    default_asset_theme()
+}
+
+/// Returns the asset theme used outside an [`AssetTheme`] context.
+pub fn default_asset_theme() -> AssetTheme {
+    DEFAULT_ASSET_THEME.read().clone()
+}
+
+/// Sets the asset theme used outside an [`AssetTheme`] context.
+pub fn set_default_asset_theme(theme: AssetTheme) {
+    *DEFAULT_ASSET_THEME.write() = theme;
+}
+
+/// Picks the variant that best matches the current scale factor and theme.
+///
+/// Variants of the current theme are preferred, then unqualified variants.
+/// Among those, the smallest scale that is not below the current scale factor
+/// wins, falling back to the largest available scale.
+#[doc(hidden)]
+pub fn select_asset_variant(variants: &[AssetQualifiers]) -> usize {
+    if variants.len() <= 1 {
+        return 0;
+    }
+    let scale_factor = SCALE_FACTOR.get().map(|lock| *lock.read()).unwrap_or(1.0) as f32;
+    let theme = current_asset_theme();
+    select_variant(variants, scale_factor, theme.name())
+}
+
+fn select_variant(variants: &[AssetQualifiers], scale_factor: f32, theme: Option<&str>) -> usize {
+    let themed = |wanted: Option<&str>| {
+        variants
+            .iter()
+            .enumerate()
+            .filter(move |(_, variant)| variant.theme == wanted)
+    };
+    let has_theme = theme.is_some_and(|theme| themed(Some(theme)).next().is_some());
+    let wanted_theme = if has_theme { theme } else { None };
+    let candidates = if themed(wanted_theme).next().is_some() {
+        themed(wanted_theme).collect::<Vec<_>>()
+    } else {
+        variants.iter().enumerate().collect()
+    };
+
+    let at_least = candidates
+        .iter()
+        .filter(|(_, variant)| variant.scale >= scale_factor)
+        .min_by(|(_, left), (_, right)| left.scale.total_cmp(&right.scale));
+    let largest = candidates
+        .iter()
+        .max_by(|(_, left), (_, right)| left.scale.total_cmp(&right.scale));
+    at_least.or(largest).map_or(0, |(index, _)| *index)
 }
 
 /// Shared helper for generated asset readers that adds an in-memory LRU cache.
@@ -130,3 +276,31 @@ fn cache_write() -> RwLockWriteGuard<'static, AssetLruCache> {
         Err(poisoned) => poisoned.into_inner(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VARIANTS: &[AssetQualifiers] = &[
+        AssetQualifiers::new(1.0, None),
+        AssetQualifiers::new(2.0, None),
+        AssetQualifiers::new(3.0, None),
+        AssetQualifiers::new(1.0, Some("dark")),
+        AssetQualifiers::new(2.0, Some("dark")),
+    ];
+
+    #[test]
+    fn picks_smallest_scale_covering_the_scale_factor() {
+        assert_eq!(select_variant(VARIANTS, 1.0, None), 0);
+        assert_eq!(select_variant(VARIANTS, 1.5, None), 1);
+        assert_eq!(select_variant(VARIANTS, 2.0, None), 1);
+        assert_eq!(select_variant(VARIANTS, 4.0, None), 2);
+    }
+
+    #[test]
+    fn prefers_the_current_theme_and_falls_back_to_unqualified() {
+        assert_eq!(select_variant(VARIANTS, 1.0, Some("dark")), 3);
+        assert_eq!(select_variant(VARIANTS, 3.0, Some("dark")), 4);
+        assert_eq!(select_variant(VARIANTS, 3.0, Some("sepia")), 2);
+    }
+}
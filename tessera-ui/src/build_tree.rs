@@ -60,9 +60,9 @@ pub(crate) struct BuildTreeResult {
     partial_replay_nodes: Option<u64>,
     #[cfg(feature = "profiling")]
     total_nodes_before_build: Option<u64>,
-    #[cfg(feature = "debug-dirty-overlay")]
+    #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
     had_invalidations: bool,
-    #[cfg(feature = "debug-dirty-overlay")]
+    #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
     dirty_replay_roots: Vec<u64>,
 }
 
@@ -75,9 +75,9 @@ impl BuildTreeResult {
             partial_replay_nodes: None,
             #[cfg(feature = "profiling")]
             total_nodes_before_build: None,
-            #[cfg(feature = "debug-dirty-overlay")]
+            #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
             had_invalidations: false,
-            #[cfg(feature = "debug-dirty-overlay")]
+            #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
             dirty_replay_roots: Vec::new(),
         }
     }
@@ -87,9 +87,9 @@ impl BuildTreeResult {
         Self {
             duration,
             mode: BuildTreeMode::PartialReplay,
-            #[cfg(feature = "debug-dirty-overlay")]
+            #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
             had_invalidations: false,
-            #[cfg(feature = "debug-dirty-overlay")]
+            #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
             dirty_replay_roots: Vec::new(),
         }
     }
@@ -105,9 +105,9 @@ impl BuildTreeResult {
             mode: BuildTreeMode::PartialReplay,
             partial_replay_nodes: Some(partial_replay_nodes),
             total_nodes_before_build: Some(total_nodes_before_build),
-            #[cfg(feature = "debug-dirty-overlay")]
+            #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
             had_invalidations: false,
-            #[cfg(feature = "debug-dirty-overlay")]
+            #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
             dirty_replay_roots: Vec::new(),
         }
     }
@@ -120,14 +120,14 @@ impl BuildTreeResult {
             partial_replay_nodes: None,
             #[cfg(feature = "profiling")]
             total_nodes_before_build: None,
-            #[cfg(feature = "debug-dirty-overlay")]
+            #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
             had_invalidations: false,
-            #[cfg(feature = "debug-dirty-overlay")]
+            #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
             dirty_replay_roots: Vec::new(),
         }
     }
 
-    #[cfg(feature = "debug-dirty-overlay")]
+    #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
     fn with_dirty_replay_info(
         mut self,
         had_invalidations: bool,
@@ -154,19 +154,19 @@ impl BuildTreeResult {
             self.partial_replay_nodes = retry.partial_replay_nodes;
             self.total_nodes_before_build = retry.total_nodes_before_build;
         }
-        #[cfg(feature = "debug-dirty-overlay")]
+        #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
         {
             self.had_invalidations = retry.had_invalidations;
             self.dirty_replay_roots = retry.dirty_replay_roots;
         }
     }
 
-    #[cfg(feature = "debug-dirty-overlay")]
+    #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
     pub(crate) fn had_invalidations(&self) -> bool {
         self.had_invalidations
     }
 
-    #[cfg(feature = "debug-dirty-overlay")]
+    #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
     pub(crate) fn dirty_replay_roots(&self) -> &[u64] {
         &self.dirty_replay_roots
     }
@@ -447,12 +447,12 @@ pub(crate) fn build_component_tree<F: Fn()>(entry_point: &F) -> BuildTreeResult
 
         let tree_is_empty = TesseraRuntime::with(|rt| rt.component_tree.tree().count() == 0);
         let invalidations = take_build_invalidations();
-        #[cfg(feature = "debug-dirty-overlay")]
+        #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
         let had_invalidations = !invalidations.dirty_instance_keys.is_empty();
         with_build_dirty_instance_keys(&invalidations.dirty_instance_keys, || {
             if tree_is_empty {
                 let result = run_root_recompose();
-                #[cfg(feature = "debug-dirty-overlay")]
+                #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
                 let result = result.with_dirty_replay_info(had_invalidations, Vec::new());
                 return result;
             }
@@ -460,7 +460,7 @@ pub(crate) fn build_component_tree<F: Fn()>(entry_point: &F) -> BuildTreeResult
             if invalidations.dirty_instance_keys.is_empty() {
                 debug!("Skipping component tree build: no invalidations");
                 let result = BuildTreeResult::skip_no_invalidation();
-                #[cfg(feature = "debug-dirty-overlay")]
+                #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
                 let result = result.with_dirty_replay_info(false, Vec::new());
                 return result;
             }
@@ -470,14 +470,14 @@ pub(crate) fn build_component_tree<F: Fn()>(entry_point: &F) -> BuildTreeResult
             let initial_dirty_roots = collect_dirty_replay_roots(&initial_live_dirty_instance_keys);
             if dirty_roots_include_tree_root(&initial_dirty_roots) {
                 let result = run_root_recompose();
-                #[cfg(feature = "debug-dirty-overlay")]
+                #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
                 let result = result.with_dirty_replay_info(had_invalidations, Vec::new());
                 return result;
             }
             if initial_dirty_roots.is_empty() {
                 debug!("Skipping component tree build: no dirty replay roots");
                 let result = BuildTreeResult::skip_no_invalidation();
-                #[cfg(feature = "debug-dirty-overlay")]
+                #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
                 let result = result.with_dirty_replay_info(had_invalidations, Vec::new());
                 return result;
             }
@@ -643,7 +643,7 @@ pub(crate) fn build_component_tree<F: Fn()>(entry_point: &F) -> BuildTreeResult
 
             if fallback_to_root_recompose {
                 let result = run_root_recompose();
-                #[cfg(feature = "debug-dirty-overlay")]
+                #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
                 let result = result.with_dirty_replay_info(had_invalidations, Vec::new());
                 return result;
             }
@@ -675,7 +675,7 @@ pub(crate) fn build_component_tree<F: Fn()>(entry_point: &F) -> BuildTreeResult
                     replayed_nodes,
                     total_nodes_before_build,
                 );
-                #[cfg(feature = "debug-dirty-overlay")]
+                #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
                 let result = result
                     .with_dirty_replay_info(had_invalidations, replay_roots_for_debug.clone());
                 result
@@ -683,7 +683,7 @@ pub(crate) fn build_component_tree<F: Fn()>(entry_point: &F) -> BuildTreeResult
             #[cfg(not(feature = "profiling"))]
             {
                 let result = BuildTreeResult::partial_replay(build_tree_cost);
-                #[cfg(feature = "debug-dirty-overlay")]
+                #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
                 let result = result
                     .with_dirty_replay_info(had_invalidations, replay_roots_for_debug.clone());
                 result
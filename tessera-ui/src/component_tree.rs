@@ -21,8 +21,11 @@ use crate::{
     render_graph::{RenderGraph, RenderGraphBuilder},
     runtime::{
         LayoutDirtyNodes, RuntimePhase, StructureReconcileResult, TesseraRuntime,
-        push_current_component_instance_key, push_current_node_with_instance_logic_id, push_phase,
+        has_bounds_observers, has_visibility_observers, push_current_component_instance_key,
+        push_current_node_with_instance_logic_id, push_phase, sync_bounds_observers,
+        sync_visibility_observers,
     },
+    shortcut::{self, KeyChord, SequenceMatch},
     time::Instant,
 };
 
@@ -38,6 +41,10 @@ pub(crate) use node::{
     WindowRequests, direct_layout_children, measure_node,
 };
 
+#[cfg(feature = "inspector")]
+use crate::inspector::{
+    InspectorConstraint, InspectorNode, InspectorSemantics, focus_state_name, node_bounds,
+};
 #[cfg(feature = "profiling")]
 use crate::profiler::{NodeMeta, Phase as ProfilerPhase, ScopeGuard as ProfilerScopeGuard};
 
@@ -74,6 +81,11 @@ impl LayoutSnapshotMap {
     pub(crate) fn get_cloned(&self, instance_key: &u64) -> Option<LayoutSnapshotEntry> {
         self.entries.get(instance_key).cloned()
     }
+
+    #[cfg(feature = "inspector")]
+    pub(crate) fn get(&self, instance_key: &u64) -> Option<&LayoutSnapshotEntry> {
+        self.entries.get(instance_key)
+    }
 }
 
 pub(crate) fn nearest_replay_boundary_instance_key(
@@ -295,6 +307,7 @@ pub(crate) struct ComputeParams<'a> {
     pub retry_focus_reveal: bool,
     pub modifiers: winit::keyboard::ModifiersState,
     pub layout_dirty_nodes: &'a LayoutDirtyNodes,
+    pub window_visible: bool,
 }
 
 #[derive(Debug)]
@@ -323,6 +336,9 @@ pub struct ComponentTree {
     active_pointer_paths: HashMap<u64, Vec<u64>>,
     /// Per-tree focus owner used for keyboard and IME routing.
     focus_owner: FocusOwner,
+    /// Key presses held back while they form the prefix of a shortcut
+    /// sequence.
+    held_key_events: Vec<winit::event::KeyEvent>,
 }
 
 #[derive(Clone, PartialEq)]
@@ -372,6 +388,7 @@ impl ComponentTree {
             replay_reuse_candidates: HashMap::default(),
             active_pointer_paths: HashMap::default(),
             focus_owner: FocusOwner::new(),
+            held_key_events: Vec::new(),
         }
     }
 
@@ -797,6 +814,69 @@ impl ComponentTree {
         nodes
     }
 
+    /// Collect per-node details for the inspector, in pre-order.
+    #[cfg(feature = "inspector")]
+    pub(crate) fn inspector_nodes(&self) -> Vec<InspectorNode> {
+        let Some(root_node) = self
+            .tree
+            .get_node_id_at(NonZero::new(1).expect("root node index must be non-zero"))
+        else {
+            return Vec::new();
+        };
+
+        let mut stack = vec![root_node];
+        let mut nodes = Vec::new();
+        while let Some(node_id) = stack.pop() {
+            let Some(node_ref) = self.tree.get(node_id) else {
+                continue;
+            };
+            let node = node_ref.get();
+            let metadata = self.metadatas.get(&node_id);
+            let computed_data = metadata.and_then(|m| m.computed_data);
+            let abs_position = metadata.and_then(|m| m.abs_position);
+            nodes.push(InspectorNode {
+                id: node_id.to_string(),
+                parent: node_ref.parent().map(|parent| parent.to_string()),
+                fn_name: node.fn_name.clone(),
+                role: match node.role {
+                    NodeRole::Composition => "composition",
+                    NodeRole::Layout => "layout",
+                },
+                instance_key: node.instance_key,
+                constraint: self
+                    .layout_snapshots
+                    .get(&node.instance_key)
+                    .map(|entry| InspectorConstraint::from(entry.constraint_key)),
+                size: computed_data.map(|d| (d.width.0, d.height.0)),
+                rel_pos: metadata
+                    .and_then(|m| m.rel_position)
+                    .map(|p| (p.x.0, p.y.0)),
+                abs_pos: abs_position.map(|p| (p.x.0, p.y.0)),
+                layout_cache_hit: metadata.is_some_and(|m| m.layout_cache_hit),
+                props_unchanged: node.props_unchanged_from_previous,
+                clips_children: metadata.is_some_and(|m| m.clips_children),
+                opacity: metadata.map_or(1.0, |m| m.opacity),
+                modifiers: node.modifier.debug_chain(),
+                semantics: metadata
+                    .and_then(|m| m.accessibility.as_ref())
+                    .map(InspectorSemantics::from),
+                focus: node.focus_registration.as_ref().map(|registration| {
+                    focus_state_name(self.focus_owner.state_of(registration.id))
+                }),
+                bounds: node_bounds(
+                    abs_position,
+                    computed_data,
+                    metadata.and_then(|m| m.event_clip_rect),
+                ),
+            });
+            // Push children in reverse so they are visited in source order.
+            let children: Vec<_> = node_id.children(&self.tree).collect();
+            stack.extend(children.into_iter().rev());
+        }
+
+        nodes
+    }
+
     /// Compute the ComponentTree into a render graph
     ///
     /// This method processes the component tree through three main phases:
@@ -830,6 +910,7 @@ impl ComponentTree {
             retry_focus_reveal,
             modifiers,
             layout_dirty_nodes,
+            window_visible,
         } = params;
         let Some(root_node) = self
             .tree
@@ -902,46 +983,59 @@ impl ComponentTree {
             dirty_expand_ns,
         );
 
-        let (compute_resource_manager, gpu) = match mode {
+        // Headless test runs skip drawing but still dispatch input, so tests
+        // can drive components with pointer and keyboard events.
+        let (graph, record_cost) = match mode {
             ComputeMode::Full {
                 compute_resource_manager,
                 gpu,
-            } => (compute_resource_manager, gpu),
+            } => {
+                let record_timer = Instant::now();
+                record_layout_commands(
+                    root_node,
+                    &self.tree,
+                    &mut self.metadatas,
+                    compute_resource_manager,
+                    gpu,
+                );
+                let record_cost = record_timer.elapsed();
+                populate_layout_metadata(root_node, &self.tree, &mut self.metadatas);
+                sync_node_visibility(
+                    root_node,
+                    &self.tree,
+                    &self.metadatas,
+                    screen_size,
+                    window_visible,
+                );
+                sync_node_bounds(root_node, &self.tree, &self.metadatas);
+
+                let compute_draw_timer = Instant::now();
+                debug!("Start computing render graph...");
+                let graph =
+                    build_render_graph(root_node, &self.tree, &mut self.metadatas, screen_size);
+                debug!(
+                    "Render graph built in {:?}, total ops: {}",
+                    compute_draw_timer.elapsed(),
+                    graph.ops().len()
+                );
+                (graph, record_cost)
+            }
             #[cfg(feature = "testing")]
             ComputeMode::LayoutOnly => {
                 populate_layout_metadata(root_node, &self.tree, &mut self.metadatas);
-                return (
-                    RenderGraph::default(),
-                    WindowRequests::default(),
-                    diagnostics_snapshot,
-                    std::time::Duration::ZERO,
-                    None,
-                    false,
+                sync_node_visibility(
+                    root_node,
+                    &self.tree,
+                    &self.metadatas,
+                    screen_size,
+                    window_visible,
                 );
+                sync_node_bounds(root_node, &self.tree, &self.metadatas);
+                (RenderGraph::default(), std::time::Duration::ZERO)
             }
         };
-
-        let record_timer = Instant::now();
-        record_layout_commands(
-            root_node,
-            &self.tree,
-            &mut self.metadatas,
-            compute_resource_manager,
-            gpu,
-        );
-        let record_cost = record_timer.elapsed();
-        populate_layout_metadata(root_node, &self.tree, &mut self.metadatas);
-
-        let compute_draw_timer = Instant::now();
-        debug!("Start computing render graph...");
-        let graph = build_render_graph(root_node, &self.tree, &mut self.metadatas, screen_size);
         self.focus_owner
             .sync_layout_from_component_tree(root_node, &self.tree, &self.metadatas);
-        debug!(
-            "Render graph built in {:?}, total ops: {}",
-            compute_draw_timer.elapsed(),
-            graph.ops().len()
-        );
 
         let input_dispatch_timer = Instant::now();
         let mut window_requests = WindowRequests::default();
@@ -1101,6 +1195,12 @@ impl ComponentTree {
                 focus_owner: &mut self.focus_owner,
             };
 
+            dispatch_command_shortcuts(
+                &mut keyboard_dispatch_ctx,
+                &focus_chain_node_ids,
+                &mut self.held_key_events,
+            );
+
             for node_id in focus_chain_node_ids.iter().copied() {
                 let Some(node) = keyboard_dispatch_ctx.tree.get(node_id).map(|n| n.get()) else {
                     continue;
@@ -1476,7 +1576,7 @@ fn build_pointer_change_paths(
     for change in pointer_changes {
         let debug_position = match &change.content {
             CursorEventContent::Moved(position) => Some(*position),
-            _ => cursor_position,
+            _ => change.position.or(cursor_position),
         };
         let path = match &change.content {
             CursorEventContent::Pressed(_) => {
@@ -1505,12 +1605,14 @@ fn build_pointer_change_paths(
                 active_pointer_paths.remove(&change.pointer_id);
                 computed
             }
-            CursorEventContent::Scroll(_) => active_pointer_paths
-                .get(&change.pointer_id)
-                .cloned()
-                .unwrap_or_else(|| {
-                    hit_path_instance_keys(root_node, tree, metadatas, debug_position)
-                }),
+            CursorEventContent::Scroll(_) | CursorEventContent::Transform(_) => {
+                active_pointer_paths
+                    .get(&change.pointer_id)
+                    .cloned()
+                    .unwrap_or_else(|| {
+                        hit_path_instance_keys(root_node, tree, metadatas, debug_position)
+                    })
+            }
         };
         paths.push(path);
     }
@@ -1724,6 +1826,7 @@ fn run_pointer_input_for_node<F>(
     let input = PointerInput {
         pass,
         computed_data: node_computed_data,
+        abs_position: abs_pos,
         cursor_position_rel: current_cursor_position,
         cursor_position_abs: cursor_position_ref,
         pointer_changes: &mut local_pointer_changes,
@@ -1933,6 +2036,98 @@ fn run_ime_input_for_node<F>(
     attach_ime_position_if_needed(window_requests, abs_pos);
 }
 
+/// Turns key presses bound in the shortcut registry into commands.
+///
+/// Runs before any keyboard handler sees the events. Commands queued with
+/// [`shortcut::execute_command`] run first. A completed sequence goes to the
+/// command handler nearest to the focused node, then to app-wide handlers;
+/// its key press is consumed only when something handled it.
+///
+/// Presses that start a longer sequence are held in `held` until it
+/// completes. When the sequence breaks, or completes without a handler, they
+/// are replayed to the focused node ahead of the press that ended it.
+fn dispatch_command_shortcuts(
+    dispatch_ctx: &mut KeyboardInputDispatchContext<'_>,
+    focus_chain_node_ids: &[indextree::NodeId],
+    held: &mut Vec<winit::event::KeyEvent>,
+) {
+    for command in shortcut::take_executed_commands() {
+        dispatch_commands(dispatch_ctx, focus_chain_node_ids, &[command]);
+    }
+    if dispatch_ctx.keyboard_events.is_empty() {
+        return;
+    }
+
+    let events = std::mem::take(dispatch_ctx.keyboard_events);
+    for event in events {
+        if !dispatch_command_shortcut(dispatch_ctx, focus_chain_node_ids, held, &event) {
+            dispatch_ctx.keyboard_events.push(event);
+        }
+    }
+}
+
+fn dispatch_command_shortcut(
+    dispatch_ctx: &mut KeyboardInputDispatchContext<'_>,
+    focus_chain_node_ids: &[indextree::NodeId],
+    held: &mut Vec<winit::event::KeyEvent>,
+    event: &winit::event::KeyEvent,
+) -> bool {
+    if event.state != winit::event::ElementState::Pressed {
+        return false;
+    }
+    let Some(chord) = KeyChord::from_key_event(&event.logical_key, dispatch_ctx.modifiers) else {
+        return false;
+    };
+    let matched = shortcut::match_chord(chord);
+    if matched.broke_sequence {
+        dispatch_ctx.keyboard_events.append(held);
+    }
+    match matched.outcome {
+        SequenceMatch::None => false,
+        SequenceMatch::Pending => {
+            held.push(event.clone());
+            true
+        }
+        SequenceMatch::Complete(commands) => {
+            let prefix = std::mem::take(held);
+            if dispatch_commands(dispatch_ctx, focus_chain_node_ids, &commands) {
+                true
+            } else {
+                dispatch_ctx.keyboard_events.extend(prefix);
+                false
+            }
+        }
+    }
+}
+
+/// Runs the handler of the first of `commands` that has one, returning whether
+/// any did.
+fn dispatch_commands(
+    dispatch_ctx: &mut KeyboardInputDispatchContext<'_>,
+    focus_chain_node_ids: &[indextree::NodeId],
+    commands: &[shortcut::CommandId],
+) -> bool {
+    for node_id in focus_chain_node_ids.iter().rev().copied() {
+        let Some(handler) = dispatch_ctx.tree.get(node_id).and_then(|node| {
+            commands
+                .iter()
+                .find_map(|command| node.get().modifier.command_handler(command))
+        }) else {
+            continue;
+        };
+        run_keyboard_input_for_node(dispatch_ctx, node_id, |_| handler.call());
+        return true;
+    }
+
+    if let Some(handler) = shortcut::global_command_handler(commands) {
+        let _phase_guard = push_phase(RuntimePhase::Input);
+        let _focus_owner_guard = bind_focus_owner(dispatch_ctx.focus_owner);
+        handler();
+        return true;
+    }
+    false
+}
+
 fn dispatch_default_focus_keyboard_navigation(
     tree: &ComponentNodeTree,
     keyboard_events: &mut Vec<winit::event::KeyEvent>,
@@ -2225,6 +2420,10 @@ fn record_layout_commands(
                 Some(node.get().fn_name.as_str()),
             ))
         };
+        if let Some(metadata) = metadatas.get_mut(&node_id) {
+            // Text runs are re-recorded along with the draw commands.
+            metadata.accessibility_text = None;
+        }
         let mut input = RenderInput::new(node_id, metadatas, compute_resource_manager, gpu);
         let node_ref = node.get();
         let draw_nodes: Vec<_> = node_ref
@@ -2383,6 +2582,122 @@ fn populate_layout_metadata(
     );
 }
 
+/// Publishes node visibility from the latest layout to
+/// [`remember_visibility`](crate::remember_visibility) observers.
+///
+/// Layout nodes are visible when their rect intersects the window and their
+/// ancestor clip. Composition nodes have no rect of their own and are visible
+/// when any of their descendants is.
+fn sync_node_visibility(
+    root_node: indextree::NodeId,
+    tree: &ComponentNodeTree,
+    metadatas: &ComponentNodeMetaDatas,
+    screen_size: PxSize,
+    window_visible: bool,
+) {
+    fn visit(
+        node_id: indextree::NodeId,
+        tree: &ComponentNodeTree,
+        metadatas: &ComponentNodeMetaDatas,
+        screen_rect: PxRect,
+        visibility: &mut HashMap<u64, bool>,
+    ) -> bool {
+        let Some(node) = tree.get(node_id) else {
+            return false;
+        };
+        let mut any_child_visible = false;
+        for child in node_id.children(tree) {
+            any_child_visible |= visit(child, tree, metadatas, screen_rect, visibility);
+        }
+        let visible = match node.get().role {
+            NodeRole::Composition => any_child_visible,
+            NodeRole::Layout => metadatas.get(&node_id).is_some_and(|metadata| {
+                let (Some(position), Some(size)) = (metadata.abs_position, metadata.computed_data)
+                else {
+                    return false;
+                };
+                let node_rect = PxRect {
+                    x: position.x,
+                    y: position.y,
+                    width: size.width,
+                    height: size.height,
+                };
+                let visible_rect = match metadata.event_clip_rect {
+                    Some(clip) => clip.intersection(&screen_rect),
+                    None => Some(screen_rect),
+                };
+                visible_rect.is_some_and(|rect| node_rect.intersection(&rect).is_some())
+            }),
+        };
+        visibility.insert(node.get().instance_key, visible);
+        visible
+    }
+
+    if !has_visibility_observers() {
+        return;
+    }
+    let screen_rect = PxRect {
+        x: Px(0),
+        y: Px(0),
+        width: screen_size.width,
+        height: screen_size.height,
+    };
+    let mut visibility = HashMap::default();
+    visit(root_node, tree, metadatas, screen_rect, &mut visibility);
+    sync_visibility_observers(window_visible, |instance_key| {
+        visibility.get(&instance_key).copied()
+    });
+}
+
+/// Publishes node bounds from the latest layout to
+/// [`remember_bounds`](crate::remember_bounds) observers.
+///
+/// Layout nodes report their placed rect. Composition nodes report the union
+/// of their descendants' rects.
+fn sync_node_bounds(
+    root_node: indextree::NodeId,
+    tree: &ComponentNodeTree,
+    metadatas: &ComponentNodeMetaDatas,
+) {
+    fn visit(
+        node_id: indextree::NodeId,
+        tree: &ComponentNodeTree,
+        metadatas: &ComponentNodeMetaDatas,
+        bounds: &mut HashMap<u64, Option<PxRect>>,
+    ) -> Option<PxRect> {
+        let node = tree.get(node_id)?;
+        let mut children_rect: Option<PxRect> = None;
+        for child in node_id.children(tree) {
+            if let Some(rect) = visit(child, tree, metadatas, bounds) {
+                children_rect = Some(match children_rect {
+                    Some(current) => current.union(&rect),
+                    None => rect,
+                });
+            }
+        }
+        let rect = match node.get().role {
+            NodeRole::Composition => children_rect,
+            NodeRole::Layout => metadatas.get(&node_id).and_then(|metadata| {
+                let position = metadata.abs_position?;
+                let size = metadata.computed_data?;
+                Some(PxRect::from_position_size(
+                    position,
+                    PxSize::new(size.width, size.height),
+                ))
+            }),
+        };
+        bounds.insert(node.get().instance_key, rect);
+        rect
+    }
+
+    if !has_bounds_observers() {
+        return;
+    }
+    let mut bounds = HashMap::default();
+    visit(root_node, tree, metadatas, &mut bounds);
+    sync_bounds_observers(|instance_key| bounds.get(&instance_key).copied());
+}
+
 /// Sequential computation of render graph ops from the component tree.
 #[tracing::instrument(level = "trace", skip(tree, metadatas))]
 fn build_render_graph(
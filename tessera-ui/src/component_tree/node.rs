@@ -11,7 +11,10 @@ use winit::window::CursorIcon;
 
 use crate::{
     Px,
-    accessibility::{AccessibilityActionHandler, AccessibilityNode},
+    accessibility::{
+        AccessibilityActionHandler, AccessibilityActionHandlers, AccessibilityNode,
+        AccessibilityText, AccessibilityTextActionHandler,
+    },
     cursor::{CursorEventContent, PointerChange},
     focus::{
         FocusDirection, FocusRegistration, FocusRequester, FocusRevealRequest, FocusState,
@@ -141,6 +144,12 @@ pub(crate) struct ComponentNodeMetaData {
     pub accessibility: Option<AccessibilityNode>,
     /// Handler for accessibility actions on this node.
     pub accessibility_action_handler: Option<AccessibilityActionHandler>,
+    /// Typed handlers for standard and custom actions on this node.
+    pub accessibility_action_handlers: AccessibilityActionHandlers,
+    /// Laid-out text recorded for assistive technologies.
+    pub accessibility_text: Option<AccessibilityText>,
+    /// Handler for text selection and editing actions on this node.
+    pub accessibility_text_action_handler: Option<AccessibilityTextActionHandler>,
 }
 
 impl ComponentNodeMetaData {
@@ -159,6 +168,9 @@ impl ComponentNodeMetaData {
             opacity: 1.0,
             accessibility: None,
             accessibility_action_handler: None,
+            accessibility_action_handlers: AccessibilityActionHandlers::default(),
+            accessibility_text: None,
+            accessibility_text_action_handler: None,
         }
     }
 
@@ -320,6 +332,11 @@ pub struct PointerInput<'a> {
     pub pass: PointerEventPass,
     /// The size of the component node, computed during the measure stage.
     pub computed_data: ComputedData,
+    /// Absolute position of the component in window coordinates.
+    ///
+    /// Subtract it from [`PointerChange::position`] to get a position relative
+    /// to the component.
+    pub abs_position: PxPosition,
     /// The position of the cursor, if available.
     /// Relative to the root position of the component.
     pub cursor_position_rel: Option<PxPosition>,
@@ -724,6 +724,37 @@ where
             .map(|entry| Context::new(entry.slot, entry.generation))
     })
 }
+/// Contexts visible at the point where the snapshot was captured.
+///
+/// Components that render content on behalf of another component, such as an
+/// overlay host drawing a popup declared deeper in the tree, capture the
+/// caller's contexts with [`ContextSnapshot::capture`] and build the content
+/// inside [`ContextSnapshot::provide`] so it sees what the caller saw.
+#[derive(Clone, Default, PartialEq)]
+pub struct ContextSnapshot {
+    map: ContextMap,
+}
+
+impl ContextSnapshot {
+    /// Captures the contexts provided around the current component.
+    ///
+    /// This is intended for use inside component build functions only.
+    pub fn capture() -> Self {
+        ensure_build_phase();
+        Self {
+            map: current_context_map(),
+        }
+    }
+
+    /// Runs `f` with the captured contexts in place of the current ones.
+    ///
+    /// Contexts whose provider has left the tree since the capture are not
+    /// visible.
+    pub fn provide<R>(&self, f: impl FnOnce() -> R) -> R {
+        with_context_snapshot(&self.map, f)
+    }
+}
+
 // (legacy comment removed)
 
 #[cfg(test)]
@@ -733,8 +764,8 @@ mod tests {
     use parking_lot::RwLock;
 
     use super::{
-        ContextMap, ContextSnapshotEntry, SlotEntry, SlotKey, SlotTable, with_context_snapshot,
-        with_slot_table_mut,
+        ContextMap, ContextSnapshot, ContextSnapshotEntry, SlotEntry, SlotKey, SlotTable,
+        with_context_snapshot, with_slot_table_mut,
     };
     use crate::execution_context::{
         reset_execution_context, with_execution_context, with_execution_context_mut,
@@ -885,4 +916,36 @@ mod tests {
             assert_eq!(context.get(), 7);
         });
     }
+
+    #[test]
+    fn context_snapshot_restores_contexts_captured_in_a_nested_scope() {
+        reset_test_state();
+
+        let key = SlotKey {
+            instance_logic_id: 59,
+            slot_hash: 61,
+            type_id: TypeId::of::<u32>(),
+        };
+        with_slot_table_mut(|table| {
+            table.entries.push(SlotEntry {
+                key,
+                generation: 0,
+                value: Some(Arc::new(RwLock::new(5_u32))),
+                last_alive_epoch: 0,
+            });
+            table.key_to_slot.insert(key, 0);
+        });
+
+        let _phase_guard = push_phase(RuntimePhase::Build);
+        super::push_context_layer(TypeId::of::<u32>(), 0, 0, key);
+        let snapshot = ContextSnapshot::capture();
+        super::pop_context_layer();
+
+        assert!(super::use_context::<u32>().is_none());
+        snapshot.provide(|| {
+            let context = super::use_context::<u32>().expect("captured context should be visible");
+            assert_eq!(context.get(), 5);
+        });
+        assert!(super::use_context::<u32>().is_none());
+    }
 }
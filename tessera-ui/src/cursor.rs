@@ -190,6 +190,7 @@ impl CursorState {
         let press_event = PointerChange {
             timestamp: now,
             pointer_id: touch_id,
+            position: Some(position),
             content: CursorEventContent::Pressed(PressKeyEventType::Left),
             gesture_state: GestureState::TapCandidate,
             consumed: false,
@@ -223,6 +224,7 @@ impl CursorState {
         self.push_event(PointerChange {
             timestamp: now,
             pointer_id: touch_id,
+            position: Some(current_position),
             content: CursorEventContent::Moved(current_position),
             gesture_state: GestureState::TapCandidate,
             consumed: false,
@@ -246,6 +248,7 @@ impl CursorState {
                 return Some(PointerChange {
                     timestamp: now,
                     pointer_id: touch_id,
+                    position: Some(current_position),
                     content: CursorEventContent::Scroll(ScrollEventContent {
                         delta_x, // Direct scroll delta for touch move
                         delta_y,
@@ -273,15 +276,18 @@ impl CursorState {
     pub fn handle_touch_end(&mut self, touch_id: u64) {
         let now = Instant::now();
         let mut was_drag = false;
+        let mut position = None;
 
         if let Some(touch_state) = self.touch_points.get_mut(&touch_id) {
             was_drag |= touch_state.generated_scroll_event;
+            position = Some(touch_state.last_position);
         }
 
         self.touch_points.remove(&touch_id);
         let release_event = PointerChange {
             timestamp: now,
             pointer_id: touch_id,
+            position,
             content: CursorEventContent::Released(PressKeyEventType::Left),
             gesture_state: if was_drag {
                 GestureState::Dragged
@@ -303,12 +309,21 @@ impl CursorState {
 /// `PointerChange` encapsulates all pointer interactions including
 /// presses, releases, and scroll actions. Each event includes a timestamp for
 /// precise timing and ordering of input events.
+///
+/// New fields may be added in minor releases; build changes with
+/// [`PointerChange::new`].
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct PointerChange {
     /// Timestamp indicating when this event occurred.
     pub timestamp: Instant,
     /// Pointer identifier for this input stream.
     pub pointer_id: PointerId,
+    /// Window position of the pointer when this change occurred, if known.
+    ///
+    /// Unlike the single tracked cursor position, this is kept per change so
+    /// multi-touch gestures can follow every pointer independently.
+    pub position: Option<PxPosition>,
     /// The specific type and data of this pointer change.
     pub content: CursorEventContent,
     /// Classification of the gesture associated with this event.
@@ -322,6 +337,26 @@ pub struct PointerChange {
 }
 
 impl PointerChange {
+    /// Creates an unconsumed change for `pointer_id` stamped with the current
+    /// time.
+    ///
+    /// The position is taken from `Moved` content and left unset otherwise;
+    /// assign the public fields to override the defaults.
+    pub fn new(pointer_id: PointerId, content: CursorEventContent) -> Self {
+        let position = match content {
+            CursorEventContent::Moved(position) => Some(position),
+            _ => None,
+        };
+        Self {
+            timestamp: Instant::now(),
+            pointer_id,
+            position,
+            content,
+            gesture_state: GestureState::TapCandidate,
+            consumed: false,
+        }
+    }
+
     /// Marks this change as consumed.
     pub fn consume(&mut self) {
         self.consumed = true;
@@ -350,12 +385,28 @@ pub struct ScrollEventContent {
     pub source: ScrollEventSource,
 }
 
+/// Contains zoom and rotation data for trackpad transform gestures.
+///
+/// Touch screens report individual touch points instead; recognizers derive
+/// the same quantities from them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransformEventContent {
+    /// Multiplicative zoom change, where `1.0` means no change.
+    pub zoom: f32,
+    /// Rotation change in radians, positive values rotating clockwise.
+    pub rotation: f32,
+}
+
 /// Enumeration of all possible cursor event types.
 ///
 /// `CursorEventContent` represents the different kinds of interactions
 /// that can occur with cursor or touch input, including button presses,
 /// releases, and scroll actions.
+///
+/// New event kinds may be added in minor releases, so matches need a wildcard
+/// arm.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum CursorEventContent {
     /// The pointer moved to a new absolute position.
     Moved(PxPosition),
@@ -365,6 +416,8 @@ pub enum CursorEventContent {
     Released(PressKeyEventType),
     /// A scroll action occurred (mouse wheel or touch drag).
     Scroll(ScrollEventContent),
+    /// A trackpad pinch or rotation gesture occurred.
+    Transform(TransformEventContent),
 }
 
 /// Describes the high-level gesture classification of a cursor event.
@@ -440,6 +493,26 @@ impl CursorEventContent {
             source: ScrollEventSource::Wheel,
         })
     }
+
+    /// Creates a transform event from a winit trackpad pinch delta.
+    ///
+    /// Positive deltas magnify.
+    pub fn from_pinch_gesture(delta: f64) -> Self {
+        Self::Transform(TransformEventContent {
+            zoom: (1.0 + delta as f32).max(f32::EPSILON),
+            rotation: 0.0,
+        })
+    }
+
+    /// Creates a transform event from a winit trackpad rotation delta.
+    ///
+    /// winit reports degrees with counterclockwise as positive.
+    pub fn from_rotation_gesture(delta: f32) -> Self {
+        Self::Transform(TransformEventContent {
+            zoom: 1.0,
+            rotation: -delta.to_radians(),
+        })
+    }
 }
 
 /// Represents the different types of cursor buttons or touch interactions.
@@ -17,6 +17,8 @@ use crate::{
     renderer::{Renderer, TesseraConfig},
 };
 
+#[cfg(not(target_os = "android"))]
+use crate::renderer::{EmbeddedConfig, EmbeddedRenderer};
 #[cfg(target_os = "android")]
 use winit::platform::android::activity::AndroidApp;
 
@@ -67,12 +69,41 @@ impl EntryPoint {
         self
     }
 
+    /// Consumes the builder and returns the root UI function.
+    #[cfg(all(
+        feature = "hot-reload",
+        not(target_os = "android"),
+        not(target_family = "wasm")
+    ))]
+    pub(crate) fn into_entry(self) -> Box<dyn Fn()> {
+        self.entry
+    }
+
     /// Runs the entry point on desktop platforms.
     #[cfg(all(not(target_os = "android"), not(target_family = "wasm")))]
     pub fn run_desktop(self) -> Result<(), winit::error::EventLoopError> {
         init_tracing();
         init_deadlock_detection();
-        Renderer::run_with_config(self.entry, self.registry.finish(), self.config)
+        #[cfg(feature = "hot-reload")]
+        let entry = crate::hot_reload::wrap_entry(self.entry);
+        #[cfg(not(feature = "hot-reload"))]
+        let entry = self.entry;
+        Renderer::run_with_config(entry, self.registry.finish(), self.config)
+    }
+
+    /// Creates an embedded renderer for a host application that owns the
+    /// event loop and GPU device.
+    ///
+    /// Unlike the `run_*` methods this installs no tracing subscriber, so the
+    /// host keeps its own.
+    #[cfg(not(target_os = "android"))]
+    pub fn embed(
+        self,
+        device: wgpu::Device,
+        queue: wgpu::Queue,
+        config: EmbeddedConfig,
+    ) -> EmbeddedRenderer<Box<dyn Fn()>> {
+        EmbeddedRenderer::new(device, queue, config, self.entry, self.registry.finish())
     }
 
     /// Runs the entry point on web platforms.
@@ -801,6 +801,36 @@ impl FocusManager {
     pub fn move_focus(self, direction: FocusDirection) -> bool {
         with_focus_owner_mut(|owner| owner.move_focus(direction))
     }
+
+    /// Remembers the focused node so focus can return to it later.
+    ///
+    /// Returns `None` when nothing is focused. Popups use this to restore
+    /// focus to whatever opened them once they close.
+    pub fn save_focus(self) -> Option<SavedFocus> {
+        with_focus_owner_mut(|owner| owner.active_handle_id()).map(|id| SavedFocus { id })
+    }
+}
+
+/// Focus position captured by [`FocusManager::save_focus`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SavedFocus {
+    id: FocusHandleId,
+}
+
+impl SavedFocus {
+    /// Moves focus back to the saved node.
+    ///
+    /// Returns `false` when the node has left the tree or can no longer take
+    /// focus.
+    pub fn restore(self) -> bool {
+        with_focus_owner_mut(|owner| {
+            if !owner.is_attached(self.id) {
+                return false;
+            }
+            owner.request_focus(self.id);
+            true
+        })
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -974,6 +1004,10 @@ impl FocusOwner {
         self.active
     }
 
+    pub(crate) fn is_attached(&self, id: FocusHandleId) -> bool {
+        self.nodes.get(&id).is_some_and(|node| node.is_attached())
+    }
+
     pub(crate) fn take_pending_reveal(&mut self) -> Option<FocusHandleId> {
         self.pending_reveal.take()
     }
@@ -0,0 +1,148 @@
+//! # Hot Reload
+//!
+//! ## Usage
+//!
+//! Reload component code of a running desktop app without restarting it.
+//!
+//! `cargo tessera dev --hot-reload` compiles the app library as a dynamic
+//! library and starts the app with `TESSERA_HOT_RELOAD_ADDR` pointing at a
+//! local TCP socket. Every rebuild sends one line over that socket:
+//!
+//! ```text
+//! reload /path/to/target/debug/hot-reload/libexample_lib-3.so
+//! ```
+//!
+//! The runtime loads the library, resolves the root entry exported by
+//! `#[tessera::entry]` and swaps it in before the next frame. The component
+//! tree and layout caches are invalidated so the next frame performs a full
+//! root recomposition, while memoized slots are kept and reused wherever the
+//! slot keys still match.
+//!
+//! Only the root entry function is replaced. Changes to packages, plugins or
+//! the renderer configuration still require a restart. Loaded libraries are
+//! never unloaded because retained state and callbacks may still reference
+//! code from them.
+
+use std::{
+    cell::RefCell,
+    io::{BufRead, BufReader},
+    net::TcpStream,
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::Arc,
+    thread,
+};
+
+use parking_lot::Mutex;
+use tracing::{error, info, warn};
+
+use crate::{
+    component_tree::clear_layout_snapshots,
+    entry_point::EntryPoint,
+    runtime::{TesseraRuntime, reset_component_replay_tracking, reset_layout_dirty_tracking},
+};
+
+/// Environment variable carrying the address of the dev server socket.
+const HOT_RELOAD_ADDR_ENV: &str = "TESSERA_HOT_RELOAD_ADDR";
+
+/// Symbol exported by `#[tessera::entry]` in hot reload builds.
+const HOT_RELOAD_ENTRY_SYMBOL: &[u8] = b"__tessera_hot_reload_entry";
+
+type HotReloadWaker = Arc<dyn Fn() + Send + Sync + 'static>;
+
+static PENDING_LIBRARY: Mutex<Option<PathBuf>> = Mutex::new(None);
+static WAKER: Mutex<Option<HotReloadWaker>> = Mutex::new(None);
+
+thread_local! {
+    static PATCHED_ENTRY: RefCell<Option<Rc<dyn Fn()>>> = const { RefCell::new(None) };
+}
+
+/// Wraps the root entry so it can be replaced by reloaded libraries.
+///
+/// When `TESSERA_HOT_RELOAD_ADDR` is not set, the entry is returned unchanged.
+pub(crate) fn wrap_entry(entry: Box<dyn Fn()>) -> Box<dyn Fn()> {
+    let Ok(addr) = std::env::var(HOT_RELOAD_ADDR_ENV) else {
+        return entry;
+    };
+    spawn_client(addr);
+    Box::new(move || {
+        let patched = PATCHED_ENTRY.with(|slot| slot.borrow().clone());
+        match patched {
+            Some(patched) => patched(),
+            None => entry(),
+        }
+    })
+}
+
+/// Installs the callback used to wake the event loop after a reload request.
+pub(crate) fn install_waker(waker: HotReloadWaker) {
+    *WAKER.lock() = Some(waker);
+}
+
+/// Applies the most recent reload request, if any.
+///
+/// Must be called on the UI thread before the component tree is built.
+pub(crate) fn apply_pending_reload() {
+    let Some(path) = PENDING_LIBRARY.lock().take() else {
+        return;
+    };
+    match load_entry(&path) {
+        Ok(entry) => {
+            PATCHED_ENTRY.with(|slot| *slot.borrow_mut() = Some(entry));
+            invalidate_component_tree();
+            info!("Hot reloaded {}", path.display());
+        }
+        Err(err) => error!("Hot reload of {} failed: {err}", path.display()),
+    }
+}
+
+fn spawn_client(addr: String) {
+    let spawn_result = thread::Builder::new()
+        .name("tessera-hot-reload".to_string())
+        .spawn(move || {
+            let stream = match TcpStream::connect(&addr) {
+                Ok(stream) => stream,
+                Err(err) => {
+                    warn!("Failed to connect to hot reload server at {addr}: {err}");
+                    return;
+                }
+            };
+            for line in BufReader::new(stream).lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                let Some(path) = line.strip_prefix("reload ") else {
+                    continue;
+                };
+                *PENDING_LIBRARY.lock() = Some(PathBuf::from(path.trim()));
+                let waker = WAKER.lock().clone();
+                if let Some(waker) = waker {
+                    waker();
+                }
+            }
+        });
+    if let Err(err) = spawn_result {
+        warn!("Failed to start hot reload client: {err}");
+    }
+}
+
+fn load_entry(path: &Path) -> Result<Rc<dyn Fn()>, libloading::Error> {
+    // SAFETY: the library is produced by `cargo tessera dev` from the same
+    // sources, toolchain and shared runtime as the running app, so the
+    // exported entry has the expected signature.
+    let library = unsafe { libloading::Library::new(path)? };
+    let entry_point = unsafe {
+        let symbol = library.get::<fn() -> EntryPoint>(HOT_RELOAD_ENTRY_SYMBOL)?;
+        symbol()
+    };
+    // Retained state and callbacks may still point into this library.
+    std::mem::forget(library);
+    Ok(Rc::from(entry_point.into_entry()))
+}
+
+fn invalidate_component_tree() {
+    TesseraRuntime::with_mut(|runtime| runtime.component_tree.clear());
+    clear_layout_snapshots();
+    reset_layout_dirty_tracking();
+    reset_component_replay_tracking();
+}
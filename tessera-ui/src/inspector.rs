@@ -0,0 +1,346 @@
+//! # UI Inspector
+//!
+//! ## Usage
+//!
+//! Answer "why is this node this size" and "why did this rebuild" on a running
+//! app.
+//!
+//! Build with the `inspector` feature (`cargo tessera dev --inspector` does
+//! this) and press `F12` in the app window to toggle hover-to-pick. The node
+//! under the cursor is highlighted and its details are reported.
+//!
+//! When `TESSERA_INSPECTOR_ADDR` is set, the runtime connects to that local
+//! TCP socket and streams one JSON object per line:
+//!
+//! - `{"type":"frame", ...}` after every frame that rebuilt or re-laid out the
+//!   tree, carrying the build mode, redraw reasons, dirty replay roots and the
+//!   whole component tree.
+//! - `{"type":"pick", ...}` whenever the picked node changes, carrying the
+//!   picked node and its ancestor path.
+//!
+//! Without a socket, picked nodes are logged through `tracing` instead.
+//!
+//! Each node record contains its `fn_name`, incoming constraint, computed size,
+//! relative and absolute position, modifier chain, semantics and focus state.
+
+use std::{cell::RefCell, io::Write, net::TcpStream};
+
+use serde::Serialize;
+use tracing::{info, warn};
+use winit::{
+    event::{ElementState, KeyEvent},
+    keyboard::{KeyCode, PhysicalKey},
+};
+
+use crate::{
+    PxPosition, PxRect,
+    accessibility::AccessibilityNode,
+    build_tree::{BuildTreeMode, BuildTreeResult},
+    component_tree::{ComputedData, Constraint},
+    focus::FocusState,
+    runtime::TesseraRuntime,
+};
+
+/// Environment variable carrying the address of the inspector socket.
+const INSPECTOR_ADDR_ENV: &str = "TESSERA_INSPECTOR_ADDR";
+
+/// Key that toggles hover-to-pick.
+const PICK_TOGGLE_KEY: KeyCode = KeyCode::F12;
+
+/// Serialized details of one component node.
+#[derive(Clone, Debug, Serialize)]
+pub(crate) struct InspectorNode {
+    pub id: String,
+    pub parent: Option<String>,
+    pub fn_name: String,
+    pub role: &'static str,
+    pub instance_key: u64,
+    pub constraint: Option<InspectorConstraint>,
+    pub size: Option<(i32, i32)>,
+    pub rel_pos: Option<(i32, i32)>,
+    pub abs_pos: Option<(i32, i32)>,
+    pub layout_cache_hit: bool,
+    pub props_unchanged: bool,
+    pub clips_children: bool,
+    pub opacity: f32,
+    pub modifiers: Vec<String>,
+    pub semantics: Option<InspectorSemantics>,
+    pub focus: Option<&'static str>,
+    /// Visible bounds used for picking.
+    #[serde(skip)]
+    pub bounds: Option<PxRect>,
+}
+
+/// Incoming constraint of a node as `(min, max)` pairs, `None` for unbounded.
+#[derive(Clone, Copy, Debug, Serialize)]
+pub(crate) struct InspectorConstraint {
+    pub width: (i32, Option<i32>),
+    pub height: (i32, Option<i32>),
+}
+
+impl From<Constraint> for InspectorConstraint {
+    fn from(constraint: Constraint) -> Self {
+        Self {
+            width: (
+                constraint.width.min.0,
+                constraint.width.max.map(|max| max.0),
+            ),
+            height: (
+                constraint.height.min.0,
+                constraint.height.max.map(|max| max.0),
+            ),
+        }
+    }
+}
+
+/// Accessibility semantics attached to a node.
+#[derive(Clone, Debug, Serialize)]
+pub(crate) struct InspectorSemantics {
+    pub role: Option<String>,
+    pub label: Option<String>,
+    pub description: Option<String>,
+    pub value: Option<String>,
+    pub toggled: Option<String>,
+    pub focusable: bool,
+    pub disabled: bool,
+    pub hidden: bool,
+    pub actions: Vec<String>,
+}
+
+impl From<&AccessibilityNode> for InspectorSemantics {
+    fn from(node: &AccessibilityNode) -> Self {
+        Self {
+            role: node.role.map(|role| format!("{role:?}")),
+            label: node.label.clone(),
+            description: node.description.clone(),
+            value: node.value.clone(),
+            toggled: node.toggled.map(|toggled| format!("{toggled:?}")),
+            focusable: node.focusable,
+            disabled: node.disabled,
+            hidden: node.hidden,
+            actions: node
+                .actions
+                .iter()
+                .map(|action| format!("{action:?}"))
+                .collect(),
+        }
+    }
+}
+
+pub(crate) fn focus_state_name(state: FocusState) -> &'static str {
+    match state {
+        FocusState::Inactive => "inactive",
+        FocusState::ActiveParent => "active_parent",
+        FocusState::Active => "active",
+        FocusState::Captured => "captured",
+    }
+}
+
+pub(crate) fn node_bounds(
+    abs_position: Option<PxPosition>,
+    computed_data: Option<ComputedData>,
+    clip_rect: Option<PxRect>,
+) -> Option<PxRect> {
+    let abs_position = abs_position?;
+    let computed_data = computed_data?;
+    if computed_data.width.0 <= 0 || computed_data.height.0 <= 0 {
+        return None;
+    }
+    let rect = PxRect::from_position_size(
+        abs_position,
+        crate::PxSize::new(computed_data.width, computed_data.height),
+    );
+    match clip_rect {
+        Some(clip_rect) => clip_rect.intersection(&rect),
+        None => Some(rect),
+    }
+}
+
+#[derive(Serialize)]
+struct FrameRecord<'a> {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    frame: u64,
+    build_mode: &'static str,
+    redraw_reasons: &'a [String],
+    dirty_replay_roots: &'a [u64],
+    picked: Option<&'a str>,
+    nodes: &'a [InspectorNode],
+}
+
+#[derive(Serialize)]
+struct PickRecord<'a> {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    frame: u64,
+    node: Option<&'a InspectorNode>,
+    ancestors: Vec<&'a str>,
+}
+
+/// Per-frame input used to update the inspector.
+pub(crate) struct InspectorFrameInput<'a> {
+    pub frame_idx: u64,
+    pub build_tree_result: &'a BuildTreeResult,
+    pub layout_changed: bool,
+    pub cursor_position: Option<PxPosition>,
+    pub redraw_reasons: Vec<String>,
+}
+
+#[derive(Default)]
+struct InspectorState {
+    picking: bool,
+    picked_instance_key: Option<u64>,
+    stream: Option<TcpStream>,
+    connect_attempted: bool,
+}
+
+impl InspectorState {
+    fn connect(&mut self) {
+        if self.connect_attempted {
+            return;
+        }
+        self.connect_attempted = true;
+        let Ok(addr) = std::env::var(INSPECTOR_ADDR_ENV) else {
+            return;
+        };
+        match TcpStream::connect(&addr) {
+            Ok(stream) => self.stream = Some(stream),
+            Err(err) => warn!("Failed to connect to inspector at {addr}: {err}"),
+        }
+    }
+
+    fn send(&mut self, record: &impl Serialize) {
+        let Some(stream) = self.stream.as_mut() else {
+            return;
+        };
+        let result = serde_json::to_writer(&mut *stream, record)
+            .map_err(std::io::Error::from)
+            .and_then(|()| stream.write_all(b"\n"));
+        if let Err(err) = result {
+            warn!("Inspector stream closed: {err}");
+            self.stream = None;
+        }
+    }
+}
+
+thread_local! {
+    static INSPECTOR: RefCell<InspectorState> = RefCell::new(InspectorState::default());
+}
+
+/// Toggles hover-to-pick on `F12`. Returns `true` when the event was consumed.
+pub(crate) fn handle_keyboard_input(event: &KeyEvent) -> bool {
+    if event.physical_key != PhysicalKey::Code(PICK_TOGGLE_KEY) {
+        return false;
+    }
+    if event.state == ElementState::Pressed && !event.repeat {
+        INSPECTOR.with(|inspector| {
+            let mut inspector = inspector.borrow_mut();
+            inspector.picking = !inspector.picking;
+            inspector.picked_instance_key = None;
+            info!(
+                "Inspector hover-to-pick {}",
+                if inspector.picking {
+                    "enabled"
+                } else {
+                    "disabled"
+                }
+            );
+        });
+    }
+    true
+}
+
+/// Records the current frame and returns the highlight rectangle of the
+/// picked node, if any.
+pub(crate) fn record_frame(input: InspectorFrameInput<'_>) -> Option<PxRect> {
+    INSPECTOR.with(|inspector| {
+        let mut inspector = inspector.borrow_mut();
+        inspector.connect();
+
+        let tree_changed = input.layout_changed
+            || !matches!(
+                input.build_tree_result.mode(),
+                BuildTreeMode::SkipNoInvalidation
+            );
+        let streaming = inspector.stream.is_some();
+        if !inspector.picking && !(streaming && tree_changed) {
+            return None;
+        }
+
+        let nodes = TesseraRuntime::with(|rt| rt.component_tree.inspector_nodes());
+        let picked = if inspector.picking {
+            input
+                .cursor_position
+                .and_then(|cursor| pick_node(&nodes, cursor))
+        } else {
+            None
+        };
+
+        if streaming && tree_changed {
+            let record = FrameRecord {
+                kind: "frame",
+                frame: input.frame_idx,
+                build_mode: build_mode_name(input.build_tree_result.mode()),
+                redraw_reasons: &input.redraw_reasons,
+                dirty_replay_roots: input.build_tree_result.dirty_replay_roots(),
+                picked: picked.map(|node| node.id.as_str()),
+                nodes: &nodes,
+            };
+            inspector.send(&record);
+        }
+
+        let picked_instance_key = picked.map(|node| node.instance_key);
+        if inspector.picking && picked_instance_key != inspector.picked_instance_key {
+            inspector.picked_instance_key = picked_instance_key;
+            let record = PickRecord {
+                kind: "pick",
+                frame: input.frame_idx,
+                node: picked,
+                ancestors: picked
+                    .map(|node| ancestor_path(&nodes, node))
+                    .unwrap_or_default(),
+            };
+            if streaming {
+                inspector.send(&record);
+            } else if let Some(node) = picked {
+                info!(
+                    "Inspector picked `{}` size={:?} abs_pos={:?} constraint={:?} modifiers={:?}",
+                    node.fn_name, node.size, node.abs_pos, node.constraint, node.modifiers
+                );
+            }
+        }
+
+        picked.and_then(|node| node.bounds)
+    })
+}
+
+fn pick_node(nodes: &[InspectorNode], cursor: PxPosition) -> Option<&InspectorNode> {
+    // Nodes are in pre-order, so the last hit is the deepest, top-most node.
+    nodes
+        .iter()
+        .filter(|node| node.role == "layout")
+        .filter(|node| node.bounds.is_some_and(|bounds| bounds.contains(cursor)))
+        .last()
+}
+
+fn ancestor_path<'a>(nodes: &'a [InspectorNode], node: &'a InspectorNode) -> Vec<&'a str> {
+    let mut path = Vec::new();
+    let mut parent = node.parent.as_deref();
+    while let Some(parent_id) = parent {
+        let Some(parent_node) = nodes.iter().find(|candidate| candidate.id == parent_id) else {
+            break;
+        };
+        path.push(parent_node.fn_name.as_str());
+        parent = parent_node.parent.as_deref();
+    }
+    path.reverse();
+    path
+}
+
+fn build_mode_name(mode: BuildTreeMode) -> &'static str {
+    match mode {
+        BuildTreeMode::RootRecompose => "root_recompose",
+        BuildTreeMode::PartialReplay => "partial_replay",
+        BuildTreeMode::SkipNoInvalidation => "skip_no_invalidation",
+    }
+}
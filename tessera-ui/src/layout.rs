@@ -8,8 +8,8 @@ use std::{
 };
 
 use crate::{
-    ComputeResourceManager, ComputedData, Constraint, MeasurementError, ParentConstraint, Px,
-    RenderSlot,
+    AccessibilityText, ComputeResourceManager, ComputedData, Constraint, MeasurementError,
+    ParentConstraint, Px, RenderSlot,
     component_tree::{
         ComponentNodeMetaData, ComponentNodeMetaDatas, ComponentNodeTree, LayoutContext,
         measure_node,
@@ -529,6 +529,14 @@ impl RenderMetadataMut<'_> {
     pub fn multiply_opacity(&mut self, opacity: f32) {
         self.metadata.opacity *= opacity;
     }
+
+    /// Records laid-out text for assistive technologies.
+    ///
+    /// Runs are exposed as text-run children of this node, or of the nearest
+    /// ancestor with semantics when this node has none.
+    pub fn set_accessibility_text(&mut self, text: AccessibilityText) {
+        self.metadata.accessibility_text = Some(text);
+    }
 }
 
 /// Pure layout policy for measuring and placing child nodes.
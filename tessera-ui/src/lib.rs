@@ -293,9 +293,18 @@ pub mod entry_point;
 pub mod entry_registry;
 mod execution_context;
 pub mod focus;
+#[cfg(all(
+    feature = "hot-reload",
+    not(target_os = "android"),
+    not(target_family = "wasm")
+))]
+mod hot_reload;
 mod ime_state;
+#[cfg(feature = "inspector")]
+mod inspector;
 mod keyboard_state;
 pub mod layout;
+pub mod locale;
 pub mod modifier;
 pub(crate) mod pipeline_cache;
 pub mod pipeline_context;
@@ -304,6 +313,8 @@ pub mod plugin;
 pub mod profiler;
 mod prop;
 pub mod px;
+#[cfg(any(feature = "profiling", feature = "inspector"))]
+mod redraw_reason;
 mod render_graph;
 pub mod render_module;
 mod render_pass;
@@ -311,10 +322,12 @@ pub mod render_scene;
 pub mod renderer;
 mod runtime;
 pub mod scroll;
+pub mod shortcut;
 #[cfg(feature = "testing")]
 pub mod testing;
 mod thread_utils;
 pub mod time;
+mod ui_thread;
 
 pub use accesskit;
 pub use indextree::{Arena, NodeId};
@@ -323,35 +336,42 @@ pub use wgpu;
 pub use winit;
 
 pub use crate::{
-    accessibility::{AccessibilityActionHandler, AccessibilityId, AccessibilityNode},
-    asset::AssetExt,
+    accessibility::{
+        AccessibilityActionHandler, AccessibilityActionHandlers, AccessibilityId,
+        AccessibilityNode, AccessibilityText, AccessibilityTextAction,
+        AccessibilityTextActionHandler, AccessibilityTextRun, AccessibilityTextSelection,
+        AccessibilityValue,
+    },
+    asset::{AssetExt, AssetTheme},
     color::Color,
     component_tree::{
         AxisConstraint, ComponentTree, ComputedData, Constraint, ImeInput, ImeInputHandlerFn,
         ImeRequest, ImeSession, KeyboardInput, KeyboardInputHandlerFn, MeasurementError,
         ParentConstraint, PointerEventPass, PointerInput, PointerInputHandlerFn,
     },
-    context::{Context, provide_context, use_context},
+    context::{Context, ContextSnapshot, provide_context, use_context},
     cursor::{
         CursorEventContent, GestureState, MOUSE_POINTER_ID, PointerChange, PointerId,
         PressKeyEventType, ScrollDeltaUnit, ScrollEventContent, ScrollEventSource,
+        TransformEventContent,
     },
     dp::Dp,
     entry_point::EntryPoint,
     entry_registry::{EntryRegistry, TesseraPackage},
     focus::{
         FocusDirection, FocusGroupNode, FocusManager, FocusProperties, FocusRequester,
-        FocusScopeNode, FocusState, FocusTraversalPolicy, FocusTraversalStrategy,
+        FocusScopeNode, FocusState, FocusTraversalPolicy, FocusTraversalStrategy, SavedFocus,
     },
     layout::{
         DefaultLayoutPolicy, LayoutPolicy, LayoutResult, NoopRenderPolicy, RenderInput,
         RenderMetadataMut, RenderPolicy,
     },
+    locale::{Locale, current_locale},
     modifier::{
-        BuildModifierNode, CursorModifierExt, CursorModifierNode, DrawModifierContent,
-        DrawModifierContext, DrawModifierNode, FocusModifierExt, ImeInputModifierNode,
-        KeyboardInputModifierNode, LayoutModifierChild, LayoutModifierInput, LayoutModifierNode,
-        LayoutModifierOutput, Modifier, ParentDataMap, ParentDataModifierNode,
+        BuildModifierNode, CommandModifierExt, CursorModifierExt, CursorModifierNode,
+        DrawModifierContent, DrawModifierContext, DrawModifierNode, FocusModifierExt,
+        ImeInputModifierNode, KeyboardInputModifierNode, LayoutModifierChild, LayoutModifierInput,
+        LayoutModifierNode, LayoutModifierOutput, Modifier, ParentDataMap, ParentDataModifierNode,
         PlacementModifierNode, PointerInputModifierNode, SemanticsModifierNode,
     },
     pipeline_context::PipelineContext,
@@ -370,8 +390,8 @@ pub use crate::{
     renderer::{
         Renderer,
         composite::{
-            self, CompositeBatchItem, CompositeContext, CompositeOutput, CompositePipeline,
-            CompositePipelineRegistry, CompositeReplacement,
+            self, CompositeBatchItem, CompositeContext, CompositeEncodeContext, CompositeEncodeFn,
+            CompositeOutput, CompositePipeline, CompositePipelineRegistry, CompositeReplacement,
         },
         compute::{
             self, ComputablePipeline, ComputeCommand, ComputePipelineRegistry, ComputeResource,
@@ -382,9 +402,12 @@ pub use crate::{
     },
     runtime::{
         FrameNanosControl, State, current_frame_nanos, current_frame_time, frame_delta, key,
-        receive_frame_nanos, remember, remember_with_key, retain, retain_with_key,
+        receive_frame_nanos, remember, remember_bounds, remember_visibility, remember_with_key,
+        retain, retain_with_key,
     },
     scroll::{PlatformScrollConfig, normalize_platform_scroll_delta, platform_scroll_config},
+    shortcut::{CommandId, KeyChord, KeySequence},
+    ui_thread::post_to_ui_thread,
 };
 
 use ime_state::ImeState;
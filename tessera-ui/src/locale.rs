@@ -0,0 +1,433 @@
+//! # Localization
+//!
+//! ## Usage
+//!
+//! Switch the language of string resources and built-in component labels at
+//! runtime.
+//!
+//! Provide a [`Locale`] context near the root of the app. Components that read
+//! localized strings during build subscribe to it, so calling
+//! [`Context::set`](crate::Context::set) with another locale rebuilds exactly
+//! those components.
+//!
+//! ```
+//! use tessera_ui::{locale::Locale, provide_context, tessera, use_context};
+//!
+//! #[tessera]
+//! fn app() {
+//!     provide_context(
+//!         || Locale::new("zh-CN"),
+//!         || {
+//!             let locale = use_context::<Locale>().expect("Locale must be provided");
+//!             assert_eq!(locale.get().language(), "zh");
+//!         },
+//!     );
+//! }
+//! ```
+//!
+//! String resources are `.ftl` files compiled by `tessera-build` into typed
+//! accessors. Accessors resolve the [`current_locale`] and use the plural
+//! rules and number formats defined here.
+
+use std::{fmt, sync::Arc};
+
+use parking_lot::RwLock;
+
+use crate::{
+    context::use_context,
+    runtime::{RuntimePhase, current_phase},
+};
+
+static DEFAULT_LOCALE: RwLock<Option<Locale>> = RwLock::new(None);
+
+/// A BCP 47 language tag such as `en-US` or `zh-Hans-CN`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Locale {
+    tag: Arc<str>,
+}
+
+impl Locale {
+    /// Creates a locale from a language tag.
+    ///
+    /// POSIX forms such as `pt_BR.UTF-8` are accepted and normalized to
+    /// `pt-BR`.
+    pub fn new(tag: impl AsRef<str>) -> Self {
+        let tag = tag.as_ref();
+        let tag = tag.split(['.', '@']).next().unwrap_or_default();
+        let normalized = tag
+            .split(['-', '_'])
+            .filter(|subtag| !subtag.is_empty())
+            .enumerate()
+            .map(|(index, subtag)| match (index, subtag.len()) {
+                (0, _) => subtag.to_ascii_lowercase(),
+                (_, 2) => subtag.to_ascii_uppercase(),
+                (_, 4) => {
+                    let mut script = subtag.to_ascii_lowercase();
+                    script[..1].make_ascii_uppercase();
+                    script
+                }
+                _ => subtag.to_ascii_lowercase(),
+            })
+            .collect::<Vec<_>>()
+            .join("-");
+        if normalized.is_empty() {
+            return Self::default();
+        }
+        Self {
+            tag: normalized.into(),
+        }
+    }
+
+    /// Returns the locale configured for the current process.
+    ///
+    /// Reads `LC_ALL`, `LC_MESSAGES` and `LANG`, falling back to `en-US`.
+    pub fn system() -> Self {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty() && value != "C" && value != "POSIX")
+            .map(Self::new)
+            .unwrap_or_default()
+    }
+
+    /// Returns the normalized language tag.
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Returns the language subtag, e.g. `en`.
+    pub fn language(&self) -> &str {
+        self.tag.split('-').next().unwrap_or_default()
+    }
+
+    /// Returns the region subtag, e.g. `US`.
+    pub fn region(&self) -> Option<&str> {
+        self.tag
+            .split('-')
+            .skip(1)
+            .find(|subtag| subtag.len() == 2 || subtag.chars().all(|ch| ch.is_ascii_digit()))
+    }
+
+    /// Picks the best match for this locale from `available` tags.
+    ///
+    /// Exact matches win, then tags sharing the language. Returns `None` when
+    /// no tag shares the language.
+    pub fn resolve(&self, available: &[&str]) -> Option<usize> {
+        let exact = available
+            .iter()
+            .position(|tag| tag.eq_ignore_ascii_case(self.tag()));
+        exact.or_else(|| {
+            available
+                .iter()
+                .position(|tag| Locale::new(tag).language() == self.language())
+        })
+    }
+
+    /// Returns the CLDR cardinal plural category of `value`.
+    pub fn plural_category(&self, value: f64) -> PluralCategory {
+        let value = value.abs();
+        let i = value.trunc() as u64;
+        let has_fraction = value.fract() != 0.0;
+        let (i10, i100) = (i % 10, i % 100);
+        let integer = |n: u64| !has_fraction && i == n;
+
+        match self.language() {
+            "ja" | "zh" | "ko" | "vi" | "th" | "id" | "ms" | "my" | "lo" | "km" | "yue" => {
+                PluralCategory::Other
+            }
+            "fr" if i <= 1 => PluralCategory::One,
+            "pt" if self.region() != Some("PT") && i <= 1 => PluralCategory::One,
+            "ru" | "uk" | "be" if !has_fraction => {
+                if i10 == 1 && i100 != 11 {
+                    PluralCategory::One
+                } else if (2..=4).contains(&i10) && !(12..=14).contains(&i100) {
+                    PluralCategory::Few
+                } else {
+                    PluralCategory::Many
+                }
+            }
+            "pl" if !has_fraction => {
+                if i == 1 {
+                    PluralCategory::One
+                } else if (2..=4).contains(&i10) && !(12..=14).contains(&i100) {
+                    PluralCategory::Few
+                } else {
+                    PluralCategory::Many
+                }
+            }
+            "cs" | "sk" => match i {
+                _ if has_fraction => PluralCategory::Many,
+                1 => PluralCategory::One,
+                2..=4 => PluralCategory::Few,
+                _ => PluralCategory::Other,
+            },
+            "ar" if !has_fraction => match (i, i100) {
+                (0, _) => PluralCategory::Zero,
+                (1, _) => PluralCategory::One,
+                (2, _) => PluralCategory::Two,
+                (_, 3..=10) => PluralCategory::Few,
+                (_, 11..=99) => PluralCategory::Many,
+                _ => PluralCategory::Other,
+            },
+            "he" if integer(2) => PluralCategory::Two,
+            _ if integer(1) => PluralCategory::One,
+            _ => PluralCategory::Other,
+        }
+    }
+
+    /// Formats a number with this locale's decimal and grouping separators,
+    /// keeping up to three fraction digits.
+    pub fn format_number(&self, value: f64) -> String {
+        if !value.is_finite() {
+            return value.to_string();
+        }
+        let (decimal, group) = self.number_separators();
+        let rounded = format!("{:.3}", value.abs());
+        let (integer, fraction) = rounded.split_once('.').unwrap_or((&rounded, ""));
+        let fraction = fraction.trim_end_matches('0');
+
+        let mut formatted = String::new();
+        if value < 0.0 && (integer != "0" || !fraction.is_empty()) {
+            formatted.push('-');
+        }
+        for (index, digit) in integer.chars().enumerate() {
+            if index > 0 && (integer.len() - index) % 3 == 0 {
+                formatted.push_str(group);
+            }
+            formatted.push(digit);
+        }
+        if !fraction.is_empty() {
+            formatted.push_str(decimal);
+            formatted.push_str(fraction);
+        }
+        formatted
+    }
+
+    /// Returns `true` when calendars in this locale start weeks on Sunday.
+    pub fn week_starts_on_sunday(&self) -> bool {
+        match self.region() {
+            Some(region) => matches!(
+                region,
+                "US" | "CA" | "MX" | "BR" | "JP" | "KR" | "CN" | "TW" | "HK" | "IL" | "PH" | "IN"
+            ),
+            None => matches!(self.language(), "ja" | "ko" | "zh" | "he"),
+        }
+    }
+
+    fn number_separators(&self) -> (&'static str, &'static str) {
+        match self.language() {
+            "de" | "es" | "it" | "nl" | "pt" | "id" | "tr" | "da" | "el" => (",", "."),
+            "fr" => (",", "\u{202f}"),
+            "ru" | "uk" | "be" | "pl" | "cs" | "sk" | "fi" | "sv" | "nb" | "bg" | "hu" => {
+                (",", "\u{a0}")
+            }
+            _ => (".", ","),
+        }
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self {
+            tag: Arc::from("en-US"),
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.tag)
+    }
+}
+
+/// CLDR plural categories used by select expressions in string resources.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    /// `zero`
+    Zero,
+    /// `one`
+    One,
+    /// `two`
+    Two,
+    /// `few`
+    Few,
+    /// `many`
+    Many,
+    /// `other`
+    Other,
+}
+
+impl PluralCategory {
+    /// Returns the category name used as a variant key.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Zero => "zero",
+            Self::One => "one",
+            Self::Two => "two",
+            Self::Few => "few",
+            Self::Many => "many",
+            Self::Other => "other",
+        }
+    }
+}
+
+/// An argument passed to a generated string accessor.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MessageArg {
+    /// A number, formatted and plural-selected per locale.
+    Number(f64),
+    /// Text inserted verbatim and matched exactly by select expressions.
+    Text(String),
+}
+
+impl MessageArg {
+    /// Formats the argument for display in `locale`.
+    pub fn format(&self, locale: &Locale) -> String {
+        match self {
+            Self::Number(value) => locale.format_number(*value),
+            Self::Text(text) => text.clone(),
+        }
+    }
+}
+
+macro_rules! impl_number_message_arg {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for MessageArg {
+                fn from(value: $ty) -> Self {
+                    Self::Number(value as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_number_message_arg!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+impl From<&str> for MessageArg {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_owned())
+    }
+}
+
+impl From<String> for MessageArg {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&String> for MessageArg {
+    fn from(value: &String) -> Self {
+        Self::Text(value.clone())
+    }
+}
+
+/// Returns the locale used to resolve string resources.
+///
+/// During component builds this reads the nearest [`Locale`] context and
+/// subscribes the component to it. Elsewhere, or when no context is provided,
+/// the [`default_locale`] is used.
+pub fn current_locale() -> Locale {
+    if current_phase() == Some(RuntimePhase::Build)
+        && let Some(locale) = use_context::<Locale>()
+    {
+        return locale.get();
+    }
+    default_locale()
+}
+
+/// Returns the locale used outside a [`Locale`] context.
+///
+/// Defaults to [`Locale::system`].
+pub fn default_locale() -> Locale {
+    if let Some(locale) = DEFAULT_LOCALE.read().as_ref() {
+        return locale.clone();
+    }
+    DEFAULT_LOCALE
+        .write()
+        .get_or_insert_with(Locale::system)
+        .clone()
+}
+
+/// Sets the locale used outside a [`Locale`] context.
+pub fn set_default_locale(locale: Locale) {
+    *DEFAULT_LOCALE.write() = Some(locale);
+}
+
+/// Picks the variant of a select expression for `selector`.
+///
+/// Numbers match numeric keys exactly, then their plural category. Text
+/// matches keys exactly. Returns `default` when nothing matches.
+#[doc(hidden)]
+pub fn select_message_variant(
+    locale: &Locale,
+    selector: &MessageArg,
+    keys: &[&str],
+    default: usize,
+) -> usize {
+    let position = match selector {
+        MessageArg::Number(value) => keys
+            .iter()
+            .position(|key| key.parse::<f64>().is_ok_and(|key| key == *value))
+            .or_else(|| {
+                let category = locale.plural_category(*value).as_str();
+                keys.iter().position(|key| *key == category)
+            }),
+        MessageArg::Text(text) => keys.iter().position(|key| key == text),
+    };
+    position.unwrap_or(default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_language_tags() {
+        assert_eq!(Locale::new("pt_br.UTF-8").tag(), "pt-BR");
+        assert_eq!(Locale::new("zh-hans-cn").tag(), "zh-Hans-CN");
+        assert_eq!(Locale::new("zh-Hans-CN").region(), Some("CN"));
+        assert_eq!(Locale::new("").tag(), "en-US");
+    }
+
+    #[test]
+    fn resolves_exact_then_language_matches() {
+        let available = ["en", "zh-CN", "zh-TW"];
+        assert_eq!(Locale::new("zh-TW").resolve(&available), Some(2));
+        assert_eq!(Locale::new("zh-SG").resolve(&available), Some(1));
+        assert_eq!(Locale::new("en-GB").resolve(&available), Some(0));
+        assert_eq!(Locale::new("fr").resolve(&available), None);
+    }
+
+    #[test]
+    fn applies_plural_rules() {
+        let en = Locale::new("en");
+        assert_eq!(en.plural_category(1.0), PluralCategory::One);
+        assert_eq!(en.plural_category(1.5), PluralCategory::Other);
+        let ru = Locale::new("ru");
+        assert_eq!(ru.plural_category(21.0), PluralCategory::One);
+        assert_eq!(ru.plural_category(23.0), PluralCategory::Few);
+        assert_eq!(ru.plural_category(11.0), PluralCategory::Many);
+        assert_eq!(
+            Locale::new("zh").plural_category(1.0),
+            PluralCategory::Other
+        );
+    }
+
+    #[test]
+    fn formats_numbers_per_locale() {
+        assert_eq!(Locale::new("en").format_number(1234567.5), "1,234,567.5");
+        assert_eq!(Locale::new("de").format_number(-1234.25), "-1.234,25");
+        assert_eq!(Locale::new("en").format_number(2.0), "2");
+    }
+
+    #[test]
+    fn selects_numeric_keys_before_plural_categories() {
+        let en = Locale::new("en");
+        let keys = ["0", "one", "other"];
+        assert_eq!(select_message_variant(&en, &0.into(), &keys, 2), 0);
+        assert_eq!(select_message_variant(&en, &1.into(), &keys, 2), 1);
+        assert_eq!(select_message_variant(&en, &5.into(), &keys, 2), 2);
+        assert_eq!(select_message_variant(&en, &"mon".into(), &["mon"], 0), 0);
+    }
+}
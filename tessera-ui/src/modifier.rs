@@ -7,7 +7,7 @@
 //! component node.
 
 use std::{
-    any::{Any, TypeId},
+    any::{Any, TypeId, type_name},
     collections::HashMap,
     fmt,
     hash::{Hash, Hasher},
@@ -17,13 +17,15 @@ use std::{
 use smallvec::SmallVec;
 
 use crate::{
-    AccessibilityActionHandler, AccessibilityNode, ComputedData, Constraint, FocusGroupNode,
-    FocusProperties, FocusRequester, FocusScopeNode, FocusState, FocusTraversalPolicy, ImeInput,
-    KeyboardInput, MeasurementError, PointerInput, PxPosition,
+    AccessibilityActionHandler, AccessibilityActionHandlers, AccessibilityNode,
+    AccessibilityTextActionHandler, ComputedData, Constraint, FocusGroupNode, FocusProperties,
+    FocusRequester, FocusScopeNode, FocusState, FocusTraversalPolicy, ImeInput, KeyboardInput,
+    MeasurementError, PointerInput, PxPosition,
     focus::{FocusDirection, FocusNode, FocusRevealRequest},
     layout::{LayoutInput, RenderInput},
-    prop::CallbackWith,
+    prop::{Callback, CallbackWith},
     runtime::{TesseraRuntime, ensure_build_phase},
+    shortcut::CommandId,
     winit::window::CursorIcon,
 };
 
@@ -106,6 +108,16 @@ pub trait SemanticsModifierNode: Send + Sync + 'static {
         accessibility: &mut AccessibilityNode,
         action_handler: &mut Option<AccessibilityActionHandler>,
     );
+
+    /// Registers typed handlers for standard and custom actions.
+    ///
+    /// Later modifiers in the chain replace handlers for the same action.
+    fn apply_action_handlers(&self, _handlers: &mut AccessibilityActionHandlers) {}
+
+    /// Installs a handler for text selection and editing actions.
+    ///
+    /// Only nodes that record laid-out text need to override this.
+    fn apply_text_actions(&self, _handler: &mut Option<AccessibilityTextActionHandler>) {}
 }
 
 /// A node-local pointer input modifier.
@@ -331,6 +343,14 @@ enum ModifierAction {
     ImePreviewInput(Arc<dyn ImeInputModifierNode>),
     ImeInput(Arc<dyn ImeInputModifierNode>),
     Focus(FocusModifierOp),
+    Command(CommandHandler),
+}
+
+/// A command handler registered on a node.
+#[derive(Clone)]
+struct CommandHandler {
+    command: CommandId,
+    handler: Callback,
 }
 
 #[derive(Clone)]
@@ -353,6 +373,8 @@ pub(crate) enum OrderedModifierAction {
 struct ModifierLink {
     prev: Option<Arc<ModifierLink>>,
     action: ModifierAction,
+    /// Type name of the node that produced `action`, for debugging tools.
+    type_name: &'static str,
 }
 
 fn collect_actions(mut node: Option<Arc<ModifierLink>>) -> Vec<ModifierAction> {
@@ -431,6 +453,17 @@ pub trait FocusModifierExt {
         F: Into<CallbackWith<FocusRevealRequest, bool>>;
 }
 
+/// Command-specific modifier extensions for [`Modifier`].
+pub trait CommandModifierExt {
+    /// Handles `command` while focus is inside this subtree.
+    ///
+    /// When a shortcut bound to `command` is typed, the handler nearest to
+    /// the focused node runs. See [`crate::shortcut`] for bindings.
+    fn on_command<F>(self, command: impl Into<CommandId>, handler: F) -> Modifier
+    where
+        F: Into<Callback>;
+}
+
 /// Cursor-specific modifier extensions for [`Modifier`].
 pub trait CursorModifierExt {
     /// Sets the cursor icon used while the pointer hovers this node.
@@ -444,21 +477,27 @@ impl Modifier {
         Self::default()
     }
 
-    fn push_action(self, action: ModifierAction) -> Self {
+    fn push_action(self, action: ModifierAction, type_name: &'static str) -> Self {
         ensure_build_phase();
         Self {
             tail: Some(Arc::new(ModifierLink {
                 prev: self.tail,
                 action,
+                type_name,
             })),
         }
     }
 
     /// Appends another modifier chain after this one.
     pub fn then(mut self, other: Modifier) -> Self {
-        let actions = collect_actions(other.tail);
-        for action in actions.into_iter().rev() {
-            self = self.push_action(action);
+        let mut links: SmallVec<[Arc<ModifierLink>; 8]> = SmallVec::new();
+        let mut node = other.tail;
+        while let Some(current) = node {
+            node = current.prev.clone();
+            links.push(current);
+        }
+        for link in links.into_iter().rev() {
+            self = self.push_action(link.action.clone(), link.type_name);
         }
         self
     }
@@ -467,106 +506,126 @@ impl Modifier {
     where
         N: LayoutModifierNode + PartialEq,
     {
-        self.push_action(ModifierAction::Layout(Arc::new(
-            ComparableLayoutModifierNode {
+        self.push_action(
+            ModifierAction::Layout(Arc::new(ComparableLayoutModifierNode {
                 node: Arc::new(node),
-            },
-        )))
+            })),
+            type_name::<N>(),
+        )
     }
 
     pub(crate) fn push_placement<N>(self, node: N) -> Self
     where
         N: PlacementModifierNode + PartialEq,
     {
-        self.push_action(ModifierAction::Placement(Arc::new(
-            ComparablePlacementModifierNode {
+        self.push_action(
+            ModifierAction::Placement(Arc::new(ComparablePlacementModifierNode {
                 node: Arc::new(node),
-            },
-        )))
+            })),
+            type_name::<N>(),
+        )
     }
 
     pub(crate) fn push_draw<N>(self, node: N) -> Self
     where
         N: DrawModifierNode,
     {
-        self.push_action(ModifierAction::Draw(Arc::new(node)))
+        self.push_action(ModifierAction::Draw(Arc::new(node)), type_name::<N>())
     }
 
     pub(crate) fn push_parent_data<N>(self, node: N) -> Self
     where
         N: ParentDataModifierNode,
     {
-        self.push_action(ModifierAction::ParentData(Arc::new(node)))
+        self.push_action(ModifierAction::ParentData(Arc::new(node)), type_name::<N>())
     }
 
     pub(crate) fn push_build<N>(self, node: N) -> Self
     where
         N: BuildModifierNode,
     {
-        self.push_action(ModifierAction::Build(Arc::new(node)))
+        self.push_action(ModifierAction::Build(Arc::new(node)), type_name::<N>())
     }
 
     pub(crate) fn push_semantics<N>(self, node: N) -> Self
     where
         N: SemanticsModifierNode,
     {
-        self.push_action(ModifierAction::Semantics(Arc::new(node)))
+        self.push_action(ModifierAction::Semantics(Arc::new(node)), type_name::<N>())
     }
 
     fn push_cursor<N>(self, node: N) -> Self
     where
         N: CursorModifierNode,
     {
-        self.push_action(ModifierAction::Cursor(Arc::new(node)))
+        self.push_action(ModifierAction::Cursor(Arc::new(node)), type_name::<N>())
     }
 
     pub(crate) fn push_pointer_preview_input<N>(self, node: N) -> Self
     where
         N: PointerInputModifierNode,
     {
-        self.push_action(ModifierAction::PointerPreviewInput(Arc::new(node)))
+        self.push_action(
+            ModifierAction::PointerPreviewInput(Arc::new(node)),
+            type_name::<N>(),
+        )
     }
 
     pub(crate) fn push_pointer_input<N>(self, node: N) -> Self
     where
         N: PointerInputModifierNode,
     {
-        self.push_action(ModifierAction::PointerInput(Arc::new(node)))
+        self.push_action(
+            ModifierAction::PointerInput(Arc::new(node)),
+            type_name::<N>(),
+        )
     }
 
     pub(crate) fn push_pointer_final_input<N>(self, node: N) -> Self
     where
         N: PointerInputModifierNode,
     {
-        self.push_action(ModifierAction::PointerFinalInput(Arc::new(node)))
+        self.push_action(
+            ModifierAction::PointerFinalInput(Arc::new(node)),
+            type_name::<N>(),
+        )
     }
 
     pub(crate) fn push_keyboard_preview_input<N>(self, node: N) -> Self
     where
         N: KeyboardInputModifierNode,
     {
-        self.push_action(ModifierAction::KeyboardPreviewInput(Arc::new(node)))
+        self.push_action(
+            ModifierAction::KeyboardPreviewInput(Arc::new(node)),
+            type_name::<N>(),
+        )
     }
 
     pub(crate) fn push_keyboard_input<N>(self, node: N) -> Self
     where
         N: KeyboardInputModifierNode,
     {
-        self.push_action(ModifierAction::KeyboardInput(Arc::new(node)))
+        self.push_action(
+            ModifierAction::KeyboardInput(Arc::new(node)),
+            type_name::<N>(),
+        )
     }
 
     pub(crate) fn push_ime_preview_input<N>(self, node: N) -> Self
     where
         N: ImeInputModifierNode,
     {
-        self.push_action(ModifierAction::ImePreviewInput(Arc::new(node)))
+        self.push_action(
+            ModifierAction::ImePreviewInput(Arc::new(node)),
+            type_name::<N>(),
+        )
     }
 
     pub(crate) fn push_ime_input<N>(self, node: N) -> Self
     where
         N: ImeInputModifierNode,
     {
-        self.push_action(ModifierAction::ImeInput(Arc::new(node)))
+        self.push_action(ModifierAction::ImeInput(Arc::new(node)), type_name::<N>())
     }
 
     fn push_focus_requester(self, requester: FocusRequester) -> Self {
@@ -644,7 +703,24 @@ impl Modifier {
     }
 
     fn push_focus_op(self, op: FocusModifierOp) -> Self {
-        self.push_action(ModifierAction::Focus(op))
+        self.push_action(ModifierAction::Focus(op), "focus")
+    }
+
+    fn push_command_handler(self, command: CommandId, handler: Callback) -> Self {
+        self.push_action(
+            ModifierAction::Command(CommandHandler { command, handler }),
+            "command",
+        )
+    }
+
+    /// Returns the handler for `command`; later modifiers in the chain win.
+    pub(crate) fn command_handler(&self, command: &CommandId) -> Option<Callback> {
+        collect_actions(self.tail.clone())
+            .into_iter()
+            .find_map(|action| match action {
+                ModifierAction::Command(entry) if entry.command == *command => Some(entry.handler),
+                _ => None,
+            })
     }
 
     /// Attaches this modifier chain to the current component node.
@@ -658,6 +734,8 @@ impl Modifier {
 
         let mut accessibility = AccessibilityNode::new();
         let mut action_handler = None;
+        let mut action_handlers = AccessibilityActionHandlers::default();
+        let mut text_action_handler = None;
         let mut has_semantics = false;
         for action in actions.into_iter().rev() {
             match action {
@@ -667,6 +745,8 @@ impl Modifier {
                 ModifierAction::Semantics(node) => {
                     has_semantics = true;
                     node.apply(&mut accessibility, &mut action_handler);
+                    node.apply_action_handlers(&mut action_handlers);
+                    node.apply_text_actions(&mut text_action_handler);
                 }
                 ModifierAction::Focus(op) => {
                     TesseraRuntime::with_mut(|runtime| {
@@ -680,6 +760,8 @@ impl Modifier {
         TesseraRuntime::with_mut(|runtime| {
             runtime.set_current_accessibility(has_semantics.then_some(accessibility));
             runtime.set_current_accessibility_action_handler(action_handler);
+            runtime.set_current_accessibility_action_handlers(action_handlers);
+            runtime.set_current_accessibility_text_action_handler(text_action_handler);
         });
     }
 
@@ -697,6 +779,56 @@ impl Modifier {
         self.tail.is_none()
     }
 
+    /// Describes each node of this chain in source order, for the inspector.
+    #[cfg(feature = "inspector")]
+    pub(crate) fn debug_chain(&self) -> Vec<String> {
+        let mut entries = Vec::new();
+        let mut node = self.tail.clone();
+        while let Some(current) = node {
+            let kind = match &current.action {
+                ModifierAction::Layout(_) => "layout",
+                ModifierAction::Placement(_) => "placement",
+                ModifierAction::Draw(_) => "draw",
+                ModifierAction::ParentData(_) => "parent_data",
+                ModifierAction::Build(_) => "build",
+                ModifierAction::Semantics(_) => "semantics",
+                ModifierAction::Cursor(_) => "cursor",
+                ModifierAction::PointerPreviewInput(_) => "pointer_preview_input",
+                ModifierAction::PointerInput(_) => "pointer_input",
+                ModifierAction::PointerFinalInput(_) => "pointer_final_input",
+                ModifierAction::KeyboardPreviewInput(_) => "keyboard_preview_input",
+                ModifierAction::KeyboardInput(_) => "keyboard_input",
+                ModifierAction::ImePreviewInput(_) => "ime_preview_input",
+                ModifierAction::ImeInput(_) => "ime_input",
+                ModifierAction::Focus(op) => match op {
+                    FocusModifierOp::Requester(_) => "focus_requester",
+                    FocusModifierOp::Registration(_) => "focus_registration",
+                    FocusModifierOp::Properties(_) => "focus_properties",
+                    FocusModifierOp::TraversalPolicy(_) => "focus_traversal_policy",
+                    FocusModifierOp::ChangedHandler(_) => "focus_changed_handler",
+                    FocusModifierOp::EventHandler(_) => "focus_event_handler",
+                    FocusModifierOp::BeyondBoundsHandler(_) => "focus_beyond_bounds_handler",
+                    FocusModifierOp::RevealHandler(_) => "focus_reveal_handler",
+                },
+                ModifierAction::Command(_) => "command",
+            };
+            let short_name = current
+                .type_name
+                .split('<')
+                .next()
+                .and_then(|path| path.rsplit("::").next())
+                .unwrap_or(current.type_name);
+            if short_name == "focus" || short_name == "command" {
+                entries.push(kind.to_string());
+            } else {
+                entries.push(format!("{kind}({short_name})"));
+            }
+            node = current.prev.clone();
+        }
+        entries.reverse();
+        entries
+    }
+
     pub(crate) fn ordered_actions(&self) -> Vec<OrderedModifierAction> {
         collect_actions_in_source_order(self.tail.clone())
             .into_iter()
@@ -727,7 +859,8 @@ impl Modifier {
                 ModifierAction::ImeInput(node) => Some(OrderedModifierAction::ImeInput(node)),
                 ModifierAction::Build(_)
                 | ModifierAction::Semantics(_)
-                | ModifierAction::Focus(_) => None,
+                | ModifierAction::Focus(_)
+                | ModifierAction::Command(_) => None,
             })
             .collect()
     }
@@ -917,6 +1050,15 @@ impl CursorModifierExt for Modifier {
     }
 }
 
+impl CommandModifierExt for Modifier {
+    fn on_command<F>(self, command: impl Into<CommandId>, handler: F) -> Modifier
+    where
+        F: Into<Callback>,
+    {
+        self.push_command_handler(command.into(), handler.into())
+    }
+}
+
 impl FocusModifierExt for Modifier {
     fn focusable(self) -> Modifier {
         self.push_focus_target()
@@ -4,6 +4,12 @@
 //!
 //! Stream JSONL frame timing for external analyzer tools.
 //!
+//! Records are written to the file set by [`set_output_path`]. When a stream
+//! address is set through [`set_stream_address`] (or the
+//! `TESSERA_PROFILING_STREAM` environment variable), the same records are sent
+//! to that local TCP socket instead, which is what
+//! `cargo tessera profiling live` listens on.
+//!
 //! ## Format
 //!
 //! Here is an example of the output file:
@@ -19,6 +25,7 @@
 //!   "frame": 0,
 //!   "build_mode": "partial_replay",
 //!   "redraw_reasons": ["mouse_input"],
+//!   "start_ns": 1204000,
 //!   "inter_frame_wait_ns": 16564000,
 //!   "render_time_ns": 1349800,
 //!   "frame_total_ns": 57486200,
@@ -96,12 +103,18 @@
 //! present, it indicates whether the layout cache was hit for that node in the
 //! current frame.
 //!
+//! `start_ns` on frame records, `time_ns` on wake records and `build_start_ns`
+//! in component phases are timestamps relative to profiler start. They allow
+//! the stream to be laid out on a timeline, e.g. when exporting to the Chrome
+//! trace event format.
+//!
 //! See [`FrameEventRecord`] and [`ComponentRecord`] for equivalent Rust
 //! structures.
 use std::{
     collections::HashMap,
-    fs::{File, OpenOptions, create_dir_all},
+    fs::{OpenOptions, create_dir_all},
     io::{BufWriter, Write},
+    net::TcpStream,
     path::{Path, PathBuf},
     sync::{
         OnceLock,
@@ -119,6 +132,8 @@ use tracing::error;
 
 use crate::{component_tree::LayoutFrameDiagnostics, time::Instant};
 
+pub use crate::redraw_reason::{RedrawReason, WakeSource};
+
 /// Profiling phases that can be emitted.
 #[derive(Clone, Copy)]
 pub enum Phase {
@@ -146,52 +161,6 @@ pub enum BuildMode {
     SkipNoInvalidation,
 }
 
-/// Reason that caused a redraw request.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize)]
-#[serde(rename_all = "snake_case")]
-pub enum RedrawReason {
-    /// Initial frame request during startup/resume.
-    Startup,
-    /// Window resize event.
-    WindowResized,
-    /// Cursor move event.
-    CursorMoved,
-    /// Cursor leave event.
-    CursorLeft,
-    /// Mouse button input event.
-    MouseInput,
-    /// Mouse wheel event.
-    MouseWheel,
-    /// Touch event.
-    TouchInput,
-    /// Scale factor change event.
-    ScaleFactorChanged,
-    /// Keyboard input event.
-    KeyboardInput,
-    /// Keyboard modifier state change event.
-    ModifiersChanged,
-    /// IME event.
-    ImeEvent,
-    /// Focus change event.
-    FocusChanged,
-    /// Runtime state invalidation requires next frame.
-    RuntimeInvalidation,
-    /// Frame awaiter callback requires next frame.
-    RuntimeFrameAwaiter,
-}
-
-/// Source category for redraw wake events.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize)]
-#[serde(rename_all = "snake_case")]
-pub enum WakeSource {
-    /// Triggered by the renderer lifecycle.
-    Lifecycle,
-    /// Triggered by a window/input event.
-    WindowEvent,
-    /// Triggered by runtime pending work.
-    Runtime,
-}
-
 /// Runtime lifecycle event kind emitted by profiler.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -240,6 +209,8 @@ pub struct FrameMeta {
     pub build_mode: BuildMode,
     /// Redraw reasons that woke this frame.
     pub redraw_reasons: Vec<RedrawReason>,
+    /// Time at which the frame started.
+    pub started_at: Instant,
     /// Time spent waiting between this and the previous frame.
     pub inter_frame_wait_ns: Option<u128>,
     /// Number of nodes replayed by partial build in this frame.
@@ -292,7 +263,7 @@ pub struct RuntimeMeta {
 /// A minimal frame event written to the profiler output:
 ///
 /// ```json
-/// {"type":"frame","frame":1,"build_mode":"root_recompose","redraw_reasons":["startup"],"render_time_ns":1000000,"frame_total_ns":2000000,"components":[{"id":"1","fn_name":"root","abs_pos":{"x":0,"y":0},"size":{"w":100,"h":50},"layout_cache_hit":true,"phases":{"build_ns":5000},"children":[]}]}
+/// {"type":"frame","frame":1,"build_mode":"root_recompose","redraw_reasons":["startup"],"start_ns":0,"render_time_ns":1000000,"frame_total_ns":2000000,"components":[{"id":"1","fn_name":"root","abs_pos":{"x":0,"y":0},"size":{"w":100,"h":50},"layout_cache_hit":true,"phases":{"build_ns":5000},"children":[]}]}
 /// ```
 #[expect(
     clippy::large_enum_variant,
@@ -301,7 +272,7 @@ pub struct RuntimeMeta {
 enum Message {
     Sample(Sample),
     FrameMeta(FrameMeta),
-    WakeMeta(WakeMeta),
+    WakeMeta(WakeMeta, Instant),
     RuntimeMeta(RuntimeMeta),
 }
 
@@ -311,13 +282,15 @@ struct ProfilerRuntime {
 
 struct WorkerState {
     frames: HashMap<u64, Vec<Sample>>,
-    writer: BufWriter<File>,
+    writer: BufWriter<Box<dyn Write + Send>>,
     header_written: bool,
 }
 
 static RUNTIME: OnceLock<ProfilerRuntime> = OnceLock::new();
 static FRAME_INDEX: AtomicU64 = AtomicU64::new(0);
 static OUTPUT_PATH: OnceLock<PathBuf> = OnceLock::new();
+static STREAM_ADDRESS: OnceLock<String> = OnceLock::new();
+static EPOCH: OnceLock<Instant> = OnceLock::new();
 
 fn output_path() -> PathBuf {
     OUTPUT_PATH
@@ -331,8 +304,26 @@ pub fn set_output_path(path: impl AsRef<Path>) {
     let _ = OUTPUT_PATH.set(path.as_ref().to_path_buf());
 }
 
+/// Stream profiler records to a local TCP socket instead of the output file.
+/// Must be called before any profiling begins.
+///
+/// If the connection fails, records fall back to the output file.
+pub fn set_stream_address(addr: impl Into<String>) {
+    let _ = STREAM_ADDRESS.set(addr.into());
+}
+
+/// Time the profiler started, used as the origin of all record timestamps.
+fn epoch() -> Instant {
+    *EPOCH.get_or_init(Instant::now)
+}
+
+fn nanos_since_epoch(instant: Instant) -> u128 {
+    instant.saturating_duration_since(epoch()).as_nanos()
+}
+
 fn profiler_runtime() -> &'static ProfilerRuntime {
     RUNTIME.get_or_init(|| {
+        epoch();
         let (sender, receiver) = mpsc::channel::<Message>();
         let _ = thread::Builder::new()
             .name("tessera-profiler".to_string())
@@ -342,7 +333,19 @@ fn profiler_runtime() -> &'static ProfilerRuntime {
     })
 }
 
-fn worker_loop(receiver: mpsc::Receiver<Message>) {
+fn open_output() -> Option<Box<dyn Write + Send>> {
+    if let Some(addr) = STREAM_ADDRESS.get() {
+        match TcpStream::connect(addr) {
+            Ok(stream) => {
+                let _ = stream.set_nodelay(true);
+                return Some(Box::new(stream));
+            }
+            Err(err) => {
+                error!("tessera profiler failed to connect to stream at {addr}: {err}");
+            }
+        }
+    }
+
     let mut options = OpenOptions::new();
     options.create(true).write(true).truncate(true);
 
@@ -355,21 +358,27 @@ fn worker_loop(receiver: mpsc::Receiver<Message>) {
             "tessera profiler failed to create output directory {}: {err}",
             parent.display()
         );
-        return;
+        return None;
     }
-    let file = match options.open(&output_path) {
-        Ok(file) => file,
+    match options.open(&output_path) {
+        Ok(file) => Some(Box::new(file)),
         Err(err) => {
             error!(
                 "tessera profiler failed to open output file {}: {err}",
                 output_path.display()
             );
-            return;
+            None
         }
+    }
+}
+
+fn worker_loop(receiver: mpsc::Receiver<Message>) {
+    let Some(output) = open_output() else {
+        return;
     };
     let mut state = WorkerState {
         frames: HashMap::new(),
-        writer: BufWriter::new(file),
+        writer: BufWriter::new(output),
         header_written: false,
     };
 
@@ -389,9 +398,10 @@ fn worker_loop(receiver: mpsc::Receiver<Message>) {
                     .unwrap_or_default();
                 flush_frame(&mut state, frame_meta, samples);
             }
-            Message::WakeMeta(wake_meta) => {
+            Message::WakeMeta(wake_meta, requested_at) => {
                 let event = TraceEvent::Wake(WakeEventRecord {
                     frame: wake_meta.frame_idx,
+                    time_ns: nanos_since_epoch(requested_at),
                     source: wake_meta.source,
                     reasons: wake_meta.reasons,
                 });
@@ -499,6 +509,8 @@ pub struct FrameEventRecord {
     build_mode: BuildMode,
     /// Redraw reasons that woke this frame.
     redraw_reasons: Vec<RedrawReason>,
+    /// Frame start time relative to profiler start.
+    start_ns: u128,
     /// Time spent waiting between this and the previous frame.
     #[serde(skip_serializing_if = "Option::is_none")]
     inter_frame_wait_ns: Option<u128>,
@@ -544,6 +556,8 @@ pub struct FrameEventRecord {
 struct WakeEventRecord {
     /// Frame index associated with this wake request.
     frame: u64,
+    /// Request time relative to profiler start.
+    time_ns: u128,
     /// Source category that requested the redraw.
     source: WakeSource,
     /// Wake reasons for this request.
@@ -627,6 +641,8 @@ struct Size {
 
 #[derive(Serialize, Default)]
 struct PhaseDurations {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    build_start_ns: Option<u128>,
     #[serde(skip_serializing_if = "Option::is_none")]
     build_ns: Option<u128>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -746,6 +762,13 @@ fn build_frame_record(frame_meta: FrameMeta, samples: Vec<Sample>) -> Option<Fra
 
         match sample.phase {
             Phase::Build => {
+                let start_ns = nanos_since_epoch(sample.start);
+                entry.phases.build_start_ns = Some(
+                    entry
+                        .phases
+                        .build_start_ns
+                        .map_or(start_ns, |current| current.min(start_ns)),
+                );
                 entry.phases.build_ns = Some(entry.phases.build_ns.unwrap_or(0) + duration_ns);
             }
             Phase::Measure => {
@@ -766,6 +789,7 @@ fn build_frame_record(frame_meta: FrameMeta, samples: Vec<Sample>) -> Option<Fra
             frame: frame_meta.frame_idx,
             build_mode: frame_meta.build_mode,
             redraw_reasons: frame_meta.redraw_reasons,
+            start_ns: nanos_since_epoch(frame_meta.started_at),
             inter_frame_wait_ns: frame_meta.inter_frame_wait_ns,
             partial_replay_nodes: frame_meta.partial_replay_nodes,
             total_nodes_before_build: frame_meta.total_nodes_before_build,
@@ -834,6 +858,7 @@ fn build_frame_record(frame_meta: FrameMeta, samples: Vec<Sample>) -> Option<Fra
         frame: frame_meta.frame_idx,
         build_mode: frame_meta.build_mode,
         redraw_reasons: frame_meta.redraw_reasons,
+        start_ns: nanos_since_epoch(frame_meta.started_at),
         inter_frame_wait_ns: frame_meta.inter_frame_wait_ns,
         partial_replay_nodes: frame_meta.partial_replay_nodes,
         total_nodes_before_build: frame_meta.total_nodes_before_build,
@@ -869,7 +894,8 @@ pub fn submit_frame_meta(frame_meta: FrameMeta) {
 
 /// Submit redraw wake metadata.
 pub fn submit_wake_meta(wake_meta: WakeMeta) {
-    if let Err(err) = profiler_runtime().sender.send(Message::WakeMeta(wake_meta)) {
+    let message = Message::WakeMeta(wake_meta, Instant::now());
+    if let Err(err) = profiler_runtime().sender.send(message) {
         eprintln!("tessera profiler wake meta send failed: {err}");
     }
 }
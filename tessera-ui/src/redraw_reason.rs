@@ -0,0 +1,58 @@
+//! Reasons recorded for redraw requests.
+//!
+//! ## Usage
+//!
+//! Attach the events that woke the renderer to profiler records and the
+//! inspector overlay.
+
+use serde::Serialize;
+
+/// Reason that caused a redraw request.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RedrawReason {
+    /// Initial frame request during startup/resume.
+    Startup,
+    /// Window resize event.
+    WindowResized,
+    /// Cursor move event.
+    CursorMoved,
+    /// Cursor leave event.
+    CursorLeft,
+    /// Mouse button input event.
+    MouseInput,
+    /// Mouse wheel event.
+    MouseWheel,
+    /// Touch event.
+    TouchInput,
+    /// Trackpad pinch or rotation gesture.
+    TransformGesture,
+    /// Scale factor change event.
+    ScaleFactorChanged,
+    /// Keyboard input event.
+    KeyboardInput,
+    /// Keyboard modifier state change event.
+    ModifiersChanged,
+    /// IME event.
+    ImeEvent,
+    /// Focus change event.
+    FocusChanged,
+    /// Window occlusion change event.
+    WindowOccluded,
+    /// Runtime state invalidation requires next frame.
+    RuntimeInvalidation,
+    /// Frame awaiter callback requires next frame.
+    RuntimeFrameAwaiter,
+}
+
+/// Source category for redraw wake events.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WakeSource {
+    /// Triggered by the renderer lifecycle.
+    Lifecycle,
+    /// Triggered by a window/input event.
+    WindowEvent,
+    /// Triggered by runtime pending work.
+    Runtime,
+}
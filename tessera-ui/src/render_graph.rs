@@ -14,6 +14,7 @@ use smallvec::SmallVec;
 use crate::{
     Command, CompositeCommand, ComputeCommand, DrawCommand, DrawRegion, SampleRegion,
     px::{Px, PxPosition, PxRect, PxSize},
+    renderer::composite::CompositeEncodeFn,
 };
 
 /// Resource identifier used by render graph nodes.
@@ -204,6 +205,9 @@ pub struct RenderGraph {
     ops: Vec<RenderGraphOp>,
     resources: Vec<RenderResource>,
     external_resources: Vec<ExternalTextureDesc>,
+    /// Recorded before the frame's passes; only composite expansion adds it,
+    /// after the last graph transform.
+    encoder_work: Vec<CompositeEncodeFn>,
 }
 
 /// Owned render graph payload for graph transforms.
@@ -236,6 +240,8 @@ impl RenderGraph {
     }
 
     /// Decomposes the graph into owned parts for graph processing.
+    ///
+    /// Encoder work from composite expansion is not part of the payload.
     #[must_use]
     pub fn into_parts(self) -> RenderGraphParts {
         RenderGraphParts {
@@ -252,15 +258,24 @@ impl RenderGraph {
             ops: parts.ops,
             resources: parts.resources,
             external_resources: parts.external_resources,
+            encoder_work: Vec::new(),
         }
     }
 
+    /// Attaches work that is recorded into the frame's encoder before its
+    /// passes.
+    pub(crate) fn with_encoder_work(mut self, encoder_work: Vec<CompositeEncodeFn>) -> Self {
+        self.encoder_work = encoder_work;
+        self
+    }
+
     /// Consumes the graph and returns an execution-ready payload.
     pub(crate) fn into_execution(self) -> RenderGraphExecution {
         RenderGraphExecution {
             ops: order_ops(self.ops),
             resources: self.resources,
             external_resources: self.external_resources,
+            encoder_work: self.encoder_work,
         }
     }
 }
@@ -270,6 +285,7 @@ pub(crate) struct RenderGraphExecution {
     pub(crate) ops: Vec<RenderGraphOp>,
     pub(crate) resources: Vec<RenderResource>,
     pub(crate) external_resources: Vec<ExternalTextureDesc>,
+    pub(crate) encoder_work: Vec<CompositeEncodeFn>,
 }
 
 /// Builder for a frame-level render graph.
@@ -388,6 +404,7 @@ impl RenderGraphBuilder {
             ops: self.ops,
             resources: self.resources,
             external_resources: self.external_resources,
+            encoder_work: Vec::new(),
         }
     }
 }
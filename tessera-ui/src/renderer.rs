@@ -6,6 +6,8 @@ pub mod composite;
 pub mod compute;
 pub mod core;
 pub mod drawer;
+#[cfg(not(target_os = "android"))]
+pub mod embedded;
 pub mod external;
 
 use std::sync::{
@@ -29,7 +31,7 @@ use winit::{
 
 use crate::{
     ImeRequest, ImeState, PxPosition,
-    build_tree::build_component_tree,
+    build_tree::{BuildTreeResult, build_component_tree},
     component_tree::{LayoutFrameDiagnostics, WindowRequests, clear_layout_snapshots},
     context::{reset_component_context_tracking, reset_context_read_dependencies},
     cursor::{
@@ -64,22 +66,26 @@ pub use compute::{
     ComputablePipeline, ComputeBatchItem, ComputePipelineRegistry, ErasedComputeBatchItem,
 };
 pub use drawer::{DrawCommand, DrawablePipeline, PipelineRegistry};
+#[cfg(not(target_os = "android"))]
+pub use embedded::{EmbeddedConfig, EmbeddedFrame, EmbeddedRenderer};
 pub use external::{ExternalTextureHandle, ExternalTextureRegistry};
 
-#[cfg(feature = "debug-dirty-overlay")]
+#[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
 use crate::PxRect;
 #[cfg(feature = "debug-dirty-overlay")]
-use crate::build_tree::{BuildTreeMode, BuildTreeResult};
+use crate::build_tree::BuildTreeMode;
 
 #[cfg(feature = "profiling")]
 use crate::profiler::{
-    FrameMeta, Phase as ProfilerPhase, RedrawReason, RuntimeEventKind, RuntimeMeta,
-    ScopeGuard as ProfilerScopeGuard, WakeMeta, WakeSource, begin_frame as profiler_begin_frame,
+    FrameMeta, Phase as ProfilerPhase, RuntimeEventKind, RuntimeMeta,
+    ScopeGuard as ProfilerScopeGuard, WakeMeta, begin_frame as profiler_begin_frame,
     end_frame as profiler_end_frame, submit_frame_meta, submit_runtime_meta, submit_wake_meta,
 };
+#[cfg(any(feature = "profiling", feature = "inspector"))]
+use crate::redraw_reason::{RedrawReason, WakeSource};
 #[cfg(feature = "profiling")]
 use crate::runtime::frame_delta;
-#[cfg(feature = "profiling")]
+#[cfg(any(feature = "profiling", feature = "inspector"))]
 use std::collections::BTreeSet;
 #[cfg(feature = "profiling")]
 use std::path::PathBuf;
@@ -135,7 +141,22 @@ impl RuntimePendingWork {
         self.invalidation_pending || self.frame_receiver_pending
     }
 
-    #[cfg(feature = "profiling")]
+    /// When the pending work wants the frame after one built at `now`.
+    ///
+    /// Invalidated components rebuild right away, while frame callbacks wait
+    /// for the next display frame `frame_interval` later.
+    #[cfg(not(target_os = "android"))]
+    fn next_frame_at(self, now: Instant, frame_interval: std::time::Duration) -> Option<Instant> {
+        if self.invalidation_pending {
+            Some(now)
+        } else if self.frame_receiver_pending {
+            Some(now + frame_interval)
+        } else {
+            None
+        }
+    }
+
+    #[cfg(any(feature = "profiling", feature = "inspector"))]
     fn redraw_reasons(self) -> Vec<RedrawReason> {
         let mut reasons = Vec::new();
         if self.invalidation_pending {
@@ -163,6 +184,26 @@ fn resolve_profiler_output_path(config: &TesseraConfig) -> PathBuf {
     config.profiler_output_path.clone()
 }
 
+#[cfg(feature = "profiling")]
+fn resolve_profiler_stream_address() -> Option<String> {
+    if let Ok(addr) = std::env::var("TESSERA_PROFILING_STREAM")
+        && !addr.trim().is_empty()
+    {
+        return Some(addr);
+    }
+    option_env!("TESSERA_PROFILING_STREAM")
+        .filter(|addr| !addr.trim().is_empty())
+        .map(ToOwned::to_owned)
+}
+
+#[cfg(feature = "profiling")]
+fn configure_profiler(config: &TesseraConfig) {
+    crate::profiler::set_output_path(resolve_profiler_output_path(config));
+    if let Some(addr) = resolve_profiler_stream_address() {
+        crate::profiler::set_stream_address(addr);
+    }
+}
+
 /// Window creation options for desktop platforms.
 #[derive(Debug, Clone)]
 pub struct WindowConfig {
@@ -418,7 +459,7 @@ pub struct Renderer<F: Fn()> {
     /// Whether a web-side render core initialization task is currently
     /// running.
     web_init_in_progress: bool,
-    #[cfg(feature = "profiling")]
+    #[cfg(any(feature = "profiling", feature = "inspector"))]
     /// Aggregated redraw reasons that will be attached to the next rendered
     /// frame.
     pending_redraw_reasons: BTreeSet<RedrawReason>,
@@ -540,7 +581,7 @@ impl<F: Fn()> Renderer<F> {
         let ime_state = ImeState::default();
         let ime_bridge_state = RendererImeBridgeState::default();
         #[cfg(feature = "profiling")]
-        crate::profiler::set_output_path(resolve_profiler_output_path(&config));
+        configure_profiler(&config);
         let mut renderer = Self {
             app,
             entry_point,
@@ -564,7 +605,7 @@ impl<F: Fn()> Renderer<F> {
             web_init_epoch: 0,
             #[cfg(target_family = "wasm")]
             web_init_in_progress: false,
-            #[cfg(feature = "profiling")]
+            #[cfg(any(feature = "profiling", feature = "inspector"))]
             pending_redraw_reasons: BTreeSet::new(),
         };
         thread_utils::set_thread_name("TesseraMain");
@@ -602,7 +643,7 @@ impl<F: Fn()> Renderer<F> {
         let ime_state = ImeState::default();
         let ime_bridge_state = RendererImeBridgeState::default();
         #[cfg(feature = "profiling")]
-        crate::profiler::set_output_path(resolve_profiler_output_path(&config));
+        configure_profiler(&config);
         let renderer = Self {
             app,
             entry_point,
@@ -623,7 +664,7 @@ impl<F: Fn()> Renderer<F> {
             pending_web_inits: Rc::new(RefCell::new(Vec::new())),
             web_init_epoch: 0,
             web_init_in_progress: false,
-            #[cfg(feature = "profiling")]
+            #[cfg(any(feature = "profiling", feature = "inspector"))]
             pending_redraw_reasons: BTreeSet::new(),
         };
         thread_utils::set_thread_name("TesseraMain");
@@ -738,7 +779,7 @@ impl<F: Fn()> Renderer<F> {
         let ime_state = ImeState::default();
         let ime_bridge_state = RendererImeBridgeState::default();
         #[cfg(feature = "profiling")]
-        crate::profiler::set_output_path(resolve_profiler_output_path(&config));
+        configure_profiler(&config);
         let mut renderer = Self {
             app,
             entry_point,
@@ -757,7 +798,7 @@ impl<F: Fn()> Renderer<F> {
             redraw_request_pending: Arc::new(AtomicBool::new(false)),
             pending_close_requested: false,
             resize_in_progress: false,
-            #[cfg(feature = "profiling")]
+            #[cfg(any(feature = "profiling", feature = "inspector"))]
             pending_redraw_reasons: BTreeSet::new(),
         };
         thread_utils::set_thread_name("TesseraMain");
@@ -786,7 +827,7 @@ struct RenderFrameContext<'a, F: Fn()> {
     decorations: bool,
     window_label: &'a str,
     frame_idx: u64,
-    #[cfg(feature = "profiling")]
+    #[cfg(any(feature = "profiling", feature = "inspector"))]
     redraw_reasons: Vec<RedrawReason>,
 }
 
@@ -798,6 +839,16 @@ struct RenderFrameOutcome {
     overlay_clear_pending: bool,
 }
 
+/// Component tree build and render graph recorded for one frame.
+struct RecordedFrame {
+    build_tree_result: BuildTreeResult,
+    graph: RenderGraph,
+    window_requests: WindowRequests,
+    draw_cost: std::time::Duration,
+    layout_diagnostics: LayoutFrameDiagnostics,
+    record_cost: std::time::Duration,
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 struct RendererImeBridgeState {
     current_request: Option<ImeRequest>,
@@ -1123,6 +1174,7 @@ Fps: {:.2}
             pending_focus_move_retry,
             pending_focus_reveal_retry,
         ) = TesseraRuntime::with_mut(|rt| {
+            let window_visible = rt.is_window_visible();
             let component_tree = &mut rt.component_tree;
             let (gpu, compute_resource_manager) = args.app.record_resources();
             component_tree.compute(
@@ -1136,6 +1188,7 @@ Fps: {:.2}
                     retry_focus_reveal,
                     modifiers: args.keyboard_state.modifiers(),
                     layout_dirty_nodes: &layout_dirty_nodes,
+                    window_visible,
                 },
                 crate::component_tree::ComputeMode::Full {
                     compute_resource_manager,
@@ -1225,7 +1278,7 @@ Fps: {:.2}
 
     /// Perform the actual GPU rendering for the provided commands and return
     /// the render duration.
-    #[cfg(not(feature = "debug-dirty-overlay"))]
+    #[cfg(not(any(feature = "debug-dirty-overlay", feature = "inspector")))]
     #[instrument(level = "debug", skip(args, execution))]
     fn perform_render<'a>(
         args: &mut RenderFrameArgs<'a>,
@@ -1248,12 +1301,12 @@ Fps: {:.2}
         render_cost
     }
 
-    #[cfg(feature = "debug-dirty-overlay")]
-    #[instrument(level = "debug", skip(args, execution, dirty_overlay_rects))]
+    #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
+    #[instrument(level = "debug", skip(args, execution, overlay_rects))]
     fn perform_render<'a>(
         args: &mut RenderFrameArgs<'a>,
         execution: RenderGraphExecution,
-        dirty_overlay_rects: &[PxRect],
+        overlay_rects: &[PxRect],
     ) -> std::time::Duration {
         #[cfg(feature = "profiling")]
         let _profiler_guard =
@@ -1266,38 +1319,38 @@ Fps: {:.2}
         }
 
         debug!("Rendering draw commands...");
-        args.app.render(execution, dirty_overlay_rects);
+        args.app.render(execution, overlay_rects);
         let render_cost = render_timer.elapsed();
         debug!("Rendered to surface in {render_cost:?}");
         render_cost
     }
 
-    #[instrument(level = "debug", skip(context))]
-    fn execute_render_frame(context: RenderFrameContext<'_, F>) -> RenderFrameOutcome {
-        let RenderFrameContext {
-            entry_point,
-            args,
-            accessibility_enabled,
-            decorations,
-            window_label,
-            frame_idx,
-            #[cfg(feature = "profiling")]
-            redraw_reasons,
-        } = context;
-        #[cfg(feature = "profiling")]
-        let frame_timer = Instant::now();
-        #[cfg(feature = "profiling")]
-        profiler_begin_frame(frame_idx);
-        begin_frame_clock(Instant::now());
+    /// Advances the frame clock and runs work that must land before the
+    /// component tree is built.
+    fn begin_ui_frame(now: Instant, app: &RenderCore) {
+        begin_frame_clock(now);
         // Tick frame-nanos receivers before build so their state writes are
         // consumed by the current recomposition pass.
         tick_frame_nanos_receivers();
-        // notify the windowing system before rendering
-        // this will help winit to properly schedule and make assumptions about its
-        // internal state
-        args.app.window().pre_present_notify();
-        // and tell runtime the new size
-        TesseraRuntime::with_mut(|rt: &mut TesseraRuntime| rt.window_size = args.app.size().into());
+        // Run tasks posted from background threads for the same reason.
+        crate::ui_thread::run_pending_tasks();
+        // Tell runtime the current size
+        TesseraRuntime::with_mut(|rt: &mut TesseraRuntime| rt.window_size = app.size().into());
+        #[cfg(all(
+            feature = "hot-reload",
+            not(target_os = "android"),
+            not(target_family = "wasm")
+        ))]
+        crate::hot_reload::apply_pending_reload();
+    }
+
+    /// Builds the component tree and records its render graph, rebuilding
+    /// while focus moves wait for nodes beyond the current bounds.
+    fn build_and_record_frame(
+        entry_point: &F,
+        args: &mut RenderFrameArgs<'_>,
+        frame_idx: u64,
+    ) -> RecordedFrame {
         // Build the component tree and measure time
         let mut build_tree_result = build_component_tree(entry_point);
         debug!("Component tree build mode: {:?}", build_tree_result.mode());
@@ -1361,6 +1414,61 @@ Fps: {:.2}
                 );
             });
         }
+        RecordedFrame {
+            build_tree_result,
+            graph: new_graph,
+            window_requests,
+            draw_cost,
+            layout_diagnostics,
+            record_cost,
+        }
+    }
+
+    #[instrument(level = "debug", skip(context))]
+    fn execute_render_frame(context: RenderFrameContext<'_, F>) -> RenderFrameOutcome {
+        let RenderFrameContext {
+            entry_point,
+            args,
+            accessibility_enabled,
+            decorations,
+            window_label,
+            frame_idx,
+            #[cfg(any(feature = "profiling", feature = "inspector"))]
+            redraw_reasons,
+        } = context;
+        #[cfg(feature = "profiling")]
+        let frame_timer = Instant::now();
+        #[cfg(feature = "profiling")]
+        profiler_begin_frame(frame_idx);
+        Self::begin_ui_frame(Instant::now(), args.app);
+        // notify the windowing system before rendering
+        // this will help winit to properly schedule and make assumptions about its
+        // internal state
+        args.app.window().pre_present_notify();
+        let screen_size: PxSize = args.app.size().into();
+        let RecordedFrame {
+            build_tree_result,
+            graph: new_graph,
+            window_requests,
+            draw_cost,
+            layout_diagnostics,
+            record_cost,
+        } = Self::build_and_record_frame(entry_point, args, frame_idx);
+        #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
+        let mut overlay_rects: Vec<PxRect> = Vec::new();
+        #[cfg(feature = "inspector")]
+        overlay_rects.extend(crate::inspector::record_frame(
+            crate::inspector::InspectorFrameInput {
+                frame_idx,
+                build_tree_result: &build_tree_result,
+                layout_changed: layout_diagnostics.cache_store_count > 0,
+                cursor_position: args.cursor_state.position(),
+                redraw_reasons: redraw_reasons
+                    .iter()
+                    .map(|reason| format!("{reason:?}"))
+                    .collect(),
+            },
+        ));
         #[cfg(not(feature = "profiling"))]
         let _ = (layout_diagnostics, record_cost);
         #[cfg(feature = "debug-dirty-overlay")]
@@ -1368,6 +1476,8 @@ Fps: {:.2}
             Self::collect_dirty_overlay_rects(screen_size, &build_tree_result);
         #[cfg(feature = "debug-dirty-overlay")]
         let overlay_clear_pending = !dirty_overlay_rects.is_empty();
+        #[cfg(feature = "debug-dirty-overlay")]
+        overlay_rects.extend(dirty_overlay_rects);
         let (composite_context, composite_registry) =
             args.app.composite_context_parts(screen_size, frame_idx);
         let new_graph =
@@ -1376,6 +1486,7 @@ Fps: {:.2}
             ops,
             resources,
             external_resources,
+            encoder_work,
         } = new_graph.into_execution();
         // Perform GPU render every frame.
         let render_cost = Self::perform_render(
@@ -1384,9 +1495,10 @@ Fps: {:.2}
                 ops,
                 resources,
                 external_resources,
+                encoder_work,
             },
-            #[cfg(feature = "debug-dirty-overlay")]
-            &dirty_overlay_rects,
+            #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
+            &overlay_rects,
         );
         // Log frame statistics
         let render_breakdown = args.app.last_render_breakdown();
@@ -1413,6 +1525,7 @@ Fps: {:.2}
                 frame_idx,
                 build_mode: build_tree_result.profiler_build_mode(),
                 redraw_reasons,
+                started_at: frame_timer,
                 inter_frame_wait_ns,
                 partial_replay_nodes: build_tree_result.partial_replay_nodes(),
                 total_nodes_before_build: build_tree_result.total_nodes_before_build(),
@@ -1581,8 +1694,26 @@ impl<F: Fn()> Renderer<F> {
     fn install_runtime_redraw_waker(&self) {
         let Some(proxy) = self.event_loop_proxy.clone() else {
             clear_redraw_waker();
+            crate::ui_thread::clear_waker();
             return;
         };
+        #[cfg(all(
+            feature = "hot-reload",
+            not(target_os = "android"),
+            not(target_family = "wasm")
+        ))]
+        {
+            let proxy = proxy.clone();
+            crate::hot_reload::install_waker(Arc::new(move || {
+                let _ = proxy.send_event(RendererUserEvent::RuntimeRedrawWake);
+            }));
+        }
+        {
+            let proxy = proxy.clone();
+            crate::ui_thread::install_waker(Arc::new(move || {
+                let _ = proxy.send_event(RendererUserEvent::RuntimeRedrawWake);
+            }));
+        }
         install_redraw_waker(Arc::new(move || {
             let _ = proxy.send_event(RendererUserEvent::RuntimeRedrawWake);
         }));
@@ -1632,9 +1763,9 @@ impl<F: Fn()> Renderer<F> {
         self.app = Some(render_core);
         self.web_init_in_progress = false;
         self.install_runtime_redraw_waker();
-        #[cfg(feature = "profiling")]
+        #[cfg(any(feature = "profiling", feature = "inspector"))]
         self.request_redraw_with_reasons(WakeSource::Lifecycle, vec![RedrawReason::Startup]);
-        #[cfg(not(feature = "profiling"))]
+        #[cfg(not(any(feature = "profiling", feature = "inspector")))]
         self.request_redraw_now();
 
         if let Some(context) = self.plugin_context(event_loop) {
@@ -1643,7 +1774,7 @@ impl<F: Fn()> Renderer<F> {
         true
     }
 
-    #[cfg(feature = "profiling")]
+    #[cfg(any(feature = "profiling", feature = "inspector"))]
     fn request_redraw_with_reasons(&mut self, source: WakeSource, mut reasons: Vec<RedrawReason>) {
         reasons.sort_unstable();
         reasons.dedup();
@@ -1653,16 +1784,19 @@ impl<F: Fn()> Renderer<F> {
 
         self.pending_redraw_reasons.extend(reasons.iter().copied());
 
+        #[cfg(feature = "profiling")]
         submit_wake_meta(WakeMeta {
             frame_idx: self.frame_index,
             source,
             reasons: reasons.clone(),
         });
+        #[cfg(not(feature = "profiling"))]
+        let _ = source;
 
         self.request_redraw_now();
     }
 
-    #[cfg(feature = "profiling")]
+    #[cfg(any(feature = "profiling", feature = "inspector"))]
     fn take_pending_redraw_reasons(&mut self) -> Vec<RedrawReason> {
         std::mem::take(&mut self.pending_redraw_reasons)
             .into_iter()
@@ -1746,6 +1880,7 @@ impl<F: Fn()> Renderer<F> {
         self.cursor_state.push_event(PointerChange {
             timestamp: Instant::now(),
             pointer_id: MOUSE_POINTER_ID,
+            position: Some(px_position),
             content: CursorEventContent::Moved(px_position),
             gesture_state: GestureState::TapCandidate,
             consumed: false,
@@ -1834,6 +1969,7 @@ impl<F: Fn()> Renderer<F> {
         let event = PointerChange {
             timestamp: Instant::now(),
             pointer_id: MOUSE_POINTER_ID,
+            position: self.cursor_state.position(),
             content: event_content,
             gesture_state: GestureState::TapCandidate,
             consumed: false,
@@ -1850,6 +1986,7 @@ impl<F: Fn()> Renderer<F> {
         let event = PointerChange {
             timestamp: Instant::now(),
             pointer_id: MOUSE_POINTER_ID,
+            position: self.cursor_state.position(),
             content: event_content,
             gesture_state: GestureState::Dragged,
             consumed: false,
@@ -1858,6 +1995,22 @@ impl<F: Fn()> Renderer<F> {
         debug!("Mouse scroll: {delta:?}");
     }
 
+    fn handle_transform_gesture(&mut self, event_content: CursorEventContent) {
+        if self.resize_in_progress {
+            return;
+        }
+        debug!("Transform gesture: {event_content:?}");
+        let event = PointerChange {
+            timestamp: Instant::now(),
+            pointer_id: MOUSE_POINTER_ID,
+            position: self.cursor_state.position(),
+            content: event_content,
+            gesture_state: GestureState::Dragged,
+            consumed: false,
+        };
+        self.cursor_state.push_event(event);
+    }
+
     fn handle_touch(&mut self, touch_event: winit::event::Touch) {
         if self.resize_in_progress {
             return;
@@ -1905,7 +2058,7 @@ impl<F: Fn()> Renderer<F> {
         app.resize_if_needed();
         let accessibility_enabled = self.accessibility_adapter.is_some();
         let frame_idx = self.frame_index;
-        #[cfg(feature = "profiling")]
+        #[cfg(any(feature = "profiling", feature = "inspector"))]
         let redraw_reasons = self.take_pending_redraw_reasons();
         let window_label = &self.config.window_title;
 
@@ -1934,7 +2087,7 @@ impl<F: Fn()> Renderer<F> {
                 decorations: self.config.window.decorations,
                 window_label,
                 frame_idx,
-                #[cfg(feature = "profiling")]
+                #[cfg(any(feature = "profiling", feature = "inspector"))]
                 redraw_reasons,
             })
         };
@@ -1955,12 +2108,12 @@ impl<F: Fn()> Renderer<F> {
         self.app = Some(app);
 
         if runtime_pending_work.requires_redraw() {
-            #[cfg(feature = "profiling")]
+            #[cfg(any(feature = "profiling", feature = "inspector"))]
             self.request_redraw_with_reasons(
                 WakeSource::Runtime,
                 runtime_pending_work.redraw_reasons(),
             );
-            #[cfg(not(feature = "profiling"))]
+            #[cfg(not(any(feature = "profiling", feature = "inspector")))]
             self.request_redraw_now();
         }
         #[cfg(feature = "debug-dirty-overlay")]
@@ -2014,9 +2167,9 @@ impl<F: Fn()> ApplicationHandler<RendererUserEvent> for Renderer<F> {
         // Just return if the app is already created
         if self.app.is_some() {
             self.install_runtime_redraw_waker();
-            #[cfg(feature = "profiling")]
+            #[cfg(any(feature = "profiling", feature = "inspector"))]
             self.request_redraw_with_reasons(WakeSource::Lifecycle, vec![RedrawReason::Startup]);
-            #[cfg(not(feature = "profiling"))]
+            #[cfg(not(any(feature = "profiling", feature = "inspector")))]
             self.request_redraw_now();
             return;
         }
@@ -2085,9 +2238,9 @@ impl<F: Fn()> ApplicationHandler<RendererUserEvent> for Renderer<F> {
 
             self.app = Some(render_core);
             self.install_runtime_redraw_waker();
-            #[cfg(feature = "profiling")]
+            #[cfg(any(feature = "profiling", feature = "inspector"))]
             self.request_redraw_with_reasons(WakeSource::Lifecycle, vec![RedrawReason::Startup]);
-            #[cfg(not(feature = "profiling"))]
+            #[cfg(not(any(feature = "profiling", feature = "inspector")))]
             self.request_redraw_now();
 
             if let Some(context) = self.plugin_context(event_loop) {
@@ -2146,6 +2299,7 @@ impl<F: Fn()> ApplicationHandler<RendererUserEvent> for Renderer<F> {
             runtime.component_tree.reset();
             runtime.cursor_icon_request = None;
             runtime.window_minimized = false;
+            runtime.window_occluded = false;
             runtime.window_size = [0, 0];
         });
         clear_layout_snapshots();
@@ -2159,9 +2313,10 @@ impl<F: Fn()> ApplicationHandler<RendererUserEvent> for Renderer<F> {
         reset_build_invalidations();
         reset_frame_clock();
         clear_redraw_waker();
+        crate::ui_thread::clear_waker();
         clear_persistent_focus_handles();
         crate::runtime::reset_slots();
-        #[cfg(feature = "profiling")]
+        #[cfg(any(feature = "profiling", feature = "inspector"))]
         self.pending_redraw_reasons.clear();
     }
 
@@ -2185,7 +2340,7 @@ impl<F: Fn()> ApplicationHandler<RendererUserEvent> for Renderer<F> {
 
         // Handle window events
         let mut request_redraw = false;
-        #[cfg(feature = "profiling")]
+        #[cfg(any(feature = "profiling", feature = "inspector"))]
         let mut redraw_reasons = Vec::new();
         match event {
             WindowEvent::CloseRequested => {
@@ -2194,7 +2349,7 @@ impl<F: Fn()> ApplicationHandler<RendererUserEvent> for Renderer<F> {
             WindowEvent::Resized(size) => {
                 self.handle_resized(size);
                 request_redraw = true;
-                #[cfg(feature = "profiling")]
+                #[cfg(any(feature = "profiling", feature = "inspector"))]
                 redraw_reasons.push(RedrawReason::WindowResized);
             }
             WindowEvent::CursorMoved {
@@ -2203,13 +2358,13 @@ impl<F: Fn()> ApplicationHandler<RendererUserEvent> for Renderer<F> {
             } => {
                 self.handle_cursor_moved(position);
                 request_redraw = true;
-                #[cfg(feature = "profiling")]
+                #[cfg(any(feature = "profiling", feature = "inspector"))]
                 redraw_reasons.push(RedrawReason::CursorMoved);
             }
             WindowEvent::CursorLeft { device_id: _ } => {
                 self.handle_cursor_left();
                 request_redraw = true;
-                #[cfg(feature = "profiling")]
+                #[cfg(any(feature = "profiling", feature = "inspector"))]
                 redraw_reasons.push(RedrawReason::CursorLeft);
             }
             WindowEvent::MouseInput {
@@ -2219,7 +2374,7 @@ impl<F: Fn()> ApplicationHandler<RendererUserEvent> for Renderer<F> {
             } => {
                 self.handle_mouse_input(state, button);
                 request_redraw = true;
-                #[cfg(feature = "profiling")]
+                #[cfg(any(feature = "profiling", feature = "inspector"))]
                 redraw_reasons.push(RedrawReason::MouseInput);
             }
             WindowEvent::MouseWheel {
@@ -2229,15 +2384,27 @@ impl<F: Fn()> ApplicationHandler<RendererUserEvent> for Renderer<F> {
             } => {
                 self.handle_mouse_wheel(delta);
                 request_redraw = true;
-                #[cfg(feature = "profiling")]
+                #[cfg(any(feature = "profiling", feature = "inspector"))]
                 redraw_reasons.push(RedrawReason::MouseWheel);
             }
             WindowEvent::Touch(touch_event) => {
                 self.handle_touch(touch_event);
                 request_redraw = true;
-                #[cfg(feature = "profiling")]
+                #[cfg(any(feature = "profiling", feature = "inspector"))]
                 redraw_reasons.push(RedrawReason::TouchInput);
             }
+            WindowEvent::PinchGesture { delta, .. } => {
+                self.handle_transform_gesture(CursorEventContent::from_pinch_gesture(delta));
+                request_redraw = true;
+                #[cfg(any(feature = "profiling", feature = "inspector"))]
+                redraw_reasons.push(RedrawReason::TransformGesture);
+            }
+            WindowEvent::RotationGesture { delta, .. } => {
+                self.handle_transform_gesture(CursorEventContent::from_rotation_gesture(delta));
+                request_redraw = true;
+                #[cfg(any(feature = "profiling", feature = "inspector"))]
+                redraw_reasons.push(RedrawReason::TransformGesture);
+            }
             WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
                 if let Some(scale_factor_lock) = SCALE_FACTOR.get() {
                     *scale_factor_lock.write() = scale_factor;
@@ -2248,27 +2415,32 @@ impl<F: Fn()> ApplicationHandler<RendererUserEvent> for Renderer<F> {
                     self.update_native_window_shape(app.window());
                 }
                 request_redraw = true;
-                #[cfg(feature = "profiling")]
+                #[cfg(any(feature = "profiling", feature = "inspector"))]
                 redraw_reasons.push(RedrawReason::ScaleFactorChanged);
             }
             WindowEvent::KeyboardInput { event, .. } => {
+                #[cfg(feature = "inspector")]
+                if crate::inspector::handle_keyboard_input(&event) {
+                    self.request_redraw_now();
+                    return;
+                }
                 self.handle_keyboard_input(event);
                 request_redraw = true;
-                #[cfg(feature = "profiling")]
+                #[cfg(any(feature = "profiling", feature = "inspector"))]
                 redraw_reasons.push(RedrawReason::KeyboardInput);
             }
             WindowEvent::ModifiersChanged(modifiers) => {
                 debug!("Modifiers changed: {modifiers:?}");
                 self.keyboard_state.update_modifiers(modifiers.state());
                 request_redraw = true;
-                #[cfg(feature = "profiling")]
+                #[cfg(any(feature = "profiling", feature = "inspector"))]
                 redraw_reasons.push(RedrawReason::ModifiersChanged);
             }
             WindowEvent::Ime(ime_event) => {
                 debug!("IME event: {ime_event:?}");
                 self.ime_state.push_event(ime_event);
                 request_redraw = true;
-                #[cfg(feature = "profiling")]
+                #[cfg(any(feature = "profiling", feature = "inspector"))]
                 redraw_reasons.push(RedrawReason::ImeEvent);
             }
             WindowEvent::Focused(focused) => {
@@ -2284,9 +2456,15 @@ impl<F: Fn()> ApplicationHandler<RendererUserEvent> for Renderer<F> {
                     self.cursor_state.clear();
                 }
                 request_redraw = true;
-                #[cfg(feature = "profiling")]
+                #[cfg(any(feature = "profiling", feature = "inspector"))]
                 redraw_reasons.push(RedrawReason::FocusChanged);
             }
+            WindowEvent::Occluded(occluded) => {
+                TesseraRuntime::with_mut(|runtime| runtime.window_occluded = occluded);
+                request_redraw = true;
+                #[cfg(any(feature = "profiling", feature = "inspector"))]
+                redraw_reasons.push(RedrawReason::WindowOccluded);
+            }
             WindowEvent::RedrawRequested => {
                 #[cfg(target_os = "android")]
                 self.handle_redraw_requested(event_loop);
@@ -2297,9 +2475,9 @@ impl<F: Fn()> ApplicationHandler<RendererUserEvent> for Renderer<F> {
         }
 
         if request_redraw {
-            #[cfg(feature = "profiling")]
+            #[cfg(any(feature = "profiling", feature = "inspector"))]
             self.request_redraw_with_reasons(WakeSource::WindowEvent, redraw_reasons);
-            #[cfg(not(feature = "profiling"))]
+            #[cfg(not(any(feature = "profiling", feature = "inspector")))]
             self.request_redraw_now();
         }
     }
@@ -2311,12 +2489,12 @@ impl<F: Fn()> ApplicationHandler<RendererUserEvent> for Renderer<F> {
 
         match event {
             RendererUserEvent::RuntimeRedrawWake => {
-                #[cfg(feature = "profiling")]
+                #[cfg(any(feature = "profiling", feature = "inspector"))]
                 self.request_redraw_with_reasons(
                     WakeSource::Runtime,
                     vec![RedrawReason::RuntimeInvalidation],
                 );
-                #[cfg(not(feature = "profiling"))]
+                #[cfg(not(any(feature = "profiling", feature = "inspector")))]
                 self.request_redraw_now();
             }
             #[cfg(target_family = "wasm")]
@@ -12,6 +12,7 @@ use crate::{
         ExternalTextureDesc, RenderGraph, RenderGraphOp, RenderGraphParts, RenderResource,
         RenderResourceId,
     },
+    wgpu,
 };
 
 use super::{core::RenderResources, external::ExternalTextureRegistry};
@@ -72,6 +73,24 @@ pub struct CompositeReplacement {
     pub ops: Vec<RenderGraphOp>,
 }
 
+/// GPU handles passed to a [`CompositeEncodeFn`].
+pub struct CompositeEncodeContext<'a> {
+    /// Device that owns the frame's resources.
+    pub device: &'a wgpu::Device,
+    /// Queue the frame is submitted on.
+    pub queue: &'a wgpu::Queue,
+    /// Command encoder of the frame.
+    pub encoder: &'a mut wgpu::CommandEncoder,
+}
+
+/// GPU work recorded into the frame's command encoder before its passes.
+///
+/// Offscreen passes that replacement ops sample belong here: the frame's
+/// owner submits the encoder, so they stay ordered with the rest of the
+/// frame, including a host's work in embedded mode. Pipelines must not
+/// submit on their own.
+pub type CompositeEncodeFn = Box<dyn FnOnce(&mut CompositeEncodeContext<'_>) + Send + Sync>;
+
 /// Composite pipeline output for a batch.
 pub struct CompositeOutput {
     /// Local resources referenced by prelude and replacement ops.
@@ -82,6 +101,8 @@ pub struct CompositeOutput {
     pub prelude_ops: Vec<RenderGraphOp>,
     /// Replacement ops for composite commands.
     pub replacements: Vec<CompositeReplacement>,
+    /// Work recorded into the frame's encoder before any pass runs.
+    pub encoder_work: Vec<CompositeEncodeFn>,
 }
 
 impl CompositeOutput {
@@ -92,6 +113,7 @@ impl CompositeOutput {
             external_resources: Vec::new(),
             prelude_ops: Vec::new(),
             replacements: Vec::new(),
+            encoder_work: Vec::new(),
         }
     }
 
@@ -248,12 +270,14 @@ pub(crate) fn expand_composites(
     let mut new_external_resources = external_resources;
     let mut prelude_ops: Vec<RenderGraphOp> = Vec::new();
     let mut replacements: HashMap<usize, Vec<Vec<RenderGraphOp>>> = HashMap::new();
+    let mut encoder_work: Vec<CompositeEncodeFn> = Vec::new();
 
     for type_id in type_order {
         let items = batches
             .get(&type_id)
             .expect("composite batch missing type entry");
         let output = registry.compile_erased(&context, items);
+        encoder_work.extend(output.encoder_work);
 
         let resource_map = map_resources(&mut new_resources, &output.resources);
         let external_map =
@@ -320,6 +344,7 @@ pub(crate) fn expand_composites(
         resources: new_resources,
         external_resources: new_external_resources,
     })
+    .with_encoder_work(encoder_work)
 }
 
 fn map_resources(
@@ -63,10 +63,11 @@ struct ComputeState {
 
 struct BlitState {
     pipeline: wgpu::RenderPipeline,
+    pipeline_over: wgpu::RenderPipeline,
     pipeline_rgba: wgpu::RenderPipeline,
     bind_group_layout: wgpu::BindGroupLayout,
     sampler: wgpu::Sampler,
-    #[cfg(feature = "debug-dirty-overlay")]
+    #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
     dirty_overlay_pipeline: wgpu::RenderPipeline,
 }
 
@@ -251,17 +252,23 @@ fn create_msaa_view(
     texture.create_view(&wgpu::TextureViewDescriptor::default())
 }
 
-/// Render core holding device, surface, pipelines, and frame resources.
-pub struct RenderCore {
+/// Window surface a render core presents frames to.
+struct WindowPresentation {
     /// Avoiding release the window
-    #[allow(unused)]
     window: Arc<Window>,
     /// WGPU instance used to recreate surfaces when the swapchain is lost.
     instance: wgpu::Instance,
-    /// WGPU device
-    device: wgpu::Device,
     /// WGPU surface
     surface: wgpu::Surface<'static>,
+}
+
+/// Render core holding device, surface, pipelines, and frame resources.
+pub struct RenderCore {
+    /// Window and surface, or `None` when rendering into host-provided
+    /// targets.
+    presentation: Option<WindowPresentation>,
+    /// WGPU device
+    device: wgpu::Device,
     /// WGPU queue
     queue: wgpu::Queue,
     /// WGPU surface configuration
@@ -275,8 +282,8 @@ pub struct RenderCore {
 
     /// WGPU pipeline cache for faster pipeline creation when supported.
     pipeline_cache: Option<wgpu::PipelineCache>,
-    /// Gpu adapter info
-    adapter_info: wgpu::AdapterInfo,
+    /// Gpu adapter info, unknown for embedded cores.
+    adapter_info: Option<wgpu::AdapterInfo>,
 
     /// Render target resources for the current frame.
     targets: FrameTargets,
@@ -321,13 +328,34 @@ impl RenderCore {
     }
 
     /// Returns the current window handle.
+    ///
+    /// # Panics
+    ///
+    /// Panics for embedded cores, which render into host-provided targets
+    /// and own no window.
     pub fn window(&self) -> &Window {
-        &self.window
+        &self.window_presentation().window
     }
 
     /// Returns a cloned window handle for external storage.
+    ///
+    /// # Panics
+    ///
+    /// Panics for embedded cores, like [`Self::window`].
     pub fn window_arc(&self) -> Arc<Window> {
-        self.window.clone()
+        self.window_presentation().window.clone()
+    }
+
+    /// Returns whether this core renders into host-provided targets instead
+    /// of a window surface.
+    pub fn is_embedded(&self) -> bool {
+        self.presentation.is_none()
+    }
+
+    fn window_presentation(&self) -> &WindowPresentation {
+        self.presentation
+            .as_ref()
+            .expect("embedded render cores have no window")
     }
 
     /// Returns the WGPU device.
@@ -438,8 +466,10 @@ impl RenderCore {
     }
 
     pub(crate) fn save_pipeline_cache(&self) -> io::Result<()> {
-        if let Some(cache) = self.pipeline_cache.as_ref() {
-            save_cache(cache, &self.adapter_info)?;
+        if let (Some(cache), Some(adapter_info)) =
+            (self.pipeline_cache.as_ref(), self.adapter_info.as_ref())
+        {
+            save_cache(cache, adapter_info)?;
         }
         Ok(())
     }
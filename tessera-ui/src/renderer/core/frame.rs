@@ -14,6 +14,7 @@ use crate::{
         ClipOps, ComputePlanItem, DrawOrClip, RenderPassGraph, RenderPassKind, RenderPassPlan,
     },
     renderer::{
+        composite::CompositeEncodeContext,
         compute::{ErasedComputeBatchItem, pipeline::ErasedDispatchContext},
         drawer::ErasedDrawContext,
         external::{ExternalTextureRegistry, ExternalTextureSlotGuard},
@@ -359,6 +360,12 @@ struct ComputePassParams<'a> {
     resource_manager: &'a mut ComputeResourceManager,
 }
 
+/// Timings of the surface-independent part of a frame.
+struct EncodeTimings {
+    build_passes: Duration,
+    encode: Duration,
+}
+
 impl RenderCore {
     /// Render the surface using the unified command system.
     ///
@@ -377,43 +384,123 @@ impl RenderCore {
     pub(crate) fn render(
         &mut self,
         execution: RenderGraphExecution,
-        #[cfg(feature = "debug-dirty-overlay")] dirty_overlay_rects: &[PxRect],
+        #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
+        overlay_rects: &[PxRect],
     ) {
         let render_start = Instant::now();
-        let current_frame = self.frame_index;
         self.last_render_breakdown = None;
         let acquire_start = Instant::now();
-        let (output_frame, reconfigure_after_present) = match self.surface.get_current_texture() {
-            wgpu::CurrentSurfaceTexture::Success(frame) => (frame, false),
-            wgpu::CurrentSurfaceTexture::Suboptimal(frame) => {
-                debug!("Surface suboptimal, rendering frame before reconfiguring...");
-                (frame, true)
-            }
-            wgpu::CurrentSurfaceTexture::Timeout => {
-                warn!("Surface timeout. Frame will be dropped.");
-                return;
-            }
-            wgpu::CurrentSurfaceTexture::Occluded => {
-                warn!("Surface occluded. Frame will be dropped.");
-                return;
-            }
-            wgpu::CurrentSurfaceTexture::Outdated => {
-                debug!("Surface outdated, reconfiguring...");
-                self.resize_surface();
-                return;
-            }
-            wgpu::CurrentSurfaceTexture::Lost => {
-                warn!("Surface lost, recreating surface...");
-                self.recreate_surface();
-                return;
-            }
-            wgpu::CurrentSurfaceTexture::Validation => {
-                error!("Surface validation error. Frame will be dropped.");
-                return;
-            }
+        let Some(presentation) = self.presentation.as_ref() else {
+            error!("Embedded render cores render through `render_to_view`.");
+            return;
         };
+        let (output_frame, reconfigure_after_present) =
+            match presentation.surface.get_current_texture() {
+                wgpu::CurrentSurfaceTexture::Success(frame) => (frame, false),
+                wgpu::CurrentSurfaceTexture::Suboptimal(frame) => {
+                    debug!("Surface suboptimal, rendering frame before reconfiguring...");
+                    (frame, true)
+                }
+                wgpu::CurrentSurfaceTexture::Timeout => {
+                    warn!("Surface timeout. Frame will be dropped.");
+                    return;
+                }
+                wgpu::CurrentSurfaceTexture::Occluded => {
+                    warn!("Surface occluded. Frame will be dropped.");
+                    return;
+                }
+                wgpu::CurrentSurfaceTexture::Outdated => {
+                    debug!("Surface outdated, reconfiguring...");
+                    self.resize_surface();
+                    return;
+                }
+                wgpu::CurrentSurfaceTexture::Lost => {
+                    warn!("Surface lost, recreating surface...");
+                    self.recreate_surface();
+                    return;
+                }
+                wgpu::CurrentSurfaceTexture::Validation => {
+                    error!("Surface validation error. Frame will be dropped.");
+                    return;
+                }
+            };
         let acquire = acquire_start.elapsed();
 
+        let output_view = output_frame
+            .texture
+            .create_view(&wgpu::TextureViewDescriptor::default());
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Render Encoder"),
+            });
+        let timings = self.encode_frame(
+            &mut encoder,
+            &output_view,
+            execution,
+            #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
+            overlay_rects,
+        );
+
+        let submit_start = Instant::now();
+        self.queue.submit(Some(encoder.finish()));
+        let submit = submit_start.elapsed();
+
+        let present_start = Instant::now();
+        output_frame.present();
+        if reconfigure_after_present {
+            self.resize_surface();
+        }
+        let present = present_start.elapsed();
+        self.last_render_breakdown = Some(RenderTimingBreakdown {
+            acquire,
+            build_passes: timings.build_passes,
+            encode: timings.encode,
+            submit,
+            present,
+            total: render_start.elapsed(),
+        });
+    }
+
+    /// Records the frame into a host-provided encoder and target view.
+    ///
+    /// The target must match the core's format and size, and the frame is
+    /// blended over its current contents. Nothing is submitted; the host
+    /// submits the encoder with its own work.
+    pub(crate) fn render_to_view(
+        &mut self,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        execution: RenderGraphExecution,
+        #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
+        overlay_rects: &[PxRect],
+    ) {
+        let render_start = Instant::now();
+        let timings = self.encode_frame(
+            encoder,
+            target,
+            execution,
+            #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
+            overlay_rects,
+        );
+        self.last_render_breakdown = Some(RenderTimingBreakdown {
+            build_passes: timings.build_passes,
+            encode: timings.encode,
+            total: render_start.elapsed(),
+            ..Default::default()
+        });
+    }
+
+    fn encode_frame(
+        &mut self,
+        encoder: &mut wgpu::CommandEncoder,
+        output_view: &wgpu::TextureView,
+        execution: RenderGraphExecution,
+        #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
+        overlay_rects: &[PxRect],
+    ) -> EncodeTimings {
+        let current_frame = self.frame_index;
+        let embedded = self.is_embedded();
         let texture_size = wgpu::Extent3d {
             width: self.config.width,
             height: self.config.height,
@@ -424,6 +511,7 @@ impl RenderCore {
             ops,
             resources,
             external_resources,
+            encoder_work,
         } = execution;
         for resource in &external_resources {
             self.external_textures
@@ -435,12 +523,6 @@ impl RenderCore {
         let last_use_passes = compute_last_use_passes(&passes, resources.len());
         let build_passes = build_start.elapsed();
 
-        let mut encoder = self
-            .device
-            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
-                label: Some("Render Encoder"),
-            });
-
         let device = &self.device;
         let queue = &self.queue;
         let config = &self.config;
@@ -459,6 +541,16 @@ impl RenderCore {
             .pipeline_registry
             .begin_all_frames(device, queue, config);
 
+        // Offscreen composite work comes first so the passes below can
+        // sample its output.
+        for work in encoder_work {
+            work(&mut CompositeEncodeContext {
+                device,
+                queue,
+                encoder,
+            });
+        }
+
         let mut scene_texture_view = targets.offscreen.clone();
         let mut scene_source = SceneSource::Offscreen;
         let mut clip_stack: SmallVec<[PxRect; 16]> = SmallVec::new();
@@ -473,10 +565,6 @@ impl RenderCore {
             external: self.external_textures.clone(),
         });
 
-        let output_view = output_frame
-            .texture
-            .create_view(&wgpu::TextureViewDescriptor::default());
-
         let mut clear_state = RenderPassClearState::new(resources.len(), &external_resources);
 
         let mut frame_state = RenderCoreFrameState {
@@ -493,7 +581,7 @@ impl RenderCore {
             Self::execute_render_pass(
                 &mut frame_state,
                 RenderPassExecParams {
-                    encoder: &mut encoder,
+                    encoder,
                     scene_texture_view: &mut scene_texture_view,
                     scene_source: &mut scene_source,
                     resources: &mut frame_resources,
@@ -506,24 +594,32 @@ impl RenderCore {
         }
 
         let target_size = PxSize::new(Px(self.config.width as i32), Px(self.config.height as i32));
+        if !clear_state.scene_written {
+            // Nothing drew this frame, so the scene still holds the last one.
+            Self::clear_view(encoder, &scene_texture_view);
+        }
         RenderCore::blit_to_view(BlitParams {
-            encoder: &mut encoder,
+            encoder,
             device,
             source: &scene_texture_view,
-            target: &output_view,
+            target: output_view,
             bind_group_layout: &blit.bind_group_layout,
             sampler: &blit.sampler,
-            pipeline: &blit.pipeline,
+            pipeline: if embedded {
+                &blit.pipeline_over
+            } else {
+                &blit.pipeline
+            },
             target_size,
             scissor_rect: None,
         });
-        #[cfg(feature = "debug-dirty-overlay")]
+        #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
         Self::render_dirty_overlay(
-            &mut encoder,
-            &output_view,
+            encoder,
+            output_view,
             &blit.dirty_overlay_pipeline,
             target_size,
-            dirty_overlay_rects,
+            overlay_rects,
         );
 
         // Frame-level end for all pipelines
@@ -534,26 +630,12 @@ impl RenderCore {
             .end_all_frames(device, queue, config);
         let encode = encode_start.elapsed();
 
-        let submit_start = Instant::now();
-        queue.submit(Some(encoder.finish()));
-        let submit = submit_start.elapsed();
-
-        let present_start = Instant::now();
-        output_frame.present();
-        if reconfigure_after_present {
-            self.resize_surface();
-        }
-        let present = present_start.elapsed();
         self.external_textures.collect_garbage(current_frame, 2);
         self.frame_index = self.frame_index.wrapping_add(1);
-        self.last_render_breakdown = Some(RenderTimingBreakdown {
-            acquire,
+        EncodeTimings {
             build_passes,
             encode,
-            submit,
-            present,
-            total: render_start.elapsed(),
-        });
+        }
     }
 
     fn execute_render_pass(
@@ -804,6 +886,23 @@ impl RenderCore {
         }
     }
 
+    fn clear_view(encoder: &mut wgpu::CommandEncoder, view: &wgpu::TextureView) {
+        encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Scene Clear Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                depth_slice: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            ..Default::default()
+        });
+    }
+
     fn blit_to_view(params: BlitParams<'_>) {
         let BlitParams {
             encoder,
@@ -862,7 +961,7 @@ impl RenderCore {
         rpass.draw(0..3, 0..1);
     }
 
-    #[cfg(feature = "debug-dirty-overlay")]
+    #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
     fn render_dirty_overlay(
         encoder: &mut wgpu::CommandEncoder,
         target: &wgpu::TextureView,
@@ -15,7 +15,23 @@ use crate::{
     },
 };
 
-use super::{BlitState, ComputeState, FrameTargets, LocalTexturePool, RenderCore, RenderPipelines};
+use super::{
+    BlitState, ComputeState, FrameTargets, LocalTexturePool, RenderCore, RenderPipelines,
+    WindowPresentation,
+};
+
+/// Device, configuration, and presentation state shared by every way of
+/// creating a render core.
+struct CoreParts {
+    presentation: Option<WindowPresentation>,
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    config: wgpu::SurfaceConfiguration,
+    size: winit::dpi::PhysicalSize<u32>,
+    pipeline_cache: Option<wgpu::PipelineCache>,
+    adapter_info: Option<wgpu::AdapterInfo>,
+    sample_count: u32,
+}
 
 impl RenderCore {
     fn pick_alpha_mode(
@@ -212,6 +228,70 @@ impl RenderCore {
         // Create pipeline cache if supported
         let pipeline_cache = initialize_cache(&device, &adapter_info);
 
+        // Set scale factor for dp conversion
+        let scale_factor = window.scale_factor();
+        info!("Window scale factor: {scale_factor}");
+        let _ = SCALE_FACTOR.set(RwLock::new(scale_factor));
+
+        Self::from_parts(CoreParts {
+            presentation: Some(WindowPresentation {
+                window,
+                instance,
+                surface,
+            }),
+            device,
+            queue,
+            config,
+            size,
+            pipeline_cache,
+            adapter_info: Some(adapter_info),
+            sample_count,
+        })
+    }
+
+    /// Create a render core that draws into textures owned by the host
+    /// application instead of a window surface.
+    pub(crate) fn new_embedded(
+        device: wgpu::Device,
+        queue: wgpu::Queue,
+        format: TextureFormat,
+        size: winit::dpi::PhysicalSize<u32>,
+        sample_count: u32,
+    ) -> Self {
+        let config = wgpu::SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            format,
+            width: size.width.max(1),
+            height: size.height.max(1),
+            present_mode: wgpu::PresentMode::Fifo,
+            alpha_mode: wgpu::CompositeAlphaMode::Auto,
+            view_formats: vec![],
+            desired_maximum_frame_latency: 2,
+        };
+        Self::from_parts(CoreParts {
+            presentation: None,
+            device,
+            queue,
+            config,
+            size,
+            pipeline_cache: None,
+            adapter_info: None,
+            sample_count,
+        })
+    }
+
+    fn from_parts(parts: CoreParts) -> Self {
+        let CoreParts {
+            presentation,
+            device,
+            queue,
+            config,
+            size,
+            pipeline_cache,
+            adapter_info,
+            sample_count,
+        } = parts;
+
         // Create MSAA Target
         let (msaa_texture, msaa_view) = Self::make_msaa_resources(&device, sample_count, &config);
 
@@ -233,11 +313,6 @@ impl RenderCore {
 
         let drawer = Drawer::new();
 
-        // Set scale factor for dp conversion
-        let scale_factor = window.scale_factor();
-        info!("Window scale factor: {scale_factor}");
-        let _ = SCALE_FACTOR.set(RwLock::new(scale_factor));
-
         // Create blit pipeline resources
         let blit_shader = device.create_shader_module(wgpu::include_wgsl!("../shaders/blit.wgsl"));
         let blit_sampler = device.create_sampler(&wgpu::SamplerDescriptor::default());
@@ -291,6 +366,34 @@ impl RenderCore {
             multiview_mask: None,
             cache: pipeline_cache.as_ref(),
         });
+        // Embedded cores draw the UI over the host's frame instead of
+        // replacing it. Scene colors are premultiplied by the alpha blending
+        // of the draw pipelines.
+        let blit_pipeline_over = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Blit Pipeline Over"),
+            layout: Some(&blit_pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &blit_shader,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &blit_shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: config.format,
+                    blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview_mask: None,
+            cache: pipeline_cache.as_ref(),
+        });
         let blit_pipeline_rgba = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
             label: Some("Blit Pipeline Rgba8"),
             layout: Some(&blit_pipeline_layout),
@@ -312,17 +415,17 @@ impl RenderCore {
             multiview_mask: None,
             cache: pipeline_cache.as_ref(),
         });
-        #[cfg(feature = "debug-dirty-overlay")]
+        #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
         let dirty_overlay_shader =
             device.create_shader_module(wgpu::include_wgsl!("../shaders/dirty_overlay.wgsl"));
-        #[cfg(feature = "debug-dirty-overlay")]
+        #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
         let dirty_overlay_pipeline_layout =
             device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
                 label: Some("Dirty Overlay Pipeline Layout"),
                 bind_group_layouts: &[],
                 immediate_size: 0,
             });
-        #[cfg(feature = "debug-dirty-overlay")]
+        #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
         let dirty_overlay_pipeline =
             device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
                 label: Some("Dirty Overlay Pipeline"),
@@ -383,18 +486,17 @@ impl RenderCore {
 
         let blit = BlitState {
             pipeline: blit_pipeline,
+            pipeline_over: blit_pipeline_over,
             pipeline_rgba: blit_pipeline_rgba,
             bind_group_layout: blit_bind_group_layout,
             sampler: blit_sampler,
-            #[cfg(feature = "debug-dirty-overlay")]
+            #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
             dirty_overlay_pipeline,
         };
 
         Self {
-            window,
-            instance,
+            presentation,
             device,
-            surface,
             queue,
             config,
             size,
@@ -488,24 +590,32 @@ impl RenderCore {
         if self.size.width > 0 && self.size.height > 0 {
             self.config.width = self.size.width;
             self.config.height = self.size.height;
-            self.surface.configure(&self.device, &self.config);
+            if let Some(presentation) = self.presentation.as_ref() {
+                presentation.surface.configure(&self.device, &self.config);
+            }
             self.rebuild_pass_targets();
         }
     }
 
     pub(crate) fn recreate_surface(&mut self) {
-        let surface = match self.instance.create_surface(self.window.clone()) {
+        let Some(presentation) = self.presentation.as_mut() else {
+            return;
+        };
+        let surface = match presentation
+            .instance
+            .create_surface(presentation.window.clone())
+        {
             Ok(surface) => surface,
             Err(err) => {
                 error!("Failed to recreate surface: {err:?}");
                 return;
             }
         };
-        self.surface = surface;
+        presentation.surface = surface;
         if self.size.width > 0 && self.size.height > 0 {
             self.config.width = self.size.width;
             self.config.height = self.size.height;
-            self.surface.configure(&self.device, &self.config);
+            presentation.surface.configure(&self.device, &self.config);
             self.rebuild_pass_targets();
         }
     }
@@ -0,0 +1,526 @@
+//! Embedding Tessera in an application that owns its event loop and GPU.
+//!
+//! ## Usage
+//!
+//! Draw Tessera UI on top of a custom engine's frame, or render frames
+//! offscreen at fixed timestamps for tests and captures.
+
+use std::{sync::Arc, time::Duration};
+
+use parking_lot::RwLock;
+use winit::{
+    dpi::PhysicalSize,
+    event::{ElementState, MouseButton, MouseScrollDelta, Touch, TouchPhase, WindowEvent},
+    window::CursorIcon,
+};
+
+use crate::{
+    ImeState, PxPosition, PxSize,
+    cursor::{CursorEventContent, CursorState, GestureState, MOUSE_POINTER_ID, PointerChange},
+    dp::SCALE_FACTOR,
+    focus::flush_pending_focus_callbacks,
+    keyboard_state::KeyboardState,
+    pipeline_context::PipelineContext,
+    render_module::RenderModule,
+    runtime::{
+        TesseraRuntime, clear_redraw_waker, has_pending_build_invalidations,
+        has_pending_frame_nanos_receivers, install_redraw_waker,
+    },
+    time::Instant,
+};
+
+use super::{
+    RecordedFrame, RenderCore, RenderFrameArgs, Renderer, RendererImeBridgeState,
+    RuntimePendingWork, composite,
+};
+
+/// Target description for an [`EmbeddedRenderer`].
+#[derive(Clone, Copy, Debug)]
+pub struct EmbeddedConfig {
+    /// Format of the texture views passed to [`EmbeddedRenderer::frame`].
+    pub format: wgpu::TextureFormat,
+    /// Target size in physical pixels.
+    pub size: PhysicalSize<u32>,
+    /// Physical pixels per logical pixel, used for `Dp` conversion.
+    pub scale_factor: f64,
+    /// MSAA sample count for render pipelines.
+    pub sample_count: u32,
+    /// Time between display frames, used to schedule animation frames.
+    pub frame_interval: Duration,
+}
+
+impl EmbeddedConfig {
+    /// Creates a config for targets of `format` and `size`, at scale factor
+    /// 1 and without MSAA like the default [`super::TesseraConfig`], paced
+    /// for a 60 Hz display.
+    pub fn new(format: wgpu::TextureFormat, size: PhysicalSize<u32>) -> Self {
+        Self {
+            format,
+            size,
+            scale_factor: 1.0,
+            sample_count: 1,
+            frame_interval: Duration::from_nanos(1_000_000_000 / 60),
+        }
+    }
+
+    /// Sets the scale factor.
+    pub fn with_scale_factor(mut self, scale_factor: f64) -> Self {
+        self.scale_factor = scale_factor;
+        self
+    }
+
+    /// Sets the MSAA sample count.
+    pub fn with_sample_count(mut self, sample_count: u32) -> Self {
+        self.sample_count = sample_count;
+        self
+    }
+
+    /// Sets the time between display frames.
+    pub fn with_frame_interval(mut self, frame_interval: Duration) -> Self {
+        self.frame_interval = frame_interval;
+        self
+    }
+}
+
+/// Window requests produced by one embedded frame.
+///
+/// The host applies these to its own window, the way [`Renderer`] applies
+/// them to the window it owns.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EmbeddedFrame {
+    /// When the UI wants the next frame: the frame time itself after state
+    /// changes, one frame interval later while animations run. `None` means
+    /// it is idle until the next input event or waker call.
+    pub next_frame: Option<Instant>,
+    /// Cursor icon requested by the component under the pointer.
+    pub cursor_icon: CursorIcon,
+    /// Whether a text input currently wants IME input.
+    pub ime_allowed: bool,
+    /// IME candidate window anchor in physical pixels, when known.
+    pub ime_cursor_area: Option<(PxPosition, PxSize)>,
+}
+
+/// Runs Tessera inside a host application's event loop and GPU device.
+///
+/// The host creates the device and queue, forwards winit window events with
+/// [`Self::handle_window_event`], and calls [`Self::frame`] to record the UI
+/// into its own command encoder. Tessera never submits or presents; the
+/// host submits the encoder together with its other work.
+///
+/// The device must enable [`wgpu::Features::CLEAR_TEXTURE`]. Like
+/// [`Renderer`], only one Tessera UI can run per process because the
+/// component runtime is global.
+///
+/// # Examples
+///
+/// ```no_run
+/// use tessera_ui::{
+///     renderer::{EmbeddedConfig, EmbeddedRenderer},
+///     wgpu,
+///     winit::dpi::PhysicalSize,
+/// };
+///
+/// # fn host(device: wgpu::Device, queue: wgpu::Queue, target: wgpu::TextureView) {
+/// let config = EmbeddedConfig::new(
+///     wgpu::TextureFormat::Bgra8UnormSrgb,
+///     PhysicalSize::new(1280, 720),
+/// );
+/// let mut ui = EmbeddedRenderer::new(device.clone(), queue.clone(), config, || {}, vec![]);
+///
+/// let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+/// // ... record the host scene into `target` ...
+/// let frame = ui.frame(&mut encoder, &target);
+/// queue.submit(Some(encoder.finish()));
+/// if frame.next_frame.is_some() {
+///     // Request another redraw from the host window.
+/// }
+/// # }
+/// ```
+pub struct EmbeddedRenderer<F: Fn()> {
+    core: RenderCore,
+    entry_point: F,
+    cursor_state: CursorState,
+    keyboard_state: KeyboardState,
+    ime_state: ImeState,
+    ime_bridge_state: RendererImeBridgeState,
+    frame_interval: Duration,
+    frame_index: u64,
+}
+
+impl<F: Fn()> EmbeddedRenderer<F> {
+    /// Creates an embedded renderer drawing with the host's device and queue.
+    ///
+    /// Render modules register their pipelines here, as they would when
+    /// [`Renderer`] creates its window.
+    pub fn new(
+        device: wgpu::Device,
+        queue: wgpu::Queue,
+        config: EmbeddedConfig,
+        entry_point: F,
+        modules: Vec<Box<dyn RenderModule>>,
+    ) -> Self {
+        set_scale_factor(config.scale_factor);
+        let mut core = RenderCore::new_embedded(
+            device,
+            queue,
+            config.format,
+            config.size,
+            config.sample_count,
+        );
+        let mut context = PipelineContext::new(&mut core);
+        for module in &modules {
+            module.register_pipelines(&mut context);
+        }
+        let mut renderer = Self {
+            core,
+            entry_point,
+            cursor_state: CursorState::default(),
+            keyboard_state: KeyboardState::default(),
+            ime_state: ImeState::default(),
+            ime_bridge_state: RendererImeBridgeState::default(),
+            frame_interval: config.frame_interval,
+            frame_index: 0,
+        };
+        renderer.resize(config.size);
+        renderer
+    }
+
+    /// Installs a callback run from any thread when state changes outside
+    /// input handling, such as animations starting or background tasks
+    /// posting results. The host should request a redraw in response.
+    pub fn set_waker(&self, waker: impl Fn() + Send + Sync + 'static) {
+        let waker: Arc<dyn Fn() + Send + Sync> = Arc::new(waker);
+        crate::ui_thread::install_waker(waker.clone());
+        install_redraw_waker(waker);
+    }
+
+    /// Resizes the render target. Views passed to [`Self::frame`] must match
+    /// the new size.
+    pub fn resize(&mut self, size: PhysicalSize<u32>) {
+        let minimized = size.width == 0 || size.height == 0;
+        TesseraRuntime::with_mut(|runtime| runtime.window_minimized = minimized);
+        if !minimized {
+            self.core.resize(size);
+        }
+    }
+
+    /// Updates the scale factor used for `Dp` conversion.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        set_scale_factor(scale_factor);
+    }
+
+    /// Feeds a window event from the host's event loop.
+    ///
+    /// Returns whether the event can change the UI, in which case the host
+    /// should schedule a frame. Positions are relative to the target's top
+    /// left corner in physical pixels.
+    pub fn handle_window_event(&mut self, event: &WindowEvent) -> bool {
+        match event {
+            WindowEvent::Resized(size) => self.resize(*size),
+            WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                self.set_scale_factor(*scale_factor);
+            }
+            WindowEvent::CursorMoved { position, .. } => {
+                let position = PxPosition::from_f64_arr2([position.x, position.y]);
+                self.cursor_state.update_position(position);
+                self.push_pointer(
+                    CursorEventContent::Moved(position),
+                    GestureState::TapCandidate,
+                );
+            }
+            WindowEvent::CursorLeft { .. } => self.cursor_state.clear(),
+            WindowEvent::MouseInput { state, button, .. } => {
+                self.handle_mouse_input(*state, *button);
+            }
+            WindowEvent::MouseWheel { delta, .. } => self.handle_mouse_wheel(*delta),
+            WindowEvent::Touch(touch) => self.handle_touch(*touch),
+            WindowEvent::PinchGesture { delta, .. } => self.push_pointer(
+                CursorEventContent::from_pinch_gesture(*delta),
+                GestureState::Dragged,
+            ),
+            WindowEvent::RotationGesture { delta, .. } => self.push_pointer(
+                CursorEventContent::from_rotation_gesture(*delta),
+                GestureState::Dragged,
+            ),
+            WindowEvent::KeyboardInput { event, .. } => {
+                self.keyboard_state.push_event(event.clone());
+            }
+            WindowEvent::ModifiersChanged(modifiers) => {
+                self.keyboard_state.update_modifiers(modifiers.state());
+            }
+            WindowEvent::Ime(ime_event) => self.ime_state.push_event(ime_event.clone()),
+            WindowEvent::Focused(focused) => {
+                TesseraRuntime::with_mut(|runtime| {
+                    runtime
+                        .component_tree
+                        .focus_owner_mut()
+                        .set_owner_focused(*focused);
+                });
+                flush_pending_focus_callbacks();
+            }
+            WindowEvent::Occluded(occluded) => {
+                TesseraRuntime::with_mut(|runtime| runtime.window_occluded = *occluded);
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Builds the UI and records it into `encoder`, drawing over `target`.
+    ///
+    /// `target` must use the configured format and size. The UI is blended
+    /// over what the host already drew there, and areas without UI content
+    /// keep the host's pixels. Nothing is submitted.
+    pub fn frame(
+        &mut self,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+    ) -> EmbeddedFrame {
+        self.frame_at(encoder, target, Instant::now())
+    }
+
+    /// Like [`Self::frame`], but with an explicit frame time.
+    ///
+    /// Animations and frame callbacks observe `now`, so feeding evenly
+    /// spaced timestamps renders deterministic offscreen sequences.
+    pub fn frame_at(
+        &mut self,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        now: Instant,
+    ) -> EmbeddedFrame {
+        self.core.resize_if_needed();
+        Renderer::<F>::begin_ui_frame(now, &self.core);
+        let screen_size: PxSize = self.core.size().into();
+        let frame_idx = self.frame_index;
+        let RecordedFrame {
+            graph,
+            window_requests,
+            ..
+        } = {
+            let mut args = RenderFrameArgs {
+                cursor_state: &mut self.cursor_state,
+                keyboard_state: &mut self.keyboard_state,
+                ime_state: &mut self.ime_state,
+                ime_bridge_state: &mut self.ime_bridge_state,
+                app: &mut self.core,
+            };
+            Renderer::<F>::build_and_record_frame(&self.entry_point, &mut args, frame_idx)
+        };
+
+        if !TesseraRuntime::with(|runtime| runtime.window_minimized) {
+            let (composite_context, composite_registry) =
+                self.core.composite_context_parts(screen_size, frame_idx);
+            let graph = composite::expand_composites(graph, composite_context, composite_registry);
+            self.core.render_to_view(
+                encoder,
+                target,
+                graph.into_execution(),
+                #[cfg(any(feature = "debug-dirty-overlay", feature = "inspector"))]
+                &[],
+            );
+        }
+
+        self.cursor_state.frame_cleanup();
+        self.frame_index = self.frame_index.wrapping_add(1);
+
+        let ime_cursor_area = window_requests
+            .ime_request
+            .as_ref()
+            .and_then(|request| request.position.map(|position| (position, request.size)));
+        let pending_work = RuntimePendingWork {
+            invalidation_pending: has_pending_build_invalidations(),
+            frame_receiver_pending: has_pending_frame_nanos_receivers(),
+        };
+        EmbeddedFrame {
+            next_frame: pending_work.next_frame_at(now, self.frame_interval),
+            cursor_icon: window_requests.cursor_icon,
+            ime_allowed: window_requests.ime_request.is_some(),
+            ime_cursor_area,
+        }
+    }
+
+    fn push_pointer(&mut self, content: CursorEventContent, gesture_state: GestureState) {
+        self.cursor_state.push_event(PointerChange {
+            timestamp: Instant::now(),
+            pointer_id: MOUSE_POINTER_ID,
+            position: self.cursor_state.position(),
+            content,
+            gesture_state,
+            consumed: false,
+        });
+    }
+
+    fn handle_mouse_input(&mut self, state: ElementState, button: MouseButton) {
+        if let Some(content) = CursorEventContent::from_press_event(state, button) {
+            self.push_pointer(content, GestureState::TapCandidate);
+        }
+    }
+
+    fn handle_mouse_wheel(&mut self, delta: MouseScrollDelta) {
+        self.push_pointer(
+            CursorEventContent::from_scroll_event(delta),
+            GestureState::Dragged,
+        );
+    }
+
+    fn handle_touch(&mut self, touch: Touch) {
+        let position = PxPosition::from_f64_arr2([touch.location.x, touch.location.y]);
+        match touch.phase {
+            TouchPhase::Started => self.cursor_state.handle_touch_start(touch.id, position),
+            TouchPhase::Moved => {
+                if let Some(scroll_event) = self.cursor_state.handle_touch_move(touch.id, position)
+                {
+                    self.cursor_state.push_event(scroll_event);
+                }
+            }
+            TouchPhase::Ended | TouchPhase::Cancelled => {
+                self.cursor_state.handle_touch_end(touch.id);
+            }
+        }
+    }
+}
+
+impl<F: Fn()> Drop for EmbeddedRenderer<F> {
+    fn drop(&mut self) {
+        clear_redraw_waker();
+        crate::ui_thread::clear_waker();
+    }
+}
+
+fn set_scale_factor(scale_factor: f64) {
+    if let Some(scale_factor_lock) = SCALE_FACTOR.get() {
+        *scale_factor_lock.write() = scale_factor;
+    } else {
+        let _ = SCALE_FACTOR.set(RwLock::new(scale_factor));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIZE: u32 = 8;
+    const HOST_PIXEL: [u8; 4] = [255, 0, 0, 255];
+    /// Row pitch of the readback buffer, padded to wgpu's copy alignment.
+    const PADDED_ROW: u32 = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+
+    #[test]
+    fn next_frame_waits_one_interval_for_frame_callbacks() {
+        let now = Instant::now();
+        let interval = Duration::from_millis(16);
+        let pending = |invalidation_pending, frame_receiver_pending| RuntimePendingWork {
+            invalidation_pending,
+            frame_receiver_pending,
+        };
+        assert_eq!(pending(false, false).next_frame_at(now, interval), None);
+        assert_eq!(
+            pending(false, true).next_frame_at(now, interval),
+            Some(now + interval)
+        );
+        assert_eq!(pending(true, true).next_frame_at(now, interval), Some(now));
+    }
+
+    fn gpu() -> (wgpu::Device, wgpu::Queue) {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::new_without_display_handle());
+        let adapter =
+            pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+                .expect("a GPU adapter should be available");
+        pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor {
+            required_features: wgpu::Features::CLEAR_TEXTURE,
+            ..Default::default()
+        }))
+        .expect("device creation should succeed")
+    }
+
+    /// Draws the host scene as a solid color, renders the UI over it at
+    /// `now` and reads the target back row by row.
+    fn render_frame<F: Fn()>(
+        ui: &mut EmbeddedRenderer<F>,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        now: Instant,
+    ) -> Vec<u8> {
+        let target = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("host target"),
+            size: wgpu::Extent3d {
+                width: SIZE,
+                height: SIZE,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = target.create_view(&wgpu::TextureViewDescriptor::default());
+        let readback = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("host readback"),
+            size: u64::from(PADDED_ROW * SIZE),
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("host scene"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &view,
+                resolve_target: None,
+                depth_slice: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::RED),
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            ..Default::default()
+        });
+        ui.frame_at(&mut encoder, &view, now);
+        encoder.copy_texture_to_buffer(
+            target.as_image_copy(),
+            wgpu::TexelCopyBufferInfo {
+                buffer: &readback,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(PADDED_ROW),
+                    rows_per_image: Some(SIZE),
+                },
+            },
+            target.size(),
+        );
+        queue.submit(Some(encoder.finish()));
+
+        readback.slice(..).map_async(wgpu::MapMode::Read, |result| {
+            result.expect("readback mapping should succeed");
+        });
+        device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .expect("device poll should succeed");
+        let mapped = readback.slice(..).get_mapped_range();
+        mapped
+            .chunks(PADDED_ROW as usize)
+            .flat_map(|row| row[..(SIZE * 4) as usize].to_vec())
+            .collect()
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn offscreen_frames_keep_host_pixels_and_repeat_exactly() {
+        let (device, queue) = gpu();
+        let config = EmbeddedConfig::new(
+            wgpu::TextureFormat::Rgba8Unorm,
+            PhysicalSize::new(SIZE, SIZE),
+        );
+        let mut ui = EmbeddedRenderer::new(device.clone(), queue.clone(), config, || {}, vec![]);
+
+        let start = Instant::now();
+        let first = render_frame(&mut ui, &device, &queue, start);
+        let second = render_frame(&mut ui, &device, &queue, start + config.frame_interval);
+
+        assert!(first.chunks(4).all(|pixel| pixel == HOST_PIXEL));
+        assert_eq!(first, second);
+    }
+}
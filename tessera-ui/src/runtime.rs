@@ -15,8 +15,11 @@ use slotmap::{SlotMap, new_key_type};
 use smallvec::SmallVec;
 
 use crate::{
-    NodeId,
-    accessibility::{AccessibilityActionHandler, AccessibilityNode},
+    NodeId, PxRect,
+    accessibility::{
+        AccessibilityActionHandler, AccessibilityActionHandlers, AccessibilityNode,
+        AccessibilityTextActionHandler,
+    },
     component_tree::ComponentTree,
     execution_context::{OrderFrame, with_execution_context, with_execution_context_mut},
     focus::{
@@ -1291,6 +1294,139 @@ where
     });
 }
 
+#[derive(Default)]
+struct VisibilityTracker {
+    observers: HashMap<u64, SmallVec<[State<bool>; 1]>>,
+}
+
+fn with_visibility_tracker_mut<R>(f: impl FnOnce(&mut VisibilityTracker) -> R) -> R {
+    RUNTIME_GLOBALS.with(|globals| f(&mut globals.visibility_tracker.borrow_mut()))
+}
+
+/// Remember a state that tracks whether the current component is visible.
+///
+/// The value is refreshed after every layout pass. It is `false` while the
+/// window is minimized or occluded, or when the component was not placed,
+/// lies outside the window, or is fully clipped by an ancestor. Components
+/// start out visible until their first layout pass completes.
+///
+/// Writes only happen when visibility changes, so reading the state
+/// recomposes the component on transitions only. Use it to pause animations
+/// and other per-frame work that nobody can see.
+pub fn remember_visibility() -> State<bool> {
+    if !matches!(current_phase(), Some(RuntimePhase::Build)) {
+        panic!("remember_visibility must be called inside a tessera component build");
+    }
+    let visible = remember(|| true);
+    let owner_instance_key = current_replay_boundary_instance_key_from_scope()
+        .unwrap_or_else(|| panic!("remember_visibility requires an active component node context"));
+    with_visibility_tracker_mut(|tracker| {
+        let observers = tracker.observers.entry(owner_instance_key).or_default();
+        if !observers.contains(&visible) {
+            observers.push(visible);
+        }
+    });
+    visible
+}
+
+pub(crate) fn has_visibility_observers() -> bool {
+    with_visibility_tracker_mut(|tracker| !tracker.observers.is_empty())
+}
+
+/// Updates visibility states from the latest layout.
+///
+/// `visibility_of` returns the visibility of the node with the given instance
+/// key, or `None` when the node is no longer in the tree.
+pub(crate) fn sync_visibility_observers(
+    window_visible: bool,
+    mut visibility_of: impl FnMut(u64) -> Option<bool>,
+) {
+    let observers: Vec<(u64, State<bool>)> = with_visibility_tracker_mut(|tracker| {
+        tracker.observers.retain(|_, states| {
+            states.retain(|state| state.is_alive());
+            !states.is_empty()
+        });
+        tracker
+            .observers
+            .iter()
+            .flat_map(|(key, states)| states.iter().map(|state| (*key, *state)))
+            .collect()
+    });
+    for (instance_key, state) in observers {
+        let Some(visible) = visibility_of(instance_key) else {
+            continue;
+        };
+        let visible = visible && window_visible;
+        if state.with(|current| *current != visible) {
+            state.set(visible);
+        }
+    }
+}
+
+#[derive(Default)]
+struct BoundsTracker {
+    observers: HashMap<u64, SmallVec<[State<Option<PxRect>>; 1]>>,
+}
+
+fn with_bounds_tracker_mut<R>(f: impl FnOnce(&mut BoundsTracker) -> R) -> R {
+    RUNTIME_GLOBALS.with(|globals| f(&mut globals.bounds_tracker.borrow_mut()))
+}
+
+/// Remember a state that tracks where the current component was placed.
+///
+/// The value is the component's rect in window coordinates, refreshed after
+/// every layout pass, and `None` until the component is first placed.
+/// Components without a layout of their own report the union of their
+/// children's rects.
+///
+/// Writes only happen when the bounds change. Use it to anchor content that
+/// is rendered elsewhere in the tree, such as popups, to this component.
+pub fn remember_bounds() -> State<Option<PxRect>> {
+    if !matches!(current_phase(), Some(RuntimePhase::Build)) {
+        panic!("remember_bounds must be called inside a tessera component build");
+    }
+    let bounds = remember(|| None);
+    let owner_instance_key = current_replay_boundary_instance_key_from_scope()
+        .unwrap_or_else(|| panic!("remember_bounds requires an active component node context"));
+    with_bounds_tracker_mut(|tracker| {
+        let observers = tracker.observers.entry(owner_instance_key).or_default();
+        if !observers.contains(&bounds) {
+            observers.push(bounds);
+        }
+    });
+    bounds
+}
+
+pub(crate) fn has_bounds_observers() -> bool {
+    with_bounds_tracker_mut(|tracker| !tracker.observers.is_empty())
+}
+
+/// Updates bounds states from the latest layout.
+///
+/// `bounds_of` returns the bounds of the node with the given instance key, or
+/// `None` when the node is no longer in the tree.
+pub(crate) fn sync_bounds_observers(mut bounds_of: impl FnMut(u64) -> Option<Option<PxRect>>) {
+    let observers: Vec<(u64, State<Option<PxRect>>)> = with_bounds_tracker_mut(|tracker| {
+        tracker.observers.retain(|_, states| {
+            states.retain(|state| state.is_alive());
+            !states.is_empty()
+        });
+        tracker
+            .observers
+            .iter()
+            .flat_map(|(key, states)| states.iter().map(|state| (*key, *state)))
+            .collect()
+    });
+    for (instance_key, state) in observers {
+        let Some(bounds) = bounds_of(instance_key) else {
+            continue;
+        };
+        if state.with(|current| *current != bounds) {
+            state.set(bounds);
+        }
+    }
+}
+
 pub(crate) fn drop_slots_for_instance_logic_ids(instance_logic_ids: &HashSet<u64>) {
     if instance_logic_ids.is_empty() {
         return;
@@ -1532,7 +1668,13 @@ impl<T> State<T>
 where
     T: Send + Sync + 'static,
 {
-    fn is_alive(&self) -> bool {
+    /// Returns whether the component that remembered this state is still
+    /// mounted.
+    ///
+    /// Tasks queued with [`post_to_ui_thread`](crate::post_to_ui_thread) can
+    /// check this before writing to state whose owner may have been removed
+    /// in the meantime.
+    pub fn is_alive(&self) -> bool {
         with_slot_table(|table| {
             let Some(entry) = table.entries.get(self.slot) else {
                 return false;
@@ -1632,6 +1774,8 @@ struct RuntimeGlobals {
     persistent_focus_handle_store: RefCell<PersistentFocusHandleStore>,
     frame_clock_tracker: RefCell<FrameClockTracker>,
     layout_dirty_tracker: RefCell<LayoutDirtyTracker>,
+    visibility_tracker: RefCell<VisibilityTracker>,
+    bounds_tracker: RefCell<BoundsTracker>,
     runtime: RefCell<TesseraRuntime>,
 }
 
@@ -1650,6 +1794,8 @@ impl RuntimeGlobals {
             persistent_focus_handle_store: RefCell::new(PersistentFocusHandleStore::default()),
             frame_clock_tracker: RefCell::new(FrameClockTracker::default()),
             layout_dirty_tracker: RefCell::new(LayoutDirtyTracker::default()),
+            visibility_tracker: RefCell::new(VisibilityTracker::default()),
+            bounds_tracker: RefCell::new(BoundsTracker::default()),
             runtime: RefCell::new(TesseraRuntime::default()),
         }
     }
@@ -1670,6 +1816,8 @@ pub struct TesseraRuntime {
     pub cursor_icon_request: Option<winit::window::CursorIcon>,
     /// Whether the window is currently minimized.
     pub(crate) window_minimized: bool,
+    /// Whether the window is currently hidden behind other windows.
+    pub(crate) window_occluded: bool,
 }
 
 impl TesseraRuntime {
@@ -1694,6 +1842,11 @@ impl TesseraRuntime {
         self.window_size
     }
 
+    /// Returns whether the window is neither minimized nor occluded.
+    pub fn is_window_visible(&self) -> bool {
+        !self.window_minimized && !self.window_occluded
+    }
+
     /// Sets identity fields for the current component node.
     pub(crate) fn set_current_node_identity(&mut self, instance_key: u64, instance_logic_id: u64) {
         if let Some(node) = self.component_tree.current_node_mut() {
@@ -1839,6 +1992,38 @@ impl TesseraRuntime {
         }
     }
 
+    pub(crate) fn set_current_accessibility_action_handlers(
+        &mut self,
+        handlers: AccessibilityActionHandlers,
+    ) {
+        if let Some(node_id) = current_node_id()
+            && let Some(metadata) = self.component_tree.metadatas_mut().get_mut(&node_id)
+        {
+            metadata.accessibility_action_handlers = handlers;
+        } else {
+            debug_assert!(
+                false,
+                "set_current_accessibility_action_handlers must be called inside a component build"
+            );
+        }
+    }
+
+    pub(crate) fn set_current_accessibility_text_action_handler(
+        &mut self,
+        handler: Option<AccessibilityTextActionHandler>,
+    ) {
+        if let Some(node_id) = current_node_id()
+            && let Some(metadata) = self.component_tree.metadatas_mut().get_mut(&node_id)
+        {
+            metadata.accessibility_text_action_handler = handler;
+        } else {
+            debug_assert!(
+                false,
+                "set_current_accessibility_text_action_handler must be called inside a component build"
+            );
+        }
+    }
+
     pub(crate) fn bind_current_focus_requester(&mut self, requester: FocusRequester) {
         if let Some(current) = self.component_tree.current_node_mut() {
             current.focus_requester_binding = Some(requester);
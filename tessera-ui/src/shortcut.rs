@@ -0,0 +1,944 @@
+//! # Keyboard Shortcuts
+//!
+//! ## Usage
+//!
+//! Bind named commands to key chords and handle them where focus is.
+//!
+//! A command is identified by a [`CommandId`]. The app-wide registry maps key
+//! sequences to commands; components handle a command with
+//! [`CommandModifierExt::on_command`](crate::modifier::CommandModifierExt::on_command).
+//! When a bound sequence is typed, the handler closest to the focused node
+//! wins, so a command only reaches the focused subtree and the same command
+//! can mean different things in different panes. Handlers registered with
+//! [`register_command_handler`] run when no node in the focus chain handles
+//! the command.
+//!
+//! ```
+//! use tessera_ui::{
+//!     Modifier,
+//!     layout::layout,
+//!     modifier::CommandModifierExt as _,
+//!     shortcut::{KeySequence, bind_command},
+//!     tessera,
+//! };
+//!
+//! #[tessera]
+//! fn editor() {
+//!     layout().modifier(Modifier::new().on_command("file.save", || {
+//!         // Save the document shown by this editor.
+//!     }));
+//! }
+//!
+//! let sequence: KeySequence = "Primary+S".parse().expect("valid shortcut");
+//! bind_command("file.save", sequence);
+//! ```
+//!
+//! `Primary` is Cmd on Apple platforms and Ctrl elsewhere. Sequences of
+//! several chords are written with spaces, e.g. `Primary+K Primary+C`; the
+//! first chord is held until the sequence completes or another key breaks it.
+//!
+//! Users can rebind commands from a config file with [`load_bindings`], one
+//! `command = sequence` line per command.
+
+use std::{error::Error, fmt, path::Path, str::FromStr, sync::Arc};
+
+use parking_lot::RwLock;
+use winit::keyboard::{Key, ModifiersState, NamedKey};
+
+/// Command ids bound by default.
+///
+/// Text inputs handle the edit commands; apps may handle them too.
+pub mod commands {
+    /// Copies the selection.
+    pub const COPY: &str = "edit.copy";
+    /// Cuts the selection.
+    pub const CUT: &str = "edit.cut";
+    /// Pastes the clipboard.
+    pub const PASTE: &str = "edit.paste";
+    /// Selects all content.
+    pub const SELECT_ALL: &str = "edit.select_all";
+}
+
+const DEFAULT_BINDINGS: &[(&str, &str)] = &[
+    (commands::COPY, "Primary+C"),
+    (commands::COPY, "Ctrl+Insert"),
+    (commands::CUT, "Primary+X"),
+    (commands::CUT, "Shift+Delete"),
+    (commands::PASTE, "Primary+V"),
+    (commands::PASTE, "Shift+Insert"),
+    (commands::SELECT_ALL, "Primary+A"),
+];
+
+const NAMED_KEYS: &[(&str, NamedKey)] = &[
+    ("Enter", NamedKey::Enter),
+    ("Tab", NamedKey::Tab),
+    ("Space", NamedKey::Space),
+    ("Escape", NamedKey::Escape),
+    ("Backspace", NamedKey::Backspace),
+    ("Delete", NamedKey::Delete),
+    ("Insert", NamedKey::Insert),
+    ("Home", NamedKey::Home),
+    ("End", NamedKey::End),
+    ("PageUp", NamedKey::PageUp),
+    ("PageDown", NamedKey::PageDown),
+    ("Up", NamedKey::ArrowUp),
+    ("Down", NamedKey::ArrowDown),
+    ("Left", NamedKey::ArrowLeft),
+    ("Right", NamedKey::ArrowRight),
+    ("F1", NamedKey::F1),
+    ("F2", NamedKey::F2),
+    ("F3", NamedKey::F3),
+    ("F4", NamedKey::F4),
+    ("F5", NamedKey::F5),
+    ("F6", NamedKey::F6),
+    ("F7", NamedKey::F7),
+    ("F8", NamedKey::F8),
+    ("F9", NamedKey::F9),
+    ("F10", NamedKey::F10),
+    ("F11", NamedKey::F11),
+    ("F12", NamedKey::F12),
+];
+
+static REGISTRY: RwLock<Option<CommandRegistry>> = RwLock::new(None);
+
+/// Identifies a command, e.g. `file.save`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CommandId(Arc<str>);
+
+impl CommandId {
+    /// Creates a command id.
+    pub fn new(id: impl Into<Arc<str>>) -> Self {
+        Self(id.into())
+    }
+
+    /// Returns the id as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for CommandId {
+    fn from(id: &str) -> Self {
+        Self::new(id)
+    }
+}
+
+impl From<String> for CommandId {
+    fn from(id: String) -> Self {
+        Self::new(id)
+    }
+}
+
+impl fmt::Display for CommandId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// The non-modifier key of a [`KeyChord`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChordKey {
+    /// A character key, stored lowercase.
+    Character(String),
+    /// A named key such as `Enter` or `F5`.
+    Named(NamedKey),
+}
+
+impl ChordKey {
+    /// Returns the name of the key as written in shortcuts, e.g. `P` or
+    /// `PageDown`.
+    pub fn name(&self) -> String {
+        match self {
+            Self::Character(text) => text.to_uppercase(),
+            Self::Named(named) => NAMED_KEYS
+                .iter()
+                .find(|(_, key)| key == named)
+                .map(|(name, _)| (*name).to_string())
+                .unwrap_or_else(|| format!("{named:?}")),
+        }
+    }
+}
+
+/// A key pressed together with modifiers, e.g. `Ctrl+Shift+P`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    key: ChordKey,
+    ctrl: bool,
+    alt: bool,
+    shift: bool,
+    super_key: bool,
+}
+
+impl KeyChord {
+    /// Creates a chord for `key` without modifiers.
+    pub fn new(key: ChordKey) -> Self {
+        let key = match key {
+            ChordKey::Character(text) => ChordKey::Character(text.to_lowercase()),
+            named => named,
+        };
+        Self {
+            key,
+            ctrl: false,
+            alt: false,
+            shift: false,
+            super_key: false,
+        }
+    }
+
+    /// Adds the platform's primary modifier: Cmd on Apple platforms, Ctrl
+    /// elsewhere.
+    pub fn primary(self) -> Self {
+        if is_apple() {
+            self.super_key()
+        } else {
+            self.ctrl()
+        }
+    }
+
+    /// Adds the Ctrl modifier.
+    pub fn ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    /// Adds the Alt (Option) modifier.
+    pub fn alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+
+    /// Adds the Shift modifier.
+    pub fn shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    /// Adds the Super (Cmd, Windows) modifier.
+    pub fn super_key(mut self) -> Self {
+        self.super_key = true;
+        self
+    }
+
+    /// Returns the key of this chord.
+    pub fn key(&self) -> &ChordKey {
+        &self.key
+    }
+
+    /// Returns the modifiers held in this chord.
+    pub fn modifiers(&self) -> ModifiersState {
+        let mut modifiers = ModifiersState::empty();
+        for (held, modifier) in [
+            (self.ctrl, ModifiersState::CONTROL),
+            (self.alt, ModifiersState::ALT),
+            (self.shift, ModifiersState::SHIFT),
+            (self.super_key, ModifiersState::SUPER),
+        ] {
+            if held {
+                modifiers |= modifier;
+            }
+        }
+        modifiers
+    }
+
+    /// Returns the label shown for this chord on the current platform, e.g.
+    /// `Ctrl+Shift+P` or `⇧⌘P`.
+    pub fn label(&self) -> String {
+        self.label_for(is_apple())
+    }
+
+    fn label_for(&self, apple: bool) -> String {
+        let key = self.key.name();
+        if apple {
+            let mut label = String::new();
+            for (held, symbol) in [
+                (self.ctrl, '⌃'),
+                (self.alt, '⌥'),
+                (self.shift, '⇧'),
+                (self.super_key, '⌘'),
+            ] {
+                if held {
+                    label.push(symbol);
+                }
+            }
+            label.push_str(&key);
+            label
+        } else {
+            let mut parts = Vec::new();
+            for (held, name) in [
+                (self.ctrl, "Ctrl"),
+                (self.alt, "Alt"),
+                (self.shift, "Shift"),
+                (self.super_key, "Super"),
+            ] {
+                if held {
+                    parts.push(name.to_string());
+                }
+            }
+            parts.push(key);
+            parts.join("+")
+        }
+    }
+
+    /// Builds the chord typed by a key press; modifier keys alone yield
+    /// `None`.
+    pub(crate) fn from_key_event(key: &Key, modifiers: ModifiersState) -> Option<Self> {
+        let key = match key {
+            Key::Character(text) => ChordKey::Character(text.to_lowercase()),
+            Key::Named(
+                NamedKey::Shift
+                | NamedKey::Control
+                | NamedKey::Alt
+                | NamedKey::AltGraph
+                | NamedKey::Super
+                | NamedKey::Meta
+                | NamedKey::Hyper
+                | NamedKey::Fn
+                | NamedKey::FnLock
+                | NamedKey::CapsLock
+                | NamedKey::NumLock
+                | NamedKey::ScrollLock,
+            ) => return None,
+            Key::Named(named) => ChordKey::Named(*named),
+            _ => return None,
+        };
+        Some(Self {
+            key,
+            ctrl: modifiers.control_key(),
+            alt: modifiers.alt_key(),
+            shift: modifiers.shift_key(),
+            super_key: modifiers.super_key(),
+        })
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = ShortcutParseError;
+
+    /// Parses `Modifier+...+Key`, e.g. `Primary+Shift+P` or `Ctrl++`.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let (modifiers, key) = if text == "+" {
+            ("", "+")
+        } else if let Some(modifiers) = text.strip_suffix("++") {
+            (modifiers, "+")
+        } else {
+            text.rsplit_once('+').unwrap_or(("", text))
+        };
+        if key.is_empty() {
+            return Err(ShortcutParseError::Empty);
+        }
+
+        let mut chars = key.chars();
+        let key = match (chars.next(), chars.next()) {
+            (Some(ch), None) => ChordKey::Character(ch.to_string()),
+            _ => NAMED_KEYS
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(key))
+                .map(|(_, named)| ChordKey::Named(*named))
+                .or_else(|| match key.to_ascii_lowercase().as_str() {
+                    "esc" => Some(ChordKey::Named(NamedKey::Escape)),
+                    "del" => Some(ChordKey::Named(NamedKey::Delete)),
+                    "return" => Some(ChordKey::Named(NamedKey::Enter)),
+                    "arrowup" => Some(ChordKey::Named(NamedKey::ArrowUp)),
+                    "arrowdown" => Some(ChordKey::Named(NamedKey::ArrowDown)),
+                    "arrowleft" => Some(ChordKey::Named(NamedKey::ArrowLeft)),
+                    "arrowright" => Some(ChordKey::Named(NamedKey::ArrowRight)),
+                    _ => None,
+                })
+                .ok_or_else(|| ShortcutParseError::UnknownKey(key.to_string()))?,
+        };
+
+        let mut chord = KeyChord::new(key);
+        for modifier in modifiers.split('+').filter(|part| !part.is_empty()) {
+            chord = match modifier.trim().to_ascii_lowercase().as_str() {
+                "primary" | "mod" | "cmdorctrl" => chord.primary(),
+                "ctrl" | "control" => chord.ctrl(),
+                "alt" | "option" | "opt" => chord.alt(),
+                "shift" => chord.shift(),
+                "super" | "meta" | "cmd" | "command" | "win" => chord.super_key(),
+                _ => return Err(ShortcutParseError::UnknownModifier(modifier.to_string())),
+            };
+        }
+        Ok(chord)
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.label())
+    }
+}
+
+/// One or more chords typed in order, e.g. `Primary+K Primary+C`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeySequence(Vec<KeyChord>);
+
+impl KeySequence {
+    /// Creates a sequence from its chords.
+    pub fn new(chords: Vec<KeyChord>) -> Self {
+        Self(chords)
+    }
+
+    /// Returns the chords of this sequence.
+    pub fn chords(&self) -> &[KeyChord] {
+        &self.0
+    }
+
+    /// Returns the label shown for this sequence on the current platform.
+    pub fn label(&self) -> String {
+        self.0
+            .iter()
+            .map(KeyChord::label)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn starts_with(&self, prefix: &[KeyChord]) -> bool {
+        self.0.starts_with(prefix)
+    }
+}
+
+impl From<KeyChord> for KeySequence {
+    fn from(chord: KeyChord) -> Self {
+        Self(vec![chord])
+    }
+}
+
+impl FromStr for KeySequence {
+    type Err = ShortcutParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let chords = text
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<KeyChord>, _>>()?;
+        if chords.is_empty() {
+            return Err(ShortcutParseError::Empty);
+        }
+        Ok(Self(chords))
+    }
+}
+
+impl fmt::Display for KeySequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.label())
+    }
+}
+
+/// Error returned when a shortcut string cannot be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ShortcutParseError {
+    /// The shortcut has no key.
+    Empty,
+    /// A modifier name is not recognized.
+    UnknownModifier(String),
+    /// A key name is not recognized.
+    UnknownKey(String),
+}
+
+impl fmt::Display for ShortcutParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("shortcut has no key"),
+            Self::UnknownModifier(name) => write!(f, "unknown modifier `{name}`"),
+            Self::UnknownKey(name) => write!(f, "unknown key `{name}`"),
+        }
+    }
+}
+
+impl Error for ShortcutParseError {}
+
+/// Error returned by [`load_bindings`] and [`load_bindings_file`].
+#[derive(Debug)]
+pub enum BindingsError {
+    /// The bindings file could not be read.
+    Io(std::io::Error),
+    /// A line is not `command = sequence[, sequence...]`.
+    Syntax {
+        /// 1-based line number.
+        line: usize,
+    },
+    /// A sequence on a line could not be parsed.
+    Shortcut {
+        /// 1-based line number.
+        line: usize,
+        /// The parse failure.
+        error: ShortcutParseError,
+    },
+}
+
+impl fmt::Display for BindingsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to read bindings: {error}"),
+            Self::Syntax { line } => {
+                write!(f, "line {line}: expected `command = sequence`")
+            }
+            Self::Shortcut { line, error } => write!(f, "line {line}: {error}"),
+        }
+    }
+}
+
+impl Error for BindingsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Syntax { .. } => None,
+            Self::Shortcut { error, .. } => Some(error),
+        }
+    }
+}
+
+/// Two bindings that cannot both work as written.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BindingConflict {
+    /// Several commands share one sequence. The handler nearest to focus
+    /// decides which one runs.
+    Duplicate {
+        /// The shared sequence.
+        sequence: KeySequence,
+        /// Commands bound to it, in binding order.
+        commands: Vec<CommandId>,
+    },
+    /// A sequence is a prefix of a longer one, so the longer one can never
+    /// complete.
+    Shadowed {
+        /// The shorter sequence and its command.
+        prefix: (KeySequence, CommandId),
+        /// The unreachable sequence and its command.
+        shadowed: (KeySequence, CommandId),
+    },
+}
+
+type GlobalCommandHandler = Arc<dyn Fn() + Send + Sync>;
+
+/// Result of feeding one chord to the registry.
+pub(crate) enum SequenceMatch {
+    /// The chord is not part of any binding.
+    None,
+    /// The chord started or continued a longer sequence.
+    Pending,
+    /// The chord completed a sequence bound to these commands.
+    Complete(Vec<CommandId>),
+}
+
+/// A [`SequenceMatch`] plus what happened to the sequence typed before it.
+pub(crate) struct ChordMatch {
+    pub(crate) outcome: SequenceMatch,
+    /// Whether the chord broke off a pending sequence. The chords of that
+    /// prefix were held back and should now be handled as ordinary keys.
+    pub(crate) broke_sequence: bool,
+}
+
+#[derive(Default)]
+struct CommandRegistry {
+    bindings: Vec<(CommandId, KeySequence)>,
+    handlers: Vec<(CommandId, GlobalCommandHandler)>,
+    pending: Vec<KeyChord>,
+    /// Commands run through [`execute_command`], waiting for the next frame.
+    executed: Vec<CommandId>,
+}
+
+impl CommandRegistry {
+    fn with_defaults() -> Self {
+        let mut registry = Self::default();
+        for (command, sequence) in DEFAULT_BINDINGS {
+            let sequence = sequence.parse().expect("default bindings must parse");
+            registry.bind(CommandId::from(*command), sequence);
+        }
+        registry
+    }
+
+    fn bind(&mut self, command: CommandId, sequence: KeySequence) {
+        let exists = self
+            .bindings
+            .iter()
+            .any(|(bound, bound_sequence)| *bound == command && *bound_sequence == sequence);
+        if !exists {
+            self.bindings.push((command, sequence));
+        }
+    }
+
+    fn set_bindings(&mut self, command: &CommandId, sequences: Vec<KeySequence>) {
+        self.bindings.retain(|(bound, _)| bound != command);
+        for sequence in sequences {
+            self.bind(command.clone(), sequence);
+        }
+    }
+
+    fn bindings(&self, command: &CommandId) -> Vec<KeySequence> {
+        self.bindings
+            .iter()
+            .filter(|(bound, _)| bound == command)
+            .map(|(_, sequence)| sequence.clone())
+            .collect()
+    }
+
+    fn conflicts(&self) -> Vec<BindingConflict> {
+        let mut conflicts: Vec<BindingConflict> = Vec::new();
+        for (index, (command, sequence)) in self.bindings.iter().enumerate() {
+            let duplicates: Vec<CommandId> = self
+                .bindings
+                .iter()
+                .filter(|(other, other_sequence)| other_sequence == sequence && other != command)
+                .map(|(other, _)| other.clone())
+                .collect();
+            let already_reported = self.bindings[..index]
+                .iter()
+                .any(|(_, earlier)| earlier == sequence);
+            if !duplicates.is_empty() && !already_reported {
+                let mut commands = vec![command.clone()];
+                commands.extend(duplicates);
+                conflicts.push(BindingConflict::Duplicate {
+                    sequence: sequence.clone(),
+                    commands,
+                });
+            }
+
+            for (other, other_sequence) in &self.bindings {
+                if other_sequence.chords().len() > sequence.chords().len()
+                    && other_sequence.starts_with(sequence.chords())
+                {
+                    conflicts.push(BindingConflict::Shadowed {
+                        prefix: (sequence.clone(), command.clone()),
+                        shadowed: (other_sequence.clone(), other.clone()),
+                    });
+                }
+            }
+        }
+        conflicts
+    }
+
+    fn load(&mut self, source: &str) -> Result<(), BindingsError> {
+        let mut overrides: Vec<(CommandId, Vec<KeySequence>)> = Vec::new();
+        for (index, line) in source.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((command, sequences)) = line.split_once('=') else {
+                return Err(BindingsError::Syntax { line: line_number });
+            };
+            let command = command.trim();
+            if command.is_empty() {
+                return Err(BindingsError::Syntax { line: line_number });
+            }
+            let sequences = sequences
+                .split(',')
+                .map(str::trim)
+                .filter(|sequence| !sequence.is_empty())
+                .map(|sequence| {
+                    sequence.parse().map_err(|error| BindingsError::Shortcut {
+                        line: line_number,
+                        error,
+                    })
+                })
+                .collect::<Result<Vec<KeySequence>, _>>()?;
+            overrides.push((CommandId::from(command), sequences));
+        }
+
+        // Apply only once the whole source parsed.
+        for (command, sequences) in overrides {
+            self.set_bindings(&command, sequences);
+        }
+        Ok(())
+    }
+
+    fn match_chord(&mut self, chord: KeyChord) -> ChordMatch {
+        let mut candidate = std::mem::take(&mut self.pending);
+        let continued = !candidate.is_empty();
+        candidate.push(chord.clone());
+        if let Some(outcome) = self.match_candidate(candidate) {
+            return ChordMatch {
+                outcome,
+                broke_sequence: false,
+            };
+        }
+
+        // A broken sequence drops its prefix; the chord may start anew.
+        let outcome = if continued {
+            self.match_candidate(vec![chord])
+                .unwrap_or(SequenceMatch::None)
+        } else {
+            SequenceMatch::None
+        };
+        ChordMatch {
+            outcome,
+            broke_sequence: continued,
+        }
+    }
+
+    fn match_candidate(&mut self, candidate: Vec<KeyChord>) -> Option<SequenceMatch> {
+        let exact: Vec<CommandId> = self
+            .bindings
+            .iter()
+            .filter(|(_, sequence)| sequence.chords() == candidate.as_slice())
+            .map(|(command, _)| command.clone())
+            .collect();
+        if !exact.is_empty() {
+            return Some(SequenceMatch::Complete(exact));
+        }
+        if self
+            .bindings
+            .iter()
+            .any(|(_, sequence)| sequence.starts_with(&candidate))
+        {
+            self.pending = candidate;
+            return Some(SequenceMatch::Pending);
+        }
+        None
+    }
+}
+
+fn with_registry<R>(f: impl FnOnce(&mut CommandRegistry) -> R) -> R {
+    let mut registry = REGISTRY.write();
+    f(registry.get_or_insert_with(CommandRegistry::with_defaults))
+}
+
+fn is_apple() -> bool {
+    cfg!(any(target_os = "macos", target_os = "ios"))
+}
+
+/// Adds `sequence` as a shortcut for `command`, keeping existing ones.
+pub fn bind_command(command: impl Into<CommandId>, sequence: impl Into<KeySequence>) {
+    let (command, sequence) = (command.into(), sequence.into());
+    with_registry(|registry| registry.bind(command, sequence));
+}
+
+/// Replaces every shortcut of `command`; an empty list unbinds it.
+pub fn set_command_bindings(command: impl Into<CommandId>, sequences: Vec<KeySequence>) {
+    let command = command.into();
+    with_registry(|registry| registry.set_bindings(&command, sequences));
+}
+
+/// Returns the shortcuts bound to `command`, first binding first.
+pub fn command_bindings(command: impl Into<CommandId>) -> Vec<KeySequence> {
+    let command = command.into();
+    with_registry(|registry| registry.bindings(&command))
+}
+
+/// Returns the label of the first shortcut bound to `command`, for display
+/// next to menu items.
+pub fn accelerator_label(command: impl Into<CommandId>) -> Option<String> {
+    command_bindings(command).first().map(KeySequence::label)
+}
+
+/// Returns bindings that collide with each other.
+pub fn binding_conflicts() -> Vec<BindingConflict> {
+    with_registry(|registry| registry.conflicts())
+}
+
+/// Applies user bindings, one `command = sequence[, sequence...]` per line.
+///
+/// Listed commands lose their previous shortcuts; an empty right-hand side
+/// unbinds the command. Lines starting with `#` are comments. Nothing is
+/// applied when any line fails to parse.
+///
+/// ```
+/// use tessera_ui::shortcut::{accelerator_label, load_bindings};
+///
+/// load_bindings(
+///     "# user keymap\n\
+///      file.save = Ctrl+S, Ctrl+K S\n\
+///      edit.select_all =",
+/// )
+/// .expect("valid bindings");
+/// let expected = if cfg!(any(target_os = "macos", target_os = "ios")) {
+///     "⌃S"
+/// } else {
+///     "Ctrl+S"
+/// };
+/// assert_eq!(accelerator_label("file.save").as_deref(), Some(expected));
+/// assert_eq!(accelerator_label("edit.select_all"), None);
+/// ```
+pub fn load_bindings(source: &str) -> Result<(), BindingsError> {
+    with_registry(|registry| registry.load(source))
+}
+
+/// Reads a bindings file and applies it with [`load_bindings`].
+pub fn load_bindings_file(path: impl AsRef<Path>) -> Result<(), BindingsError> {
+    let source = std::fs::read_to_string(path).map_err(BindingsError::Io)?;
+    load_bindings(&source)
+}
+
+/// Registers an app-wide handler for `command`.
+///
+/// It runs when no node in the focus chain handles the command, including
+/// when nothing is focused. Registering again replaces the handler.
+pub fn register_command_handler<F>(command: impl Into<CommandId>, handler: F)
+where
+    F: Fn() + Send + Sync + 'static,
+{
+    let command = command.into();
+    with_registry(|registry| {
+        registry.handlers.retain(|(bound, _)| *bound != command);
+        registry.handlers.push((command, Arc::new(handler)));
+    });
+}
+
+/// Removes the app-wide handler for `command`.
+pub fn unregister_command_handler(command: impl Into<CommandId>) {
+    let command = command.into();
+    with_registry(|registry| registry.handlers.retain(|(bound, _)| *bound != command));
+}
+
+/// Runs `command` as if one of its shortcuts was typed.
+///
+/// Use this for native menus and other UI outside the component tree. It can
+/// be called from any thread; the command reaches the focused subtree, or the
+/// app-wide handler, on the next frame.
+pub fn execute_command(command: impl Into<CommandId>) {
+    let command = command.into();
+    with_registry(|registry| registry.executed.push(command));
+    crate::ui_thread::wake();
+}
+
+/// Feeds a typed chord to the pending key sequence.
+pub(crate) fn match_chord(chord: KeyChord) -> ChordMatch {
+    with_registry(|registry| registry.match_chord(chord))
+}
+
+/// Drains the commands queued by [`execute_command`].
+pub(crate) fn take_executed_commands() -> Vec<CommandId> {
+    with_registry(|registry| std::mem::take(&mut registry.executed))
+}
+
+/// Returns the app-wide handler for the first of `commands` that has one.
+pub(crate) fn global_command_handler(commands: &[CommandId]) -> Option<GlobalCommandHandler> {
+    with_registry(|registry| {
+        commands.iter().find_map(|command| {
+            registry
+                .handlers
+                .iter()
+                .find(|(bound, _)| bound == command)
+                .map(|(_, handler)| handler.clone())
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequence(text: &str) -> KeySequence {
+        text.parse().expect("valid sequence")
+    }
+
+    fn chord(text: &str) -> KeyChord {
+        text.parse().expect("valid chord")
+    }
+
+    #[test]
+    fn chords_parse_modifiers_and_keys() {
+        let parsed = chord("ctrl+Shift+p");
+        assert_eq!(
+            parsed,
+            KeyChord::new(ChordKey::Character("p".into()))
+                .ctrl()
+                .shift()
+        );
+        assert_eq!(parsed.label_for(false), "Ctrl+Shift+P");
+        assert_eq!(parsed.label_for(true), "⌃⇧P");
+        assert_eq!(
+            chord("Ctrl++"),
+            KeyChord::new(ChordKey::Character("+".into())).ctrl()
+        );
+        assert_eq!(
+            chord("Alt+PageDown").key(),
+            &ChordKey::Named(NamedKey::PageDown)
+        );
+        assert_eq!(
+            "Hyper+P".parse::<KeyChord>(),
+            Err(ShortcutParseError::UnknownModifier("Hyper".into()))
+        );
+        assert_eq!(
+            "Ctrl+Nope".parse::<KeyChord>(),
+            Err(ShortcutParseError::UnknownKey("Nope".into()))
+        );
+    }
+
+    #[test]
+    fn primary_follows_the_platform() {
+        let primary = chord("Primary+S");
+        let expected = if is_apple() {
+            chord("Cmd+S")
+        } else {
+            chord("Ctrl+S")
+        };
+        assert_eq!(primary, expected);
+    }
+
+    #[test]
+    fn sequences_wait_for_their_last_chord() {
+        let mut registry = CommandRegistry::default();
+        registry.bind("comment".into(), sequence("Ctrl+K Ctrl+C"));
+        registry.bind("find".into(), sequence("Ctrl+F"));
+
+        assert!(matches!(
+            registry.match_chord(chord("Ctrl+K")).outcome,
+            SequenceMatch::Pending
+        ));
+        assert!(matches!(
+            registry.match_chord(chord("Ctrl+C")).outcome,
+            SequenceMatch::Complete(commands) if commands == vec![CommandId::from("comment")]
+        ));
+
+        // A broken sequence lets the breaking chord match on its own.
+        registry.match_chord(chord("Ctrl+K"));
+        let matched = registry.match_chord(chord("Ctrl+F"));
+        assert!(matched.broke_sequence);
+        assert!(matches!(
+            matched.outcome,
+            SequenceMatch::Complete(commands) if commands == vec![CommandId::from("find")]
+        ));
+        let matched = registry.match_chord(chord("Ctrl+C"));
+        assert!(!matched.broke_sequence);
+        assert!(matches!(matched.outcome, SequenceMatch::None));
+
+        registry.match_chord(chord("Ctrl+K"));
+        let matched = registry.match_chord(chord("Ctrl+X"));
+        assert!(matched.broke_sequence);
+        assert!(matches!(matched.outcome, SequenceMatch::None));
+    }
+
+    #[test]
+    fn conflicts_report_duplicates_and_shadowed_sequences() {
+        let mut registry = CommandRegistry::default();
+        registry.bind("a".into(), sequence("Ctrl+K"));
+        registry.bind("b".into(), sequence("Ctrl+K"));
+        registry.bind("c".into(), sequence("Ctrl+K Ctrl+C"));
+
+        let conflicts = registry.conflicts();
+        assert!(conflicts.contains(&BindingConflict::Duplicate {
+            sequence: sequence("Ctrl+K"),
+            commands: vec!["a".into(), "b".into()],
+        }));
+        assert!(conflicts.contains(&BindingConflict::Shadowed {
+            prefix: (sequence("Ctrl+K"), "a".into()),
+            shadowed: (sequence("Ctrl+K Ctrl+C"), "c".into()),
+        }));
+        assert_eq!(conflicts.len(), 3);
+    }
+
+    #[test]
+    fn loading_replaces_listed_commands_only() {
+        let mut registry = CommandRegistry::with_defaults();
+        registry
+            .load("# keymap\nedit.copy = Ctrl+Shift+C\nedit.select_all =\n")
+            .expect("valid bindings");
+
+        assert_eq!(
+            registry.bindings(&commands::COPY.into()),
+            vec![sequence("Ctrl+Shift+C")]
+        );
+        assert!(registry.bindings(&commands::SELECT_ALL.into()).is_empty());
+        assert_eq!(registry.bindings(&commands::PASTE.into()).len(), 2);
+
+        let error = registry
+            .load("edit.paste = Ctrl+V\nbroken line\n")
+            .expect_err("missing `=`");
+        assert!(matches!(error, BindingsError::Syntax { line: 2 }));
+        assert_eq!(registry.bindings(&commands::PASTE.into()).len(), 2);
+    }
+}
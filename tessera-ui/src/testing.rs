@@ -9,9 +9,10 @@ use std::{collections::BTreeSet, time::Duration};
 use rustc_hash::FxHashMap as HashMap;
 
 use crate::{
-    Px, PxPosition, PxSize,
+    AccessibilityId, CursorEventContent, MOUSE_POINTER_ID, PointerChange, PressKeyEventType, Px,
+    PxPosition, PxSize,
     build_tree::build_component_tree,
-    component_tree::{ComputeMode, ComputeParams, clear_layout_snapshots},
+    component_tree::{ComponentNodeMetaDatas, ComputeMode, ComputeParams, clear_layout_snapshots},
     context::{reset_component_context_tracking, reset_context_read_dependencies},
     focus::flush_pending_focus_callbacks,
     runtime::{
@@ -74,6 +75,8 @@ pub mod __private {
         viewport: (u32, u32),
         frame_origin: Instant,
         current_frame_nanos: u64,
+        cursor_position: Option<PxPosition>,
+        pointer_changes: Vec<PointerChange>,
     }
 
     pub fn start_layout_test_session<F>(harness: LayoutTestHarness<F>) -> LayoutTestSession<F>
@@ -86,9 +89,107 @@ pub mod __private {
             viewport: harness.viewport,
             frame_origin: Instant::now(),
             current_frame_nanos: 0,
+            cursor_position: None,
+            pointer_changes: Vec::new(),
         }
     }
 
+    /// Queues a pointer change for the next pumped frame.
+    ///
+    /// Changes with a position also move the session's cursor there.
+    pub fn push_layout_test_pointer_change<F>(
+        session: &mut LayoutTestSession<F>,
+        change: PointerChange,
+    ) where
+        F: Fn(),
+    {
+        if let Some(position) = change.position {
+            session.cursor_position = Some(position);
+        }
+        session.pointer_changes.push(change);
+    }
+
+    /// Queues a primary-button press and release at `position` for the next
+    /// pumped frame.
+    pub fn tap_layout_test_session<F>(session: &mut LayoutTestSession<F>, position: PxPosition)
+    where
+        F: Fn(),
+    {
+        for content in [
+            CursorEventContent::Moved(position),
+            CursorEventContent::Pressed(PressKeyEventType::Left),
+            CursorEventContent::Released(PressKeyEventType::Left),
+        ] {
+            let mut change = PointerChange::new(MOUSE_POINTER_ID, content);
+            change.position = Some(position);
+            push_layout_test_pointer_change(session, change);
+        }
+    }
+
+    /// Sends `action` to the node tagged `selector` the way an assistive
+    /// technology would, returning `true` when a handler ran.
+    ///
+    /// Uses the tree from the last pumped frame.
+    pub fn perform_layout_test_accessibility_action<F>(
+        _session: &mut LayoutTestSession<F>,
+        selector: &str,
+        action: accesskit::Action,
+        data: Option<accesskit::ActionData>,
+    ) -> bool
+    where
+        F: Fn(),
+    {
+        let handled = TesseraRuntime::with_mut(|runtime| {
+            let (tree, metadatas, focus_owner) =
+                runtime.component_tree.accessibility_dispatch_context();
+            let node_id = tagged_node(metadatas, selector);
+            crate::accessibility::dispatch_action(
+                tree,
+                metadatas,
+                focus_owner,
+                accesskit::ActionRequest {
+                    action,
+                    target_tree: accesskit::TreeId::ROOT,
+                    target_node: AccessibilityId::from_component_node_id(node_id).to_accesskit_id(),
+                    data,
+                },
+            )
+        });
+        flush_pending_focus_callbacks();
+        handled
+    }
+
+    /// Runs the custom action labelled `label` on the node tagged `selector`,
+    /// returning `true` when a handler ran.
+    ///
+    /// Panics if the node does not advertise the action.
+    pub fn perform_layout_test_custom_action<F>(
+        session: &mut LayoutTestSession<F>,
+        selector: &str,
+        label: &str,
+    ) -> bool
+    where
+        F: Fn(),
+    {
+        let id = TesseraRuntime::with(|runtime| {
+            let metadatas = runtime.component_tree.metadatas();
+            let node_id = tagged_node(metadatas, selector);
+            metadatas
+                .get(&node_id)
+                .into_iter()
+                .flat_map(|metadata| metadata.accessibility_action_handlers.custom_actions())
+                .find(|action| &*action.description == label)
+                .map(|action| action.id)
+                .unwrap_or_else(|| panic!("`{selector}` has no custom action `{label}`"))
+        });
+        perform_layout_test_accessibility_action(
+            session,
+            selector,
+            accesskit::Action::CustomAction,
+            Some(accesskit::ActionData::CustomAction(id)),
+        )
+    }
+
     pub fn pump_layout_test_session<F>(
         session: &mut LayoutTestSession<F>,
         frame_nanos: u64,
@@ -101,6 +202,7 @@ pub mod __private {
         begin_frame_clock(frame_time);
         // Match renderer frame order so frame callbacks update state before build.
         tick_frame_nanos_receivers();
+        crate::ui_thread::run_pending_tasks();
         let _ = build_component_tree(&session.content);
         let layout_dirty_nodes = take_layout_dirty_nodes();
         let screen_size = PxSize::new(
@@ -112,14 +214,15 @@ pub mod __private {
             let _ = runtime.component_tree.compute(
                 ComputeParams {
                     screen_size,
-                    cursor_position: None,
-                    pointer_changes: Vec::new(),
+                    cursor_position: session.cursor_position,
+                    pointer_changes: std::mem::take(&mut session.pointer_changes),
                     keyboard_events: Vec::new(),
                     ime_events: Vec::new(),
                     retry_focus_move: None,
                     retry_focus_reveal: false,
                     modifiers: winit::keyboard::ModifiersState::default(),
                     layout_dirty_nodes: &layout_dirty_nodes,
+                    window_visible: true,
                 },
                 ComputeMode::LayoutOnly,
             );
@@ -527,11 +630,27 @@ impl<'a> NodeAssert<'a> {
     }
 }
 
+fn tagged_node(metadatas: &ComponentNodeMetaDatas, selector: &str) -> indextree::NodeId {
+    metadatas
+        .with_entries(|entries| {
+            entries.iter().find_map(|(&node_id, metadata)| {
+                metadata
+                    .accessibility
+                    .as_ref()
+                    .and_then(|accessibility| accessibility.key.as_deref())
+                    .is_some_and(|key| key == selector)
+                    .then_some(node_id)
+            })
+        })
+        .unwrap_or_else(|| panic!("layout test selector `{selector}` not found"))
+}
+
 fn reset_runtime_for_layout_test(viewport: (u32, u32)) {
     TesseraRuntime::with_mut(|runtime| {
         runtime.component_tree.reset();
         runtime.cursor_icon_request = None;
         runtime.window_minimized = false;
+        runtime.window_occluded = false;
         runtime.window_size = [viewport.0, viewport.1];
     });
     clear_layout_snapshots();
@@ -545,6 +664,7 @@ fn reset_runtime_for_layout_test(viewport: (u32, u32)) {
     reset_build_invalidations();
     reset_frame_clock();
     clear_redraw_waker();
+    crate::ui_thread::clear_waker();
     clear_persistent_focus_handles();
     reset_slots();
 }
@@ -616,20 +736,26 @@ macro_rules! assert_layout {
 
 #[cfg(test)]
 mod tests {
-    use std::num::NonZero;
+    use std::{
+        num::NonZero,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use parking_lot::Mutex;
 
-    use super::reset_runtime_for_layout_test;
+    use super::{__private, layout_test, reset_runtime_for_layout_test, tagged_node};
 
     use crate::{
-        AccessibilityActionHandler, AccessibilityNode, AxisConstraint, ComputedData, Constraint,
-        FrameNanosControl, LayoutModifierChild, LayoutModifierInput, LayoutModifierNode,
-        LayoutPolicy, LayoutResult, Modifier, NoopRenderPolicy, PlacementModifierNode, Px,
-        PxPosition, RenderSlot, SemanticsModifierNode,
+        AccessibilityActionHandler, AccessibilityActionHandlers, AccessibilityNode, AssetTheme,
+        AxisConstraint, ComputedData, Constraint, Context, FrameNanosControl, LayoutModifierChild,
+        LayoutModifierInput, LayoutModifierNode, LayoutPolicy, LayoutResult, Modifier,
+        NoopRenderPolicy, PlacementModifierNode, Px, PxPosition, RenderSlot, SemanticsModifierNode,
+        asset::{AssetQualifiers, select_asset_variant},
         component_tree::{NodeRole, direct_layout_children},
         layout::MeasureScope,
-        receive_frame_nanos, remember,
+        provide_context, receive_frame_nanos, remember, remember_bounds, remember_visibility,
         runtime::TesseraRuntime,
-        tessera,
+        tessera, use_context,
     };
     #[derive(Clone, PartialEq)]
     struct FixedSizePolicy {
@@ -724,6 +850,36 @@ mod tests {
         }
     }
 
+    static CUSTOM_ACTION_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    struct CustomActionSemanticsModifier {
+        label: &'static str,
+        weight: usize,
+    }
+
+    impl SemanticsModifierNode for CustomActionSemanticsModifier {
+        fn apply(
+            &self,
+            _accessibility: &mut AccessibilityNode,
+            _action_handler: &mut Option<AccessibilityActionHandler>,
+        ) {
+        }
+
+        fn apply_action_handlers(&self, handlers: &mut AccessibilityActionHandlers) {
+            let weight = self.weight;
+            handlers.add_custom_action(self.label, move || {
+                CUSTOM_ACTION_CALLS.fetch_add(weight, Ordering::Relaxed);
+            });
+        }
+    }
+
+    const THEMED_ASSET_VARIANTS: &[AssetQualifiers] = &[
+        AssetQualifiers::new(1.0, None),
+        AssetQualifiers::new(1.0, Some("dark")),
+    ];
+    static ASSET_THEME_CONTEXT: Mutex<Option<Context<AssetTheme>>> = Mutex::new(None);
+    static RESOLVED_ASSET_VARIANT: AtomicUsize = AtomicUsize::new(usize::MAX);
+
     #[derive(Clone, Copy, PartialEq)]
     struct AnimatedWidthModifierNode {
         width: i32,
@@ -882,6 +1038,68 @@ mod tests {
             });
     }
 
+    #[tessera(crate)]
+    fn visibility_probe() {
+        let visible = remember_visibility();
+        let width = if visible.get() { 20 } else { 10 };
+        crate::layout::layout()
+            .layout_policy(FixedSizePolicy { width, height: 20 })
+            .render_policy(NoopRenderPolicy)
+            .modifier(Modifier::new().push_semantics(TestTagSemanticsModifier {
+                tag: "probe".to_string(),
+            }));
+    }
+
+    #[tessera(crate)]
+    fn offscreen_visibility_sample() {
+        let offset = remember(|| 0_i32);
+
+        receive_frame_nanos(move |frame_nanos| {
+            let next_offset = if (100_000_000..200_000_000).contains(&frame_nanos) {
+                300
+            } else {
+                0
+            };
+            offset.set(next_offset);
+            if frame_nanos >= 200_000_000 {
+                FrameNanosControl::Stop
+            } else {
+                FrameNanosControl::Continue
+            }
+        });
+
+        crate::layout::layout()
+            .layout_policy(OffsetChildPolicy { x: offset.get() })
+            .render_policy(NoopRenderPolicy)
+            .modifier(Modifier::new())
+            .child(|| {
+                visibility_probe();
+            });
+    }
+
+    #[tessera(crate)]
+    fn bounds_probe() {
+        let bounds = remember_bounds();
+        let width = bounds.get().map_or(10, |rect| rect.x.0 + 5);
+        crate::layout::layout()
+            .layout_policy(FixedSizePolicy { width, height: 20 })
+            .render_policy(NoopRenderPolicy)
+            .modifier(Modifier::new().push_semantics(TestTagSemanticsModifier {
+                tag: "probe".to_string(),
+            }));
+    }
+
+    #[tessera(crate)]
+    fn offset_bounds_sample() {
+        crate::layout::layout()
+            .layout_policy(OffsetChildPolicy { x: 30 })
+            .render_policy(NoopRenderPolicy)
+            .modifier(Modifier::new())
+            .child(|| {
+                bounds_probe();
+            });
+    }
+
     #[tessera(crate)]
     fn animated_modifier_layout_sample() {
         let width = remember(|| 20_i32);
@@ -1027,6 +1245,52 @@ mod tests {
             });
     }
 
+    #[tessera(crate)]
+    fn custom_action_sample() {
+        crate::layout::layout()
+            .layout_policy(FixedSizePolicy {
+                width: 20,
+                height: 20,
+            })
+            .render_policy(NoopRenderPolicy)
+            .modifier(
+                Modifier::new()
+                    .push_semantics(TestTagSemanticsModifier {
+                        tag: "actions".to_string(),
+                    })
+                    .push_semantics(CustomActionSemanticsModifier {
+                        label: "Archive",
+                        weight: 1,
+                    })
+                    .push_semantics(CustomActionSemanticsModifier {
+                        label: "Delete",
+                        weight: 10,
+                    }),
+            );
+    }
+
+    #[tessera(crate)]
+    fn themed_asset_probe() {
+        RESOLVED_ASSET_VARIANT.store(
+            select_asset_variant(THEMED_ASSET_VARIANTS),
+            Ordering::Relaxed,
+        );
+        crate::layout::layout()
+            .layout_policy(FixedSizePolicy {
+                width: 10,
+                height: 10,
+            })
+            .render_policy(NoopRenderPolicy);
+    }
+
+    #[tessera(crate)]
+    fn themed_asset_sample() {
+        provide_context(AssetTheme::default, || {
+            *ASSET_THEME_CONTEXT.lock() = use_context::<AssetTheme>();
+            themed_asset_probe();
+        });
+    }
+
     #[tessera(crate)]
     fn explicit_layout_boundary_sample() {
         crate::layout::layout().layout_policy(FixedSizePolicy {
@@ -1100,6 +1364,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn remember_visibility_tracks_offscreen_placement() {
+        let mut session = crate::testing::__private::start_layout_test_session(
+            crate::testing::layout_test(|| {
+                offscreen_visibility_sample();
+            })
+            .viewport_px(200, 100),
+        );
+        let mut pump = |frame_nanos| {
+            crate::testing::__private::pump_layout_test_session(&mut session, frame_nanos)
+        };
+
+        pump(0).node("probe").position(0, 0).size(20, 20);
+        pump(100_000_000)
+            .node("probe")
+            .position(300, 0)
+            .size(20, 20);
+        pump(116_000_000)
+            .node("probe")
+            .position(300, 0)
+            .size(10, 20);
+        pump(200_000_000).node("probe").position(0, 0).size(10, 20);
+        pump(216_000_000).node("probe").position(0, 0).size(20, 20);
+    }
+
+    #[test]
+    fn remember_bounds_reports_window_position() {
+        let mut session = crate::testing::__private::start_layout_test_session(
+            crate::testing::layout_test(|| {
+                offset_bounds_sample();
+            })
+            .viewport_px(200, 100),
+        );
+        let mut pump = |frame_nanos| {
+            crate::testing::__private::pump_layout_test_session(&mut session, frame_nanos)
+        };
+
+        pump(0).node("probe").position(30, 0).size(10, 20);
+        pump(16_000_000).node("probe").position(30, 0).size(35, 20);
+    }
+
     #[test]
     fn assert_layout_macro_pumps_modifier_driven_animation_frames() {
         crate::assert_layout! {
@@ -1177,6 +1482,72 @@ mod tests {
         }
     }
 
+    #[test]
+    fn combined_modifiers_dispatch_custom_actions_by_stable_id() {
+        CUSTOM_ACTION_CALLS.store(0, Ordering::Relaxed);
+        let mut session = __private::start_layout_test_session(
+            layout_test(|| {
+                custom_action_sample();
+            })
+            .viewport_px(100, 100),
+        );
+        let _ = __private::pump_layout_test_session(&mut session, 0);
+
+        let custom = TesseraRuntime::with(|runtime| {
+            let metadatas = runtime.component_tree.metadatas();
+            let node_id = tagged_node(metadatas, "actions");
+            metadatas
+                .get(&node_id)
+                .expect("tagged node must have metadata")
+                .accessibility_action_handlers
+                .custom_actions()
+        });
+        let labels: Vec<_> = custom.iter().map(|action| &*action.description).collect();
+        assert_eq!(labels, ["Archive", "Delete"]);
+
+        let mut delete_only = AccessibilityActionHandlers::default();
+        delete_only.add_custom_action("Delete", || {});
+        assert_eq!(custom[1].id, delete_only.custom_actions()[0].id);
+
+        assert!(__private::perform_layout_test_custom_action(
+            &mut session,
+            "actions",
+            "Delete"
+        ));
+        assert_eq!(CUSTOM_ACTION_CALLS.load(Ordering::Relaxed), 10);
+        assert!(__private::perform_layout_test_custom_action(
+            &mut session,
+            "actions",
+            "Archive"
+        ));
+        assert_eq!(CUSTOM_ACTION_CALLS.load(Ordering::Relaxed), 11);
+        assert!(!__private::perform_layout_test_accessibility_action(
+            &mut session,
+            "actions",
+            accesskit::Action::Increment,
+            None,
+        ));
+    }
+
+    #[test]
+    fn asset_theme_context_changes_rebuild_variant_readers() {
+        let mut session = __private::start_layout_test_session(
+            layout_test(|| {
+                themed_asset_sample();
+            })
+            .viewport_px(100, 100),
+        );
+        let _ = __private::pump_layout_test_session(&mut session, 0);
+        assert_eq!(RESOLVED_ASSET_VARIANT.load(Ordering::Relaxed), 0);
+
+        ASSET_THEME_CONTEXT
+            .lock()
+            .expect("asset theme context should be provided")
+            .set(AssetTheme::new("dark"));
+        let _ = __private::advance_layout_test_session_by_nanos(&mut session, 16_666_667);
+        assert_eq!(RESOLVED_ASSET_VARIANT.load(Ordering::Relaxed), 1);
+    }
+
     #[test]
     fn layout_keeps_composition_boundary_and_emits_layout_child() {
         reset_runtime_for_layout_test((100, 100));
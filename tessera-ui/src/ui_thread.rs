@@ -0,0 +1,62 @@
+//! # UI Thread Tasks
+//!
+//! ## Usage
+//!
+//! Hand results from background threads back to component state.
+//!
+//! Component state lives on the UI thread, so workers cannot write to a
+//! [`State`](crate::State) directly. Post a task with [`post_to_ui_thread`]
+//! instead; the runtime wakes the event loop and runs pending tasks at the
+//! start of the next frame, before the component tree is built, so state
+//! writes inside a task are picked up by that frame's recomposition.
+
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+type UiTask = Box<dyn FnOnce() + Send + 'static>;
+type UiThreadWaker = Arc<dyn Fn() + Send + Sync + 'static>;
+
+static PENDING_TASKS: Mutex<Vec<UiTask>> = Mutex::new(Vec::new());
+static WAKER: Mutex<Option<UiThreadWaker>> = Mutex::new(None);
+
+/// Queues `task` to run on the UI thread before the next frame is built.
+///
+/// This can be called from any thread. Tasks run in posting order. Tasks
+/// posted before the event loop starts run on its first frame.
+pub fn post_to_ui_thread<F>(task: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    PENDING_TASKS.lock().push(Box::new(task));
+    wake();
+}
+
+/// Wakes the event loop so a frame runs soon.
+pub(crate) fn wake() {
+    let waker = WAKER.lock().clone();
+    if let Some(waker) = waker {
+        waker();
+    }
+}
+
+/// Installs the callback used to wake the event loop after a task is posted.
+pub(crate) fn install_waker(waker: UiThreadWaker) {
+    *WAKER.lock() = Some(waker);
+}
+
+/// Removes the event loop wake callback.
+pub(crate) fn clear_waker() {
+    *WAKER.lock() = None;
+}
+
+/// Runs every task posted so far.
+///
+/// Must be called on the UI thread. Tasks posted while draining run on the
+/// next call.
+pub(crate) fn run_pending_tasks() {
+    let tasks = std::mem::take(&mut *PENDING_TASKS.lock());
+    for task in tasks {
+        task();
+    }
+}
@@ -0,0 +1,482 @@
+//! SVG tessellation and the pre-tessellated vector mesh format.
+//!
+//! ## Usage
+//!
+//! `tessera-build` tessellates SVG assets at build time and embeds the encoded
+//! mesh; `tessera-components` decodes it at runtime, or tessellates SVG bytes
+//! directly when no pre-tessellated mesh is available.
+//!
+//! Only a subset of SVG is supported: solid color fills and strokes without
+//! clip paths, masks, filters, blend modes or dash arrays. The encoded mesh
+//! layout, all little-endian, is:
+//!
+//! ```text
+//! magic            [u8; 8]   VECTOR_MESH_MAGIC
+//! viewport_width   f32
+//! viewport_height  f32
+//! vertex_count     u32
+//! index_count      u32
+//! vertices         vertex_count x [x, y, r, g, b, a] f32
+//! indices          index_count x u32
+//! ```
+//!
+//! Vertex positions are normalized to the viewport.
+
+#![deny(
+    missing_docs,
+    clippy::unwrap_used,
+    rustdoc::broken_intra_doc_links,
+    rustdoc::invalid_rust_codeblocks,
+    rustdoc::invalid_html_tags
+)]
+
+use std::path::Path as StdPath;
+
+use lyon_geom::point;
+use lyon_path::Path as LyonPath;
+use lyon_tessellation::{
+    BuffersBuilder, FillOptions, FillRule as LyonFillRule, FillTessellator, FillVertex,
+    LineCap as LyonLineCap, LineJoin as LyonLineJoin, StrokeOptions, StrokeTessellator,
+    StrokeVertex, TessellationError, VertexBuffers,
+};
+use thiserror::Error;
+use usvg::{
+    BlendMode, FillRule, Group, LineCap as SvgLineCap, LineJoin as SvgLineJoin, Node, Paint,
+    PaintOrder, Path, Stroke, Tree, tiny_skia_path::PathSegment,
+};
+
+/// Magic bytes identifying pre-tessellated vector mesh data.
+pub const VECTOR_MESH_MAGIC: &[u8; 8] = b"TSVMESH1";
+
+const HEADER_LEN: usize = 16;
+const VERTEX_LEN: usize = 24;
+const INDEX_LEN: usize = 4;
+
+/// Errors that can occur while tessellating or decoding a vector mesh.
+#[derive(Debug, Error)]
+pub enum VectorMeshError {
+    /// SVG parsing failed.
+    #[error("failed to parse SVG: {0}")]
+    Parse(#[from] usvg::Error),
+    /// The SVG viewport dimensions are invalid.
+    #[error("SVG viewport must have finite, positive size")]
+    InvalidViewport,
+    /// Encountered an SVG feature that isn't supported.
+    #[error("unsupported SVG feature: {0}")]
+    UnsupportedFeature(String),
+    /// Failed to apply the absolute transform for a path.
+    #[error("failed to apply SVG transforms")]
+    TransformFailed,
+    /// Tessellation of the path geometry failed.
+    #[error("tessellation error: {0}")]
+    Tessellation(#[from] TessellationError),
+    /// No renderable geometry was produced.
+    #[error("SVG produced no renderable paths")]
+    EmptyGeometry,
+    /// Encoded mesh data is truncated or malformed.
+    #[error("invalid pre-tessellated vector mesh")]
+    InvalidMesh,
+}
+
+/// A vertex of a tessellated vector image.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VectorMeshVertex {
+    /// Position normalized to the viewport.
+    pub position: [f32; 2],
+    /// RGBA color with components in `0.0..=1.0`.
+    pub color: [f32; 4],
+}
+
+/// Triangles of a tessellated vector image.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorMesh {
+    /// Width and height of the SVG viewport.
+    pub viewport: [f32; 2],
+    /// Triangle vertices.
+    pub vertices: Vec<VectorMeshVertex>,
+    /// Triangle list indices into `vertices`.
+    pub indices: Vec<u32>,
+}
+
+/// Parses and tessellates SVG bytes.
+///
+/// `resources_dir` resolves relative references inside the SVG.
+pub fn tessellate_svg(
+    bytes: &[u8],
+    resources_dir: Option<&StdPath>,
+) -> Result<VectorMesh, VectorMeshError> {
+    let options = usvg::Options {
+        resources_dir: resources_dir.map(StdPath::to_path_buf),
+        ..Default::default()
+    };
+    let tree = Tree::from_data(bytes, &options)?;
+
+    let size = tree.size();
+    let viewport = [size.width(), size.height()];
+    if !valid_viewport(viewport) {
+        return Err(VectorMeshError::InvalidViewport);
+    }
+
+    let mut buffers = VertexBuffers::new();
+    visit_group(tree.root(), 1.0, viewport, &mut buffers)?;
+    if buffers.vertices.is_empty() || buffers.indices.is_empty() {
+        return Err(VectorMeshError::EmptyGeometry);
+    }
+
+    Ok(VectorMesh {
+        viewport,
+        vertices: buffers.vertices,
+        indices: buffers.indices,
+    })
+}
+
+/// Encodes `mesh` into the vector mesh format, magic included.
+pub fn encode_vector_mesh(mesh: &VectorMesh) -> Vec<u8> {
+    let mut encoded = Vec::with_capacity(
+        VECTOR_MESH_MAGIC.len()
+            + HEADER_LEN
+            + mesh.vertices.len() * VERTEX_LEN
+            + mesh.indices.len() * INDEX_LEN,
+    );
+    encoded.extend_from_slice(VECTOR_MESH_MAGIC);
+    encoded.extend_from_slice(&mesh.viewport[0].to_le_bytes());
+    encoded.extend_from_slice(&mesh.viewport[1].to_le_bytes());
+    encoded.extend_from_slice(&(mesh.vertices.len() as u32).to_le_bytes());
+    encoded.extend_from_slice(&(mesh.indices.len() as u32).to_le_bytes());
+    for vertex in &mesh.vertices {
+        for value in vertex.position.iter().chain(&vertex.color) {
+            encoded.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+    for index in &mesh.indices {
+        encoded.extend_from_slice(&index.to_le_bytes());
+    }
+    encoded
+}
+
+/// Decodes data written by [`encode_vector_mesh`], magic included.
+pub fn decode_vector_mesh(bytes: &[u8]) -> Result<VectorMesh, VectorMeshError> {
+    let body = bytes
+        .strip_prefix(VECTOR_MESH_MAGIC)
+        .ok_or(VectorMeshError::InvalidMesh)?;
+    let mut words = body
+        .chunks_exact(4)
+        .map(|word| [word[0], word[1], word[2], word[3]]);
+    let mut next_word = || words.next().ok_or(VectorMeshError::InvalidMesh);
+
+    let viewport = [
+        f32::from_le_bytes(next_word()?),
+        f32::from_le_bytes(next_word()?),
+    ];
+    if !valid_viewport(viewport) {
+        return Err(VectorMeshError::InvalidViewport);
+    }
+
+    let vertex_count = u32::from_le_bytes(next_word()?) as usize;
+    let index_count = u32::from_le_bytes(next_word()?) as usize;
+    let expected_len = vertex_count
+        .checked_mul(VERTEX_LEN)
+        .zip(index_count.checked_mul(INDEX_LEN))
+        .and_then(|(vertices, indices)| vertices.checked_add(indices))
+        .and_then(|len| len.checked_add(HEADER_LEN));
+    if expected_len != Some(body.len()) {
+        return Err(VectorMeshError::InvalidMesh);
+    }
+
+    let mut vertices = Vec::with_capacity(vertex_count);
+    for _ in 0..vertex_count {
+        let mut values = [0.0f32; 6];
+        for value in &mut values {
+            *value = f32::from_le_bytes(next_word()?);
+        }
+        vertices.push(VectorMeshVertex {
+            position: [values[0], values[1]],
+            color: [values[2], values[3], values[4], values[5]],
+        });
+    }
+    let mut indices = Vec::with_capacity(index_count);
+    for _ in 0..index_count {
+        let index = u32::from_le_bytes(next_word()?);
+        if index as usize >= vertex_count {
+            return Err(VectorMeshError::InvalidMesh);
+        }
+        indices.push(index);
+    }
+    if vertices.is_empty() || indices.is_empty() {
+        return Err(VectorMeshError::EmptyGeometry);
+    }
+
+    Ok(VectorMesh {
+        viewport,
+        vertices,
+        indices,
+    })
+}
+
+fn valid_viewport(viewport: [f32; 2]) -> bool {
+    viewport
+        .iter()
+        .all(|extent| extent.is_finite() && *extent > 0.0)
+}
+
+fn visit_group(
+    group: &Group,
+    inherited_opacity: f32,
+    viewport: [f32; 2],
+    buffers: &mut VertexBuffers<VectorMeshVertex, u32>,
+) -> Result<(), VectorMeshError> {
+    if group.clip_path().is_some() || group.mask().is_some() || !group.filters().is_empty() {
+        return Err(VectorMeshError::UnsupportedFeature(
+            "clip paths, masks, and filters are not supported".to_string(),
+        ));
+    }
+    if group.blend_mode() != BlendMode::Normal {
+        return Err(VectorMeshError::UnsupportedFeature(
+            "non-normal blend modes".to_string(),
+        ));
+    }
+
+    let opacity = inherited_opacity * group.opacity().get();
+    for node in group.children() {
+        match node {
+            Node::Group(child) => visit_group(child, opacity, viewport, buffers)?,
+            Node::Path(path) => tessellate_path(path, opacity, viewport, buffers)?,
+            Node::Image(_) | Node::Text(_) => {
+                return Err(VectorMeshError::UnsupportedFeature(
+                    "non-path nodes in SVG are not supported".to_string(),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn tessellate_path(
+    path: &Path,
+    inherited_opacity: f32,
+    viewport: [f32; 2],
+    buffers: &mut VertexBuffers<VectorMeshVertex, u32>,
+) -> Result<(), VectorMeshError> {
+    if !path.is_visible() {
+        return Ok(());
+    }
+    if path.rendering_mode() != usvg::ShapeRendering::default() {
+        return Err(VectorMeshError::UnsupportedFeature(
+            "shape-rendering modes are not supported".to_string(),
+        ));
+    }
+
+    let lyon_path = convert_to_lyon_path(path)?;
+    let fill = |buffers: &mut VertexBuffers<VectorMeshVertex, u32>| match path.fill() {
+        Some(fill) => tessellate_fill(&lyon_path, fill, inherited_opacity, viewport, buffers),
+        None => Ok(()),
+    };
+    let stroke = |buffers: &mut VertexBuffers<VectorMeshVertex, u32>| match path.stroke() {
+        Some(stroke) => tessellate_stroke(&lyon_path, stroke, inherited_opacity, viewport, buffers),
+        None => Ok(()),
+    };
+    match path.paint_order() {
+        PaintOrder::FillAndStroke => {
+            fill(buffers)?;
+            stroke(buffers)
+        }
+        PaintOrder::StrokeAndFill => {
+            stroke(buffers)?;
+            fill(buffers)
+        }
+    }
+}
+
+fn tessellate_fill(
+    path: &LyonPath,
+    fill: &usvg::Fill,
+    inherited_opacity: f32,
+    viewport: [f32; 2],
+    buffers: &mut VertexBuffers<VectorMeshVertex, u32>,
+) -> Result<(), VectorMeshError> {
+    let color = color_from_paint(fill.paint(), fill.opacity().get(), inherited_opacity)?;
+    let fill_rule = match fill.rule() {
+        FillRule::EvenOdd => LyonFillRule::EvenOdd,
+        FillRule::NonZero => LyonFillRule::NonZero,
+    };
+    let options = FillOptions::default().with_fill_rule(fill_rule);
+
+    FillTessellator::new().tessellate_path(
+        path,
+        &options,
+        &mut BuffersBuilder::new(buffers, |vertex: FillVertex| {
+            mesh_vertex(vertex.position().to_array(), color, viewport)
+        }),
+    )?;
+    Ok(())
+}
+
+fn tessellate_stroke(
+    path: &LyonPath,
+    stroke: &Stroke,
+    inherited_opacity: f32,
+    viewport: [f32; 2],
+    buffers: &mut VertexBuffers<VectorMeshVertex, u32>,
+) -> Result<(), VectorMeshError> {
+    if stroke.dasharray().is_some() {
+        return Err(VectorMeshError::UnsupportedFeature(
+            "stroke dash arrays".to_string(),
+        ));
+    }
+
+    let color = color_from_paint(stroke.paint(), stroke.opacity().get(), inherited_opacity)?;
+    let mut options = StrokeOptions::default()
+        .with_line_width(stroke.width().get())
+        .with_line_cap(map_line_cap(stroke.linecap()))
+        .with_line_join(map_line_join(stroke.linejoin()));
+    options.miter_limit = stroke.miterlimit().get();
+
+    StrokeTessellator::new().tessellate_path(
+        path,
+        &options,
+        &mut BuffersBuilder::new(buffers, |vertex: StrokeVertex| {
+            mesh_vertex(vertex.position().to_array(), color, viewport)
+        }),
+    )?;
+    Ok(())
+}
+
+fn mesh_vertex(position: [f32; 2], color: [f32; 4], viewport: [f32; 2]) -> VectorMeshVertex {
+    VectorMeshVertex {
+        position: [position[0] / viewport[0], position[1] / viewport[1]],
+        color,
+    }
+}
+
+fn color_from_paint(
+    paint: &Paint,
+    paint_opacity: f32,
+    inherited_opacity: f32,
+) -> Result<[f32; 4], VectorMeshError> {
+    let opacity = (paint_opacity * inherited_opacity).clamp(0.0, 1.0);
+    match paint {
+        Paint::Color(color) => Ok([
+            f32::from(color.red) / 255.0,
+            f32::from(color.green) / 255.0,
+            f32::from(color.blue) / 255.0,
+            opacity,
+        ]),
+        _ => Err(VectorMeshError::UnsupportedFeature(
+            "only solid color fills and strokes are supported".to_string(),
+        )),
+    }
+}
+
+fn convert_to_lyon_path(path: &Path) -> Result<LyonPath, VectorMeshError> {
+    let transformed = path
+        .data()
+        .clone()
+        .transform(path.abs_transform())
+        .ok_or(VectorMeshError::TransformFailed)?;
+
+    let mut builder = LyonPath::builder().with_svg();
+    for segment in transformed.segments() {
+        match segment {
+            PathSegment::MoveTo(p0) => {
+                builder.move_to(point(p0.x, p0.y));
+            }
+            PathSegment::LineTo(p0) => {
+                builder.line_to(point(p0.x, p0.y));
+            }
+            PathSegment::QuadTo(p0, p1) => {
+                builder.quadratic_bezier_to(point(p0.x, p0.y), point(p1.x, p1.y));
+            }
+            PathSegment::CubicTo(p0, p1, p2) => {
+                builder.cubic_bezier_to(point(p0.x, p0.y), point(p1.x, p1.y), point(p2.x, p2.y));
+            }
+            PathSegment::Close => {
+                builder.close();
+            }
+        }
+    }
+    Ok(builder.build())
+}
+
+fn map_line_cap(cap: SvgLineCap) -> LyonLineCap {
+    match cap {
+        SvgLineCap::Butt => LyonLineCap::Butt,
+        SvgLineCap::Round => LyonLineCap::Round,
+        SvgLineCap::Square => LyonLineCap::Square,
+    }
+}
+
+fn map_line_join(join: SvgLineJoin) -> LyonLineJoin {
+    match join {
+        SvgLineJoin::Miter | SvgLineJoin::MiterClip => LyonLineJoin::Miter,
+        SvgLineJoin::Round => LyonLineJoin::Round,
+        SvgLineJoin::Bevel => LyonLineJoin::Bevel,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SVG: &[u8] = br##"<svg xmlns="http://www.w3.org/2000/svg" width="10" height="20">
+        <rect width="10" height="10" fill="#ff0000"/>
+        <path d="M0 15 L10 15" stroke="#00ff00" stroke-width="2" opacity="0.5"/>
+    </svg>"##;
+
+    #[test]
+    fn tessellated_svg_round_trips_through_the_encoding() {
+        let mesh = tessellate_svg(SVG, None).expect("SVG should tessellate");
+        assert_eq!(mesh.viewport, [10.0, 20.0]);
+        assert!(
+            mesh.vertices
+                .iter()
+                .any(|vertex| vertex.color == [1.0, 0.0, 0.0, 1.0])
+        );
+        assert!(
+            mesh.vertices
+                .iter()
+                .any(|vertex| vertex.color == [0.0, 1.0, 0.0, 0.5])
+        );
+
+        let encoded = encode_vector_mesh(&mesh);
+        assert!(encoded.starts_with(VECTOR_MESH_MAGIC));
+        let decoded = decode_vector_mesh(&encoded).expect("encoded mesh should decode");
+        assert_eq!(decoded, mesh);
+    }
+
+    #[test]
+    fn malformed_meshes_are_rejected() {
+        let encoded =
+            encode_vector_mesh(&tessellate_svg(SVG, None).expect("SVG should tessellate"));
+        assert!(matches!(
+            decode_vector_mesh(&encoded[..encoded.len() - 1]),
+            Err(VectorMeshError::InvalidMesh)
+        ));
+        assert!(matches!(
+            decode_vector_mesh(&encoded[VECTOR_MESH_MAGIC.len()..]),
+            Err(VectorMeshError::InvalidMesh)
+        ));
+
+        let mut out_of_range = encoded.clone();
+        let last_index = out_of_range.len() - INDEX_LEN;
+        out_of_range[last_index..].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            decode_vector_mesh(&out_of_range),
+            Err(VectorMeshError::InvalidMesh)
+        ));
+    }
+
+    #[test]
+    fn unsupported_svg_features_are_reported() {
+        let gradient = br##"<svg xmlns="http://www.w3.org/2000/svg" width="10" height="10">
+            <linearGradient id="g">
+                <stop offset="0" stop-color="#000"/>
+                <stop offset="1" stop-color="#fff"/>
+            </linearGradient>
+            <rect width="10" height="10" fill="url(#g)"/>
+        </svg>"##;
+        assert!(matches!(
+            tessellate_svg(gradient, None),
+            Err(VectorMeshError::UnsupportedFeature(_))
+        ));
+    }
+}